@@ -0,0 +1,2 @@
+-- Add optional captions to photos
+ALTER TABLE photos ADD COLUMN caption TEXT;
@@ -0,0 +1,15 @@
+-- Tombstones for deleted records so clients can sync deletions
+CREATE TABLE deletions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    entity_type TEXT NOT NULL CHECK (entity_type IN ('plant', 'tracking_entry', 'photo')),
+    entity_id TEXT NOT NULL,
+    user_id TEXT NOT NULL,
+    deleted_at TEXT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_deletions_user_id_deleted_at ON deletions(user_id, deleted_at);
+
+-- Track photo modifications (captions) for delta sync
+ALTER TABLE photos ADD COLUMN updated_at TEXT;
+UPDATE photos SET updated_at = created_at;
@@ -0,0 +1,9 @@
+-- Cached transcodes of photos for clients that can't display AVIF
+CREATE TABLE photo_renditions (
+    photo_id TEXT NOT NULL,
+    format TEXT NOT NULL,
+    data BLOB NOT NULL,
+    created_at TEXT NOT NULL,
+    PRIMARY KEY (photo_id, format),
+    FOREIGN KEY (photo_id) REFERENCES photos(id) ON DELETE CASCADE
+);
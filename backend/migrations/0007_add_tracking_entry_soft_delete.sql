@@ -0,0 +1,4 @@
+-- Deleted tracking entries stay restorable until the purge job removes them
+ALTER TABLE tracking_entries ADD COLUMN deleted_at TEXT;
+
+CREATE INDEX idx_tracking_entries_deleted_at ON tracking_entries(deleted_at);
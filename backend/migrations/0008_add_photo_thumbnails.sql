@@ -0,0 +1,2 @@
+-- Small AVIF previews for photo grids; NULL until generated
+ALTER TABLE photos ADD COLUMN thumbnail_data BLOB;
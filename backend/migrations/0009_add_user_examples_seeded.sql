@@ -0,0 +1,2 @@
+-- When example plants were added for the user; NULL until they opt in
+ALTER TABLE users ADD COLUMN examples_seeded_at TEXT;
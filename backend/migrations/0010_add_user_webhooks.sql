@@ -0,0 +1,9 @@
+-- Per-user webhook endpoint and the secret used to sign deliveries
+CREATE TABLE user_webhooks (
+    user_id TEXT PRIMARY KEY,
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
@@ -0,0 +1,2 @@
+-- First day of the week for weekly rollups
+ALTER TABLE users ADD COLUMN week_start TEXT NOT NULL DEFAULT 'monday' CHECK (week_start IN ('sunday', 'monday'));
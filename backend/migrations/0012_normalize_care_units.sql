@@ -0,0 +1,29 @@
+-- Rewrite common spellings of care schedule units to their canonical form.
+-- Units that don't match a known alias are left as they are.
+UPDATE plants SET watering_unit = CASE lower(trim(watering_unit))
+    WHEN 'milliliter' THEN 'ml' WHEN 'milliliters' THEN 'ml'
+    WHEN 'millilitre' THEN 'ml' WHEN 'millilitres' THEN 'ml'
+    WHEN 'liter' THEN 'l' WHEN 'liters' THEN 'l' WHEN 'litre' THEN 'l' WHEN 'litres' THEN 'l'
+    WHEN 'fl oz' THEN 'oz' WHEN 'ounce' THEN 'oz' WHEN 'ounces' THEN 'oz'
+    WHEN 'cups' THEN 'cup'
+    WHEN 'teaspoon' THEN 'tsp' WHEN 'teaspoons' THEN 'tsp'
+    WHEN 'tablespoon' THEN 'tbsp' WHEN 'tablespoons' THEN 'tbsp'
+    WHEN '' THEN NULL
+    ELSE CASE WHEN lower(trim(watering_unit)) IN ('ml', 'l', 'oz', 'cup', 'tsp', 'tbsp')
+        THEN lower(trim(watering_unit)) ELSE watering_unit END
+END
+WHERE watering_unit IS NOT NULL;
+
+UPDATE plants SET fertilizing_unit = CASE lower(trim(fertilizing_unit))
+    WHEN 'milliliter' THEN 'ml' WHEN 'milliliters' THEN 'ml'
+    WHEN 'millilitre' THEN 'ml' WHEN 'millilitres' THEN 'ml'
+    WHEN 'liter' THEN 'l' WHEN 'liters' THEN 'l' WHEN 'litre' THEN 'l' WHEN 'litres' THEN 'l'
+    WHEN 'fl oz' THEN 'oz' WHEN 'ounce' THEN 'oz' WHEN 'ounces' THEN 'oz'
+    WHEN 'cups' THEN 'cup'
+    WHEN 'teaspoon' THEN 'tsp' WHEN 'teaspoons' THEN 'tsp'
+    WHEN 'tablespoon' THEN 'tbsp' WHEN 'tablespoons' THEN 'tbsp'
+    WHEN '' THEN NULL
+    ELSE CASE WHEN lower(trim(fertilizing_unit)) IN ('ml', 'l', 'oz', 'cup', 'tsp', 'tbsp')
+        THEN lower(trim(fertilizing_unit)) ELSE fertilizing_unit END
+END
+WHERE fertilizing_unit IS NOT NULL;
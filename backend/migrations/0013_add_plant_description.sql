@@ -0,0 +1,2 @@
+-- Free-form description of the plant itself, separate from care notes
+ALTER TABLE plants ADD COLUMN description TEXT;
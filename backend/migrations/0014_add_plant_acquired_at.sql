@@ -0,0 +1,2 @@
+-- Date the plant was acquired (YYYY-MM-DD), used for its age and anniversaries
+ALTER TABLE plants ADD COLUMN acquired_at TEXT;
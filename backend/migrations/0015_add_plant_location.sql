@@ -0,0 +1,6 @@
+-- Where a plant lives: a free-form room/place name and optional coordinates
+ALTER TABLE plants ADD COLUMN location_name TEXT;
+ALTER TABLE plants ADD COLUMN latitude REAL CHECK (latitude BETWEEN -90 AND 90);
+ALTER TABLE plants ADD COLUMN longitude REAL CHECK (longitude BETWEEN -180 AND 180);
+
+CREATE INDEX idx_plants_user_location ON plants(user_id, latitude, longitude);
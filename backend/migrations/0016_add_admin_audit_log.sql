@@ -0,0 +1,12 @@
+-- Record of admin actions that change other users' data
+CREATE TABLE admin_audit_log (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    admin_id TEXT,
+    action TEXT NOT NULL,
+    target_id TEXT,
+    details TEXT NOT NULL DEFAULT '{}', -- JSON object describing the change
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (admin_id) REFERENCES users(id) ON DELETE SET NULL
+);
+
+CREATE INDEX idx_admin_audit_log_created_at ON admin_audit_log(created_at);
@@ -0,0 +1,3 @@
+-- When scheduled care lands: an optional fixed hour (UTC) and whether to skip weekends
+ALTER TABLE users ADD COLUMN care_hour INTEGER CHECK (care_hour BETWEEN 0 AND 23);
+ALTER TABLE users ADD COLUMN avoid_weekends BOOLEAN NOT NULL DEFAULT FALSE;
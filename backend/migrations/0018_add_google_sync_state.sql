@@ -0,0 +1,16 @@
+-- Google account a token belongs to, so reconnecting a different account can reset sync state
+ALTER TABLE google_oauth_tokens ADD COLUMN account_email TEXT;
+
+-- Google tasks created by a sync, one per plant care slot
+CREATE TABLE google_synced_tasks (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id TEXT NOT NULL,
+    plant_id TEXT NOT NULL,
+    task_type TEXT NOT NULL CHECK (task_type IN ('watering', 'fertilizing')),
+    due_date TEXT NOT NULL,
+    google_task_id TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    UNIQUE (user_id, plant_id, task_type, due_date),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (plant_id) REFERENCES plants(id) ON DELETE CASCADE
+);
@@ -0,0 +1,69 @@
+-- Recurring care beyond the built-in watering and fertilizing, e.g. misting or repotting
+CREATE TABLE care_tasks (
+    id TEXT PRIMARY KEY,
+    plant_id TEXT NOT NULL,
+    task_type TEXT NOT NULL CHECK (task_type NOT IN ('watering', 'fertilizing')),
+    interval_days INTEGER CHECK (interval_days IS NULL OR interval_days > 0),
+    last_done TEXT,
+    amount REAL,
+    unit TEXT,
+    notes TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    UNIQUE (plant_id, task_type),
+    FOREIGN KEY (plant_id) REFERENCES plants(id) ON DELETE CASCADE
+);
+
+-- Care entries record which task they were for. SQLite can't change a CHECK
+-- constraint in place, so the table is rebuilt.
+CREATE TABLE tracking_entries_new (
+    id TEXT PRIMARY KEY,
+    plant_id TEXT NOT NULL,
+    metric_id TEXT,
+    entry_type TEXT NOT NULL CHECK (entry_type IN ('watering', 'fertilizing', 'measurement', 'note', 'photo', 'care')),
+    care_task_type TEXT,
+    timestamp TEXT NOT NULL,
+    value TEXT, -- JSON stored as TEXT for SQLite compatibility
+    notes TEXT,
+    photo_ids TEXT, -- JSON array of photo UUIDs
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    deleted_at TEXT,
+    CHECK ((entry_type = 'care') = (care_task_type IS NOT NULL)),
+    FOREIGN KEY (plant_id) REFERENCES plants(id) ON DELETE CASCADE,
+    FOREIGN KEY (metric_id) REFERENCES custom_metrics(id) ON DELETE SET NULL
+);
+
+INSERT INTO tracking_entries_new (id, plant_id, metric_id, entry_type, timestamp, value, notes, photo_ids, created_at, updated_at, deleted_at)
+SELECT id, plant_id, metric_id, entry_type, timestamp, value, notes, photo_ids, created_at, updated_at, deleted_at
+FROM tracking_entries;
+
+DROP TABLE tracking_entries;
+ALTER TABLE tracking_entries_new RENAME TO tracking_entries;
+
+CREATE INDEX idx_tracking_entries_plant_id ON tracking_entries(plant_id);
+CREATE INDEX idx_tracking_entries_timestamp ON tracking_entries(timestamp);
+CREATE INDEX idx_tracking_entries_entry_type ON tracking_entries(entry_type);
+CREATE INDEX idx_tracking_entries_timestamp_type ON tracking_entries(timestamp, entry_type);
+CREATE INDEX idx_tracking_entries_deleted_at ON tracking_entries(deleted_at);
+
+-- Synced Google tasks can be for any care task, not just the built-in two
+CREATE TABLE google_synced_tasks_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id TEXT NOT NULL,
+    plant_id TEXT NOT NULL,
+    task_type TEXT NOT NULL,
+    due_date TEXT NOT NULL,
+    google_task_id TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    UNIQUE (user_id, plant_id, task_type, due_date),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (plant_id) REFERENCES plants(id) ON DELETE CASCADE
+);
+
+INSERT INTO google_synced_tasks_new (id, user_id, plant_id, task_type, due_date, google_task_id, created_at)
+SELECT id, user_id, plant_id, task_type, due_date, google_task_id, created_at
+FROM google_synced_tasks;
+
+DROP TABLE google_synced_tasks;
+ALTER TABLE google_synced_tasks_new RENAME TO google_synced_tasks;
@@ -0,0 +1,2 @@
+-- The user's offset from UTC, so care due "today" means their today
+ALTER TABLE users ADD COLUMN utc_offset_minutes INTEGER NOT NULL DEFAULT 0 CHECK (utc_offset_minutes BETWEEN -720 AND 840);
@@ -0,0 +1,2 @@
+-- Notes can be kept from people a plant is shared with; existing entries stay shared
+ALTER TABLE tracking_entries ADD COLUMN visibility TEXT NOT NULL DEFAULT 'shared' CHECK (visibility IN ('owner_only', 'shared'));
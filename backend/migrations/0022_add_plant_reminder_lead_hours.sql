@@ -0,0 +1,2 @@
+-- Hours before care is due that reminders fire for this plant; NULL uses the server default
+ALTER TABLE plants ADD COLUMN reminder_lead_hours INTEGER CHECK (reminder_lead_hours BETWEEN 0 AND 168);
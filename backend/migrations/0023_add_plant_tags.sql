@@ -0,0 +1,10 @@
+-- Free-form labels such as "low-light", stored lowercase
+CREATE TABLE plant_tags (
+    plant_id TEXT NOT NULL,
+    tag TEXT NOT NULL CHECK (tag = lower(tag)),
+    created_at TEXT NOT NULL,
+    PRIMARY KEY (plant_id, tag),
+    FOREIGN KEY (plant_id) REFERENCES plants(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_plant_tags_tag ON plant_tags(tag);
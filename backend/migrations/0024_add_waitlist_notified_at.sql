@@ -0,0 +1,2 @@
+-- When a pending waitlist entry was last emailed by an admin, so bulk notifications don't repeat
+ALTER TABLE waitlist ADD COLUMN notified_at TEXT;
@@ -0,0 +1,11 @@
+-- Care recommendations a user has dismissed, with the intervals they were
+-- based on so a materially different recommendation shows up again
+CREATE TABLE dismissed_recommendations (
+    plant_id TEXT NOT NULL,
+    rec_key TEXT NOT NULL,
+    current_interval_days INTEGER,
+    suggested_interval_days INTEGER NOT NULL,
+    dismissed_at TEXT NOT NULL,
+    PRIMARY KEY (plant_id, rec_key),
+    FOREIGN KEY (plant_id) REFERENCES plants(id) ON DELETE CASCADE
+);
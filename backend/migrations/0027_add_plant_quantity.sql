@@ -0,0 +1,2 @@
+-- Number of identical specimens tracked as one plant, e.g. three pots of basil
+ALTER TABLE plants ADD COLUMN quantity INTEGER NOT NULL DEFAULT 1 CHECK (quantity >= 1);
@@ -0,0 +1,8 @@
+-- Read-only links to a single plant for people without an account.
+-- A plant has at most one link; creating a new one replaces it.
+CREATE TABLE public_plant_links (
+    plant_id TEXT PRIMARY KEY,
+    token TEXT NOT NULL UNIQUE,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (plant_id) REFERENCES plants(id) ON DELETE CASCADE
+);
@@ -0,0 +1,3 @@
+-- When a plant was retired; archived plants keep their history but drop out
+-- of listings, the calendar feed and Google Tasks
+ALTER TABLE plants ADD COLUMN archived_at TEXT;
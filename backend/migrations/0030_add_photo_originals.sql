@@ -0,0 +1,9 @@
+-- Uploaded bytes of photos, kept when KEEP_PHOTO_ORIGINALS is on so photos
+-- can be reprocessed after the image settings change
+CREATE TABLE photo_originals (
+    photo_id TEXT PRIMARY KEY NOT NULL,
+    content_type TEXT NOT NULL,
+    data BLOB NOT NULL,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (photo_id) REFERENCES photos(id) ON DELETE CASCADE
+);
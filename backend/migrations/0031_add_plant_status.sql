@@ -0,0 +1,6 @@
+-- Whether a plant is growing, resting or has died. Dead plants keep their
+-- history for the memorial view but no longer get care reminders
+ALTER TABLE plants ADD COLUMN status TEXT NOT NULL DEFAULT 'active'
+    CHECK (status IN ('active', 'dormant', 'dead'));
+ALTER TABLE plants ADD COLUMN died_at TEXT;
+ALTER TABLE plants ADD COLUMN death_reason TEXT;
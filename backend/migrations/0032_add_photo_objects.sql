@@ -0,0 +1,24 @@
+-- Image bytes kept by the database photo store: each photo's processed image,
+-- its kept original and cached renditions. There is no foreign key to photos
+-- because images are stored before their photo's row is inserted, and the
+-- store removes them once the row is deleted
+CREATE TABLE photo_objects (
+    photo_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    content_type TEXT NOT NULL,
+    data BLOB NOT NULL,
+    PRIMARY KEY (photo_id, name)
+);
+
+INSERT INTO photo_objects (photo_id, name, content_type, data)
+SELECT id, 'image', content_type, data FROM photos WHERE length(data) > 0;
+
+INSERT INTO photo_objects (photo_id, name, content_type, data)
+SELECT photo_id, 'original', content_type, data FROM photo_originals;
+
+INSERT INTO photo_objects (photo_id, name, content_type, data)
+SELECT photo_id, format, 'image/' || format, data FROM photo_renditions;
+
+DROP TABLE photo_renditions;
+DROP TABLE photo_originals;
+ALTER TABLE photos DROP COLUMN data;
@@ -0,0 +1,25 @@
+-- Syncing into another task list creates tasks there too, so the list is part
+-- of what identifies a synced task. Earlier rows didn't record their list and
+-- are kept under an empty one
+CREATE TABLE google_synced_tasks_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id TEXT NOT NULL,
+    task_list_id TEXT NOT NULL,
+    plant_id TEXT NOT NULL,
+    task_type TEXT NOT NULL,
+    due_date TEXT NOT NULL,
+    google_task_id TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (plant_id) REFERENCES plants(id) ON DELETE CASCADE
+);
+
+INSERT INTO google_synced_tasks_new (id, user_id, task_list_id, plant_id, task_type, due_date, google_task_id, created_at)
+SELECT id, user_id, '', plant_id, task_type, due_date, google_task_id, created_at
+FROM google_synced_tasks;
+
+DROP TABLE google_synced_tasks;
+ALTER TABLE google_synced_tasks_new RENAME TO google_synced_tasks;
+
+CREATE UNIQUE INDEX idx_google_synced_tasks_slot
+    ON google_synced_tasks (user_id, task_list_id, plant_id, task_type, due_date);
@@ -0,0 +1,3 @@
+-- When a calendar app last fetched the user's iCalendar feed, so the
+-- integrations list can tell whether anything is subscribed to it
+ALTER TABLE users ADD COLUMN calendar_feed_fetched_at TEXT;
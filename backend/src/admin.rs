@@ -1,6 +1,67 @@
+use crate::database::admin_settings::DEFAULT_MAX_TOTAL_USERS;
+use crate::database::users as db_users;
+use crate::models::{CreateUserRequest, User, UserRole};
 use crate::utils::errors::Result;
 use sqlx::SqlitePool;
 use tracing::info;
+use validator::Validate;
+
+/// Credentials for the first admin, read from `BOOTSTRAP_ADMIN_EMAIL` and
+/// `BOOTSTRAP_ADMIN_PASSWORD` (plus an optional `BOOTSTRAP_ADMIN_NAME`)
+#[derive(Debug, Clone)]
+pub struct BootstrapAdmin {
+    pub email: String,
+    pub password: String,
+    pub name: String,
+}
+
+impl BootstrapAdmin {
+    /// Returns `None` unless both the email and password are set
+    pub fn from_env() -> Option<Self> {
+        let email = std::env::var("BOOTSTRAP_ADMIN_EMAIL").ok()?;
+        let password = std::env::var("BOOTSTRAP_ADMIN_PASSWORD").ok()?;
+        let name = std::env::var("BOOTSTRAP_ADMIN_NAME").unwrap_or_else(|_| "Admin".to_string());
+
+        Some(Self {
+            email,
+            password,
+            name,
+        })
+    }
+}
+
+/// Create the bootstrap admin unless an admin already exists.
+///
+/// Returns the created user, or `None` when creation was skipped.
+pub async fn ensure_bootstrap_admin(
+    pool: &SqlitePool,
+    bootstrap: &BootstrapAdmin,
+) -> Result<Option<User>> {
+    let admin_count = sqlx::query_scalar!(
+        "SELECT COUNT(*) FROM users WHERE role = 'admin'"
+    )
+    .fetch_one(pool)
+    .await?;
+
+    if admin_count > 0 {
+        info!("Admin user already exists, skipping admin bootstrap");
+        return Ok(None);
+    }
+
+    let request = CreateUserRequest {
+        email: bootstrap.email.clone(),
+        name: bootstrap.name.clone(),
+        password: bootstrap.password.clone(),
+        invite_code: None,
+    };
+    request.validate()?;
+
+    let user =
+        db_users::create_user_internal(pool, &request, UserRole::Admin, true, None).await?;
+
+    info!("Created bootstrap admin user {}", user.email);
+    Ok(Some(user))
+}
 
 pub async fn ensure_admin_invite(pool: &SqlitePool) -> Result<String> {
     // Check if any admin users exist
@@ -115,7 +176,7 @@ pub async fn get_system_stats(pool: &SqlitePool) -> Result<SystemStats> {
 
     let max_total_users = max_users_setting
         .and_then(|v| v.parse::<i32>().ok())
-        .unwrap_or(1000);
+        .unwrap_or(DEFAULT_MAX_TOTAL_USERS);
 
     Ok(SystemStats {
         total_users,
@@ -128,6 +189,7 @@ pub async fn get_system_stats(pool: &SqlitePool) -> Result<SystemStats> {
 }
 
 #[derive(Debug, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SystemStats {
     pub total_users: i32,
     pub max_total_users: i32,
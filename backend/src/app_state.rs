@@ -1,23 +1,74 @@
 use std::sync::Arc;
 use tokio::sync::Notify;
 
+use crate::config::AppConfig;
 use crate::database::DatabasePool;
+use crate::utils::feature_cache::FeatureCache;
+use crate::utils::image_workers::ImageWorkers;
+use crate::utils::job_registry::JobRegistry;
+use crate::utils::mailer::{LogMailer, Mailer};
+use crate::utils::photo_store::{DatabasePhotoStore, PhotoStore};
+use crate::utils::task_list_cache::TaskListCache;
+use crate::utils::thumbnail_backfill::ThumbnailBackfill;
+use crate::utils::weather::{StubWeatherProvider, WeatherProvider};
 
 /// Application state that gets passed to all handlers
 #[derive(Clone)]
 pub struct AppState {
     pub pool: DatabasePool,
+    pub config: Arc<AppConfig>,
     pub token_refresh_notifier: Option<Arc<Notify>>,
+    pub google_integration_enabled: bool,
+    pub job_registry: JobRegistry,
+    pub task_lists: TaskListCache,
+    pub features: FeatureCache,
+    pub thumbnail_backfill: ThumbnailBackfill,
+    pub weather: Arc<dyn WeatherProvider>,
+    pub photo_store: Arc<dyn PhotoStore>,
+    pub image_workers: ImageWorkers,
+    pub mailer: Arc<dyn Mailer>,
 }
 
 impl AppState {
     pub fn new(pool: DatabasePool) -> Self {
         Self {
+            photo_store: Arc::new(DatabasePhotoStore::new(pool.clone())),
             pool,
+            config: Arc::new(AppConfig::default()),
             token_refresh_notifier: None,
+            google_integration_enabled: true,
+            job_registry: JobRegistry::new(),
+            task_lists: TaskListCache::new(),
+            features: FeatureCache::new(),
+            thumbnail_backfill: ThumbnailBackfill::new(),
+            weather: Arc::new(StubWeatherProvider),
+            image_workers: ImageWorkers::default(),
+            mailer: Arc::new(LogMailer),
         }
     }
 
+    /// Use `config`, including the weather provider, photo store and image
+    /// processing limits it selects
+    pub fn with_config(mut self, config: AppConfig) -> Self {
+        self.weather = config.weather.provider();
+        self.photo_store = config.photo_storage.store(self.pool.clone());
+        self.image_workers =
+            ImageWorkers::new(config.image_processing_concurrency, config.image_processing_queue);
+        self.config = Arc::new(config);
+        self
+    }
+
+    pub fn with_google_integration(mut self, enabled: bool) -> Self {
+        self.google_integration_enabled = enabled;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_mailer(mut self, mailer: Arc<dyn Mailer>) -> Self {
+        self.mailer = mailer;
+        self
+    }
+
     pub fn with_token_notifier(mut self, notifier: Arc<Notify>) -> Self {
         self.token_refresh_notifier = Some(notifier);
         self
@@ -1,11 +1,16 @@
+use axum::{
+    extract::FromRequestParts,
+    http::{header, request::Parts},
+};
 use axum_login::{
-    tower_sessions::{cookie::SameSite, Expiry, SessionManagerLayer},
+    tower_sessions::{cookie::SameSite, Expiry, Session, SessionManagerLayer},
     AuthManagerLayerBuilder,
 };
+use chrono::Utc;
 use time::Duration;
 use tower_sessions_sqlx_store::SqliteStore;
 
-use crate::database::{users as db_users, DatabasePool};
+use crate::database::{api_keys as db_api_keys, users as db_users, DatabasePool};
 use crate::models::User;
 use crate::utils::errors::AppError;
 
@@ -73,6 +78,107 @@ pub struct Credentials {
 // Type aliases for convenience
 pub type AuthSession = axum_login::AuthSession<AuthBackend>;
 
+/// Session key axum-login keeps the logged-in user's id under
+pub const AUTH_DATA_KEY: &str = "axum-login.data";
+
+/// Session key holding when the user logged in; the session store doesn't track it
+pub const SESSION_CREATED_AT_KEY: &str = "planty.created_at";
+
+/// Stamp a freshly logged-in session with the login time, for the admin session list
+///
+/// Failing to do so only leaves the time unknown, so it is logged rather than returned.
+pub async fn record_login_time(session: &Session) {
+    if let Err(e) = session.insert(SESSION_CREATED_AT_KEY, Utc::now()).await {
+        tracing::warn!("Failed to record session login time: {}", e);
+    }
+}
+
+/// The authenticated user for the current request, from the session or an
+/// `Authorization: Bearer` API key; rejects with 401 otherwise.
+#[derive(Debug, Clone)]
+pub struct CurrentUser(pub User);
+
+/// Request extension marking a [`CurrentUser`] that came from an API key
+#[derive(Debug, Clone, Copy)]
+pub struct ApiKeyAuthenticated;
+
+#[async_trait::async_trait]
+impl<S> FromRequestParts<S> for CurrentUser
+where
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        // Reuse the user resolved by the middleware when the route is layered
+        if let Some(user) = parts.extensions.get::<Self>() {
+            return Ok(user.clone());
+        }
+
+        let auth_session = AuthSession::from_request_parts(parts, state)
+            .await
+            .map_err(|(_, message)| AppError::Internal {
+                message: message.to_string(),
+            })?;
+
+        if let Some(user) = auth_session.user {
+            return Ok(Self(user));
+        }
+
+        // Scripts authenticate with an API key instead of a session cookie
+        if let Some(key) = bearer_token(parts) {
+            let user = db_api_keys::authenticate_api_key(&auth_session.backend.db, key)
+                .await?
+                .ok_or(AppError::Authentication {
+                    message: "Invalid or expired API key".to_string(),
+                })?;
+            parts.extensions.insert(ApiKeyAuthenticated);
+            return Ok(Self(user));
+        }
+
+        Err(AppError::Authentication {
+            message: "Not authenticated".to_string(),
+        })
+    }
+}
+
+/// The user for the current request, signed in with a session; API keys are
+/// rejected with 403 so a leaked key can't mint more keys or reach admin routes.
+#[derive(Debug, Clone)]
+pub struct SessionUser(pub User);
+
+#[async_trait::async_trait]
+impl<S> FromRequestParts<S> for SessionUser
+where
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let CurrentUser(user) = CurrentUser::from_request_parts(parts, state).await?;
+
+        if parts.extensions.get::<ApiKeyAuthenticated>().is_some() {
+            return Err(AppError::Authorization {
+                message: "API keys can't be used here; sign in instead".to_string(),
+            });
+        }
+
+        Ok(Self(user))
+    }
+}
+
+/// The token from an `Authorization: Bearer <token>` header
+fn bearer_token(parts: &Parts) -> Option<&str> {
+    parts
+        .headers
+        .get(header::AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+}
+
 // Helper function to create session and auth layers
 // Uses SQLite-backed session storage for persistence across server restarts
 #[must_use]
@@ -0,0 +1,456 @@
+use crate::utils::errors::{AppError, Result};
+use crate::utils::google_tasks::GoogleTasksConfig;
+use crate::utils::image_processing::{ImageSettings, OversizeMode, ServeFormat, MAX_DIMENSION};
+use crate::utils::image_workers::{DEFAULT_IMAGE_CONCURRENCY, DEFAULT_IMAGE_QUEUE};
+use crate::utils::photo_store::PhotoStorage;
+use crate::utils::weather::{WeatherSource, OPEN_METEO_URL};
+
+/// Default upload limit (10MB)
+const DEFAULT_MAX_FILE_SIZE: usize = 10 * 1024 * 1024;
+
+/// Default number of custom metrics a plant can have
+const DEFAULT_MAX_CUSTOM_METRICS: usize = 20;
+
+/// Default hours before care is due that calendar reminders fire
+const DEFAULT_REMINDER_LEAD_HOURS: u32 = 1;
+
+/// Longest reminder lead time, globally or per plant (one week)
+pub const MAX_REMINDER_LEAD_HOURS: u32 = 168;
+
+/// Default days deletion tombstones are kept for sync clients
+const DEFAULT_TOMBSTONE_RETENTION_DAYS: u32 = 90;
+
+/// Runtime configuration, parsed once at startup and shared through `AppState`
+///
+/// Server basics such as the port and database URL stay on the CLI arguments;
+/// everything handlers need at request time lives here. `HOST_IP` (default
+/// `localhost`) is only used to build the default URLs.
+#[derive(Debug, Clone)]
+pub struct AppConfig {
+    /// Public URL of the server (`BASE_URL`); request headers are used when unset
+    pub base_url: Option<String>,
+    /// Where OAuth flows redirect back to (`FRONTEND_URL`)
+    pub frontend_url: String,
+    /// Origins allowed by CORS in release builds (`ALLOWED_ORIGINS`, comma-separated,
+    /// `https://*.example.com` allows any subdomain)
+    pub allowed_origins: Vec<String>,
+    /// Largest accepted request body and photo upload in bytes (`MAX_FILE_SIZE`)
+    pub max_file_size: usize,
+    /// Most custom metrics a single plant can have (`MAX_CUSTOM_METRICS`)
+    pub max_custom_metrics: usize,
+    /// Hours before care is due that reminders fire, unless a plant overrides it
+    /// (`REMINDER_LEAD_HOURS`)
+    pub reminder_lead_hours: u32,
+    /// How uploads over the maximum dimension are handled (`IMAGE_OVERSIZE`)
+    pub image_oversize: OversizeMode,
+    /// Longest side of stored photos in pixels, up to 3840 (`IMAGE_MAX_DIMENSION`)
+    pub image_max_dimension: u32,
+    /// Keep uploaded photo bytes so photos can be reprocessed (`KEEP_PHOTO_ORIGINALS`)
+    pub keep_photo_originals: bool,
+    /// Format served to clients without AVIF or WebP support (`IMAGE_FALLBACK_FORMAT`)
+    pub image_fallback_format: ServeFormat,
+    /// Photos processed at once (`IMAGE_PROCESSING_CONCURRENCY`)
+    pub image_processing_concurrency: usize,
+    /// Photos waiting to be processed before uploads are turned away with a
+    /// 503 (`IMAGE_PROCESSING_QUEUE`)
+    pub image_processing_queue: usize,
+    /// Google Tasks credentials, `None` unless the client ID and secret are set
+    pub google: Option<GoogleTasksConfig>,
+    /// Precipitation source for watering suggestions (`WEATHER_PROVIDER`, `WEATHER_API_URL`)
+    pub weather: WeatherSource,
+    /// Where photo images are kept (`PHOTO_STORAGE`, plus the `S3_*` and `AWS_*`
+    /// variables for S3)
+    pub photo_storage: PhotoStorage,
+    /// Let webhooks target loopback and private network addresses, for local
+    /// development and tests (`WEBHOOK_ALLOW_PRIVATE_TARGETS`)
+    pub webhook_allow_private_targets: bool,
+    /// Days deletion tombstones are kept before pruning; clients that last synced
+    /// longer ago need a full sync (`TOMBSTONE_RETENTION_DAYS`)
+    pub tombstone_retention_days: u32,
+}
+
+impl AppConfig {
+    /// Read the configuration from the process environment
+    pub fn from_env() -> Result<Self> {
+        Self::from_lookup(|name| std::env::var(name).ok())
+    }
+
+    /// Build the configuration from `lookup`, which maps a variable name to its value
+    ///
+    /// Blank values are treated as unset.
+    ///
+    /// # Errors
+    /// * Returns `Configuration` if a set variable has an invalid value
+    pub fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let var = |name: &str| {
+            lookup(name)
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+
+        let host_ip = var("HOST_IP").unwrap_or_else(|| "localhost".to_string());
+
+        let allowed_origins = match var("ALLOWED_ORIGINS") {
+            Some(origins) => origins
+                .split(',')
+                .map(str::trim)
+                .filter(|origin| !origin.is_empty())
+                .map(str::to_string)
+                .collect(),
+            None => vec![
+                format!("http://{}:3000", host_ip),
+                "http://127.0.0.1:3000".to_string(),
+            ],
+        };
+
+        let max_file_size = match var("MAX_FILE_SIZE") {
+            None => DEFAULT_MAX_FILE_SIZE,
+            Some(value) => value
+                .parse::<usize>()
+                .ok()
+                .filter(|size| *size > 0)
+                .ok_or_else(|| AppError::Configuration {
+                    message: "MAX_FILE_SIZE must be a positive number of bytes".to_string(),
+                })?,
+        };
+
+        let max_custom_metrics = match var("MAX_CUSTOM_METRICS") {
+            None => DEFAULT_MAX_CUSTOM_METRICS,
+            Some(value) => value
+                .parse::<usize>()
+                .ok()
+                .filter(|max| *max > 0)
+                .ok_or_else(|| AppError::Configuration {
+                    message: "MAX_CUSTOM_METRICS must be a positive number".to_string(),
+                })?,
+        };
+
+        let reminder_lead_hours = match var("REMINDER_LEAD_HOURS") {
+            None => DEFAULT_REMINDER_LEAD_HOURS,
+            Some(value) => value
+                .parse::<u32>()
+                .ok()
+                .filter(|hours| *hours <= MAX_REMINDER_LEAD_HOURS)
+                .ok_or_else(|| AppError::Configuration {
+                    message: format!(
+                        "REMINDER_LEAD_HOURS must be a whole number of hours up to {}",
+                        MAX_REMINDER_LEAD_HOURS
+                    ),
+                })?,
+        };
+
+        let image_oversize = match var("IMAGE_OVERSIZE").as_deref() {
+            None | Some("downscale") => OversizeMode::Downscale,
+            Some("reject") => OversizeMode::Reject,
+            Some(_) => {
+                return Err(AppError::Configuration {
+                    message: "IMAGE_OVERSIZE must be downscale or reject".to_string(),
+                })
+            }
+        };
+
+        let image_max_dimension = match var("IMAGE_MAX_DIMENSION") {
+            None => MAX_DIMENSION,
+            Some(value) => value
+                .parse::<u32>()
+                .ok()
+                .filter(|max| (1..=MAX_DIMENSION).contains(max))
+                .ok_or_else(|| AppError::Configuration {
+                    message: format!(
+                        "IMAGE_MAX_DIMENSION must be a number of pixels from 1 to {}",
+                        MAX_DIMENSION
+                    ),
+                })?,
+        };
+
+        let keep_photo_originals = match var("KEEP_PHOTO_ORIGINALS").as_deref() {
+            None | Some("false") => false,
+            Some("true") => true,
+            Some(_) => {
+                return Err(AppError::Configuration {
+                    message: "KEEP_PHOTO_ORIGINALS must be true or false".to_string(),
+                })
+            }
+        };
+
+        let image_fallback_format = match var("IMAGE_FALLBACK_FORMAT").as_deref() {
+            None | Some("jpeg") => ServeFormat::Jpeg,
+            Some("webp") => ServeFormat::WebP,
+            Some(_) => {
+                return Err(AppError::Configuration {
+                    message: "IMAGE_FALLBACK_FORMAT must be jpeg or webp".to_string(),
+                })
+            }
+        };
+
+        let image_processing_concurrency = match var("IMAGE_PROCESSING_CONCURRENCY") {
+            None => DEFAULT_IMAGE_CONCURRENCY,
+            Some(value) => value
+                .parse::<usize>()
+                .ok()
+                .filter(|workers| *workers > 0)
+                .ok_or_else(|| AppError::Configuration {
+                    message: "IMAGE_PROCESSING_CONCURRENCY must be a positive number".to_string(),
+                })?,
+        };
+
+        let image_processing_queue = match var("IMAGE_PROCESSING_QUEUE") {
+            None => DEFAULT_IMAGE_QUEUE,
+            Some(value) => value
+                .parse::<usize>()
+                .map_err(|_| AppError::Configuration {
+                    message: "IMAGE_PROCESSING_QUEUE must be a number of photos".to_string(),
+                })?,
+        };
+
+        let tombstone_retention_days = match var("TOMBSTONE_RETENTION_DAYS") {
+            None => DEFAULT_TOMBSTONE_RETENTION_DAYS,
+            Some(value) => value
+                .parse::<u32>()
+                .ok()
+                .filter(|days| *days > 0)
+                .ok_or_else(|| AppError::Configuration {
+                    message: "TOMBSTONE_RETENTION_DAYS must be a positive number of days"
+                        .to_string(),
+                })?,
+        };
+
+        let webhook_allow_private_targets = match var("WEBHOOK_ALLOW_PRIVATE_TARGETS").as_deref() {
+            None | Some("false") => false,
+            Some("true") => true,
+            Some(_) => {
+                return Err(AppError::Configuration {
+                    message: "WEBHOOK_ALLOW_PRIVATE_TARGETS must be true or false".to_string(),
+                })
+            }
+        };
+
+        let weather = match var("WEATHER_PROVIDER").as_deref() {
+            None | Some("stub") => WeatherSource::Stub,
+            Some("open-meteo") if cfg!(feature = "weather-api") => WeatherSource::OpenMeteo {
+                url: var("WEATHER_API_URL").unwrap_or_else(|| OPEN_METEO_URL.to_string()),
+            },
+            Some("open-meteo") => {
+                return Err(AppError::Configuration {
+                    message: "WEATHER_PROVIDER=open-meteo requires the weather-api feature"
+                        .to_string(),
+                })
+            }
+            Some(_) => {
+                return Err(AppError::Configuration {
+                    message: "WEATHER_PROVIDER must be stub or open-meteo".to_string(),
+                })
+            }
+        };
+
+        Ok(Self {
+            frontend_url: var("FRONTEND_URL").unwrap_or_else(|| format!("http://{}:3000", host_ip)),
+            base_url: var("BASE_URL"),
+            google: GoogleTasksConfig::from_lookup(&var, &host_ip)?,
+            photo_storage: PhotoStorage::from_lookup(&var)?,
+            allowed_origins,
+            max_file_size,
+            max_custom_metrics,
+            reminder_lead_hours,
+            image_oversize,
+            image_max_dimension,
+            keep_photo_originals,
+            image_fallback_format,
+            image_processing_concurrency,
+            image_processing_queue,
+            weather,
+            webhook_allow_private_targets,
+            tombstone_retention_days,
+        })
+    }
+
+    /// How uploaded photos are processed and kept
+    pub fn image_settings(&self) -> ImageSettings {
+        ImageSettings {
+            oversize_mode: self.image_oversize,
+            max_dimension: self.image_max_dimension,
+            keep_originals: self.keep_photo_originals,
+        }
+    }
+
+    /// How long deletion tombstones are kept
+    pub fn tombstone_retention(&self) -> chrono::Duration {
+        chrono::Duration::days(self.tombstone_retention_days.into())
+    }
+
+    /// The Google Tasks configuration, or a `Configuration` error if it isn't set up
+    pub fn google_tasks(&self) -> Result<&GoogleTasksConfig> {
+        self.google.as_ref().ok_or_else(|| AppError::Configuration {
+            message: "GOOGLE_CLIENT_ID and GOOGLE_CLIENT_SECRET environment variables must be set"
+                .to_string(),
+        })
+    }
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        // With nothing set every value falls back to its default, which can't fail
+        Self::from_lookup(|_| None).expect("default configuration is valid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::photo_store::{S3Config, DEFAULT_S3_REGION};
+    use std::collections::HashMap;
+
+    fn config_from(vars: &[(&str, &str)]) -> Result<AppConfig> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        AppConfig::from_lookup(|name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn test_app_config_from_vars() {
+        let config = config_from(&[
+            ("HOST_IP", "192.168.1.10"),
+            ("BASE_URL", "https://plants.example.com"),
+            (
+                "ALLOWED_ORIGINS",
+                "https://a.example.com, https://b.example.com",
+            ),
+            ("MAX_FILE_SIZE", "2048"),
+            ("MAX_CUSTOM_METRICS", "5"),
+            ("REMINDER_LEAD_HOURS", "24"),
+            ("IMAGE_OVERSIZE", "reject"),
+            ("IMAGE_MAX_DIMENSION", "1024"),
+            ("KEEP_PHOTO_ORIGINALS", "true"),
+            ("IMAGE_FALLBACK_FORMAT", "webp"),
+            ("IMAGE_PROCESSING_CONCURRENCY", "4"),
+            ("IMAGE_PROCESSING_QUEUE", "0"),
+            ("GOOGLE_CLIENT_ID", "client-id"),
+            ("GOOGLE_CLIENT_SECRET", "client-secret"),
+            ("GOOGLE_TOKEN_REFRESH_MARGIN_MINUTES", "10"),
+            ("GOOGLE_TOKEN_URL", "http://localhost:8080/token"),
+            ("PHOTO_STORAGE", "s3"),
+            ("S3_BUCKET", "plants"),
+            ("S3_ENDPOINT", "http://minio.local:9000/"),
+            ("WEBHOOK_ALLOW_PRIVATE_TARGETS", "true"),
+            ("TOMBSTONE_RETENTION_DAYS", "30"),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            config.base_url.as_deref(),
+            Some("https://plants.example.com")
+        );
+        assert_eq!(config.frontend_url, "http://192.168.1.10:3000");
+        assert_eq!(
+            config.allowed_origins,
+            vec!["https://a.example.com", "https://b.example.com"]
+        );
+        assert_eq!(config.max_file_size, 2048);
+        assert_eq!(config.max_custom_metrics, 5);
+        assert_eq!(config.reminder_lead_hours, 24);
+        assert_eq!(
+            config.image_settings(),
+            ImageSettings {
+                oversize_mode: OversizeMode::Reject,
+                max_dimension: 1024,
+                keep_originals: true,
+            }
+        );
+        assert_eq!(config.image_fallback_format, ServeFormat::WebP);
+        assert_eq!(config.image_processing_concurrency, 4);
+        assert_eq!(config.image_processing_queue, 0);
+
+        let google = config.google_tasks().unwrap();
+        assert_eq!(google.client_id, "client-id");
+        assert_eq!(
+            google.redirect_uri,
+            "http://192.168.1.10:3000/api/v1/google-tasks/callback"
+        );
+        assert_eq!(google.refresh_margin, chrono::Duration::minutes(10));
+        assert_eq!(google.token_url, "http://localhost:8080/token");
+
+        let PhotoStorage::S3(s3) = &config.photo_storage else {
+            panic!("expected S3 photo storage");
+        };
+        assert_eq!(s3.bucket, "plants");
+        assert_eq!(s3.region, DEFAULT_S3_REGION);
+        assert_eq!(s3.endpoint.as_deref(), Some("http://minio.local:9000"));
+        assert!(config.webhook_allow_private_targets);
+        assert_eq!(config.tombstone_retention(), chrono::Duration::days(30));
+    }
+
+    #[test]
+    fn test_app_config_defaults() {
+        let config = config_from(&[("BASE_URL", "  ")]).unwrap();
+
+        assert_eq!(config.base_url, None);
+        assert_eq!(
+            config.allowed_origins,
+            vec!["http://localhost:3000", "http://127.0.0.1:3000"]
+        );
+        assert_eq!(config.max_file_size, DEFAULT_MAX_FILE_SIZE);
+        assert_eq!(config.max_custom_metrics, DEFAULT_MAX_CUSTOM_METRICS);
+        assert_eq!(config.reminder_lead_hours, DEFAULT_REMINDER_LEAD_HOURS);
+        assert_eq!(config.image_settings(), ImageSettings::default());
+        assert_eq!(config.image_fallback_format, ServeFormat::Jpeg);
+        assert_eq!(
+            config.image_processing_concurrency,
+            DEFAULT_IMAGE_CONCURRENCY
+        );
+        assert_eq!(config.image_processing_queue, DEFAULT_IMAGE_QUEUE);
+        assert!(config.google.is_none());
+        assert_eq!(config.weather, WeatherSource::Stub);
+        assert_eq!(config.photo_storage, PhotoStorage::Database);
+        assert!(!config.webhook_allow_private_targets);
+        assert_eq!(
+            config.tombstone_retention_days,
+            DEFAULT_TOMBSTONE_RETENTION_DAYS
+        );
+        assert!(matches!(
+            config.google_tasks(),
+            Err(AppError::Configuration { .. })
+        ));
+    }
+
+    #[test]
+    fn test_app_config_rejects_invalid_values() {
+        for vars in [
+            [("MAX_FILE_SIZE", "ten megabytes")],
+            [("MAX_CUSTOM_METRICS", "0")],
+            [("REMINDER_LEAD_HOURS", "169")],
+            [("IMAGE_OVERSIZE", "crop")],
+            [("IMAGE_MAX_DIMENSION", "0")],
+            [("IMAGE_MAX_DIMENSION", "4096")],
+            [("KEEP_PHOTO_ORIGINALS", "yes")],
+            [("IMAGE_FALLBACK_FORMAT", "gif")],
+            [("IMAGE_PROCESSING_CONCURRENCY", "0")],
+            [("IMAGE_PROCESSING_QUEUE", "-1")],
+            [("GOOGLE_TOKEN_REFRESH_POLL_MINUTES", "0")],
+            [("WEATHER_PROVIDER", "sunny")],
+            [("PHOTO_STORAGE", "ftp")],
+            [("WEBHOOK_ALLOW_PRIVATE_TARGETS", "1")],
+            [("TOMBSTONE_RETENTION_DAYS", "0")],
+            // S3 without a bucket
+            [("PHOTO_STORAGE", "s3")],
+        ] {
+            assert!(
+                matches!(config_from(&vars), Err(AppError::Configuration { .. })),
+                "{:?}",
+                vars
+            );
+        }
+
+        let s3_vars = [("PHOTO_STORAGE", "s3"), ("S3_BUCKET", "plants")];
+        let config = config_from(&s3_vars).unwrap();
+        assert!(matches!(
+            config.photo_storage,
+            PhotoStorage::S3(S3Config { endpoint: None, .. })
+        ));
+        assert!(matches!(
+            config_from(&[&s3_vars[..], &[("S3_ENDPOINT", "minio.local:9000")]].concat()),
+            Err(AppError::Configuration { .. })
+        ));
+    }
+}
@@ -0,0 +1,112 @@
+use serde::Serialize;
+use std::{collections::HashMap, str::FromStr};
+use utoipa::ToSchema;
+
+use crate::database::DatabasePool;
+use crate::utils::errors::AppError;
+
+pub const MAX_TOTAL_USERS_KEY: &str = "max_total_users";
+pub const DEFAULT_USER_INVITE_LIMIT_KEY: &str = "default_user_invite_limit";
+pub const REGISTRATION_ENABLED_KEY: &str = "registration_enabled";
+
+/// Users allowed in the system when `max_total_users` isn't stored
+pub const DEFAULT_MAX_TOTAL_USERS: i32 = 1000;
+
+/// Invites a new user can create when `default_user_invite_limit` isn't stored
+pub const DEFAULT_USER_INVITE_LIMIT: i32 = 5;
+
+/// Whether registration is open when `registration_enabled` isn't stored
+pub const DEFAULT_REGISTRATION_ENABLED: bool = true;
+
+/// Where an effective setting's value came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SettingSource {
+    /// A value stored in `admin_settings`
+    Stored,
+    /// The built-in default, as nothing usable is stored
+    Default,
+}
+
+/// A setting's effective value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Setting<T> {
+    pub value: T,
+    pub source: SettingSource,
+}
+
+/// Every admin setting, stored values merged over the defaults
+#[derive(Debug, Clone, Copy)]
+pub struct AdminSettings {
+    pub max_total_users: Setting<i32>,
+    pub default_user_invite_limit: Setting<i32>,
+    pub registration_enabled: Setting<bool>,
+}
+
+/// The stored value of `key`, or `default` if it is missing or can't be parsed
+fn setting<T: FromStr>(stored: &HashMap<String, String>, key: &str, default: T) -> Setting<T> {
+    let value = stored.get(key).and_then(|value| {
+        let parsed = value.parse().ok();
+        if parsed.is_none() {
+            tracing::warn!("Ignoring malformed admin setting {}: {:?}", key, value);
+        }
+        parsed
+    });
+
+    match value {
+        Some(value) => Setting {
+            value,
+            source: SettingSource::Stored,
+        },
+        None => Setting {
+            value: default,
+            source: SettingSource::Default,
+        },
+    }
+}
+
+/// Load the effective admin settings
+///
+/// Settings missing from the table, e.g. deleted by hand, fall back to their defaults.
+pub async fn get_admin_settings(pool: &DatabasePool) -> Result<AdminSettings, AppError> {
+    let stored: HashMap<String, String> =
+        sqlx::query_as::<_, (String, String)>("SELECT key, value FROM admin_settings")
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .collect();
+
+    Ok(AdminSettings {
+        max_total_users: setting(&stored, MAX_TOTAL_USERS_KEY, DEFAULT_MAX_TOTAL_USERS),
+        default_user_invite_limit: setting(
+            &stored,
+            DEFAULT_USER_INVITE_LIMIT_KEY,
+            DEFAULT_USER_INVITE_LIMIT,
+        ),
+        registration_enabled: setting(
+            &stored,
+            REGISTRATION_ENABLED_KEY,
+            DEFAULT_REGISTRATION_ENABLED,
+        ),
+    })
+}
+
+/// Store a setting, recreating its row if it was deleted
+pub async fn set_admin_setting(
+    pool: &DatabasePool,
+    key: &str,
+    value: &str,
+) -> Result<(), AppError> {
+    sqlx::query(
+        "INSERT INTO admin_settings (key, value, created_at, updated_at)
+         VALUES (?, ?, datetime('now'), ?)
+         ON CONFLICT (key) DO UPDATE SET value = excluded.value, updated_at = excluded.updated_at",
+    )
+    .bind(key)
+    .bind(value)
+    .bind(chrono::Utc::now().to_rfc3339())
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{sqlite::SqliteRow, Row};
+use uuid::Uuid;
+
+use crate::database::{users as db_users, DatabasePool};
+use crate::models::api_key::{ApiKey, CreateApiKeyRequest};
+use crate::models::User;
+use crate::utils::api_keys::{display_prefix, generate_api_key, hash_api_key};
+use crate::utils::errors::{AppError, Result};
+
+const API_KEY_COLUMNS: &str = "id, label, prefix, last_used_at, expires_at, created_at";
+
+/// How stale `last_used_at` may get before a request refreshes it, to spare a
+/// write on every request from busy scripts
+const LAST_USED_RESOLUTION_SECONDS: i64 = 60;
+
+fn api_key_from_row(row: &SqliteRow) -> Result<ApiKey> {
+    let parse = |value: String| {
+        value
+            .parse::<DateTime<Utc>>()
+            .map_err(|_| AppError::Internal {
+                message: "Invalid datetime in database".to_string(),
+            })
+    };
+
+    Ok(ApiKey {
+        id: row.get("id"),
+        label: row.get("label"),
+        prefix: row.get("prefix"),
+        last_used_at: row.get::<Option<String>, _>("last_used_at").map(parse).transpose()?,
+        expires_at: row.get::<Option<String>, _>("expires_at").map(parse).transpose()?,
+        created_at: parse(row.get("created_at"))?,
+    })
+}
+
+/// Create a key for the user, returning the key itself alongside its details
+pub async fn create_api_key(
+    pool: &DatabasePool,
+    user_id: &str,
+    request: &CreateApiKeyRequest,
+) -> Result<(String, ApiKey)> {
+    let key = generate_api_key();
+    let id = Uuid::new_v4().to_string();
+    let now = Utc::now();
+    let expires_at = request
+        .expires_in_days
+        .map(|days| now + Duration::days(i64::from(days)));
+
+    sqlx::query(
+        "INSERT INTO api_keys (id, user_id, label, key_hash, prefix, expires_at, created_at)
+         VALUES (?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(&id)
+    .bind(user_id)
+    .bind(request.label.trim())
+    .bind(hash_api_key(&key))
+    .bind(display_prefix(&key))
+    .bind(expires_at.map(|at| at.to_rfc3339()))
+    .bind(now.to_rfc3339())
+    .execute(pool)
+    .await?;
+
+    let row = sqlx::query(&format!("SELECT {API_KEY_COLUMNS} FROM api_keys WHERE id = ?"))
+        .bind(&id)
+        .fetch_one(pool)
+        .await?;
+
+    Ok((key, api_key_from_row(&row)?))
+}
+
+/// The user's keys, newest first
+pub async fn list_api_keys(pool: &DatabasePool, user_id: &str) -> Result<Vec<ApiKey>> {
+    let rows = sqlx::query(&format!(
+        "SELECT {API_KEY_COLUMNS} FROM api_keys WHERE user_id = ? ORDER BY created_at DESC"
+    ))
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    rows.iter().map(api_key_from_row).collect()
+}
+
+/// Revoke one of the user's keys; requests using it are rejected from then on
+pub async fn delete_api_key(pool: &DatabasePool, user_id: &str, id: &str) -> Result<()> {
+    let result = sqlx::query("DELETE FROM api_keys WHERE id = ? AND user_id = ?")
+        .bind(id)
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::api_key_not_found());
+    }
+
+    Ok(())
+}
+
+/// The user owning `key`, or `None` if it is unknown, revoked or expired
+///
+/// Records the key as used.
+pub async fn authenticate_api_key(pool: &DatabasePool, key: &str) -> Result<Option<User>> {
+    let now = Utc::now();
+
+    let Some(row) = sqlx::query(
+        "SELECT id, user_id FROM api_keys
+         WHERE key_hash = ? AND (expires_at IS NULL OR julianday(expires_at) > julianday(?))",
+    )
+    .bind(hash_api_key(key))
+    .bind(now.to_rfc3339())
+    .fetch_optional(pool)
+    .await?
+    else {
+        return Ok(None);
+    };
+
+    let id: String = row.get("id");
+    let user_id: String = row.get("user_id");
+
+    sqlx::query(
+        "UPDATE api_keys SET last_used_at = ?
+         WHERE id = ? AND (last_used_at IS NULL OR julianday(last_used_at) < julianday(?))",
+    )
+    .bind(now.to_rfc3339())
+    .bind(&id)
+    .bind((now - Duration::seconds(LAST_USED_RESOLUTION_SECONDS)).to_rfc3339())
+    .execute(pool)
+    .await?;
+
+    match db_users::get_user_by_id(pool, &user_id).await {
+        Ok(user) => Ok(Some(user)),
+        Err(AppError::NotFound { .. }) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
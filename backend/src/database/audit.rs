@@ -0,0 +1,30 @@
+use chrono::Utc;
+use sqlx::SqliteExecutor;
+
+use crate::utils::errors::AppError;
+
+/// Record an admin action in the audit log
+///
+/// Takes any executor so the entry can be written in the same transaction as
+/// the change it describes.
+pub async fn record_admin_action<'e>(
+    executor: impl SqliteExecutor<'e>,
+    admin_id: &str,
+    action: &str,
+    target_id: Option<&str>,
+    details: serde_json::Value,
+) -> Result<(), AppError> {
+    sqlx::query(
+        "INSERT INTO admin_audit_log (admin_id, action, target_id, details, created_at)
+         VALUES (?, ?, ?, ?, ?)",
+    )
+    .bind(admin_id)
+    .bind(action)
+    .bind(target_id)
+    .bind(details.to_string())
+    .bind(Utc::now().to_rfc3339())
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,237 @@
+use chrono::{DateTime, Utc};
+use sqlx::{sqlite::SqliteRow, Row};
+use uuid::Uuid;
+
+use crate::database::DatabasePool;
+use crate::models::PlantId;
+use crate::models::plant::{
+    CareSchedule, CareTask, CareUnit, CreateCareTaskRequest, PlantResponse, UpdateCareTaskRequest,
+};
+use crate::utils::errors::AppError;
+
+const CARE_TASK_COLUMNS: &str =
+    "plant_id, task_type, interval_days, last_done, amount, unit, notes";
+
+/// Missing care task, or one on a plant the caller can't see
+fn care_task_not_found() -> AppError {
+    AppError::NotFound {
+        resource: "Care task".to_string(),
+    }
+}
+
+fn care_task_from_row(row: &SqliteRow) -> CareTask {
+    let last_done: Option<String> = row.get("last_done");
+
+    CareTask {
+        task_type: row.get("task_type"),
+        schedule: CareSchedule {
+            interval_days: row.get("interval_days"),
+            amount: row.get("amount"),
+            unit: row.get("unit"),
+            notes: row.get("notes"),
+        },
+        last_done: last_done
+            .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+            .map(|dt| dt.with_timezone(&Utc)),
+    }
+}
+
+/// Bump the plant's `updated_at` so sync clients pick up its changed care tasks or tags
+pub(crate) async fn touch_plant(pool: &DatabasePool, plant_id: PlantId) -> Result<(), AppError> {
+    sqlx::query("UPDATE plants SET updated_at = ? WHERE id = ?")
+        .bind(Utc::now().to_rfc3339())
+        .bind(plant_id.to_string())
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+pub(crate) async fn ensure_plant_owned(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    user_id: &str,
+) -> Result<(), AppError> {
+    sqlx::query("SELECT 1 FROM plants WHERE id = ? AND user_id = ?")
+        .bind(plant_id.to_string())
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?
+        .map(|_| ())
+        .ok_or_else(AppError::plant_not_found)
+}
+
+/// Fill in each plant's care tasks.
+///
+/// Uses a single query over all given plants to avoid N+1 lookups.
+pub async fn attach_care_tasks(
+    pool: &DatabasePool,
+    plants: &mut [PlantResponse],
+) -> Result<(), AppError> {
+    if plants.is_empty() {
+        return Ok(());
+    }
+
+    let placeholders = vec!["?"; plants.len()].join(", ");
+    let query = format!(
+        "SELECT {CARE_TASK_COLUMNS} FROM care_tasks
+         WHERE plant_id IN ({placeholders})
+         ORDER BY task_type"
+    );
+
+    let mut query_builder = sqlx::query(&query);
+    for plant in plants.iter() {
+        query_builder = query_builder.bind(plant.id.to_string());
+    }
+    let rows = query_builder.fetch_all(pool).await?;
+
+    for row in rows {
+        let plant_id: String = row.get("plant_id");
+        if let Some(plant) = plants.iter_mut().find(|p| p.id.to_string() == plant_id) {
+            plant.care_tasks.push(care_task_from_row(&row));
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn list_care_tasks(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    user_id: &str,
+) -> Result<Vec<CareTask>, AppError> {
+    ensure_plant_owned(pool, plant_id, user_id).await?;
+
+    let rows = sqlx::query(&format!(
+        "SELECT {CARE_TASK_COLUMNS} FROM care_tasks WHERE plant_id = ? ORDER BY task_type"
+    ))
+    .bind(plant_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.iter().map(care_task_from_row).collect())
+}
+
+pub async fn get_care_task(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    user_id: &str,
+    task_type: &str,
+) -> Result<CareTask, AppError> {
+    ensure_plant_owned(pool, plant_id, user_id).await?;
+
+    let row = sqlx::query(&format!(
+        "SELECT {CARE_TASK_COLUMNS} FROM care_tasks WHERE plant_id = ? AND task_type = ?"
+    ))
+    .bind(plant_id.to_string())
+    .bind(task_type)
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(care_task_not_found)?;
+
+    Ok(care_task_from_row(&row))
+}
+
+/// Add a care task to a plant; each task type can only be added once
+pub async fn create_care_task(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    user_id: &str,
+    request: &CreateCareTaskRequest,
+) -> Result<CareTask, AppError> {
+    ensure_plant_owned(pool, plant_id, user_id).await?;
+
+    let now = Utc::now().to_rfc3339();
+    let result = sqlx::query(
+        "INSERT INTO care_tasks (id, plant_id, task_type, interval_days, last_done, amount, unit, notes, created_at, updated_at)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+         ON CONFLICT (plant_id, task_type) DO NOTHING",
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(plant_id.to_string())
+    .bind(&request.task_type)
+    .bind(request.schedule.interval_days)
+    .bind(request.last_done.map(|dt| dt.to_rfc3339()))
+    .bind(request.schedule.amount)
+    .bind(CareUnit::normalize(request.schedule.unit.as_deref()))
+    .bind(&request.schedule.notes)
+    .bind(&now)
+    .bind(&now)
+    .execute(pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::BadRequest {
+            message: format!("Plant already has a {} care task", request.task_type),
+        });
+    }
+    touch_plant(pool, plant_id).await?;
+
+    get_care_task(pool, plant_id, user_id, &request.task_type).await
+}
+
+pub async fn update_care_task(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    user_id: &str,
+    task_type: &str,
+    request: &UpdateCareTaskRequest,
+) -> Result<CareTask, AppError> {
+    let current = get_care_task(pool, plant_id, user_id, task_type).await?;
+
+    let (interval_days, amount, unit, notes) = match &request.schedule {
+        Some(schedule) => (
+            schedule.interval_days,
+            schedule.amount,
+            CareUnit::normalize(schedule.unit.as_deref()),
+            schedule.notes.clone(),
+        ),
+        None => (
+            current.schedule.interval_days,
+            current.schedule.amount,
+            current.schedule.unit,
+            current.schedule.notes,
+        ),
+    };
+    let last_done = request.last_done.unwrap_or(current.last_done);
+
+    sqlx::query(
+        "UPDATE care_tasks SET interval_days = ?, amount = ?, unit = ?, notes = ?, last_done = ?, updated_at = ?
+         WHERE plant_id = ? AND task_type = ?",
+    )
+    .bind(interval_days)
+    .bind(amount)
+    .bind(unit)
+    .bind(notes)
+    .bind(last_done.map(|dt| dt.to_rfc3339()))
+    .bind(Utc::now().to_rfc3339())
+    .bind(plant_id.to_string())
+    .bind(task_type)
+    .execute(pool)
+    .await?;
+    touch_plant(pool, plant_id).await?;
+
+    get_care_task(pool, plant_id, user_id, task_type).await
+}
+
+/// Remove a care task. Entries already logged for it are kept.
+pub async fn delete_care_task(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    user_id: &str,
+    task_type: &str,
+) -> Result<(), AppError> {
+    ensure_plant_owned(pool, plant_id, user_id).await?;
+
+    let result = sqlx::query("DELETE FROM care_tasks WHERE plant_id = ? AND task_type = ?")
+        .bind(plant_id.to_string())
+        .bind(task_type)
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(care_task_not_found());
+    }
+    touch_plant(pool, plant_id).await?;
+
+    Ok(())
+}
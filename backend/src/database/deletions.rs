@@ -0,0 +1,92 @@
+use chrono::{DateTime, Utc};
+use sqlx::{Row, SqliteExecutor};
+use uuid::Uuid;
+
+use crate::database::DatabasePool;
+use crate::models::sync::{DeletedEntityType, Deletion};
+use crate::utils::errors::AppError;
+
+/// Record a tombstone for a deleted record
+pub async fn record_deletion<'e>(
+    executor: impl SqliteExecutor<'e>,
+    entity_type: DeletedEntityType,
+    entity_id: &Uuid,
+    user_id: &str,
+) -> Result<(), AppError> {
+    sqlx::query(
+        "INSERT INTO deletions (entity_type, entity_id, user_id, deleted_at) VALUES (?, ?, ?, ?)",
+    )
+    .bind(entity_type.as_db_str())
+    .bind(entity_id.to_string())
+    .bind(user_id)
+    .bind(Utc::now().to_rfc3339())
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
+
+/// Drop the tombstone for a record that has been restored
+pub async fn clear_deletion<'e>(
+    executor: impl SqliteExecutor<'e>,
+    entity_type: DeletedEntityType,
+    entity_id: &Uuid,
+) -> Result<(), AppError> {
+    sqlx::query("DELETE FROM deletions WHERE entity_type = ? AND entity_id = ?")
+        .bind(entity_type.as_db_str())
+        .bind(entity_id.to_string())
+        .execute(executor)
+        .await?;
+
+    Ok(())
+}
+
+/// List a user's tombstones recorded after `since`
+pub async fn list_deletions_since(
+    pool: &DatabasePool,
+    user_id: &str,
+    since: DateTime<Utc>,
+) -> Result<Vec<Deletion>, AppError> {
+    let rows = sqlx::query(
+        "SELECT entity_type, entity_id, deleted_at
+         FROM deletions
+         WHERE user_id = ? AND julianday(deleted_at) > julianday(?)
+         ORDER BY deleted_at ASC",
+    )
+    .bind(user_id)
+    .bind(since.to_rfc3339())
+    .fetch_all(pool)
+    .await?;
+
+    let deletions = rows
+        .into_iter()
+        .filter_map(|row| {
+            let entity_type: String = row.get("entity_type");
+            let entity_id: String = row.get("entity_id");
+            let deleted_at: String = row.get("deleted_at");
+
+            Some(Deletion {
+                entity_type: DeletedEntityType::from_db_str(&entity_type)?,
+                entity_id: Uuid::parse_str(&entity_id).ok()?,
+                deleted_at: chrono::DateTime::parse_from_rfc3339(&deleted_at)
+                    .ok()?
+                    .with_timezone(&Utc),
+            })
+        })
+        .collect();
+
+    Ok(deletions)
+}
+
+/// Remove tombstones recorded before `cutoff`, returning how many were pruned
+pub async fn prune_deletions_before(
+    pool: &DatabasePool,
+    cutoff: DateTime<Utc>,
+) -> Result<u64, AppError> {
+    let result = sqlx::query("DELETE FROM deletions WHERE julianday(deleted_at) < julianday(?)")
+        .bind(cutoff.to_rfc3339())
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected())
+}
@@ -1,6 +1,7 @@
-use chrono::{DateTime, Utc};
-use sqlx::SqlitePool;
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{SqliteExecutor, SqlitePool};
 
+use crate::database::with_transaction;
 use crate::models::google_oauth::GoogleOAuthToken;
 use crate::utils::errors::{AppError, Result};
 
@@ -149,6 +150,143 @@ pub async fn delete_oauth_token(pool: &SqlitePool, user_id: &str) -> Result<()>
     Ok(())
 }
 
+/// Google account email recorded for a user's connection, if known
+pub async fn get_account_email(pool: &SqlitePool, user_id: &str) -> Result<Option<String>> {
+    let email: Option<Option<String>> =
+        sqlx::query_scalar("SELECT account_email FROM google_oauth_tokens WHERE user_id = ?")
+            .bind(user_id)
+            .fetch_optional(pool)
+            .await?;
+
+    Ok(email.flatten())
+}
+
+/// Record which Google account a user's token belongs to
+///
+/// When it differs from the account recorded before, the synced-task records
+/// refer to another account's tasks and are cleared. Returns whether they were.
+pub async fn record_account_email(pool: &SqlitePool, user_id: &str, email: &str) -> Result<bool> {
+    let user_id = user_id.to_string();
+    let email = email.to_string();
+
+    with_transaction(pool, move |conn| {
+        Box::pin(async move {
+            let previous: Option<Option<String>> = sqlx::query_scalar(
+                "SELECT account_email FROM google_oauth_tokens WHERE user_id = ?",
+            )
+            .bind(&user_id)
+            .fetch_optional(&mut *conn)
+            .await?;
+
+            sqlx::query("UPDATE google_oauth_tokens SET account_email = ? WHERE user_id = ?")
+                .bind(&email)
+                .bind(&user_id)
+                .execute(&mut *conn)
+                .await?;
+
+            let account_changed = matches!(previous, Some(Some(previous)) if previous != email);
+            if account_changed {
+                let cleared = clear_synced_tasks(&mut *conn, &user_id).await?;
+                tracing::info!(
+                    "Google account changed for user {}, cleared {} synced tasks",
+                    user_id,
+                    cleared
+                );
+            }
+
+            Ok(account_changed)
+        })
+    })
+    .await
+}
+
+/// Forget every task synced for a user, returning how many records were removed
+pub async fn clear_synced_tasks<'e>(
+    executor: impl SqliteExecutor<'e>,
+    user_id: &str,
+) -> Result<u64> {
+    let result = sqlx::query("DELETE FROM google_synced_tasks WHERE user_id = ?")
+        .bind(user_id)
+        .execute(executor)
+        .await?;
+
+    Ok(result.rows_affected())
+}
+
+/// The Google task already synced into `task_list_id` for a plant's care on
+/// `due_date`, if any
+pub async fn get_synced_task_id(
+    pool: &SqlitePool,
+    user_id: &str,
+    task_list_id: &str,
+    plant_id: &str,
+    task_type: &str,
+    due_date: NaiveDate,
+) -> Result<Option<String>> {
+    let google_task_id = sqlx::query_scalar(
+        "SELECT google_task_id FROM google_synced_tasks
+         WHERE user_id = ? AND task_list_id = ? AND plant_id = ? AND task_type = ? AND due_date = ?",
+    )
+    .bind(user_id)
+    .bind(task_list_id)
+    .bind(plant_id)
+    .bind(task_type)
+    .bind(due_date.to_string())
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(google_task_id)
+}
+
+/// Move tasks synced before their list was recorded into `task_list_id`
+///
+/// Those tasks all went into the "Plant Care" list, so this is called with
+/// that list once it's resolved. Slots the list already has a task for keep it.
+pub async fn claim_legacy_synced_tasks(
+    pool: &SqlitePool,
+    user_id: &str,
+    task_list_id: &str,
+) -> Result<u64> {
+    let result = sqlx::query(
+        "UPDATE OR IGNORE google_synced_tasks SET task_list_id = ?
+         WHERE user_id = ? AND task_list_id = ''",
+    )
+    .bind(task_list_id)
+    .bind(user_id)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+/// Remember the Google task created in `task_list_id` for a plant's care on `due_date`
+pub async fn record_synced_task(
+    pool: &SqlitePool,
+    user_id: &str,
+    task_list_id: &str,
+    plant_id: &str,
+    task_type: &str,
+    due_date: NaiveDate,
+    google_task_id: &str,
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO google_synced_tasks (user_id, task_list_id, plant_id, task_type, due_date, google_task_id, created_at)
+         VALUES (?, ?, ?, ?, ?, ?, ?)
+         ON CONFLICT (user_id, task_list_id, plant_id, task_type, due_date) DO NOTHING",
+    )
+    .bind(user_id)
+    .bind(task_list_id)
+    .bind(plant_id)
+    .bind(task_type)
+    .bind(due_date.to_string())
+    .bind(google_task_id)
+    .bind(Utc::now().to_rfc3339())
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
 /// Check if a user has a valid (non-expired) Google OAuth token
 #[allow(dead_code)]
 pub async fn has_valid_token(pool: &SqlitePool, user_id: &str) -> Result<bool> {
@@ -186,9 +324,12 @@ pub async fn get_users_with_google_tasks(pool: &SqlitePool) -> Result<Vec<String
     Ok(user_ids)
 }
 
-/// Get all tokens that need refreshing (expire within the next 10 minutes)
-pub async fn get_tokens_needing_refresh(pool: &SqlitePool) -> Result<Vec<GoogleOAuthToken>> {
-    let cutoff_time = Utc::now() + chrono::Duration::minutes(10);
+/// Get all tokens that need refreshing (expire within `margin` from now)
+pub async fn get_tokens_needing_refresh(
+    pool: &SqlitePool,
+    margin: chrono::Duration,
+) -> Result<Vec<GoogleOAuthToken>> {
+    let cutoff_time = Utc::now() + margin;
     
     let rows = sqlx::query!(
         r#"
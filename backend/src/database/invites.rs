@@ -206,4 +206,44 @@ pub async fn update_waitlist_status(
     let entry = entry_row.to_waitlist_entry()?;
 
     Ok(entry)
-}
\ No newline at end of file
+}
+/// Pending waitlist entries no admin has emailed yet, oldest first
+pub async fn get_unnotified_waitlist_entries(pool: &DatabasePool) -> Result<Vec<WaitlistEntry>> {
+    let entry_rows = sqlx::query_as::<_, WaitlistEntryRow>(
+        "SELECT * FROM waitlist WHERE status = 'pending' AND notified_at IS NULL ORDER BY created_at ASC",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(AppError::Database)?;
+
+    entry_rows
+        .into_iter()
+        .map(|row| row.to_waitlist_entry())
+        .collect()
+}
+
+/// Mark an entry notified before its email is sent, returning `false` if it
+/// already was, so concurrent or repeated runs never email it twice
+pub async fn claim_waitlist_notification(pool: &DatabasePool, id: &str) -> Result<bool> {
+    let now_str = Utc::now().to_rfc3339();
+
+    let result = sqlx::query(
+        "UPDATE waitlist SET notified_at = ?, updated_at = ? WHERE id = ? AND notified_at IS NULL",
+    )
+    .bind(&now_str)
+    .bind(&now_str)
+    .bind(id)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() == 1)
+}
+
+/// Undo a claim whose email failed, so the next run retries it
+pub async fn release_waitlist_notification(pool: &DatabasePool, id: &str) -> Result<()> {
+    sqlx::query("UPDATE waitlist SET notified_at = NULL WHERE id = ?")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
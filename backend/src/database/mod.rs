@@ -1,9 +1,28 @@
 use anyhow::Result;
-use sqlx::{sqlite::SqlitePool, Pool, Sqlite};
-use std::env;
+use serde::Serialize;
+use sqlx::{
+    pool::PoolConnection,
+    sqlite::{SqliteConnectOptions, SqlitePool},
+    Pool, Sqlite, SqliteConnection,
+};
+use std::{
+    collections::HashSet,
+    env,
+    future::Future,
+    ops::{Deref, DerefMut},
+    pin::Pin,
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::utils::errors::AppError;
 
 pub type DatabasePool = Pool<Sqlite>;
 
+/// Future returned by the closure passed to [`with_transaction`]
+pub type TransactionFuture<'c, T> =
+    Pin<Box<dyn Future<Output = std::result::Result<T, AppError>> + Send + 'c>>;
+
 /// Creates a database connection pool using the default `DATABASE_URL` environment variable.
 ///
 /// # Errors
@@ -34,7 +53,8 @@ pub async fn create_pool() -> Result<DatabasePool> {
 pub async fn create_pool_with_url(database_url: &str) -> Result<DatabasePool> {
     tracing::info!("Connecting to database: {}", database_url);
 
-    let pool = SqlitePool::connect(database_url).await?;
+    let options = SqliteConnectOptions::from_str(database_url)?.busy_timeout(BUSY_TIMEOUT);
+    let pool = SqlitePool::connect_with(options).await?;
 
     tracing::info!("Database connected and ready");
     Ok(pool)
@@ -57,9 +77,227 @@ pub async fn run_migrations(pool: &DatabasePool) -> Result<()> {
     Ok(())
 }
 
+/// Whether the database schema includes every embedded migration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MigrationStatus {
+    UpToDate,
+    Behind,
+}
+
+/// Compares the successfully applied migrations against those embedded in the binary.
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// - The `_sqlx_migrations` table cannot be read
+pub async fn migration_status(pool: &DatabasePool) -> Result<MigrationStatus> {
+    let applied: HashSet<i64> =
+        sqlx::query_scalar("SELECT version FROM _sqlx_migrations WHERE success = 1")
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .collect();
+
+    let up_to_date = sqlx::migrate!("./migrations")
+        .iter()
+        .all(|migration| applied.contains(&migration.version));
+
+    Ok(if up_to_date {
+        MigrationStatus::UpToDate
+    } else {
+        MigrationStatus::Behind
+    })
+}
+
+/// How long a statement waits for another connection's lock before SQLite
+/// reports the database as busy
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Attempts made by [`retry_if_busy`] before giving up on a locked database
+const BUSY_RETRY_ATTEMPTS: u32 = 4;
+
+/// Delay before the first retry, doubled after each further attempt
+const BUSY_RETRY_BACKOFF: Duration = Duration::from_millis(50);
+
+/// Runs `operation`, re-running it with a short backoff while SQLite reports
+/// the database as busy or locked.
+///
+/// Only use for operations that are safe to repeat, e.g. a single write
+/// statement or one that hasn't changed anything when it fails.
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// - `operation` fails with anything other than a busy database
+/// - The database is still busy after the last attempt
+pub async fn retry_if_busy<T, F, Fut>(mut operation: F) -> std::result::Result<T, AppError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = std::result::Result<T, AppError>>,
+{
+    let mut backoff = BUSY_RETRY_BACKOFF;
+    for _ in 1..BUSY_RETRY_ATTEMPTS {
+        match operation().await {
+            Err(e) if e.is_database_busy() => {
+                tracing::debug!("Database busy, retrying in {:?}", backoff);
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+            }
+            result => return result,
+        }
+    }
+    operation().await
+}
+
+/// A transaction begun with `BEGIN IMMEDIATE`, see [`begin_write`]
+///
+/// Dereferences to its connection for running queries. Dropping it without
+/// committing rolls it back.
+pub struct WriteTransaction {
+    connection: Option<PoolConnection<Sqlite>>,
+}
+
+impl WriteTransaction {
+    /// Commits the transaction, rolling it back if the commit fails
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The transaction cannot be committed
+    pub async fn commit(mut self) -> std::result::Result<(), AppError> {
+        let mut connection = self.take_connection();
+        if let Err(e) = sqlx::query("COMMIT").execute(&mut *connection).await {
+            rollback(&mut connection).await;
+            return Err(e.into());
+        }
+        Ok(())
+    }
+
+    /// Rolls the transaction back
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The transaction cannot be rolled back
+    pub async fn rollback(mut self) -> std::result::Result<(), AppError> {
+        let mut connection = self.take_connection();
+        sqlx::query("ROLLBACK").execute(&mut *connection).await?;
+        Ok(())
+    }
+
+    fn take_connection(&mut self) -> PoolConnection<Sqlite> {
+        self.connection
+            .take()
+            .expect("transaction already finished")
+    }
+}
+
+/// Rolls back whatever is open on `connection`, so it goes back to the pool clean
+async fn rollback(connection: &mut SqliteConnection) {
+    if let Err(e) = sqlx::query("ROLLBACK").execute(connection).await {
+        tracing::warn!("Failed to roll back transaction: {}", e);
+    }
+}
+
+impl Deref for WriteTransaction {
+    type Target = SqliteConnection;
+
+    fn deref(&self) -> &Self::Target {
+        self.connection
+            .as_ref()
+            .expect("transaction already finished")
+    }
+}
+
+impl DerefMut for WriteTransaction {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.connection
+            .as_mut()
+            .expect("transaction already finished")
+    }
+}
+
+impl Drop for WriteTransaction {
+    fn drop(&mut self) {
+        // Left by an early return; the connection is released once rolled back
+        if let Some(mut connection) = self.connection.take() {
+            tokio::spawn(async move { rollback(&mut connection).await });
+        }
+    }
+}
+
+/// Begins a transaction that already holds SQLite's write lock.
+///
+/// A plain `BEGIN` only takes the lock at the first write, where a busy
+/// database fails the transaction part-way through. `BEGIN IMMEDIATE` takes
+/// it up front, so the wait (and any retry) happens before anything has run.
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// - The transaction cannot be started
+/// - The database is still busy after retrying
+pub async fn begin_write(pool: &DatabasePool) -> std::result::Result<WriteTransaction, AppError> {
+    retry_if_busy(|| async {
+        let mut connection = pool.acquire().await?;
+        sqlx::query("BEGIN IMMEDIATE")
+            .execute(&mut *connection)
+            .await?;
+        Ok(WriteTransaction {
+            connection: Some(connection),
+        })
+    })
+    .await
+}
+
+/// Runs `operation` inside a transaction, committing if it succeeds and rolling back if it fails.
+///
+/// All queries in `operation` must go through the provided connection; the
+/// closure should own whatever it captures.
+///
+/// # Errors
+///
+/// This function will return an error if:
+/// - The transaction cannot be started, committed or rolled back
+/// - The database is still busy after retrying (see [`begin_write`])
+/// - `operation` returns an error
+pub async fn with_transaction<T, F>(
+    pool: &DatabasePool,
+    operation: F,
+) -> std::result::Result<T, AppError>
+where
+    F: for<'c> FnOnce(&'c mut SqliteConnection) -> TransactionFuture<'c, T>,
+{
+    let mut tx = begin_write(pool).await?;
+
+    match operation(&mut tx).await {
+        Ok(value) => {
+            tx.commit().await?;
+            Ok(value)
+        }
+        Err(e) => {
+            tx.rollback().await?;
+            Err(e)
+        }
+    }
+}
+
+pub mod admin_settings;
+pub mod api_keys;
+pub mod audit;
+pub mod care_tasks;
+pub mod deletions;
 pub mod google_oauth;
 pub mod invites;
 pub mod photos;
 pub mod plants;
+pub mod public_links;
+pub mod recommendations;
+pub mod sessions;
+pub mod sync;
+pub mod tags;
+pub mod timeline;
 pub mod tracking;
 pub mod users;
+pub mod webhooks;
@@ -1,17 +1,23 @@
 use chrono::Utc;
-use sqlx::Row;
+use sqlx::{sqlite::SqliteRow, Row};
 use uuid::Uuid;
 
-use crate::database::DatabasePool;
-use crate::models::{Photo, PhotosResponse, UploadPhotoRequest};
+use crate::database::{deletions, with_transaction, DatabasePool};
+use crate::models::sync::DeletedEntityType;
+use crate::models::{Photo, PhotoId, PhotosResponse, PlantId, UploadPhotoRequest};
 use crate::utils::errors::AppError;
-use crate::utils::image_processing::process_uploaded_image;
+use crate::utils::image_processing::{
+    can_decode, generate_thumbnail, process_uploaded_image_with_mode, transcode_image,
+    ImageSettings, ImageTooLarge, InvalidImage, OversizeMode, ServeFormat,
+};
+use crate::utils::image_workers::{ImageWorkers, ImageWorkersBusy};
+use crate::utils::photo_store::{delete_images, PhotoObject, PhotoStore, StoredObject};
 
 /// Get all photos for a specific plant
 #[allow(dead_code)]
 pub async fn get_photos_for_plant(
     pool: &DatabasePool,
-    plant_id: &Uuid,
+    plant_id: PlantId,
     user_id: &str,
 ) -> Result<PhotosResponse, AppError> {
     get_photos_for_plant_paginated(pool, plant_id, user_id, None, None, None).await
@@ -20,7 +26,7 @@ pub async fn get_photos_for_plant(
 /// Get photos for a specific plant with pagination
 pub async fn get_photos_for_plant_paginated(
     pool: &DatabasePool,
-    plant_id: &Uuid,
+    plant_id: PlantId,
     user_id: &str,
     limit: Option<i64>,
     offset: Option<i64>,
@@ -34,9 +40,7 @@ pub async fn get_photos_for_plant_paginated(
         .await?;
 
     if plant_exists.is_none() {
-        return Err(AppError::NotFound {
-            resource: format!("Plant with id {plant_id}"),
-        });
+        return Err(AppError::plant_not_found());
     }
 
     // Set default values
@@ -60,7 +64,7 @@ pub async fn get_photos_for_plant_paginated(
 
     // Get photos (without data to save memory for listings) with pagination
     let query = format!(
-        "SELECT id, plant_id, filename, original_filename, size, content_type, width, height, created_at 
+        "SELECT id, plant_id, filename, original_filename, size, content_type, width, height, caption, created_at 
          FROM photos 
          WHERE plant_id = ? 
          {} 
@@ -75,37 +79,58 @@ pub async fn get_photos_for_plant_paginated(
         .fetch_all(pool)
         .await?;
 
-    let photos: Vec<Photo> = photos_rows
-        .into_iter()
-        .map(|row| {
-            let id_str: String = row.get("id");
-            let plant_id_str: String = row.get("plant_id");
-            let created_at_str: String = row.get("created_at");
-
-            Photo {
-                id: Uuid::parse_str(&id_str).expect("Invalid UUID"),
-                plant_id: Uuid::parse_str(&plant_id_str).expect("Invalid UUID"),
-                filename: row.get("filename"),
-                original_filename: row.get("original_filename"),
-                size: row.get("size"),
-                content_type: row.get("content_type"),
-                width: row.get("width"),
-                height: row.get("height"),
-                created_at: chrono::DateTime::parse_from_rfc3339(&created_at_str)
-                    .expect("Invalid timestamp")
-                    .with_timezone(&Utc),
-            }
-        })
-        .collect();
+    let photos: Vec<Photo> = photos_rows.iter().map(photo_from_row).collect();
 
-    Ok(PhotosResponse { photos, total })
+    Ok(PhotosResponse {
+        photos,
+        total,
+        limit,
+        offset,
+        next: None,
+        prev: None,
+    })
+}
+
+/// Get a single photo's metadata without loading its image data
+pub async fn get_photo_metadata(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    photo_id: &PhotoId,
+    user_id: &str,
+) -> Result<Photo, AppError> {
+    // First verify the plant exists and belongs to the user
+    let plant_exists = sqlx::query("SELECT 1 FROM plants WHERE id = ? AND user_id = ?")
+        .bind(plant_id.to_string())
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?;
+
+    if plant_exists.is_none() {
+        return Err(AppError::plant_not_found());
+    }
+
+    let photo_row = sqlx::query(
+        "SELECT id, plant_id, filename, original_filename, size, content_type, width, height, caption, created_at 
+         FROM photos 
+         WHERE id = ? AND plant_id = ?",
+    )
+    .bind(photo_id.to_string())
+    .bind(plant_id.to_string())
+    .fetch_optional(pool)
+    .await?;
+
+    photo_row
+        .as_ref()
+        .map(photo_from_row)
+        .ok_or_else(AppError::photo_not_found)
 }
 
 /// Get a single photo with its data for serving
 pub async fn get_photo_data(
     pool: &DatabasePool,
-    plant_id: &Uuid,
-    photo_id: &Uuid,
+    store: &dyn PhotoStore,
+    plant_id: PlantId,
+    photo_id: &PhotoId,
     user_id: &str,
 ) -> Result<(Vec<u8>, String), AppError> {
     // First verify the plant exists and belongs to the user
@@ -116,37 +141,231 @@ pub async fn get_photo_data(
         .await?;
 
     if plant_exists.is_none() {
-        return Err(AppError::NotFound {
-            resource: format!("Plant with id {plant_id}"),
-        });
+        return Err(AppError::plant_not_found());
     }
 
-    // Get photo data
-    let photo_row =
-        sqlx::query("SELECT data, content_type FROM photos WHERE id = ? AND plant_id = ?")
+    let content_type: String =
+        sqlx::query_scalar("SELECT content_type FROM photos WHERE id = ? AND plant_id = ?")
             .bind(photo_id.to_string())
             .bind(plant_id.to_string())
             .fetch_optional(pool)
+            .await?
+            .ok_or_else(AppError::photo_not_found)?;
+
+    // Get photo data
+    match store.get(photo_id, PhotoObject::Image).await? {
+        Some(image) => Ok((image.data, content_type)),
+        None => {
+            tracing::warn!("Photo {} has no image in {} storage", photo_id, store.name());
+            Err(AppError::photo_not_found())
+        }
+    }
+}
+
+/// Get a photo's data in the requested format, transcoding and caching on first use.
+pub async fn get_photo_data_in_format(
+    pool: &DatabasePool,
+    store: &dyn PhotoStore,
+    plant_id: PlantId,
+    photo_id: &PhotoId,
+    user_id: &str,
+    format: ServeFormat,
+) -> Result<(Vec<u8>, String), AppError> {
+    let (data, content_type) = get_photo_data(pool, store, plant_id, photo_id, user_id).await?;
+
+    if format == ServeFormat::Avif || content_type != ServeFormat::Avif.content_type() {
+        return Ok((data, content_type));
+    }
+
+    let object = PhotoObject::Rendition(format);
+    if let Some(rendition) = store.get(photo_id, object).await? {
+        return Ok((rendition.data, rendition.content_type));
+    }
+
+    // Without the avif-decoder feature, transcode the kept original scaled to
+    // the stored image's size instead
+    let (source, bounds) = if can_decode(&content_type) {
+        (data, None)
+    } else {
+        match store.get(photo_id, PhotoObject::Original).await? {
+            Some(original) if can_decode(&original.content_type) => {
+                let photo = get_photo_metadata(pool, plant_id, photo_id, user_id).await?;
+                let bounds = photo.width.zip(photo.height);
+                (original.data, bounds.map(|(width, height)| (width as u32, height as u32)))
+            }
+            _ => {
+                tracing::warn!(
+                    "Serving photo {} as AVIF: it has no original to transcode and \
+                     this build can't decode AVIF (avif-decoder feature)",
+                    photo_id
+                );
+                return Ok((data, content_type));
+            }
+        }
+    };
+
+    let rendition = transcode_image(&source, format, bounds).await.map_err(|e| {
+        tracing::error!(
+            "Failed to transcode photo {} to {}: {:?}",
+            photo_id,
+            format.as_str(),
+            e
+        );
+        AppError::Internal {
+            message: "Failed to convert photo".to_string(),
+        }
+    })?;
+    store
+        .put(photo_id, object, &rendition, format.content_type())
+        .await?;
+
+    Ok((rendition, format.content_type().to_string()))
+}
+
+/// Count photos that don't have a thumbnail yet
+pub async fn count_photos_missing_thumbnail(pool: &DatabasePool) -> Result<i64, AppError> {
+    let count = sqlx::query_scalar("SELECT COUNT(*) FROM photos WHERE thumbnail_data IS NULL")
+        .fetch_one(pool)
+        .await?;
+
+    Ok(count)
+}
+
+/// Ids of photos without a thumbnail, in id order, starting after `after_id`
+pub async fn list_photos_missing_thumbnail(
+    pool: &DatabasePool,
+    after_id: Option<&PhotoId>,
+    limit: i64,
+) -> Result<Vec<PhotoId>, AppError> {
+    let ids: Vec<String> = sqlx::query_scalar(
+        "SELECT id FROM photos
+         WHERE thumbnail_data IS NULL AND (? IS NULL OR id > ?)
+         ORDER BY id
+         LIMIT ?",
+    )
+    .bind(after_id.map(PhotoId::to_string))
+    .bind(after_id.map(PhotoId::to_string))
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(ids.iter().filter_map(|id| id.parse().ok()).collect())
+}
+
+/// A photo's thumbnail, generating it on first request for photos uploaded without one
+pub async fn get_photo_thumbnail(
+    pool: &DatabasePool,
+    store: &dyn PhotoStore,
+    plant_id: PlantId,
+    photo_id: &PhotoId,
+    user_id: &str,
+) -> Result<(Vec<u8>, String), AppError> {
+    // Verifies the plant belongs to the user and the photo to the plant
+    get_photo_metadata(pool, plant_id, photo_id, user_id).await?;
+
+    let thumbnail: Option<Vec<u8>> =
+        sqlx::query_scalar("SELECT thumbnail_data FROM photos WHERE id = ?")
+            .bind(photo_id.to_string())
+            .fetch_one(pool)
             .await?;
+    if let Some(thumbnail) = thumbnail {
+        return Ok((thumbnail, ServeFormat::Avif.content_type().to_string()));
+    }
 
-    match photo_row {
-        Some(row) => {
-            let data: Vec<u8> = row.get("data");
-            let content_type: String = row.get("content_type");
-            Ok((data, content_type))
+    let generated = match thumbnail_source(store, photo_id).await? {
+        Some(source) => generate_thumbnail(&source.data).await,
+        None if store.get(photo_id, PhotoObject::Image).await?.is_none() => {
+            return Err(AppError::photo_not_found());
+        }
+        None => Err(anyhow::anyhow!(
+            "No image this build can decode (AVIF needs the avif-decoder feature)"
+        )),
+    };
+    match generated {
+        Ok(thumbnail) => {
+            save_photo_thumbnail(pool, photo_id, &thumbnail).await?;
+            Ok((thumbnail, ServeFormat::Avif.content_type().to_string()))
+        }
+        Err(e) => {
+            tracing::error!("Failed to generate thumbnail for photo {}: {:?}", photo_id, e);
+            Err(AppError::Internal {
+                message: "Failed to generate thumbnail".to_string(),
+            })
         }
-        None => Err(AppError::NotFound {
-            resource: format!("Photo with id {photo_id}"),
-        }),
+    }
+}
+
+/// The first of a photo's objects this build can decode to generate a thumbnail from
+///
+/// The stored image is AVIF, so without the `avif-decoder` feature thumbnails come
+/// from the kept original or a cached rendition; `None` if the photo has neither.
+pub async fn thumbnail_source(
+    store: &dyn PhotoStore,
+    photo_id: &PhotoId,
+) -> Result<Option<StoredObject>, AppError> {
+    let objects = [
+        PhotoObject::Image,
+        PhotoObject::Original,
+        PhotoObject::Rendition(ServeFormat::Jpeg),
+        PhotoObject::Rendition(ServeFormat::WebP),
+    ];
+    for object in objects {
+        if let Some(stored) = store.get(photo_id, object).await? {
+            if can_decode(&stored.content_type) {
+                return Ok(Some(stored));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Store a generated thumbnail for a photo
+pub async fn save_photo_thumbnail(
+    pool: &DatabasePool,
+    photo_id: &PhotoId,
+    data: &[u8],
+) -> Result<(), AppError> {
+    sqlx::query("UPDATE photos SET thumbnail_data = ? WHERE id = ?")
+        .bind(data)
+        .bind(photo_id.to_string())
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Map a photo row to a `Photo`
+pub(crate) fn photo_from_row(row: &SqliteRow) -> Photo {
+    let id_str: String = row.get("id");
+    let plant_id_str: String = row.get("plant_id");
+    let created_at_str: String = row.get("created_at");
+
+    Photo {
+        id: Uuid::parse_str(&id_str).expect("Invalid UUID"),
+        plant_id: Uuid::parse_str(&plant_id_str).expect("Invalid UUID"),
+        filename: row.get("filename"),
+        original_filename: row.get("original_filename"),
+        size: row.get("size"),
+        content_type: row.get("content_type"),
+        width: row.get("width"),
+        height: row.get("height"),
+        caption: row.get("caption"),
+        created_at: chrono::DateTime::parse_from_rfc3339(&created_at_str)
+            .expect("Invalid timestamp")
+            .with_timezone(&Utc),
     }
 }
 
 /// Upload a new photo for a plant
 pub async fn create_photo(
     pool: &DatabasePool,
-    plant_id: &Uuid,
+    store: &dyn PhotoStore,
+    workers: &ImageWorkers,
+    plant_id: PlantId,
     user_id: &str,
     request: &UploadPhotoRequest,
+    settings: ImageSettings,
 ) -> Result<Photo, AppError> {
     // First verify the plant exists and belongs to the user
     let plant_exists = sqlx::query("SELECT 1 FROM plants WHERE id = ? AND user_id = ?")
@@ -156,29 +375,50 @@ pub async fn create_photo(
         .await?;
 
     if plant_exists.is_none() {
-        return Err(AppError::NotFound {
-            resource: format!("Plant with id {plant_id}"),
-        });
+        return Err(AppError::plant_not_found());
     }
 
-    let photo_id = Uuid::new_v4();
+    let photo_id = PhotoId(Uuid::new_v4());
     let now = Utc::now();
 
-    // Process the uploaded image to AVIF with 4K cropping
-    let processed_image = process_uploaded_image(&request.data, &request.content_type)
-        .await
-        .map_err(|e| {
-            tracing::error!("Failed to process uploaded image: {:?}", e);
-            AppError::Validation(validator::ValidationErrors::new())
-        })?;
+    // Process the uploaded image to AVIF, downscaled to the maximum dimension
+    let processed_image = process_uploaded_image_with_mode(
+        workers,
+        &request.data,
+        &request.content_type,
+        settings.oversize_mode,
+        settings.max_dimension,
+    )
+    .await
+    .map_err(image_processing_error)?;
+
+    let caption = normalize_caption(request.caption.as_deref());
 
     // Generate unique filename with AVIF extension
     let filename = format!("{}_{}.avif", plant_id, photo_id);
 
-    // Store processed AVIF image data in database
-    sqlx::query(
-        "INSERT INTO photos (id, plant_id, filename, original_filename, size, content_type, data, width, height, created_at)
-         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+    // Store the images first, so the row never points at an image that isn't there
+    store
+        .put(
+            &photo_id,
+            PhotoObject::Image,
+            &processed_image.data,
+            &processed_image.content_type,
+        )
+        .await?;
+    if settings.keep_originals {
+        if let Err(e) = store
+            .put(&photo_id, PhotoObject::Original, &request.data, &request.content_type)
+            .await
+        {
+            delete_images(store, &[photo_id]).await;
+            return Err(e);
+        }
+    }
+
+    let inserted = sqlx::query(
+        "INSERT INTO photos (id, plant_id, filename, original_filename, size, content_type, thumbnail_data, width, height, caption, created_at, updated_at)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
     )
     .bind(photo_id.to_string())
     .bind(plant_id.to_string())
@@ -186,12 +426,18 @@ pub async fn create_photo(
     .bind(&request.original_filename)
     .bind(processed_image.data.len() as i64) // Use processed image size
     .bind(&processed_image.content_type) // Always "image/avif"
-    .bind(&processed_image.data)
+    .bind(&processed_image.thumbnail)
     .bind(processed_image.width as i32)
     .bind(processed_image.height as i32)
+    .bind(&caption)
+    .bind(now.to_rfc3339())
     .bind(now.to_rfc3339())
     .execute(pool)
-    .await?;
+    .await;
+    if let Err(e) = inserted {
+        delete_images(store, &[photo_id]).await;
+        return Err(e.into());
+    }
 
     tracing::info!(
         "Successfully processed and stored image: {} bytes -> {} bytes AVIF ({}x{})",
@@ -202,23 +448,169 @@ pub async fn create_photo(
     );
 
     Ok(Photo {
-        id: photo_id,
-        plant_id: *plant_id,
+        id: photo_id.0,
+        plant_id: plant_id.0,
         filename,
         original_filename: request.original_filename.clone(),
         size: processed_image.data.len() as i64,
         content_type: processed_image.content_type,
         width: Some(processed_image.width as i32),
         height: Some(processed_image.height as i32),
+        caption,
         created_at: now,
     })
 }
 
+/// Map an image processing failure to the error reported for the upload
+fn image_processing_error(e: anyhow::Error) -> AppError {
+    if let Some(busy) = e.downcast_ref::<ImageWorkersBusy>() {
+        tracing::warn!("Turned away uploaded image: {}", busy);
+        return AppError::Busy {
+            message: "Too many photos are being processed, please try again shortly".to_string(),
+        };
+    }
+
+    let (code, message) = if let Some(too_large) = e.downcast_ref::<ImageTooLarge>() {
+        ("too_large", too_large.to_string())
+    } else if let Some(invalid) = e.downcast_ref::<InvalidImage>() {
+        (invalid.code(), invalid.to_string())
+    } else {
+        tracing::error!("Failed to process uploaded image: {:?}", e);
+        return AppError::Internal {
+            message: "Failed to process image".to_string(),
+        };
+    };
+
+    tracing::warn!("Rejected uploaded image: {}", message);
+    let mut error = validator::ValidationError::new(code);
+    error.message = Some(message.into());
+    let mut errors = validator::ValidationErrors::new();
+    errors.add("file", error);
+    AppError::Validation(errors)
+}
+
+/// Process a photo's original upload again with the current image settings
+///
+/// Only photos uploaded while originals were kept can be reprocessed. The
+/// original is always downscaled to fit, since rejecting a photo that is
+/// already stored would leave it unchanged. The thumbnail is regenerated along
+/// with it, and cached renditions are dropped to be transcoded again on request.
+pub async fn reprocess_photo(
+    pool: &DatabasePool,
+    store: &dyn PhotoStore,
+    workers: &ImageWorkers,
+    plant_id: PlantId,
+    photo_id: &PhotoId,
+    user_id: &str,
+    settings: ImageSettings,
+) -> Result<Photo, AppError> {
+    // Verifies the plant belongs to the user and the photo to the plant
+    get_photo_metadata(pool, plant_id, photo_id, user_id).await?;
+
+    let original = store
+        .get(photo_id, PhotoObject::Original)
+        .await?
+        .ok_or_else(|| AppError::BadRequest {
+            message: "The original upload of this photo wasn't kept, so it can't be reprocessed. \
+                      Originals are only kept for photos uploaded with KEEP_PHOTO_ORIGINALS=true"
+                .to_string(),
+        })?;
+
+    let processed_image = process_uploaded_image_with_mode(
+        workers,
+        &original.data,
+        &original.content_type,
+        OversizeMode::Downscale,
+        settings.max_dimension,
+    )
+    .await
+    .map_err(image_processing_error)?;
+
+    store
+        .put(
+            photo_id,
+            PhotoObject::Image,
+            &processed_image.data,
+            &processed_image.content_type,
+        )
+        .await?;
+
+    sqlx::query(
+        "UPDATE photos SET size = ?, width = ?, height = ?, thumbnail_data = ?, updated_at = ? WHERE id = ?",
+    )
+    .bind(processed_image.data.len() as i64)
+    .bind(processed_image.width as i32)
+    .bind(processed_image.height as i32)
+    .bind(&processed_image.thumbnail)
+    .bind(Utc::now().to_rfc3339())
+    .bind(photo_id.to_string())
+    .execute(pool)
+    .await?;
+
+    for rendition in PhotoObject::RENDITIONS {
+        store.delete(photo_id, rendition).await?;
+    }
+
+    tracing::info!(
+        "Reprocessed photo {}: {} bytes AVIF ({}x{})",
+        photo_id,
+        processed_image.data.len(),
+        processed_image.width,
+        processed_image.height
+    );
+
+    get_photo_metadata(pool, plant_id, photo_id, user_id).await
+}
+
+/// Update a photo's caption
+pub async fn update_photo_caption(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    photo_id: &PhotoId,
+    user_id: &str,
+    caption: Option<&str>,
+) -> Result<Photo, AppError> {
+    // First verify the plant exists and belongs to the user
+    let plant_exists = sqlx::query("SELECT 1 FROM plants WHERE id = ? AND user_id = ?")
+        .bind(plant_id.to_string())
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?;
+
+    if plant_exists.is_none() {
+        return Err(AppError::plant_not_found());
+    }
+
+    let result =
+        sqlx::query("UPDATE photos SET caption = ?, updated_at = ? WHERE id = ? AND plant_id = ?")
+            .bind(normalize_caption(caption))
+            .bind(Utc::now().to_rfc3339())
+            .bind(photo_id.to_string())
+            .bind(plant_id.to_string())
+            .execute(pool)
+            .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::photo_not_found());
+    }
+
+    get_photo_metadata(pool, plant_id, photo_id, user_id).await
+}
+
+/// Trim a caption, treating blank captions as absent
+fn normalize_caption(caption: Option<&str>) -> Option<String> {
+    caption
+        .map(str::trim)
+        .filter(|caption| !caption.is_empty())
+        .map(str::to_string)
+}
+
 /// Delete a photo
 pub async fn delete_photo(
     pool: &DatabasePool,
-    plant_id: &Uuid,
-    photo_id: &Uuid,
+    store: &dyn PhotoStore,
+    plant_id: PlantId,
+    photo_id: &PhotoId,
     user_id: &str,
 ) -> Result<(), AppError> {
     // First verify the plant exists and belongs to the user
@@ -229,9 +621,7 @@ pub async fn delete_photo(
         .await?;
 
     if plant_exists.is_none() {
-        return Err(AppError::NotFound {
-            resource: format!("Plant with id {plant_id}"),
-        });
+        return Err(AppError::plant_not_found());
     }
 
     // Verify photo exists before deletion
@@ -242,33 +632,71 @@ pub async fn delete_photo(
         .await?;
 
     if photo_row.is_none() {
-        return Err(AppError::NotFound {
-            resource: format!("Photo with id {photo_id}"),
-        });
+        return Err(AppError::photo_not_found());
     }
 
-    // Photo data will be automatically deleted with the record
+    // Delete the photo record and leave a tombstone for syncing clients together
+    let photo_uuid = photo_id.0;
+    let photo_id_str = photo_id.to_string();
+    let plant_id_str = plant_id.to_string();
+    let user_id = user_id.to_string();
+    with_transaction(pool, move |conn| {
+        Box::pin(async move {
+            let result = sqlx::query("DELETE FROM photos WHERE id = ? AND plant_id = ?")
+                .bind(&photo_id_str)
+                .bind(&plant_id_str)
+                .execute(&mut *conn)
+                .await?;
+
+            if result.rows_affected() == 0 {
+                return Err(AppError::photo_not_found());
+            }
 
-    // Delete photo record
-    let result = sqlx::query("DELETE FROM photos WHERE id = ? AND plant_id = ?")
-        .bind(photo_id.to_string())
+            deletions::record_deletion(&mut *conn, DeletedEntityType::Photo, &photo_uuid, &user_id)
+                .await
+        })
+    })
+    .await?;
+
+    delete_images(store, &[*photo_id]).await;
+
+    Ok(())
+}
+
+/// IDs of a plant's photos, to clean up their images once the plant is deleted
+pub async fn list_photo_ids_for_plant(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+) -> Result<Vec<PhotoId>, AppError> {
+    let ids: Vec<String> = sqlx::query_scalar("SELECT id FROM photos WHERE plant_id = ?")
         .bind(plant_id.to_string())
-        .execute(pool)
+        .fetch_all(pool)
         .await?;
 
-    if result.rows_affected() == 0 {
-        return Err(AppError::NotFound {
-            resource: format!("Photo with id {photo_id}"),
-        });
-    }
+    Ok(ids.iter().filter_map(|id| id.parse().ok()).collect())
+}
 
-    Ok(())
+/// IDs of the photos on all of a user's plants, to clean up their images once
+/// the user is deleted
+pub async fn list_photo_ids_for_user(
+    pool: &DatabasePool,
+    user_id: &str,
+) -> Result<Vec<PhotoId>, AppError> {
+    let ids: Vec<String> = sqlx::query_scalar(
+        "SELECT ph.id FROM photos ph JOIN plants p ON p.id = ph.plant_id WHERE p.user_id = ?",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(ids.iter().filter_map(|id| id.parse().ok()).collect())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::database::create_pool_with_url;
+    use crate::utils::photo_store::DatabasePhotoStore;
 
     async fn setup_test_db() -> DatabasePool {
         let pool = create_pool_with_url("sqlite::memory:")
@@ -282,9 +710,9 @@ mod tests {
         pool
     }
 
-    async fn create_test_user_and_plant(pool: &DatabasePool) -> (String, Uuid) {
+    async fn create_test_user_and_plant(pool: &DatabasePool) -> (String, PlantId) {
         let user_id = Uuid::new_v4().to_string();
-        let plant_id = Uuid::new_v4();
+        let plant_id = PlantId(Uuid::new_v4());
         let now = Utc::now().to_rfc3339();
 
         // Create user
@@ -327,9 +755,9 @@ mod tests {
     async fn test_get_photos_for_nonexistent_plant() {
         let pool = setup_test_db().await;
         let user_id = Uuid::new_v4().to_string();
-        let plant_id = Uuid::new_v4();
+        let plant_id = PlantId(Uuid::new_v4());
 
-        let result = get_photos_for_plant(&pool, &plant_id, &user_id).await;
+        let result = get_photos_for_plant(&pool, plant_id, &user_id).await;
         assert!(matches!(result, Err(AppError::NotFound { .. })));
     }
 
@@ -338,7 +766,7 @@ mod tests {
         let pool = setup_test_db().await;
         let (user_id, plant_id) = create_test_user_and_plant(&pool).await;
 
-        let result = get_photos_for_plant(&pool, &plant_id, &user_id).await;
+        let result = get_photos_for_plant(&pool, plant_id, &user_id).await;
         assert!(result.is_ok());
 
         let response = result.unwrap();
@@ -349,6 +777,8 @@ mod tests {
     #[tokio::test]
     async fn test_create_photo() {
         let pool = setup_test_db().await;
+        let store = DatabasePhotoStore::new(pool.clone());
+        let workers = ImageWorkers::default();
         let (user_id, plant_id) = create_test_user_and_plant(&pool).await;
 
         // Create a valid 1x1 pixel JPEG using the image crate
@@ -368,13 +798,16 @@ mod tests {
             size: jpeg_data.len() as i64,
             content_type: "image/jpeg".to_string(),
             data: jpeg_data,
+            caption: None,
         };
 
-        let result = create_photo(&pool, &plant_id, &user_id, &request).await;
+        let result =
+
+            create_photo(&pool, &store, &workers, plant_id, &user_id, &request, ImageSettings::default()).await;
         assert!(result.is_ok());
 
         let photo = result.unwrap();
-        assert_eq!(photo.plant_id, plant_id);
+        assert_eq!(photo.plant_id, plant_id.0);
         assert_eq!(photo.original_filename, "test.jpg");
         assert_eq!(photo.content_type, "image/avif"); // Should be converted to AVIF
         assert!(photo.size > 0); // Size will be different after AVIF conversion
@@ -383,26 +816,147 @@ mod tests {
         assert!(photo.filename.contains(&plant_id.to_string()));
     }
 
+    #[tokio::test]
+    async fn test_create_photo_when_image_workers_are_saturated() {
+        let pool = setup_test_db().await;
+        let store = DatabasePhotoStore::new(pool.clone());
+        let (user_id, plant_id) = create_test_user_and_plant(&pool).await;
+
+        // One worker, busy until released, and no room to queue
+        let workers = ImageWorkers::new(1, 0);
+        let (release, released) = std::sync::mpsc::channel::<()>();
+        let running = tokio::spawn({
+            let workers = workers.clone();
+            async move { workers.run(move || released.recv().unwrap()).await }
+        });
+        while workers.running() == 0 {
+            tokio::task::yield_now().await;
+        }
+
+        let request = UploadPhotoRequest {
+            original_filename: "test.png".to_string(),
+            size: 4,
+            content_type: "image/png".to_string(),
+            data: vec![1, 2, 3, 4],
+            caption: None,
+        };
+        let result = create_photo(
+            &pool,
+            &store,
+            &workers,
+            plant_id,
+            &user_id,
+            &request,
+            ImageSettings::default(),
+        )
+        .await;
+        assert!(matches!(result, Err(AppError::Busy { .. })));
+
+        let photos = get_photos_for_plant(&pool, plant_id, &user_id).await.unwrap();
+        assert!(photos.photos.is_empty());
+
+        release.send(()).unwrap();
+        running.await.unwrap().unwrap();
+    }
+
+    /// A store whose bucket can't be reached
+    struct UnavailableStore;
+
+    #[async_trait::async_trait]
+    impl PhotoStore for UnavailableStore {
+        fn name(&self) -> &'static str {
+            "unavailable"
+        }
+
+        async fn put(
+            &self,
+            _photo_id: &PhotoId,
+            _object: PhotoObject,
+            _data: &[u8],
+            _content_type: &str,
+        ) -> Result<(), AppError> {
+            Err(AppError::Internal {
+                message: "Photo storage is unavailable".to_string(),
+            })
+        }
+
+        async fn get(
+            &self,
+            _photo_id: &PhotoId,
+            _object: PhotoObject,
+        ) -> Result<Option<crate::utils::photo_store::StoredObject>, AppError> {
+            Ok(None)
+        }
+
+        async fn delete(&self, _photo_id: &PhotoId, _object: PhotoObject) -> Result<(), AppError> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_photo_leaves_no_row_when_the_store_fails() {
+        let pool = setup_test_db().await;
+        let workers = ImageWorkers::default();
+        let (user_id, plant_id) = create_test_user_and_plant(&pool).await;
+
+        use image::{DynamicImage, ImageOutputFormat};
+        use std::io::Cursor;
+
+        let img = DynamicImage::new_rgb8(4, 4);
+        let mut png_data = Vec::new();
+        img.write_to(&mut Cursor::new(&mut png_data), ImageOutputFormat::Png)
+            .unwrap();
+        let request = UploadPhotoRequest {
+            original_filename: "test.png".to_string(),
+            size: png_data.len() as i64,
+            content_type: "image/png".to_string(),
+            data: png_data,
+            caption: None,
+        };
+
+        let result = create_photo(
+            &pool,
+            &UnavailableStore,
+            &workers,
+            plant_id,
+            &user_id,
+            &request,
+            ImageSettings::default(),
+        )
+        .await;
+        assert!(matches!(result, Err(AppError::Internal { .. })));
+
+        let photos = get_photos_for_plant(&pool, plant_id, &user_id).await.unwrap();
+        assert!(photos.photos.is_empty());
+    }
+
     #[tokio::test]
     async fn test_create_photo_for_nonexistent_plant() {
         let pool = setup_test_db().await;
+        let store = DatabasePhotoStore::new(pool.clone());
+        let workers = ImageWorkers::default();
         let user_id = Uuid::new_v4().to_string();
-        let plant_id = Uuid::new_v4();
+        let plant_id = PlantId(Uuid::new_v4());
 
         let request = UploadPhotoRequest {
             original_filename: "test.jpg".to_string(),
             size: 1024,
             content_type: "image/jpeg".to_string(),
             data: vec![1, 2, 3, 4],
+            caption: None,
         };
 
-        let result = create_photo(&pool, &plant_id, &user_id, &request).await;
+        let result =
+
+            create_photo(&pool, &store, &workers, plant_id, &user_id, &request, ImageSettings::default()).await;
         assert!(matches!(result, Err(AppError::NotFound { .. })));
     }
 
     #[tokio::test]
     async fn test_delete_photo() {
         let pool = setup_test_db().await;
+        let store = DatabasePhotoStore::new(pool.clone());
+        let workers = ImageWorkers::default();
         let (user_id, plant_id) = create_test_user_and_plant(&pool).await;
 
         // Create a valid JPEG image
@@ -423,18 +977,19 @@ mod tests {
             size: jpeg_data.len() as i64,
             content_type: "image/jpeg".to_string(),
             data: jpeg_data,
+            caption: None,
         };
 
-        let photo = create_photo(&pool, &plant_id, &user_id, &request)
+        let photo = create_photo(&pool, &store, &workers, plant_id, &user_id, &request, ImageSettings::default())
             .await
             .expect("Failed to create photo");
 
         // Delete photo
-        let result = delete_photo(&pool, &plant_id, &photo.id, &user_id).await;
+        let result = delete_photo(&pool, &store, plant_id, &PhotoId(photo.id), &user_id).await;
         assert!(result.is_ok());
 
         // Verify photo is deleted
-        let photos = get_photos_for_plant(&pool, &plant_id, &user_id)
+        let photos = get_photos_for_plant(&pool, plant_id, &user_id)
             .await
             .expect("Failed to get photos");
         assert_eq!(photos.photos.len(), 0);
@@ -443,16 +998,19 @@ mod tests {
     #[tokio::test]
     async fn test_delete_nonexistent_photo() {
         let pool = setup_test_db().await;
+        let store = DatabasePhotoStore::new(pool.clone());
         let (user_id, plant_id) = create_test_user_and_plant(&pool).await;
-        let photo_id = Uuid::new_v4();
+        let photo_id = PhotoId(Uuid::new_v4());
 
-        let result = delete_photo(&pool, &plant_id, &photo_id, &user_id).await;
+        let result = delete_photo(&pool, &store, plant_id, &photo_id, &user_id).await;
         assert!(matches!(result, Err(AppError::NotFound { .. })));
     }
 
     #[tokio::test]
     async fn test_get_photo_data() {
         let pool = setup_test_db().await;
+        let store = DatabasePhotoStore::new(pool.clone());
+        let workers = ImageWorkers::default();
         let (user_id, plant_id) = create_test_user_and_plant(&pool).await;
 
         // Create a valid JPEG image
@@ -473,14 +1031,15 @@ mod tests {
             size: jpeg_data.len() as i64,
             content_type: "image/jpeg".to_string(),
             data: jpeg_data,
+            caption: None,
         };
 
-        let photo = create_photo(&pool, &plant_id, &user_id, &request)
+        let photo = create_photo(&pool, &store, &workers, plant_id, &user_id, &request, ImageSettings::default())
             .await
             .expect("Failed to create photo");
 
         // Get photo data
-        let result = get_photo_data(&pool, &plant_id, &photo.id, &user_id).await;
+        let result = get_photo_data(&pool, &store, plant_id, &PhotoId(photo.id), &user_id).await;
         assert!(result.is_ok());
 
         let (data, content_type) = result.unwrap();
@@ -492,10 +1051,107 @@ mod tests {
     #[tokio::test]
     async fn test_get_photo_data_for_nonexistent_photo() {
         let pool = setup_test_db().await;
+        let store = DatabasePhotoStore::new(pool.clone());
         let (user_id, plant_id) = create_test_user_and_plant(&pool).await;
-        let photo_id = Uuid::new_v4();
+        let photo_id = PhotoId(Uuid::new_v4());
 
-        let result = get_photo_data(&pool, &plant_id, &photo_id, &user_id).await;
+        let result = get_photo_data(&pool, &store, plant_id, &photo_id, &user_id).await;
         assert!(matches!(result, Err(AppError::NotFound { .. })));
     }
+
+    #[tokio::test]
+    async fn test_reprocess_photo_at_smaller_max_dimension() {
+        let pool = setup_test_db().await;
+        let store = DatabasePhotoStore::new(pool.clone());
+        let workers = ImageWorkers::default();
+        let (user_id, plant_id) = create_test_user_and_plant(&pool).await;
+
+        use image::{DynamicImage, ImageOutputFormat};
+        use std::io::Cursor;
+
+        let img = DynamicImage::new_rgb8(200, 100);
+        let mut png_data = Vec::new();
+        img.write_to(&mut Cursor::new(&mut png_data), ImageOutputFormat::Png)
+            .unwrap();
+        let request = UploadPhotoRequest {
+            original_filename: "wide.png".to_string(),
+            size: png_data.len() as i64,
+            content_type: "image/png".to_string(),
+            data: png_data,
+            caption: None,
+        };
+
+        // Without the original there's nothing to reprocess
+        let discarded = create_photo(
+            &pool,
+            &store,
+            &workers,
+            plant_id,
+            &user_id,
+            &request,
+            ImageSettings::default(),
+        )
+        .await
+        .unwrap();
+        let result = reprocess_photo(
+            &pool,
+            &store,
+            &workers,
+            plant_id,
+            &PhotoId(discarded.id),
+            &user_id,
+            ImageSettings::default(),
+        )
+        .await;
+        assert!(matches!(result, Err(AppError::BadRequest { .. })));
+
+        let keep = ImageSettings {
+            keep_originals: true,
+            ..ImageSettings::default()
+        };
+        let photo = create_photo(&pool, &store, &workers, plant_id, &user_id, &request, keep)
+            .await
+            .unwrap();
+        assert_eq!((photo.width, photo.height), (Some(200), Some(100)));
+        let (original_avif, _) = get_photo_data(&pool, &store, plant_id, &PhotoId(photo.id), &user_id)
+            .await
+            .unwrap();
+        let (original_thumbnail, _) = get_photo_thumbnail(&pool, &store, plant_id, &PhotoId(photo.id), &user_id)
+            .await
+            .unwrap();
+
+        let smaller = ImageSettings {
+            max_dimension: 50,
+            ..keep
+        };
+        let reprocessed = reprocess_photo(&pool, &store, &workers, plant_id, &PhotoId(photo.id), &user_id, smaller)
+            .await
+            .unwrap();
+        assert_eq!(reprocessed.id, photo.id);
+        assert_eq!((reprocessed.width, reprocessed.height), (Some(50), Some(25)));
+
+        let (data, content_type) = get_photo_data(&pool, &store, plant_id, &PhotoId(photo.id), &user_id)
+            .await
+            .unwrap();
+        assert_eq!(content_type, "image/avif");
+        assert_eq!(reprocessed.size, data.len() as i64);
+        assert_ne!(data, original_avif);
+
+        let (thumbnail, _) = get_photo_thumbnail(&pool, &store, plant_id, &PhotoId(photo.id), &user_id)
+            .await
+            .unwrap();
+        assert_ne!(thumbnail, original_thumbnail);
+        assert_eq!(&thumbnail[4..8], b"ftyp");
+
+        assert!(store
+            .get(&PhotoId(photo.id), PhotoObject::Rendition(ServeFormat::Jpeg))
+            .await
+            .unwrap()
+            .is_none());
+        let (jpeg, _) = get_photo_data_in_format(&pool, &store, plant_id, &PhotoId(photo.id), &user_id, ServeFormat::Jpeg)
+            .await
+            .unwrap();
+        let jpeg = image::load_from_memory(&jpeg).unwrap();
+        assert_eq!((jpeg.width(), jpeg.height()), (50, 25));
+    }
 }
@@ -1,11 +1,27 @@
 use anyhow::Result;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use sqlx::{FromRow, Row};
 use uuid::Uuid;
 
-use crate::database::DatabasePool;
-use crate::models::{CreatePlantRequest, PlantResponse, UpdatePlantRequest};
+use crate::database::{
+    audit, begin_write, care_tasks, deletions, tags, users as db_users, with_transaction,
+    DatabasePool,
+};
+use crate::models::{PhotoId, PlantId};
+use crate::models::sync::DeletedEntityType;
+use crate::models::{
+    BulkUpdateScheduleRequest, CreateCareScheduleRequest, CreateCustomMetricRequest,
+    CreatePlantRequest, MetricDataType, PlantResponse, UpdateCustomMetricRequest,
+    UpdatePlantRequest,
+};
+use crate::models::plant::{
+    next_anniversary, plant_age_days, validate_custom_metric_count, BoundingBox, CareKind,
+    NearFilter, PlantAnniversary, PlantCareStatus, PlantListFilter, PlantStatus, ScheduleShift,
+    validate_death_details,
+};
 use crate::utils::errors::AppError;
+use crate::utils::pagination::PageCursor;
+use crate::utils::text::normalize_whitespace;
 
 #[derive(Debug, FromRow)]
 pub struct PlantRow {
@@ -13,6 +29,11 @@ pub struct PlantRow {
     pub user_id: String,
     pub name: String,
     pub genus: String,
+    pub description: Option<String>,
+    pub acquired_at: Option<String>,
+    pub location_name: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
     pub watering_interval_days: Option<i32>,
     pub fertilizing_interval_days: Option<i32>,
     pub watering_amount: Option<f64>,
@@ -23,6 +44,12 @@ pub struct PlantRow {
     pub fertilizing_notes: Option<String>,
     pub last_watered: Option<String>,
     pub last_fertilized: Option<String>,
+    pub reminder_lead_hours: Option<u32>,
+    pub quantity: u32,
+    pub archived_at: Option<String>,
+    pub status: PlantStatus,
+    pub died_at: Option<String>,
+    pub death_reason: Option<String>,
     pub preview_id: Option<String>,
     pub created_at: String,
     pub updated_at: String,
@@ -36,12 +63,27 @@ impl PlantRow {
     /// Returns an error if the plant ID in the database is not a valid UUID.
     #[allow(clippy::wrong_self_convention)]
     pub fn to_response(self) -> Result<PlantResponse, AppError> {
+        let acquired_at = self
+            .acquired_at
+            .as_deref()
+            .map(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d"))
+            .transpose()
+            .map_err(|_| AppError::Internal {
+                message: "Invalid date in database".to_string(),
+            })?;
+
         Ok(PlantResponse {
             id: Uuid::parse_str(&self.id).map_err(|_| AppError::Internal {
                 message: "Invalid UUID in database".to_string(),
             })?,
             name: self.name,
             genus: self.genus,
+            description: self.description,
+            acquired_at,
+            age_days: acquired_at.map(|date| plant_age_days(date, Utc::now().date_naive())),
+            location_name: self.location_name,
+            latitude: self.latitude,
+            longitude: self.longitude,
             watering_schedule: crate::models::plant::CareSchedule {
                 interval_days: self.watering_interval_days,
                 amount: self.watering_amount,
@@ -68,6 +110,27 @@ impl PlantRow {
                 .map_err(|_| AppError::Internal {
                     message: "Invalid datetime in database".to_string(),
                 })?,
+            reminder_lead_hours: self.reminder_lead_hours,
+            quantity: self.quantity,
+            archived_at: self
+                .archived_at
+                .map(|s| s.parse::<DateTime<Utc>>())
+                .transpose()
+                .map_err(|_| AppError::Internal {
+                    message: "Invalid datetime in database".to_string(),
+                })?,
+            status: self.status,
+            died_at: self
+                .died_at
+                .map(|s| s.parse::<DateTime<Utc>>())
+                .transpose()
+                .map_err(|_| AppError::Internal {
+                    message: "Invalid datetime in database".to_string(),
+                })?,
+            death_reason: self.death_reason,
+            last_note_at: None,
+            last_photo_at: None,
+            last_measurement_at: None,
             preview_id: self
                 .preview_id
                 .as_ref()
@@ -76,7 +139,14 @@ impl PlantRow {
                 .preview_id
                 .as_ref()
                 .map(|thumb_id| format!("/api/v1/plants/{}/photos/{}", self.id, thumb_id)),
+            thumbnail_url: self.preview_id.as_ref().map(|thumb_id| {
+                format!("/api/v1/plants/{}/photos/{}/thumbnail", self.id, thumb_id)
+            }),
+            photo_count: 0,
+            latest_photo_id: None,
             custom_metrics: vec![], // TODO: Load custom metrics
+            care_tasks: vec![],
+            tags: vec![],
             created_at: self.created_at.parse::<DateTime<Utc>>().map_err(|_| {
                 AppError::Internal {
                     message: "Invalid datetime in database".to_string(),
@@ -92,6 +162,14 @@ impl PlantRow {
     }
 }
 
+/// Trim optional free text, treating a blank value as unset
+fn normalize_optional_text(description: Option<&str>) -> Option<String> {
+    description
+        .map(str::trim)
+        .filter(|description| !description.is_empty())
+        .map(str::to_string)
+}
+
 /// Creates a new plant in the database for a specific user.
 ///
 /// # Arguments
@@ -126,55 +204,227 @@ pub async fn create_plant(
     let fertilizing_notes = request.fertilizing_notes();
     let last_watered = request.last_watered.map(|dt| dt.to_rfc3339());
     let last_fertilized = request.last_fertilized.map(|dt| dt.to_rfc3339());
+    let name = normalize_whitespace(&request.name);
+    let genus = normalize_whitespace(&request.genus);
+    let description = normalize_optional_text(request.description.as_deref());
+    let acquired_at = request.acquired_at.map(|date| date.to_string());
+    let location_name = normalize_optional_text(request.location_name.as_deref());
+    let (latitude, longitude) = (request.latitude, request.longitude);
+    let reminder_lead_hours = request.reminder_lead_hours;
+    let quantity = request.quantity.unwrap_or(1);
 
-    let result = sqlx::query!(
-        r#"
-        INSERT INTO plants (
-            id, user_id, name, genus, 
-            watering_interval_days, fertilizing_interval_days,
-            watering_amount, watering_unit, watering_notes,
-            fertilizing_amount, fertilizing_unit, fertilizing_notes,
-            last_watered, last_fertilized,
-            created_at, updated_at
-        ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-        "#,
-        plant_id_str,
-        user_id,
-        request.name,
-        request.genus,
-        watering_interval,
-        fertilizing_interval,
-        watering_amount,
-        watering_unit,
-        watering_notes,
-        fertilizing_amount,
-        fertilizing_unit,
-        fertilizing_notes,
-        last_watered,
-        last_fertilized,
-        now,
-        now
-    )
-    .execute(pool)
-    .await
-    .map_err(|e| {
-        tracing::error!("Failed to create plant: {}", e);
-        AppError::Database(e)
-    })?;
+    let user_id = user_id.to_string();
+    let custom_metrics: Vec<(String, String, String, &'static str)> = request
+        .custom_metrics
+        .iter()
+        .flatten()
+        .map(|metric| {
+            (
+                Uuid::new_v4().to_string(),
+                metric.name.clone(),
+                metric.unit.clone(),
+                metric.data_type.as_db_str(),
+            )
+        })
+        .collect();
 
-    if result.rows_affected() != 1 {
-        return Err(AppError::Internal {
-            message: "Failed to create plant".to_string(),
-        });
-    }
+    // Insert the plant and its custom metrics together
+    with_transaction(pool, move |conn| {
+        Box::pin(async move {
+            let result = sqlx::query!(
+                r#"
+                INSERT INTO plants (
+                    id, user_id, name, genus, description, acquired_at,
+                    location_name, latitude, longitude,
+                    watering_interval_days, fertilizing_interval_days,
+                    watering_amount, watering_unit, watering_notes,
+                    fertilizing_amount, fertilizing_unit, fertilizing_notes,
+                    last_watered, last_fertilized, reminder_lead_hours, quantity,
+                    created_at, updated_at
+                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+                plant_id_str,
+                user_id,
+                name,
+                genus,
+                description,
+                acquired_at,
+                location_name,
+                latitude,
+                longitude,
+                watering_interval,
+                fertilizing_interval,
+                watering_amount,
+                watering_unit,
+                watering_notes,
+                fertilizing_amount,
+                fertilizing_unit,
+                fertilizing_notes,
+                last_watered,
+                last_fertilized,
+                reminder_lead_hours,
+                quantity,
+                now,
+                now
+            )
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to create plant: {}", e);
+                AppError::Database(e)
+            })?;
+
+            if result.rows_affected() != 1 {
+                return Err(AppError::Internal {
+                    message: "Failed to create plant".to_string(),
+                });
+            }
+
+            for (metric_id, metric_name, unit, data_type) in &custom_metrics {
+                sqlx::query(
+                    "INSERT INTO custom_metrics (id, plant_id, name, unit, data_type, created_at, updated_at)
+                     VALUES (?, ?, ?, ?, ?, ?, ?)",
+                )
+                .bind(metric_id)
+                .bind(&plant_id_str)
+                .bind(metric_name)
+                .bind(unit)
+                .bind(data_type)
+                .bind(&now)
+                .bind(&now)
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| {
+                    tracing::error!("Failed to create custom metric: {}", e);
+                    AppError::Database(e)
+                })?;
+            }
+
+            Ok(())
+        })
+    })
+    .await?;
 
     // Return the created plant
-    get_plant_by_id(pool, plant_id).await
+    get_plant_by_id(pool, PlantId(plant_id)).await
+}
+
+/// Fill in each plant's latest note, photo and measurement entry times.
+///
+/// Uses a single grouped query over all given plants to avoid N+1 lookups.
+/// Times are compared as instants, since stored timestamps may carry any offset.
+pub async fn attach_last_occurrences(
+    pool: &DatabasePool,
+    plants: &mut [PlantResponse],
+) -> Result<(), AppError> {
+    if plants.is_empty() {
+        return Ok(());
+    }
+
+    let placeholders = vec!["?"; plants.len()].join(", ");
+    let query = format!(
+        "SELECT plant_id,
+            strftime('%Y-%m-%dT%H:%M:%fZ',
+                MAX(CASE WHEN entry_type = 'note' THEN julianday(timestamp) END)) AS last_note_at,
+            strftime('%Y-%m-%dT%H:%M:%fZ',
+                MAX(CASE WHEN entry_type = 'photo' THEN julianday(timestamp) END)) AS last_photo_at,
+            strftime('%Y-%m-%dT%H:%M:%fZ',
+                MAX(CASE WHEN entry_type = 'measurement' THEN julianday(timestamp) END))
+                AS last_measurement_at
+         FROM tracking_entries
+         WHERE plant_id IN ({placeholders}) AND deleted_at IS NULL
+         GROUP BY plant_id"
+    );
+
+    let mut query_builder = sqlx::query(&query);
+    for plant in plants.iter() {
+        query_builder = query_builder.bind(plant.id.to_string());
+    }
+    let rows = query_builder.fetch_all(pool).await?;
+
+    let parse = |value: Option<String>| {
+        value
+            .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+            .map(|dt| dt.with_timezone(&Utc))
+    };
+
+    for row in rows {
+        let plant_id: String = row.get("plant_id");
+        if let Some(plant) = plants.iter_mut().find(|p| p.id.to_string() == plant_id) {
+            plant.last_note_at = parse(row.get("last_note_at"));
+            plant.last_photo_at = parse(row.get("last_photo_at"));
+            plant.last_measurement_at = parse(row.get("last_measurement_at"));
+        }
+    }
+
+    Ok(())
+}
+
+/// Fill in each plant's photo count and most recently uploaded photo.
+///
+/// Uses a single grouped query over all given plants to avoid N+1 lookups.
+pub async fn attach_photo_stats(
+    pool: &DatabasePool,
+    plants: &mut [PlantResponse],
+) -> Result<(), AppError> {
+    if plants.is_empty() {
+        return Ok(());
+    }
+
+    let placeholders = vec!["?"; plants.len()].join(", ");
+    let query = format!(
+        "SELECT plant_id, COUNT(*) AS photo_count,
+            (SELECT latest.id FROM photos latest
+             WHERE latest.plant_id = photos.plant_id
+             ORDER BY latest.created_at DESC, latest.rowid DESC
+             LIMIT 1) AS latest_photo_id
+         FROM photos
+         WHERE plant_id IN ({placeholders})
+         GROUP BY plant_id"
+    );
+
+    let mut query_builder = sqlx::query(&query);
+    for plant in plants.iter() {
+        query_builder = query_builder.bind(plant.id.to_string());
+    }
+    let rows = query_builder.fetch_all(pool).await?;
+
+    for row in rows {
+        let plant_id: String = row.get("plant_id");
+        if let Some(plant) = plants.iter_mut().find(|p| p.id.to_string() == plant_id) {
+            plant.photo_count = row.get("photo_count");
+            plant.latest_photo_id = row
+                .get::<Option<String>, _>("latest_photo_id")
+                .and_then(|id| Uuid::parse_str(&id).ok());
+        }
+    }
+
+    Ok(())
+}
+
+/// Fill in a single plant's photo count and most recently uploaded photo
+async fn load_photo_stats(pool: &DatabasePool, plant: &mut PlantResponse) -> Result<(), AppError> {
+    let plant_id = plant.id.to_string();
+
+    plant.photo_count = sqlx::query_scalar("SELECT COUNT(*) FROM photos WHERE plant_id = ?")
+        .bind(&plant_id)
+        .fetch_one(pool)
+        .await?;
+
+    let latest_photo_id: Option<String> = sqlx::query_scalar(
+        "SELECT id FROM photos WHERE plant_id = ? ORDER BY created_at DESC, rowid DESC LIMIT 1",
+    )
+    .bind(&plant_id)
+    .fetch_optional(pool)
+    .await?;
+    plant.latest_photo_id = latest_photo_id.and_then(|id| Uuid::parse_str(&id).ok());
+
+    Ok(())
 }
 
 pub async fn get_plant_by_id(
     pool: &DatabasePool,
-    plant_id: Uuid,
+    plant_id: PlantId,
 ) -> Result<PlantResponse, AppError> {
     let plant_id_str = plant_id.to_string();
     let plant_row = sqlx::query_as::<_, PlantRow>("SELECT * FROM plants WHERE id = ?")
@@ -186,14 +436,94 @@ pub async fn get_plant_by_id(
             AppError::Database(e)
         })?;
 
-    plant_row.map_or_else(
+    let mut plant = plant_row.map_or_else(
         || {
-            Err(AppError::NotFound {
-                resource: format!("Plant with id {plant_id}"),
-            })
+            Err(AppError::plant_not_found())
         },
         PlantRow::to_response,
-    )
+    )?;
+
+    attach_last_occurrences(pool, std::slice::from_mut(&mut plant)).await?;
+    load_photo_stats(pool, &mut plant).await?;
+    care_tasks::attach_care_tasks(pool, std::slice::from_mut(&mut plant)).await?;
+    tags::attach_tags(pool, std::slice::from_mut(&mut plant)).await?;
+    Ok(plant)
+}
+
+/// Next watering and fertilizing due dates for one of the user's plants, and
+/// whether they have passed as of `now`
+pub async fn get_plant_care_status(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    user_id: &str,
+    now: DateTime<Utc>,
+    default_lead_hours: u32,
+) -> Result<PlantCareStatus, AppError> {
+    let plant = get_plant_by_id(pool, plant_id).await?;
+    if plant.user_id != user_id {
+        return Err(AppError::plant_not_found());
+    }
+
+    let care_day = db_users::get_care_day(pool, user_id).await?;
+    Ok(PlantCareStatus::new(&plant, now, care_day, default_lead_hours))
+}
+
+/// `WHERE` conditions for a plant listing's filters, bound by `bind_plant_filters`
+fn plant_list_conditions(filter: &PlantListFilter<'_>) -> Vec<&'static str> {
+    let mut conditions = vec!["user_id = ?"];
+    conditions.push(if filter.archived {
+        "archived_at IS NOT NULL"
+    } else {
+        "archived_at IS NULL"
+    });
+    conditions.push(if filter.status.is_some() {
+        "status = ?"
+    } else {
+        "status != 'dead'"
+    });
+    if filter.search.is_some() {
+        conditions.push("(name LIKE ? OR genus LIKE ? OR description LIKE ?)");
+    }
+    if filter.near.is_some() {
+        conditions.push("latitude BETWEEN ? AND ? AND longitude BETWEEN ? AND ?");
+    }
+    // Every tag has to match, so each one narrows the listing further
+    conditions.extend(
+        filter
+            .tags
+            .iter()
+            .map(|_| "id IN (SELECT plant_id FROM plant_tags WHERE tag = ?)"),
+    );
+    conditions
+}
+
+/// Bind the `plant_list_conditions` parameters in `WHERE` clause order
+fn bind_plant_filters<'q>(
+    query: sqlx::query::Query<'q, sqlx::Sqlite, sqlx::sqlite::SqliteArguments<'q>>,
+    user_id: &'q str,
+    status: Option<PlantStatus>,
+    search_pattern: Option<&'q str>,
+    bounds: Option<&BoundingBox>,
+    tags: &'q [String],
+) -> sqlx::query::Query<'q, sqlx::Sqlite, sqlx::sqlite::SqliteArguments<'q>> {
+    let mut query = query.bind(user_id);
+    if let Some(status) = status {
+        query = query.bind(status.as_db_str());
+    }
+    if let Some(pattern) = search_pattern {
+        query = query.bind(pattern).bind(pattern).bind(pattern);
+    }
+    if let Some(bounds) = bounds {
+        query = query
+            .bind(bounds.min_latitude)
+            .bind(bounds.max_latitude)
+            .bind(bounds.min_longitude)
+            .bind(bounds.max_longitude);
+    }
+    for tag in tags {
+        query = query.bind(tag);
+    }
+    query
 }
 
 pub async fn list_plants_for_user(
@@ -203,7 +533,11 @@ pub async fn list_plants_for_user(
     offset: i64,
     search: Option<&str>,
 ) -> Result<(Vec<PlantResponse>, i64), AppError> {
-    list_plants_for_user_with_sort(pool, user_id, limit, offset, search, None).await
+    let filter = PlantListFilter {
+        search,
+        ..PlantListFilter::default()
+    };
+    list_plants_for_user_with_sort(pool, user_id, limit, offset, &filter).await
 }
 
 pub async fn list_plants_for_user_with_sort(
@@ -211,98 +545,273 @@ pub async fn list_plants_for_user_with_sort(
     user_id: &str,
     limit: i64,
     offset: i64,
-    search: Option<&str>,
-    sort: Option<&str>,
+    filter: &PlantListFilter<'_>,
 ) -> Result<(Vec<PlantResponse>, i64), AppError> {
     // Determine sort order
-    let order_clause = match sort {
-        Some("date_asc") => "ORDER BY created_at ASC",
+    let order_clause = match filter.sort {
+        Some("date_asc") => "ORDER BY created_at ASC, id ASC",
         Some("name_asc") => "ORDER BY name ASC",
         Some("name_desc") => "ORDER BY name DESC",
-        _ => "ORDER BY created_at DESC", // default
+        _ => "ORDER BY created_at DESC, id DESC", // default
     };
 
-    let (query, count_query, search_param) = search.map_or((
-            format!("SELECT * FROM plants WHERE user_id = ? {} LIMIT ? OFFSET ?", order_clause),
-            "SELECT COUNT(*) as count FROM plants WHERE user_id = ?".to_string(),
-            None
-        ), |search_term| {
-        let search_pattern = format!("%{search_term}%");
-        (
-            format!("SELECT * FROM plants WHERE user_id = ? AND (name LIKE ? OR genus LIKE ?) {} LIMIT ? OFFSET ?", order_clause),
-            "SELECT COUNT(*) as count FROM plants WHERE user_id = ? AND (name LIKE ? OR genus LIKE ?)".to_string(),
-            Some(search_pattern)
-        )
-    });
+    let search_pattern = filter.search.map(|search_term| format!("%{search_term}%"));
+    let bounds = filter.near.map(NearFilter::bounding_box);
+    let where_clause = plant_list_conditions(filter).join(" AND ");
 
     // Get total count
-    let total = if let Some(search_param) = &search_param {
-        sqlx::query(&count_query)
-            .bind(user_id)
-            .bind(search_param)
-            .bind(search_param)
-            .fetch_one(pool)
-            .await
-            .map_err(|e| {
-                tracing::error!("Failed to count plants: {}", e);
-                AppError::Database(e)
-            })?
-            .get::<i64, _>("count")
-    } else {
-        sqlx::query(&count_query)
-            .bind(user_id)
-            .fetch_one(pool)
-            .await
-            .map_err(|e| {
-                tracing::error!("Failed to count plants: {}", e);
-                AppError::Database(e)
-            })?
-            .get::<i64, _>("count")
-    };
+    let count_query = format!("SELECT COUNT(*) as count FROM plants WHERE {where_clause}");
+    let total = bind_plant_filters(
+        sqlx::query(&count_query),
+        user_id,
+        filter.status,
+        search_pattern.as_deref(),
+        bounds.as_ref(),
+        filter.tags,
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to count plants: {}", e);
+        AppError::Database(e)
+    })?
+    .get::<i64, _>("count");
 
     // Get plants
-    let plant_rows = if let Some(search_param) = &search_param {
-        sqlx::query_as::<_, PlantRow>(&query)
-            .bind(user_id)
-            .bind(search_param)
-            .bind(search_param)
-            .bind(limit)
-            .bind(offset)
-            .fetch_all(pool)
-            .await
-    } else {
-        sqlx::query_as::<_, PlantRow>(&query)
-            .bind(user_id)
-            .bind(limit)
-            .bind(offset)
-            .fetch_all(pool)
-            .await
-    }
+    let query =
+        format!("SELECT * FROM plants WHERE {where_clause} {order_clause} LIMIT ? OFFSET ?");
+    let plant_rows = bind_plant_filters(
+        sqlx::query(&query),
+        user_id,
+        filter.status,
+        search_pattern.as_deref(),
+        bounds.as_ref(),
+        filter.tags,
+    )
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await
     .map_err(|e| {
         tracing::error!("Failed to fetch plants: {}", e);
         AppError::Database(e)
-    })?;
+    })?
+    .iter()
+    .map(PlantRow::from_row)
+    .collect::<Result<Vec<_>, _>>()?;
+
+    let plants = listed_plants(pool, plant_rows).await?;
+
+    Ok((plants, total))
+}
+
+/// A page of the user's plants following `after`, in creation order
+///
+/// Plants are ordered by `created_at` and then `id`, newest first unless the
+/// filter sorts by `date_asc`; name sorts aren't supported. Alongside the page
+/// comes the cursor of its last plant when more plants follow it.
+pub async fn list_plants_for_user_after(
+    pool: &DatabasePool,
+    user_id: &str,
+    limit: i64,
+    after: Option<&PageCursor>,
+    filter: &PlantListFilter<'_>,
+) -> Result<(Vec<PlantResponse>, Option<PageCursor>), AppError> {
+    let (order_clause, after_condition) = match filter.sort {
+        Some("date_asc") => (
+            "ORDER BY created_at ASC, id ASC",
+            "(created_at > ? OR (created_at = ? AND id > ?))",
+        ),
+        _ => (
+            "ORDER BY created_at DESC, id DESC",
+            "(created_at < ? OR (created_at = ? AND id < ?))",
+        ),
+    };
+
+    let search_pattern = filter.search.map(|search_term| format!("%{search_term}%"));
+    let bounds = filter.near.map(NearFilter::bounding_box);
+    let mut conditions = plant_list_conditions(filter);
+    if after.is_some() {
+        conditions.push(after_condition);
+    }
+    let where_clause = conditions.join(" AND ");
+
+    // One extra row tells whether another page follows
+    let query = format!("SELECT * FROM plants WHERE {where_clause} {order_clause} LIMIT ?");
+    let mut query = bind_plant_filters(
+        sqlx::query(&query),
+        user_id,
+        filter.status,
+        search_pattern.as_deref(),
+        bounds.as_ref(),
+        filter.tags,
+    );
+    if let Some(after) = after {
+        query = query
+            .bind(&after.created_at)
+            .bind(&after.created_at)
+            .bind(&after.id);
+    }
+    let mut plant_rows = query
+        .bind(limit.saturating_add(1))
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to fetch plants: {}", e);
+            AppError::Database(e)
+        })?
+        .iter()
+        .map(PlantRow::from_row)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let has_more = plant_rows.len() as i64 > limit;
+    plant_rows.truncate(limit.max(0) as usize);
+    let next_cursor = plant_rows
+        .last()
+        .filter(|_| has_more)
+        .map(|row| PageCursor {
+            created_at: row.created_at.clone(),
+            id: row.id.clone(),
+        });
 
-    let plants = plant_rows
+    let plants = listed_plants(pool, plant_rows).await?;
+
+    Ok((plants, next_cursor))
+}
+
+/// Responses for listed plant rows, with the details plant listings include
+async fn listed_plants(
+    pool: &DatabasePool,
+    rows: Vec<PlantRow>,
+) -> Result<Vec<PlantResponse>, AppError> {
+    let mut plants = rows
         .into_iter()
         .map(PlantRow::to_response)
         .collect::<Result<Vec<_>, _>>()?;
 
-    Ok((plants, total))
+    attach_last_occurrences(pool, &mut plants).await?;
+    attach_photo_stats(pool, &mut plants).await?;
+    care_tasks::attach_care_tasks(pool, &mut plants).await?;
+    tags::attach_tags(pool, &mut plants).await?;
+
+    Ok(plants)
+}
+
+/// The user's dead plants, archived or not, most recently died first
+pub async fn list_memorial_plants(
+    pool: &DatabasePool,
+    user_id: &str,
+) -> Result<Vec<PlantResponse>, AppError> {
+    let rows = sqlx::query_as::<_, PlantRow>(
+        "SELECT * FROM plants
+         WHERE user_id = ? AND status = 'dead'
+         ORDER BY died_at DESC, name ASC",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    let mut plants = rows
+        .into_iter()
+        .map(PlantRow::to_response)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    attach_last_occurrences(pool, &mut plants).await?;
+    attach_photo_stats(pool, &mut plants).await?;
+    tags::attach_tags(pool, &mut plants).await?;
+
+    Ok(plants)
+}
+
+/// Plants whose next monthly acquisition anniversary falls within `within_days` of `today`
+///
+/// Sorted by how soon the anniversary is.
+pub async fn list_plant_anniversaries(
+    pool: &DatabasePool,
+    user_id: &str,
+    today: NaiveDate,
+    within_days: i64,
+) -> Result<Vec<PlantAnniversary>, AppError> {
+    let rows = sqlx::query_as::<_, PlantRow>(
+        "SELECT * FROM plants
+         WHERE user_id = ? AND acquired_at IS NOT NULL AND archived_at IS NULL
+           AND status != 'dead'",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    let mut anniversaries = Vec::new();
+    for row in rows {
+        let plant = row.to_response()?;
+        let Some(acquired_at) = plant.acquired_at else {
+            continue;
+        };
+        let Some((date, months)) = next_anniversary(acquired_at, today) else {
+            continue;
+        };
+        let days_until = (date - today).num_days();
+        if days_until <= within_days {
+            anniversaries.push(PlantAnniversary {
+                plant_id: plant.id,
+                name: plant.name,
+                acquired_at,
+                date,
+                days_until,
+                months,
+                yearly: months % 12 == 0,
+            });
+        }
+    }
+
+    anniversaries.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.name.cmp(&b.name)));
+    Ok(anniversaries)
 }
 
+/// Update a plant. With `convert_metric_values`, values recorded for a custom
+/// metric whose data type changes are converted to the new type. Adding
+/// metrics beyond `max_custom_metrics` is rejected.
 pub async fn update_plant(
     pool: &DatabasePool,
-    plant_id: Uuid,
+    plant_id: PlantId,
     user_id: &str,
     request: &UpdatePlantRequest,
+    convert_metric_values: bool,
+    max_custom_metrics: usize,
 ) -> Result<PlantResponse, AppError> {
     // First verify the plant exists and belongs to the user
     let existing_plant = get_plant_by_id(pool, plant_id).await?;
     if existing_plant.user_id != user_id {
-        return Err(AppError::NotFound {
-            resource: format!("Plant with id {plant_id}"),
-        });
+        return Err(AppError::plant_not_found());
+    }
+
+    // Death details belong to dead plants and go once a plant is revived
+    let status = request.status.unwrap_or(existing_plant.status);
+    validate_death_details(status, request)?;
+    let (died_at, death_reason) = if status == PlantStatus::Dead {
+        let died_at = request
+            .died_at
+            .or(existing_plant.died_at)
+            .unwrap_or_else(Utc::now);
+        let death_reason = match &request.death_reason {
+            Some(reason) => normalize_optional_text(Some(reason)),
+            None => existing_plant.death_reason,
+        };
+        (Some(died_at.to_rfc3339()), death_reason)
+    } else {
+        (None, None)
+    };
+
+    // Metric changes go first so a refused type change leaves the plant untouched
+    if let Some(metrics) = &request.custom_metrics {
+        update_custom_metrics(
+            pool,
+            plant_id,
+            metrics,
+            convert_metric_values,
+            max_custom_metrics,
+        )
+        .await?;
     }
 
     let now = Utc::now().to_rfc3339();
@@ -312,6 +821,13 @@ pub async fn update_plant(
         UPDATE plants SET 
             name = COALESCE(?, name),
             genus = COALESCE(?, genus),
+            description = CASE WHEN ? THEN ? ELSE description END,
+            acquired_at = CASE WHEN ? THEN ? ELSE acquired_at END,
+            location_name = CASE WHEN ? THEN ? ELSE location_name END,
+            latitude = CASE WHEN ? THEN ? ELSE latitude END,
+            longitude = CASE WHEN ? THEN ? ELSE longitude END,
+            reminder_lead_hours = CASE WHEN ? THEN ? ELSE reminder_lead_hours END,
+            quantity = COALESCE(?, quantity),
             watering_interval_days = CASE WHEN ? THEN ? WHEN ? THEN NULL ELSE watering_interval_days END,
             fertilizing_interval_days = CASE WHEN ? THEN ? WHEN ? THEN NULL ELSE fertilizing_interval_days END,
             watering_amount = CASE WHEN ? THEN ? WHEN ? THEN NULL ELSE watering_amount END,
@@ -320,11 +836,41 @@ pub async fn update_plant(
             fertilizing_amount = CASE WHEN ? THEN ? WHEN ? THEN NULL ELSE fertilizing_amount END,
             fertilizing_unit = CASE WHEN ? THEN ? WHEN ? THEN NULL ELSE fertilizing_unit END,
             fertilizing_notes = CASE WHEN ? THEN ? WHEN ? THEN NULL ELSE fertilizing_notes END,
+            status = ?,
+            died_at = ?,
+            death_reason = ?,
             updated_at = ?
         WHERE id = ? AND user_id = ?
     ";
 
-    let mut query_builder = sqlx::query(query).bind(&request.name).bind(&request.genus);
+    let name = request.name.as_deref().map(normalize_whitespace);
+    let genus = request.genus.as_deref().map(normalize_whitespace);
+    let mut query_builder = sqlx::query(query).bind(name).bind(genus);
+
+    // Description: omitted = no change, blank = cleared
+    query_builder = query_builder
+        .bind(request.description.is_some())
+        .bind(normalize_optional_text(request.description.as_deref()));
+
+    // Acquisition date: omitted = no change, null = cleared
+    query_builder = query_builder
+        .bind(request.acquired_at.is_some())
+        .bind(request.acquired_at.flatten().map(|date| date.to_string()));
+
+    // Location: name works like the description, coordinates like the acquisition date
+    query_builder = query_builder
+        .bind(request.location_name.is_some())
+        .bind(normalize_optional_text(request.location_name.as_deref()))
+        .bind(request.latitude.is_some())
+        .bind(request.latitude.flatten())
+        .bind(request.longitude.is_some())
+        .bind(request.longitude.flatten());
+
+    // Reminder lead time: omitted = no change, null = back to the server default
+    query_builder = query_builder
+        .bind(request.reminder_lead_hours.is_some())
+        .bind(request.reminder_lead_hours.flatten())
+        .bind(request.quantity);
 
     // Handle watering schedule fields with explicit null handling
     let watering_schedule_provided = request.watering_schedule.is_some();
@@ -407,6 +953,9 @@ pub async fn update_plant(
     }
 
     query_builder = query_builder
+        .bind(status.as_db_str())
+        .bind(died_at)
+        .bind(death_reason)
         .bind(&now)
         .bind(plant_id.to_string())
         .bind(user_id);
@@ -417,47 +966,375 @@ pub async fn update_plant(
     })?;
 
     if result.rows_affected() != 1 {
-        return Err(AppError::NotFound {
-            resource: format!("Plant with id {plant_id}"),
-        });
+        return Err(AppError::plant_not_found());
     }
 
     // Return the updated plant
     get_plant_by_id(pool, plant_id).await
 }
 
+/// Add and change a plant's custom metrics in one transaction.
+///
+/// A metric's data type can't change while values are recorded for it, unless
+/// `convert_values` is set and every value converts to the new type.
+async fn update_custom_metrics(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    metrics: &[UpdateCustomMetricRequest],
+    convert_values: bool,
+    max_metrics: usize,
+) -> Result<(), AppError> {
+    let now = Utc::now().to_rfc3339();
+    let plant_id = plant_id.to_string();
+    let mut tx = begin_write(pool).await?;
+
+    // Metrics without an id are added alongside the existing ones
+    let existing: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM custom_metrics WHERE plant_id = ?")
+        .bind(&plant_id)
+        .fetch_one(&mut *tx)
+        .await?;
+    let added = metrics.iter().filter(|metric| metric.id.is_none()).count();
+    validate_custom_metric_count(existing as usize + added, max_metrics)?;
+
+    for metric in metrics {
+        let Some(metric_id) = metric.id else {
+            sqlx::query(
+                "INSERT INTO custom_metrics (id, plant_id, name, unit, data_type, created_at, updated_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(Uuid::new_v4().to_string())
+            .bind(&plant_id)
+            .bind(&metric.name)
+            .bind(&metric.unit)
+            .bind(metric.data_type.as_db_str())
+            .bind(&now)
+            .bind(&now)
+            .execute(&mut *tx)
+            .await?;
+            continue;
+        };
+        let metric_id = metric_id.to_string();
+
+        let current_type: String = sqlx::query_scalar(
+            "SELECT data_type FROM custom_metrics WHERE id = ? AND plant_id = ?",
+        )
+        .bind(&metric_id)
+        .bind(&plant_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::NotFound {
+            resource: "Custom metric".to_string(),
+        })?;
+
+        let new_type = metric.data_type.as_db_str();
+        if current_type != new_type {
+            // Deleted entries count too, since they can still be restored
+            let values = sqlx::query(
+                "SELECT id, value FROM tracking_entries WHERE metric_id = ? AND value IS NOT NULL",
+            )
+            .bind(&metric_id)
+            .fetch_all(&mut *tx)
+            .await?;
+
+            if !values.is_empty() && !convert_values {
+                return Err(AppError::BadRequest {
+                    message: format!(
+                        "Can't change {} from {} to {} while it has recorded values; \
+                         pass convert=true to convert them",
+                        metric.name, current_type, new_type
+                    ),
+                });
+            }
+
+            for row in values {
+                let entry_id: String = row.get("id");
+                let stored: String = row.get("value");
+                let converted = serde_json::from_str(&stored)
+                    .ok()
+                    .and_then(|value| metric.data_type.convert_value(&value))
+                    .ok_or_else(|| AppError::BadRequest {
+                        message: format!(
+                            "Recorded value {} for {} can't be converted to {}",
+                            stored, metric.name, new_type
+                        ),
+                    })?;
+
+                sqlx::query("UPDATE tracking_entries SET value = ?, updated_at = ? WHERE id = ?")
+                    .bind(converted.to_string())
+                    .bind(&now)
+                    .bind(&entry_id)
+                    .execute(&mut *tx)
+                    .await?;
+            }
+        }
+
+        sqlx::query(
+            "UPDATE custom_metrics SET name = ?, unit = ?, data_type = ?, updated_at = ?
+             WHERE id = ? AND plant_id = ?",
+        )
+        .bind(&metric.name)
+        .bind(&metric.unit)
+        .bind(new_type)
+        .bind(&now)
+        .bind(&metric_id)
+        .bind(&plant_id)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// Apply care intervals to the user's plants among `plant_ids` in one transaction.
+///
+/// Plants that don't exist or belong to someone else are skipped; returns the
+/// number of plants updated.
+pub async fn bulk_update_schedule(
+    pool: &DatabasePool,
+    user_id: &str,
+    request: &BulkUpdateScheduleRequest,
+) -> Result<u64, AppError> {
+    let now = Utc::now().to_rfc3339();
+    let mut plant_ids = request.plant_ids.clone();
+    plant_ids.sort();
+    plant_ids.dedup();
+
+    let mut tx = begin_write(pool).await?;
+    let mut updated = 0;
+
+    for plant_id in plant_ids {
+        let result = sqlx::query(
+            "UPDATE plants SET
+                watering_interval_days = COALESCE(?, watering_interval_days),
+                fertilizing_interval_days = COALESCE(?, fertilizing_interval_days),
+                updated_at = ?
+             WHERE id = ? AND user_id = ?",
+        )
+        .bind(request.watering_interval_days)
+        .bind(request.fertilizing_interval_days)
+        .bind(&now)
+        .bind(plant_id.to_string())
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+
+        updated += result.rows_affected();
+    }
+
+    tx.commit().await?;
+
+    Ok(updated)
+}
+
+/// Move last-care dates of the user's plants as proposed by a rebalance, in
+/// one transaction.
+///
+/// Plants that don't exist or belong to someone else are skipped; returns the
+/// number of shifts applied.
+pub async fn apply_schedule_shifts(
+    pool: &DatabasePool,
+    user_id: &str,
+    shifts: &[ScheduleShift],
+) -> Result<u64, AppError> {
+    let now = Utc::now().to_rfc3339();
+    let mut tx = begin_write(pool).await?;
+    let mut applied = 0;
+
+    for shift in shifts {
+        let query = match shift.care_type {
+            CareKind::Watering => {
+                "UPDATE plants SET last_watered = ?, updated_at = ? WHERE id = ? AND user_id = ?"
+            }
+            CareKind::Fertilizing => {
+                "UPDATE plants SET last_fertilized = ?, updated_at = ? WHERE id = ? AND user_id = ?"
+            }
+        };
+        let result = sqlx::query(query)
+            .bind(shift.proposed_last_care.to_rfc3339())
+            .bind(&now)
+            .bind(shift.plant_id.to_string())
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        applied += result.rows_affected();
+    }
+
+    tx.commit().await?;
+
+    Ok(applied)
+}
+
 pub async fn delete_plant(
     pool: &DatabasePool,
-    plant_id: Uuid,
+    plant_id: PlantId,
     user_id: &str,
 ) -> Result<(), AppError> {
     let plant_id_str = plant_id.to_string();
+    let user_id = user_id.to_string();
 
-    let result = sqlx::query!(
-        "DELETE FROM plants WHERE id = ? AND user_id = ?",
-        plant_id_str,
-        user_id
+    // The tombstone goes in with the delete so syncing clients always see it
+    with_transaction(pool, move |conn| {
+        Box::pin(async move {
+            let result = sqlx::query!(
+                "DELETE FROM plants WHERE id = ? AND user_id = ?",
+                plant_id_str,
+                user_id
+            )
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to delete plant: {}", e);
+                AppError::Database(e)
+            })?;
+
+            if result.rows_affected() != 1 {
+                return Err(AppError::plant_not_found());
+            }
+
+            deletions::record_deletion(&mut *conn, DeletedEntityType::Plant, &plant_id.0, &user_id)
+                .await
+        })
+    })
+    .await
+}
+
+/// Retire a plant without deleting it
+///
+/// Its entries and photos are kept, but it drops out of plant listings, the
+/// calendar feed and Google Tasks until unarchived. Archiving an archived
+/// plant keeps the original archive time.
+pub async fn archive_plant(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    user_id: &str,
+) -> Result<PlantResponse, AppError> {
+    set_plant_archived(pool, plant_id, user_id, true).await
+}
+
+/// Bring an archived plant back into listings and care reminders
+pub async fn unarchive_plant(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    user_id: &str,
+) -> Result<PlantResponse, AppError> {
+    set_plant_archived(pool, plant_id, user_id, false).await
+}
+
+async fn set_plant_archived(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    user_id: &str,
+    archived: bool,
+) -> Result<PlantResponse, AppError> {
+    let now = Utc::now().to_rfc3339();
+    let result = sqlx::query(
+        "UPDATE plants
+         SET archived_at = CASE WHEN ? THEN COALESCE(archived_at, ?) ELSE NULL END,
+             updated_at = ?
+         WHERE id = ? AND user_id = ?",
     )
+    .bind(archived)
+    .bind(&now)
+    .bind(&now)
+    .bind(plant_id.to_string())
+    .bind(user_id)
     .execute(pool)
     .await
     .map_err(|e| {
-        tracing::error!("Failed to delete plant: {}", e);
+        tracing::error!("Failed to update plant archive state: {}", e);
         AppError::Database(e)
     })?;
 
     if result.rows_affected() != 1 {
-        return Err(AppError::NotFound {
-            resource: format!("Plant with id {plant_id}"),
-        });
+        return Err(AppError::plant_not_found());
     }
 
-    Ok(())
+    get_plant_by_id(pool, plant_id).await
+}
+
+/// Move every plant owned by `from_user_id` to `to_user_id`, returning how many moved
+///
+/// Entries, photos and metrics belong to the plant and follow it. Everything
+/// moved is marked updated so the new owner's clients pick it up on sync, the
+/// old owner gets tombstones for the plants, and the transfer is recorded in
+/// the admin audit log as part of the same transaction.
+pub async fn transfer_plants(
+    pool: &DatabasePool,
+    from_user_id: &str,
+    to_user_id: &str,
+    admin_id: &str,
+) -> Result<u64, AppError> {
+    let from_user_id = from_user_id.to_string();
+    let to_user_id = to_user_id.to_string();
+    let admin_id = admin_id.to_string();
+    let now_str = Utc::now().to_rfc3339();
+
+    with_transaction(pool, move |conn| {
+        Box::pin(async move {
+            let plant_ids: Vec<String> =
+                sqlx::query_scalar("SELECT id FROM plants WHERE user_id = ?")
+                    .bind(&from_user_id)
+                    .fetch_all(&mut *conn)
+                    .await?;
+
+            for plant_id in &plant_ids {
+                sqlx::query("UPDATE tracking_entries SET updated_at = ? WHERE plant_id = ?")
+                    .bind(&now_str)
+                    .bind(plant_id)
+                    .execute(&mut *conn)
+                    .await?;
+                sqlx::query("UPDATE photos SET updated_at = ? WHERE plant_id = ?")
+                    .bind(&now_str)
+                    .bind(plant_id)
+                    .execute(&mut *conn)
+                    .await?;
+
+                let plant_uuid = Uuid::parse_str(plant_id).map_err(|_| AppError::Internal {
+                    message: "Invalid plant ID in database".to_string(),
+                })?;
+                deletions::record_deletion(
+                    &mut *conn,
+                    DeletedEntityType::Plant,
+                    &plant_uuid,
+                    &from_user_id,
+                )
+                .await?;
+            }
+
+            let transferred =
+                sqlx::query("UPDATE plants SET user_id = ?, updated_at = ? WHERE user_id = ?")
+                    .bind(&to_user_id)
+                    .bind(&now_str)
+                    .bind(&from_user_id)
+                    .execute(&mut *conn)
+                    .await?
+                    .rows_affected();
+
+            audit::record_admin_action(
+                &mut *conn,
+                &admin_id,
+                "transfer_plants",
+                Some(&from_user_id),
+                serde_json::json!({
+                    "toUserId": to_user_id,
+                    "plantIds": plant_ids,
+                }),
+            )
+            .await?;
+
+            Ok(transferred)
+        })
+    })
+    .await
 }
 
 pub async fn set_plant_preview(
     pool: &DatabasePool,
-    plant_id: Uuid,
-    photo_id: Uuid,
+    plant_id: PlantId,
+    photo_id: PhotoId,
     user_id: &str,
 ) -> Result<PlantResponse, AppError> {
     let plant_id_str = plant_id.to_string();
@@ -475,9 +1352,7 @@ pub async fn set_plant_preview(
         })?;
 
     if plant_exists.is_none() {
-        return Err(AppError::NotFound {
-            resource: format!("Plant with id {plant_id}"),
-        });
+        return Err(AppError::plant_not_found());
     }
 
     // Verify the photo exists and belongs to the plant
@@ -492,9 +1367,7 @@ pub async fn set_plant_preview(
         })?;
 
     if photo_exists.is_none() {
-        return Err(AppError::NotFound {
-            resource: format!("Photo with id {photo_id} for plant {plant_id}"),
-        });
+        return Err(AppError::photo_not_found());
     }
 
     // Update the plant's preview_id
@@ -524,7 +1397,7 @@ pub async fn set_plant_preview(
 }
 pub async fn clear_plant_preview(
     pool: &DatabasePool,
-    plant_id: Uuid,
+    plant_id: PlantId,
     user_id: &str,
 ) -> Result<PlantResponse, AppError> {
     let plant_id_str = plant_id.to_string();
@@ -537,9 +1410,7 @@ pub async fn clear_plant_preview(
         .await?;
 
     if plant_exists.is_none() {
-        return Err(AppError::NotFound {
-            resource: format!("Plant with id {plant_id}"),
-        });
+        return Err(AppError::plant_not_found());
     }
 
     // Clear the plant's preview_id
@@ -554,11 +1425,100 @@ pub async fn clear_plant_preview(
     .await?;
 
     if result.rows_affected() == 0 {
-        return Err(AppError::NotFound {
-            resource: format!("Plant with id {plant_id}"),
-        });
+        return Err(AppError::plant_not_found());
     }
 
     // Return the updated plant
     get_plant_by_id(pool, plant_id).await
-}
\ No newline at end of file
+}
+/// Create the example plants for a user who hasn't had them yet.
+///
+/// Returns `None` if examples were already seeded for this account, even if
+/// the user has since deleted them.
+pub async fn seed_example_plants(
+    pool: &DatabasePool,
+    user_id: &str,
+) -> Result<Option<Vec<PlantResponse>>, AppError> {
+    // Claim the flag first so concurrent requests can't both seed
+    let claimed = sqlx::query(
+        "UPDATE users SET examples_seeded_at = ? WHERE id = ? AND examples_seeded_at IS NULL",
+    )
+    .bind(Utc::now().to_rfc3339())
+    .bind(user_id)
+    .execute(pool)
+    .await?
+    .rows_affected();
+
+    if claimed == 0 {
+        return Ok(None);
+    }
+
+    let mut plants = Vec::new();
+    for request in example_plants() {
+        match create_plant(pool, user_id, &request).await {
+            Ok(plant) => plants.push(plant),
+            Err(e) => {
+                // Undo the partial seeding so the user can try again
+                for plant in &plants {
+                    delete_plant(pool, PlantId(plant.id), user_id).await?;
+                }
+                sqlx::query("UPDATE users SET examples_seeded_at = NULL WHERE id = ?")
+                    .bind(user_id)
+                    .execute(pool)
+                    .await?;
+                return Err(e);
+            }
+        }
+    }
+
+    Ok(Some(plants))
+}
+
+fn example_plants() -> Vec<CreatePlantRequest> {
+    let schedule = |interval_days, amount, unit: &str| CreateCareScheduleRequest {
+        interval_days: Some(interval_days),
+        amount,
+        unit: Some(unit.to_string()),
+        notes: None,
+    };
+    let metric = |name: &str, unit: &str| CreateCustomMetricRequest {
+        name: name.to_string(),
+        unit: unit.to_string(),
+        data_type: MetricDataType::Number,
+    };
+
+    vec![
+        CreatePlantRequest {
+            name: "Monstera (example)".to_string(),
+            genus: "Monstera".to_string(),
+            description: None,
+            acquired_at: None,
+            location_name: None,
+            latitude: None,
+            longitude: None,
+            watering_schedule: Some(schedule(7, Some(500.0), "ml")),
+            fertilizing_schedule: Some(schedule(30, Some(5.0), "ml")),
+            reminder_lead_hours: None,
+            quantity: None,
+            custom_metrics: Some(vec![metric("Height", "cm"), metric("Leaf count", "leaves")]),
+            last_watered: None,
+            last_fertilized: None,
+        },
+        CreatePlantRequest {
+            name: "Snake Plant (example)".to_string(),
+            genus: "Sansevieria".to_string(),
+            description: None,
+            acquired_at: None,
+            location_name: None,
+            latitude: None,
+            longitude: None,
+            watering_schedule: Some(schedule(14, Some(250.0), "ml")),
+            fertilizing_schedule: Some(schedule(60, Some(2.5), "ml")),
+            reminder_lead_hours: None,
+            quantity: None,
+            custom_metrics: Some(vec![metric("Height", "cm")]),
+            last_watered: None,
+            last_fertilized: None,
+        },
+    ]
+}
@@ -0,0 +1,128 @@
+use chrono::{DateTime, Utc};
+use rand::RngCore;
+use sqlx::Row;
+
+use crate::database::DatabasePool;
+use crate::models::PlantId;
+use crate::models::public_link::PublicNote;
+use crate::models::tracking_entry::NoteVisibility;
+use crate::utils::errors::{AppError, Result};
+use crate::utils::hex::to_hex;
+
+/// A plant reachable through a public link, and its owner
+pub struct LinkedPlant {
+    pub plant_id: PlantId,
+    pub user_id: String,
+}
+
+fn generate_token() -> String {
+    let mut bytes = [0u8; 16];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    to_hex(&bytes)
+}
+
+/// Give one of the user's plants a new public link, replacing any it had
+pub async fn create_public_link(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    user_id: &str,
+) -> Result<(String, DateTime<Utc>)> {
+    let token = generate_token();
+    let now = Utc::now();
+
+    let result = sqlx::query(
+        "INSERT INTO public_plant_links (plant_id, token, created_at)
+         SELECT id, ?, ? FROM plants WHERE id = ? AND user_id = ?
+         ON CONFLICT (plant_id) DO UPDATE SET token = excluded.token, created_at = excluded.created_at",
+    )
+    .bind(&token)
+    .bind(now.to_rfc3339())
+    .bind(plant_id.to_string())
+    .bind(user_id)
+    .execute(pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::plant_not_found());
+    }
+
+    Ok((token, now))
+}
+
+/// Revoke a plant's public link; its token stops working immediately
+pub async fn delete_public_link(pool: &DatabasePool, plant_id: PlantId, user_id: &str) -> Result<()> {
+    let result = sqlx::query(
+        "DELETE FROM public_plant_links
+         WHERE plant_id = (SELECT id FROM plants WHERE id = ? AND user_id = ?)",
+    )
+    .bind(plant_id.to_string())
+    .bind(user_id)
+    .execute(pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::public_link_not_found());
+    }
+
+    Ok(())
+}
+
+/// The plant `token` links to, if the link exists
+pub async fn get_linked_plant(pool: &DatabasePool, token: &str) -> Result<Option<LinkedPlant>> {
+    let Some(row) = sqlx::query(
+        "SELECT p.id, p.user_id FROM public_plant_links l
+         JOIN plants p ON p.id = l.plant_id
+         WHERE l.token = ?",
+    )
+    .bind(token)
+    .fetch_optional(pool)
+    .await?
+    else {
+        return Ok(None);
+    };
+
+    let plant_id = row.get::<String, _>("id").parse().map_err(|_| AppError::Internal {
+        message: "Invalid UUID in database".to_string(),
+    })?;
+
+    Ok(Some(LinkedPlant {
+        plant_id,
+        user_id: row.get("user_id"),
+    }))
+}
+
+/// The plant's most recent shared notes, newest first; owner-only notes are
+/// never shown to people viewing the plant through its link
+pub async fn get_shared_notes(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    limit: i64,
+) -> Result<Vec<PublicNote>> {
+    let rows = sqlx::query(
+        "SELECT timestamp, notes FROM tracking_entries
+         WHERE plant_id = ? AND entry_type = 'note' AND visibility = ?
+           AND notes IS NOT NULL AND deleted_at IS NULL
+         ORDER BY timestamp DESC, id DESC
+         LIMIT ?",
+    )
+    .bind(plant_id.to_string())
+    .bind(NoteVisibility::Shared.as_str())
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    rows.iter()
+        .map(|row| {
+            let timestamp = row
+                .get::<String, _>("timestamp")
+                .parse::<DateTime<Utc>>()
+                .map_err(|_| AppError::Internal {
+                    message: "Invalid datetime in database".to_string(),
+                })?;
+            Ok(PublicNote {
+                timestamp,
+                notes: row.get("notes"),
+            })
+        })
+        .collect()
+}
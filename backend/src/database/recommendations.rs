@@ -0,0 +1,55 @@
+use chrono::Utc;
+use sqlx::Row;
+
+use crate::database::DatabasePool;
+use crate::models::PlantId;
+use crate::models::watering::DismissedRecommendation;
+use crate::utils::errors::AppError;
+
+/// The last dismissal of the plant's `rec_key` recommendation, if any
+pub async fn get_dismissal(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    rec_key: &str,
+) -> Result<Option<DismissedRecommendation>, AppError> {
+    let row = sqlx::query(
+        "SELECT current_interval_days, suggested_interval_days FROM dismissed_recommendations
+         WHERE plant_id = ? AND rec_key = ?",
+    )
+    .bind(plant_id.to_string())
+    .bind(rec_key)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.map(|row| DismissedRecommendation {
+        current_interval_days: row.get("current_interval_days"),
+        suggested_interval_days: row.get("suggested_interval_days"),
+    }))
+}
+
+/// Record a dismissal, replacing any earlier one for the same recommendation
+pub async fn dismiss_recommendation(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    rec_key: &str,
+    dismissed: &DismissedRecommendation,
+) -> Result<(), AppError> {
+    sqlx::query(
+        "INSERT INTO dismissed_recommendations
+             (plant_id, rec_key, current_interval_days, suggested_interval_days, dismissed_at)
+         VALUES (?, ?, ?, ?, ?)
+         ON CONFLICT (plant_id, rec_key) DO UPDATE SET
+             current_interval_days = excluded.current_interval_days,
+             suggested_interval_days = excluded.suggested_interval_days,
+             dismissed_at = excluded.dismissed_at",
+    )
+    .bind(plant_id.to_string())
+    .bind(rec_key)
+    .bind(dismissed.current_interval_days)
+    .bind(dismissed.suggested_interval_days)
+    .bind(Utc::now().to_rfc3339())
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
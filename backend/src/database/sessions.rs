@@ -0,0 +1,123 @@
+use axum_login::tower_sessions::{session::Id, session_store, SessionStore};
+use chrono::{DateTime, Utc};
+use time::OffsetDateTime;
+use tower_sessions_sqlx_store::SqliteStore;
+
+use crate::auth::{AUTH_DATA_KEY, SESSION_CREATED_AT_KEY};
+use crate::database::{audit, with_transaction, DatabasePool};
+use crate::utils::errors::AppError;
+
+/// A logged-in session read back from the session store
+#[derive(Debug, Clone)]
+pub struct StoredSession {
+    pub session_id: String,
+    pub user_id: String,
+    /// `None` for sessions started before login times were recorded
+    pub created_at: Option<DateTime<Utc>>,
+    pub expires_at: DateTime<Utc>,
+}
+
+fn store_error(error: session_store::Error) -> AppError {
+    AppError::Internal {
+        message: format!("Session store error: {}", error),
+    }
+}
+
+/// Load and decode one session, `None` if it is missing, expired or malformed
+async fn load_session(
+    store: &SqliteStore,
+    session_id: &str,
+) -> Result<Option<StoredSession>, AppError> {
+    let Ok(id) = session_id.parse::<Id>() else {
+        return Ok(None);
+    };
+    let Some(record) = store.load(&id).await.map_err(store_error)? else {
+        return Ok(None);
+    };
+
+    // Sessions that never logged in, or have logged out, have no user
+    let Some(user_id) = record
+        .data
+        .get(AUTH_DATA_KEY)
+        .and_then(|data| data.get("user_id"))
+        .and_then(|user_id| user_id.as_str())
+    else {
+        return Ok(None);
+    };
+
+    Ok(Some(StoredSession {
+        session_id: session_id.to_string(),
+        user_id: user_id.to_string(),
+        created_at: record
+            .data
+            .get(SESSION_CREATED_AT_KEY)
+            .and_then(|created_at| serde_json::from_value(created_at.clone()).ok()),
+        expires_at: DateTime::from_timestamp(record.expiry_date.unix_timestamp(), 0)
+            .unwrap_or_default(),
+    }))
+}
+
+/// Every unexpired session with a logged-in user, most recently active first
+///
+/// The store only indexes sessions by id, so each one is loaded and decoded.
+pub async fn list_active_sessions(pool: &DatabasePool) -> Result<Vec<StoredSession>, AppError> {
+    let ids: Vec<String> =
+        sqlx::query_scalar("SELECT id FROM tower_sessions WHERE expiry_date > ?")
+            .bind(OffsetDateTime::now_utc())
+            .fetch_all(pool)
+            .await?;
+
+    let store = SqliteStore::new(pool.clone());
+    let mut sessions = Vec::new();
+    for id in ids {
+        if let Some(session) = load_session(&store, &id).await? {
+            sessions.push(session);
+        }
+    }
+
+    // Expiry is on inactivity, so the latest expiry is the latest activity
+    sessions.sort_by_key(|session| std::cmp::Reverse(session.expires_at));
+    Ok(sessions)
+}
+
+pub async fn get_active_session(
+    pool: &DatabasePool,
+    session_id: &str,
+) -> Result<StoredSession, AppError> {
+    load_session(&SqliteStore::new(pool.clone()), session_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound {
+            resource: "Session".to_string(),
+        })
+}
+
+/// Delete a session, logging its user out on their next request
+///
+/// The revocation is recorded in the admin audit log as part of the same transaction.
+pub async fn revoke_session(
+    pool: &DatabasePool,
+    session: &StoredSession,
+    admin_id: &str,
+) -> Result<(), AppError> {
+    let session = session.clone();
+    let admin_id = admin_id.to_string();
+
+    with_transaction(pool, move |conn| {
+        Box::pin(async move {
+            sqlx::query("DELETE FROM tower_sessions WHERE id = ?")
+                .bind(&session.session_id)
+                .execute(&mut *conn)
+                .await?;
+
+            audit::record_admin_action(
+                &mut *conn,
+                &admin_id,
+                "revoke_session",
+                Some(&session.user_id),
+                serde_json::json!({ "sessionId": session.session_id }),
+            )
+            .await
+        })
+    })
+    .await
+}
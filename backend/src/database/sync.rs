@@ -0,0 +1,88 @@
+use chrono::{DateTime, Utc};
+
+use crate::database::{care_tasks, deletions, tags};
+use crate::database::photos::photo_from_row;
+use crate::database::plants::{self, PlantRow};
+use crate::database::tracking::tracking_entry_from_row;
+use crate::database::DatabasePool;
+use crate::models::sync::SyncChangesResponse;
+use crate::utils::errors::AppError;
+
+/// Collect a user's plants, tracking entries, photos and tombstones changed after `since`.
+///
+/// `server_time` should be captured before calling so that writes racing with
+/// this query are picked up again by the next sync.
+pub async fn get_changes_since(
+    pool: &DatabasePool,
+    user_id: &str,
+    since: Option<DateTime<Utc>>,
+    server_time: DateTime<Utc>,
+) -> Result<SyncChangesResponse, AppError> {
+    let since_str = since.map(|since| since.to_rfc3339());
+
+    let mut plants = sqlx::query_as::<_, PlantRow>(
+        "SELECT * FROM plants
+         WHERE user_id = ? AND (? IS NULL OR julianday(updated_at) > julianday(?))
+         ORDER BY updated_at ASC",
+    )
+    .bind(user_id)
+    .bind(&since_str)
+    .bind(&since_str)
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(PlantRow::to_response)
+    .collect::<Result<Vec<_>, _>>()?;
+    plants::attach_last_occurrences(pool, &mut plants).await?;
+    plants::attach_photo_stats(pool, &mut plants).await?;
+    care_tasks::attach_care_tasks(pool, &mut plants).await?;
+    tags::attach_tags(pool, &mut plants).await?;
+
+    let tracking_entries = sqlx::query(
+        "SELECT e.id, e.plant_id, e.entry_type, e.care_task_type, e.timestamp, e.value, e.notes, e.metric_id, e.photo_ids, e.visibility, e.created_at, e.updated_at
+         FROM tracking_entries e
+         JOIN plants p ON p.id = e.plant_id
+         WHERE p.user_id = ? AND e.deleted_at IS NULL
+           AND (? IS NULL OR julianday(e.updated_at) > julianday(?))
+         ORDER BY e.updated_at ASC, e.id ASC",
+    )
+    .bind(user_id)
+    .bind(&since_str)
+    .bind(&since_str)
+    .fetch_all(pool)
+    .await?
+    .iter()
+    .map(tracking_entry_from_row)
+    .collect();
+
+    let photos = sqlx::query(
+        "SELECT ph.id, ph.plant_id, ph.filename, ph.original_filename, ph.size, ph.content_type, ph.width, ph.height, ph.caption, ph.created_at
+         FROM photos ph
+         JOIN plants p ON p.id = ph.plant_id
+         WHERE p.user_id = ? AND (? IS NULL OR julianday(COALESCE(ph.updated_at, ph.created_at)) > julianday(?))
+         ORDER BY ph.created_at ASC",
+    )
+    .bind(user_id)
+    .bind(&since_str)
+    .bind(&since_str)
+    .fetch_all(pool)
+    .await?
+    .iter()
+    .map(photo_from_row)
+    .collect();
+
+    // A full sync has nothing to delete on the client
+    let deleted = match since {
+        Some(since) => deletions::list_deletions_since(pool, user_id, since).await?,
+        None => Vec::new(),
+    };
+
+    Ok(SyncChangesResponse {
+        plants,
+        tracking_entries,
+        photos,
+        deleted,
+        server_time,
+        full_resync_required: false,
+    })
+}
@@ -0,0 +1,112 @@
+use chrono::Utc;
+use sqlx::Row;
+
+use crate::database::care_tasks::{ensure_plant_owned, touch_plant};
+use crate::database::DatabasePool;
+use crate::models::PlantId;
+use crate::models::plant::{normalize_tag, PlantResponse};
+use crate::utils::errors::AppError;
+
+/// Fill in each plant's tags.
+///
+/// Uses a single query over all given plants to avoid N+1 lookups.
+pub async fn attach_tags(
+    pool: &DatabasePool,
+    plants: &mut [PlantResponse],
+) -> Result<(), AppError> {
+    if plants.is_empty() {
+        return Ok(());
+    }
+
+    let placeholders = vec!["?"; plants.len()].join(", ");
+    let query = format!(
+        "SELECT plant_id, tag FROM plant_tags
+         WHERE plant_id IN ({placeholders})
+         ORDER BY tag"
+    );
+
+    let mut query_builder = sqlx::query(&query);
+    for plant in plants.iter() {
+        query_builder = query_builder.bind(plant.id.to_string());
+    }
+    let rows = query_builder.fetch_all(pool).await?;
+
+    for row in rows {
+        let plant_id: String = row.get("plant_id");
+        if let Some(plant) = plants.iter_mut().find(|p| p.id.to_string() == plant_id) {
+            plant.tags.push(row.get("tag"));
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn list_tags(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    user_id: &str,
+) -> Result<Vec<String>, AppError> {
+    ensure_plant_owned(pool, plant_id, user_id).await?;
+
+    let tags = sqlx::query_scalar("SELECT tag FROM plant_tags WHERE plant_id = ? ORDER BY tag")
+        .bind(plant_id.to_string())
+        .fetch_all(pool)
+        .await?;
+
+    Ok(tags)
+}
+
+/// Tag a plant, returning all of its tags. Tags it already has are ignored.
+pub async fn add_tags(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    user_id: &str,
+    tags: &[String],
+) -> Result<Vec<String>, AppError> {
+    ensure_plant_owned(pool, plant_id, user_id).await?;
+
+    let now = Utc::now().to_rfc3339();
+    let mut added = 0;
+    for tag in tags {
+        added += sqlx::query(
+            "INSERT INTO plant_tags (plant_id, tag, created_at) VALUES (?, ?, ?)
+             ON CONFLICT (plant_id, tag) DO NOTHING",
+        )
+        .bind(plant_id.to_string())
+        .bind(normalize_tag(tag))
+        .bind(&now)
+        .execute(pool)
+        .await?
+        .rows_affected();
+    }
+
+    if added > 0 {
+        touch_plant(pool, plant_id).await?;
+    }
+
+    list_tags(pool, plant_id, user_id).await
+}
+
+pub async fn remove_tag(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    user_id: &str,
+    tag: &str,
+) -> Result<(), AppError> {
+    ensure_plant_owned(pool, plant_id, user_id).await?;
+
+    let result = sqlx::query("DELETE FROM plant_tags WHERE plant_id = ? AND tag = ?")
+        .bind(plant_id.to_string())
+        .bind(normalize_tag(tag))
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::NotFound {
+            resource: "Tag".to_string(),
+        });
+    }
+    touch_plant(pool, plant_id).await?;
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use sqlx::Row;
+use std::collections::HashMap;
+
+use crate::database::photos::photo_from_row;
+use crate::database::tracking::tracking_entry_from_row;
+use crate::database::DatabasePool;
+use crate::models::PlantId;
+use crate::models::photo::Photo;
+use crate::models::timeline::{TimelineItem, TimelinePhoto, TimelineResponse};
+use crate::utils::errors::AppError;
+
+/// Entries and photos of a plant as (kind, id, occurred_at) rows.
+///
+/// Photos already attached to a (non-deleted) photo entry are left out so
+/// they only show up once, as part of that entry.
+const TIMELINE_ITEMS: &str = "
+    SELECT 'entry' AS kind, id, timestamp AS occurred_at
+    FROM tracking_entries
+    WHERE plant_id = ?1 AND deleted_at IS NULL
+    UNION ALL
+    SELECT 'photo' AS kind, id, created_at AS occurred_at
+    FROM photos
+    WHERE plant_id = ?1 AND NOT EXISTS (
+        SELECT 1 FROM tracking_entries e, json_each(e.photo_ids) attached
+        WHERE e.plant_id = ?1 AND e.deleted_at IS NULL AND e.photo_ids IS NOT NULL
+        AND attached.value = photos.id
+    )";
+
+/// Get a page of a plant's timeline, newest first
+pub async fn get_plant_timeline(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    user_id: &str,
+    limit: Option<i64>,
+    offset: Option<i64>,
+) -> Result<TimelineResponse, AppError> {
+    let limit = limit.unwrap_or(50);
+    let offset = offset.unwrap_or(0);
+
+    // First verify the plant exists and belongs to the user
+    let plant_exists = sqlx::query("SELECT 1 FROM plants WHERE id = ? AND user_id = ?")
+        .bind(plant_id.to_string())
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?;
+
+    if plant_exists.is_none() {
+        return Err(AppError::plant_not_found());
+    }
+
+    let plant_id_str = plant_id.to_string();
+
+    let total: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM ({TIMELINE_ITEMS})"))
+        .bind(&plant_id_str)
+        .fetch_one(pool)
+        .await?;
+
+    // Pick the page first, then load the full rows for just those items
+    let page = sqlx::query(&format!(
+        "SELECT kind, id FROM ({TIMELINE_ITEMS})
+         ORDER BY julianday(occurred_at) DESC, id DESC
+         LIMIT ?2 OFFSET ?3"
+    ))
+    .bind(&plant_id_str)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await?;
+
+    let page: Vec<(String, String)> = page
+        .iter()
+        .map(|row| (row.get("kind"), row.get("id")))
+        .collect();
+    let ids_of = |kind: &str| -> Vec<&str> {
+        page.iter()
+            .filter(|(k, _)| k == kind)
+            .map(|(_, id)| id.as_str())
+            .collect()
+    };
+
+    let entry_ids = ids_of("entry");
+    let mut entries = HashMap::new();
+    if !entry_ids.is_empty() {
+        let query = format!(
+            "SELECT * FROM tracking_entries WHERE id IN ({})",
+            vec!["?"; entry_ids.len()].join(", ")
+        );
+        let mut query_builder = sqlx::query(&query);
+        for id in &entry_ids {
+            query_builder = query_builder.bind(*id);
+        }
+        for row in query_builder.fetch_all(pool).await? {
+            let entry = tracking_entry_from_row(&row);
+            entries.insert(entry.id.to_string(), entry);
+        }
+    }
+
+    let photo_ids = ids_of("photo");
+    let mut photos = HashMap::new();
+    if !photo_ids.is_empty() {
+        let query = format!(
+            "SELECT id, plant_id, filename, original_filename, size, content_type, width, height, caption, created_at
+             FROM photos WHERE id IN ({})",
+            vec!["?"; photo_ids.len()].join(", ")
+        );
+        let mut query_builder = sqlx::query(&query);
+        for id in &photo_ids {
+            query_builder = query_builder.bind(*id);
+        }
+        for row in query_builder.fetch_all(pool).await? {
+            let photo = photo_from_row(&row);
+            photos.insert(photo.id.to_string(), photo);
+        }
+    }
+
+    let items = page
+        .iter()
+        .filter_map(|(kind, id)| match kind.as_str() {
+            "entry" => entries.remove(id).map(TimelineItem::Entry),
+            _ => photos.remove(id).map(|photo: Photo| {
+                let url = photo.url();
+                TimelineItem::Photo(TimelinePhoto { photo, url })
+            }),
+        })
+        .collect();
+
+    Ok(TimelineResponse {
+        items,
+        total,
+        limit,
+        offset,
+    })
+}
@@ -1,17 +1,25 @@
-use chrono::Utc;
-use sqlx::Row;
+use chrono::{DateTime, Utc};
+use sqlx::{sqlite::SqliteRow, Row, SqliteConnection};
+use std::collections::HashMap;
 use uuid::Uuid;
 
-use crate::database::DatabasePool;
+use crate::database::{deletions, with_transaction, DatabasePool};
+use crate::models::{EntryId, PlantId};
+use crate::models::sync::DeletedEntityType;
 use crate::models::tracking_entry::{
-    CreateTrackingEntryRequest, EntryType, TrackingEntriesResponse, TrackingEntry,
+    CreateTrackingEntryRequest, DeleteEntriesRequest, EntryRollupBucket, EntryType,
+    RollupGranularity, TrackingEntriesResponse, TrackingEntry,
 };
+use crate::models::user::WeekStart;
 use crate::utils::errors::AppError;
 
+/// How long a deleted entry can be restored before the purge job removes it
+pub const ENTRY_RESTORE_WINDOW_DAYS: i64 = 30;
+
 /// Get all tracking entries for a specific plant with pagination
 pub async fn get_tracking_entries_for_plant_paginated(
     pool: &DatabasePool,
-    plant_id: &Uuid,
+    plant_id: PlantId,
     user_id: &str,
     limit: i64,
     offset: i64,
@@ -26,16 +34,14 @@ pub async fn get_tracking_entries_for_plant_paginated(
         .await?;
 
     if plant_exists.is_none() {
-        return Err(AppError::NotFound {
-            resource: format!("Plant with id {plant_id}"),
-        });
+        return Err(AppError::plant_not_found());
     }
 
-    // Build sort order
+    // Build sort order; `id` breaks timestamp ties so pages never overlap
     let order_clause = if sort_desc {
-        "ORDER BY timestamp DESC"
+        "ORDER BY timestamp DESC, id DESC"
     } else {
-        "ORDER BY timestamp ASC"
+        "ORDER BY timestamp ASC, id ASC"
     };
 
     // Build filter clause for entry type
@@ -47,7 +53,7 @@ pub async fn get_tracking_entries_for_plant_paginated(
 
     // Get total count
     let count_query = format!(
-        "SELECT COUNT(*) as count FROM tracking_entries WHERE plant_id = ?{}",
+        "SELECT COUNT(*) as count FROM tracking_entries WHERE plant_id = ? AND deleted_at IS NULL{}",
         count_filter_clause
     );
     
@@ -68,9 +74,9 @@ pub async fn get_tracking_entries_for_plant_paginated(
 
     // Get tracking entries with pagination
     let entries_query = format!(
-        "SELECT id, plant_id, entry_type, timestamp, value, notes, metric_id, photo_ids, created_at, updated_at 
+        "SELECT id, plant_id, entry_type, care_task_type, timestamp, value, notes, metric_id, photo_ids, visibility, created_at, updated_at 
          FROM tracking_entries 
-         WHERE plant_id = ?{} 
+         WHERE plant_id = ? AND deleted_at IS NULL{} 
          {} 
          LIMIT ? OFFSET ?",
         filter_clause, order_clause
@@ -93,55 +99,21 @@ pub async fn get_tracking_entries_for_plant_paginated(
             .await?
     };
 
-    let entries: Vec<TrackingEntry> = entries_rows
-        .into_iter()
-        .map(|row| {
-            let id_str: String = row.get("id");
-            let plant_id_str: String = row.get("plant_id");
-            let timestamp_str: String = row.get("timestamp");
-            let created_at_str: String = row.get("created_at");
-            let updated_at_str: String = row.get("updated_at");
-            let entry_type_str: String = row.get("entry_type");
-            let metric_id_str: Option<String> = row.get("metric_id");
-            let value_str: Option<String> = row.get("value");
-            let photo_ids_str: Option<String> = row.get("photo_ids");
-
-            TrackingEntry {
-                id: Uuid::parse_str(&id_str).expect("Invalid UUID"),
-                plant_id: Uuid::parse_str(&plant_id_str).expect("Invalid UUID"),
-                entry_type: match entry_type_str.as_str() {
-                    "watering" => EntryType::Watering,
-                    "fertilizing" => EntryType::Fertilizing,
-                    "measurement" => EntryType::CustomMetric,
-                    "note" => EntryType::Note,
-                    "photo" => EntryType::Photo,
-                    _ => EntryType::Watering, // fallback
-                },
-                timestamp: chrono::DateTime::parse_from_rfc3339(&timestamp_str)
-                    .expect("Invalid timestamp")
-                    .with_timezone(&Utc),
-                value: value_str.and_then(|v| serde_json::from_str(&v).ok()),
-                notes: row.get("notes"),
-                metric_id: metric_id_str.and_then(|id| Uuid::parse_str(&id).ok()),
-                photo_ids: photo_ids_str.and_then(|v| serde_json::from_str(&v).ok()),
-                created_at: chrono::DateTime::parse_from_rfc3339(&created_at_str)
-                    .expect("Invalid timestamp")
-                    .with_timezone(&Utc),
-                updated_at: chrono::DateTime::parse_from_rfc3339(&updated_at_str)
-                    .expect("Invalid timestamp")
-                    .with_timezone(&Utc),
-            }
-        })
-        .collect();
+    let entries: Vec<TrackingEntry> = entries_rows.iter().map(tracking_entry_from_row).collect();
 
-    Ok(TrackingEntriesResponse { entries, total })
+    Ok(TrackingEntriesResponse {
+        entries,
+        total,
+        next: None,
+        prev: None,
+    })
 }
 
 /// Get all tracking entries for a specific plant
 #[allow(dead_code)]
 pub async fn get_tracking_entries_for_plant(
     pool: &DatabasePool,
-    plant_id: &Uuid,
+    plant_id: PlantId,
     user_id: &str,
 ) -> Result<TrackingEntriesResponse, AppError> {
     // First verify the plant exists and belongs to the user
@@ -152,72 +124,98 @@ pub async fn get_tracking_entries_for_plant(
         .await?;
 
     if plant_exists.is_none() {
-        return Err(AppError::NotFound {
-            resource: format!("Plant with id {plant_id}"),
-        });
+        return Err(AppError::plant_not_found());
     }
 
     // Get tracking entries
     let entries_rows = sqlx::query(
-        "SELECT id, plant_id, entry_type, timestamp, value, notes, metric_id, photo_ids, created_at, updated_at 
+        "SELECT id, plant_id, entry_type, care_task_type, timestamp, value, notes, metric_id, photo_ids, visibility, created_at, updated_at 
          FROM tracking_entries 
-         WHERE plant_id = ? 
-         ORDER BY timestamp DESC"
+         WHERE plant_id = ? AND deleted_at IS NULL
+         ORDER BY timestamp DESC, id DESC"
     )
     .bind(plant_id.to_string())
     .fetch_all(pool)
     .await?;
 
-    let entries: Vec<TrackingEntry> = entries_rows
-        .into_iter()
-        .map(|row| {
-            let id_str: String = row.get("id");
-            let plant_id_str: String = row.get("plant_id");
-            let timestamp_str: String = row.get("timestamp");
-            let created_at_str: String = row.get("created_at");
-            let updated_at_str: String = row.get("updated_at");
-            let entry_type_str: String = row.get("entry_type");
-            let metric_id_str: Option<String> = row.get("metric_id");
-            let value_str: Option<String> = row.get("value");
-            let photo_ids_str: Option<String> = row.get("photo_ids");
-
-            TrackingEntry {
-                id: Uuid::parse_str(&id_str).expect("Invalid UUID"),
-                plant_id: Uuid::parse_str(&plant_id_str).expect("Invalid UUID"),
-                entry_type: match entry_type_str.as_str() {
-                    "watering" => EntryType::Watering,
-                    "fertilizing" => EntryType::Fertilizing,
-                    "measurement" => EntryType::CustomMetric,
-                    "note" => EntryType::Note,
-                    "photo" => EntryType::Photo,
-                    _ => EntryType::Watering, // fallback
-                },
-                timestamp: chrono::DateTime::parse_from_rfc3339(&timestamp_str)
-                    .expect("Invalid timestamp")
-                    .with_timezone(&Utc),
-                value: value_str.and_then(|v| serde_json::from_str(&v).ok()),
-                notes: row.get("notes"),
-                metric_id: metric_id_str.and_then(|id| Uuid::parse_str(&id).ok()),
-                photo_ids: photo_ids_str.and_then(|v| serde_json::from_str(&v).ok()),
-                created_at: chrono::DateTime::parse_from_rfc3339(&created_at_str)
-                    .expect("Invalid timestamp")
-                    .with_timezone(&Utc),
-                updated_at: chrono::DateTime::parse_from_rfc3339(&updated_at_str)
-                    .expect("Invalid timestamp")
-                    .with_timezone(&Utc),
-            }
-        })
-        .collect();
+    let entries: Vec<TrackingEntry> = entries_rows.iter().map(tracking_entry_from_row).collect();
 
     let total = entries.len() as i64;
 
-    Ok(TrackingEntriesResponse { entries, total })
+    Ok(TrackingEntriesResponse {
+        entries,
+        total,
+        next: None,
+        prev: None,
+    })
+}
+
+/// When the plant last got `entry_type` care (watering or fertilizing),
+/// newest first, up to `limit` times
+pub async fn get_recent_care_times(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    entry_type: &EntryType,
+    limit: i64,
+) -> Result<Vec<DateTime<Utc>>, AppError> {
+    let timestamps: Vec<String> = sqlx::query_scalar(
+        "SELECT timestamp FROM tracking_entries
+         WHERE plant_id = ? AND entry_type = ? AND deleted_at IS NULL
+         ORDER BY timestamp DESC
+         LIMIT ?",
+    )
+    .bind(plant_id.to_string())
+    .bind(entry_type_str(entry_type))
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(timestamps
+        .iter()
+        .filter_map(|timestamp| DateTime::parse_from_rfc3339(timestamp).ok())
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .collect())
+}
+
+/// Map a `tracking_entries` row to a `TrackingEntry`
+pub(crate) fn tracking_entry_from_row(row: &SqliteRow) -> TrackingEntry {
+    let id_str: String = row.get("id");
+    let plant_id_str: String = row.get("plant_id");
+    let timestamp_str: String = row.get("timestamp");
+    let created_at_str: String = row.get("created_at");
+    let updated_at_str: String = row.get("updated_at");
+    let entry_type_str: String = row.get("entry_type");
+    let care_task_type: Option<String> = row.get("care_task_type");
+    let metric_id_str: Option<String> = row.get("metric_id");
+    let value_str: Option<String> = row.get("value");
+    let photo_ids_str: Option<String> = row.get("photo_ids");
+    let visibility_str: String = row.get("visibility");
+
+    TrackingEntry {
+        id: Uuid::parse_str(&id_str).expect("Invalid UUID"),
+        plant_id: Uuid::parse_str(&plant_id_str).expect("Invalid UUID"),
+        entry_type: parse_entry_type(&entry_type_str, care_task_type),
+        timestamp: chrono::DateTime::parse_from_rfc3339(&timestamp_str)
+            .expect("Invalid timestamp")
+            .with_timezone(&Utc),
+        value: value_str.and_then(|v| serde_json::from_str(&v).ok()),
+        notes: row.get("notes"),
+        metric_id: metric_id_str.and_then(|id| Uuid::parse_str(&id).ok()),
+        photo_ids: photo_ids_str.and_then(|v| serde_json::from_str(&v).ok()),
+        visibility: visibility_str.parse().unwrap_or_default(),
+        created_at: chrono::DateTime::parse_from_rfc3339(&created_at_str)
+            .expect("Invalid timestamp")
+            .with_timezone(&Utc),
+        updated_at: chrono::DateTime::parse_from_rfc3339(&updated_at_str)
+            .expect("Invalid timestamp")
+            .with_timezone(&Utc),
+    }
 }
 
 /// Create a new tracking entry for a plant
 pub async fn create_tracking_entry(
     pool: &DatabasePool,
-    plant_id: &Uuid,
+    plant_id: PlantId,
     user_id: &str,
     request: &CreateTrackingEntryRequest,
 ) -> Result<TrackingEntry, AppError> {
@@ -229,21 +227,14 @@ pub async fn create_tracking_entry(
         .await?;
 
     if plant_exists.is_none() {
-        return Err(AppError::NotFound {
-            resource: format!("Plant with id {plant_id}"),
-        });
+        return Err(AppError::plant_not_found());
     }
 
     let entry_id = Uuid::new_v4();
     let now = Utc::now();
 
-    let entry_type_str = match request.entry_type {
-        EntryType::Watering => "watering",
-        EntryType::Fertilizing => "fertilizing",
-        EntryType::CustomMetric => "measurement",
-        EntryType::Note => "note",
-        EntryType::Photo => "photo",
-    };
+    let entry_type_str = entry_type_str(&request.entry_type);
+    let care_task_type = care_task_type(&request.entry_type).map(str::to_string);
 
     let value_json = request
         .value
@@ -255,78 +246,241 @@ pub async fn create_tracking_entry(
         .as_ref()
         .map(|v| serde_json::to_string(v).unwrap_or_default());
 
-    // Create the tracking entry
-    sqlx::query(
-        "INSERT INTO tracking_entries (id, plant_id, entry_type, timestamp, value, notes, metric_id, photo_ids, created_at, updated_at)
-         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
-    )
-    .bind(entry_id.to_string())
-    .bind(plant_id.to_string())
-    .bind(entry_type_str)
-    .bind(request.timestamp.to_rfc3339())
-    .bind(&value_json)
-    .bind(&request.notes)
-    .bind(request.metric_id.map(|id| id.to_string()))
-    .bind(&photo_ids_json)
-    .bind(now.to_rfc3339())
-    .bind(now.to_rfc3339())
-    .execute(pool)
-    .await?;
-
-    // Update the plant's last watered/fertilized date if this is a watering or fertilizing entry
-    match request.entry_type {
-        EntryType::Watering => {
+    let entry_id_str = entry_id.to_string();
+    let plant_id_str = plant_id.to_string();
+    let user_id = user_id.to_string();
+    let timestamp = request.timestamp.to_rfc3339();
+    let notes = request.notes.clone();
+    let metric_id = request.metric_id.map(|id| id.to_string());
+    let visibility = request.visibility.unwrap_or_default();
+    let now_str = now.to_rfc3339();
+    let entry_type = request.entry_type.clone();
+
+    // Insert the entry and update the plant's care dates together
+    with_transaction(pool, move |conn| {
+        Box::pin(async move {
             sqlx::query(
-                "UPDATE plants SET last_watered = ?, updated_at = ? WHERE id = ? AND user_id = ?",
+                "INSERT INTO tracking_entries (id, plant_id, entry_type, care_task_type, timestamp, value, notes, metric_id, photo_ids, visibility, created_at, updated_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
             )
-            .bind(request.timestamp.to_rfc3339())
-            .bind(now.to_rfc3339())
-            .bind(plant_id.to_string())
-            .bind(user_id)
-            .execute(pool)
-            .await?;
-        }
-        EntryType::Fertilizing => {
-            sqlx::query(
-                "UPDATE plants SET last_fertilized = ?, updated_at = ? WHERE id = ? AND user_id = ?"
-            )
-            .bind(request.timestamp.to_rfc3339())
-            .bind(now.to_rfc3339())
-            .bind(plant_id.to_string())
-            .bind(user_id)
-            .execute(pool)
+            .bind(&entry_id_str)
+            .bind(&plant_id_str)
+            .bind(entry_type_str)
+            .bind(&care_task_type)
+            .bind(&timestamp)
+            .bind(&value_json)
+            .bind(&notes)
+            .bind(&metric_id)
+            .bind(&photo_ids_json)
+            .bind(visibility.as_str())
+            .bind(&now_str)
+            .bind(&now_str)
+            .execute(&mut *conn)
             .await?;
-        }
-        EntryType::CustomMetric => {
-            // Custom metrics don't update plant care dates
-        }
-        EntryType::Note => {
-            // Notes don't update plant care dates
-        }
-        EntryType::Photo => {
-            // Photos don't update plant care dates
-        }
-    }
+
+            // Update the plant's last watered/fertilized date if this is a watering or fertilizing entry
+            match entry_type {
+                EntryType::Watering => {
+                    sqlx::query(
+                        "UPDATE plants SET last_watered = ?, updated_at = ? WHERE id = ? AND user_id = ?",
+                    )
+                    .bind(&timestamp)
+                    .bind(&now_str)
+                    .bind(&plant_id_str)
+                    .bind(&user_id)
+                    .execute(&mut *conn)
+                    .await?;
+                }
+                EntryType::Fertilizing => {
+                    sqlx::query(
+                        "UPDATE plants SET last_fertilized = ?, updated_at = ? WHERE id = ? AND user_id = ?"
+                    )
+                    .bind(&timestamp)
+                    .bind(&now_str)
+                    .bind(&plant_id_str)
+                    .bind(&user_id)
+                    .execute(&mut *conn)
+                    .await?;
+                }
+                EntryType::CustomMetric => {
+                    // Custom metrics don't update plant care dates
+                }
+                EntryType::Note => {
+                    // Notes don't update plant care dates
+                }
+                EntryType::Photo => {
+                    // Photos don't update plant care dates
+                }
+                EntryType::Care { .. } => {
+                    // Only plants with a matching care task track when it was last done
+                    sqlx::query(
+                        "UPDATE care_tasks SET last_done = ?, updated_at = ? WHERE plant_id = ? AND task_type = ?",
+                    )
+                    .bind(&timestamp)
+                    .bind(&now_str)
+                    .bind(&plant_id_str)
+                    .bind(&care_task_type)
+                    .execute(&mut *conn)
+                    .await?;
+                    sqlx::query("UPDATE plants SET updated_at = ? WHERE id = ? AND user_id = ?")
+                        .bind(&now_str)
+                        .bind(&plant_id_str)
+                        .bind(&user_id)
+                        .execute(&mut *conn)
+                        .await?;
+                }
+            }
+
+            Ok(())
+        })
+    })
+    .await?;
 
     Ok(TrackingEntry {
         id: entry_id,
-        plant_id: *plant_id,
+        plant_id: plant_id.0,
         entry_type: request.entry_type.clone(),
         timestamp: request.timestamp,
         value: request.value.clone(),
         notes: request.notes.clone(),
         metric_id: request.metric_id,
         photo_ids: request.photo_ids.as_ref().map(|v| serde_json::to_value(v).unwrap_or_default()),
+        visibility,
         created_at: now,
         updated_at: now,
     })
 }
 
+/// Create several entries for a plant in one transaction
+///
+/// Each watering or fertilizing date, and each care task's `last_done`, is set
+/// once to the latest matching entry in the batch. Nothing is created if any
+/// insert fails.
+pub async fn create_entries_bulk(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    user_id: &str,
+    requests: Vec<CreateTrackingEntryRequest>,
+) -> Result<Vec<TrackingEntry>, AppError> {
+    // First verify the plant exists and belongs to the user
+    let plant_exists = sqlx::query("SELECT 1 FROM plants WHERE id = ? AND user_id = ?")
+        .bind(plant_id.to_string())
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?;
+
+    if plant_exists.is_none() {
+        return Err(AppError::plant_not_found());
+    }
+
+    let now = Utc::now();
+    let entries: Vec<TrackingEntry> = requests
+        .into_iter()
+        .map(|request| TrackingEntry {
+            id: Uuid::new_v4(),
+            plant_id: plant_id.0,
+            entry_type: request.entry_type,
+            timestamp: request.timestamp,
+            value: request.value,
+            notes: request.notes,
+            metric_id: request.metric_id,
+            photo_ids: request
+                .photo_ids
+                .map(|ids| serde_json::to_value(ids).unwrap_or_default()),
+            visibility: request.visibility.unwrap_or_default(),
+            created_at: now,
+            updated_at: now,
+        })
+        .collect();
+
+    // Latest time per plant care column and per care task
+    let mut last_care: HashMap<&'static str, DateTime<Utc>> = HashMap::new();
+    let mut last_done: HashMap<String, DateTime<Utc>> = HashMap::new();
+    for entry in &entries {
+        let latest = match (
+            last_care_column(entry_type_str(&entry.entry_type)),
+            care_task_type(&entry.entry_type),
+        ) {
+            (Some(column), _) => last_care.entry(column).or_insert(entry.timestamp),
+            (None, Some(task_type)) => last_done
+                .entry(task_type.to_string())
+                .or_insert(entry.timestamp),
+            (None, None) => continue,
+        };
+        *latest = (*latest).max(entry.timestamp);
+    }
+
+    let plant_id_str = plant_id.to_string();
+    let user_id = user_id.to_string();
+    let now_str = now.to_rfc3339();
+
+    with_transaction(pool, move |conn| {
+        Box::pin(async move {
+            for entry in &entries {
+                sqlx::query(
+                    "INSERT INTO tracking_entries (id, plant_id, entry_type, care_task_type, timestamp, value, notes, metric_id, photo_ids, visibility, created_at, updated_at)
+                     VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                )
+                .bind(entry.id.to_string())
+                .bind(&plant_id_str)
+                .bind(entry_type_str(&entry.entry_type))
+                .bind(care_task_type(&entry.entry_type))
+                .bind(entry.timestamp.to_rfc3339())
+                .bind(entry.value.as_ref().map(ToString::to_string))
+                .bind(&entry.notes)
+                .bind(entry.metric_id.map(|id| id.to_string()))
+                .bind(entry.photo_ids.as_ref().map(ToString::to_string))
+                .bind(entry.visibility.as_str())
+                .bind(&now_str)
+                .bind(&now_str)
+                .execute(&mut *conn)
+                .await?;
+            }
+
+            for (column, timestamp) in &last_care {
+                let query = format!(
+                    "UPDATE plants SET {column} = ?, updated_at = ? WHERE id = ? AND user_id = ?"
+                );
+                sqlx::query(&query)
+                    .bind(timestamp.to_rfc3339())
+                    .bind(&now_str)
+                    .bind(&plant_id_str)
+                    .bind(&user_id)
+                    .execute(&mut *conn)
+                    .await?;
+            }
+
+            for (task_type, timestamp) in &last_done {
+                sqlx::query(
+                    "UPDATE care_tasks SET last_done = ?, updated_at = ? WHERE plant_id = ? AND task_type = ?",
+                )
+                .bind(timestamp.to_rfc3339())
+                .bind(&now_str)
+                .bind(&plant_id_str)
+                .bind(task_type)
+                .execute(&mut *conn)
+                .await?;
+            }
+            if !last_done.is_empty() {
+                sqlx::query("UPDATE plants SET updated_at = ? WHERE id = ? AND user_id = ?")
+                    .bind(&now_str)
+                    .bind(&plant_id_str)
+                    .bind(&user_id)
+                    .execute(&mut *conn)
+                    .await?;
+            }
+
+            Ok(entries)
+        })
+    })
+    .await
+}
+
 /// Get a single tracking entry
 pub async fn get_tracking_entry(
     pool: &DatabasePool,
-    plant_id: &Uuid,
-    entry_id: &Uuid,
+    plant_id: PlantId,
+    entry_id: &EntryId,
     user_id: &str,
 ) -> Result<TrackingEntry, AppError> {
     // First verify the plant exists and belongs to the user
@@ -337,68 +491,30 @@ pub async fn get_tracking_entry(
         .await?;
 
     if plant_exists.is_none() {
-        return Err(AppError::NotFound {
-            resource: format!("Plant with id {plant_id}"),
-        });
+        return Err(AppError::plant_not_found());
     }
 
     // Get the specific tracking entry
     let entry_row = sqlx::query(
-        "SELECT id, plant_id, entry_type, timestamp, value, notes, metric_id, photo_ids, created_at, updated_at 
+        "SELECT id, plant_id, entry_type, care_task_type, timestamp, value, notes, metric_id, photo_ids, visibility, created_at, updated_at 
          FROM tracking_entries 
-         WHERE id = ? AND plant_id = ?"
+         WHERE id = ? AND plant_id = ? AND deleted_at IS NULL"
     )
     .bind(entry_id.to_string())
     .bind(plant_id.to_string())
     .fetch_optional(pool)
     .await?;
 
-    let row = entry_row.ok_or_else(|| AppError::NotFound {
-        resource: format!("Tracking entry with id {entry_id}"),
-    })?;
-
-    let id_str: String = row.get("id");
-    let plant_id_str: String = row.get("plant_id");
-    let timestamp_str: String = row.get("timestamp");
-    let created_at_str: String = row.get("created_at");
-    let updated_at_str: String = row.get("updated_at");
-    let entry_type_str: String = row.get("entry_type");
-    let metric_id_str: Option<String> = row.get("metric_id");
-    let value_str: Option<String> = row.get("value");
-    let photo_ids_str: Option<String> = row.get("photo_ids");
+    let row = entry_row.ok_or_else(AppError::tracking_entry_not_found)?;
 
-    Ok(TrackingEntry {
-        id: Uuid::parse_str(&id_str).expect("Invalid UUID"),
-        plant_id: Uuid::parse_str(&plant_id_str).expect("Invalid UUID"),
-        entry_type: match entry_type_str.as_str() {
-            "watering" => EntryType::Watering,
-            "fertilizing" => EntryType::Fertilizing,
-            "measurement" => EntryType::CustomMetric,
-            "note" => EntryType::Note,
-            "photo" => EntryType::Photo,
-            _ => EntryType::Watering, // fallback
-        },
-        timestamp: chrono::DateTime::parse_from_rfc3339(&timestamp_str)
-            .expect("Invalid timestamp")
-            .with_timezone(&Utc),
-        value: value_str.and_then(|v| serde_json::from_str(&v).ok()),
-        notes: row.get("notes"),
-        metric_id: metric_id_str.and_then(|id| Uuid::parse_str(&id).ok()),
-        photo_ids: photo_ids_str.and_then(|v| serde_json::from_str(&v).ok()),
-        created_at: chrono::DateTime::parse_from_rfc3339(&created_at_str)
-            .expect("Invalid timestamp")
-            .with_timezone(&Utc),
-        updated_at: chrono::DateTime::parse_from_rfc3339(&updated_at_str)
-            .expect("Invalid timestamp")
-            .with_timezone(&Utc),
-    })
+    Ok(tracking_entry_from_row(&row))
 }
 
 /// Update a tracking entry
 pub async fn update_tracking_entry(
     pool: &DatabasePool,
-    plant_id: &Uuid,
-    entry_id: &Uuid,
+    plant_id: PlantId,
+    entry_id: &EntryId,
     user_id: &str,
     request: &crate::models::tracking_entry::UpdateTrackingEntryRequest,
 ) -> Result<TrackingEntry, AppError> {
@@ -410,21 +526,22 @@ pub async fn update_tracking_entry(
         .await?;
 
     if plant_exists.is_none() {
-        return Err(AppError::NotFound {
-            resource: format!("Plant with id {plant_id}"),
-        });
+        return Err(AppError::plant_not_found());
     }
 
     // Verify the entry exists and belongs to this plant
-    let entry_exists = sqlx::query("SELECT 1 FROM tracking_entries WHERE id = ? AND plant_id = ?")
-        .bind(entry_id.to_string())
-        .bind(plant_id.to_string())
-        .fetch_optional(pool)
-        .await?;
+    let entry_type: String = sqlx::query_scalar(
+        "SELECT entry_type FROM tracking_entries WHERE id = ? AND plant_id = ? AND deleted_at IS NULL",
+    )
+    .bind(entry_id.to_string())
+    .bind(plant_id.to_string())
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(AppError::tracking_entry_not_found)?;
 
-    if entry_exists.is_none() {
-        return Err(AppError::NotFound {
-            resource: format!("Tracking entry with id {entry_id}"),
+    if request.visibility.is_some() && entry_type != "note" {
+        return Err(AppError::BadRequest {
+            message: "Only notes have a visibility".to_string(),
         });
     }
 
@@ -454,8 +571,13 @@ pub async fn update_tracking_entry(
         values.push(serde_json::to_string(photo_ids).unwrap_or_default());
     }
 
+    if let Some(visibility) = request.visibility {
+        update_parts.push("visibility = ?");
+        values.push(visibility.as_str().to_string());
+    }
+
     let query = format!(
-        "UPDATE tracking_entries SET {} WHERE id = ? AND plant_id = ?",
+        "UPDATE tracking_entries SET {} WHERE id = ? AND plant_id = ? AND deleted_at IS NULL",
         update_parts.join(", ")
     );
 
@@ -468,20 +590,19 @@ pub async fn update_tracking_entry(
     let result = query_builder.execute(pool).await?;
 
     if result.rows_affected() == 0 {
-        return Err(AppError::NotFound {
-            resource: format!("Tracking entry with id {entry_id}"),
-        });
+        return Err(AppError::tracking_entry_not_found());
     }
 
     // Return the updated entry
     get_tracking_entry(pool, plant_id, entry_id, user_id).await
 }
 
-/// Delete a tracking entry
+/// Soft-delete a tracking entry so it can be restored within
+/// [`ENTRY_RESTORE_WINDOW_DAYS`]
 pub async fn delete_tracking_entry(
     pool: &DatabasePool,
-    plant_id: &Uuid,
-    entry_id: &Uuid,
+    plant_id: PlantId,
+    entry_id: &EntryId,
     user_id: &str,
 ) -> Result<(), AppError> {
     // First verify the plant exists and belongs to the user
@@ -492,41 +613,602 @@ pub async fn delete_tracking_entry(
         .await?;
 
     if plant_exists.is_none() {
-        return Err(AppError::NotFound {
-            resource: format!("Plant with id {plant_id}"),
-        });
+        return Err(AppError::plant_not_found());
     }
 
     // Verify the entry exists and belongs to this plant
-    let entry_row =
-        sqlx::query("SELECT entry_type FROM tracking_entries WHERE id = ? AND plant_id = ?")
-            .bind(entry_id.to_string())
-            .bind(plant_id.to_string())
-            .fetch_optional(pool)
+    let entry_row = sqlx::query(
+        "SELECT entry_type, care_task_type, timestamp FROM tracking_entries
+         WHERE id = ? AND plant_id = ? AND deleted_at IS NULL",
+    )
+    .bind(entry_id.to_string())
+    .bind(plant_id.to_string())
+    .fetch_optional(pool)
+    .await?;
+
+    let row = entry_row.ok_or_else(AppError::tracking_entry_not_found)?;
+
+    let entry_type: String = row.get("entry_type");
+    let care_task_type: Option<String> = row.get("care_task_type");
+    let timestamp: String = row.get("timestamp");
+    let entry_uuid = entry_id.0;
+    let entry_id_str = entry_id.to_string();
+    let plant_id_str = plant_id.to_string();
+    let user_id = user_id.to_string();
+    let now_str = Utc::now().to_rfc3339();
+
+    // Hide the entry, roll back the plant's care date if this entry set it
+    // and leave a tombstone for syncing clients, all or nothing
+    with_transaction(pool, move |conn| {
+        Box::pin(async move {
+            sqlx::query(
+                "UPDATE tracking_entries SET deleted_at = ?, updated_at = ?
+                 WHERE id = ? AND plant_id = ? AND deleted_at IS NULL",
+            )
+            .bind(&now_str)
+            .bind(&now_str)
+            .bind(&entry_id_str)
+            .bind(&plant_id_str)
+            .execute(&mut *conn)
             .await?;
 
-    if entry_row.is_none() {
-        return Err(AppError::NotFound {
-            resource: format!("Tracking entry with id {entry_id}"),
-        });
+            if let Some(column) = last_care_column(&entry_type) {
+                let query = format!(
+                    "UPDATE plants SET {column} = (
+                        SELECT timestamp FROM tracking_entries
+                        WHERE plant_id = ? AND entry_type = ? AND deleted_at IS NULL
+                        ORDER BY julianday(timestamp) DESC
+                        LIMIT 1
+                     ), updated_at = ?
+                     WHERE id = ? AND julianday({column}) = julianday(?)"
+                );
+                sqlx::query(&query)
+                    .bind(&plant_id_str)
+                    .bind(&entry_type)
+                    .bind(&now_str)
+                    .bind(&plant_id_str)
+                    .bind(&timestamp)
+                    .execute(&mut *conn)
+                    .await?;
+            }
+
+            if let Some(task_type) = &care_task_type {
+                sqlx::query(
+                    "UPDATE care_tasks SET last_done = (
+                        SELECT timestamp FROM tracking_entries
+                        WHERE plant_id = ? AND entry_type = 'care' AND care_task_type = ?
+                          AND deleted_at IS NULL
+                        ORDER BY julianday(timestamp) DESC
+                        LIMIT 1
+                     ), updated_at = ?
+                     WHERE plant_id = ? AND task_type = ? AND julianday(last_done) = julianday(?)",
+                )
+                .bind(&plant_id_str)
+                .bind(task_type)
+                .bind(&now_str)
+                .bind(&plant_id_str)
+                .bind(task_type)
+                .bind(&timestamp)
+                .execute(&mut *conn)
+                .await?;
+            }
+
+            deletions::record_deletion(
+                &mut *conn,
+                DeletedEntityType::TrackingEntry,
+                &entry_uuid,
+                &user_id,
+            )
+            .await?;
+
+            Ok(())
+        })
+    })
+    .await
+}
+
+/// Delete every entry on the plant matching all of `filter`'s filters, rolling
+/// back care dates that were set by a deleted entry. Returns the number deleted.
+pub async fn delete_tracking_entries(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    user_id: &str,
+    filter: &DeleteEntriesRequest,
+) -> Result<u64, AppError> {
+    // First verify the plant exists and belongs to the user
+    let plant_exists = sqlx::query("SELECT 1 FROM plants WHERE id = ? AND user_id = ?")
+        .bind(plant_id.to_string())
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?;
+
+    if plant_exists.is_none() {
+        return Err(AppError::plant_not_found());
+    }
+
+    let plant_id_str = plant_id.to_string();
+    let mut conditions = vec!["plant_id = ?", "deleted_at IS NULL"];
+    let mut binds = vec![plant_id_str.clone()];
+    if let Some(entry_type) = &filter.entry_type {
+        conditions.push("entry_type = ?");
+        binds.push(entry_type_str(entry_type).to_string());
+        if let Some(task_type) = care_task_type(entry_type) {
+            conditions.push("care_task_type = ?");
+            binds.push(task_type.to_string());
+        }
+    }
+    if let Some(from) = filter.from {
+        conditions.push("julianday(timestamp) >= julianday(?)");
+        binds.push(from.to_rfc3339());
+    }
+    if let Some(to) = filter.to {
+        conditions.push("julianday(timestamp) < julianday(?)");
+        binds.push(to.to_rfc3339());
     }
+    let ids_condition;
+    if let Some(ids) = &filter.ids {
+        ids_condition = format!("id IN ({})", vec!["?"; ids.len()].join(", "));
+        conditions.push(&ids_condition);
+        binds.extend(ids.iter().map(Uuid::to_string));
+    }
+    let where_clause = conditions.join(" AND ");
+    let user_id = user_id.to_string();
+    let now_str = Utc::now().to_rfc3339();
+
+    // Hide the entries, then roll back care dates set by any of them. Entries
+    // hidden here all share `now_str` as their `deleted_at`.
+    with_transaction(pool, move |conn| {
+        Box::pin(async move {
+            let select = format!("SELECT id FROM tracking_entries WHERE {where_clause}");
+            let mut query = sqlx::query(&select);
+            for bind in &binds {
+                query = query.bind(bind);
+            }
+            let ids: Vec<String> = query
+                .fetch_all(&mut *conn)
+                .await?
+                .iter()
+                .map(|row| row.get("id"))
+                .collect();
+            if ids.is_empty() {
+                return Ok(0);
+            }
+
+            let update = format!(
+                "UPDATE tracking_entries SET deleted_at = ?, updated_at = ? WHERE {where_clause}"
+            );
+            let mut query = sqlx::query(&update).bind(&now_str).bind(&now_str);
+            for bind in &binds {
+                query = query.bind(bind);
+            }
+            query.execute(&mut *conn).await?;
+
+            for (entry_type, column) in [
+                ("watering", "last_watered"),
+                ("fertilizing", "last_fertilized"),
+            ] {
+                let query = format!(
+                    "UPDATE plants SET {column} = (
+                        SELECT timestamp FROM tracking_entries
+                        WHERE plant_id = plants.id AND entry_type = ? AND deleted_at IS NULL
+                        ORDER BY julianday(timestamp) DESC
+                        LIMIT 1
+                     ), updated_at = ?
+                     WHERE id = ? AND EXISTS (
+                        SELECT 1 FROM tracking_entries
+                        WHERE plant_id = plants.id AND entry_type = ? AND deleted_at = ?
+                          AND julianday(timestamp) = julianday(plants.{column})
+                     )"
+                );
+                sqlx::query(&query)
+                    .bind(entry_type)
+                    .bind(&now_str)
+                    .bind(&plant_id_str)
+                    .bind(entry_type)
+                    .bind(&now_str)
+                    .execute(&mut *conn)
+                    .await?;
+            }
+
+            sqlx::query(
+                "UPDATE care_tasks SET last_done = (
+                    SELECT timestamp FROM tracking_entries
+                    WHERE plant_id = care_tasks.plant_id AND entry_type = 'care'
+                      AND care_task_type = care_tasks.task_type AND deleted_at IS NULL
+                    ORDER BY julianday(timestamp) DESC
+                    LIMIT 1
+                 ), updated_at = ?
+                 WHERE plant_id = ? AND EXISTS (
+                    SELECT 1 FROM tracking_entries
+                    WHERE plant_id = care_tasks.plant_id AND entry_type = 'care'
+                      AND care_task_type = care_tasks.task_type AND deleted_at = ?
+                      AND julianday(timestamp) = julianday(care_tasks.last_done)
+                 )",
+            )
+            .bind(&now_str)
+            .bind(&plant_id_str)
+            .bind(&now_str)
+            .execute(&mut *conn)
+            .await?;
+
+            for id in &ids {
+                let entry_id = Uuid::parse_str(id).map_err(|_| AppError::Internal {
+                    message: "Invalid tracking entry ID in database".to_string(),
+                })?;
+                deletions::record_deletion(
+                    &mut *conn,
+                    DeletedEntityType::TrackingEntry,
+                    &entry_id,
+                    &user_id,
+                )
+                .await?;
+            }
+
+            Ok(ids.len() as u64)
+        })
+    })
+    .await
+}
 
-    // Delete the tracking entry
-    let result = sqlx::query("DELETE FROM tracking_entries WHERE id = ? AND plant_id = ?")
-        .bind(entry_id.to_string())
+/// Restore a soft-deleted tracking entry that is still inside the restore window
+pub async fn restore_tracking_entry(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    entry_id: &EntryId,
+    user_id: &str,
+) -> Result<TrackingEntry, AppError> {
+    // First verify the plant exists and belongs to the user
+    let plant_exists = sqlx::query("SELECT 1 FROM plants WHERE id = ? AND user_id = ?")
         .bind(plant_id.to_string())
-        .execute(pool)
+        .bind(user_id)
+        .fetch_optional(pool)
         .await?;
 
-    if result.rows_affected() == 0 {
-        return Err(AppError::NotFound {
-            resource: format!("Tracking entry with id {entry_id}"),
-        });
+    if plant_exists.is_none() {
+        return Err(AppError::plant_not_found());
+    }
+
+    let window_start =
+        (Utc::now() - chrono::Duration::days(ENTRY_RESTORE_WINDOW_DAYS)).to_rfc3339();
+    let entry_uuid = entry_id.0;
+    let entry_id_str = entry_id.to_string();
+    let plant_id_str = plant_id.to_string();
+    let now_str = Utc::now().to_rfc3339();
+
+    // Bring the entry back, move the plant's care date forward if it is newer
+    // and drop its tombstone. The window is checked in the same transaction so
+    // a concurrent purge or restore can't slip in between.
+    with_transaction(pool, move |conn| {
+        Box::pin(async move {
+            let row = sqlx::query(
+                "SELECT entry_type, care_task_type, timestamp FROM tracking_entries
+                 WHERE id = ? AND plant_id = ? AND deleted_at IS NOT NULL
+                 AND julianday(deleted_at) >= julianday(?)",
+            )
+            .bind(&entry_id_str)
+            .bind(&plant_id_str)
+            .bind(&window_start)
+            .fetch_optional(&mut *conn)
+            .await?
+            .ok_or_else(AppError::tracking_entry_not_found)?;
+
+            let entry_type: String = row.get("entry_type");
+            let care_task_type: Option<String> = row.get("care_task_type");
+            let timestamp: String = row.get("timestamp");
+
+            let result = sqlx::query(
+                "UPDATE tracking_entries SET deleted_at = NULL, updated_at = ?
+                 WHERE id = ? AND plant_id = ? AND deleted_at IS NOT NULL
+                 AND julianday(deleted_at) >= julianday(?)",
+            )
+            .bind(&now_str)
+            .bind(&entry_id_str)
+            .bind(&plant_id_str)
+            .bind(&window_start)
+            .execute(&mut *conn)
+            .await?;
+
+            if result.rows_affected() == 0 {
+                return Err(AppError::tracking_entry_not_found());
+            }
+
+            if let Some(column) = last_care_column(&entry_type) {
+                let query = format!(
+                    "UPDATE plants SET {column} = ?, updated_at = ?
+                     WHERE id = ? AND ({column} IS NULL OR julianday({column}) < julianday(?))"
+                );
+                sqlx::query(&query)
+                    .bind(&timestamp)
+                    .bind(&now_str)
+                    .bind(&plant_id_str)
+                    .bind(&timestamp)
+                    .execute(&mut *conn)
+                    .await?;
+            }
+
+            if let Some(task_type) = &care_task_type {
+                advance_care_task(conn, &plant_id_str, task_type, &timestamp, &now_str).await?;
+            }
+
+            deletions::clear_deletion(&mut *conn, DeletedEntityType::TrackingEntry, &entry_uuid)
+                .await
+        })
+    })
+    .await?;
+
+    get_tracking_entry(pool, plant_id, entry_id, user_id).await
+}
+
+/// Insert imported entries in one transaction, returning how many were added
+///
+/// Plant care dates only move forward, since imported history is usually older
+/// than what has been tracked since. With `validate_only` the plant is still
+/// checked but nothing is written, and the count is what would have been added.
+pub async fn import_tracking_entries(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    user_id: &str,
+    entries: Vec<CreateTrackingEntryRequest>,
+    validate_only: bool,
+) -> Result<u64, AppError> {
+    // First verify the plant exists and belongs to the user
+    let plant_exists = sqlx::query("SELECT 1 FROM plants WHERE id = ? AND user_id = ?")
+        .bind(plant_id.to_string())
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?;
+
+    if plant_exists.is_none() {
+        return Err(AppError::plant_not_found());
+    }
+
+    let imported = entries.len() as u64;
+    if validate_only {
+        return Ok(imported);
+    }
+
+    let plant_id_str = plant_id.to_string();
+    let now_str = Utc::now().to_rfc3339();
+
+    with_transaction(pool, move |conn| {
+        Box::pin(async move {
+            for entry in &entries {
+                let entry_type = entry_type_str(&entry.entry_type);
+                let care_task_type = care_task_type(&entry.entry_type);
+                let timestamp = entry.timestamp.to_rfc3339();
+
+                sqlx::query(
+                    "INSERT INTO tracking_entries (id, plant_id, entry_type, care_task_type, timestamp, notes, created_at, updated_at)
+                     VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                )
+                .bind(Uuid::new_v4().to_string())
+                .bind(&plant_id_str)
+                .bind(entry_type)
+                .bind(care_task_type)
+                .bind(&timestamp)
+                .bind(&entry.notes)
+                .bind(&now_str)
+                .bind(&now_str)
+                .execute(&mut *conn)
+                .await?;
+
+                if let Some(column) = last_care_column(entry_type) {
+                    let query = format!(
+                        "UPDATE plants SET {column} = ?, updated_at = ?
+                         WHERE id = ? AND ({column} IS NULL OR julianday({column}) < julianday(?))"
+                    );
+                    sqlx::query(&query)
+                        .bind(&timestamp)
+                        .bind(&now_str)
+                        .bind(&plant_id_str)
+                        .bind(&timestamp)
+                        .execute(&mut *conn)
+                        .await?;
+                }
+
+                if let Some(task_type) = care_task_type {
+                    advance_care_task(conn, &plant_id_str, task_type, &timestamp, &now_str)
+                        .await?;
+                }
+            }
+
+            Ok(())
+        })
+    })
+    .await?;
+
+    Ok(imported)
+}
+
+/// Count a plant's entries per period and entry type, oldest period first
+///
+/// Weeks begin on `week_start`; periods are computed in UTC.
+pub async fn rollup_entries(
+    pool: &DatabasePool,
+    plant_id: PlantId,
+    user_id: &str,
+    granularity: RollupGranularity,
+    week_start: WeekStart,
+) -> Result<Vec<EntryRollupBucket>, AppError> {
+    // First verify the plant exists and belongs to the user
+    let plant_exists = sqlx::query("SELECT 1 FROM plants WHERE id = ? AND user_id = ?")
+        .bind(plant_id.to_string())
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?;
+
+    if plant_exists.is_none() {
+        return Err(AppError::plant_not_found());
     }
 
+    let period_start = match granularity {
+        RollupGranularity::Day => "date(timestamp)".to_string(),
+        // Step back to the most recent week start on or before the entry
+        RollupGranularity::Week => format!(
+            "date(timestamp, '-6 days', 'weekday {}')",
+            week_start.sqlite_weekday()
+        ),
+        RollupGranularity::Month => "strftime('%Y-%m-01', timestamp)".to_string(),
+    };
+    let query = format!(
+        "SELECT {period_start} AS period_start, entry_type, care_task_type, COUNT(*) AS count
+         FROM tracking_entries
+         WHERE plant_id = ? AND deleted_at IS NULL
+         GROUP BY period_start, entry_type, care_task_type
+         ORDER BY period_start, entry_type, care_task_type"
+    );
+
+    let rows = sqlx::query(&query)
+        .bind(plant_id.to_string())
+        .fetch_all(pool)
+        .await?;
+
+    rows.iter()
+        .map(|row| {
+            let period_start: String = row.get("period_start");
+            Ok(EntryRollupBucket {
+                period_start: period_start.parse().map_err(|_| AppError::Internal {
+                    message: "Invalid datetime in database".to_string(),
+                })?,
+                entry_type: parse_entry_type(
+                    &row.get::<String, _>("entry_type"),
+                    row.get("care_task_type"),
+                ),
+                count: row.get("count"),
+            })
+        })
+        .collect()
+}
+
+/// Count a user's entries of `entry_type` across all their plants since `since`
+pub async fn count_user_entries_since(
+    pool: &DatabasePool,
+    user_id: &str,
+    entry_type: &EntryType,
+    since: DateTime<Utc>,
+) -> Result<i64, AppError> {
+    let count = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM tracking_entries te
+         JOIN plants p ON p.id = te.plant_id
+         WHERE p.user_id = ? AND te.entry_type = ? AND te.deleted_at IS NULL
+           AND julianday(te.timestamp) >= julianday(?)",
+    )
+    .bind(user_id)
+    .bind(entry_type_str(entry_type))
+    .bind(since.to_rfc3339())
+    .fetch_one(pool)
+    .await?;
+
+    Ok(count)
+}
+
+/// A user's watering and fertilizing entries across all their plants since
+/// `since`, newest first
+pub async fn get_user_care_history(
+    pool: &DatabasePool,
+    user_id: &str,
+    since: DateTime<Utc>,
+) -> Result<Vec<TrackingEntry>, AppError> {
+    let rows = sqlx::query(
+        "SELECT te.id, te.plant_id, te.entry_type, te.care_task_type, te.timestamp, te.value,
+                te.notes, te.metric_id, te.photo_ids, te.visibility, te.created_at, te.updated_at
+         FROM tracking_entries te
+         JOIN plants p ON p.id = te.plant_id
+         WHERE p.user_id = ? AND te.entry_type IN ('watering', 'fertilizing')
+           AND te.deleted_at IS NULL AND julianday(te.timestamp) >= julianday(?)
+         ORDER BY te.timestamp DESC, te.id DESC",
+    )
+    .bind(user_id)
+    .bind(since.to_rfc3339())
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.iter().map(tracking_entry_from_row).collect())
+}
+
+/// Hard-delete entries soft-deleted before `cutoff`, returning how many were purged
+pub async fn purge_deleted_entries_before(
+    pool: &DatabasePool,
+    cutoff: DateTime<Utc>,
+) -> Result<u64, AppError> {
+    let result = sqlx::query(
+        "DELETE FROM tracking_entries
+         WHERE deleted_at IS NOT NULL AND julianday(deleted_at) < julianday(?)",
+    )
+    .bind(cutoff.to_rfc3339())
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+/// How an entry type is stored in `tracking_entries.entry_type`
+fn entry_type_str(entry_type: &EntryType) -> &'static str {
+    match entry_type {
+        EntryType::Watering => "watering",
+        EntryType::Fertilizing => "fertilizing",
+        EntryType::CustomMetric => "measurement",
+        EntryType::Note => "note",
+        EntryType::Photo => "photo",
+        EntryType::Care { .. } => "care",
+    }
+}
+
+/// The care task a care entry is for, stored in `tracking_entries.care_task_type`
+fn care_task_type(entry_type: &EntryType) -> Option<&str> {
+    match entry_type {
+        EntryType::Care { task_type } => Some(task_type),
+        _ => None,
+    }
+}
+
+/// Inverse of `entry_type_str` and `care_task_type`
+fn parse_entry_type(entry_type: &str, care_task_type: Option<String>) -> EntryType {
+    match entry_type {
+        "watering" => EntryType::Watering,
+        "fertilizing" => EntryType::Fertilizing,
+        "measurement" => EntryType::CustomMetric,
+        "note" => EntryType::Note,
+        "photo" => EntryType::Photo,
+        "care" => EntryType::Care {
+            task_type: care_task_type.unwrap_or_default(),
+        },
+        _ => EntryType::Watering, // fallback
+    }
+}
+
+/// Move a care task's `last_done` forward to `timestamp` if that is newer
+async fn advance_care_task(
+    conn: &mut SqliteConnection,
+    plant_id: &str,
+    task_type: &str,
+    timestamp: &str,
+    now: &str,
+) -> Result<(), AppError> {
+    sqlx::query(
+        "UPDATE care_tasks SET last_done = ?, updated_at = ?
+         WHERE plant_id = ? AND task_type = ?
+           AND (last_done IS NULL OR julianday(last_done) < julianday(?))",
+    )
+    .bind(timestamp)
+    .bind(now)
+    .bind(plant_id)
+    .bind(task_type)
+    .bind(timestamp)
+    .execute(conn)
+    .await?;
     Ok(())
 }
 
+/// The plant column tracking the latest entry of this type, if any
+fn last_care_column(entry_type: &str) -> Option<&'static str> {
+    match entry_type {
+        "watering" => Some("last_watered"),
+        "fertilizing" => Some("last_fertilized"),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -544,9 +1226,9 @@ mod tests {
         pool
     }
 
-    async fn create_test_user_and_plant(pool: &DatabasePool) -> (String, Uuid) {
+    async fn create_test_user_and_plant(pool: &DatabasePool) -> (String, PlantId) {
         let user_id = Uuid::new_v4().to_string();
-        let plant_id = Uuid::new_v4();
+        let plant_id = PlantId(Uuid::new_v4());
         let now = Utc::now().to_rfc3339();
 
         // Create user
@@ -590,7 +1272,7 @@ mod tests {
         let pool = setup_test_db().await;
         let (user_id, plant_id) = create_test_user_and_plant(&pool).await;
 
-        let result = get_tracking_entries_for_plant(&pool, &plant_id, &user_id).await;
+        let result = get_tracking_entries_for_plant(&pool, plant_id, &user_id).await;
         assert!(result.is_ok());
 
         let response = result.unwrap();
@@ -610,13 +1292,14 @@ mod tests {
             notes: Some("Test watering".to_string()),
             metric_id: None,
             photo_ids: None,
+            visibility: None,
         };
 
-        let result = create_tracking_entry(&pool, &plant_id, &user_id, &request).await;
+        let result = create_tracking_entry(&pool, plant_id, &user_id, &request).await;
         assert!(result.is_ok());
 
         let entry = result.unwrap();
-        assert_eq!(entry.plant_id, plant_id);
+        assert_eq!(entry.plant_id, plant_id.0);
         assert!(matches!(entry.entry_type, EntryType::Watering));
         assert_eq!(entry.notes, Some("Test watering".to_string()));
     }
@@ -634,18 +1317,19 @@ mod tests {
             notes: None,
             metric_id: None,
             photo_ids: None,
+            visibility: None,
         };
 
-        let entry = create_tracking_entry(&pool, &plant_id, &user_id, &request)
+        let entry = create_tracking_entry(&pool, plant_id, &user_id, &request)
             .await
             .expect("Failed to create tracking entry");
 
         // Delete entry
-        let result = delete_tracking_entry(&pool, &plant_id, &entry.id, &user_id).await;
+        let result = delete_tracking_entry(&pool, plant_id, &EntryId(entry.id), &user_id).await;
         assert!(result.is_ok());
 
         // Verify entry is deleted
-        let entries = get_tracking_entries_for_plant(&pool, &plant_id, &user_id)
+        let entries = get_tracking_entries_for_plant(&pool, plant_id, &user_id)
             .await
             .expect("Failed to get tracking entries");
         assert_eq!(entries.entries.len(), 0);
@@ -664,16 +1348,17 @@ mod tests {
             notes: Some("Growth observation with photos".to_string()),
             metric_id: None,
             photo_ids: Some(photo_ids.clone()),
+            visibility: None,
         };
 
-        let result = create_tracking_entry(&pool, &plant_id, &user_id, &request).await;
+        let result = create_tracking_entry(&pool, plant_id, &user_id, &request).await;
         if result.is_err() {
             eprintln!("Error creating note entry: {:?}", result);
         }
         assert!(result.is_ok());
 
         let entry = result.unwrap();
-        assert_eq!(entry.plant_id, plant_id);
+        assert_eq!(entry.plant_id, plant_id.0);
         assert!(matches!(entry.entry_type, EntryType::Note));
         assert_eq!(entry.notes, Some("Growth observation with photos".to_string()));
         
@@ -699,19 +1384,20 @@ mod tests {
             notes: Some("Spring fertilizer".to_string()),
             metric_id: None,
             photo_ids: None,
+            visibility: None,
         };
 
-        let created_entry = create_tracking_entry(&pool, &plant_id, &user_id, &request)
+        let created_entry = create_tracking_entry(&pool, plant_id, &user_id, &request)
             .await
             .expect("Failed to create tracking entry");
 
         // Retrieve the entry
-        let result = get_tracking_entry(&pool, &plant_id, &created_entry.id, &user_id).await;
+        let result = get_tracking_entry(&pool, plant_id, &EntryId(created_entry.id), &user_id).await;
         assert!(result.is_ok());
 
         let retrieved_entry = result.unwrap();
         assert_eq!(retrieved_entry.id, created_entry.id);
-        assert_eq!(retrieved_entry.plant_id, plant_id);
+        assert_eq!(retrieved_entry.plant_id, plant_id.0);
         assert!(matches!(retrieved_entry.entry_type, EntryType::Fertilizing));
         assert_eq!(retrieved_entry.notes, Some("Spring fertilizer".to_string()));
     }
@@ -721,12 +1407,12 @@ mod tests {
         let pool = setup_test_db().await;
         let (user_id, plant_id) = create_test_user_and_plant(&pool).await;
 
-        let non_existent_id = Uuid::new_v4();
-        let result = get_tracking_entry(&pool, &plant_id, &non_existent_id, &user_id).await;
+        let non_existent_id = EntryId(Uuid::new_v4());
+        let result = get_tracking_entry(&pool, plant_id, &non_existent_id, &user_id).await;
         assert!(result.is_err());
         
         if let Err(AppError::NotFound { resource }) = result {
-            assert!(resource.contains(&non_existent_id.to_string()));
+            assert_eq!(resource, "Tracking entry");
         } else {
             panic!("Expected NotFound error");
         }
@@ -745,9 +1431,10 @@ mod tests {
             notes: Some("Initial note".to_string()),
             metric_id: None,
             photo_ids: None,
+            visibility: None,
         };
 
-        let created_entry = create_tracking_entry(&pool, &plant_id, &user_id, &request)
+        let created_entry = create_tracking_entry(&pool, plant_id, &user_id, &request)
             .await
             .expect("Failed to create tracking entry");
 
@@ -759,9 +1446,10 @@ mod tests {
             value: None,
             notes: Some("Updated note with more details".to_string()),
             photo_ids: Some(photo_ids.clone()),
+            visibility: None,
         };
 
-        let result = update_tracking_entry(&pool, &plant_id, &created_entry.id, &user_id, &update_request).await;
+        let result = update_tracking_entry(&pool, plant_id, &EntryId(created_entry.id), &user_id, &update_request).await;
         assert!(result.is_ok());
 
         let updated_entry = result.unwrap();
@@ -782,24 +1470,67 @@ mod tests {
         let pool = setup_test_db().await;
         let (user_id, plant_id) = create_test_user_and_plant(&pool).await;
 
-        let non_existent_id = Uuid::new_v4();
+        let non_existent_id = EntryId(Uuid::new_v4());
         let update_request = crate::models::tracking_entry::UpdateTrackingEntryRequest {
             timestamp: None,
             value: None,
             notes: Some("This should fail".to_string()),
             photo_ids: None,
+            visibility: None,
         };
 
-        let result = update_tracking_entry(&pool, &plant_id, &non_existent_id, &user_id, &update_request).await;
+        let result = update_tracking_entry(&pool, plant_id, &non_existent_id, &user_id, &update_request).await;
         assert!(result.is_err());
         
         if let Err(AppError::NotFound { resource }) = result {
-            assert!(resource.contains(&non_existent_id.to_string()));
+            assert_eq!(resource, "Tracking entry");
         } else {
             panic!("Expected NotFound error");
         }
     }
 
+    #[tokio::test]
+    async fn test_create_tracking_entry_rolls_back_on_plant_update_failure() {
+        let pool = setup_test_db().await;
+        let (user_id, plant_id) = create_test_user_and_plant(&pool).await;
+
+        // Fail the plant update that follows the entry insert
+        sqlx::query(
+            "CREATE TRIGGER fail_plant_update BEFORE UPDATE ON plants
+             BEGIN SELECT RAISE(ABORT, 'injected failure'); END",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let request = CreateTrackingEntryRequest {
+            entry_type: EntryType::Watering,
+            timestamp: Utc::now(),
+            value: None,
+            notes: Some("Should not persist".to_string()),
+            metric_id: None,
+            photo_ids: None,
+            visibility: None,
+        };
+
+        let result = create_tracking_entry(&pool, plant_id, &user_id, &request).await;
+        assert!(matches!(result, Err(AppError::Database(_))));
+
+        let entry_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM tracking_entries")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(entry_count, 0);
+
+        let last_watered: Option<String> =
+            sqlx::query_scalar("SELECT last_watered FROM plants WHERE id = ?")
+                .bind(plant_id.to_string())
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert!(last_watered.is_none());
+    }
+
     #[tokio::test]
     async fn test_create_custom_metric_entry() {
         let pool = setup_test_db().await;
@@ -830,16 +1561,17 @@ mod tests {
             notes: Some("Plant height measurement".to_string()),
             metric_id: Some(metric_id),
             photo_ids: None,
+            visibility: None,
         };
 
-        let result = create_tracking_entry(&pool, &plant_id, &user_id, &request).await;
+        let result = create_tracking_entry(&pool, plant_id, &user_id, &request).await;
         if result.is_err() {
             eprintln!("Error creating custom metric entry: {:?}", result);
         }
         assert!(result.is_ok());
 
         let entry = result.unwrap();
-        assert_eq!(entry.plant_id, plant_id);
+        assert_eq!(entry.plant_id, plant_id.0);
         assert!(matches!(entry.entry_type, EntryType::CustomMetric));
         assert_eq!(entry.metric_id, Some(metric_id));
         assert!(entry.value.is_some());
@@ -862,16 +1594,17 @@ mod tests {
             notes: None,
             metric_id: None,
             photo_ids: Some(photo_ids.clone()),
+            visibility: None,
         };
 
-        let result = create_tracking_entry(&pool, &plant_id, &user_id, &request).await;
+        let result = create_tracking_entry(&pool, plant_id, &user_id, &request).await;
         if result.is_err() {
             eprintln!("Error creating photo entry: {:?}", result);
         }
         assert!(result.is_ok());
 
         let entry = result.unwrap();
-        assert_eq!(entry.plant_id, plant_id);
+        assert_eq!(entry.plant_id, plant_id.0);
         assert!(matches!(entry.entry_type, EntryType::Photo));
         
         // Verify photo_ids are stored correctly
@@ -932,18 +1665,19 @@ mod tests {
             notes: Some("User 1 watering".to_string()),
             metric_id: None,
             photo_ids: None,
+            visibility: None,
         };
 
-        let entry1 = create_tracking_entry(&pool, &plant1_id, &user1_id, &request1)
+        let entry1 = create_tracking_entry(&pool, plant1_id, &user1_id, &request1)
             .await
             .expect("Failed to create entry for user 1");
 
         // User 2 should not be able to access user 1's entry
-        let result = get_tracking_entry(&pool, &plant1_id, &entry1.id, &user2_id).await;
+        let result = get_tracking_entry(&pool, plant1_id, &EntryId(entry1.id), &user2_id).await;
         assert!(result.is_err());
         
         // User 2 should not see user 1's entries when listing
-        let entries_result = get_tracking_entries_for_plant(&pool, &plant1_id, &user2_id).await;
+        let entries_result = get_tracking_entries_for_plant(&pool, plant1_id, &user2_id).await;
         assert!(entries_result.is_err());
     }
 }
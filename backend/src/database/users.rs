@@ -1,10 +1,13 @@
 use anyhow::Result;
 use bcrypt::{hash, verify, DEFAULT_COST};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use sqlx::{Row, SqliteExecutor};
 use uuid::Uuid;
 
-use crate::database::DatabasePool;
-use crate::models::{CreateUserRequest, User, UserRow, UserRole};
+use crate::database::{admin_settings, DatabasePool};
+use crate::models::{
+    CareDay, CareTiming, CreateUserRequest, User, UserPreferences, UserRow, UserRole, WeekStart,
+};
 use crate::utils::errors::AppError;
 
 pub async fn create_user(
@@ -97,7 +100,7 @@ async fn get_default_invite_limit(pool: &DatabasePool) -> Result<i32, AppError>
 
     Ok(limit
         .and_then(|v| v.parse::<i32>().ok())
-        .unwrap_or(5))
+        .unwrap_or(admin_settings::DEFAULT_USER_INVITE_LIMIT))
 }
 
 async fn get_max_total_users(pool: &DatabasePool) -> Result<i32, AppError> {
@@ -110,7 +113,7 @@ async fn get_max_total_users(pool: &DatabasePool) -> Result<i32, AppError> {
 
     Ok(max_users
         .and_then(|v| v.parse::<i32>().ok())
-        .unwrap_or(1000))
+        .unwrap_or(admin_settings::DEFAULT_MAX_TOTAL_USERS))
 }
 
 pub async fn get_user_by_id(pool: &DatabasePool, user_id: &str) -> Result<User, AppError> {
@@ -125,9 +128,7 @@ pub async fn get_user_by_id(pool: &DatabasePool, user_id: &str) -> Result<User,
 
     user_row.map_or_else(
         || {
-            Err(AppError::NotFound {
-                resource: format!("User with id {user_id}"),
-            })
+            Err(AppError::user_not_found())
         },
         UserRow::to_user,
     )
@@ -143,14 +144,7 @@ pub async fn get_user_by_email(pool: &DatabasePool, email: &str) -> Result<User,
             AppError::Database(e)
         })?;
 
-    user_row.map_or_else(
-        || {
-            Err(AppError::NotFound {
-                resource: format!("User with email {email}"),
-            })
-        },
-        UserRow::to_user,
-    )
+    user_row.map_or_else(|| Err(AppError::user_not_found()), UserRow::to_user)
 }
 
 pub async fn verify_password(
@@ -185,8 +179,126 @@ pub async fn update_user_login_time(pool: &DatabasePool, user_id: &str) -> Resul
         })?;
 
     if result.rows_affected() != 1 {
-        return Err(AppError::NotFound {
-            resource: format!("User with id {user_id}"),
+        return Err(AppError::user_not_found());
+    }
+
+    Ok(())
+}
+
+pub async fn get_week_start(pool: &DatabasePool, user_id: &str) -> Result<WeekStart, AppError> {
+    let week_start: String = sqlx::query_scalar("SELECT week_start FROM users WHERE id = ?")
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(AppError::user_not_found)?;
+
+    week_start
+        .parse()
+        .map_err(|message| AppError::Internal { message })
+}
+
+/// Load the user's display and scheduling preferences
+pub async fn get_preferences(
+    pool: &DatabasePool,
+    user_id: &str,
+) -> Result<UserPreferences, AppError> {
+    let row = sqlx::query(
+        "SELECT week_start, care_hour, avoid_weekends, utc_offset_minutes FROM users WHERE id = ?",
+    )
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(AppError::user_not_found)?;
+
+    Ok(UserPreferences {
+        week_start: row
+            .get::<String, _>("week_start")
+            .parse()
+            .map_err(|message| AppError::Internal { message })?,
+        care_hour: row.get("care_hour"),
+        avoid_weekends: row.get("avoid_weekends"),
+        utc_offset_minutes: row.get("utc_offset_minutes"),
+    })
+}
+
+/// The user's care timing preferences, used when scheduling reminders
+pub async fn get_care_timing(pool: &DatabasePool, user_id: &str) -> Result<CareTiming, AppError> {
+    Ok(get_preferences(pool, user_id).await?.care_timing())
+}
+
+/// The user's calendar day, used when judging whether care is overdue
+pub async fn get_care_day(pool: &DatabasePool, user_id: &str) -> Result<CareDay, AppError> {
+    Ok(get_preferences(pool, user_id).await?.care_day())
+}
+
+pub async fn set_preferences(
+    pool: &DatabasePool,
+    user_id: &str,
+    preferences: &UserPreferences,
+) -> Result<(), AppError> {
+    let result = sqlx::query(
+        "UPDATE users SET week_start = ?, care_hour = ?, avoid_weekends = ?,
+         utc_offset_minutes = ?, updated_at = ?
+         WHERE id = ?",
+    )
+    .bind(preferences.week_start.as_str())
+    .bind(preferences.care_hour)
+    .bind(preferences.avoid_weekends)
+    .bind(preferences.utc_offset_minutes)
+    .bind(Utc::now().to_rfc3339())
+    .bind(user_id)
+    .execute(pool)
+    .await?;
+
+    if result.rows_affected() != 1 {
+        return Err(AppError::user_not_found());
+    }
+
+    Ok(())
+}
+
+/// Note that a calendar app just fetched the user's iCalendar feed
+pub async fn record_calendar_feed_fetch(pool: &DatabasePool, user_id: &str) -> Result<(), AppError> {
+    sqlx::query("UPDATE users SET calendar_feed_fetched_at = ? WHERE id = ?")
+        .bind(Utc::now().to_rfc3339())
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// When a calendar app last fetched the user's iCalendar feed, if ever
+pub async fn get_calendar_feed_fetched_at(
+    pool: &DatabasePool,
+    user_id: &str,
+) -> Result<Option<DateTime<Utc>>, AppError> {
+    let fetched_at: Option<String> =
+        sqlx::query_scalar("SELECT calendar_feed_fetched_at FROM users WHERE id = ?")
+            .bind(user_id)
+            .fetch_optional(pool)
+            .await?
+            .flatten();
+
+    Ok(fetched_at
+        .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+        .map(|dt| dt.with_timezone(&Utc)))
+}
+
+/// Ensure at least one admin remains if `user_ids` lose their admin role or are deleted
+pub async fn ensure_admin_remains<'e>(
+    executor: impl SqliteExecutor<'e>,
+    user_ids: &[String],
+) -> Result<(), AppError> {
+    let admin_role = UserRole::Admin.to_string();
+    let admin_ids: Vec<String> = sqlx::query_scalar("SELECT id FROM users WHERE role = ?")
+        .bind(admin_role)
+        .fetch_all(executor)
+        .await?;
+
+    if admin_ids.iter().all(|id| user_ids.contains(id)) {
+        return Err(AppError::BadRequest {
+            message: "Cannot remove the last remaining admin".to_string(),
         });
     }
 
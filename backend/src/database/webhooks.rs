@@ -0,0 +1,112 @@
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+
+use crate::database::DatabasePool;
+use crate::utils::errors::{AppError, Result};
+use crate::utils::webhooks::generate_secret;
+
+/// A user's configured webhook endpoint
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    pub url: String,
+    pub secret: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+pub async fn get_webhook(pool: &DatabasePool, user_id: &str) -> Result<Option<Webhook>> {
+    let row = sqlx::query(
+        "SELECT url, secret, created_at, updated_at FROM user_webhooks WHERE user_id = ?",
+    )
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?;
+
+    row.map(|row| {
+        let parse = |column: &str| {
+            row.get::<String, _>(column)
+                .parse::<DateTime<Utc>>()
+                .map_err(|_| AppError::Internal {
+                    message: "Invalid datetime in database".to_string(),
+                })
+        };
+
+        Ok(Webhook {
+            url: row.get("url"),
+            secret: row.get("secret"),
+            created_at: parse("created_at")?,
+            updated_at: parse("updated_at")?,
+        })
+    })
+    .transpose()
+}
+
+/// Set the webhook URL, generating a secret the first time one is configured.
+///
+/// Returns the webhook and whether its secret was just created.
+pub async fn set_webhook_url(
+    pool: &DatabasePool,
+    user_id: &str,
+    url: &str,
+) -> Result<(Webhook, bool)> {
+    let now = Utc::now().to_rfc3339();
+
+    let result = sqlx::query("UPDATE user_webhooks SET url = ?, updated_at = ? WHERE user_id = ?")
+        .bind(url)
+        .bind(&now)
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+
+    let created = result.rows_affected() == 0;
+    if created {
+        sqlx::query(
+            "INSERT INTO user_webhooks (user_id, url, secret, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(user_id)
+        .bind(url)
+        .bind(generate_secret())
+        .bind(&now)
+        .bind(&now)
+        .execute(pool)
+        .await?;
+    }
+
+    let webhook = get_webhook(pool, user_id)
+        .await?
+        .ok_or_else(AppError::webhook_not_found)?;
+    Ok((webhook, created))
+}
+
+/// Replace the signing secret, returning the new one
+pub async fn rotate_webhook_secret(pool: &DatabasePool, user_id: &str) -> Result<String> {
+    let secret = generate_secret();
+
+    let result =
+        sqlx::query("UPDATE user_webhooks SET secret = ?, updated_at = ? WHERE user_id = ?")
+            .bind(&secret)
+            .bind(Utc::now().to_rfc3339())
+            .bind(user_id)
+            .execute(pool)
+            .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::webhook_not_found());
+    }
+
+    Ok(secret)
+}
+
+pub async fn delete_webhook(pool: &DatabasePool, user_id: &str) -> Result<()> {
+    let result = sqlx::query("DELETE FROM user_webhooks WHERE user_id = ?")
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::webhook_not_found());
+    }
+
+    Ok(())
+}
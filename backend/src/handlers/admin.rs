@@ -1,20 +1,35 @@
 use axum::{
-    extract::{Query, State},
+    http::StatusCode,
+    extract::{OriginalUri, Query, State},
+    middleware,
     response::Json,
     routing::{delete, get, post, put},
     Json as JsonExtractor, Router,
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 
 use crate::{
     admin::{get_system_stats, SystemStats},
     app_state::AppState,
-    auth::AuthSession,
+    database::{
+        self,
+        admin_settings::{AdminSettings, SettingSource},
+    },
+    middleware::require_admin::{require_admin, AdminUser},
+    middleware::validation::ValidatedJson,
     models::user::{UserResponse, UserRole},
     utils::errors::{AppError, Result},
+    utils::job_registry::JobStatus,
+    utils::mailer::Email,
+    utils::pagination::{page_links, PageLinks},
+    utils::photo_store,
+    utils::thumbnail_backfill::BackfillProgress,
 };
 
 #[derive(Debug, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AdminDashboardResponse {
     pub system_stats: SystemStats,
     pub recent_users: Vec<UserResponse>,
@@ -22,6 +37,7 @@ pub struct AdminDashboardResponse {
 }
 
 #[derive(Debug, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct InviteInfo {
     pub id: String,
     pub code: String,
@@ -30,8 +46,8 @@ pub struct InviteInfo {
     pub max_uses: i32,
     pub current_uses: i32,
     pub is_active: bool,
-    pub expires_at: Option<String>,
-    pub created_at: String,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -42,15 +58,21 @@ pub struct UserListQuery {
 }
 
 #[derive(Debug, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserListResponse {
     pub users: Vec<UserResponse>,
     pub total: i32,
     pub page: i32,
     pub limit: i32,
     pub total_pages: i32,
+    /// Relative URL of the next page, `null` on the last page
+    pub next: Option<String>,
+    /// Relative URL of the previous page, `null` on the first page
+    pub prev: Option<String>,
 }
 
 #[derive(Debug, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UpdateUserRequest {
     pub role: Option<UserRole>,
     pub can_create_invites: Option<bool>,
@@ -58,25 +80,148 @@ pub struct UpdateUserRequest {
 }
 
 #[derive(Debug, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AdminSettingsResponse {
     pub max_total_users: i32,
     pub default_user_invite_limit: i32,
     pub registration_enabled: bool,
 }
 
+impl From<AdminSettings> for AdminSettingsResponse {
+    fn from(settings: AdminSettings) -> Self {
+        Self {
+            max_total_users: settings.max_total_users.value,
+            default_user_invite_limit: settings.default_user_invite_limit.value,
+            registration_enabled: settings.registration_enabled.value,
+        }
+    }
+}
+
+/// A numeric setting's effective value and where it came from
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EffectiveNumberSetting {
+    pub value: i32,
+    pub source: SettingSource,
+}
+
+/// An on/off setting's effective value and where it came from
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EffectiveFlagSetting {
+    pub value: bool,
+    pub source: SettingSource,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EffectiveAdminSettingsResponse {
+    pub max_total_users: EffectiveNumberSetting,
+    pub default_user_invite_limit: EffectiveNumberSetting,
+    pub registration_enabled: EffectiveFlagSetting,
+}
+
+impl From<AdminSettings> for EffectiveAdminSettingsResponse {
+    fn from(settings: AdminSettings) -> Self {
+        Self {
+            max_total_users: EffectiveNumberSetting {
+                value: settings.max_total_users.value,
+                source: settings.max_total_users.source,
+            },
+            default_user_invite_limit: EffectiveNumberSetting {
+                value: settings.default_user_invite_limit.value,
+                source: settings.default_user_invite_limit.source,
+            },
+            registration_enabled: EffectiveFlagSetting {
+                value: settings.registration_enabled.value,
+                source: settings.registration_enabled.source,
+            },
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UpdateAdminSettingsRequest {
     pub max_total_users: Option<i32>,
     pub default_user_invite_limit: Option<i32>,
     pub registration_enabled: Option<bool>,
 }
 
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JobListResponse {
+    pub jobs: Vec<JobStatus>,
+}
+
 #[derive(Debug, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BulkUserActionRequest {
     pub user_ids: Vec<String>,
     pub action: BulkUserAction,
 }
 
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferPlantsResponse {
+    pub from_user_id: String,
+    pub to_user_id: String,
+    /// Number of plants that changed owner
+    pub transferred: u64,
+}
+
+#[derive(Debug, Deserialize, Validate, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyWaitlistRequest {
+    #[validate(length(min = 1, max = 200))]
+    pub subject: String,
+    /// Plain-text email body
+    #[validate(length(min = 1, max = 10000))]
+    pub body: String,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyWaitlistResponse {
+    /// Entries emailed by this run
+    pub notified: u32,
+    /// Entries whose email failed; they are retried by the next run
+    pub failed: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SessionListQuery {
+    pub page: Option<i32>,
+    pub limit: Option<i32>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminSessionInfo {
+    pub session_id: String,
+    pub user_id: String,
+    /// `null` if the user has since been deleted
+    pub email: Option<String>,
+    /// When the user logged in, `null` if unknown
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Sessions expire after a week without requests
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionListResponse {
+    pub sessions: Vec<AdminSessionInfo>,
+    pub total: i32,
+    pub page: i32,
+    pub limit: i32,
+    pub total_pages: i32,
+    /// Relative URL of the next page, `null` on the last page
+    pub next: Option<String>,
+    /// Relative URL of the previous page, `null` on the first page
+    pub prev: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, utoipa::ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum BulkUserAction {
@@ -86,6 +231,32 @@ pub enum BulkUserAction {
     DisableInvites,
 }
 
+/// Parse a timestamp read from `table`
+///
+/// A malformed value is logged and gives `None`, so the row can be skipped
+/// rather than shown with a made-up date.
+fn parse_stored_timestamp(
+    table: &str,
+    id: &str,
+    column: &str,
+    value: &str,
+) -> Option<DateTime<Utc>> {
+    match value.parse::<DateTime<Utc>>() {
+        Ok(timestamp) => Some(timestamp),
+        Err(e) => {
+            tracing::warn!(
+                "Skipping {} row {}: malformed {} {:?}: {}",
+                table,
+                id,
+                column,
+                value,
+                e
+            );
+            None
+        }
+    }
+}
+
 /// Get admin dashboard data
 #[utoipa::path(
     get,
@@ -98,20 +269,9 @@ pub enum BulkUserAction {
     security(("session" = []))
 )]
 pub async fn get_admin_dashboard(
-    auth_session: AuthSession,
+    _admin: AdminUser,
     State(state): State<AppState>,
 ) -> Result<Json<AdminDashboardResponse>> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Authentication required".to_string(),
-    })?;
-
-    // Check if user is admin
-    if !user.is_admin() {
-        return Err(AppError::Authorization {
-            message: "Admin access required".to_string(),
-        });
-    }
-
     let system_stats = get_system_stats(&state.pool).await?;
 
     // Get recent users (last 10)
@@ -128,8 +288,12 @@ pub async fn get_admin_dashboard(
 
     let recent_users: Vec<UserResponse> = recent_users_rows
         .into_iter()
-        .map(|row| {
-            Ok(UserResponse {
+        .filter_map(|row| {
+            let created_at =
+                parse_stored_timestamp("users", &row.id, "created_at", &row.created_at)?;
+            let updated_at =
+                parse_stored_timestamp("users", &row.id, "updated_at", &row.updated_at)?;
+            Some(UserResponse {
                 id: row.id,
                 email: row.email,
                 name: row.name,
@@ -140,24 +304,17 @@ pub async fn get_admin_dashboard(
                 invites_remaining: row
                     .max_invites
                     .map(|max| (max as i32) - (row.invites_created as i32)),
-                created_at: row
-                    .created_at
-                    .parse::<chrono::DateTime<chrono::Utc>>()
-                    .unwrap_or_default(),
-                updated_at: row
-                    .updated_at
-                    .parse::<chrono::DateTime<chrono::Utc>>()
-                    .unwrap_or_default(),
+                created_at,
+                updated_at,
             })
         })
-        .filter_map(Result::ok)
         .collect();
 
     // Get recent invites (last 10)
     let recent_invites_rows = sqlx::query!(
         r#"
         SELECT 
-            ic.id, ic.code, ic.created_by, ic.max_uses, ic.current_uses, 
+            ic.id as "id!", ic.code, ic.created_by, ic.max_uses, ic.current_uses, 
             ic.is_active, ic.expires_at, ic.created_at,
             u.name as created_by_name
         FROM invite_codes ic
@@ -171,16 +328,29 @@ pub async fn get_admin_dashboard(
 
     let recent_invites: Vec<InviteInfo> = recent_invites_rows
         .into_iter()
-        .map(|row| InviteInfo {
-            id: row.id.unwrap_or_default(),
-            code: row.code,
-            created_by: row.created_by,
-            created_by_name: row.created_by_name,
-            max_uses: row.max_uses as i32,
-            current_uses: row.current_uses as i32,
-            is_active: row.is_active,
-            expires_at: row.expires_at,
-            created_at: row.created_at,
+        .filter_map(|row| {
+            let expires_at = match &row.expires_at {
+                Some(expires_at) => Some(parse_stored_timestamp(
+                    "invite_codes",
+                    &row.id,
+                    "expires_at",
+                    expires_at,
+                )?),
+                None => None,
+            };
+            let created_at =
+                parse_stored_timestamp("invite_codes", &row.id, "created_at", &row.created_at)?;
+            Some(InviteInfo {
+                id: row.id,
+                code: row.code,
+                created_by: row.created_by,
+                created_by_name: row.created_by_name,
+                max_uses: row.max_uses as i32,
+                current_uses: row.current_uses as i32,
+                is_active: row.is_active,
+                expires_at,
+                created_at,
+            })
         })
         .collect();
 
@@ -208,21 +378,11 @@ pub async fn get_admin_dashboard(
     security(("session" = []))
 )]
 pub async fn list_users(
-    auth_session: AuthSession,
+    _admin: AdminUser,
     State(state): State<AppState>,
+    OriginalUri(uri): OriginalUri,
     Query(query): Query<UserListQuery>,
 ) -> Result<Json<UserListResponse>> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Authentication required".to_string(),
-    })?;
-
-    // Check if user is admin
-    if !user.is_admin() {
-        return Err(AppError::Authorization {
-            message: "Admin access required".to_string(),
-        });
-    }
-
     let page = query.page.unwrap_or(1).max(1);
     let limit = query.limit.unwrap_or(20).clamp(1, 100);
     let offset = (page - 1) * limit;
@@ -276,6 +436,7 @@ pub async fn list_users(
     .await?;
 
     let total_pages = (total as f64 / limit as f64).ceil() as i32;
+    let PageLinks { next, prev } = page_links(&uri, page, total_pages);
 
     Ok(Json(UserListResponse {
         users,
@@ -283,6 +444,8 @@ pub async fn list_users(
         page,
         limit,
         total_pages,
+        next,
+        prev,
     }))
 }
 
@@ -296,6 +459,7 @@ pub async fn list_users(
     request_body = UpdateUserRequest,
     responses(
         (status = 200, description = "User updated successfully", body = UserResponse),
+        (status = 400, description = "No updates provided or last admin would be demoted"),
         (status = 401, description = "Unauthorized"),
         (status = 403, description = "Forbidden - Admin access required"),
         (status = 404, description = "User not found")
@@ -303,22 +467,11 @@ pub async fn list_users(
     security(("session" = []))
 )]
 pub async fn update_user(
-    auth_session: AuthSession,
+    AdminUser(user): AdminUser,
     State(state): State<AppState>,
     axum::extract::Path(user_id): axum::extract::Path<String>,
     JsonExtractor(request): JsonExtractor<UpdateUserRequest>,
 ) -> Result<Json<UserResponse>> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Authentication required".to_string(),
-    })?;
-
-    // Check if user is admin
-    if !user.is_admin() {
-        return Err(AppError::Authorization {
-            message: "Admin access required".to_string(),
-        });
-    }
-
     // Prevent users from modifying themselves
     if user.id == user_id {
         return Err(AppError::Authorization {
@@ -332,56 +485,73 @@ pub async fn update_user(
         user_id
     )
     .fetch_optional(&state.pool)
-    .await?.ok_or_else(|| AppError::NotFound {
-        resource: "User not found".to_string(),
-    })?;
+    .await?.ok_or_else(AppError::user_not_found)?;
 
     // Validate that at least one field is being updated
     if request.role.is_none()
         && request.can_create_invites.is_none()
         && request.max_invites.is_none()
     {
-        return Err(AppError::Authorization {
+        return Err(AppError::BadRequest {
             message: "No updates provided".to_string(),
         });
     }
 
-    // Execute individual updates - simpler approach for SQLite
+    // Execute individual updates - simpler approach for SQLite. The last-admin
+    // check shares their transaction so two admins can't demote each other at once.
     let now = chrono::Utc::now().to_rfc3339();
+    let target_id = user_id.clone();
+
+    database::with_transaction(&state.pool, move |conn| {
+        Box::pin(async move {
+            let user_id = target_id;
+
+            if let Some(role) = &request.role {
+                if *role != UserRole::Admin {
+                    database::users::ensure_admin_remains(
+                        &mut *conn,
+                        std::slice::from_ref(&user_id),
+                    )
+                    .await?;
+                }
 
-    if let Some(role) = &request.role {
-        let role_str = role.to_string();
-        sqlx::query!(
-            "UPDATE users SET role = ?, updated_at = ? WHERE id = ?",
-            role_str,
-            now,
-            user_id
-        )
-        .execute(&state.pool)
-        .await?;
-    }
+                let role_str = role.to_string();
+                sqlx::query!(
+                    "UPDATE users SET role = ?, updated_at = ? WHERE id = ?",
+                    role_str,
+                    now,
+                    user_id
+                )
+                .execute(&mut *conn)
+                .await?;
+            }
 
-    if let Some(can_create_invites) = request.can_create_invites {
-        sqlx::query!(
-            "UPDATE users SET can_create_invites = ?, updated_at = ? WHERE id = ?",
-            can_create_invites,
-            now,
-            user_id
-        )
-        .execute(&state.pool)
-        .await?;
-    }
+            if let Some(can_create_invites) = request.can_create_invites {
+                sqlx::query!(
+                    "UPDATE users SET can_create_invites = ?, updated_at = ? WHERE id = ?",
+                    can_create_invites,
+                    now,
+                    user_id
+                )
+                .execute(&mut *conn)
+                .await?;
+            }
 
-    if let Some(max_invites) = request.max_invites {
-        sqlx::query!(
-            "UPDATE users SET max_invites = ?, updated_at = ? WHERE id = ?",
-            max_invites,
-            now,
-            user_id
-        )
-        .execute(&state.pool)
-        .await?;
-    }
+            if let Some(max_invites) = request.max_invites {
+                sqlx::query!(
+                    "UPDATE users SET max_invites = ?, updated_at = ? WHERE id = ?",
+                    max_invites,
+                    now,
+                    user_id
+                )
+                .execute(&mut *conn)
+                .await?;
+            }
+
+            Ok(())
+        })
+    })
+    .await?;
 
     // Fetch updated user
     let updated_user = sqlx::query!(
@@ -418,6 +588,7 @@ pub async fn update_user(
     ),
     responses(
         (status = 200, description = "User deleted successfully"),
+        (status = 400, description = "Cannot delete the last admin"),
         (status = 401, description = "Unauthorized"),
         (status = 403, description = "Forbidden - Admin access required"),
         (status = 404, description = "User not found")
@@ -425,21 +596,10 @@ pub async fn update_user(
     security(("session" = []))
 )]
 pub async fn delete_user(
-    auth_session: AuthSession,
+    AdminUser(user): AdminUser,
     State(state): State<AppState>,
     axum::extract::Path(user_id): axum::extract::Path<String>,
 ) -> Result<Json<serde_json::Value>> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Authentication required".to_string(),
-    })?;
-
-    // Check if user is admin
-    if !user.is_admin() {
-        return Err(AppError::Authorization {
-            message: "Admin access required".to_string(),
-        });
-    }
-
     // Prevent users from deleting themselves
     if user.id == user_id {
         return Err(AppError::Authorization {
@@ -453,21 +613,88 @@ pub async fn delete_user(
         .await?;
 
     if target_user.is_none() {
-        return Err(AppError::NotFound {
-            resource: "User not found".to_string(),
-        });
+        return Err(AppError::user_not_found());
     }
 
-    // Delete user (cascading deletes should handle related data)
-    sqlx::query!("DELETE FROM users WHERE id = ?", user_id)
-        .execute(&state.pool)
-        .await?;
+    let photo_ids = database::photos::list_photo_ids_for_user(&state.pool, &user_id).await?;
+
+    // Delete user (cascading deletes should handle related data), checking in
+    // the same transaction that another admin remains
+    let target_id = user_id.clone();
+    database::with_transaction(&state.pool, move |conn| {
+        Box::pin(async move {
+            database::users::ensure_admin_remains(&mut *conn, std::slice::from_ref(&target_id))
+                .await?;
+
+            sqlx::query!("DELETE FROM users WHERE id = ?", target_id)
+                .execute(&mut *conn)
+                .await?;
+
+            Ok(())
+        })
+    })
+    .await?;
+
+    // Images outside the database don't cascade
+    photo_store::delete_images(state.photo_store.as_ref(), &photo_ids).await;
 
     Ok(Json(serde_json::json!({
         "message": "User deleted successfully"
     })))
 }
 
+/// Reassign all of a user's plants to another user (admin only)
+///
+/// Entries, photos and metrics move with their plants; the transfer is audit-logged.
+#[utoipa::path(
+    post,
+    path = "/admin/users/{from_id}/transfer-plants/{to_id}",
+    params(
+        ("from_id" = String, Path, description = "User whose plants are transferred"),
+        ("to_id" = String, Path, description = "User receiving the plants")
+    ),
+    responses(
+        (status = 200, description = "Plants transferred", body = TransferPlantsResponse),
+        (status = 400, description = "Source and target are the same user"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 404, description = "User not found")
+    ),
+    security(("session" = []))
+)]
+pub async fn transfer_plants(
+    AdminUser(user): AdminUser,
+    State(state): State<AppState>,
+    axum::extract::Path((from_id, to_id)): axum::extract::Path<(String, String)>,
+) -> Result<Json<TransferPlantsResponse>> {
+    if from_id == to_id {
+        return Err(AppError::BadRequest {
+            message: "Cannot transfer plants to the same user".to_string(),
+        });
+    }
+
+    for user_id in [&from_id, &to_id] {
+        database::users::get_user_by_id(&state.pool, user_id).await?;
+    }
+
+    let transferred =
+        database::plants::transfer_plants(&state.pool, &from_id, &to_id, &user.id).await?;
+
+    tracing::info!(
+        "Admin {} transferred {} plants from user {} to user {}",
+        user.id,
+        transferred,
+        from_id,
+        to_id
+    );
+
+    Ok(Json(TransferPlantsResponse {
+        from_user_id: from_id,
+        to_user_id: to_id,
+        transferred,
+    }))
+}
+
 /// Get admin settings
 #[utoipa::path(
     get,
@@ -480,47 +707,30 @@ pub async fn delete_user(
     security(("session" = []))
 )]
 pub async fn get_admin_settings(
-    auth_session: AuthSession,
+    _admin: AdminUser,
     State(state): State<AppState>,
 ) -> Result<Json<AdminSettingsResponse>> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Authentication required".to_string(),
-    })?;
-
-    // Check if user is admin
-    if !user.is_admin() {
-        return Err(AppError::Authorization {
-            message: "Admin access required".to_string(),
-        });
-    }
-
-    let max_total_users_opt =
-        sqlx::query_scalar!("SELECT value FROM admin_settings WHERE key = 'max_total_users'")
-            .fetch_one(&state.pool)
-            .await?;
-
-    let max_total_users = max_total_users_opt.parse::<i32>().unwrap_or(1000);
-
-    let default_user_invite_limit_opt = sqlx::query_scalar!(
-        "SELECT value FROM admin_settings WHERE key = 'default_user_invite_limit'"
-    )
-    .fetch_one(&state.pool)
-    .await?;
-
-    let default_user_invite_limit = default_user_invite_limit_opt.parse::<i32>().unwrap_or(5);
-
-    let registration_enabled_opt =
-        sqlx::query_scalar!("SELECT value FROM admin_settings WHERE key = 'registration_enabled'")
-            .fetch_one(&state.pool)
-            .await?;
-
-    let registration_enabled = registration_enabled_opt.parse::<bool>().unwrap_or(true);
+    let settings = database::admin_settings::get_admin_settings(&state.pool).await?;
+    Ok(Json(settings.into()))
+}
 
-    Ok(Json(AdminSettingsResponse {
-        max_total_users,
-        default_user_invite_limit,
-        registration_enabled,
-    }))
+/// Get each admin setting's effective value and whether it's stored or the default
+#[utoipa::path(
+    get,
+    path = "/admin/settings/effective",
+    responses(
+        (status = 200, description = "Effective admin settings", body = EffectiveAdminSettingsResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin access required")
+    ),
+    security(("session" = []))
+)]
+pub async fn get_effective_admin_settings(
+    _admin: AdminUser,
+    State(state): State<AppState>,
+) -> Result<Json<EffectiveAdminSettingsResponse>> {
+    let settings = database::admin_settings::get_admin_settings(&state.pool).await?;
+    Ok(Json(settings.into()))
 }
 
 /// Update admin settings
@@ -536,84 +746,41 @@ pub async fn get_admin_settings(
     security(("session" = []))
 )]
 pub async fn update_admin_settings(
-    auth_session: AuthSession,
+    _admin: AdminUser,
     State(state): State<AppState>,
     JsonExtractor(request): JsonExtractor<UpdateAdminSettingsRequest>,
 ) -> Result<Json<AdminSettingsResponse>> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Authentication required".to_string(),
-    })?;
-
-    // Check if user is admin
-    if !user.is_admin() {
-        return Err(AppError::Authorization {
-            message: "Admin access required".to_string(),
-        });
-    }
-
-    let now = chrono::Utc::now().to_rfc3339();
+    use database::admin_settings::{
+        set_admin_setting, DEFAULT_USER_INVITE_LIMIT_KEY, MAX_TOTAL_USERS_KEY,
+        REGISTRATION_ENABLED_KEY,
+    };
 
     if let Some(max_total_users) = request.max_total_users {
-        let value_str = max_total_users.to_string();
-        sqlx::query!(
-            "UPDATE admin_settings SET value = ?, updated_at = ? WHERE key = 'max_total_users'",
-            value_str,
-            now
-        )
-        .execute(&state.pool)
-        .await?;
+        set_admin_setting(&state.pool, MAX_TOTAL_USERS_KEY, &max_total_users.to_string()).await?;
     }
 
     if let Some(default_user_invite_limit) = request.default_user_invite_limit {
-        let value_str = default_user_invite_limit.to_string();
-        sqlx::query!(
-            "UPDATE admin_settings SET value = ?, updated_at = ? WHERE key = 'default_user_invite_limit'",
-            value_str,
-            now
+        set_admin_setting(
+            &state.pool,
+            DEFAULT_USER_INVITE_LIMIT_KEY,
+            &default_user_invite_limit.to_string(),
         )
-        .execute(&state.pool)
         .await?;
     }
 
     if let Some(registration_enabled) = request.registration_enabled {
-        let value_str = registration_enabled.to_string();
-        sqlx::query!(
-            "UPDATE admin_settings SET value = ?, updated_at = ? WHERE key = 'registration_enabled'",
-            value_str,
-            now
+        set_admin_setting(
+            &state.pool,
+            REGISTRATION_ENABLED_KEY,
+            &registration_enabled.to_string(),
         )
-        .execute(&state.pool)
         .await?;
+        state.features.forget();
     }
 
     // Return updated settings by fetching them again
-    let max_total_users_opt =
-        sqlx::query_scalar!("SELECT value FROM admin_settings WHERE key = 'max_total_users'")
-            .fetch_one(&state.pool)
-            .await?;
-
-    let max_total_users = max_total_users_opt.parse::<i32>().unwrap_or(1000);
-
-    let default_user_invite_limit_opt = sqlx::query_scalar!(
-        "SELECT value FROM admin_settings WHERE key = 'default_user_invite_limit'"
-    )
-    .fetch_one(&state.pool)
-    .await?;
-
-    let default_user_invite_limit = default_user_invite_limit_opt.parse::<i32>().unwrap_or(5);
-
-    let registration_enabled_opt =
-        sqlx::query_scalar!("SELECT value FROM admin_settings WHERE key = 'registration_enabled'")
-            .fetch_one(&state.pool)
-            .await?;
-
-    let registration_enabled = registration_enabled_opt.parse::<bool>().unwrap_or(true);
-
-    Ok(Json(AdminSettingsResponse {
-        max_total_users,
-        default_user_invite_limit,
-        registration_enabled,
-    }))
+    let settings = database::admin_settings::get_admin_settings(&state.pool).await?;
+    Ok(Json(settings.into()))
 }
 
 /// Perform bulk actions on users
@@ -625,28 +792,17 @@ pub async fn update_admin_settings(
         (status = 200, description = "Bulk action completed successfully"),
         (status = 401, description = "Unauthorized"),
         (status = 403, description = "Forbidden - Admin access required"),
-        (status = 400, description = "Invalid request")
+        (status = 400, description = "Invalid request or last admin would be removed")
     ),
     security(("session" = []))
 )]
 pub async fn bulk_user_action(
-    auth_session: AuthSession,
+    AdminUser(user): AdminUser,
     State(state): State<AppState>,
     JsonExtractor(request): JsonExtractor<BulkUserActionRequest>,
 ) -> Result<Json<serde_json::Value>> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Authentication required".to_string(),
-    })?;
-
-    // Check if user is admin
-    if !user.is_admin() {
-        return Err(AppError::Authorization {
-            message: "Admin access required".to_string(),
-        });
-    }
-
     if request.user_ids.is_empty() {
-        return Err(AppError::Authorization {
+        return Err(AppError::BadRequest {
             message: "No users specified".to_string(),
         });
     }
@@ -658,62 +814,66 @@ pub async fn bulk_user_action(
         });
     }
 
-    let now = chrono::Utc::now().to_rfc3339();
-    let mut affected_count = 0;
+    let removes_admins = match &request.action {
+        BulkUserAction::Delete => true,
+        BulkUserAction::SetRole(role) => *role != UserRole::Admin,
+        BulkUserAction::EnableInvites | BulkUserAction::DisableInvites => false,
+    };
+
     let action_debug = format!("{:?}", request.action);
+    let (set_clause, role) = match &request.action {
+        BulkUserAction::Delete => (None, None),
+        BulkUserAction::SetRole(role) => (Some("role = ?, updated_at = ?"), Some(role.to_string())),
+        BulkUserAction::EnableInvites => (Some("can_create_invites = TRUE, updated_at = ?"), None),
+        BulkUserAction::DisableInvites => (Some("can_create_invites = FALSE, updated_at = ?"), None),
+    };
 
-    match request.action {
-        BulkUserAction::Delete => {
-            for user_id in &request.user_ids {
-                let result = sqlx::query!("DELETE FROM users WHERE id = ?", user_id)
-                    .execute(&state.pool)
-                    .await?;
-                affected_count += result.rows_affected();
-            }
+    // One statement over every user, which never touches the acting admin
+    let placeholders = vec!["?"; request.user_ids.len()].join(", ");
+    let targets = format!("id IN ({placeholders}) AND id != ?");
+    let statement = match set_clause {
+        Some(set_clause) => format!("UPDATE users SET {set_clause} WHERE {targets}"),
+        None => format!("DELETE FROM users WHERE {targets}"),
+    };
+    let now = chrono::Utc::now().to_rfc3339();
+    let user_ids = request.user_ids;
+    let admin_id = user.id;
+
+    let mut photo_ids = Vec::new();
+    if set_clause.is_none() {
+        for user_id in user_ids.iter().filter(|user_id| **user_id != admin_id) {
+            photo_ids.extend(database::photos::list_photo_ids_for_user(&state.pool, user_id).await?);
         }
-        BulkUserAction::SetRole(role) => {
-            let role_str = role.to_string();
-            for user_id in &request.user_ids {
-                let result = sqlx::query!(
-                    "UPDATE users SET role = ?, updated_at = ? WHERE id = ?",
-                    role_str,
-                    now,
-                    user_id
-                )
-                .execute(&state.pool)
-                .await?;
-                affected_count += result.rows_affected();
+    }
+
+    let affected_count = database::with_transaction(&state.pool, move |conn| {
+        Box::pin(async move {
+            if removes_admins {
+                database::users::ensure_admin_remains(&mut *conn, &user_ids).await?;
             }
-        }
-        BulkUserAction::EnableInvites => {
-            for user_id in &request.user_ids {
-                let result = sqlx::query!(
-                    "UPDATE users SET can_create_invites = TRUE, updated_at = ? WHERE id = ?",
-                    now,
-                    user_id
-                )
-                .execute(&state.pool)
-                .await?;
-                affected_count += result.rows_affected();
+
+            let mut query = sqlx::query(&statement);
+            if let Some(role) = &role {
+                query = query.bind(role);
             }
-        }
-        BulkUserAction::DisableInvites => {
-            for user_id in &request.user_ids {
-                let result = sqlx::query!(
-                    "UPDATE users SET can_create_invites = FALSE, updated_at = ? WHERE id = ?",
-                    now,
-                    user_id
-                )
-                .execute(&state.pool)
-                .await?;
-                affected_count += result.rows_affected();
+            if set_clause.is_some() {
+                query = query.bind(&now);
             }
-        }
-    }
+            for user_id in &user_ids {
+                query = query.bind(user_id);
+            }
+            let result = query.bind(&admin_id).execute(&mut *conn).await?;
+
+            Ok(result.rows_affected())
+        })
+    })
+    .await?;
+
+    photo_store::delete_images(state.photo_store.as_ref(), &photo_ids).await;
 
     Ok(Json(serde_json::json!({
         "message": "Bulk action completed successfully",
-        "affected_count": affected_count,
+        "affectedCount": affected_count,
         "action": action_debug
     })))
 }
@@ -730,20 +890,9 @@ pub async fn bulk_user_action(
     security(("session" = []))
 )]
 pub async fn get_system_health(
-    auth_session: AuthSession,
+    _admin: AdminUser,
     State(state): State<AppState>,
 ) -> Result<Json<serde_json::Value>> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Authentication required".to_string(),
-    })?;
-
-    // Check if user is admin
-    if !user.is_admin() {
-        return Err(AppError::Authorization {
-            message: "Admin access required".to_string(),
-        });
-    }
-
     // Check database connectivity
     let db_status = match sqlx::query_scalar!("SELECT 1").fetch_one(&state.pool).await {
         Ok(_) => "healthy",
@@ -765,6 +914,11 @@ pub async fn get_system_health(
 
     let db_size_bytes = db_page_count * db_page_size;
 
+    // A missing migrations table means the schema was never migrated
+    let migrations = database::migration_status(&state.pool)
+        .await
+        .unwrap_or(database::MigrationStatus::Behind);
+
     // Get recent activity counts
     let users_last_24h = sqlx::query_scalar!(
         "SELECT COUNT(*) FROM users WHERE created_at > datetime('now', '-1 day')"
@@ -785,13 +939,14 @@ pub async fn get_system_health(
         "timestamp": chrono::Utc::now().to_rfc3339(),
         "database": {
             "status": db_status,
-            "size_bytes": db_size_bytes,
-            "page_count": db_page_count,
-            "page_size": db_page_size
+            "sizeBytes": db_size_bytes,
+            "pageCount": db_page_count,
+            "pageSize": db_page_size
         },
-        "activity_24h": {
-            "new_users": users_last_24h,
-            "new_invites": invites_last_24h
+        "migrations": migrations,
+        "activity24h": {
+            "newUsers": users_last_24h,
+            "newInvites": invites_last_24h
         },
         "uptime": {
             "note": "Application uptime tracking not implemented"
@@ -799,6 +954,257 @@ pub async fn get_system_health(
     })))
 }
 
+/// List background jobs with their last run and next scheduled time
+#[utoipa::path(
+    get,
+    path = "/admin/jobs",
+    responses(
+        (status = 200, description = "Background job status", body = JobListResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin access required")
+    ),
+    security(("session" = []))
+)]
+pub async fn list_jobs(
+    _admin: AdminUser,
+    State(state): State<AppState>,
+) -> Result<Json<JobListResponse>> {
+    Ok(Json(JobListResponse {
+        jobs: state.job_registry.snapshot(),
+    }))
+}
+
+/// Start generating thumbnails for photos that don't have one
+///
+/// Runs in the background; if a backfill is already running its progress is
+/// returned instead of starting another.
+#[utoipa::path(
+    post,
+    path = "/admin/photos/backfill-thumbnails",
+    responses(
+        (status = 202, description = "Backfill started", body = BackfillProgress),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin access required")
+    ),
+    security(("session" = []))
+)]
+pub async fn start_thumbnail_backfill(
+    _admin: AdminUser,
+    State(state): State<AppState>,
+) -> Result<(StatusCode, Json<BackfillProgress>)> {
+    let progress = state
+        .thumbnail_backfill
+        .start(
+            state.pool.clone(),
+            state.photo_store.clone(),
+            state.job_registry.clone(),
+        );
+
+    Ok((StatusCode::ACCEPTED, Json(progress)))
+}
+
+/// Progress of the current or most recent thumbnail backfill
+#[utoipa::path(
+    get,
+    path = "/admin/photos/backfill-thumbnails",
+    responses(
+        (status = 200, description = "Backfill progress", body = BackfillProgress),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin access required")
+    ),
+    security(("session" = []))
+)]
+pub async fn get_thumbnail_backfill(
+    _admin: AdminUser,
+    State(state): State<AppState>,
+) -> Result<Json<BackfillProgress>> {
+    Ok(Json(state.thumbnail_backfill.progress()))
+}
+
+/// List logged-in sessions, most recently active first
+#[utoipa::path(
+    get,
+    path = "/admin/sessions",
+    params(
+        ("page" = Option<i32>, Query, description = "Page number (default: 1)"),
+        ("limit" = Option<i32>, Query, description = "Items per page (default: 20)")
+    ),
+    responses(
+        (status = 200, description = "Active sessions", body = SessionListResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin access required")
+    ),
+    security(("session" = []))
+)]
+pub async fn list_sessions(
+    _admin: AdminUser,
+    State(state): State<AppState>,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<SessionListQuery>,
+) -> Result<Json<SessionListResponse>> {
+    let page = query.page.unwrap_or(1).max(1);
+    let limit = query.limit.unwrap_or(20).clamp(1, 100);
+    let offset = (page - 1) * limit;
+
+    let sessions = database::sessions::list_active_sessions(&state.pool).await?;
+    let total = sessions.len() as i32;
+    let sessions: Vec<_> = sessions
+        .into_iter()
+        .skip(offset as usize)
+        .take(limit as usize)
+        .collect();
+
+    let mut emails = std::collections::HashMap::new();
+    for session in &sessions {
+        if !emails.contains_key(&session.user_id) {
+            let email = sqlx::query_scalar!("SELECT email FROM users WHERE id = ?", session.user_id)
+                .fetch_optional(&state.pool)
+                .await?;
+            emails.insert(session.user_id.clone(), email);
+        }
+    }
+
+    let sessions = sessions
+        .into_iter()
+        .map(|session| AdminSessionInfo {
+            email: emails.get(&session.user_id).cloned().flatten(),
+            session_id: session.session_id,
+            user_id: session.user_id,
+            created_at: session.created_at,
+            expires_at: session.expires_at,
+        })
+        .collect();
+
+    let total_pages = (total as f64 / limit as f64).ceil() as i32;
+    let PageLinks { next, prev } = page_links(&uri, page, total_pages);
+
+    Ok(Json(SessionListResponse {
+        sessions,
+        total,
+        page,
+        limit,
+        total_pages,
+        next,
+        prev,
+    }))
+}
+
+/// Revoke a session, logging its user out (admin only)
+///
+/// The revocation is audit-logged.
+#[utoipa::path(
+    delete,
+    path = "/admin/sessions/{session_id}",
+    params(
+        ("session_id" = String, Path, description = "Session ID to revoke")
+    ),
+    responses(
+        (status = 204, description = "Session revoked"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 404, description = "Session not found or already expired")
+    ),
+    security(("session" = []))
+)]
+pub async fn revoke_session(
+    AdminUser(user): AdminUser,
+    State(state): State<AppState>,
+    axum::extract::Path(session_id): axum::extract::Path<String>,
+) -> Result<StatusCode> {
+    let session = database::sessions::get_active_session(&state.pool, &session_id).await?;
+    database::sessions::revoke_session(&state.pool, &session, &user.id).await?;
+
+    tracing::info!(
+        "Admin {} revoked session of user {}",
+        user.id,
+        session.user_id
+    );
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Waitlist emails sent before pausing
+const WAITLIST_NOTIFY_BATCH_SIZE: usize = 20;
+
+/// Pause between batches of waitlist emails, to stay within mail provider rate limits
+const WAITLIST_NOTIFY_BATCH_PAUSE: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// Email every pending waitlist entry that hasn't been notified yet (admin only)
+///
+/// Entries are marked as they are emailed, so re-running after an interruption
+/// only reaches the rest. Each run is audit-logged.
+#[utoipa::path(
+    post,
+    path = "/admin/waitlist/notify",
+    request_body = NotifyWaitlistRequest,
+    responses(
+        (status = 200, description = "Waitlist notified", body = NotifyWaitlistResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 422, description = "Missing or overlong subject or body")
+    ),
+    security(("session" = []))
+)]
+pub async fn notify_waitlist(
+    AdminUser(user): AdminUser,
+    State(state): State<AppState>,
+    ValidatedJson(request): ValidatedJson<NotifyWaitlistRequest>,
+) -> Result<Json<NotifyWaitlistResponse>> {
+    // Entries joining during the run wait for the next one
+    let entries = database::invites::get_unnotified_waitlist_entries(&state.pool).await?;
+
+    let mut notified = 0;
+    let mut failed = 0;
+    for (index, batch) in entries.chunks(WAITLIST_NOTIFY_BATCH_SIZE).enumerate() {
+        if index > 0 {
+            tokio::time::sleep(WAITLIST_NOTIFY_BATCH_PAUSE).await;
+        }
+
+        for entry in batch {
+            if !database::invites::claim_waitlist_notification(&state.pool, &entry.id).await? {
+                continue;
+            }
+
+            let email = Email {
+                to: entry.email.clone(),
+                subject: request.subject.clone(),
+                body: request.body.clone(),
+            };
+            match state.mailer.send(&email).await {
+                Ok(()) => notified += 1,
+                Err(e) => {
+                    tracing::warn!("Failed to email waitlist entry {}: {}", entry.id, e);
+                    database::invites::release_waitlist_notification(&state.pool, &entry.id)
+                        .await?;
+                    failed += 1;
+                }
+            }
+        }
+    }
+
+    database::audit::record_admin_action(
+        &state.pool,
+        &user.id,
+        "notify_waitlist",
+        None,
+        serde_json::json!({
+            "subject": request.subject,
+            "notified": notified,
+            "failed": failed,
+        }),
+    )
+    .await?;
+
+    tracing::info!(
+        "Admin {} notified {} waitlist entries ({} failed)",
+        user.id,
+        notified,
+        failed
+    );
+
+    Ok(Json(NotifyWaitlistResponse { notified, failed }))
+}
+
 /// Admin routes  
 pub fn routes() -> Router<AppState> {
     Router::new()
@@ -807,9 +1213,68 @@ pub fn routes() -> Router<AppState> {
         .route("/users/:user_id", put(update_user))
         .route("/users/:user_id", delete(delete_user))
         .route("/users/bulk", post(bulk_user_action))
+        .route(
+            "/users/:from_id/transfer-plants/:to_id",
+            post(transfer_plants),
+        )
         .route(
             "/settings",
             get(get_admin_settings).put(update_admin_settings),
         )
+        .route("/settings/effective", get(get_effective_admin_settings))
         .route("/health", get(get_system_health))
+        .route("/jobs", get(list_jobs))
+        .route("/sessions", get(list_sessions))
+        .route("/waitlist/notify", post(notify_waitlist))
+        .route("/sessions/:session_id", delete(revoke_session))
+        .route(
+            "/photos/backfill-thumbnails",
+            get(get_thumbnail_backfill).post(start_thumbnail_backfill),
+        )
+        .route_layer(middleware::from_fn(require_admin))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    /// Collects formatted log output
+    #[derive(Clone, Default)]
+    struct LogBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for LogBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_malformed_stored_timestamp_is_logged_and_skipped() {
+        let logs = LogBuffer::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+
+        let parsed = tracing::subscriber::with_default(subscriber, || {
+            parse_stored_timestamp("users", "user-1", "created_at", "yesterday-ish")
+        });
+
+        assert_eq!(parsed, None);
+        let logs = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+        assert!(logs.contains("WARN"));
+        assert!(logs.contains("users row user-1: malformed created_at \"yesterday-ish\""));
+
+        assert_eq!(
+            parse_stored_timestamp("users", "user-1", "created_at", "2024-05-01T10:00:00+00:00"),
+            Some("2024-05-01T10:00:00Z".parse().unwrap())
+        );
+    }
 }
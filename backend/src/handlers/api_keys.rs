@@ -0,0 +1,102 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    routing::{delete, get},
+    Router,
+};
+
+use crate::app_state::AppState;
+use crate::auth::SessionUser;
+use crate::database::api_keys as db_api_keys;
+use crate::middleware::validation::ValidatedJson;
+use crate::models::api_key::{ApiKeysResponse, CreateApiKeyRequest, CreatedApiKeyResponse};
+use crate::utils::errors::Result;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_api_keys).post(create_api_key))
+        .route("/:key_id", delete(revoke_api_key))
+}
+
+/// List the current user's API keys
+#[utoipa::path(
+    get,
+    path = "/auth/api-keys",
+    responses(
+        (status = 200, description = "API keys, newest first", body = ApiKeysResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Requested with an API key instead of a session")
+    ),
+    tag = "auth",
+    security(
+        ("session" = [])
+    )
+)]
+pub async fn list_api_keys(
+    SessionUser(user): SessionUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<ApiKeysResponse>> {
+    let api_keys = db_api_keys::list_api_keys(&app_state.pool, &user.id).await?;
+    Ok(Json(ApiKeysResponse { api_keys }))
+}
+
+/// Create an API key for scripts, used as `Authorization: Bearer <key>`
+///
+/// The key is only returned here; store it somewhere safe.
+#[utoipa::path(
+    post,
+    path = "/auth/api-keys",
+    request_body = CreateApiKeyRequest,
+    responses(
+        (status = 201, description = "API key created", body = CreatedApiKeyResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Requested with an API key instead of a session"),
+        (status = 422, description = "Missing label or invalid expiry")
+    ),
+    tag = "auth",
+    security(
+        ("session" = [])
+    )
+)]
+pub async fn create_api_key(
+    SessionUser(user): SessionUser,
+    State(app_state): State<AppState>,
+    ValidatedJson(payload): ValidatedJson<CreateApiKeyRequest>,
+) -> Result<(StatusCode, Json<CreatedApiKeyResponse>)> {
+    let (key, api_key) = db_api_keys::create_api_key(&app_state.pool, &user.id, &payload).await?;
+
+    tracing::info!("Created API key {} for user {}", api_key.id, user.id);
+
+    Ok((StatusCode::CREATED, Json(CreatedApiKeyResponse { key, api_key })))
+}
+
+/// Revoke an API key; requests using it are rejected from then on
+#[utoipa::path(
+    delete,
+    path = "/auth/api-keys/{key_id}",
+    params(
+        ("key_id" = String, Path, description = "API key ID")
+    ),
+    responses(
+        (status = 204, description = "API key revoked"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Requested with an API key instead of a session"),
+        (status = 404, description = "API key not found")
+    ),
+    tag = "auth",
+    security(
+        ("session" = [])
+    )
+)]
+pub async fn revoke_api_key(
+    SessionUser(user): SessionUser,
+    State(app_state): State<AppState>,
+    Path(key_id): Path<String>,
+) -> Result<StatusCode> {
+    db_api_keys::delete_api_key(&app_state.pool, &user.id, &key_id).await?;
+
+    tracing::info!("Revoked API key {} for user {}", key_id, user.id);
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -4,11 +4,17 @@ use axum::{
     Router,
 };
 
+use axum_login::tower_sessions::Session;
+use chrono::{DateTime, Utc};
+
 use crate::app_state::AppState;
-use crate::auth::{AuthSession, Credentials};
+use crate::auth::{record_login_time, AuthSession, Credentials};
 use crate::database::users as db_users;
+use crate::handlers::api_keys;
 use crate::middleware::validation::ValidatedJson;
-use crate::models::{AuthResponse, CreateUserRequest, LoginRequest, UserResponse, UserRole};
+use crate::models::{
+    AuthResponse, CreateUserRequest, LoginRequest, SessionStatusResponse, UserResponse, UserRole,
+};
 use crate::utils::errors::{AppError, Result};
 
 pub fn routes() -> Router<AppState> {
@@ -17,6 +23,8 @@ pub fn routes() -> Router<AppState> {
         .route("/register", post(register))
         .route("/logout", post(logout))
         .route("/me", get(me))
+        .route("/session", get(session_status))
+        .nest("/api-keys", api_keys::routes())
 }
 
 #[utoipa::path(
@@ -31,6 +39,7 @@ pub fn routes() -> Router<AppState> {
 )]
 async fn login(
     mut auth_session: AuthSession,
+    session: Session,
     ValidatedJson(payload): ValidatedJson<LoginRequest>,
 ) -> Result<Json<AuthResponse>> {
     tracing::info!("Login attempt for email: {}", payload.email);
@@ -62,6 +71,7 @@ async fn login(
             message: "Failed to create session".to_string(),
         });
     }
+    record_login_time(&session).await;
 
     let response = AuthResponse { user: user.into() };
 
@@ -81,6 +91,7 @@ async fn login(
 )]
 async fn register(
     mut auth_session: AuthSession,
+    session: Session,
     ValidatedJson(payload): ValidatedJson<CreateUserRequest>,
 ) -> Result<(axum::http::StatusCode, Json<AuthResponse>)> {
     tracing::info!("Registration attempt for email: {}", payload.email);
@@ -166,6 +177,7 @@ async fn register(
             message: "Failed to create session".to_string(),
         });
     }
+    record_login_time(&session).await;
 
     let response = AuthResponse { user: user.into() };
 
@@ -185,6 +197,35 @@ async fn me(auth_session: AuthSession) -> Result<Json<UserResponse>> {
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/auth/session",
+    responses(
+        (status = 200, description = "Session status, refreshing the inactivity expiry when logged in", body = SessionStatusResponse),
+    )
+)]
+async fn session_status(
+    auth_session: AuthSession,
+    session: Session,
+) -> Result<Json<SessionStatusResponse>> {
+    if auth_session.user.is_none() {
+        return Ok(Json(SessionStatusResponse {
+            authenticated: false,
+            expires_at: None,
+        }));
+    }
+
+    // Re-setting the expiry marks the session modified, so the session layer
+    // saves it and reissues the cookie with the inactivity timer restarted
+    session.set_expiry(session.expiry());
+    let expires_at = DateTime::<Utc>::from_timestamp(session.expiry_date().unix_timestamp(), 0);
+
+    Ok(Json(SessionStatusResponse {
+        authenticated: true,
+        expires_at,
+    }))
+}
+
 async fn logout(mut auth_session: AuthSession) -> Result<axum::http::StatusCode> {
     match auth_session.logout().await {
         Ok(_) => {
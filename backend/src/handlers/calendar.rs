@@ -5,13 +5,25 @@ use axum::{
     routing::get,
     Router,
 };
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
+use sha2::{Digest, Sha256};
 
 use crate::app_state::AppState;
-use crate::auth::AuthSession;
-use crate::database::plants as db_plants;
-use crate::utils::calendar::{generate_calendar_token, generate_plant_calendar};
+use crate::auth::CurrentUser;
+use crate::database::{plants as db_plants, tracking as db_tracking, users as db_users};
+use crate::models::plant::PlantResponse;
+use crate::models::tracking_entry::TrackingEntry;
+use crate::models::user::CareTiming;
+use crate::utils::calendar::{
+    generate_calendar_token, generate_plant_calendar, CalendarLocale, HISTORY_DAYS,
+};
 use crate::utils::errors::{AppError, Result};
+use crate::utils::hex::to_hex;
+use crate::utils::http_cache::etag_matches;
+
+/// How long calendar apps may reuse a feed before revalidating it
+const FEED_CACHE_CONTROL: &str = "private, max-age=3600";
 
 /// Extract base URL from request headers
 fn get_base_url_from_headers(headers: &HeaderMap, _uri: &Uri) -> String {
@@ -42,6 +54,40 @@ fn get_base_url_from_headers(headers: &HeaderMap, _uri: &Uri) -> String {
     format!("{}://{}", scheme, host)
 }
 
+/// Everything the feed is generated from, so an unchanged ETag means an unchanged feed
+///
+/// Plants' `updated_at` moves whenever they are edited or cared for. The date is
+/// included because the window of upcoming events moves forward each day.
+fn feed_etag(
+    plants: &[PlantResponse],
+    history: &[TrackingEntry],
+    params: &CalendarQuery,
+    base_url: &str,
+    timing: CareTiming,
+    reminder_lead_hours: u32,
+    now: DateTime<Utc>,
+) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(format!(
+        "{}|{:?}|{:?}|{}|{:?}|{}|{}\n",
+        now.date_naive(),
+        params.lang,
+        params.include_history,
+        base_url,
+        timing,
+        reminder_lead_hours,
+        plants.len()
+    ));
+    for plant in plants {
+        hasher.update(format!("{}|{}\n", plant.id, plant.updated_at.to_rfc3339()));
+    }
+    for entry in history {
+        hasher.update(format!("{}|{}\n", entry.id, entry.updated_at.to_rfc3339()));
+    }
+
+    format!("\"{}\"", to_hex(&hasher.finalize()[..16]))
+}
+
 /// Create calendar routes
 pub fn routes() -> Router<AppState> {
     Router::new()
@@ -56,6 +102,11 @@ pub fn routes() -> Router<AppState> {
 #[derive(Deserialize)]
 pub struct CalendarQuery {
     token: Option<String>,
+    /// Language for event text, e.g. `fr`; English by default
+    #[serde(alias = "locale")]
+    lang: Option<String>,
+    /// Also emit past watering and fertilizing entries as completed events
+    include_history: Option<bool>,
 }
 
 /// Serve an iCalendar feed for a user's plants
@@ -64,10 +115,13 @@ pub struct CalendarQuery {
     path = "/calendar/{user_id}.ics",
     params(
         ("user_id" = String, Path, description = "User ID for calendar"),
-        ("token" = Option<String>, Query, description = "Calendar access token")
+        ("token" = Option<String>, Query, description = "Calendar access token"),
+        ("lang" = Option<String>, Query, description = "Language for event text (`en` or `fr`, also accepted as `locale`); defaults to English"),
+        ("include_history" = Option<bool>, Query, description = "Also include watering and fertilizing done in the past year as completed events")
     ),
     responses(
         (status = 200, description = "iCalendar feed", content_type = "text/calendar"),
+        (status = 304, description = "Feed unchanged since the ETag in `If-None-Match`"),
         (status = 401, description = "Unauthorized - invalid or missing token"),
         (status = 404, description = "User not found"),
         (status = 500, description = "Internal server error")
@@ -87,7 +141,7 @@ pub async fn get_calendar_feed(
 
     // For now, we'll use a simple token validation
     // In a production system, you'd want to store tokens in the database
-    let provided_token = params.token.ok_or(AppError::Authentication {
+    let provided_token = params.token.as_deref().ok_or(AppError::Authentication {
         message: "Calendar token required".to_string(),
     })?;
 
@@ -116,6 +170,7 @@ pub async fn get_calendar_feed(
     }
 
     tracing::info!("Calendar token validation passed for user: {}", user_id);
+    db_users::record_calendar_feed_fetch(&app_state.pool, user_id).await?;
 
     // Get all plants for the user
     let (plants, _total) =
@@ -136,8 +191,48 @@ pub async fn get_calendar_feed(
     // Get base URL from request headers
     let base_url = get_base_url_from_headers(&headers, &uri);
 
+    let locale = CalendarLocale::from_param(params.lang.as_deref());
+    let timing = db_users::get_care_timing(&app_state.pool, user_id).await?;
+    let now = Utc::now();
+    let history = if params.include_history.unwrap_or(false) {
+        let since = now - chrono::Duration::days(HISTORY_DAYS);
+        db_tracking::get_user_care_history(&app_state.pool, user_id, since).await?
+    } else {
+        Vec::new()
+    };
+
+    // Calendar apps poll the feed, so skip regenerating it when nothing changed
+    let etag = feed_etag(
+        &plants,
+        &history,
+        &params,
+        &base_url,
+        timing,
+        app_state.config.reminder_lead_hours,
+        now,
+    );
+    if etag_matches(&headers, &etag) {
+        tracing::debug!("Calendar feed for user {} not modified", user_id);
+        return Response::builder()
+            .status(StatusCode::NOT_MODIFIED)
+            .header(header::CACHE_CONTROL, FEED_CACHE_CONTROL)
+            .header(header::ETAG, etag)
+            .body(Default::default())
+            .map_err(|_| AppError::Internal {
+                message: "Failed to build calendar response".to_string(),
+            });
+    }
+
     // Generate the iCalendar feed
-    let calendar_content = generate_plant_calendar(&plants, user_id, &base_url)?;
+    let calendar_content = generate_plant_calendar(
+        &plants,
+        &history,
+        user_id,
+        &base_url,
+        locale,
+        timing,
+        app_state.config.reminder_lead_hours,
+    )?;
 
     tracing::info!(
         "Generated calendar feed for user: {} with {} plants, content length: {} chars",
@@ -151,7 +246,8 @@ pub async fn get_calendar_feed(
     Response::builder()
         .status(StatusCode::OK)
         .header(header::CONTENT_TYPE, "text/calendar; charset=utf-8")
-        .header(header::CACHE_CONTROL, "private, max-age=3600") // Cache for 1 hour
+        .header(header::CACHE_CONTROL, FEED_CACHE_CONTROL)
+        .header(header::ETAG, etag)
         .header(
             "Content-Disposition",
             &format!("attachment; filename=\"plant-care-{}.ics\"", user_id),
@@ -176,22 +272,22 @@ pub async fn get_calendar_feed(
     )
 )]
 pub async fn get_calendar_subscription_info(
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
     uri: Uri,
     headers: HeaderMap,
 ) -> Result<impl IntoResponse> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
     tracing::info!("Calendar subscription info request for user: {}", user.id);
 
     // Generate a calendar token for this user
     let calendar_token = generate_calendar_token(&user.id);
 
-    // Get base URL from request headers or environment
-    let base_url = std::env::var("BASE_URL")
-        .unwrap_or_else(|_| get_base_url_from_headers(&headers, &uri));
+    // Get base URL from configuration or request headers
+    let base_url = app_state
+        .config
+        .base_url
+        .clone()
+        .unwrap_or_else(|| get_base_url_from_headers(&headers, &uri));
 
     // Determine API prefix from current request URI
     let api_path = if uri.path().starts_with("/api/v1/") {
@@ -238,22 +334,22 @@ pub async fn get_calendar_subscription_info(
     )
 )]
 pub async fn regenerate_calendar_token(
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
     uri: Uri,
     headers: HeaderMap,
 ) -> Result<impl IntoResponse> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
     tracing::info!("Calendar token regeneration request for user: {}", user.id);
 
     // Generate a new calendar token
     let calendar_token = generate_calendar_token(&user.id);
 
-    // Get base URL from request headers or environment
-    let base_url = std::env::var("BASE_URL")
-        .unwrap_or_else(|_| get_base_url_from_headers(&headers, &uri));
+    // Get base URL from configuration or request headers
+    let base_url = app_state
+        .config
+        .base_url
+        .clone()
+        .unwrap_or_else(|| get_base_url_from_headers(&headers, &uri));
 
     // Determine API prefix from current request URI
     let api_path = if uri.path().starts_with("/api/v1/") {
@@ -0,0 +1,197 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    routing::get,
+    Router,
+};
+
+use crate::app_state::AppState;
+use crate::auth::CurrentUser;
+use crate::database::care_tasks as db_care_tasks;
+use crate::middleware::validation::ValidatedJson;
+use crate::models::PlantId;
+use crate::models::plant::{
+    CareTask, CareTasksResponse, CreateCareTaskRequest, UpdateCareTaskRequest,
+};
+use crate::utils::errors::Result;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/care-tasks", get(list_care_tasks).post(create_care_task))
+        .route(
+            "/care-tasks/:task_type",
+            get(get_care_task)
+                .put(update_care_task)
+                .delete(delete_care_task),
+        )
+}
+
+#[utoipa::path(
+    get,
+    path = "/plants/{plant_id}/care-tasks",
+    params(
+        ("plant_id" = Uuid, Path, description = "Plant ID")
+    ),
+    responses(
+        (status = 200, description = "Care tasks for the plant besides watering and fertilizing", body = CareTasksResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant not found"),
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn list_care_tasks(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path(plant_id): Path<PlantId>,
+) -> Result<Json<CareTasksResponse>> {
+    let care_tasks = db_care_tasks::list_care_tasks(&app_state.pool, plant_id, &user.id).await?;
+
+    Ok(Json(CareTasksResponse { care_tasks }))
+}
+
+/// Add a recurring care task such as misting or repotting
+///
+/// Watering and fertilizing are built in and set on the plant itself.
+#[utoipa::path(
+    post,
+    path = "/plants/{plant_id}/care-tasks",
+    params(
+        ("plant_id" = Uuid, Path, description = "Plant ID")
+    ),
+    request_body = CreateCareTaskRequest,
+    responses(
+        (status = 201, description = "Care task created", body = CareTask),
+        (status = 400, description = "Plant already has a care task of this type"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant not found"),
+        (status = 422, description = "Invalid task type or schedule"),
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn create_care_task(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path(plant_id): Path<PlantId>,
+    ValidatedJson(payload): ValidatedJson<CreateCareTaskRequest>,
+) -> Result<(StatusCode, Json<CareTask>)> {
+    tracing::info!(
+        "Create {} care task for plant: {} by user: {}",
+        payload.task_type,
+        plant_id,
+        user.id
+    );
+
+    let care_task =
+        db_care_tasks::create_care_task(&app_state.pool, plant_id, &user.id, &payload).await?;
+
+    Ok((StatusCode::CREATED, Json(care_task)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/plants/{plant_id}/care-tasks/{task_type}",
+    params(
+        ("plant_id" = Uuid, Path, description = "Plant ID"),
+        ("task_type" = String, Path, description = "Care task type, e.g. misting")
+    ),
+    responses(
+        (status = 200, description = "Care task", body = CareTask),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant or care task not found"),
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn get_care_task(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path((plant_id, task_type)): Path<(PlantId, String)>,
+) -> Result<Json<CareTask>> {
+    let care_task =
+        db_care_tasks::get_care_task(&app_state.pool, plant_id, &user.id, &task_type).await?;
+
+    Ok(Json(care_task))
+}
+
+#[utoipa::path(
+    put,
+    path = "/plants/{plant_id}/care-tasks/{task_type}",
+    params(
+        ("plant_id" = Uuid, Path, description = "Plant ID"),
+        ("task_type" = String, Path, description = "Care task type, e.g. misting")
+    ),
+    request_body = UpdateCareTaskRequest,
+    responses(
+        (status = 200, description = "Care task updated", body = CareTask),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant or care task not found"),
+        (status = 422, description = "Invalid schedule"),
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn update_care_task(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path((plant_id, task_type)): Path<(PlantId, String)>,
+    ValidatedJson(payload): ValidatedJson<UpdateCareTaskRequest>,
+) -> Result<Json<CareTask>> {
+    tracing::info!(
+        "Update {} care task for plant: {} by user: {}",
+        task_type,
+        plant_id,
+        user.id
+    );
+
+    let care_task =
+        db_care_tasks::update_care_task(&app_state.pool, plant_id, &user.id, &task_type, &payload)
+            .await?;
+
+    Ok(Json(care_task))
+}
+
+/// Remove a care task; entries already logged for it are kept
+#[utoipa::path(
+    delete,
+    path = "/plants/{plant_id}/care-tasks/{task_type}",
+    params(
+        ("plant_id" = Uuid, Path, description = "Plant ID"),
+        ("task_type" = String, Path, description = "Care task type, e.g. misting")
+    ),
+    responses(
+        (status = 204, description = "Care task removed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant or care task not found"),
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn delete_care_task(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path((plant_id, task_type)): Path<(PlantId, String)>,
+) -> Result<StatusCode> {
+    tracing::info!(
+        "Delete {} care task for plant: {} by user: {}",
+        task_type,
+        plant_id,
+        user.id
+    );
+
+    db_care_tasks::delete_care_task(&app_state.pool, plant_id, &user.id, &task_type).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,62 @@
+use axum::{extract::State, response::Json, routing::get, Router};
+
+use crate::app_state::AppState;
+use crate::auth::CurrentUser;
+use crate::database::{plants as db_plants, tracking as db_tracking, users as db_users};
+use crate::models::dashboard::DashboardSummary;
+use crate::models::tracking_entry::EntryType;
+use crate::models::PlantSummary;
+use crate::utils::errors::Result;
+
+pub fn routes() -> Router<AppState> {
+    Router::new().route("/summary", get(get_summary))
+}
+
+/// Totals across the current user's plants for the dashboard header
+#[utoipa::path(
+    get,
+    path = "/dashboard/summary",
+    responses(
+        (status = 200, description = "Dashboard totals", body = DashboardSummary),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "dashboard",
+    security(
+        ("session" = [])
+    )
+)]
+pub async fn get_summary(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<DashboardSummary>> {
+    let now = chrono::Utc::now();
+    let preferences = db_users::get_preferences(&app_state.pool, &user.id).await?;
+    let care_day = preferences.care_day();
+
+    let (plants, total_plants) =
+        db_plants::list_plants_for_user(&app_state.pool, &user.id, i64::MAX, 0, None).await?;
+    let total_photos = plants.iter().map(|plant| plant.photo_count).sum();
+    let needs_care_today = plants
+        .into_iter()
+        .map(|plant| {
+            PlantSummary::new(plant, now, care_day, app_state.config.reminder_lead_hours)
+        })
+        .filter(|summary| summary.needs_care_today(now, care_day))
+        .count() as i64;
+
+    let week_start = preferences.week_start.start_of_week(care_day.date_of(now));
+    let waterings_this_week = db_tracking::count_user_entries_since(
+        &app_state.pool,
+        &user.id,
+        &EntryType::Watering,
+        care_day.start_of(week_start),
+    )
+    .await?;
+
+    Ok(Json(DashboardSummary {
+        total_plants,
+        needs_care_today,
+        waterings_this_week,
+        total_photos,
+    }))
+}
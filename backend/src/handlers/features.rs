@@ -0,0 +1,45 @@
+use axum::{extract::State, response::Json, routing::get, Router};
+
+use crate::app_state::AppState;
+use crate::database::admin_settings::DEFAULT_REGISTRATION_ENABLED;
+use crate::models::features::FeatureFlags;
+use crate::utils::errors::Result;
+
+pub fn routes() -> Router<AppState> {
+    Router::new().route("/", get(get_features))
+}
+
+/// Optional features active on this server, for the frontend to show or hide
+/// what it offers. Available without a session.
+#[utoipa::path(
+    get,
+    path = "/features",
+    responses(
+        (status = 200, description = "Active features", body = FeatureFlags)
+    ),
+    tag = "features"
+)]
+pub async fn get_features(State(app_state): State<AppState>) -> Result<Json<FeatureFlags>> {
+    let flags = app_state
+        .features
+        .get_or_resolve(|| resolve_features(&app_state))
+        .await?;
+    Ok(Json(flags))
+}
+
+async fn resolve_features(app_state: &AppState) -> Result<FeatureFlags> {
+    let registration_enabled: Option<String> =
+        sqlx::query_scalar("SELECT value FROM admin_settings WHERE key = 'registration_enabled'")
+            .fetch_optional(&app_state.pool)
+            .await?;
+
+    Ok(FeatureFlags {
+        google_integration: app_state.google_integration_enabled
+            && app_state.config.google.is_some(),
+        registration_enabled: registration_enabled
+            .and_then(|value| value.parse::<bool>().ok())
+            .unwrap_or(DEFAULT_REGISTRATION_ENABLED),
+        // The waitlist has no switch of its own
+        waitlist_enabled: true,
+    })
+}
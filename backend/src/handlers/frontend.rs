@@ -0,0 +1,99 @@
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::{header, HeaderValue, StatusCode},
+    middleware::map_response,
+    response::{Html, Response},
+    routing::get,
+    Router,
+};
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
+use tower_http::services::ServeDir;
+
+/// Directory the frontend build puts fingerprinted (content-hashed) files in
+const ASSETS_DIR: &str = "assets";
+
+/// Cache policy for files whose name changes whenever their content does
+const IMMUTABLE: HeaderValue = HeaderValue::from_static("public, max-age=31536000, immutable");
+
+/// Cache policy for everything else, so a deploy is picked up on the next load
+const NO_CACHE: HeaderValue = HeaderValue::from_static("no-cache");
+
+/// `index.html`, read from disk at most once per TTL
+pub struct IndexCache {
+    path: PathBuf,
+    ttl: Duration,
+    cached: RwLock<Option<(Instant, Bytes)>>,
+}
+
+impl IndexCache {
+    pub fn new(frontend_dir: &Path, ttl: Duration) -> Self {
+        Self {
+            path: frontend_dir.join("index.html"),
+            ttl,
+            cached: RwLock::new(None),
+        }
+    }
+
+    /// The page's contents, re-read once the cached copy is older than the TTL
+    async fn load(&self) -> std::io::Result<Bytes> {
+        if let Some((loaded_at, content)) = self.cached.read().unwrap().as_ref() {
+            if loaded_at.elapsed() < self.ttl {
+                return Ok(content.clone());
+            }
+        }
+
+        let content = Bytes::from(tokio::fs::read(&self.path).await?);
+        *self.cached.write().unwrap() = Some((Instant::now(), content.clone()));
+        Ok(content)
+    }
+}
+
+/// Serves the built SPA from `frontend_dir`
+///
+/// Files under `assets/` are cached by browsers indefinitely; everything else,
+/// including `index.html` for client-side routes, must be revalidated.
+pub fn routes(frontend_dir: &Path, index_ttl: Duration) -> Router {
+    let index = Arc::new(IndexCache::new(frontend_dir, index_ttl));
+
+    // No SPA fallback here: a missing asset is a 404, not a cacheable page
+    let assets = Router::new()
+        .nest_service("/assets", ServeDir::new(frontend_dir.join(ASSETS_DIR)))
+        .layer(map_response(cache_immutable));
+
+    Router::new()
+        .route("/", get(serve_index))
+        .route("/index.html", get(serve_index))
+        .fallback_service(
+            ServeDir::new(frontend_dir)
+                .append_index_html_on_directories(true)
+                .fallback(get(serve_index).with_state(index.clone())),
+        )
+        .with_state(index)
+        .layer(map_response(cache_no_cache))
+        .merge(assets)
+}
+
+// SPA fallback handler - serves index.html for unmatched routes
+async fn serve_index(State(index): State<Arc<IndexCache>>) -> Result<Html<Bytes>, StatusCode> {
+    index.load().await.map(Html).map_err(|_| {
+        tracing::error!("Failed to read index.html from: {}", index.path.display());
+        StatusCode::NOT_FOUND
+    })
+}
+
+async fn cache_immutable(mut response: Response) -> Response {
+    if response.status().is_success() {
+        response.headers_mut().insert(header::CACHE_CONTROL, IMMUTABLE);
+    }
+    response
+}
+
+async fn cache_no_cache(mut response: Response) -> Response {
+    response.headers_mut().insert(header::CACHE_CONTROL, NO_CACHE);
+    response
+}
@@ -1,5 +1,6 @@
 use axum::{
     extract::{Query, State},
+    middleware,
     response::{IntoResponse, Redirect},
     routing::{get, post},
     Json, Router,
@@ -9,34 +10,66 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 use crate::app_state::AppState;
-use crate::auth::AuthSession;
-use crate::database::{google_oauth, plants as db_plants};
+use crate::auth::CurrentUser;
+use crate::database::{google_oauth, plants as db_plants, users as db_users};
+use crate::middleware::require_user::require_user;
+use crate::models::plant::{care_occurrences, CareKind};
 use crate::models::google_oauth::{
     CreateGoogleTaskRequest, GoogleOAuthCallbackRequest, GoogleOAuthSuccessResponse,
-    GoogleOAuthUrlResponse, GoogleTasksStatus, SyncPlantTasksRequest,
+    GoogleOAuthUrlResponse, GoogleTaskListsResponse, GoogleTasksStatus, SyncPlantTasksRequest,
+    TokenRefreshResponse,
 };
 use crate::utils::errors::{AppError, Result};
 use crate::utils::google_tasks::{
-    create_plant_care_task, ensure_valid_token, exchange_code_for_tokens, generate_auth_url,
-    generate_oauth_state, get_or_create_plant_care_task_list, GoogleTasksConfig,
+    create_plant_care_task, ensure_valid_token, exchange_code_for_tokens, fetch_account_email,
+    generate_auth_url, generate_oauth_state, get_or_create_plant_care_task_list,
+    list_task_lists as fetch_task_lists, SyncedTask,
 };
 
 /// Create Google Tasks routes
 pub fn routes() -> Router<AppState> {
     Router::new()
         .route("/auth-url", get(get_google_auth_url))
-        .route("/callback", get(handle_google_oauth_callback))
         .route("/store-tokens", post(store_google_tokens))
         .route("/status", get(get_google_tasks_status))
         .route("/disconnect", post(disconnect_google_tasks))
+        .route("/refresh-token", post(refresh_google_token))
+        .route("/lists", get(list_task_lists))
         .route("/sync-tasks", post(sync_plant_tasks))
         .route("/create-task", post(create_task))
+        .route_layer(middleware::from_fn(require_user))
+        // Google redirects here without our session; the user is in the state
+        .route("/callback", get(handle_google_oauth_callback))
+}
+
+/// Routes mounted instead of [`routes`] when the Google integration is disabled.
+/// Only the status endpoint remains so clients can tell the feature is off.
+pub fn disabled_routes() -> Router<AppState> {
+    Router::new()
+        .route("/status", get(get_google_tasks_disabled_status))
+        .route_layer(middleware::from_fn(require_user))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GoogleAuthUrlQuery {
+    /// Replace an existing connection instead of reporting it as already connected
+    #[serde(default)]
+    force: bool,
 }
 
+/// Marks states whose callback may replace an existing connection
+const FORCE_STATE_SUFFIX: &str = ":force";
+
 /// Generate Google OAuth authorization URL
+///
+/// Completing the flow while already connected leaves the existing connection
+/// in place unless `force=true` is passed here.
 #[utoipa::path(
     get,
     path = "/google-tasks/auth-url",
+    params(
+        ("force" = Option<bool>, Query, description = "Replace an existing Google connection")
+    ),
     responses(
         (status = 200, description = "Google OAuth authorization URL", body = GoogleOAuthUrlResponse),
         (status = 401, description = "Unauthorized"),
@@ -47,15 +80,18 @@ pub fn routes() -> Router<AppState> {
         ("session" = [])
     )
 )]
-pub async fn get_google_auth_url(auth_session: AuthSession) -> Result<impl IntoResponse> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
-    let config = GoogleTasksConfig::from_env()?;
-    // Include user ID in the state parameter
-    let state = format!("{}:{}", generate_oauth_state(), user.id);
-    let auth_url = generate_auth_url(&config, &state);
+pub async fn get_google_auth_url(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Query(query): Query<GoogleAuthUrlQuery>,
+) -> Result<impl IntoResponse> {
+    let config = app_state.config.google_tasks()?;
+    // Include user ID, and whether to replace an existing connection, in the state parameter
+    let mut state = format!("{}:{}", generate_oauth_state(), user.id);
+    if query.force {
+        state.push_str(FORCE_STATE_SUFFIX);
+    }
+    let auth_url = generate_auth_url(config, &state);
 
     tracing::info!("Generated Google OAuth URL for user: {}", user.id);
 
@@ -63,6 +99,10 @@ pub async fn get_google_auth_url(auth_session: AuthSession) -> Result<impl IntoR
 }
 
 /// Handle Google OAuth callback
+///
+/// If the user already has a usable connection and the flow wasn't started
+/// with `force=true`, the new authorization is discarded and the frontend is
+/// sent to `/calendar-settings?google=already_connected`.
 #[utoipa::path(
     get,
     path = "/google-tasks/callback",
@@ -82,11 +122,11 @@ pub async fn handle_google_oauth_callback(
 ) -> Result<impl IntoResponse> {
     tracing::info!("Handling Google OAuth callback with code: {}", params.code);
     
-    let config = GoogleTasksConfig::from_env()?;
+    let config = app_state.config.google_tasks()?;
     tracing::info!("Google OAuth config loaded successfully");
 
     // Extract user ID from state parameter
-    let user_id = if let Some(state) = &params.state {
+    let (user_id, force) = if let Some(state) = &params.state {
         // URL decode the state parameter first
         let decoded_state = urlencoding::decode(state).map_err(|e| {
             tracing::error!("Failed to decode state parameter: {}", e);
@@ -95,9 +135,12 @@ pub async fn handle_google_oauth_callback(
             }
         })?;
         
-        // State format is "random_string:user_id"
-        if let Some((_, user_id)) = decoded_state.split_once(':') {
-            user_id.to_string()
+        // State format is "random_string:user_id", with ":force" appended to replace a connection
+        if let Some((_, rest)) = decoded_state.split_once(':') {
+            match rest.strip_suffix(FORCE_STATE_SUFFIX) {
+                Some(user_id) => (user_id.to_string(), true),
+                None => (rest.to_string(), false),
+            }
         } else {
             tracing::error!("Invalid state parameter format: {}", decoded_state);
             return Err(AppError::Authentication {
@@ -113,9 +156,25 @@ pub async fn handle_google_oauth_callback(
 
     tracing::info!("Extracted user ID from state: {}", user_id);
 
+    let settings_url = format!("{}/calendar-settings", app_state.config.frontend_url);
+
+    if !force {
+        let existing = google_oauth::get_oauth_token(&app_state.pool, &user_id).await?;
+        if existing.is_some_and(|token| token.is_usable()) {
+            tracing::info!(
+                "User {} is already connected to Google Tasks, keeping the existing token",
+                user_id
+            );
+            return Ok(Redirect::temporary(&format!(
+                "{}?google=already_connected",
+                settings_url
+            )));
+        }
+    }
+
     // Exchange code for tokens
     let (access_token, refresh_token, expires_at) =
-        exchange_code_for_tokens(&config, &params.code).await?;
+        exchange_code_for_tokens(config, &params.code).await?;
 
     tracing::info!(
         "Successfully exchanged OAuth code for tokens for user: {}",
@@ -135,21 +194,16 @@ pub async fn handle_google_oauth_callback(
     )
     .await?;
 
+    record_connected_account(&app_state, &user_id, &access_token).await?;
+
     tracing::info!("Stored Google OAuth tokens for user: {}", user_id);
 
     // Notify the token refresh scheduler about the new token
     app_state.notify_token_added();
 
     // Redirect back to calendar settings without any parameters
-    let frontend_url = std::env::var("FRONTEND_URL").unwrap_or_else(|_| {
-        let host_ip = std::env::var("HOST_IP").unwrap_or_else(|_| "localhost".to_string());
-        format!("http://{}:3000", host_ip)
-    });
-
-    let redirect_url = format!("{}/calendar-settings", frontend_url);
-
-    tracing::info!("Google OAuth callback successful, redirecting to: {}", redirect_url);
-    Ok(Redirect::temporary(&redirect_url))
+    tracing::info!("Google OAuth callback successful, redirecting to: {}", settings_url);
+    Ok(Redirect::temporary(&settings_url))
 }
 
 /// Store Google OAuth tokens (called by frontend after callback)
@@ -169,13 +223,9 @@ pub async fn handle_google_oauth_callback(
 )]
 pub async fn store_google_tokens(
     State(app_state): State<AppState>,
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
     Json(request): Json<StoreTokensRequest>,
 ) -> Result<impl IntoResponse> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
     let expires_at = if request.expires_at > 0 {
         Some(chrono::DateTime::from_timestamp(request.expires_at, 0).unwrap_or_else(Utc::now))
     } else {
@@ -194,6 +244,8 @@ pub async fn store_google_tokens(
     )
     .await?;
 
+    record_connected_account(&app_state, &user.id, &request.access_token).await?;
+
     tracing::info!("Stored Google OAuth tokens for user: {}", user.id);
 
     // Notify the token refresh scheduler about the new token
@@ -207,6 +259,26 @@ pub async fn store_google_tokens(
     }))
 }
 
+/// Remember which Google account was just connected, when it can be looked up
+async fn record_connected_account(
+    app_state: &AppState,
+    user_id: &str,
+    access_token: &str,
+) -> Result<()> {
+    // The new account has its own task list
+    app_state.task_lists.forget(user_id);
+
+    let Ok(config) = app_state.config.google_tasks() else {
+        return Ok(());
+    };
+
+    if let Some(email) = fetch_account_email(config, access_token).await {
+        google_oauth::record_account_email(&app_state.pool, user_id, &email).await?;
+    }
+
+    Ok(())
+}
+
 #[derive(Deserialize, Serialize, ToSchema)]
 pub struct StoreTokensRequest {
     /// The access token from Google OAuth
@@ -232,12 +304,8 @@ pub struct StoreTokensRequest {
 )]
 pub async fn get_google_tasks_status(
     State(app_state): State<AppState>,
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
 ) -> Result<impl IntoResponse> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
     let token = google_oauth::get_oauth_token(&app_state.pool, &user.id).await?;
 
     let status = match token {
@@ -252,6 +320,7 @@ pub async fn get_google_tasks_status(
             };
 
             GoogleTasksStatus {
+                enabled: true,
                 connected: is_valid,
                 connected_at: Some(token.created_at),
                 scopes: Some(
@@ -262,19 +331,40 @@ pub async fn get_google_tasks_status(
                         .collect(),
                 ),
                 expires_at: token.expires_at,
+                account_email: google_oauth::get_account_email(&app_state.pool, &user.id)
+                    .await?,
+                message: None,
             }
         }
         None => GoogleTasksStatus {
+            enabled: true,
             connected: false,
             connected_at: None,
             scopes: None,
             expires_at: None,
+            account_email: None,
+            message: None,
         },
     };
 
     Ok(Json(status))
 }
 
+/// Connection status reported when `GOOGLE_INTEGRATION_ENABLED` is off
+pub async fn get_google_tasks_disabled_status(
+    CurrentUser(_user): CurrentUser,
+) -> Result<impl IntoResponse> {
+    Ok(Json(GoogleTasksStatus {
+        enabled: false,
+        connected: false,
+        connected_at: None,
+        scopes: None,
+        expires_at: None,
+        account_email: None,
+        message: Some("Google integration is disabled on this server".to_string()),
+    }))
+}
+
 /// Disconnect Google Tasks integration
 #[utoipa::path(
     post,
@@ -291,15 +381,18 @@ pub async fn get_google_tasks_status(
 )]
 pub async fn disconnect_google_tasks(
     State(app_state): State<AppState>,
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
 ) -> Result<impl IntoResponse> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
     google_oauth::delete_oauth_token(&app_state.pool, &user.id).await?;
+    app_state.task_lists.forget(&user.id);
+    // A later connection may be to another account, whose tasks these aren't
+    let cleared = google_oauth::clear_synced_tasks(&app_state.pool, &user.id).await?;
 
-    tracing::info!("Disconnected Google Tasks for user: {}", user.id);
+    tracing::info!(
+        "Disconnected Google Tasks for user: {} ({} synced tasks forgotten)",
+        user.id,
+        cleared
+    );
 
     Ok(Json(serde_json::json!({
         "success": true,
@@ -307,15 +400,94 @@ pub async fn disconnect_google_tasks(
     })))
 }
 
+/// Refresh the current user's access token if it is close to expiring
+#[utoipa::path(
+    post,
+    path = "/google-tasks/refresh-token",
+    responses(
+        (status = 200, description = "Token expiry, or a flag that the user must reconnect", body = TokenRefreshResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "No Google Tasks connection found"),
+        (status = 500, description = "Configuration error")
+    ),
+    tag = "google-tasks",
+    security(
+        ("session" = [])
+    )
+)]
+pub async fn refresh_google_token(
+    State(app_state): State<AppState>,
+    CurrentUser(user): CurrentUser,
+) -> Result<impl IntoResponse> {
+    let config = app_state.config.google_tasks()?;
+
+    let existing = google_oauth::get_oauth_token(&app_state.pool, &user.id)
+        .await?
+        .ok_or_else(|| AppError::NotFound {
+            resource: "Google Tasks connection".to_string(),
+        })?;
+
+    let response = match ensure_valid_token(&app_state.pool, &user.id, config).await {
+        Ok(token) => TokenRefreshResponse {
+            expires_at: token.expires_at,
+            needs_reauth: false,
+            message: None,
+        },
+        // Missing or rejected refresh token: only reconnecting can fix it
+        Err(AppError::Authentication { message }) => {
+            tracing::info!("Token refresh for user {} needs reauth: {}", user.id, message);
+            TokenRefreshResponse {
+                expires_at: existing.expires_at,
+                needs_reauth: true,
+                message: Some(message),
+            }
+        }
+        Err(e) => return Err(e),
+    };
+
+    Ok(Json(response))
+}
+
+/// List the user's Google Tasks lists, to pick one to sync into
+#[utoipa::path(
+    get,
+    path = "/google-tasks/lists",
+    responses(
+        (status = 200, description = "The user's task lists", body = GoogleTaskListsResponse),
+        (status = 401, description = "Unauthorized, or no usable Google Tasks connection"),
+        (status = 502, description = "Google Tasks request failed")
+    ),
+    tag = "google-tasks",
+    security(
+        ("session" = [])
+    )
+)]
+pub async fn list_task_lists(
+    State(app_state): State<AppState>,
+    CurrentUser(user): CurrentUser,
+) -> Result<Json<GoogleTaskListsResponse>> {
+    let config = app_state.config.google_tasks()?;
+    let token = ensure_valid_token(&app_state.pool, &user.id, config).await?;
+
+    let lists = fetch_task_lists(&token).await?;
+
+    Ok(Json(GoogleTaskListsResponse { lists }))
+}
+
 /// Sync plant care tasks to Google Tasks
+///
+/// Tasks go into `task_list_id` when given, which must be one of the user's
+/// lists, and otherwise into the "Plant Care" list. Care already synced for
+/// the same plant, task type and day is skipped rather than created again,
+/// including tasks synced into "Plant Care" before lists were tracked.
 #[utoipa::path(
     post,
     path = "/google-tasks/sync-tasks",
     request_body = SyncPlantTasksRequest,
     responses(
-        (status = 200, description = "Plant tasks synced successfully"),
+        (status = 200, description = "Plant tasks synced, with counts of tasks created and skipped as already synced"),
         (status = 401, description = "Unauthorized"),
-        (status = 404, description = "No Google Tasks connection found"),
+        (status = 404, description = "No Google Tasks connection found, or no such task list"),
         (status = 500, description = "Failed to sync tasks")
     ),
     tag = "google-tasks",
@@ -325,100 +497,131 @@ pub async fn disconnect_google_tasks(
 )]
 pub async fn sync_plant_tasks(
     State(app_state): State<AppState>,
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
     Json(request): Json<SyncPlantTasksRequest>,
 ) -> Result<impl IntoResponse> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
-    let config = GoogleTasksConfig::from_env()?;
-    let token = ensure_valid_token(&app_state.pool, &user.id, &config).await?;
-
-    // Get or create the "Plant Care" task list
-    let task_list_id = get_or_create_plant_care_task_list(&token).await?;
+    let config = app_state.config.google_tasks()?;
+    let token = ensure_valid_token(&app_state.pool, &user.id, config).await?;
+
+    let task_list_id = match request.task_list_id {
+        Some(task_list_id) => {
+            let lists = fetch_task_lists(&token).await?;
+            if !lists.iter().any(|list| list.id == task_list_id) {
+                return Err(AppError::NotFound {
+                    resource: "Google Tasks list".to_string(),
+                });
+            }
+            task_list_id
+        }
+        // Get or create the "Plant Care" task list, reusing one resolved recently
+        None => {
+            let task_list_id = app_state
+                .task_lists
+                .get_or_resolve(&user.id, || get_or_create_plant_care_task_list(&token))
+                .await?;
+            google_oauth::claim_legacy_synced_tasks(&app_state.pool, &user.id, &task_list_id)
+                .await?;
+            task_list_id
+        }
+    };
 
     // Get user's plants
     let (plants, _) = db_plants::list_plants_for_user(&app_state.pool, &user.id, 1000, 0, None).await?;
+    let timing = db_users::get_care_timing(&app_state.pool, &user.id).await?;
 
     let days_ahead = request.days_ahead.unwrap_or(365);
-    let base_url =
-        std::env::var("BASE_URL").unwrap_or_else(|_| "https://your-domain.com".to_string());
+    let base_url = app_state
+        .config
+        .base_url
+        .as_deref()
+        .unwrap_or("https://your-domain.com");
 
     let mut created_tasks = 0;
+    let mut skipped_tasks = 0;
     let now = Utc::now();
     let end_date = now + chrono::Duration::days(days_ahead as i64);
 
     for plant in &plants {
-        // Generate watering tasks
-        if let Some(watering_interval) = plant.watering_schedule.interval_days {
-            let last_watered = plant
-                .last_watered
-                .unwrap_or_else(|| now - chrono::Duration::days(watering_interval as i64));
-
-            let mut next_watering =
-                last_watered + chrono::Duration::days(watering_interval as i64);
-        while next_watering <= end_date && next_watering >= now {
-            match create_plant_care_task(
-                &token,
-                plant,
-                "watering",
-                next_watering,
-                &base_url,
-                &task_list_id,
-            )
-            .await
+        for care in CareKind::ALL {
+            let (schedule, last_care) = care.schedule_of(plant);
+            let Some(interval_days) = schedule.interval_days else {
+                continue;
+            };
+            let task_type = match care {
+                CareKind::Watering => "watering",
+                CareKind::Fertilizing => "fertilizing",
+            };
+
+            for due in care_occurrences(last_care, interval_days, now, timing)
+                .take_while(|date| *date <= end_date)
             {
-                Ok(_task_id) => created_tasks += 1,
-                Err(e) => {
-                    tracing::error!("Failed to create watering task for {}: {}", plant.name, e)
+                match create_plant_care_task(
+                    &app_state.pool,
+                    &token,
+                    plant,
+                    task_type,
+                    due,
+                    base_url,
+                    &task_list_id,
+                )
+                .await
+                {
+                    Ok(SyncedTask::Created(_)) => created_tasks += 1,
+                    Ok(SyncedTask::Skipped(_)) => skipped_tasks += 1,
+                    Err(e) => tracing::error!(
+                        "Failed to create {} task for {}: {}",
+                        task_type,
+                        plant.name,
+                        e
+                    ),
                 }
             }
-            next_watering += chrono::Duration::days(watering_interval as i64);
-        }
         }
 
-        // Generate fertilizing tasks
-        if let Some(fertilizing_interval) = plant.fertilizing_schedule.interval_days {
-            let last_fertilized = plant.last_fertilized.unwrap_or_else(|| {
-                now - chrono::Duration::days(fertilizing_interval as i64)
-            });
+        for task in &plant.care_tasks {
+            let Some(interval_days) = task.schedule.interval_days else {
+                continue;
+            };
 
-            let mut next_fertilizing =
-                last_fertilized + chrono::Duration::days(fertilizing_interval as i64);
-        while next_fertilizing <= end_date && next_fertilizing >= now {
-            match create_plant_care_task(
-                &token,
-                plant,
-                "fertilizing",
-                next_fertilizing,
-                &base_url,
-                &task_list_id,
-            )
-            .await
+            for due in care_occurrences(task.last_done, interval_days, now, timing)
+                .take_while(|date| *date <= end_date)
             {
-                Ok(_task_id) => created_tasks += 1,
-                Err(e) => tracing::error!(
-                    "Failed to create fertilizing task for {}: {}",
-                    plant.name,
-                    e
-                ),
+                match create_plant_care_task(
+                    &app_state.pool,
+                    &token,
+                    plant,
+                    &task.task_type,
+                    due,
+                    base_url,
+                    &task_list_id,
+                )
+                .await
+                {
+                    Ok(SyncedTask::Created(_)) => created_tasks += 1,
+                    Ok(SyncedTask::Skipped(_)) => skipped_tasks += 1,
+                    Err(e) => tracing::error!(
+                        "Failed to create {} task for {}: {}",
+                        task.task_type,
+                        plant.name,
+                        e
+                    ),
+                }
             }
-            next_fertilizing += chrono::Duration::days(fertilizing_interval as i64);
-        }
         }
     }
 
     tracing::info!(
-        "Synced {} plant care tasks to Google Tasks for user: {}",
+        "Synced {} plant care tasks to Google Tasks for user: {} ({} already synced)",
         created_tasks,
-        user.id
+        user.id,
+        skipped_tasks
     );
 
     Ok(Json(serde_json::json!({
         "success": true,
         "message": format!("Created {} plant care tasks in your Google Tasks", created_tasks),
         "tasks_created": created_tasks,
+        "tasks_skipped": skipped_tasks,
         "plants_processed": plants.len(),
         "days_ahead": days_ahead
     })))
@@ -442,21 +645,20 @@ pub async fn sync_plant_tasks(
 )]
 pub async fn create_task(
     State(app_state): State<AppState>,
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
     Json(request): Json<CreateGoogleTaskRequest>,
 ) -> Result<impl IntoResponse> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
-    let config = GoogleTasksConfig::from_env()?;
-    let token = ensure_valid_token(&app_state.pool, &user.id, &config).await?;
+    let config = app_state.config.google_tasks()?;
+    let token = ensure_valid_token(&app_state.pool, &user.id, config).await?;
 
     // Get or create task list
     let task_list_id = if let Some(list_id) = request.task_list_id {
         list_id
     } else {
-        get_or_create_plant_care_task_list(&token).await?
+        app_state
+            .task_lists
+            .get_or_resolve(&user.id, || get_or_create_plant_care_task_list(&token))
+            .await?
     };
 
     let client = reqwest::Client::new();
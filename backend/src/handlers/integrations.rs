@@ -0,0 +1,78 @@
+use axum::{extract::State, response::Json, routing::get, Router};
+
+use crate::app_state::AppState;
+use crate::auth::CurrentUser;
+use crate::database::{google_oauth, users as db_users};
+use crate::models::integration::{IntegrationName, IntegrationStatus, IntegrationsResponse};
+use crate::utils::errors::Result;
+
+const TASKS_SCOPE: &str = "https://www.googleapis.com/auth/tasks";
+const CALENDAR_SCOPE: &str = "https://www.googleapis.com/auth/calendar";
+
+/// Calendar apps poll the feed at least daily, so one unfetched for a week
+/// no longer has a subscriber
+const ICAL_FEED_IDLE_DAYS: i64 = 7;
+
+pub fn routes() -> Router<AppState> {
+    Router::new().route("/", get(list_integrations))
+}
+
+/// List the integrations this server supports and their status for the current user
+#[utoipa::path(
+    get,
+    path = "/integrations",
+    responses(
+        (status = 200, description = "Supported integrations", body = IntegrationsResponse),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "integrations",
+    security(
+        ("session" = [])
+    )
+)]
+pub async fn list_integrations(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<IntegrationsResponse>> {
+    let google_enabled = app_state.google_integration_enabled;
+    let google_configured = app_state.config.google.is_some();
+
+    let token = if google_enabled {
+        google_oauth::get_oauth_token(&app_state.pool, &user.id).await?
+    } else {
+        None
+    };
+    let has_scope = |scope: &str| {
+        token
+            .as_ref()
+            .is_some_and(|token| token.scope.split([',', ' ']).any(|s| s.trim() == scope))
+    };
+
+    let feed_fetched_at = db_users::get_calendar_feed_fetched_at(&app_state.pool, &user.id).await?;
+    let feed_subscribed = feed_fetched_at.is_some_and(|fetched_at| {
+        fetched_at > chrono::Utc::now() - chrono::Duration::days(ICAL_FEED_IDLE_DAYS)
+    });
+
+    let integrations = vec![
+        IntegrationStatus {
+            name: IntegrationName::GoogleTasks,
+            enabled: google_enabled,
+            configured: google_configured,
+            connected: has_scope(TASKS_SCOPE),
+        },
+        IntegrationStatus {
+            name: IntegrationName::GoogleCalendar,
+            enabled: google_enabled,
+            configured: google_configured,
+            connected: has_scope(CALENDAR_SCOPE),
+        },
+        IntegrationStatus {
+            name: IntegrationName::IcalFeed,
+            enabled: true,
+            configured: true,
+            connected: feed_subscribed,
+        },
+    ];
+
+    Ok(Json(IntegrationsResponse { integrations }))
+}
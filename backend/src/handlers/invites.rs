@@ -39,7 +39,10 @@ struct ListInvitesQuery {
         (status = 401, description = "Unauthorized"),
         (status = 400, description = "Invalid request"),
     ),
-    tag = "invites"
+    tag = "invites",
+    security(
+        ("session" = [])
+    )
 )]
 async fn create_invite(
     auth_session: AuthSession,
@@ -94,7 +97,7 @@ async fn validate_invite(
     tracing::info!("Invite code is valid: {}", payload.code);
     Ok(Json(serde_json::json!({
         "valid": true,
-        "uses_remaining": invite.max_uses - invite.current_uses
+        "usesRemaining": invite.max_uses - invite.current_uses
     })))
 }
 
@@ -108,7 +111,10 @@ async fn validate_invite(
         (status = 200, description = "List of invite codes", body = Vec<InviteResponse>),
         (status = 401, description = "Unauthorized"),
     ),
-    tag = "invites"
+    tag = "invites",
+    security(
+        ("session" = [])
+    )
 )]
 async fn list_invites(
     auth_session: AuthSession,
@@ -158,7 +164,10 @@ async fn join_waitlist(
         (status = 200, description = "List of waitlist entries", body = Vec<WaitlistResponse>),
         (status = 401, description = "Unauthorized"),
     ),
-    tag = "invites"
+    tag = "invites",
+    security(
+        ("session" = [])
+    )
 )]
 async fn list_waitlist(
     auth_session: AuthSession,
@@ -1,8 +1,19 @@
 pub mod admin;
+pub mod api_keys;
 pub mod auth;
 pub mod calendar;
+pub mod care_tasks;
+pub mod dashboard;
+pub mod features;
+pub mod frontend;
 pub mod google_tasks;
+pub mod integrations;
 pub mod invites;
 pub mod photos;
 pub mod plants;
+pub mod public_links;
+pub mod reports;
+pub mod settings;
+pub mod sync;
+pub mod tags;
 pub mod tracking;
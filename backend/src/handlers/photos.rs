@@ -1,19 +1,28 @@
 use axum::{
     body::Body,
-    extract::{Multipart, Path, Query, State},
-    http::{header, StatusCode},
+    extract::{Multipart, OriginalUri, Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
     response::{Json, Response},
-    routing::get,
+    routing::{get, post},
     Router,
 };
 use serde::Deserialize;
-use uuid::Uuid;
+use sha2::{Digest, Sha256};
+use validator::Validate;
 
 use crate::app_state::AppState;
-use crate::auth::AuthSession;
+use crate::auth::CurrentUser;
 use crate::database::photos as db_photos;
-use crate::models::{Photo, UploadPhotoRequest};
+use crate::middleware::validation::ValidatedJson;
+use crate::models::{Photo, PhotoId, PlantId, UpdatePhotoRequest, UploadPhotoRequest};
 use crate::utils::errors::{AppError, Result};
+use crate::utils::hex::to_hex;
+use crate::utils::http_cache::etag_matches;
+use crate::utils::image_processing::ServeFormat;
+use crate::utils::pagination::{offset_links, PageLinks};
+
+/// Photos and thumbnails change when a photo is reprocessed, so clients revalidate them
+const IMAGE_CACHE_CONTROL: &str = "private, no-cache";
 
 #[derive(Debug, Deserialize)]
 struct ListPhotosQuery {
@@ -29,6 +38,8 @@ struct PhotosResponse {
     total: i64,
     limit: i64,
     offset: i64,
+    next: Option<String>,
+    prev: Option<String>,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -42,28 +53,48 @@ struct PhotoWithUrlWrapper {
 pub fn routes() -> Router<AppState> {
     Router::new()
         .route("/photos", get(list_photos).post(upload_photo))
-        .route("/photos/:photo_id", get(serve_photo).delete(delete_photo))
+        .route(
+            "/photos/:photo_id",
+            get(serve_photo).put(update_photo).delete(delete_photo),
+        )
+        .route("/photos/:photo_id/thumbnail", get(serve_photo_thumbnail))
+        .route("/photos/:photo_id/metadata", get(get_photo_metadata))
+        .route("/photos/:photo_id/reprocess", post(reprocess_photo))
 }
 
+#[utoipa::path(
+    get,
+    path = "/plants/{plant_id}/photos",
+    params(
+        ("plant_id" = Uuid, Path, description = "Plant ID"),
+        ("limit" = Option<i64>, Query, description = "Maximum number of photos to return (default 50)"),
+        ("offset" = Option<i64>, Query, description = "Number of photos to skip"),
+        ("sort" = Option<String>, Query, description = "Sort order: date_desc (default) or date_asc")
+    ),
+    responses(
+        (status = 200, description = "Photos for the plant, each with a url", body = crate::models::PhotosResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant not found")
+    ),
+    tag = "photos",
+    security(
+        ("session" = [])
+    )
+)]
 async fn list_photos(
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
     State(app_state): State<AppState>,
-    Path(plant_id): Path<Uuid>,
+    OriginalUri(uri): OriginalUri,
+    Path(plant_id): Path<PlantId>,
     Query(params): Query<ListPhotosQuery>,
 ) -> Result<Json<PhotosResponse>> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
     tracing::info!(
         "List photos request for plant: {} by user: {}",
         plant_id,
         user.id
     );
 
-    // Parse query parameters
-    let limit = params.limit.unwrap_or(50);
-    let offset = params.offset.unwrap_or(0);
+    // Parse query parameters; limit and offset defaults are applied by the query
     let sort_desc = match params.sort.as_deref() {
         Some("date_asc") => false,
         _ => true, // default to date_desc
@@ -71,10 +102,10 @@ async fn list_photos(
 
     let response = db_photos::get_photos_for_plant_paginated(
         &app_state.pool,
-        &plant_id,
+        plant_id,
         &user.id,
-        Some(limit),
-        Some(offset),
+        params.limit,
+        params.offset,
         Some(sort_desc),
     )
     .await?;
@@ -96,23 +127,25 @@ async fn list_photos(
         plant_id
     );
 
+    let PageLinks { next, prev } =
+        offset_links(&uri, response.total, response.limit, response.offset);
+
     Ok(Json(PhotosResponse {
         photos: photos_with_urls,
         total: response.total,
-        limit,
-        offset,
+        limit: response.limit,
+        offset: response.offset,
+        next,
+        prev,
     }))
 }
 
 async fn serve_photo(
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
     State(app_state): State<AppState>,
-    Path((plant_id, photo_id)): Path<(Uuid, Uuid)>,
+    Path((plant_id, photo_id)): Path<(PlantId, PhotoId)>,
+    headers: HeaderMap,
 ) -> Result<Response<Body>> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
     tracing::info!(
         "Serve photo request for plant: {}, photo: {} by user: {}",
         plant_id,
@@ -120,34 +153,152 @@ async fn serve_photo(
         user.id
     );
 
-    let (data, content_type) =
-        db_photos::get_photo_data(&app_state.pool, &plant_id, &photo_id, &user.id).await?;
+    let response = photo_response(&app_state, plant_id, &photo_id, &user.id, &headers).await?;
+
+    tracing::debug!("Served photo: {} for plant: {}", photo_id, plant_id);
+    Ok(response)
+}
+
+/// The photo, in the best format `headers` accept, for a plant owned by `owner_id`
+pub(crate) async fn photo_response(
+    app_state: &AppState,
+    plant_id: PlantId,
+    photo_id: &PhotoId,
+    owner_id: &str,
+    headers: &HeaderMap,
+) -> Result<Response<Body>> {
+    let accept = headers
+        .get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok());
+    let format = ServeFormat::negotiate(accept, app_state.config.image_fallback_format);
+
+    let (data, content_type) = db_photos::get_photo_data_in_format(
+        &app_state.pool,
+        app_state.photo_store.as_ref(),
+        plant_id,
+        photo_id,
+        owner_id,
+        format,
+    )
+    .await?;
+
+    // Reprocessing rewrites the image under the same id, so the ETag follows
+    // the content, which also gives each format its own
+    let etag = format!("\"{}\"", to_hex(&Sha256::digest(&data)[..16]));
+    if etag_matches(headers, &etag) {
+        return Response::builder()
+            .status(StatusCode::NOT_MODIFIED)
+            .header(header::CACHE_CONTROL, IMAGE_CACHE_CONTROL)
+            .header(header::ETAG, etag)
+            .header(header::VARY, header::ACCEPT)
+            .body(Body::empty())
+            .map_err(|_| AppError::Internal {
+                message: "Failed to build response".to_string(),
+            });
+    }
 
-    let response = Response::builder()
+    Response::builder()
         .status(StatusCode::OK)
         .header(header::CONTENT_TYPE, content_type)
         .header(header::CONTENT_LENGTH, data.len())
-        .header(header::CACHE_CONTROL, "public, max-age=31536000") // Cache for 1 year
-        .header(header::ETAG, format!("\"{}-{}\"", plant_id, photo_id)) // ETag for caching
+        .header(header::CACHE_CONTROL, IMAGE_CACHE_CONTROL)
+        .header(header::ETAG, etag)
+        .header(header::VARY, header::ACCEPT)
         .body(Body::from(data))
         .map_err(|_| AppError::Internal {
             message: "Failed to build response".to_string(),
-        })?;
+        })
+}
 
-    tracing::debug!("Served photo: {} for plant: {}", photo_id, plant_id);
-    Ok(response)
+#[utoipa::path(
+    get,
+    path = "/plants/{plant_id}/photos/{photo_id}/thumbnail",
+    params(
+        ("plant_id" = Uuid, Path, description = "Plant ID"),
+        ("photo_id" = Uuid, Path, description = "Photo ID")
+    ),
+    responses(
+        (status = 200, description = "A small AVIF of the photo for grids and previews", content_type = "image/avif"),
+        (status = 304, description = "Thumbnail unchanged since the given ETag"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant or photo not found"),
+        (status = 500, description = "No thumbnail could be generated")
+    ),
+    tag = "photos",
+    security(
+        ("session" = [])
+    )
+)]
+async fn serve_photo_thumbnail(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path((plant_id, photo_id)): Path<(PlantId, PhotoId)>,
+    headers: HeaderMap,
+) -> Result<Response<Body>> {
+    tracing::debug!(
+        "Serve thumbnail request for plant: {}, photo: {} by user: {}",
+        plant_id,
+        photo_id,
+        user.id
+    );
+
+    let (data, content_type) = db_photos::get_photo_thumbnail(
+        &app_state.pool,
+        app_state.photo_store.as_ref(),
+        plant_id,
+        &photo_id,
+        &user.id,
+    )
+    .await?;
+
+    let etag = format!("\"{}\"", to_hex(&Sha256::digest(&data)[..16]));
+    if etag_matches(&headers, &etag) {
+        return Response::builder()
+            .status(StatusCode::NOT_MODIFIED)
+            .header(header::CACHE_CONTROL, IMAGE_CACHE_CONTROL)
+            .header(header::ETAG, etag)
+            .body(Body::empty())
+            .map_err(|_| AppError::Internal {
+                message: "Failed to build response".to_string(),
+            });
+    }
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, content_type)
+        .header(header::CONTENT_LENGTH, data.len())
+        .header(header::CACHE_CONTROL, IMAGE_CACHE_CONTROL)
+        .header(header::ETAG, etag)
+        .body(Body::from(data))
+        .map_err(|_| AppError::Internal {
+            message: "Failed to build response".to_string(),
+        })
+}
+
+async fn get_photo_metadata(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path((plant_id, photo_id)): Path<(PlantId, PhotoId)>,
+) -> Result<Json<Photo>> {
+    tracing::info!(
+        "Photo metadata request for plant: {}, photo: {} by user: {}",
+        plant_id,
+        photo_id,
+        user.id
+    );
+
+    let photo =
+        db_photos::get_photo_metadata(&app_state.pool, plant_id, &photo_id, &user.id).await?;
+
+    Ok(Json(photo))
 }
 
 async fn upload_photo(
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
     State(app_state): State<AppState>,
-    Path(plant_id): Path<Uuid>,
+    Path(plant_id): Path<PlantId>,
     mut multipart: Multipart,
 ) -> Result<(StatusCode, Json<crate::models::Photo>)> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
     tracing::info!(
         "Upload photo request for plant: {} by user: {}",
         plant_id,
@@ -157,7 +308,7 @@ async fn upload_photo(
     let mut file_data: Option<Vec<u8>> = None;
     let mut original_filename: Option<String> = None;
     let mut content_type: Option<String> = None;
-    let mut _caption: Option<String> = None;
+    let mut caption: Option<String> = None;
 
     // Process multipart form data
     while let Some(field) = multipart
@@ -180,7 +331,7 @@ async fn upload_photo(
                 );
             }
             "caption" => {
-                _caption = Some(
+                caption = Some(
                     field
                         .text()
                         .await
@@ -206,8 +357,8 @@ async fn upload_photo(
         return Err(AppError::Validation(validator::ValidationErrors::new()));
     }
 
-    // Validate file size (10MB max)
-    if file_data.len() > 10 * 1024 * 1024 {
+    // Validate file size against the configured upload limit
+    if file_data.len() > app_state.config.max_file_size {
         return Err(AppError::Validation(validator::ValidationErrors::new()));
     }
 
@@ -217,10 +368,20 @@ async fn upload_photo(
         size: file_data.len() as i64,
         content_type,
         data: file_data,
+        caption,
     };
+    upload_request.validate()?;
 
-    let photo =
-        db_photos::create_photo(&app_state.pool, &plant_id, &user.id, &upload_request).await?;
+    let photo = db_photos::create_photo(
+        &app_state.pool,
+        app_state.photo_store.as_ref(),
+        &app_state.image_workers,
+        plant_id,
+        &user.id,
+        &upload_request,
+        app_state.config.image_settings(),
+    )
+    .await?;
 
     tracing::info!(
         "Photo uploaded with id: {} for plant: {}",
@@ -230,15 +391,65 @@ async fn upload_photo(
     Ok((StatusCode::CREATED, Json(photo)))
 }
 
+async fn update_photo(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path((plant_id, photo_id)): Path<(PlantId, PhotoId)>,
+    ValidatedJson(payload): ValidatedJson<UpdatePhotoRequest>,
+) -> Result<Json<Photo>> {
+    tracing::info!(
+        "Update photo request for plant: {}, photo: {} by user: {}",
+        plant_id,
+        photo_id,
+        user.id
+    );
+
+    let photo = db_photos::update_photo_caption(
+        &app_state.pool,
+        plant_id,
+        &photo_id,
+        &user.id,
+        payload.caption.as_deref(),
+    )
+    .await?;
+
+    tracing::info!("Updated photo: {} for plant: {}", photo_id, plant_id);
+    Ok(Json(photo))
+}
+
+/// Process a photo again from its kept original, using the current image settings
+async fn reprocess_photo(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path((plant_id, photo_id)): Path<(PlantId, PhotoId)>,
+) -> Result<Json<Photo>> {
+    tracing::info!(
+        "Reprocess photo request for plant: {}, photo: {} by user: {}",
+        plant_id,
+        photo_id,
+        user.id
+    );
+
+    let photo = db_photos::reprocess_photo(
+        &app_state.pool,
+        app_state.photo_store.as_ref(),
+        &app_state.image_workers,
+        plant_id,
+        &photo_id,
+        &user.id,
+        app_state.config.image_settings(),
+    )
+    .await?;
+
+    tracing::info!("Reprocessed photo: {} for plant: {}", photo_id, plant_id);
+    Ok(Json(photo))
+}
+
 async fn delete_photo(
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
     State(app_state): State<AppState>,
-    Path((plant_id, photo_id)): Path<(Uuid, Uuid)>,
+    Path((plant_id, photo_id)): Path<(PlantId, PhotoId)>,
 ) -> Result<StatusCode> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
     tracing::info!(
         "Delete photo request for plant: {}, photo: {} by user: {}",
         plant_id,
@@ -246,7 +457,14 @@ async fn delete_photo(
         user.id
     );
 
-    db_photos::delete_photo(&app_state.pool, &plant_id, &photo_id, &user.id).await?;
+    db_photos::delete_photo(
+        &app_state.pool,
+        app_state.photo_store.as_ref(),
+        plant_id,
+        &photo_id,
+        &user.id,
+    )
+    .await?;
 
     tracing::info!("Deleted photo: {} for plant: {}", photo_id, plant_id);
     Ok(StatusCode::NO_CONTENT)
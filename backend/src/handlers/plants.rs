@@ -1,33 +1,77 @@
 #[allow(unused_imports)]
 use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
-    response::Json,
+    extract::{OriginalUri, Path, Query, State},
+    http::{header, StatusCode},
+    middleware,
+    response::{IntoResponse, Json, Response},
     routing::{delete, get, post, put},
     Router,
 };
 use serde::Deserialize;
-use uuid::Uuid;
 
 use crate::app_state::AppState;
-use crate::auth::AuthSession;
-use crate::database::plants as db_plants;
-use crate::handlers::{photos, tracking};
+use crate::auth::CurrentUser;
+use crate::database::{
+    photos as db_photos, plants as db_plants, recommendations as db_recommendations, tracking as db_tracking,
+    users as db_users, DatabasePool,
+};
+use crate::handlers::{care_tasks, photos, public_links, reports, tags, tracking};
+use crate::middleware::require_user::require_user;
 use crate::middleware::validation::ValidatedJson;
-use crate::models::{CreatePlantRequest, PlantResponse, PlantsResponse, UpdatePlantRequest};
+use crate::models::{
+    normalize_tag, rebalance_schedule, schedule_load, upcoming_care, BulkUpdateScheduleRequest,
+    BulkUpdateScheduleResponse, CreatePlantRequest, NearFilter, PlantAnniversariesResponse,
+    MemorialResponse, PhotoId, PlantCareStatus, PlantId, PlantListFilter, PlantResponse, PlantStatus, PlantSummariesResponse, PlantSummary,
+    PlantsCursorResponse, PlantsResponse, RebalanceScheduleResponse, ScheduleLoadResponse, SeedExamplesResponse,
+    UpcomingCareResponse, UpdatePlantRequest, validate_custom_metric_count,
+};
+use crate::models::tracking_entry::EntryType;
+use crate::models::watering::{
+    lookback_days, recommend_watering_interval, suggest_watering, CareRecommendationsResponse,
+    IntervalRecommendation, WateringSuggestion, MAX_RECOMMENDATION_HISTORY,
+    WATERING_RECOMMENDATION_KEY,
+};
 use crate::utils::errors::{AppError, Result};
+use crate::utils::pagination::{cursor_link, offset_links, PageCursor, PageLinks};
+use crate::utils::photo_store;
+use crate::utils::plant_export::plants_to_csv;
 
 pub fn routes() -> Router<AppState> {
     Router::new()
         .route("/", get(list_plants).post(create_plant))
+        .route("/bulk-update-schedule", post(bulk_update_schedule))
+        .route("/seed-examples", post(seed_examples))
+        .route("/anniversaries", get(list_anniversaries))
+        .route("/memorial", get(list_memorial))
+        .route("/schedule/load", get(get_schedule_load))
+        .route("/schedule/rebalance", post(rebalance_care_schedule))
+        .route("/export.csv", get(export_plants))
         .route(
             "/:id",
             get(get_plant).put(update_plant).delete(delete_plant),
         )
+        .route("/:id/archive", post(archive_plant))
+        .route("/:id/unarchive", post(unarchive_plant))
+        .route("/:id/status", get(get_plant_care_status))
+        .route("/:id/upcoming", get(get_upcoming_care))
+        .route("/:id/watering-suggestion", get(get_watering_suggestion))
+        .route("/:id/recommendations", get(get_care_recommendations))
+        .route(
+            "/:id/recommendations/:rec_key/dismiss",
+            post(dismiss_recommendation),
+        )
         .route("/:id/preview/:photo_id", put(set_plant_preview))
         .route("/:id/preview", delete(clear_plant_preview))
-        .nest("/:plant_id", photos::routes())
+        .nest(
+            "/:plant_id",
+            photos::routes()
+                .merge(care_tasks::routes())
+                .merge(tags::routes())
+                .merge(reports::routes())
+                .merge(public_links::routes()),
+        )
         .merge(tracking::routes())
+        .route_layer(middleware::from_fn(require_user))
 }
 
 #[derive(Debug, Deserialize)]
@@ -36,19 +80,79 @@ struct ListPlantsQuery {
     offset: Option<i64>,
     search: Option<String>,
     sort: Option<String>, // "date_asc", "date_desc" (default), "name_asc", "name_desc"
+    fields: Option<String>, // "full" (default) or "summary"
+    near: Option<String>,   // "lat,long,radius_km"
+    archived: Option<bool>,
+    status: Option<PlantStatus>,
+    pagination: Option<String>, // "offset" (default) or "cursor"
+    cursor: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+struct ExportPlantsQuery {
+    search: Option<String>,
+    sort: Option<String>,
+    near: Option<String>,
+    archived: Option<bool>,
+    status: Option<PlantStatus>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpdatePlantQuery {
+    /// Convert values recorded for custom metrics whose data type changes
+    #[serde(default)]
+    convert: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpcomingCareQuery {
+    count: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnniversariesQuery {
+    within_days: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ScheduleLoadQuery {
+    days: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RebalanceScheduleQuery {
+    days: Option<i64>,
+    dry_run: Option<bool>,
+}
+
+/// Longest look-ahead accepted for anniversaries
+const MAX_ANNIVERSARY_WINDOW_DAYS: i64 = 366;
+
+/// Most occurrences `GET /plants/{id}/upcoming` returns
+const MAX_UPCOMING_COUNT: usize = 100;
+
+/// Longest window schedule load and rebalancing cover
+const MAX_SCHEDULE_LOAD_DAYS: i64 = 90;
+
 #[utoipa::path(
     get,
     path = "/plants",
     params(
         ("limit" = Option<i64>, Query, description = "Maximum number of plants to return"),
         ("offset" = Option<i64>, Query, description = "Number of plants to skip"),
-        ("search" = Option<String>, Query, description = "Search term for plant names"),
-        ("sort" = Option<String>, Query, description = "Sort order: date_asc, date_desc, name_asc, name_desc")
+        ("search" = Option<String>, Query, description = "Search term matched against plant name, genus and description"),
+        ("sort" = Option<String>, Query, description = "Sort order: date_asc, date_desc, name_asc, name_desc"),
+        ("fields" = Option<String>, Query, description = "Response shape: full (default) or summary (PlantSummariesResponse)"),
+        ("near" = Option<String>, Query, description = "Only plants within a radius: lat,long,radius_km (e.g. 55.68,12.57,10)"),
+        ("tag" = Option<Vec<String>>, Query, description = "Only plants with this tag; repeat to require several (tag=a&tag=b)"),
+        ("archived" = Option<bool>, Query, description = "List archived plants instead of active ones (default false)"),
+        ("status" = Option<PlantStatus>, Query, description = "Only plants with this status (default: all but dead plants)"),
+        ("pagination" = Option<String>, Query, description = "offset (default) or cursor (PlantsCursorResponse, sorted by date only)"),
+        ("cursor" = Option<String>, Query, description = "nextCursor of the previous page; implies pagination=cursor")
     ),
     responses(
         (status = 200, description = "List of plants", body = PlantsResponse),
+        (status = 400, description = "Unknown fields or pagination value, malformed near filter, or invalid cursor"),
         (status = 401, description = "Unauthorized"),
         (status = 500, description = "Internal server error")
     ),
@@ -58,40 +162,139 @@ struct ListPlantsQuery {
     )
 )]
 async fn list_plants(
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
     State(app_state): State<AppState>,
+    OriginalUri(uri): OriginalUri,
     Query(params): Query<ListPlantsQuery>,
-) -> Result<Json<PlantsResponse>> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
+    Query(pairs): Query<Vec<(String, String)>>,
+) -> Result<Response> {
     tracing::info!(
         "List plants request for user {} with params: {:?}",
         user.id,
         params
     );
 
+    let summary = match params.fields.as_deref() {
+        None | Some("full") => false,
+        Some("summary") => true,
+        Some(other) => {
+            return Err(AppError::BadRequest {
+                message: format!("Unknown fields value '{}', expected full or summary", other),
+            })
+        }
+    };
+
+    let cursor_mode = match (params.pagination.as_deref(), &params.cursor) {
+        (None, cursor) => cursor.is_some(),
+        (Some("cursor"), _) => true,
+        (Some("offset"), None) => false,
+        (Some("offset"), Some(_)) => {
+            return Err(AppError::BadRequest {
+                message: "cursor can't be used with pagination=offset".to_string(),
+            })
+        }
+        (Some(other), _) => {
+            return Err(AppError::BadRequest {
+                message: format!("Unknown pagination value '{}', expected offset or cursor", other),
+            })
+        }
+    };
+
+    let near = parse_near(params.near.as_deref())?;
+
     let limit = params.limit.unwrap_or(20);
     let offset = params.offset.unwrap_or(0);
 
+    let tags = tag_filter(&pairs);
+    let filter = PlantListFilter {
+        search: params.search.as_deref(),
+        sort: params.sort.as_deref(),
+        near: near.as_ref(),
+        tags: &tags,
+        archived: params.archived.unwrap_or(false),
+        status: params.status,
+    };
+
+    if cursor_mode {
+        if summary || params.offset.is_some() {
+            return Err(AppError::BadRequest {
+                message: "Cursor pagination doesn't support fields=summary or offset".to_string(),
+            });
+        }
+        return list_plants_by_cursor(&app_state, &user.id, &uri, limit, params.cursor.as_deref(), &filter)
+            .await;
+    }
+
     let (plants, total) =
-        db_plants::list_plants_for_user_with_sort(&app_state.pool, &user.id, limit, offset, params.search.as_deref(), params.sort.as_deref())
+        db_plants::list_plants_for_user_with_sort(&app_state.pool, &user.id, limit, offset, &filter)
             .await?;
 
-    let response = PlantsResponse {
+    tracing::debug!("Returning {} plants for user {}", plants.len(), user.id);
+    let PageLinks { next, prev } = offset_links(&uri, total, limit, offset);
+
+    if summary {
+        let now = chrono::Utc::now();
+        let care_day = db_users::get_care_day(&app_state.pool, &user.id).await?;
+        let lead_hours = app_state.config.reminder_lead_hours;
+        return Ok(Json(PlantSummariesResponse {
+            plants: plants
+                .into_iter()
+                .map(|plant| PlantSummary::new(plant, now, care_day, lead_hours))
+                .collect(),
+            total,
+            limit,
+            offset,
+            next,
+            prev,
+        })
+        .into_response());
+    }
+
+    Ok(Json(PlantsResponse {
         plants,
         total,
         limit,
         offset,
-    };
+        next,
+        prev,
+    })
+    .into_response())
+}
 
-    tracing::debug!(
-        "Returning {} plants for user {}",
-        response.plants.len(),
-        user.id
-    );
-    Ok(Json(response))
+/// A `GET /plants?pagination=cursor` page, following `cursor` when given
+async fn list_plants_by_cursor(
+    app_state: &AppState,
+    user_id: &str,
+    uri: &axum::http::Uri,
+    limit: i64,
+    cursor: Option<&str>,
+    filter: &PlantListFilter<'_>,
+) -> Result<Response> {
+    if !matches!(filter.sort, None | Some("date_asc" | "date_desc")) {
+        return Err(AppError::BadRequest {
+            message: "Cursor pagination only sorts by date_asc or date_desc".to_string(),
+        });
+    }
+    let after = cursor
+        .map(|token| {
+            PageCursor::decode(token).ok_or_else(|| AppError::BadRequest {
+                message: "Invalid cursor".to_string(),
+            })
+        })
+        .transpose()?;
+
+    let (plants, next_cursor) =
+        db_plants::list_plants_for_user_after(&app_state.pool, user_id, limit, after.as_ref(), filter)
+            .await?;
+
+    tracing::debug!("Returning {} plants for user {}", plants.len(), user_id);
+    Ok(Json(PlantsCursorResponse {
+        plants,
+        limit,
+        next: next_cursor.as_ref().map(|cursor| cursor_link(uri, cursor)),
+        next_cursor: next_cursor.as_ref().map(PageCursor::encode),
+    })
+    .into_response())
 }
 
 #[utoipa::path(
@@ -102,6 +305,7 @@ async fn list_plants(
         (status = 201, description = "Plant created successfully", body = PlantResponse),
         (status = 400, description = "Invalid request data"),
         (status = 401, description = "Unauthorized"),
+        (status = 422, description = "More custom metrics than the configured maximum"),
         (status = 500, description = "Internal server error")
     ),
     tag = "plants",
@@ -110,14 +314,10 @@ async fn list_plants(
     )
 )]
 async fn create_plant(
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
     State(app_state): State<AppState>,
     ValidatedJson(payload): ValidatedJson<CreatePlantRequest>,
 ) -> Result<(StatusCode, Json<PlantResponse>)> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
     tracing::info!(
         "Create plant request for user {}: name={}, genus={}",
         user.id,
@@ -125,12 +325,293 @@ async fn create_plant(
         payload.genus
     );
 
+    validate_custom_metric_count(
+        payload.custom_metrics.as_ref().map_or(0, Vec::len),
+        app_state.config.max_custom_metrics,
+    )?;
+
     let plant = db_plants::create_plant(&app_state.pool, &user.id, &payload).await?;
 
     tracing::info!("Created plant with id: {} for user: {}", plant.id, user.id);
     Ok((StatusCode::CREATED, Json(plant)))
 }
 
+/// Export every matching plant as CSV, one row per plant
+///
+/// Takes the same filters as the plant listing, without paging.
+#[utoipa::path(
+    get,
+    path = "/plants/export.csv",
+    params(
+        ("search" = Option<String>, Query, description = "Search term matched against plant name, genus and description"),
+        ("sort" = Option<String>, Query, description = "Sort order: date_asc, date_desc, name_asc, name_desc"),
+        ("near" = Option<String>, Query, description = "Only plants within a radius: lat,long,radius_km (e.g. 55.68,12.57,10)"),
+        ("tag" = Option<Vec<String>>, Query, description = "Only plants with this tag; repeat to require several (tag=a&tag=b)"),
+        ("archived" = Option<bool>, Query, description = "List archived plants instead of active ones (default false)"),
+        ("status" = Option<PlantStatus>, Query, description = "Only plants with this status (default: all but dead plants)")
+    ),
+    responses(
+        (status = 200, description = "Plants CSV with id, name, genus, care intervals, last care and next due dates", content_type = "text/csv", body = String),
+        (status = 400, description = "Malformed near filter"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn export_plants(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Query(params): Query<ExportPlantsQuery>,
+    Query(pairs): Query<Vec<(String, String)>>,
+) -> Result<Response> {
+    let near = parse_near(params.near.as_deref())?;
+
+    let tags = tag_filter(&pairs);
+    let filter = PlantListFilter {
+        search: params.search.as_deref(),
+        sort: params.sort.as_deref(),
+        near: near.as_ref(),
+        tags: &tags,
+        archived: params.archived.unwrap_or(false),
+        status: params.status,
+    };
+    let (plants, _) =
+        db_plants::list_plants_for_user_with_sort(&app_state.pool, &user.id, i64::MAX, 0, &filter)
+            .await?;
+    let csv = plants_to_csv(&plants)?;
+
+    tracing::info!("Exported {} plants as CSV for user {}", plants.len(), user.id);
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "text/csv; charset=utf-8")
+        .header(header::CONTENT_DISPOSITION, "attachment; filename=\"plants.csv\"")
+        .body(csv.into())
+        .map_err(|_| AppError::Internal {
+            message: "Failed to build CSV response".to_string(),
+        })
+}
+
+/// Parse the `near` listing filter, rejecting malformed values with `BadRequest`
+fn parse_near(near: Option<&str>) -> Result<Option<NearFilter>> {
+    near.map(str::parse::<NearFilter>)
+        .transpose()
+        .map_err(|message| AppError::BadRequest { message })
+}
+
+/// The repeatable `tag` listing filter, normalized and without duplicates
+fn tag_filter(pairs: &[(String, String)]) -> Vec<String> {
+    let mut tags: Vec<String> = pairs
+        .iter()
+        .filter(|(name, _)| name == "tag")
+        .map(|(_, tag)| normalize_tag(tag))
+        .filter(|tag| !tag.is_empty())
+        .collect();
+    tags.sort();
+    tags.dedup();
+    tags
+}
+
+/// Upcoming monthly and yearly acquisition anniversaries
+#[utoipa::path(
+    get,
+    path = "/plants/anniversaries",
+    params(
+        ("within_days" = Option<i64>, Query, description = "Days ahead to look, 0-366 (default 7)")
+    ),
+    responses(
+        (status = 200, description = "Plants with an anniversary in the window, soonest first", body = PlantAnniversariesResponse),
+        (status = 400, description = "within_days out of range"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn list_anniversaries(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Query(params): Query<AnniversariesQuery>,
+) -> Result<Json<PlantAnniversariesResponse>> {
+    let within_days = params.within_days.unwrap_or(7);
+    if !(0..=MAX_ANNIVERSARY_WINDOW_DAYS).contains(&within_days) {
+        return Err(AppError::BadRequest {
+            message: format!(
+                "within_days must be between 0 and {}",
+                MAX_ANNIVERSARY_WINDOW_DAYS
+            ),
+        });
+    }
+
+    let anniversaries = db_plants::list_plant_anniversaries(
+        &app_state.pool,
+        &user.id,
+        chrono::Utc::now().date_naive(),
+        within_days,
+    )
+    .await?;
+
+    Ok(Json(PlantAnniversariesResponse { anniversaries }))
+}
+
+/// Plants that have died, with when and why, most recently died first
+#[utoipa::path(
+    get,
+    path = "/plants/memorial",
+    responses(
+        (status = 200, description = "Dead plants, archived or not", body = MemorialResponse),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn list_memorial(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<MemorialResponse>> {
+    let plants = db_plants::list_memorial_plants(&app_state.pool, &user.id).await?;
+    Ok(Json(MemorialResponse { plants }))
+}
+
+/// Count the care events due each day across all of the user's plants
+///
+/// Uses the same schedule as the calendar feed and upcoming care, so the UI
+/// can flag heavy days and users can stagger their schedules.
+#[utoipa::path(
+    get,
+    path = "/plants/schedule/load",
+    params(
+        ("days" = Option<i64>, Query, description = "Days to cover starting today, 1-90 (default 14)")
+    ),
+    responses(
+        (status = 200, description = "Care events per day, one entry per day", body = ScheduleLoadResponse),
+        (status = 400, description = "days out of range"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn get_schedule_load(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Query(params): Query<ScheduleLoadQuery>,
+) -> Result<Json<ScheduleLoadResponse>> {
+    let days = schedule_window_days(params.days)?;
+
+    let (plants, _) =
+        db_plants::list_plants_for_user(&app_state.pool, &user.id, 1000, 0, None).await?;
+    let timing = db_users::get_care_timing(&app_state.pool, &user.id).await?;
+
+    Ok(Json(ScheduleLoadResponse {
+        days: schedule_load(&plants, days, chrono::Utc::now(), timing),
+    }))
+}
+
+/// Propose shifting last-care dates by a day or two to even out the care load
+///
+/// Only a preview unless `dry_run=false`, which applies the shifts as well.
+#[utoipa::path(
+    post,
+    path = "/plants/schedule/rebalance",
+    params(
+        ("days" = Option<i64>, Query, description = "Days to balance starting today, 1-90 (default 14)"),
+        ("dry_run" = Option<bool>, Query, description = "Only propose the shifts (default true)")
+    ),
+    responses(
+        (status = 200, description = "Proposed shifts, applied when dry_run is false", body = RebalanceScheduleResponse),
+        (status = 400, description = "days out of range"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn rebalance_care_schedule(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Query(params): Query<RebalanceScheduleQuery>,
+) -> Result<Json<RebalanceScheduleResponse>> {
+    let days = schedule_window_days(params.days)?;
+    let dry_run = params.dry_run.unwrap_or(true);
+
+    let (plants, _) =
+        db_plants::list_plants_for_user(&app_state.pool, &user.id, 1000, 0, None).await?;
+    let timing = db_users::get_care_timing(&app_state.pool, &user.id).await?;
+    let shifts = rebalance_schedule(&plants, days, chrono::Utc::now(), timing);
+
+    if !dry_run {
+        db_plants::apply_schedule_shifts(&app_state.pool, &user.id, &shifts).await?;
+    }
+
+    Ok(Json(RebalanceScheduleResponse { dry_run, shifts }))
+}
+
+/// The `days` window for schedule load and rebalancing, rejecting out-of-range
+/// values with `BadRequest`
+fn schedule_window_days(days: Option<i64>) -> Result<i64> {
+    let days = days.unwrap_or(14);
+    if !(1..=MAX_SCHEDULE_LOAD_DAYS).contains(&days) {
+        return Err(AppError::BadRequest {
+            message: format!("days must be between 1 and {}", MAX_SCHEDULE_LOAD_DAYS),
+        });
+    }
+    Ok(days)
+}
+
+/// Add a few example plants so a new account isn't empty
+///
+/// Only seeds once per account; later calls return `seeded: false`.
+#[utoipa::path(
+    post,
+    path = "/plants/seed-examples",
+    responses(
+        (status = 201, description = "Example plants created", body = SeedExamplesResponse),
+        (status = 200, description = "Examples were already seeded for this account", body = SeedExamplesResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn seed_examples(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+) -> Result<(StatusCode, Json<SeedExamplesResponse>)> {
+    tracing::info!("Seed example plants request for user {}", user.id);
+
+    let (status, response) = match db_plants::seed_example_plants(&app_state.pool, &user.id).await? {
+        Some(plants) => {
+            tracing::info!("Seeded {} example plants for user {}", plants.len(), user.id);
+            (
+                StatusCode::CREATED,
+                SeedExamplesResponse {
+                    seeded: true,
+                    plants,
+                },
+            )
+        }
+        None => (
+            StatusCode::OK,
+            SeedExamplesResponse {
+                seeded: false,
+                plants: vec![],
+            },
+        ),
+    };
+
+    Ok((status, Json(response)))
+}
+
 #[utoipa::path(
     get,
     path = "/plants/{id}",
@@ -149,40 +630,300 @@ async fn create_plant(
     )
 )]
 async fn get_plant(
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
     State(app_state): State<AppState>,
-    Path(id): Path<Uuid>,
+    Path(id): Path<PlantId>,
 ) -> Result<Json<PlantResponse>> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
     tracing::info!("Get plant request for id: {} by user: {}", id, user.id);
 
     let plant = db_plants::get_plant_by_id(&app_state.pool, id).await?;
 
     // Verify the plant belongs to the authenticated user
     if plant.user_id != user.id {
-        return Err(AppError::NotFound {
-            resource: format!("Plant with id {id}"),
-        });
+        return Err(AppError::plant_not_found());
     }
 
     tracing::debug!("Retrieved plant: {} for user: {}", plant.name, user.id);
     Ok(Json(plant))
 }
 
+/// Get when a plant is next due for watering and fertilizing
+///
+/// A plant never watered or fertilized is due right away; care without an
+/// interval has no due date and is never overdue.
+#[utoipa::path(
+    get,
+    path = "/plants/{id}/status",
+    params(
+        ("id" = Uuid, Path, description = "Plant ID")
+    ),
+    responses(
+        (status = 200, description = "Care status", body = PlantCareStatus),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant not found")
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn get_plant_care_status(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path(id): Path<PlantId>,
+) -> Result<Json<PlantCareStatus>> {
+    let status = db_plants::get_plant_care_status(
+        &app_state.pool,
+        id,
+        &user.id,
+        chrono::Utc::now(),
+        app_state.config.reminder_lead_hours,
+    )
+    .await?;
+    Ok(Json(status))
+}
+
+/// Preview the next watering and fertilizing occurrences for a plant
+///
+/// Uses the same schedule as the calendar feed and Google Tasks sync; care
+/// without an interval is left out.
+#[utoipa::path(
+    get,
+    path = "/plants/{id}/upcoming",
+    params(
+        ("id" = Uuid, Path, description = "Plant ID"),
+        ("count" = Option<usize>, Query, description = "Number of occurrences, 1-100 (default 10)")
+    ),
+    responses(
+        (status = 200, description = "Upcoming care, soonest first", body = UpcomingCareResponse),
+        (status = 400, description = "count out of range"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant not found")
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn get_upcoming_care(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path(id): Path<PlantId>,
+    Query(params): Query<UpcomingCareQuery>,
+) -> Result<Json<UpcomingCareResponse>> {
+    let count = params.count.unwrap_or(10);
+    if !(1..=MAX_UPCOMING_COUNT).contains(&count) {
+        return Err(AppError::BadRequest {
+            message: format!("count must be between 1 and {}", MAX_UPCOMING_COUNT),
+        });
+    }
+
+    let plant = db_plants::get_plant_by_id(&app_state.pool, id).await?;
+    if plant.user_id != user.id {
+        return Err(AppError::plant_not_found());
+    }
+
+    let timing = db_users::get_care_timing(&app_state.pool, &user.id).await?;
+
+    Ok(Json(UpcomingCareResponse {
+        plant_id: plant.id,
+        occurrences: upcoming_care(&plant, count, chrono::Utc::now(), timing),
+    }))
+}
+
+/// Suggest advancing or delaying the next watering based on recent rain
+///
+/// Needs the plant's coordinates and a watering interval. With the stub
+/// weather provider there is no data and the schedule is kept.
+#[utoipa::path(
+    get,
+    path = "/plants/{id}/watering-suggestion",
+    params(
+        ("id" = Uuid, Path, description = "Plant ID")
+    ),
+    responses(
+        (status = 200, description = "Watering suggestion", body = WateringSuggestion),
+        (status = 400, description = "Plant has no location or watering interval"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant not found"),
+        (status = 500, description = "Weather data couldn't be fetched")
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn get_watering_suggestion(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path(id): Path<PlantId>,
+) -> Result<Json<WateringSuggestion>> {
+    let plant = db_plants::get_plant_by_id(&app_state.pool, id).await?;
+    if plant.user_id != user.id {
+        return Err(AppError::plant_not_found());
+    }
+
+    let (Some(latitude), Some(longitude)) = (plant.latitude, plant.longitude) else {
+        return Err(AppError::BadRequest {
+            message: "Plant has no location; set latitude and longitude first".to_string(),
+        });
+    };
+    let Some(interval_days) = plant.watering_schedule.interval_days else {
+        return Err(AppError::BadRequest {
+            message: "Plant has no watering interval".to_string(),
+        });
+    };
+
+    let precipitation_mm = app_state
+        .weather
+        .recent_precipitation_mm(latitude, longitude, lookback_days(interval_days))
+        .await?;
+
+    Ok(Json(suggest_watering(
+        plant.id,
+        interval_days,
+        plant.last_watered,
+        precipitation_mm,
+        app_state.weather.name(),
+        chrono::Utc::now(),
+    )))
+}
+
+/// The plant's watering recommendation, ignoring dismissals
+async fn watering_recommendation(
+    pool: &DatabasePool,
+    plant: &PlantResponse,
+) -> Result<Option<IntervalRecommendation>> {
+    let waterings = db_tracking::get_recent_care_times(
+        pool,
+        PlantId(plant.id),
+        &EntryType::Watering,
+        MAX_RECOMMENDATION_HISTORY,
+    )
+    .await?;
+
+    Ok(recommend_watering_interval(
+        plant.watering_schedule.interval_days,
+        &waterings,
+    ))
+}
+
+/// Suggest schedule changes learned from how the plant is actually cared for
+///
+/// Compares the median gap between recent waterings with the configured
+/// interval, suggesting a new one when they differ materially. Dismissed
+/// recommendations are left out until the intervals they were based on change.
+#[utoipa::path(
+    get,
+    path = "/plants/{id}/recommendations",
+    params(
+        ("id" = Uuid, Path, description = "Plant ID")
+    ),
+    responses(
+        (status = 200, description = "Care recommendations", body = CareRecommendationsResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant not found")
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn get_care_recommendations(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path(id): Path<PlantId>,
+) -> Result<Json<CareRecommendationsResponse>> {
+    let plant = db_plants::get_plant_by_id(&app_state.pool, id).await?;
+    if plant.user_id != user.id {
+        return Err(AppError::plant_not_found());
+    }
+
+    let mut watering = watering_recommendation(&app_state.pool, &plant).await?;
+    if let Some(recommendation) = &watering {
+        let dismissal =
+            db_recommendations::get_dismissal(&app_state.pool, id, &recommendation.key)
+                .await?;
+        if dismissal.is_some_and(|dismissed| recommendation.is_dismissed_by(&dismissed)) {
+            watering = None;
+        }
+    }
+
+    Ok(Json(CareRecommendationsResponse {
+        plant_id: plant.id,
+        watering,
+    }))
+}
+
+/// Dismiss a recommendation so it stops being shown
+///
+/// It shows up again once the plant's configured interval changes or its
+/// history points to a different interval.
+#[utoipa::path(
+    post,
+    path = "/plants/{id}/recommendations/{rec_key}/dismiss",
+    params(
+        ("id" = Uuid, Path, description = "Plant ID"),
+        ("rec_key" = String, Path, description = "Key of the recommendation, e.g. `watering`")
+    ),
+    responses(
+        (status = 204, description = "Recommendation dismissed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant not found, or nothing is recommended for the key")
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn dismiss_recommendation(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path((id, rec_key)): Path<(PlantId, String)>,
+) -> Result<StatusCode> {
+    let plant = db_plants::get_plant_by_id(&app_state.pool, id).await?;
+    if plant.user_id != user.id {
+        return Err(AppError::plant_not_found());
+    }
+
+    tracing::info!(
+        "Dismiss recommendation {} for plant: {} by user: {}",
+        rec_key,
+        plant.id,
+        user.id
+    );
+
+    let recommendation = match rec_key.as_str() {
+        WATERING_RECOMMENDATION_KEY => watering_recommendation(&app_state.pool, &plant).await?,
+        _ => None,
+    }
+    .ok_or_else(AppError::recommendation_not_found)?;
+
+    db_recommendations::dismiss_recommendation(
+        &app_state.pool,
+        id,
+        &recommendation.key,
+        &recommendation.dismissal(),
+    )
+    .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
 #[utoipa::path(
     put,
     path = "/plants/{id}",
     params(
-        ("id" = Uuid, Path, description = "Plant ID")
+        ("id" = Uuid, Path, description = "Plant ID"),
+        ("convert" = Option<bool>, Query, description = "Convert values recorded for custom metrics whose data type changes (default false)")
     ),
     request_body = UpdatePlantRequest,
     responses(
         (status = 200, description = "Plant updated successfully", body = PlantResponse),
+        (status = 400, description = "Invalid request data, or a metric type change that recorded values prevent"),
         (status = 401, description = "Unauthorized"),
         (status = 404, description = "Plant not found"),
+        (status = 422, description = "More custom metrics than the configured maximum"),
         (status = 500, description = "Internal server error")
     ),
     tag = "plants",
@@ -191,24 +932,68 @@ async fn get_plant(
     )
 )]
 async fn update_plant(
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
     State(app_state): State<AppState>,
-    Path(id): Path<Uuid>,
-    Json(payload): Json<UpdatePlantRequest>,
+    Path(id): Path<PlantId>,
+    Query(params): Query<UpdatePlantQuery>,
+    ValidatedJson(payload): ValidatedJson<UpdatePlantRequest>,
 ) -> Result<Json<PlantResponse>> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
     tracing::info!("Update plant request for id: {} by user: {}", id, user.id);
     tracing::debug!("Update payload: {:?}", payload);
 
-    let plant = db_plants::update_plant(&app_state.pool, id, &user.id, &payload).await?;
+    let plant = db_plants::update_plant(
+        &app_state.pool,
+        id,
+        &user.id,
+        &payload,
+        params.convert,
+        app_state.config.max_custom_metrics,
+    )
+    .await?;
 
     tracing::info!("Updated plant: {} for user: {}", plant.name, user.id);
     Ok(Json(plant))
 }
 
+#[utoipa::path(
+    post,
+    path = "/plants/bulk-update-schedule",
+    request_body = BulkUpdateScheduleRequest,
+    responses(
+        (status = 200, description = "Schedules updated", body = BulkUpdateScheduleResponse),
+        (status = 400, description = "No interval provided"),
+        (status = 401, description = "Unauthorized"),
+        (status = 422, description = "Invalid request data"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn bulk_update_schedule(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    ValidatedJson(payload): ValidatedJson<BulkUpdateScheduleRequest>,
+) -> Result<Json<BulkUpdateScheduleResponse>> {
+    tracing::info!(
+        "Bulk schedule update for {} plants by user {}",
+        payload.plant_ids.len(),
+        user.id
+    );
+
+    if payload.watering_interval_days.is_none() && payload.fertilizing_interval_days.is_none() {
+        return Err(AppError::BadRequest {
+            message: "No schedule updates provided".to_string(),
+        });
+    }
+
+    let updated = db_plants::bulk_update_schedule(&app_state.pool, &user.id, &payload).await?;
+
+    tracing::info!("Bulk updated schedules for {} plants for user {}", updated, user.id);
+    Ok(Json(BulkUpdateScheduleResponse { updated }))
+}
+
 #[utoipa::path(
     delete,
     path = "/plants/{id}",
@@ -227,31 +1012,84 @@ async fn update_plant(
     )
 )]
 async fn delete_plant(
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
     State(app_state): State<AppState>,
-    Path(id): Path<Uuid>,
+    Path(id): Path<PlantId>,
 ) -> Result<StatusCode> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
     tracing::info!("Delete plant request for id: {} by user: {}", id, user.id);
 
+    let photo_ids = db_photos::list_photo_ids_for_plant(&app_state.pool, id).await?;
     db_plants::delete_plant(&app_state.pool, id, &user.id).await?;
+    photo_store::delete_images(app_state.photo_store.as_ref(), &photo_ids).await;
 
     tracing::info!("Deleted plant with id: {} for user: {}", id, user.id);
     Ok(StatusCode::NO_CONTENT)
 }
 
-async fn set_plant_preview(
-    auth_session: AuthSession,
+/// Archive a plant instead of deleting it
+///
+/// The plant keeps its entries and photos but no longer appears in plant
+/// listings, the calendar feed or Google Tasks.
+#[utoipa::path(
+    post,
+    path = "/plants/{id}/archive",
+    params(
+        ("id" = Uuid, Path, description = "Plant ID")
+    ),
+    responses(
+        (status = 200, description = "Plant archived", body = PlantResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant not found")
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn archive_plant(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path(id): Path<PlantId>,
+) -> Result<Json<PlantResponse>> {
+    let plant = db_plants::archive_plant(&app_state.pool, id, &user.id).await?;
+
+    tracing::info!("Archived plant with id: {} for user: {}", id, user.id);
+    Ok(Json(plant))
+}
+
+/// Restore an archived plant
+#[utoipa::path(
+    post,
+    path = "/plants/{id}/unarchive",
+    params(
+        ("id" = Uuid, Path, description = "Plant ID")
+    ),
+    responses(
+        (status = 200, description = "Plant restored", body = PlantResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant not found")
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn unarchive_plant(
+    CurrentUser(user): CurrentUser,
     State(app_state): State<AppState>,
-    Path((id, photo_id)): Path<(Uuid, Uuid)>,
+    Path(id): Path<PlantId>,
 ) -> Result<Json<PlantResponse>> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
+    let plant = db_plants::unarchive_plant(&app_state.pool, id, &user.id).await?;
 
+    tracing::info!("Unarchived plant with id: {} for user: {}", id, user.id);
+    Ok(Json(plant))
+}
+
+async fn set_plant_preview(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path((id, photo_id)): Path<(PlantId, PhotoId)>,
+) -> Result<Json<PlantResponse>> {
     tracing::info!(
         "Set preview request for plant: {}, photo: {} by user: {}",
         id,
@@ -271,14 +1109,10 @@ async fn set_plant_preview(
 }
 
 async fn clear_plant_preview(
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
     State(app_state): State<AppState>,
-    Path(id): Path<Uuid>,
+    Path(id): Path<PlantId>,
 ) -> Result<Json<PlantResponse>> {
-    let user = auth_session.user.ok_or_else(|| AppError::Authentication {
-        message: "User not authenticated".to_string(),
-    })?;
-
     tracing::info!(
         "Clear preview request for plant: {} by user: {}",
         id,
@@ -0,0 +1,208 @@
+use axum::{
+    body::Body,
+    extract::{Path, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{Json, Response},
+    routing::{get, post},
+    Router,
+};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::auth::CurrentUser;
+use crate::database::{
+    photos as db_photos, plants as db_plants, public_links as db_public_links,
+};
+use crate::handlers::photos::photo_response;
+use crate::models::{PhotoId, PlantId};
+use crate::models::public_link::{PublicLinkResponse, PublicPhoto, PublicPlantResponse};
+use crate::utils::errors::{AppError, Result};
+
+/// Photos shown on a public plant page, newest first
+const PUBLIC_PHOTOS: i64 = 12;
+
+/// Shared notes shown on a public plant page, newest first
+const PUBLIC_NOTES: i64 = 20;
+
+/// Routes for managing a plant's link, nested under `/plants/:plant_id`
+pub fn routes() -> Router<AppState> {
+    Router::new().route(
+        "/public-link",
+        post(create_public_link).delete(delete_public_link),
+    )
+}
+
+/// Routes anyone with a link can use, nested under `/public`
+pub fn public_routes() -> Router<AppState> {
+    Router::new()
+        .route("/plants/:token", get(get_public_plant))
+        .route("/plants/:token/photos/:photo_id", get(serve_public_photo))
+}
+
+fn public_photo_url(token: &str, photo_id: &Uuid) -> String {
+    format!("/api/v1/public/plants/{}/photos/{}", token, photo_id)
+}
+
+/// Create a read-only link to the plant
+///
+/// Anyone with the link can see the plant's name, genus, schedule, photos and
+/// shared notes without signing in. A plant has one link at a time, so this
+/// replaces (and revokes) any earlier one.
+#[utoipa::path(
+    post,
+    path = "/plants/{plant_id}/public-link",
+    params(
+        ("plant_id" = Uuid, Path, description = "Plant ID")
+    ),
+    responses(
+        (status = 201, description = "Public link created", body = PublicLinkResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant not found")
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn create_public_link(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path(plant_id): Path<PlantId>,
+) -> Result<(StatusCode, Json<PublicLinkResponse>)> {
+    let (token, created_at) =
+        db_public_links::create_public_link(&app_state.pool, plant_id, &user.id).await?;
+
+    tracing::info!("Created public link for plant: {} by user: {}", plant_id, user.id);
+    Ok((
+        StatusCode::CREATED,
+        Json(PublicLinkResponse {
+            url: format!("/api/v1/public/plants/{}", token),
+            token,
+            created_at,
+        }),
+    ))
+}
+
+/// Revoke the plant's read-only link
+#[utoipa::path(
+    delete,
+    path = "/plants/{plant_id}/public-link",
+    params(
+        ("plant_id" = Uuid, Path, description = "Plant ID")
+    ),
+    responses(
+        (status = 204, description = "Public link revoked"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant has no public link")
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn delete_public_link(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path(plant_id): Path<PlantId>,
+) -> Result<StatusCode> {
+    db_public_links::delete_public_link(&app_state.pool, plant_id, &user.id).await?;
+
+    tracing::info!("Revoked public link for plant: {} by user: {}", plant_id, user.id);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// View a plant through its public link, without signing in
+///
+/// Owner-only notes and everything but the plant's name, genus, schedule,
+/// photos and shared notes are left out.
+#[utoipa::path(
+    get,
+    path = "/public/plants/{token}",
+    params(
+        ("token" = String, Path, description = "Public link token")
+    ),
+    responses(
+        (status = 200, description = "The plant's public details", body = PublicPlantResponse),
+        (status = 404, description = "Unknown or revoked link")
+    ),
+    tag = "plants"
+)]
+async fn get_public_plant(
+    State(app_state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<Json<PublicPlantResponse>> {
+    let linked = db_public_links::get_linked_plant(&app_state.pool, &token)
+        .await?
+        .ok_or_else(AppError::public_link_not_found)?;
+
+    let plant = db_plants::get_plant_by_id(&app_state.pool, linked.plant_id).await?;
+
+    let photos = db_photos::get_photos_for_plant_paginated(
+        &app_state.pool,
+        linked.plant_id,
+        &linked.user_id,
+        Some(PUBLIC_PHOTOS),
+        None,
+        Some(true),
+    )
+    .await?
+    .photos
+    .into_iter()
+    .map(|photo| PublicPhoto {
+        url: public_photo_url(&token, &photo.id),
+        caption: photo.caption,
+        created_at: photo.created_at,
+    })
+    .collect();
+
+    let notes =
+        db_public_links::get_shared_notes(&app_state.pool, linked.plant_id, PUBLIC_NOTES).await?;
+
+    Ok(Json(PublicPlantResponse {
+        name: plant.name,
+        genus: plant.genus,
+        watering_schedule: plant.watering_schedule,
+        fertilizing_schedule: plant.fertilizing_schedule,
+        photos,
+        notes,
+    }))
+}
+
+/// A photo of a plant with a public link
+#[utoipa::path(
+    get,
+    path = "/public/plants/{token}/photos/{photo_id}",
+    params(
+        ("token" = String, Path, description = "Public link token"),
+        ("photo_id" = Uuid, Path, description = "Photo ID")
+    ),
+    responses(
+        (status = 200, description = "The photo", content_type = "image/*"),
+        (status = 404, description = "Unknown or revoked link, or photo not found")
+    ),
+    tag = "plants"
+)]
+async fn serve_public_photo(
+    State(app_state): State<AppState>,
+    Path((token, photo_id)): Path<(String, PhotoId)>,
+    headers: HeaderMap,
+) -> Result<Response<Body>> {
+    let linked = db_public_links::get_linked_plant(&app_state.pool, &token)
+        .await?
+        .ok_or_else(AppError::public_link_not_found)?;
+
+    let mut response = photo_response(
+        &app_state,
+        linked.plant_id,
+        &photo_id,
+        &linked.user_id,
+        &headers,
+    )
+    .await?;
+
+    // Revoking the link should hide its photos too, so don't let caches keep them
+    response
+        .headers_mut()
+        .insert(header::CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+    Ok(response)
+}
@@ -0,0 +1,184 @@
+use askama::Template;
+use axum::{
+    extract::{Path, State},
+    response::Html,
+    routing::get,
+    Router,
+};
+use chrono::{DateTime, Utc};
+
+use crate::app_state::AppState;
+use crate::auth::CurrentUser;
+use crate::database::{photos as db_photos, plants as db_plants, tracking as db_tracking};
+use crate::models::report::{care_compliance, MAX_SCORED_CARE};
+use crate::models::tracking_entry::{EntryType, TrackingEntry};
+use crate::models::{CareSchedule, PlantId, PlantResponse};
+use crate::utils::errors::{AppError, Result};
+
+/// Photos shown in a report, newest first
+const REPORT_PHOTOS: i64 = 6;
+
+/// Care history rows shown in a report, newest first
+const REPORT_ENTRIES: i64 = 50;
+
+pub fn routes() -> Router<AppState> {
+    Router::new().route("/report", get(get_plant_report))
+}
+
+struct ReportPhoto {
+    url: String,
+    caption: String,
+}
+
+struct ReportEntry {
+    date: String,
+    kind: String,
+    value: String,
+    notes: String,
+}
+
+#[derive(Template)]
+#[template(path = "plant_report.html")]
+struct PlantReportTemplate<'a> {
+    plant: &'a PlantResponse,
+    health_score: Option<u8>,
+    watering: String,
+    fertilizing: String,
+    photos: Vec<ReportPhoto>,
+    entries: Vec<ReportEntry>,
+    generated_at: String,
+}
+
+fn format_time(time: DateTime<Utc>) -> String {
+    time.format("%Y-%m-%d %H:%M UTC").to_string()
+}
+
+/// e.g. "Every 7 days, last on 2024-05-01 09:00 UTC"
+fn describe_schedule(schedule: &CareSchedule, last: Option<DateTime<Utc>>) -> String {
+    let interval = match schedule.interval_days {
+        Some(days) => format!("Every {} days", days),
+        None => "No schedule".to_string(),
+    };
+    match last {
+        Some(last) => format!("{}, last on {}", interval, format_time(last)),
+        None => format!("{}, never recorded", interval),
+    }
+}
+
+fn report_entry(entry: TrackingEntry) -> ReportEntry {
+    let kind = match &entry.entry_type {
+        EntryType::Watering => "Watering".to_string(),
+        EntryType::Fertilizing => "Fertilizing".to_string(),
+        EntryType::CustomMetric => "Measurement".to_string(),
+        EntryType::Note => "Note".to_string(),
+        EntryType::Photo => "Photo".to_string(),
+        EntryType::Care { task_type } => task_type.clone(),
+    };
+    let value = match entry.value {
+        Some(serde_json::Value::String(value)) => value,
+        Some(value) => value.to_string(),
+        None => String::new(),
+    };
+
+    ReportEntry {
+        date: format_time(entry.timestamp),
+        kind,
+        value,
+        notes: entry.notes.unwrap_or_default(),
+    }
+}
+
+/// Printable care report for a plant
+///
+/// A standalone HTML page with the plant's schedule, a health score, its most
+/// recent photos and care history, meant to be shared or printed to PDF.
+/// The health score is the percentage of recent watering and fertilizing done
+/// within a day of schedule.
+#[utoipa::path(
+    get,
+    path = "/plants/{plant_id}/report",
+    params(
+        ("plant_id" = Uuid, Path, description = "Plant ID")
+    ),
+    responses(
+        (status = 200, description = "Report as an HTML document", body = String, content_type = "text/html"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant not found")
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn get_plant_report(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path(plant_id): Path<PlantId>,
+) -> Result<Html<String>> {
+    let plant = db_plants::get_plant_by_id(&app_state.pool, plant_id).await?;
+    if plant.user_id != user.id {
+        return Err(AppError::plant_not_found());
+    }
+
+    let now = Utc::now();
+    let mut compliance = Default::default();
+    for (entry_type, schedule) in [
+        (EntryType::Watering, &plant.watering_schedule),
+        (EntryType::Fertilizing, &plant.fertilizing_schedule),
+    ] {
+        let times = db_tracking::get_recent_care_times(
+            &app_state.pool,
+            plant_id,
+            &entry_type,
+            MAX_SCORED_CARE,
+        )
+        .await?;
+        compliance = care_compliance(schedule.interval_days, &times, now).merge(compliance);
+    }
+
+    let photos = db_photos::get_photos_for_plant_paginated(
+        &app_state.pool,
+        plant_id,
+        &user.id,
+        Some(REPORT_PHOTOS),
+        None,
+        Some(true),
+    )
+    .await?
+    .photos
+    .into_iter()
+    .map(|photo| ReportPhoto {
+        url: photo.url(),
+        caption: photo.caption.unwrap_or(photo.original_filename),
+    })
+    .collect();
+
+    let entries = db_tracking::get_tracking_entries_for_plant_paginated(
+        &app_state.pool,
+        plant_id,
+        &user.id,
+        REPORT_ENTRIES,
+        0,
+        true,
+        None,
+    )
+    .await?
+    .entries
+    .into_iter()
+    .map(report_entry)
+    .collect();
+
+    let report = PlantReportTemplate {
+        plant: &plant,
+        health_score: compliance.health_score(),
+        watering: describe_schedule(&plant.watering_schedule, plant.last_watered),
+        fertilizing: describe_schedule(&plant.fertilizing_schedule, plant.last_fertilized),
+        photos,
+        entries,
+        generated_at: format_time(now),
+    };
+
+    report.render().map(Html).map_err(|e| AppError::Internal {
+        message: format!("Failed to render plant report: {}", e),
+    })
+}
@@ -0,0 +1,244 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::Json,
+    routing::{get, post},
+    Router,
+};
+
+use crate::app_state::AppState;
+use crate::auth::CurrentUser;
+use crate::database::{users as db_users, webhooks as db_webhooks};
+use crate::middleware::validation::ValidatedJson;
+use crate::models::user::UserPreferences;
+use crate::models::webhook::{
+    UpdateWebhookRequest, WebhookSecretResponse, WebhookSettingsResponse, WebhookTestResponse,
+};
+use crate::utils::errors::{AppError, Result};
+use crate::utils::webhooks::{check_webhook_url, send_test_event};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/preferences", get(get_preferences).put(update_preferences))
+        .route(
+            "/webhook",
+            get(get_webhook).put(update_webhook).delete(delete_webhook),
+        )
+        .route("/webhook/test", post(test_webhook))
+        .route("/webhook/rotate-secret", post(rotate_webhook_secret))
+}
+
+/// Get the current user's preferences
+#[utoipa::path(
+    get,
+    path = "/settings/preferences",
+    responses(
+        (status = 200, description = "User preferences", body = UserPreferences),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "settings",
+    security(
+        ("session" = [])
+    )
+)]
+pub async fn get_preferences(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<UserPreferences>> {
+    let preferences = db_users::get_preferences(&app_state.pool, &user.id).await?;
+
+    Ok(Json(preferences))
+}
+
+/// Update the current user's preferences
+#[utoipa::path(
+    put,
+    path = "/settings/preferences",
+    request_body = UserPreferences,
+    responses(
+        (status = 200, description = "Preferences updated", body = UserPreferences),
+        (status = 400, description = "Invalid preferences"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "settings",
+    security(
+        ("session" = [])
+    )
+)]
+pub async fn update_preferences(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    ValidatedJson(payload): ValidatedJson<UserPreferences>,
+) -> Result<Json<UserPreferences>> {
+    db_users::set_preferences(&app_state.pool, &user.id, &payload).await?;
+    tracing::info!("Updated preferences for user {}", user.id);
+
+    Ok(Json(payload))
+}
+
+/// Get the configured webhook endpoint
+#[utoipa::path(
+    get,
+    path = "/settings/webhook",
+    responses(
+        (status = 200, description = "Webhook settings", body = WebhookSettingsResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "No webhook configured")
+    ),
+    tag = "settings",
+    security(
+        ("session" = [])
+    )
+)]
+pub async fn get_webhook(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<WebhookSettingsResponse>> {
+    let webhook = db_webhooks::get_webhook(&app_state.pool, &user.id)
+        .await?
+        .ok_or_else(AppError::webhook_not_found)?;
+
+    Ok(Json(WebhookSettingsResponse {
+        url: webhook.url,
+        secret: None,
+        created_at: webhook.created_at,
+        updated_at: webhook.updated_at,
+    }))
+}
+
+/// Set the webhook endpoint URL
+///
+/// The signing secret is generated when the webhook is first configured and
+/// only returned in that response. The URL must be http(s) and, unless
+/// `WEBHOOK_ALLOW_PRIVATE_TARGETS` is set, not point at a private address.
+#[utoipa::path(
+    put,
+    path = "/settings/webhook",
+    request_body = UpdateWebhookRequest,
+    responses(
+        (status = 200, description = "Webhook updated", body = WebhookSettingsResponse),
+        (status = 422, description = "Invalid URL, or one pointing at a private address"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "settings",
+    security(
+        ("session" = [])
+    )
+)]
+pub async fn update_webhook(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    ValidatedJson(payload): ValidatedJson<UpdateWebhookRequest>,
+) -> Result<Json<WebhookSettingsResponse>> {
+    check_webhook_url(&payload.url, app_state.config.webhook_allow_private_targets).map_err(
+        |e| {
+            let mut error = validator::ValidationError::new(e.code());
+            error.message = Some(e.to_string().into());
+            let mut errors = validator::ValidationErrors::new();
+            errors.add("url", error);
+            AppError::Validation(errors)
+        },
+    )?;
+
+    let (webhook, created) =
+        db_webhooks::set_webhook_url(&app_state.pool, &user.id, &payload.url).await?;
+    tracing::info!("Updated webhook for user {}", user.id);
+
+    Ok(Json(WebhookSettingsResponse {
+        url: webhook.url,
+        secret: created.then_some(webhook.secret),
+        created_at: webhook.created_at,
+        updated_at: webhook.updated_at,
+    }))
+}
+
+/// Remove the webhook endpoint
+#[utoipa::path(
+    delete,
+    path = "/settings/webhook",
+    responses(
+        (status = 204, description = "Webhook removed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "No webhook configured")
+    ),
+    tag = "settings",
+    security(
+        ("session" = [])
+    )
+)]
+pub async fn delete_webhook(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+) -> Result<StatusCode> {
+    db_webhooks::delete_webhook(&app_state.pool, &user.id).await?;
+    tracing::info!("Removed webhook for user {}", user.id);
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Send a signed test event to the webhook endpoint and report how it answered
+#[utoipa::path(
+    post,
+    path = "/settings/webhook/test",
+    responses(
+        (status = 200, description = "Test delivery attempted", body = WebhookTestResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "No webhook configured")
+    ),
+    tag = "settings",
+    security(
+        ("session" = [])
+    )
+)]
+pub async fn test_webhook(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<WebhookTestResponse>> {
+    let webhook = db_webhooks::get_webhook(&app_state.pool, &user.id)
+        .await?
+        .ok_or_else(AppError::webhook_not_found)?;
+
+    let allow_private = app_state.config.webhook_allow_private_targets;
+    let response = match send_test_event(&webhook.url, &webhook.secret, allow_private).await {
+        Ok(status) => WebhookTestResponse {
+            success: (200..300).contains(&status),
+            status: Some(status),
+            error: None,
+        },
+        Err(e) => {
+            tracing::warn!("Webhook test delivery failed for user {}: {}", user.id, e);
+            // The reason stays in the logs so the endpoint can't be used to probe hosts
+            WebhookTestResponse {
+                success: false,
+                status: None,
+                error: Some("Could not reach webhook endpoint".to_string()),
+            }
+        }
+    };
+
+    Ok(Json(response))
+}
+
+/// Replace the signing secret; the new secret is only shown in this response
+#[utoipa::path(
+    post,
+    path = "/settings/webhook/rotate-secret",
+    responses(
+        (status = 200, description = "Secret rotated", body = WebhookSecretResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "No webhook configured")
+    ),
+    tag = "settings",
+    security(
+        ("session" = [])
+    )
+)]
+pub async fn rotate_webhook_secret(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+) -> Result<Json<WebhookSecretResponse>> {
+    let secret = db_webhooks::rotate_webhook_secret(&app_state.pool, &user.id).await?;
+    tracing::info!("Rotated webhook secret for user {}", user.id);
+
+    Ok(Json(WebhookSecretResponse { secret }))
+}
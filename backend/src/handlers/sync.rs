@@ -0,0 +1,83 @@
+use axum::{
+    extract::{Query, State},
+    response::Json,
+    routing::get,
+    Router,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::CurrentUser;
+use crate::database::sync as db_sync;
+use crate::models::sync::SyncChangesResponse;
+use crate::utils::errors::Result;
+
+#[derive(Debug, Deserialize)]
+pub struct SyncChangesQuery {
+    /// Cursor from the previous sync's `serverTime`; omit for a full sync
+    since: Option<DateTime<Utc>>,
+}
+
+pub fn routes() -> Router<AppState> {
+    Router::new().route("/changes", get(get_changes))
+}
+
+/// Get records changed since the last sync
+///
+/// Tombstones are only kept for `TOMBSTONE_RETENTION_DAYS`. An older cursor
+/// could miss deletions, so it gets a full sync with `fullResyncRequired` set.
+#[utoipa::path(
+    get,
+    path = "/sync/changes",
+    params(
+        ("since" = Option<String>, Query, description = "RFC 3339 timestamp from a previous sync's serverTime")
+    ),
+    responses(
+        (status = 200, description = "Changes since the cursor", body = SyncChangesResponse),
+        (status = 400, description = "Invalid cursor"),
+        (status = 401, description = "Unauthorized")
+    ),
+    tag = "sync",
+    security(
+        ("session" = [])
+    )
+)]
+pub async fn get_changes(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Query(params): Query<SyncChangesQuery>,
+) -> Result<Json<SyncChangesResponse>> {
+    tracing::info!(
+        "Sync changes request for user {} since {:?}",
+        user.id,
+        params.since
+    );
+
+    // Capture the cursor before querying so concurrent writes are not skipped
+    let server_time = Utc::now();
+    let tombstone_cutoff = server_time - app_state.config.tombstone_retention();
+    let expired = params.since.is_some_and(|since| since < tombstone_cutoff);
+    let since = if expired { None } else { params.since };
+
+    let mut changes =
+        db_sync::get_changes_since(&app_state.pool, &user.id, since, server_time).await?;
+    if expired {
+        tracing::info!(
+            "Sync cursor for user {} predates kept tombstones, sending a full sync",
+            user.id
+        );
+        changes.full_resync_required = true;
+    }
+
+    tracing::debug!(
+        "Returning {} plants, {} entries, {} photos, {} deletions for user {}",
+        changes.plants.len(),
+        changes.tracking_entries.len(),
+        changes.photos.len(),
+        changes.deleted.len(),
+        user.id
+    );
+
+    Ok(Json(changes))
+}
@@ -0,0 +1,89 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    routing::{delete, post},
+    Router,
+};
+
+use crate::app_state::AppState;
+use crate::auth::CurrentUser;
+use crate::database::tags as db_tags;
+use crate::middleware::validation::ValidatedJson;
+use crate::models::PlantId;
+use crate::models::plant::{AddTagsRequest, PlantTagsResponse};
+use crate::utils::errors::Result;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/tags", post(add_tags))
+        .route("/tags/:tag", delete(remove_tag))
+}
+
+/// Tag a plant with free-form labels such as `toxic-to-cats`
+///
+/// Tags are case-insensitive and stored lowercase; ones the plant already has are ignored.
+#[utoipa::path(
+    post,
+    path = "/plants/{plant_id}/tags",
+    params(
+        ("plant_id" = Uuid, Path, description = "Plant ID")
+    ),
+    request_body = AddTagsRequest,
+    responses(
+        (status = 200, description = "All of the plant's tags", body = PlantTagsResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant not found"),
+        (status = 422, description = "Invalid tag, or no or too many tags"),
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn add_tags(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path(plant_id): Path<PlantId>,
+    ValidatedJson(payload): ValidatedJson<AddTagsRequest>,
+) -> Result<Json<PlantTagsResponse>> {
+    tracing::info!(
+        "Add tags {:?} to plant: {} by user: {}",
+        payload.tags,
+        plant_id,
+        user.id
+    );
+
+    let tags = db_tags::add_tags(&app_state.pool, plant_id, &user.id, &payload.tags).await?;
+
+    Ok(Json(PlantTagsResponse { tags }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/plants/{plant_id}/tags/{tag}",
+    params(
+        ("plant_id" = Uuid, Path, description = "Plant ID"),
+        ("tag" = String, Path, description = "Tag to remove, matched case-insensitively")
+    ),
+    responses(
+        (status = 204, description = "Tag removed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant or tag not found"),
+    ),
+    tag = "plants",
+    security(
+        ("session" = [])
+    )
+)]
+async fn remove_tag(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path((plant_id, tag)): Path<(PlantId, String)>,
+) -> Result<StatusCode> {
+    tracing::info!("Remove tag {} from plant: {} by user: {}", tag, plant_id, user.id);
+
+    db_tags::remove_tag(&app_state.pool, plant_id, &user.id, &tag).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,22 +1,27 @@
 #[allow(unused_imports)]
 use axum::{
-    extract::{Path, Query, State},
+    extract::{OriginalUri, Path, Query, State},
     http::StatusCode,
     response::Json,
     routing::{delete, get, post, put},
     Router,
 };
 use serde::Deserialize;
-use uuid::Uuid;
 
 use crate::app_state::AppState;
-use crate::auth::AuthSession;
-use crate::database::tracking as db_tracking;
+use crate::auth::CurrentUser;
+use crate::database::{timeline as db_timeline, tracking as db_tracking, users as db_users};
 use crate::middleware::validation::ValidatedJson;
+use crate::models::{EntryId, PlantId};
 use crate::models::tracking_entry::{
-    CreateTrackingEntryRequest, TrackingEntriesResponse, TrackingEntry,
+    CreateEntriesBulkRequest, CreateEntriesBulkResponse, CreateTrackingEntryRequest,
+    DeleteEntriesRequest, DeleteEntriesResponse, EntryRollupResponse,
+    ImportEntriesResponse, RollupGranularity, TrackingEntriesResponse, TrackingEntry,
 };
+use crate::models::timeline::TimelineResponse;
+use crate::utils::care_import::parse_care_history;
 use crate::utils::errors::{AppError, Result};
+use crate::utils::pagination::{offset_links, PageLinks};
 
 #[derive(Debug, Deserialize)]
 struct ListEntriesQuery {
@@ -26,13 +31,69 @@ struct ListEntriesQuery {
     entry_type: Option<String>, // filter by entry type
 }
 
+#[derive(Debug, Deserialize)]
+struct ImportEntriesQuery {
+    /// Run every check and report the results without inserting anything
+    #[serde(default)]
+    validate_only: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct TimelineQuery {
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
 pub fn routes() -> Router<AppState> {
     Router::new()
-        .route("/:plant_id/entries", get(list_entries).post(create_entry))
+        .route(
+            "/:plant_id/entries",
+            get(list_entries).post(create_entry).delete(delete_entries),
+        )
+        .route("/:plant_id/entries/bulk", post(create_entries_bulk))
+        .route("/:plant_id/entries/import.csv", post(import_entries))
+        .route("/:plant_id/entries/rollup", get(rollup_entries))
         .route(
             "/:plant_id/entries/:entry_id",
             get(get_entry).put(update_entry).delete(delete_entry),
         )
+        .route("/:plant_id/entries/:entry_id/restore", post(restore_entry))
+        .route("/:plant_id/timeline", get(get_timeline))
+}
+
+#[utoipa::path(
+    get,
+    path = "/plants/{plant_id}/timeline",
+    params(
+        ("plant_id" = Uuid, Path, description = "Plant ID"),
+        ("limit" = Option<i64>, Query, description = "Maximum number of items to return (default 50)"),
+        ("offset" = Option<i64>, Query, description = "Number of items to skip")
+    ),
+    responses(
+        (status = 200, description = "Entries and photos for the plant, newest first", body = TimelineResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant not found"),
+    ),
+    security(
+        ("session" = [])
+    )
+)]
+async fn get_timeline(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path(plant_id): Path<PlantId>,
+    Query(params): Query<TimelineQuery>,
+) -> Result<Json<TimelineResponse>> {
+    let timeline = db_timeline::get_plant_timeline(
+        &app_state.pool,
+        plant_id,
+        &user.id,
+        params.limit,
+        params.offset,
+    )
+    .await?;
+
+    Ok(Json(timeline))
 }
 
 #[utoipa::path(
@@ -51,15 +112,12 @@ pub fn routes() -> Router<AppState> {
     )
 )]
 async fn list_entries(
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
     State(app_state): State<AppState>,
-    Path(plant_id): Path<Uuid>,
+    OriginalUri(uri): OriginalUri,
+    Path(plant_id): Path<PlantId>,
     Query(params): Query<ListEntriesQuery>,
 ) -> Result<Json<TrackingEntriesResponse>> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
     tracing::info!(
         "List tracking entries request for plant: {} by user: {} with params: {:?}",
         plant_id,
@@ -74,9 +132,9 @@ async fn list_entries(
         _ => true, // default to date_desc
     };
 
-    let response = db_tracking::get_tracking_entries_for_plant_paginated(
+    let mut response = db_tracking::get_tracking_entries_for_plant_paginated(
         &app_state.pool,
-        &plant_id,
+        plant_id,
         &user.id,
         limit,
         offset,
@@ -85,6 +143,10 @@ async fn list_entries(
     )
     .await?;
 
+    let PageLinks { next, prev } = offset_links(&uri, response.total, limit, offset);
+    response.next = next;
+    response.prev = prev;
+
     tracing::debug!(
         "Returning {} tracking entries for plant: {}",
         response.total,
@@ -93,6 +155,66 @@ async fn list_entries(
     Ok(Json(response))
 }
 
+#[derive(Debug, Deserialize)]
+struct RollupQuery {
+    granularity: Option<String>, // "day", "week" (default) or "month"
+}
+
+/// Count entries per day, week or month, using the user's week start for weeks
+#[utoipa::path(
+    get,
+    path = "/plants/{plant_id}/entries/rollup",
+    responses(
+        (status = 200, description = "Entry counts per period and type", body = EntryRollupResponse),
+        (status = 400, description = "Invalid granularity"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant not found"),
+    ),
+    params(
+        ("plant_id" = Uuid, Path, description = "Plant ID"),
+        ("granularity" = Option<RollupGranularity>, Query, description = "day, week (default) or month")
+    ),
+    security(
+        ("session" = [])
+    )
+)]
+async fn rollup_entries(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path(plant_id): Path<PlantId>,
+    Query(params): Query<RollupQuery>,
+) -> Result<Json<EntryRollupResponse>> {
+    let granularity = match params.granularity.as_deref() {
+        None | Some("week") => RollupGranularity::Week,
+        Some("day") => RollupGranularity::Day,
+        Some("month") => RollupGranularity::Month,
+        Some(other) => {
+            return Err(AppError::BadRequest {
+                message: format!(
+                    "Unknown granularity '{}', expected day, week or month",
+                    other
+                ),
+            })
+        }
+    };
+
+    let week_start = db_users::get_week_start(&app_state.pool, &user.id).await?;
+    let buckets = db_tracking::rollup_entries(
+        &app_state.pool,
+        plant_id,
+        &user.id,
+        granularity,
+        week_start,
+    )
+    .await?;
+
+    Ok(Json(EntryRollupResponse {
+        granularity,
+        week_start,
+        buckets,
+    }))
+}
+
 #[utoipa::path(
     post,
     path = "/plants/{plant_id}/entries",
@@ -111,22 +233,18 @@ async fn list_entries(
     )
 )]
 async fn create_entry(
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
     State(app_state): State<AppState>,
-    Path(plant_id): Path<Uuid>,
+    Path(plant_id): Path<PlantId>,
     ValidatedJson(payload): ValidatedJson<CreateTrackingEntryRequest>,
 ) -> Result<(StatusCode, Json<TrackingEntry>)> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
     tracing::info!(
         "Create tracking entry request for plant: {} by user: {}",
         plant_id,
         user.id
     );
 
-    let entry = db_tracking::create_tracking_entry(&app_state.pool, &plant_id, &user.id, &payload).await?;
+    let entry = db_tracking::create_tracking_entry(&app_state.pool, plant_id, &user.id, &payload).await?;
 
     tracing::info!(
         "Created tracking entry with id: {} for plant: {}",
@@ -136,15 +254,165 @@ async fn create_entry(
     Ok((StatusCode::CREATED, Json(entry)))
 }
 
+/// Create several entries at once, e.g. a week of back-dated waterings
+///
+/// All entries are validated first and created together, or not at all.
+/// The plant's watering and fertilizing dates move to the latest matching
+/// entry in the batch.
+#[utoipa::path(
+    post,
+    path = "/plants/{plant_id}/entries/bulk",
+    request_body = CreateEntriesBulkRequest,
+    responses(
+        (status = 201, description = "Tracking entries created", body = CreateEntriesBulkResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant not found"),
+        (status = 422, description = "An entry is invalid; the message names its index"),
+    ),
+    params(
+        ("plant_id" = Uuid, Path, description = "Plant ID")
+    ),
+    security(
+        ("session" = [])
+    )
+)]
+async fn create_entries_bulk(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path(plant_id): Path<PlantId>,
+    ValidatedJson(payload): ValidatedJson<CreateEntriesBulkRequest>,
+) -> Result<(StatusCode, Json<CreateEntriesBulkResponse>)> {
+    let entries =
+        db_tracking::create_entries_bulk(&app_state.pool, plant_id, &user.id, payload.entries)
+            .await?;
+
+    tracing::info!(
+        "Created {} tracking entries for plant: {} by user: {}",
+        entries.len(),
+        plant_id,
+        user.id
+    );
+    Ok((
+        StatusCode::CREATED,
+        Json(CreateEntriesBulkResponse {
+            count: entries.len(),
+            entries,
+        }),
+    ))
+}
+
+/// Delete every entry matching the filters, e.g. to undo a bad import
+///
+/// Filters combine, and at least one is required. Care dates set by a deleted
+/// entry roll back to the latest remaining one.
+#[utoipa::path(
+    delete,
+    path = "/plants/{plant_id}/entries",
+    request_body = DeleteEntriesRequest,
+    responses(
+        (status = 200, description = "Number of entries deleted", body = DeleteEntriesResponse),
+        (status = 400, description = "No filter given"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant not found"),
+    ),
+    params(
+        ("plant_id" = Uuid, Path, description = "Plant ID")
+    ),
+    security(
+        ("session" = [])
+    )
+)]
+async fn delete_entries(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path(plant_id): Path<PlantId>,
+    ValidatedJson(payload): ValidatedJson<DeleteEntriesRequest>,
+) -> Result<Json<DeleteEntriesResponse>> {
+    if !payload.has_filter() {
+        return Err(AppError::BadRequest {
+            message: "At least one of entryType, from, to or ids is required".to_string(),
+        });
+    }
+
+    let deleted =
+        db_tracking::delete_tracking_entries(&app_state.pool, plant_id, &user.id, &payload)
+            .await?;
+
+    tracing::info!(
+        "Deleted {} tracking entries for plant: {} by user: {}",
+        deleted,
+        plant_id,
+        user.id
+    );
+    Ok(Json(DeleteEntriesResponse { deleted }))
+}
+
+/// Import care history from a CSV with `timestamp,entry_type,notes` columns
+///
+/// Valid rows are inserted together; invalid rows are skipped and reported.
+/// With `validate_only=true` the same checks run but nothing is written.
+#[utoipa::path(
+    post,
+    path = "/plants/{plant_id}/entries/import.csv",
+    request_body(content = String, content_type = "text/csv"),
+    responses(
+        (status = 200, description = "Import finished", body = ImportEntriesResponse),
+        (status = 400, description = "CSV header is missing required columns"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant not found"),
+    ),
+    params(
+        ("plant_id" = Uuid, Path, description = "Plant ID"),
+        ("validate_only" = Option<bool>, Query, description = "Validate the CSV without importing it")
+    ),
+    security(
+        ("session" = [])
+    )
+)]
+async fn import_entries(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path(plant_id): Path<PlantId>,
+    Query(params): Query<ImportEntriesQuery>,
+    body: String,
+) -> Result<Json<ImportEntriesResponse>> {
+    tracing::info!(
+        "Import tracking entries request for plant: {} by user: {} (validate only: {})",
+        plant_id,
+        user.id,
+        params.validate_only
+    );
+
+    let (entries, errors) = parse_care_history(&body)?;
+    let imported = db_tracking::import_tracking_entries(
+        &app_state.pool,
+        plant_id,
+        &user.id,
+        entries,
+        params.validate_only,
+    )
+    .await?;
+
+    tracing::info!(
+        "{} {} tracking entries for plant: {} ({} rows skipped)",
+        if params.validate_only { "Validated" } else { "Imported" },
+        imported,
+        plant_id,
+        errors.len()
+    );
+    Ok(Json(ImportEntriesResponse {
+        imported,
+        skipped: errors.len() as u64,
+        errors,
+        validate_only: params.validate_only,
+    }))
+}
+
 async fn get_entry(
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
     State(app_state): State<AppState>,
-    Path((plant_id, entry_id)): Path<(Uuid, Uuid)>,
+    Path((plant_id, entry_id)): Path<(PlantId, EntryId)>,
 ) -> Result<Json<TrackingEntry>> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
     tracing::info!(
         "Get tracking entry request for plant: {}, entry: {} by user: {}",
         plant_id,
@@ -152,7 +420,7 @@ async fn get_entry(
         user.id
     );
 
-    let entry = db_tracking::get_tracking_entry(&app_state.pool, &plant_id, &entry_id, &user.id).await?;
+    let entry = db_tracking::get_tracking_entry(&app_state.pool, plant_id, &entry_id, &user.id).await?;
 
     tracing::debug!(
         "Retrieved tracking entry: {} for plant: {}",
@@ -163,17 +431,13 @@ async fn get_entry(
 }
 
 async fn update_entry(
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
     State(app_state): State<AppState>,
-    Path((plant_id, entry_id)): Path<(Uuid, Uuid)>,
+    Path((plant_id, entry_id)): Path<(PlantId, EntryId)>,
     ValidatedJson(payload): ValidatedJson<
         crate::models::tracking_entry::UpdateTrackingEntryRequest,
     >,
 ) -> Result<Json<TrackingEntry>> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
     tracing::info!(
         "Update tracking entry request for plant: {}, entry: {} by user: {}",
         plant_id,
@@ -182,7 +446,7 @@ async fn update_entry(
     );
 
     let entry =
-        db_tracking::update_tracking_entry(&app_state.pool, &plant_id, &entry_id, &user.id, &payload).await?;
+        db_tracking::update_tracking_entry(&app_state.pool, plant_id, &entry_id, &user.id, &payload).await?;
 
     tracing::info!(
         "Updated tracking entry: {} for plant: {}",
@@ -193,14 +457,10 @@ async fn update_entry(
 }
 
 async fn delete_entry(
-    auth_session: AuthSession,
+    CurrentUser(user): CurrentUser,
     State(app_state): State<AppState>,
-    Path((plant_id, entry_id)): Path<(Uuid, Uuid)>,
+    Path((plant_id, entry_id)): Path<(PlantId, EntryId)>,
 ) -> Result<StatusCode> {
-    let user = auth_session.user.ok_or(AppError::Authentication {
-        message: "Not authenticated".to_string(),
-    })?;
-
     tracing::info!(
         "Delete tracking entry request for plant: {}, entry: {} by user: {}",
         plant_id,
@@ -208,7 +468,7 @@ async fn delete_entry(
         user.id
     );
 
-    db_tracking::delete_tracking_entry(&app_state.pool, &plant_id, &entry_id, &user.id).await?;
+    db_tracking::delete_tracking_entry(&app_state.pool, plant_id, &entry_id, &user.id).await?;
 
     tracing::info!(
         "Deleted tracking entry: {} for plant: {}",
@@ -217,3 +477,43 @@ async fn delete_entry(
     );
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[utoipa::path(
+    post,
+    path = "/plants/{plant_id}/entries/{entry_id}/restore",
+    responses(
+        (status = 200, description = "Tracking entry restored", body = TrackingEntry),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Plant not found, or entry not deleted within the restore window"),
+    ),
+    params(
+        ("plant_id" = Uuid, Path, description = "Plant ID"),
+        ("entry_id" = Uuid, Path, description = "Tracking entry ID")
+    ),
+    security(
+        ("session" = [])
+    )
+)]
+async fn restore_entry(
+    CurrentUser(user): CurrentUser,
+    State(app_state): State<AppState>,
+    Path((plant_id, entry_id)): Path<(PlantId, EntryId)>,
+) -> Result<Json<TrackingEntry>> {
+    tracing::info!(
+        "Restore tracking entry request for plant: {}, entry: {} by user: {}",
+        plant_id,
+        entry_id,
+        user.id
+    );
+
+    let entry =
+        db_tracking::restore_tracking_entry(&app_state.pool, plant_id, &entry_id, &user.id)
+            .await?;
+
+    tracing::info!(
+        "Restored tracking entry: {} for plant: {}",
+        entry_id,
+        plant_id
+    );
+    Ok(Json(entry))
+}
@@ -1,8 +1,10 @@
+use utoipa::openapi::security::SecurityRequirement;
 use utoipa::OpenApi;
 
 pub mod admin;
 pub mod app_state;
 pub mod auth;
+pub mod config;
 pub mod database;
 pub mod handlers;
 pub mod middleware;
@@ -10,27 +12,50 @@ pub mod models;
 pub mod utils;
 
 use models::{
+    dashboard::DashboardSummary,
+    features::FeatureFlags,
     google_oauth::{
         CreateGoogleTaskRequest, GoogleOAuthCallbackRequest, GoogleOAuthSuccessResponse,
-        GoogleOAuthUrlResponse, GoogleTasksStatus, SyncPlantTasksRequest,
+        GoogleOAuthUrlResponse, GoogleTaskList, GoogleTaskListsResponse, GoogleTasksStatus,
+        SyncPlantTasksRequest, TokenRefreshResponse,
     },
+    integration::{IntegrationName, IntegrationStatus, IntegrationsResponse},
     invite::{
         CreateInviteRequest, InviteResponse, ValidateInviteRequest, WaitlistResponse,
         WaitlistSignupRequest,
     },
-    photo::{Photo, PhotosResponse},
-    plant::{CareSchedule, CreateCareScheduleRequest, CreateCustomMetricRequest, CreatePlantRequest, CustomMetric, MetricDataType, PlantResponse, PlantsResponse, UpdateCareScheduleRequest, UpdateCustomMetricRequest, UpdatePlantRequest},
+    api_key::{ApiKey, ApiKeysResponse, CreateApiKeyRequest, CreatedApiKeyResponse},
+    photo::{Photo, PhotosResponse, UpdatePhotoRequest},
+    sync::{DeletedEntityType, Deletion, SyncChangesResponse},
+    timeline::{TimelineItem, TimelinePhoto, TimelineResponse},
+    public_link::{PublicLinkResponse, PublicNote, PublicPhoto, PublicPlantResponse},
+    watering::{CareRecommendationsResponse, IntervalRecommendation, WateringAdjustment, WateringSuggestion},
+    plant::{AddTagsRequest, BulkUpdateScheduleRequest, BulkUpdateScheduleResponse, CareSchedule, CareSeverity, CareStatus, CareTask, CareTaskDue, CareTasksResponse, CreateCareScheduleRequest, CreateCareTaskRequest, CreateCustomMetricRequest, CreatePlantRequest, CustomMetric, MetricDataType, CareKind, CareOccurrence, UpcomingCareResponse, ScheduleLoadDay, ScheduleLoadResponse, ScheduleShift, RebalanceScheduleResponse, PlantAnniversariesResponse, PlantAnniversary, MemorialResponse, PlantStatus, PlantCareStatus, PlantResponse, PlantSummariesResponse, PlantSummary, PlantTagsResponse, PlantsCursorResponse, PlantsResponse, SeedExamplesResponse, UpdateCareScheduleRequest, UpdateCareTaskRequest, UpdateCustomMetricRequest, UpdatePlantRequest},
     tracking_entry::{
-        CreateTrackingEntryRequest, EntryType, TrackingEntriesResponse, TrackingEntry,
+        CreateEntriesBulkRequest, CreateEntriesBulkResponse, CreateTrackingEntryRequest, DeleteEntriesRequest, DeleteEntriesResponse, EntryRollupBucket,
+        EntryRollupResponse, EntryType, ImportEntriesResponse, ImportRowError, NoteVisibility, RollupGranularity, TrackingEntriesResponse,
+        TrackingEntry,
+    },
+    user::{
+        AuthResponse, CreateUserRequest, LoginRequest, SessionStatusResponse, UserPreferences,
+        UserResponse, UserRole, WeekStart,
+    },
+    webhook::{
+        UpdateWebhookRequest, WebhookSecretResponse, WebhookSettingsResponse, WebhookTestResponse,
     },
-    user::{AuthResponse, CreateUserRequest, LoginRequest, UserResponse, UserRole},
 };
 
 use admin::SystemStats;
+use database::admin_settings::SettingSource;
 use handlers::admin::{
-    AdminDashboardResponse, AdminSettingsResponse, BulkUserAction, BulkUserActionRequest,
-    InviteInfo, UpdateAdminSettingsRequest, UpdateUserRequest, UserListResponse,
+    AdminDashboardResponse, AdminSessionInfo, AdminSettingsResponse, BulkUserAction,
+    BulkUserActionRequest, EffectiveAdminSettingsResponse, EffectiveFlagSetting,
+    EffectiveNumberSetting, InviteInfo, JobListResponse, NotifyWaitlistRequest,
+    NotifyWaitlistResponse, SessionListResponse,
+    TransferPlantsResponse, UpdateAdminSettingsRequest, UpdateUserRequest, UserListResponse,
 };
+use utils::job_registry::{JobOutcome, JobStatus};
+use utils::thumbnail_backfill::{BackfillProgress, BackfillState};
 
 use handlers::google_tasks::StoreTokensRequest;
 
@@ -39,14 +64,23 @@ use handlers::google_tasks::StoreTokensRequest;
     paths(
         crate::handlers::auth::login,
         crate::handlers::auth::register,
+        crate::handlers::auth::session_status,
         crate::handlers::admin::get_admin_dashboard,
         crate::handlers::admin::list_users,
         crate::handlers::admin::update_user,
         crate::handlers::admin::delete_user,
+        crate::handlers::admin::transfer_plants,
         crate::handlers::admin::bulk_user_action,
         crate::handlers::admin::get_admin_settings,
         crate::handlers::admin::update_admin_settings,
+        crate::handlers::admin::get_effective_admin_settings,
         crate::handlers::admin::get_system_health,
+        crate::handlers::admin::list_jobs,
+        crate::handlers::admin::list_sessions,
+        crate::handlers::admin::revoke_session,
+        crate::handlers::admin::notify_waitlist,
+        crate::handlers::admin::start_thumbnail_backfill,
+        crate::handlers::admin::get_thumbnail_backfill,
         crate::handlers::invites::create_invite,
         crate::handlers::invites::validate_invite,
         crate::handlers::invites::list_invites,
@@ -56,32 +90,102 @@ use handlers::google_tasks::StoreTokensRequest;
         crate::handlers::plants::create_plant,
         crate::handlers::plants::get_plant,
         crate::handlers::plants::update_plant,
+        crate::handlers::plants::bulk_update_schedule,
+        crate::handlers::plants::seed_examples,
+        crate::handlers::plants::list_anniversaries,
+        crate::handlers::plants::list_memorial,
+        crate::handlers::plants::export_plants,
+        crate::handlers::plants::get_watering_suggestion,
+        crate::handlers::plants::get_care_recommendations,
+        crate::handlers::plants::dismiss_recommendation,
+        crate::handlers::reports::get_plant_report,
+        crate::handlers::public_links::create_public_link,
+        crate::handlers::public_links::delete_public_link,
+        crate::handlers::public_links::get_public_plant,
+        crate::handlers::public_links::serve_public_photo,
+        crate::handlers::plants::get_plant_care_status,
+        crate::handlers::plants::get_upcoming_care,
+        crate::handlers::plants::get_schedule_load,
+        crate::handlers::plants::rebalance_care_schedule,
         crate::handlers::plants::delete_plant,
+        crate::handlers::plants::archive_plant,
+        crate::handlers::plants::unarchive_plant,
+        crate::handlers::care_tasks::list_care_tasks,
+        crate::handlers::care_tasks::create_care_task,
+        crate::handlers::care_tasks::get_care_task,
+        crate::handlers::care_tasks::update_care_task,
+        crate::handlers::care_tasks::delete_care_task,
+        crate::handlers::tags::add_tags,
+        crate::handlers::tags::remove_tag,
+        crate::handlers::dashboard::get_summary,
+        crate::handlers::photos::list_photos,
+        crate::handlers::photos::serve_photo_thumbnail,
         crate::handlers::tracking::list_entries,
         crate::handlers::tracking::create_entry,
+        crate::handlers::tracking::create_entries_bulk,
+        crate::handlers::tracking::delete_entries,
+        crate::handlers::tracking::import_entries,
+        crate::handlers::tracking::rollup_entries,
+        crate::handlers::tracking::restore_entry,
+        crate::handlers::tracking::get_timeline,
+        crate::handlers::sync::get_changes,
+        crate::handlers::calendar::get_calendar_feed,
+        crate::handlers::calendar::get_calendar_subscription_info,
+        crate::handlers::calendar::regenerate_calendar_token,
         crate::handlers::google_tasks::get_google_auth_url,
         crate::handlers::google_tasks::handle_google_oauth_callback,
         crate::handlers::google_tasks::store_google_tokens,
         crate::handlers::google_tasks::get_google_tasks_status,
         crate::handlers::google_tasks::disconnect_google_tasks,
+        crate::handlers::google_tasks::refresh_google_token,
+        crate::handlers::google_tasks::list_task_lists,
         crate::handlers::google_tasks::sync_plant_tasks,
         crate::handlers::google_tasks::create_task,
+        crate::handlers::integrations::list_integrations,
+        crate::handlers::features::get_features,
+        crate::handlers::api_keys::list_api_keys,
+        crate::handlers::api_keys::create_api_key,
+        crate::handlers::api_keys::revoke_api_key,
+        crate::handlers::settings::get_preferences,
+        crate::handlers::settings::update_preferences,
+        crate::handlers::settings::get_webhook,
+        crate::handlers::settings::update_webhook,
+        crate::handlers::settings::delete_webhook,
+        crate::handlers::settings::test_webhook,
+        crate::handlers::settings::rotate_webhook_secret,
     ),
     components(
         schemas(
             AuthResponse,
+            SessionStatusResponse,
             CreateUserRequest,
             LoginRequest,
             UserResponse,
             UserRole,
+            UserPreferences,
+            WeekStart,
             SystemStats,
             AdminDashboardResponse,
             AdminSettingsResponse,
+            EffectiveAdminSettingsResponse,
+            EffectiveNumberSetting,
+            EffectiveFlagSetting,
+            SettingSource,
             UserListResponse,
             UpdateUserRequest,
             UpdateAdminSettingsRequest,
             BulkUserActionRequest,
             BulkUserAction,
+            TransferPlantsResponse,
+            JobListResponse,
+            AdminSessionInfo,
+            SessionListResponse,
+            NotifyWaitlistRequest,
+            NotifyWaitlistResponse,
+            JobStatus,
+            JobOutcome,
+            BackfillProgress,
+            BackfillState,
             InviteInfo,
             CreateInviteRequest,
             InviteResponse,
@@ -89,18 +193,71 @@ use handlers::google_tasks::StoreTokensRequest;
             WaitlistResponse,
             WaitlistSignupRequest,
             CreateTrackingEntryRequest,
+            CreateEntriesBulkRequest,
+            CreateEntriesBulkResponse,
             EntryType,
+            NoteVisibility,
             TrackingEntriesResponse,
             TrackingEntry,
+            TimelineItem,
+            TimelinePhoto,
+            TimelineResponse,
+            ImportEntriesResponse,
+            ImportRowError,
+            DeleteEntriesRequest,
+            DeleteEntriesResponse,
+            RollupGranularity,
+            EntryRollupBucket,
+            EntryRollupResponse,
             Photo,
             PhotosResponse,
+            UpdatePhotoRequest,
+            DeletedEntityType,
+            Deletion,
+            SyncChangesResponse,
             PlantResponse,
             PlantsResponse,
+            PlantsCursorResponse,
+            PlantSummary,
+            PlantSummariesResponse,
+            SeedExamplesResponse,
+            PlantAnniversary,
+            PlantAnniversariesResponse,
+            MemorialResponse,
+            PlantStatus,
+            CareStatus,
+            PlantCareStatus,
+            CareKind,
+            CareOccurrence,
+            UpcomingCareResponse,
+            ScheduleLoadDay,
+            ScheduleLoadResponse,
+            CareTask,
+            CareTaskDue,
+            CareTasksResponse,
+            CreateCareTaskRequest,
+            UpdateCareTaskRequest,
+            AddTagsRequest,
+            PlantTagsResponse,
+            DashboardSummary,
+            ScheduleShift,
+            RebalanceScheduleResponse,
+            WateringAdjustment,
+            WateringSuggestion,
+            CareRecommendationsResponse,
+            IntervalRecommendation,
+            PublicLinkResponse,
+            PublicPlantResponse,
+            PublicPhoto,
+            PublicNote,
             CreatePlantRequest,
             UpdatePlantRequest,
+            BulkUpdateScheduleRequest,
+            BulkUpdateScheduleResponse,
             CreateCustomMetricRequest,
             UpdateCustomMetricRequest,
             CareSchedule,
+            CareSeverity,
             CreateCareScheduleRequest,
             UpdateCareScheduleRequest,
             CustomMetric,
@@ -110,8 +267,23 @@ use handlers::google_tasks::StoreTokensRequest;
             GoogleOAuthSuccessResponse,
             GoogleOAuthUrlResponse,
             GoogleTasksStatus,
+            GoogleTaskList,
+            GoogleTaskListsResponse,
             SyncPlantTasksRequest,
+            TokenRefreshResponse,
             StoreTokensRequest,
+            IntegrationName,
+            IntegrationStatus,
+            IntegrationsResponse,
+            FeatureFlags,
+            UpdateWebhookRequest,
+            WebhookSettingsResponse,
+            WebhookSecretResponse,
+            WebhookTestResponse,
+            ApiKey,
+            ApiKeysResponse,
+            CreateApiKeyRequest,
+            CreatedApiKeyResponse,
         )
     ),
     tags(
@@ -121,7 +293,13 @@ use handlers::google_tasks::StoreTokensRequest;
         (name = "plants", description = "Plant management endpoints"),
         (name = "tracking", description = "Plant care tracking endpoints"),
         (name = "photos", description = "Photo management endpoints"),
+        (name = "dashboard", description = "Collection-wide dashboard totals"),
+        (name = "sync", description = "Delta sync endpoints for offline clients"),
+        (name = "calendar", description = "iCalendar feed of upcoming care"),
         (name = "google-tasks", description = "Google Tasks integration endpoints"),
+        (name = "integrations", description = "Integration discovery endpoints"),
+        (name = "features", description = "Optional features active on this server"),
+        (name = "settings", description = "User settings such as the webhook endpoint"),
     ),
     info(
         title = "Planty API",
@@ -131,3 +309,25 @@ use handlers::google_tasks::StoreTokensRequest;
     )
 )]
 pub struct ApiDoc;
+
+impl ApiDoc {
+    /// The spec trimmed to operations that don't need a session, for public docs
+    ///
+    /// Paths left without any operation are dropped.
+    pub fn public() -> utoipa::openapi::OpenApi {
+        let mut openapi = Self::openapi();
+        let is_public = |operation: &utoipa::openapi::path::Operation| {
+            operation.security.as_ref().is_none_or(|requirements| {
+                requirements
+                    .iter()
+                    .all(|requirement| *requirement == SecurityRequirement::default())
+            })
+        };
+
+        openapi.paths.paths.retain(|_, item| {
+            item.operations.retain(|_, operation| is_public(operation));
+            !item.operations.is_empty()
+        });
+        openapi
+    }
+}
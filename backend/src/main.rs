@@ -1,16 +1,18 @@
 use axum::{
     extract::DefaultBodyLimit,
-    http::{header, Method, StatusCode},
+    http::StatusCode,
     middleware::from_fn,
-    response::{Html, Json},
+    response::Json,
     routing::get,
     Router,
 };
 use clap::Parser;
 use serde_json::{json, Value};
-use std::{env, path::Path};
-use tower::ServiceBuilder;
-use tower_http::{cors::CorsLayer, services::ServeDir, trace::TraceLayer};
+use std::{path::Path, time::Duration};
+use tower::{Layer, ServiceBuilder};
+use tower_http::{
+    cors::CorsLayer, normalize_path::NormalizePathLayer, trace::TraceLayer,
+};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
@@ -18,6 +20,7 @@ use utoipa_swagger_ui::SwaggerUi;
 mod admin;
 mod app_state;
 mod auth;
+mod config;
 mod database;
 mod handlers;
 mod middleware;
@@ -25,11 +28,16 @@ mod models;
 mod utils;
 
 use app_state::AppState;
-use handlers::{admin as admin_handlers, auth as auth_handlers, calendar, google_tasks, invites, plants};
+use config::AppConfig;
+use handlers::{admin as admin_handlers, auth as auth_handlers, calendar, dashboard, features, frontend, google_tasks, integrations, invites, plants, public_links, settings, sync};
+use middleware::query_count::{
+    count_queries as count_queries_per_request, QueryCountLayer, QUERY_COUNT_DIRECTIVES,
+};
 use planty_api::ApiDoc;
 use utils::{
-    google_tasks::GoogleTasksConfig, 
     token_refresh_scheduler::start_token_refresh_scheduler,
+    entry_purger::start_entry_purger,
+    tombstone_pruner::start_tombstone_pruner,
 };
 
 #[derive(Parser, Debug)]
@@ -52,9 +60,33 @@ struct Args {
     #[arg(short, long, env = "FRONTEND_DIR", default_value = "../frontend/dist")]
     frontend_dir: String,
 
+    /// Seconds to keep index.html in memory before re-reading it from the frontend directory
+    #[arg(long, env = "INDEX_CACHE_TTL_SECS", default_value = "30")]
+    index_cache_ttl_secs: u64,
+
     /// Log level
     #[arg(short, long, env = "RUST_LOG", default_value = "info")]
     log_level: String,
+
+    /// Enable the Google Tasks integration (routes and token refresh scheduler)
+    #[arg(
+        long,
+        env = "GOOGLE_INTEGRATION_ENABLED",
+        default_value_t = true,
+        action = clap::ArgAction::Set,
+        value_parser = clap::builder::BoolishValueParser::new()
+    )]
+    google_integration_enabled: bool,
+
+    /// Add an X-DB-Query-Count header to every response (debug builds only)
+    #[arg(
+        long,
+        env = "DB_QUERY_COUNT",
+        default_value_t = false,
+        action = clap::ArgAction::Set,
+        value_parser = clap::builder::BoolishValueParser::new()
+    )]
+    db_query_count: bool,
 }
 
 #[tokio::main]
@@ -64,44 +96,86 @@ async fn main() -> anyhow::Result<()> {
     
     let args = Args::parse();
 
+    // Query counting needs sqlx's per-statement events, so only debug builds offer it
+    let count_queries = cfg!(debug_assertions) && args.db_query_count;
+
     // Initialize tracing with specified log level (now reads RUST_LOG from .env)
-    tracing_subscriber::registry()
-        .with(
-            tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| {
-                format!(
-                    "{}={},tower_http=debug",
-                    env!("CARGO_PKG_NAME").replace('-', "_"),
-                    args.log_level
-                )
-                .into()
-            }),
+    let mut env_filter = tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| {
+        format!(
+            "{}={},tower_http=debug",
+            env!("CARGO_PKG_NAME").replace('-', "_"),
+            args.log_level
         )
+        .into()
+    });
+    if count_queries {
+        for directive in QUERY_COUNT_DIRECTIVES {
+            env_filter = env_filter.add_directive(directive.parse()?);
+        }
+    }
+    tracing_subscriber::registry()
+        .with(env_filter)
         .with(tracing_subscriber::fmt::layer())
+        .with(count_queries.then_some(QueryCountLayer))
         .init();
 
+    if args.db_query_count && !count_queries {
+        tracing::warn!("DB_QUERY_COUNT is ignored in release builds");
+    }
+
+    // Parse and validate runtime configuration once, before anything uses it
+    let config = AppConfig::from_env()?;
+
     // Database setup with custom URL
     let pool = database::create_pool_with_url(&args.database_url).await?;
 
     // Run migrations for production (embedded migrations)
     database::run_migrations(&pool).await?;
 
+    // Create the first admin from the environment if configured
+    if let Some(bootstrap) = admin::BootstrapAdmin::from_env() {
+        if let Err(e) = admin::ensure_bootstrap_admin(&pool, &bootstrap).await {
+            tracing::error!("Failed to bootstrap admin user: {}", e);
+        }
+    }
+
     // Ensure admin invite exists and print it if needed
     if let Err(e) = admin::ensure_admin_invite(&pool).await {
         tracing::error!("Failed to create admin invite: {}", e);
     }
 
     // Create application state
-    let mut app_state = AppState::new(pool.clone());
+    let mut app_state = AppState::new(pool.clone())
+        .with_config(config.clone())
+        .with_google_integration(args.google_integration_enabled);
 
-    // Start token refresh scheduler if Google Tasks is configured
-    if let Ok(google_config) = GoogleTasksConfig::from_env() {
-        tracing::info!("Starting Google OAuth token refresh scheduler");
-        let notifier = start_token_refresh_scheduler(pool.clone(), google_config);
-        app_state = app_state.with_token_notifier(notifier);
+    // Start token refresh scheduler if Google Tasks is enabled and configured
+    if !args.google_integration_enabled {
+        tracing::info!("Google integration disabled, skipping token refresh scheduler");
     } else {
-        tracing::info!("Google Tasks not configured, skipping token refresh scheduler");
+        match config.google_tasks() {
+            Ok(google_config) => {
+                tracing::info!("Starting Google OAuth token refresh scheduler");
+                let notifier = start_token_refresh_scheduler(
+                    pool.clone(),
+                    google_config.clone(),
+                    app_state.job_registry.clone(),
+                );
+                app_state = app_state.with_token_notifier(notifier);
+            }
+            Err(e) => {
+                tracing::info!("Google Tasks not configured, skipping token refresh scheduler: {}", e);
+            }
+        }
     }
 
+    start_tombstone_pruner(
+        pool.clone(),
+        config.tombstone_retention(),
+        app_state.job_registry.clone(),
+    );
+    start_entry_purger(pool.clone(), app_state.job_registry.clone());
+
     // Authentication setup
     let (session_layer, auth_layer) = auth::create_auth_layers(pool.clone());
 
@@ -110,26 +184,8 @@ async fn main() -> anyhow::Result<()> {
         // Development: Allow any origin
         CorsLayer::permissive()
     } else {
-        // Production: Restrict to specific origins
-        let allowed_origins = env::var("ALLOWED_ORIGINS")
-            .unwrap_or_else(|_| {
-                let host_ip = env::var("HOST_IP").unwrap_or_else(|_| "localhost".to_string());
-                format!("http://{}:3000,http://127.0.0.1:3000", host_ip)
-            })
-            .split(',')
-            .filter_map(|origin| origin.trim().parse().ok())
-            .collect::<Vec<_>>();
-        
-        CorsLayer::new()
-            .allow_origin(allowed_origins)
-            .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE])
-            .allow_headers([
-                header::CONTENT_TYPE,
-                header::AUTHORIZATION,
-                header::COOKIE,
-                header::SET_COOKIE,
-            ])
-            .allow_credentials(true)
+        // Production: Restrict to the allowlist, which may include wildcard subdomains
+        middleware::cors::cors_layer(config.allowed_origins.clone())
     };
 
     // Get the frontend dist directory path
@@ -144,6 +200,12 @@ async fn main() -> anyhow::Result<()> {
         );
     }
 
+    let google_tasks_router = if args.google_integration_enabled {
+        google_tasks::routes()
+    } else {
+        google_tasks::disabled_routes()
+    };
+
     // Build API router
     let api_router = Router::new()
         .route("/health", get(health_check))
@@ -151,36 +213,42 @@ async fn main() -> anyhow::Result<()> {
         .nest("/admin", admin_handlers::routes())
         .nest("/invites", invites::routes())
         .nest("/plants", plants::routes())
+        .nest("/public", public_links::public_routes())
         .nest("/calendar", calendar::routes())
-        .nest("/google-tasks", google_tasks::routes())
+        .nest("/dashboard", dashboard::routes())
+        .nest("/sync", sync::routes())
+        .nest("/google-tasks", google_tasks_router)
+        .nest("/integrations", integrations::routes())
+        .nest("/features", features::routes())
+        .nest("/settings", settings::routes())
         .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()))
         .route("/openapi.json", get(|| async { Json(ApiDoc::openapi()) }))
+        .route("/openapi-public.json", get(|| async { Json(ApiDoc::public()) }))
         .with_state(app_state);
 
+    // `/plants/` reaches the same handler as `/plants`. Only API paths are
+    // trimmed, so static files and the SPA fallback see requests unchanged.
+    let api_router = NormalizePathLayer::trim_trailing_slash().layer(api_router);
+
     // Build main application router
     let app = if serve_frontend {
-        let frontend_dir_clone = args.frontend_dir.clone();
         Router::new()
-            .nest("/api/v1", api_router)
+            .nest_service("/api/v1", api_router)
             .route("/api/health", get(health_check))
             // Handle unknown API routes with 404
             .route("/api/*path", get(api_not_found))
-            .fallback_service(
-                ServeDir::new(&args.frontend_dir)
-                    .append_index_html_on_directories(true)
-                    .fallback(get(move || serve_spa_fallback(frontend_dir_clone))),
-            )
+            .merge(frontend::routes(
+                Path::new(&args.frontend_dir),
+                Duration::from_secs(args.index_cache_ttl_secs),
+            ))
     } else {
         Router::new()
             .route("/", get(health_check))
-            .nest("/v1", api_router)
+            .nest_service("/v1", api_router)
     };
 
-    // Configure file upload limit from environment
-    let max_file_size = env::var("MAX_FILE_SIZE")
-        .unwrap_or_else(|_| "10485760".to_string()) // 10MB default
-        .parse::<usize>()
-        .unwrap_or(10 * 1024 * 1024);
+    // Configure file upload limit
+    let max_file_size = config.max_file_size;
     
     tracing::info!("Max file upload size: {} bytes ({:.1} MB)", max_file_size, max_file_size as f64 / 1024.0 / 1024.0);
 
@@ -194,6 +262,14 @@ async fn main() -> anyhow::Result<()> {
             .layer(session_layer),
     );
 
+    // Outermost, so session loading and saving are counted too
+    let app = if count_queries {
+        tracing::info!("Counting database queries per request");
+        app.layer(from_fn(count_queries_per_request))
+    } else {
+        app
+    };
+
     // Start server
     let addr = format!("0.0.0.0:{}", args.port);
 
@@ -224,16 +300,3 @@ async fn api_not_found() -> (StatusCode, Json<Value>) {
         }))
     )
 }
-
-// SPA fallback handler - serves index.html for unmatched routes
-async fn serve_spa_fallback(frontend_dir: String) -> Result<Html<String>, StatusCode> {
-    let index_path = format!("{frontend_dir}/index.html");
-
-    tokio::fs::read_to_string(&index_path).await.map_or_else(
-        |_| {
-            tracing::error!("Failed to read index.html from: {}", index_path);
-            Err(StatusCode::NOT_FOUND)
-        },
-        |content| Ok(Html(content)),
-    )
-}
@@ -0,0 +1,105 @@
+use axum::http::{header, HeaderValue, Method};
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+/// CORS for release builds: only origins on the allowlist get the CORS
+/// headers, and cookies are allowed so the session works cross-origin
+pub fn cors_layer(allowed_origins: Vec<String>) -> CorsLayer {
+    CorsLayer::new()
+        .allow_origin(AllowOrigin::predicate(move |origin: &HeaderValue, _| {
+            origin
+                .to_str()
+                .is_ok_and(|origin| origin_allowed(&allowed_origins, origin))
+        }))
+        .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE])
+        .allow_headers([
+            header::CONTENT_TYPE,
+            header::AUTHORIZATION,
+            header::COOKIE,
+            header::SET_COOKIE,
+        ])
+        .allow_credentials(true)
+}
+
+/// Whether `origin` is on the allowlist. Entries are exact origins, or
+/// `scheme://*.domain` to allow any subdomain of `domain` (not `domain` itself)
+pub fn origin_allowed(allowed_origins: &[String], origin: &str) -> bool {
+    allowed_origins
+        .iter()
+        .any(|allowed| match allowed.split_once("://*.") {
+            Some((scheme, domain)) => origin
+                .strip_prefix(scheme)
+                .and_then(|rest| rest.strip_prefix("://"))
+                .and_then(|host| host.strip_suffix(domain))
+                .and_then(|host| host.strip_suffix('.'))
+                .is_some_and(|subdomain| {
+                    !subdomain.is_empty()
+                        && subdomain
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.')
+                }),
+            None => allowed.eq_ignore_ascii_case(origin),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use tower::ServiceExt;
+
+    async fn allow_origin_header(layer: CorsLayer, origin: &str) -> Option<HeaderValue> {
+        let app = Router::new()
+            .route("/", get(|| async { "ok" }))
+            .layer(layer);
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/")
+                    .header(header::ORIGIN, origin)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        if response
+            .headers()
+            .contains_key(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+        {
+            assert_eq!(
+                response.headers()[header::ACCESS_CONTROL_ALLOW_CREDENTIALS],
+                "true"
+            );
+        }
+        response
+            .headers()
+            .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+            .cloned()
+    }
+
+    #[tokio::test]
+    async fn test_wildcard_subdomain_origins() {
+        let allowed = vec![
+            "https://*.example.com".to_string(),
+            "https://plants.example.org".to_string(),
+        ];
+
+        assert_eq!(
+            allow_origin_header(cors_layer(allowed.clone()), "https://pr-123.example.com").await,
+            Some(HeaderValue::from_static("https://pr-123.example.com"))
+        );
+        assert_eq!(
+            allow_origin_header(cors_layer(allowed.clone()), "https://evil.com").await,
+            None
+        );
+
+        assert!(origin_allowed(&allowed, "https://plants.example.org"));
+        assert!(origin_allowed(&allowed, "https://a.b.example.com"));
+        assert!(!origin_allowed(&allowed, "https://example.com"));
+        assert!(!origin_allowed(&allowed, "http://pr-123.example.com"));
+        assert!(!origin_allowed(&allowed, "https://evilexample.com"));
+        assert!(!origin_allowed(&allowed, "https://evil.com/.example.com"));
+        assert!(!origin_allowed(&allowed, "https://pr-123.example.com:8443"));
+        assert!(!origin_allowed(&allowed, "https://other.example.org"));
+    }
+}
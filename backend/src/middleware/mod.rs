@@ -1,2 +1,6 @@
+pub mod cors;
 pub mod logging;
+pub mod query_count;
+pub mod require_admin;
+pub mod require_user;
 pub mod validation;
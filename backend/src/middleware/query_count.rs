@@ -0,0 +1,87 @@
+//! Per-request SQL query counts, for catching N+1 queries during development
+//!
+//! sqlx logs every statement it runs as a `sqlx::query` event, from inside the
+//! span that was current when the query was issued. [`count_queries`] runs each
+//! request in a span holding a counter and [`QueryCountLayer`] bumps the
+//! counter of the nearest such span for each of those events.
+//!
+//! A request that has the pool open a new connection also counts the
+//! statements sqlx runs to set that connection up.
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use axum::{extract::Request, http::HeaderValue, middleware::Next, response::Response};
+use tracing::{Event, Instrument, Subscriber};
+use tracing_subscriber::{
+    layer::Context,
+    registry::{LookupSpan, Registry},
+    Layer,
+};
+
+/// Response header carrying the number of queries a request ran
+pub const QUERY_COUNT_HEADER: &str = "x-db-query-count";
+
+/// Log directives that must be enabled for queries and requests to be seen
+pub const QUERY_COUNT_DIRECTIVES: [&str; 2] = [
+    "sqlx::query=debug",
+    "planty_api::middleware::query_count=debug",
+];
+
+const SQLX_QUERY_TARGET: &str = "sqlx::query";
+
+/// Queries run so far in a request, stored in its span's extensions
+struct QueryCounter(Arc<AtomicUsize>);
+
+/// Counts `sqlx::query` events towards the request they were issued from
+pub struct QueryCountLayer;
+
+impl<S> Layer<S> for QueryCountLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        if event.metadata().target() != SQLX_QUERY_TARGET {
+            return;
+        }
+        let Some(scope) = ctx.event_scope(event) else {
+            return;
+        };
+        for span in scope {
+            if let Some(counter) = span.extensions().get::<QueryCounter>() {
+                counter.0.fetch_add(1, Ordering::Relaxed);
+                return;
+            }
+        }
+    }
+}
+
+/// Adds `X-DB-Query-Count` to each response
+///
+/// Only add this when [`QueryCountLayer`] is installed, with
+/// [`QUERY_COUNT_DIRECTIVES`] enabled, in the global tracing subscriber.
+pub async fn count_queries(request: Request, next: Next) -> Response {
+    let span = tracing::debug_span!("db_query_count");
+    let counter = Arc::new(AtomicUsize::new(0));
+    let attached = span
+        .with_subscriber(|(id, dispatch)| {
+            let span = dispatch.downcast_ref::<Registry>()?.span(id)?;
+            span.extensions_mut()
+                .insert(QueryCounter(Arc::clone(&counter)));
+            Some(())
+        })
+        .flatten()
+        .is_some();
+
+    let mut response = next.run(request).instrument(span).await;
+
+    if attached {
+        response.headers_mut().insert(
+            QUERY_COUNT_HEADER,
+            HeaderValue::from(counter.load(Ordering::Relaxed)),
+        );
+    }
+    response
+}
@@ -0,0 +1,56 @@
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, Request},
+    http::request::Parts,
+    middleware::Next,
+    response::Response,
+};
+
+use crate::auth::SessionUser;
+use crate::models::User;
+use crate::utils::errors::{AppError, Result};
+
+/// The authenticated admin user, injected by [`require_admin`].
+#[derive(Debug, Clone)]
+pub struct AdminUser(pub User);
+
+impl AdminUser {
+    async fn from_session(parts: &mut Parts) -> Result<Self> {
+        let SessionUser(user) = SessionUser::from_request_parts(parts, &()).await?;
+
+        if !user.is_admin() {
+            return Err(AppError::Authorization {
+                message: "Admin access required".to_string(),
+            });
+        }
+
+        Ok(Self(user))
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for AdminUser
+where
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self> {
+        // Reuse the user resolved by the middleware when the route is layered
+        if let Some(admin) = parts.extensions.get::<Self>() {
+            return Ok(admin.clone());
+        }
+
+        Self::from_session(parts).await
+    }
+}
+
+/// Rejects the request with 401/403 before it reaches the handler unless the
+/// session belongs to an admin. API keys never grant admin access.
+pub async fn require_admin(request: Request, next: Next) -> Result<Response> {
+    let (mut parts, body) = request.into_parts();
+    let admin = AdminUser::from_session(&mut parts).await?;
+    parts.extensions.insert(admin);
+
+    Ok(next.run(Request::from_parts(parts, body)).await)
+}
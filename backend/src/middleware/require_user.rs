@@ -0,0 +1,19 @@
+use axum::{
+    extract::{FromRequestParts, Request},
+    middleware::Next,
+    response::Response,
+};
+
+use crate::auth::CurrentUser;
+use crate::utils::errors::Result;
+
+/// Rejects the request with 401 before it reaches the handler unless the
+/// session has a user, so every protected route answers with the same body.
+/// The user is stored for [`CurrentUser`] to pick up.
+pub async fn require_user(request: Request, next: Next) -> Result<Response> {
+    let (mut parts, body) = request.into_parts();
+    let user = CurrentUser::from_request_parts(&mut parts, &()).await?;
+    parts.extensions.insert(user);
+
+    Ok(next.run(Request::from_parts(parts, body)).await)
+}
@@ -1,6 +1,7 @@
 use axum::{
     async_trait,
     extract::{FromRequest, Request},
+    http::HeaderMap,
     Json,
 };
 use serde::de::DeserializeOwned;
@@ -8,9 +9,58 @@ use validator::Validate;
 
 use crate::utils::errors::{AppError, Result};
 
+/// `Prefer` header value (RFC 7240) asking for unknown fields to be rejected
+const STRICT_PREFERENCE: &str = "handling=strict";
+
+/// A JSON body that passed validation
+///
+/// Fields the request type doesn't know are ignored, unless the client sends
+/// `Prefer: handling=strict`, in which case they're rejected with a 400
+/// listing them, e.g. `wateringScedule`.
 #[derive(Debug)]
 pub struct ValidatedJson<T>(pub T);
 
+fn prefers_strict(headers: &HeaderMap) -> bool {
+    headers
+        .get_all("prefer")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|preference| preference.trim().eq_ignore_ascii_case(STRICT_PREFERENCE))
+}
+
+/// e.g. `wateringSchedule.intervalDay` or `customMetrics.0.nmae`
+fn field_path(path: &serde_ignored::Path) -> String {
+    let child = |parent: &serde_ignored::Path, field: String| match parent {
+        serde_ignored::Path::Root => field,
+        parent => format!("{}.{}", field_path(parent), field),
+    };
+    match path {
+        serde_ignored::Path::Root => String::new(),
+        serde_ignored::Path::Seq { parent, index } => child(parent, index.to_string()),
+        serde_ignored::Path::Map { parent, key } => child(parent, key.clone()),
+        // Options and newtypes don't show up in the JSON
+        serde_ignored::Path::Some { parent }
+        | serde_ignored::Path::NewtypeStruct { parent }
+        | serde_ignored::Path::NewtypeVariant { parent } => field_path(parent),
+    }
+}
+
+/// Deserialize `value`, failing on any field `T` would otherwise ignore
+fn from_value_strict<T: DeserializeOwned>(value: serde_json::Value) -> Result<T> {
+    let mut unknown = Vec::new();
+    let parsed = serde_ignored::deserialize(value, |path| unknown.push(field_path(&path)))
+        .map_err(|e| AppError::BadRequest {
+            message: format!("Invalid request body: {}", e),
+        })?;
+
+    if unknown.is_empty() {
+        Ok(parsed)
+    } else {
+        Err(AppError::UnknownFields { fields: unknown })
+    }
+}
+
 #[async_trait]
 impl<T, S> FromRequest<S> for ValidatedJson<T>
 where
@@ -20,7 +70,12 @@ where
     type Rejection = AppError;
 
     async fn from_request(req: Request, state: &S) -> Result<Self> {
-        let Json(value) = Json::<T>::from_request(req, state).await?;
+        let value = if prefers_strict(req.headers()) {
+            let Json(body) = Json::<serde_json::Value>::from_request(req, state).await?;
+            from_value_strict::<T>(body)?
+        } else {
+            Json::<T>::from_request(req, state).await?.0
+        };
         value.validate()?;
         Ok(Self(value))
     }
@@ -234,6 +289,40 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_validated_json_strict_rejects_unknown_fields() {
+        let body = serde_json::json!({
+            "email": "test@example.com",
+            "name": "John Doe",
+            "age": 25,
+            "nmae": "Typo",
+        })
+        .to_string();
+        let request = |prefer: Option<&str>| {
+            let mut builder = Request::builder()
+                .method(http::Method::POST)
+                .header(http::header::CONTENT_TYPE, "application/json");
+            if let Some(prefer) = prefer {
+                builder = builder.header("prefer", prefer);
+            }
+            builder.body(Body::from(body.clone())).unwrap()
+        };
+
+        // Lenient by default
+        let result = ValidatedJson::<TestRequest>::from_request(request(None), &()).await;
+        assert!(result.is_ok());
+
+        let result = ValidatedJson::<TestRequest>::from_request(
+            request(Some("return=minimal, handling=strict")),
+            &(),
+        )
+        .await;
+        match result.unwrap_err() {
+            AppError::UnknownFields { fields } => assert_eq!(fields, vec!["nmae"]),
+            error => panic!("Expected UnknownFields error, got: {:?}", error),
+        }
+    }
+
     #[test]
     fn test_validated_json_debug() {
         let test_data = TestRequest {
@@ -0,0 +1,14 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Collection-wide totals for the dashboard header
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DashboardSummary {
+    pub total_plants: i64,
+    /// Plants with care due today or overdue, in the user's time zone
+    pub needs_care_today: i64,
+    /// Waterings logged since the start of the user's week
+    pub waterings_this_week: i64,
+    pub total_photos: i64,
+}
@@ -0,0 +1,15 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Optional features active on this server, so one frontend build can adapt
+/// to how each deployment is configured
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureFlags {
+    /// Google Tasks and Calendar are turned on and have OAuth credentials
+    pub google_integration: bool,
+    /// New accounts can register with an invite code (admin setting)
+    pub registration_enabled: bool,
+    /// Visitors without an invite can join the waitlist
+    pub waitlist_enabled: bool,
+}
@@ -15,6 +15,13 @@ pub struct GoogleOAuthToken {
     pub updated_at: DateTime<Utc>,
 }
 
+impl GoogleOAuthToken {
+    /// Whether the token still works, either as is or after a refresh
+    pub fn is_usable(&self) -> bool {
+        self.refresh_token.is_some() || self.expires_at.is_none_or(|at| at > Utc::now())
+    }
+}
+
 /// Request payload for OAuth callback
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct GoogleOAuthCallbackRequest {
@@ -45,10 +52,27 @@ pub struct GoogleOAuthSuccessResponse {
 /// Google Tasks connection status
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct GoogleTasksStatus {
+    /// Whether the Google integration is enabled on this server
+    pub enabled: bool,
     pub connected: bool,
     pub connected_at: Option<DateTime<Utc>>,
     pub scopes: Option<Vec<String>>,
     pub expires_at: Option<DateTime<Utc>>,
+    /// Google account the connection belongs to, when known
+    pub account_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Result of a manual access token refresh
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TokenRefreshResponse {
+    /// When the (possibly refreshed) access token expires
+    pub expires_at: Option<DateTime<Utc>>,
+    /// The refresh token is missing or was rejected; the user must reconnect
+    pub needs_reauth: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
 }
 
 /// Google Tasks task creation request
@@ -73,4 +97,22 @@ pub struct SyncPlantTasksRequest {
     /// Whether to replace existing tasks or only add new ones
     #[schema(example = false)]
     pub replace_existing: Option<bool>,
+    /// Task list to create the tasks in, from `GET /google-tasks/lists`.
+    /// The "Plant Care" list is found or created when omitted.
+    pub task_list_id: Option<String>,
+}
+
+/// One of the user's Google Tasks lists
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct GoogleTaskList {
+    #[schema(example = "MDEyMzQ1Njc4OTAxMjM0NTY3ODk6MDow")]
+    pub id: String,
+    #[schema(example = "Plant Care")]
+    pub title: String,
+}
+
+/// The task lists tasks can be synced into
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GoogleTaskListsResponse {
+    pub lists: Vec<GoogleTaskList>,
 }
\ No newline at end of file
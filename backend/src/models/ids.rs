@@ -0,0 +1,172 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::sqlite::{SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
+use sqlx::{Decode, Encode, Sqlite, Type};
+use uuid::Uuid;
+
+/// A `Uuid` newtype for one kind of entity's ids
+///
+/// Serializes exactly like the bare `Uuid`, so API payloads don't change, and
+/// is stored as the same hyphenated text the id columns already hold. Being
+/// distinct types, ids of different entities can't be passed in each other's
+/// place.
+macro_rules! entity_id {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(pub Uuid);
+
+        impl From<Uuid> for $name {
+            fn from(id: Uuid) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<$name> for Uuid {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = uuid::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Uuid::parse_str(s).map(Self)
+            }
+        }
+
+        impl Type<Sqlite> for $name {
+            fn type_info() -> SqliteTypeInfo {
+                <String as Type<Sqlite>>::type_info()
+            }
+
+            fn compatible(ty: &SqliteTypeInfo) -> bool {
+                <String as Type<Sqlite>>::compatible(ty)
+            }
+        }
+
+        impl<'q> Encode<'q, Sqlite> for $name {
+            fn encode_by_ref(&self, buf: &mut Vec<SqliteArgumentValue<'q>>) -> IsNull {
+                <String as Encode<'q, Sqlite>>::encode(self.0.to_string(), buf)
+            }
+        }
+
+        impl<'r> Decode<'r, Sqlite> for $name {
+            fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+                let text = <&str as Decode<'r, Sqlite>>::decode(value)?;
+                Ok(Self(Uuid::parse_str(text)?))
+            }
+        }
+    };
+}
+
+entity_id!(
+    /// Id of a plant
+    PlantId
+);
+
+entity_id!(
+    /// Id of a plant photo
+    PhotoId
+);
+
+entity_id!(
+    /// Id of a tracking entry
+    EntryId
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::SqlitePool;
+
+    #[test]
+    fn test_ids_serialize_like_uuids() {
+        let uuid = Uuid::new_v4();
+
+        assert_eq!(
+            serde_json::to_value(PlantId(uuid)).unwrap(),
+            serde_json::to_value(uuid).unwrap()
+        );
+        assert_eq!(
+            serde_json::to_string(&PhotoId(uuid)).unwrap(),
+            format!("\"{uuid}\"")
+        );
+        assert_eq!(
+            serde_json::to_string(&Some(EntryId(uuid))).unwrap(),
+            serde_json::to_string(&Some(uuid)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_ids_deserialize_like_uuids() {
+        let uuid = Uuid::new_v4();
+        let json = serde_json::to_string(&uuid).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<PlantId>(&json).unwrap(),
+            PlantId(uuid)
+        );
+        assert_eq!(
+            serde_json::from_str::<PhotoId>(&json).unwrap(),
+            PhotoId(uuid)
+        );
+        assert_eq!(
+            serde_json::from_str::<EntryId>(&json).unwrap(),
+            EntryId(uuid)
+        );
+
+        // Uuid's other accepted spellings keep working
+        let simple = format!("\"{}\"", uuid.simple());
+        assert_eq!(
+            serde_json::from_str::<PlantId>(&simple).unwrap(),
+            PlantId(uuid)
+        );
+
+        assert!(serde_json::from_str::<PlantId>("\"not-a-uuid\"").is_err());
+        assert!(serde_json::from_str::<PlantId>("42").is_err());
+    }
+
+    #[test]
+    fn test_ids_display_and_parse_like_uuids() {
+        let uuid = Uuid::new_v4();
+        let id = PlantId::from(uuid);
+
+        assert_eq!(id.to_string(), uuid.to_string());
+        assert_eq!(uuid.to_string().parse::<PlantId>().unwrap(), id);
+        assert_eq!(Uuid::from(id), uuid);
+        assert!("nope".parse::<PhotoId>().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_ids_are_stored_as_uuid_text() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let id = PlantId(Uuid::new_v4());
+
+        let stored: String = sqlx::query_scalar("SELECT ?")
+            .bind(id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(stored, id.to_string());
+
+        let loaded: PlantId = sqlx::query_scalar("SELECT ?")
+            .bind(id.to_string())
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(loaded, id);
+    }
+}
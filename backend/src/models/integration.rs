@@ -0,0 +1,28 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum IntegrationName {
+    GoogleTasks,
+    GoogleCalendar,
+    IcalFeed,
+}
+
+/// Availability of an integration for the current user
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationStatus {
+    pub name: IntegrationName,
+    /// Turned on for this deployment
+    pub enabled: bool,
+    /// Required server configuration (e.g. OAuth credentials) is present
+    pub configured: bool,
+    /// The user has connected it; for the iCalendar feed, a calendar app has fetched it this week
+    pub connected: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IntegrationsResponse {
+    pub integrations: Vec<IntegrationStatus>,
+}
@@ -6,6 +6,7 @@ use uuid::Uuid;
 use validator::Validate;
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct InviteCode {
     pub id: String,
     pub code: String,
@@ -34,6 +35,7 @@ pub struct InviteCodeRow {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct WaitlistEntry {
     pub id: String,
     pub email: String,
@@ -42,6 +44,8 @@ pub struct WaitlistEntry {
     pub status: String,
     pub invited_at: Option<DateTime<Utc>>,
     pub invite_code: Option<String>,
+    /// When an admin last emailed the entry while it was pending
+    pub notified_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -55,17 +59,20 @@ pub struct WaitlistEntryRow {
     pub status: String,
     pub invited_at: Option<String>,
     pub invite_code: Option<String>,
+    pub notified_at: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
 
 #[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
 pub struct CreateInviteRequest {
     pub max_uses: Option<i32>,
     pub expires_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
 pub struct WaitlistSignupRequest {
     #[validate(email)]
     pub email: String,
@@ -74,12 +81,14 @@ pub struct WaitlistSignupRequest {
 }
 
 #[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+#[serde(rename_all = "camelCase")]
 pub struct ValidateInviteRequest {
     #[validate(length(min = 1))]
     pub code: String,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct InviteResponse {
     pub id: String,
     pub code: String,
@@ -91,11 +100,13 @@ pub struct InviteResponse {
 }
 
 #[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct WaitlistResponse {
     pub id: String,
     pub email: String,
     pub name: Option<String>,
     pub status: String,
+    pub notified_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -178,6 +189,15 @@ impl WaitlistEntryRow {
                 None
             },
             invite_code: self.invite_code,
+            notified_at: if let Some(notified_str) = self.notified_at {
+                Some(notified_str.parse::<DateTime<Utc>>().map_err(|_| {
+                    crate::utils::errors::AppError::Internal {
+                        message: "Invalid datetime in database".to_string(),
+                    }
+                })?)
+            } else {
+                None
+            },
             created_at: self.created_at.parse::<DateTime<Utc>>().map_err(|_| {
                 crate::utils::errors::AppError::Internal {
                     message: "Invalid datetime in database".to_string(),
@@ -199,6 +219,7 @@ impl From<WaitlistEntry> for WaitlistResponse {
             email: entry.email,
             name: entry.name,
             status: entry.status,
+            notified_at: entry.notified_at,
             created_at: entry.created_at,
         }
     }
@@ -1,10 +1,22 @@
+pub mod api_key;
+pub mod dashboard;
+pub mod features;
 pub mod google_oauth;
+pub mod ids;
+pub mod integration;
 pub mod invite;
 pub mod photo;
 pub mod plant;
+pub mod public_link;
+pub mod report;
+pub mod sync;
+pub mod timeline;
 pub mod tracking_entry;
 pub mod user;
+pub mod watering;
+pub mod webhook;
 
+pub use ids::{EntryId, PhotoId, PlantId};
 pub use invite::{
     CreateInviteRequest, InviteCode, InviteCodeRow, InviteResponse, ValidateInviteRequest,
     WaitlistEntry, WaitlistEntryRow, WaitlistResponse, WaitlistSignupRequest,
@@ -22,6 +22,7 @@ pub struct Photo {
     pub content_type: String,
     pub width: Option<i32>,
     pub height: Option<i32>,
+    pub caption: Option<String>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -42,6 +43,13 @@ impl Photo {
 pub struct PhotosResponse {
     pub photos: Vec<Photo>,
     pub total: i64,
+    /// Page size and offset that were applied, after defaults
+    pub limit: i64,
+    pub offset: i64,
+    /// Relative URL of the next page, `null` on the last page
+    pub next: Option<String>,
+    /// Relative URL of the previous page, `null` on the first page
+    pub prev: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Validate)]
@@ -54,8 +62,19 @@ pub struct UploadPhotoRequest {
     #[validate(regex(path = "*CONTENT_TYPE_REGEX"))]
     pub content_type: String,
     pub data: Vec<u8>, // Raw image data
+    #[validate(length(max = 500))]
+    pub caption: Option<String>,
 }
 
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePhotoRequest {
+    /// New caption; `null` or an empty string clears it
+    #[validate(length(max = 500))]
+    pub caption: Option<String>,
+}
+
+#[allow(dead_code)]
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ProcessedImageInfo {
@@ -1,9 +1,14 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use utoipa::ToSchema;
 use uuid::Uuid;
-use validator::Validate;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::config::MAX_REMINDER_LEAD_HOURS;
+use crate::models::user::{CareDay, CareTiming};
+use crate::utils::nullable::deserialize_nullable;
+use crate::utils::text::validate_display_text;
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -14,14 +19,80 @@ pub struct CareSchedule {
     pub notes: Option<String>,
 }
 
+/// Units accepted for care schedule amounts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CareUnit {
+    Ml,
+    L,
+    Oz,
+    Cup,
+    Tsp,
+    Tbsp,
+}
+
+impl CareUnit {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CareUnit::Ml => "ml",
+            CareUnit::L => "l",
+            CareUnit::Oz => "oz",
+            CareUnit::Cup => "cup",
+            CareUnit::Tsp => "tsp",
+            CareUnit::Tbsp => "tbsp",
+        }
+    }
+
+    /// Canonical form of a unit as it should be stored; blank units become `None`
+    ///
+    /// Unknown units are returned unchanged, since validation has already
+    /// rejected them on the way in.
+    pub fn normalize(unit: Option<&str>) -> Option<String> {
+        let unit = unit.map(str::trim).filter(|u| !u.is_empty())?;
+        Some(
+            unit.parse::<CareUnit>()
+                .map_or_else(|_| unit.to_string(), |u| u.as_str().to_string()),
+        )
+    }
+}
+
+impl std::str::FromStr for CareUnit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().trim_end_matches('.') {
+            "ml" | "milliliter" | "milliliters" | "millilitre" | "millilitres" => Ok(CareUnit::Ml),
+            "l" | "liter" | "liters" | "litre" | "litres" => Ok(CareUnit::L),
+            "oz" | "fl oz" | "floz" | "ounce" | "ounces" | "fluid ounce" | "fluid ounces" => {
+                Ok(CareUnit::Oz)
+            }
+            "cup" | "cups" => Ok(CareUnit::Cup),
+            "tsp" | "teaspoon" | "teaspoons" => Ok(CareUnit::Tsp),
+            "tbsp" | "tablespoon" | "tablespoons" => Ok(CareUnit::Tbsp),
+            _ => Err(format!("Invalid care unit: {}", s)),
+        }
+    }
+}
+
+/// Rejects care schedule units that don't map to a [`CareUnit`]; blank is allowed.
+fn validate_care_unit(value: &str) -> Result<(), ValidationError> {
+    if value.trim().is_empty() || value.parse::<CareUnit>().is_ok() {
+        return Ok(());
+    }
+
+    let mut error = ValidationError::new("unknown_unit");
+    error.message = Some("Unit must be one of ml, l, oz, cup, tsp or tbsp".into());
+    Err(error)
+}
+
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_create_amount_unit"))]
 pub struct CreateCareScheduleRequest {
     #[validate(range(min = 1, max = 365))]
     pub interval_days: Option<i32>,
     #[validate(range(min = 0.01))]
     pub amount: Option<f64>,
-    #[validate(length(max = 20))]
+    #[validate(length(max = 20), custom(function = "validate_care_unit"))]
     pub unit: Option<String>,
     #[validate(length(max = 500))]
     pub notes: Option<String>,
@@ -29,13 +100,149 @@ pub struct CreateCareScheduleRequest {
 
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_update_amount_unit"))]
 pub struct UpdateCareScheduleRequest {
     pub interval_days: Option<i32>,
     pub amount: Option<f64>,
+    #[validate(length(max = 20), custom(function = "validate_care_unit"))]
     pub unit: Option<String>,
     pub notes: Option<String>,
 }
 
+/// An amount means nothing without a unit and vice versa; a blank unit counts as none
+fn check_amount_unit(amount: Option<f64>, unit: Option<&str>) -> Result<(), ValidationError> {
+    let has_unit = unit.is_some_and(|unit| !unit.trim().is_empty());
+    if amount.is_some() != has_unit {
+        let mut error = ValidationError::new("amount_unit");
+        error.message = Some("amount and unit must be set together".into());
+        return Err(error);
+    }
+    Ok(())
+}
+
+fn validate_create_amount_unit(request: &CreateCareScheduleRequest) -> Result<(), ValidationError> {
+    check_amount_unit(request.amount, request.unit.as_deref())
+}
+
+fn validate_update_amount_unit(request: &UpdateCareScheduleRequest) -> Result<(), ValidationError> {
+    check_amount_unit(request.amount, request.unit.as_deref())
+}
+
+/// Care types with their own plant columns, which care tasks can't reuse
+const BUILT_IN_CARE_TYPES: [&str; 2] = ["watering", "fertilizing"];
+
+/// Care task types are 1-32 lowercase letters, digits, `-` or `_`, start with
+/// a letter, and aren't one of the built-in care types
+pub fn validate_task_type(value: &str) -> Result<(), ValidationError> {
+    let well_formed = (1..=32).contains(&value.len())
+        && value.starts_with(|c: char| c.is_ascii_lowercase())
+        && value
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+    if !well_formed {
+        let mut error = ValidationError::new("task_type");
+        error.message = Some(
+            "Task type must be 1-32 lowercase letters, digits, '-' or '_', starting with a letter"
+                .into(),
+        );
+        return Err(error);
+    }
+
+    if BUILT_IN_CARE_TYPES.contains(&value) {
+        let mut error = ValidationError::new("built_in_task_type");
+        error.message = Some("Watering and fertilizing are set on the plant itself".into());
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// A recurring care task beyond watering and fertilizing, such as misting
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CareTask {
+    /// Lowercase name such as `misting`, unique per plant
+    pub task_type: String,
+    pub schedule: CareSchedule,
+    pub last_done: Option<DateTime<Utc>>,
+}
+
+impl CareTask {
+    /// The task type for display, e.g. `leaf_shine` becomes `Leaf shine`
+    pub fn label(&self) -> String {
+        let words = self.task_type.replace(['-', '_'], " ");
+        let mut chars = words.chars();
+        chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCareTaskRequest {
+    #[validate(custom(function = "validate_task_type"))]
+    pub task_type: String,
+    #[validate(nested)]
+    pub schedule: CreateCareScheduleRequest,
+    pub last_done: Option<DateTime<Utc>>,
+}
+
+/// Omitted fields are left unchanged
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCareTaskRequest {
+    /// Replaces the whole schedule
+    #[validate(nested)]
+    pub schedule: Option<CreateCareScheduleRequest>,
+    /// Omit to keep the current date, `null` to clear it
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    #[schema(value_type = Option<DateTime<Utc>>)]
+    pub last_done: Option<Option<DateTime<Utc>>>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CareTasksResponse {
+    pub care_tasks: Vec<CareTask>,
+}
+
+/// Tags are matched case-insensitively, so they're stored trimmed and lowercase
+pub fn normalize_tag(tag: &str) -> String {
+    tag.trim().to_lowercase()
+}
+
+fn validate_tags(tags: &[String]) -> Result<(), ValidationError> {
+    let well_formed = tags.iter().map(|tag| normalize_tag(tag)).all(|tag| {
+        (1..=32).contains(&tag.len())
+            && tag
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+    });
+    if !well_formed {
+        let mut error = ValidationError::new("tag");
+        error.message = Some("Tags must be 1-32 letters, digits, '-' or '_'".into());
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct AddTagsRequest {
+    /// Free-form labels such as `low-light`; stored lowercase, existing tags are ignored
+    #[validate(length(min = 1, max = 20))]
+    #[validate(custom(function = "validate_tags"))]
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PlantTagsResponse {
+    pub tags: Vec<String>,
+}
+
+#[allow(dead_code)]
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Plant {
@@ -80,18 +287,81 @@ pub enum MetricDataType {
     Boolean,
 }
 
+impl MetricDataType {
+    pub fn as_db_str(&self) -> &'static str {
+        match self {
+            Self::Number => "number",
+            Self::Text => "text",
+            Self::Boolean => "boolean",
+        }
+    }
+
+    /// A recorded value as this type, or `None` if it has no sensible equivalent.
+    /// Text like `"12.5"` or `"true"` converts back to numbers and booleans.
+    pub fn convert_value(&self, value: &serde_json::Value) -> Option<serde_json::Value> {
+        use serde_json::Value;
+
+        match (self, value) {
+            (_, Value::Null) => Some(Value::Null),
+            (Self::Text, Value::String(_)) => Some(value.clone()),
+            (Self::Text, Value::Number(n)) => Some(Value::String(n.to_string())),
+            (Self::Text, Value::Bool(b)) => Some(Value::String(b.to_string())),
+            (Self::Number, Value::Number(_)) => Some(value.clone()),
+            (Self::Number, Value::String(s)) => {
+                let s = s.trim();
+                match s.parse::<i64>() {
+                    Ok(n) => Some(Value::from(n)),
+                    Err(_) => s
+                        .parse::<f64>()
+                        .ok()
+                        .and_then(serde_json::Number::from_f64)
+                        .map(Value::Number),
+                }
+            }
+            (Self::Boolean, Value::Bool(_)) => Some(value.clone()),
+            (Self::Boolean, Value::String(s)) => match s.trim().to_ascii_lowercase().as_str() {
+                "true" => Some(Value::Bool(true)),
+                "false" => Some(Value::Bool(false)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 #[allow(dead_code)]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_create_coordinates"))]
 pub struct CreatePlantRequest {
-    #[validate(length(min = 1, max = 100))]
+    #[validate(length(min = 1, max = 100), custom(function = "validate_display_text"))]
     pub name: String,
-    #[validate(length(min = 1, max = 100))]
+    #[validate(length(min = 1, max = 100), custom(function = "validate_display_text"))]
     pub genus: String,
+    /// Free-form notes about the plant itself, separate from care notes
+    #[validate(length(max = 2000))]
+    pub description: Option<String>,
+    /// Date the plant was acquired; can't be in the future
+    #[validate(custom(function = "validate_acquired_at"))]
+    pub acquired_at: Option<NaiveDate>,
+    /// Room or place the plant lives in
+    #[validate(length(max = 100))]
+    pub location_name: Option<String>,
+    /// Set together with `longitude`
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub latitude: Option<f64>,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub longitude: Option<f64>,
     #[validate(nested)]
     pub watering_schedule: Option<CreateCareScheduleRequest>,
     #[validate(nested)]
     pub fertilizing_schedule: Option<CreateCareScheduleRequest>,
+    /// Hours before care is due that reminders fire, overriding the server default
+    #[validate(range(max = MAX_REMINDER_LEAD_HOURS))]
+    pub reminder_lead_hours: Option<u32>,
+    /// Identical specimens tracked as this plant; defaults to 1
+    #[validate(range(min = 1))]
+    pub quantity: Option<u32>,
     pub custom_metrics: Option<Vec<CreateCustomMetricRequest>>,
     pub last_watered: Option<DateTime<Utc>>,
     pub last_fertilized: Option<DateTime<Utc>>,
@@ -109,7 +379,9 @@ impl CreatePlantRequest {
     }
 
     pub fn watering_unit(&self) -> Option<String> {
-        self.watering_schedule.as_ref().and_then(|s| s.unit.clone())
+        self.watering_schedule
+            .as_ref()
+            .and_then(|s| CareUnit::normalize(s.unit.as_deref()))
     }
 
     pub fn watering_notes(&self) -> Option<String> {
@@ -131,7 +403,7 @@ impl CreatePlantRequest {
     pub fn fertilizing_unit(&self) -> Option<String> {
         self.fertilizing_schedule
             .as_ref()
-            .and_then(|s| s.unit.clone())
+            .and_then(|s| CareUnit::normalize(s.unit.as_deref()))
     }
 
     pub fn fertilizing_notes(&self) -> Option<String> {
@@ -152,14 +424,91 @@ pub struct CreateCustomMetricRequest {
     pub data_type: MetricDataType,
 }
 
-#[derive(Debug, Deserialize, ToSchema)]
+/// Rejects a plant ending up with more than `max` custom metrics
+pub fn validate_custom_metric_count(count: usize, max: usize) -> Result<(), ValidationErrors> {
+    if count <= max {
+        return Ok(());
+    }
+
+    let mut error = ValidationError::new("too_many_metrics");
+    error.message = Some(format!("A plant can have at most {max} custom metrics").into());
+    let mut errors = ValidationErrors::new();
+    errors.add("customMetrics", error);
+    Err(errors)
+}
+
+/// Whether a plant is growing. Dead plants get no care reminders and only
+/// show up in the memorial view.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(rename_all = "lowercase")]
+pub enum PlantStatus {
+    #[default]
+    Active,
+    Dormant,
+    Dead,
+}
+
+impl PlantStatus {
+    pub fn as_db_str(&self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Dormant => "dormant",
+            Self::Dead => "dead",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 #[allow(dead_code)]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_update_coordinates"))]
 pub struct UpdatePlantRequest {
+    #[validate(length(min = 1, max = 100), custom(function = "validate_display_text"))]
     pub name: Option<String>,
+    #[validate(length(min = 1, max = 100), custom(function = "validate_display_text"))]
     pub genus: Option<String>,
+    /// Replaces the description; an empty string clears it
+    #[validate(length(max = 2000))]
+    pub description: Option<String>,
+    /// Omit to keep the current date, `null` to clear it
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    #[validate(custom(function = "validate_acquired_at"))]
+    #[schema(value_type = Option<NaiveDate>)]
+    pub acquired_at: Option<Option<NaiveDate>>,
+    /// Replaces the location name; an empty string clears it
+    #[validate(length(max = 100))]
+    pub location_name: Option<String>,
+    /// Set or clear (`null`) together with `longitude`
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    #[validate(range(min = -90.0, max = 90.0))]
+    #[schema(value_type = Option<f64>)]
+    pub latitude: Option<Option<f64>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    #[validate(range(min = -180.0, max = 180.0))]
+    #[schema(value_type = Option<f64>)]
+    pub longitude: Option<Option<f64>>,
+    #[validate(nested)]
     pub watering_schedule: Option<UpdateCareScheduleRequest>,
+    #[validate(nested)]
     pub fertilizing_schedule: Option<UpdateCareScheduleRequest>,
+    /// Omit to keep the current lead time, `null` to use the server default again
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    #[validate(range(max = MAX_REMINDER_LEAD_HOURS))]
+    #[schema(value_type = Option<u32>)]
+    pub reminder_lead_hours: Option<Option<u32>>,
+    #[validate(range(min = 1))]
+    pub quantity: Option<u32>,
+    /// Setting any status but `dead` clears `diedAt` and `deathReason`
+    pub status: Option<PlantStatus>,
+    /// When the plant died, only for dead plants; defaults to when it was marked dead
+    #[validate(custom(function = "validate_died_at"))]
+    pub died_at: Option<DateTime<Utc>>,
+    /// What the plant died of, only for dead plants; an empty string clears it
+    #[validate(length(max = 500))]
+    pub death_reason: Option<String>,
+    /// Metrics to add or change; metrics not listed are left as they are
+    #[validate(nested)]
     pub custom_metrics: Option<Vec<UpdateCustomMetricRequest>>,
 }
 
@@ -173,7 +522,9 @@ impl UpdatePlantRequest {
     }
 
     pub fn watering_unit(&self) -> Option<Option<String>> {
-        self.watering_schedule.as_ref().map(|s| s.unit.clone())
+        self.watering_schedule
+            .as_ref()
+            .map(|s| CareUnit::normalize(s.unit.as_deref()))
     }
 
     pub fn watering_notes(&self) -> Option<Option<String>> {
@@ -189,7 +540,9 @@ impl UpdatePlantRequest {
     }
 
     pub fn fertilizing_unit(&self) -> Option<Option<String>> {
-        self.fertilizing_schedule.as_ref().map(|s| s.unit.clone())
+        self.fertilizing_schedule
+            .as_ref()
+            .map(|s| CareUnit::normalize(s.unit.as_deref()))
     }
 
     pub fn fertilizing_notes(&self) -> Option<Option<String>> {
@@ -197,11 +550,33 @@ impl UpdatePlantRequest {
     }
 }
 
-#[derive(Debug, Deserialize, ToSchema)]
-#[allow(dead_code)]
+/// Set care intervals on several plants at once. Omitted intervals are left unchanged.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkUpdateScheduleRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub plant_ids: Vec<Uuid>,
+    #[validate(range(min = 1, max = 365))]
+    pub watering_interval_days: Option<i32>,
+    #[validate(range(min = 1, max = 365))]
+    pub fertilizing_interval_days: Option<i32>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkUpdateScheduleResponse {
+    pub updated: u64,
+}
+
+/// A metric to add (no `id`) or change. Changing `data_type` is refused while
+/// values are recorded for the metric unless they are converted.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UpdateCustomMetricRequest {
     pub id: Option<Uuid>,
+    #[validate(length(min = 1, max = 50))]
     pub name: String,
+    #[validate(length(max = 20))]
     pub unit: String,
     pub data_type: MetricDataType,
 }
@@ -212,13 +587,43 @@ pub struct PlantResponse {
     pub id: Uuid,
     pub name: String,
     pub genus: String,
+    pub description: Option<String>,
+    pub acquired_at: Option<NaiveDate>,
+    /// Days since `acquired_at`
+    pub age_days: Option<i64>,
+    pub location_name: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
     pub watering_schedule: CareSchedule,
     pub fertilizing_schedule: CareSchedule,
     pub last_watered: Option<DateTime<Utc>>,
     pub last_fertilized: Option<DateTime<Utc>>,
+    /// Hours before care is due that reminders fire, `null` for the server default
+    pub reminder_lead_hours: Option<u32>,
+    /// Identical specimens tracked as this plant, e.g. three pots of basil
+    pub quantity: u32,
+    /// When the plant was archived, `null` while it's active
+    pub archived_at: Option<DateTime<Utc>>,
+    pub status: PlantStatus,
+    /// When and why the plant died, `null` unless it's dead
+    pub died_at: Option<DateTime<Utc>>,
+    pub death_reason: Option<String>,
+    /// Most recent note, photo and measurement tracking entries
+    pub last_note_at: Option<DateTime<Utc>>,
+    pub last_photo_at: Option<DateTime<Utc>>,
+    pub last_measurement_at: Option<DateTime<Utc>>,
     pub preview_id: Option<Uuid>,
     pub preview_url: Option<String>,
+    /// Small variant of the preview photo, for grids
+    pub thumbnail_url: Option<String>,
+    pub photo_count: i64,
+    /// Most recently uploaded photo
+    pub latest_photo_id: Option<Uuid>,
     pub custom_metrics: Vec<CustomMetric>,
+    /// Care beyond watering and fertilizing
+    pub care_tasks: Vec<CareTask>,
+    /// Lowercase free-form tags, sorted
+    pub tags: Vec<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub user_id: String,
@@ -230,6 +635,783 @@ pub struct PlantsResponse {
     pub total: i64,
     pub limit: i64,
     pub offset: i64,
+    /// Relative URL of the next page, `null` on the last page
+    pub next: Option<String>,
+    /// Relative URL of the previous page, `null` on the first page
+    pub prev: Option<String>,
+}
+
+/// A page of plants returned by `GET /plants?pagination=cursor`
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PlantsCursorResponse {
+    pub plants: Vec<PlantResponse>,
+    pub limit: i64,
+    /// Pass as `cursor` to fetch the next page, `null` on the last page
+    pub next_cursor: Option<String>,
+    /// Relative URL of the next page, `null` on the last page
+    pub next: Option<String>,
+}
+
+/// Trimmed plant shape returned by `GET /plants?fields=summary`
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PlantSummary {
+    pub id: Uuid,
+    pub name: String,
+    pub genus: String,
+    pub preview_url: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub next_watering_due: Option<DateTime<Utc>>,
+    pub next_fertilizing_due: Option<DateTime<Utc>>,
+    pub watering_severity: Option<CareSeverity>,
+    pub fertilizing_severity: Option<CareSeverity>,
+    /// The plant's other care tasks, in the same shape
+    pub care_tasks: Vec<CareTaskDue>,
+}
+
+/// When one of a plant's care tasks is next due and how urgent it is
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CareTaskDue {
+    pub task_type: String,
+    pub next_due: Option<DateTime<Utc>>,
+    pub severity: Option<CareSeverity>,
+}
+
+impl PlantResponse {
+    /// The plant's reminder lead time, or `default_hours` when it has none
+    pub fn reminder_lead_hours_or(&self, default_hours: u32) -> u32 {
+        self.reminder_lead_hours.unwrap_or(default_hours)
+    }
+
+    /// Dead plants aren't cared for, so they have nothing due
+    pub fn is_dead(&self) -> bool {
+        self.status == PlantStatus::Dead
+    }
+}
+
+impl PlantSummary {
+    /// Summarize `plant` as of `now`, judging due dates by the user's `care_day`
+    ///
+    /// Care counts as due soon within the plant's reminder lead time, falling
+    /// back to `default_lead_hours`.
+    pub fn new(
+        plant: PlantResponse,
+        now: DateTime<Utc>,
+        care_day: CareDay,
+        default_lead_hours: u32,
+    ) -> Self {
+        let due_soon_days = due_soon_days(plant.reminder_lead_hours_or(default_lead_hours));
+        let next_watering_due = next_due(&plant.watering_schedule, plant.last_watered);
+        let next_fertilizing_due = next_due(&plant.fertilizing_schedule, plant.last_fertilized);
+
+        let care_tasks = plant
+            .care_tasks
+            .into_iter()
+            .map(|task| {
+                let next_due = next_due(&task.schedule, task.last_done);
+                CareTaskDue {
+                    severity: due_severity(&task.schedule, next_due, now, care_day, due_soon_days),
+                    next_due,
+                    task_type: task.task_type,
+                }
+            })
+            .collect();
+
+        Self {
+            care_tasks,
+            watering_severity: due_severity(
+                &plant.watering_schedule,
+                next_watering_due,
+                now,
+                care_day,
+                due_soon_days,
+            ),
+            fertilizing_severity: due_severity(
+                &plant.fertilizing_schedule,
+                next_fertilizing_due,
+                now,
+                care_day,
+                due_soon_days,
+            ),
+            next_watering_due,
+            next_fertilizing_due,
+            id: plant.id,
+            name: plant.name,
+            genus: plant.genus,
+            preview_url: plant.preview_url,
+            thumbnail_url: plant.thumbnail_url,
+        }
+    }
+
+    /// Whether any scheduled care is due on or before the user's current day
+    pub fn needs_care_today(&self, now: DateTime<Utc>, care_day: CareDay) -> bool {
+        [self.next_watering_due, self.next_fertilizing_due]
+            .into_iter()
+            .chain(self.care_tasks.iter().map(|task| task.next_due))
+            .flatten()
+            .any(|due| care_day.days_overdue(due, now) >= 0)
+    }
+}
+
+/// When care is next due; a plant that has never been cared for is due now,
+/// matching the calendar feed
+pub fn next_due(schedule: &CareSchedule, last_care: Option<DateTime<Utc>>) -> Option<DateTime<Utc>> {
+    next_due_at(schedule, last_care, Utc::now())
+}
+
+/// [`next_due`] as of `now`
+fn next_due_at(
+    schedule: &CareSchedule,
+    last_care: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let interval_days = schedule.interval_days.filter(|days| *days > 0)?;
+    Some(
+        last_care
+            .map(|last| last + chrono::Duration::days(interval_days.into()))
+            .unwrap_or(now),
+    )
+}
+
+/// Where one kind of scheduled care stands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CareStatus {
+    /// `null` without a schedule; care never given is due right away
+    pub next_due: Option<DateTime<Utc>>,
+    /// Whether `next_due` has arrived; never true without a schedule
+    pub overdue: bool,
+    pub severity: Option<CareSeverity>,
+}
+
+impl CareStatus {
+    /// Status as of `now`, judging severity by the user's `care_day`
+    pub fn new(
+        schedule: &CareSchedule,
+        last_care: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+        care_day: CareDay,
+        due_soon_days: i64,
+    ) -> Self {
+        let next_due = next_due_at(schedule, last_care, now);
+        Self {
+            next_due,
+            overdue: next_due.is_some_and(|due| due <= now),
+            severity: due_severity(schedule, next_due, now, care_day, due_soon_days),
+        }
+    }
+}
+
+/// Watering and fertilizing status for `GET /plants/{id}/status`
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PlantCareStatus {
+    pub plant_id: Uuid,
+    pub watering: CareStatus,
+    pub fertilizing: CareStatus,
+}
+
+impl PlantCareStatus {
+    /// Status of `plant` as of `now`, judging due dates by the user's `care_day`
+    ///
+    /// A dead plant has nothing due, as if it had no schedules.
+    pub fn new(
+        plant: &PlantResponse,
+        now: DateTime<Utc>,
+        care_day: CareDay,
+        default_lead_hours: u32,
+    ) -> Self {
+        if plant.is_dead() {
+            let unscheduled = CareStatus {
+                next_due: None,
+                overdue: false,
+                severity: None,
+            };
+            return Self {
+                plant_id: plant.id,
+                watering: unscheduled,
+                fertilizing: unscheduled,
+            };
+        }
+
+        let due_soon_days = due_soon_days(plant.reminder_lead_hours_or(default_lead_hours));
+        Self {
+            plant_id: plant.id,
+            watering: CareStatus::new(
+                &plant.watering_schedule,
+                plant.last_watered,
+                now,
+                care_day,
+                due_soon_days,
+            ),
+            fertilizing: CareStatus::new(
+                &plant.fertilizing_schedule,
+                plant.last_fertilized,
+                now,
+                care_day,
+                due_soon_days,
+            ),
+        }
+    }
+}
+
+/// A kind of scheduled care
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum CareKind {
+    Watering,
+    Fertilizing,
+}
+
+impl CareKind {
+    pub const ALL: [CareKind; 2] = [CareKind::Watering, CareKind::Fertilizing];
+
+    /// The plant's schedule for this kind of care and when it was last given
+    pub fn schedule_of(self, plant: &PlantResponse) -> (&CareSchedule, Option<DateTime<Utc>>) {
+        match self {
+            CareKind::Watering => (&plant.watering_schedule, plant.last_watered),
+            CareKind::Fertilizing => (&plant.fertilizing_schedule, plant.last_fertilized),
+        }
+    }
+}
+
+/// Scheduled care dates from `start` on, every `interval_days` after `last_care`
+///
+/// A plant that has never been cared for is due at `start`. Dates up to an hour
+/// before `start` are kept so care that is due right now isn't skipped. Yields
+/// nothing for a non-positive interval. Each date is adjusted by `timing`
+/// without drifting the schedule; dates that land on the same adjusted time
+/// are yielded once.
+pub fn care_occurrences(
+    last_care: Option<DateTime<Utc>>,
+    interval_days: i32,
+    start: DateTime<Utc>,
+    timing: CareTiming,
+) -> impl Iterator<Item = DateTime<Utc>> {
+    let interval = chrono::Duration::days(interval_days.into());
+    let mut first = last_care.unwrap_or(start - interval) + interval;
+
+    // Jump straight past missed dates rather than stepping through them
+    let threshold = start - chrono::Duration::hours(1);
+    if interval_days > 0 && first <= threshold {
+        let missed = (threshold - first).num_seconds() / interval.num_seconds() + 1;
+        first += interval * missed as i32;
+    }
+
+    let mut previous = None;
+    std::iter::successors((interval_days > 0).then_some(first), move |date| {
+        Some(*date + interval)
+    })
+    .map(move |date| timing.apply(date))
+    .filter(move |date| previous.replace(*date) != Some(*date))
+}
+
+/// One upcoming care occurrence
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CareOccurrence {
+    pub date: DateTime<Utc>,
+    pub care_type: CareKind,
+}
+
+/// The next scheduled care for a plant, soonest first
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpcomingCareResponse {
+    pub plant_id: Uuid,
+    pub occurrences: Vec<CareOccurrence>,
+}
+
+/// The next `count` watering and fertilizing occurrences for `plant`, sorted by date
+///
+/// Care without an interval is left out.
+pub fn upcoming_care(
+    plant: &PlantResponse,
+    count: usize,
+    now: DateTime<Utc>,
+    timing: CareTiming,
+) -> Vec<CareOccurrence> {
+    if plant.is_dead() {
+        return Vec::new();
+    }
+
+    let mut occurrences: Vec<CareOccurrence> = CareKind::ALL
+        .into_iter()
+        .filter_map(|care_type| {
+            let (schedule, last_care) = care_type.schedule_of(plant);
+            let interval_days = schedule.interval_days?;
+            Some(
+                care_occurrences(last_care, interval_days, now, timing)
+                    .take(count)
+                    .map(move |date| CareOccurrence { date, care_type }),
+            )
+        })
+        .flatten()
+        .collect();
+
+    occurrences.sort_by_key(|occurrence| (occurrence.date, occurrence.care_type));
+    occurrences.truncate(count);
+    occurrences
+}
+
+/// Number of care events due on one day
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleLoadDay {
+    pub date: NaiveDate,
+    pub count: usize,
+}
+
+/// Care events per day across all of a user's plants, so heavy days stand out
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleLoadResponse {
+    pub days: Vec<ScheduleLoadDay>,
+}
+
+/// Care events due on each of the `days` UTC days starting with `now`'s, across
+/// `plants`, counting care tasks as well as watering and fertilizing
+///
+/// Every day in the window is listed, with a count of 0 when nothing is due.
+pub fn schedule_load(
+    plants: &[PlantResponse],
+    days: i64,
+    now: DateTime<Utc>,
+    timing: CareTiming,
+) -> Vec<ScheduleLoadDay> {
+    let today = now.date_naive();
+    let mut counts = vec![0; days.max(0) as usize];
+    let end = (today + chrono::Duration::days(days))
+        .and_time(chrono::NaiveTime::MIN)
+        .and_utc();
+
+    for plant in plants {
+        let built_in = CareKind::ALL.into_iter().map(|care_type| {
+            let (schedule, last_care) = care_type.schedule_of(plant);
+            (schedule.interval_days, last_care)
+        });
+        let care_tasks = plant
+            .care_tasks
+            .iter()
+            .map(|task| (task.schedule.interval_days, task.last_done));
+
+        for (interval_days, last_care) in built_in.chain(care_tasks) {
+            let Some(interval_days) = interval_days else {
+                continue;
+            };
+            for date in care_occurrences(last_care, interval_days, now, timing)
+                .take_while(|date| *date < end)
+            {
+                // Care due in the hour before `now` may fall on yesterday
+                let day = (date.date_naive() - today).num_days();
+                if let Some(count) = usize::try_from(day)
+                    .ok()
+                    .and_then(|day| counts.get_mut(day))
+                {
+                    *count += 1;
+                }
+            }
+        }
+    }
+
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(offset, count)| ScheduleLoadDay {
+            date: today + chrono::Duration::days(offset as i64),
+            count,
+        })
+        .collect()
+}
+
+/// Furthest `rebalance_schedule` moves a care anchor, in days
+pub const MAX_REBALANCE_SHIFT_DAYS: i64 = 2;
+
+/// A proposed move of one plant's last-care date to spread out care
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleShift {
+    pub plant_id: Uuid,
+    pub care_type: CareKind,
+    /// Days the schedule moves; negative brings care forward
+    pub offset_days: i64,
+    pub last_care: DateTime<Utc>,
+    pub proposed_last_care: DateTime<Utc>,
+}
+
+/// Proposed schedule shifts, and whether they were applied
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RebalanceScheduleResponse {
+    pub dry_run: bool,
+    pub shifts: Vec<ScheduleShift>,
+}
+
+/// Propose small shifts of last-care dates that flatten the per-day care load
+/// over the `days` UTC days starting with `now`'s
+///
+/// Greedy: schedules are placed one at a time, most frequent first, each at the
+/// offset of up to `MAX_REBALANCE_SHIFT_DAYS` (and under half its interval)
+/// whose events land on the least busy days, preferring to stay put. Care that
+/// has never been given is due now and has no anchor to move, and anchors are
+/// never moved into the future. Only watering and fertilizing are balanced;
+/// care tasks are left alone.
+pub fn rebalance_schedule(
+    plants: &[PlantResponse],
+    days: i64,
+    now: DateTime<Utc>,
+    timing: CareTiming,
+) -> Vec<ScheduleShift> {
+    let today = now.date_naive();
+    let mut counts = vec![0usize; days.max(0) as usize];
+    let end = (today + chrono::Duration::days(days))
+        .and_time(chrono::NaiveTime::MIN)
+        .and_utc();
+
+    let mut anchors: Vec<_> = plants
+        .iter()
+        .flat_map(|plant| {
+            CareKind::ALL.into_iter().filter_map(|care_type| {
+                let (schedule, last_care) = care_type.schedule_of(plant);
+                let interval_days = schedule.interval_days.filter(|days| *days > 0)?;
+                Some((interval_days, plant.id, care_type, last_care?))
+            })
+        })
+        .collect();
+    anchors.sort_by_key(|&(interval_days, plant_id, care_type, _)| {
+        (interval_days, plant_id, care_type)
+    });
+
+    let mut shifts = Vec::new();
+    for (interval_days, plant_id, care_type, last_care) in anchors {
+        let max_shift = MAX_REBALANCE_SHIFT_DAYS.min(i64::from((interval_days - 1) / 2));
+        let offsets = std::iter::once(0).chain((1..=max_shift).flat_map(|shift| [-shift, shift]));
+
+        // Compare offsets by the average load on the days their events land on,
+        // so pushing an event out of the window isn't mistaken for balancing
+        let mut best: Option<(i64, usize, Vec<usize>)> = None;
+        for offset in offsets {
+            let anchor = last_care + chrono::Duration::days(offset);
+            if anchor > now {
+                continue;
+            }
+
+            let event_days: Vec<usize> = care_occurrences(Some(anchor), interval_days, now, timing)
+                .take_while(|date| *date < end)
+                .filter_map(|date| usize::try_from((date.date_naive() - today).num_days()).ok())
+                .filter(|day| *day < counts.len())
+                .collect();
+            if event_days.is_empty() {
+                continue;
+            }
+
+            let load: usize = event_days.iter().map(|day| counts[*day]).sum();
+            let better = match &best {
+                None => true,
+                Some((_, best_load, best_days)) => {
+                    load * best_days.len() < best_load * event_days.len()
+                }
+            };
+            if better {
+                best = Some((offset, load, event_days));
+            }
+        }
+
+        let Some((offset, _, event_days)) = best else {
+            continue;
+        };
+        for day in event_days {
+            counts[day] += 1;
+        }
+        if offset != 0 {
+            shifts.push(ScheduleShift {
+                plant_id,
+                care_type,
+                offset_days: offset,
+                last_care,
+                proposed_last_care: last_care + chrono::Duration::days(offset),
+            });
+        }
+    }
+
+    shifts
+}
+
+/// How urgently a care task needs attention
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CareSeverity {
+    /// Due within the reminder lead time, and at least today or tomorrow, in
+    /// the user's time zone
+    DueSoon,
+    Overdue,
+    /// More than twice the interval has passed since the last care
+    Critical,
+}
+
+/// Days ahead of the due date care counts as due soon, for reminders fired
+/// `lead_hours` before it: today and tomorrow, or longer for longer lead times
+pub fn due_soon_days(lead_hours: u32) -> i64 {
+    i64::from(lead_hours.div_ceil(24).max(1))
+}
+
+/// Severity of a care task that is `days_overdue` days past due (negative
+/// when it isn't due yet), or `None` if it isn't due within `due_soon_days`
+pub fn care_severity(
+    interval_days: i32,
+    days_overdue: i64,
+    due_soon_days: i64,
+) -> Option<CareSeverity> {
+    match days_overdue {
+        days if days < -due_soon_days => None,
+        ..=0 => Some(CareSeverity::DueSoon),
+        days if days > i64::from(interval_days) => Some(CareSeverity::Critical),
+        _ => Some(CareSeverity::Overdue),
+    }
+}
+
+/// Severity of care `due` at the given instant, counted in calendar days so
+/// care due earlier today stays due today until the user's midnight
+fn due_severity(
+    schedule: &CareSchedule,
+    due: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+    care_day: CareDay,
+    due_soon_days: i64,
+) -> Option<CareSeverity> {
+    let interval_days = schedule.interval_days.filter(|days| *days > 0)?;
+    care_severity(interval_days, care_day.days_overdue(due?, now), due_soon_days)
+}
+
+/// Rejects acquisition dates in the future, allowing a day of slack for time zones
+fn validate_acquired_at(value: &NaiveDate) -> Result<(), ValidationError> {
+    if *value > Utc::now().date_naive() + chrono::Duration::days(1) {
+        return Err(ValidationError::new("in_future"));
+    }
+    Ok(())
+}
+
+fn validate_died_at(value: &DateTime<Utc>) -> Result<(), ValidationError> {
+    if *value > Utc::now() + chrono::Duration::days(1) {
+        return Err(ValidationError::new("in_future"));
+    }
+    Ok(())
+}
+
+/// Rejects `diedAt` or `deathReason` for a plant that ends up not dead
+pub fn validate_death_details(
+    status: PlantStatus,
+    request: &UpdatePlantRequest,
+) -> Result<(), ValidationErrors> {
+    if status == PlantStatus::Dead {
+        return Ok(());
+    }
+
+    let mut errors = ValidationErrors::new();
+    for (field, set) in [
+        ("diedAt", request.died_at.is_some()),
+        ("deathReason", request.death_reason.is_some()),
+    ] {
+        if set {
+            let mut error = ValidationError::new("not_dead");
+            error.message = Some("Only dead plants have death details".into());
+            errors.add(field, error);
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn coordinates_error() -> ValidationError {
+    let mut error = ValidationError::new("coordinates");
+    error.message = Some("latitude and longitude must be set together".into());
+    error
+}
+
+fn validate_create_coordinates(request: &CreatePlantRequest) -> Result<(), ValidationError> {
+    if request.latitude.is_some() != request.longitude.is_some() {
+        return Err(coordinates_error());
+    }
+    Ok(())
+}
+
+fn validate_update_coordinates(request: &UpdatePlantRequest) -> Result<(), ValidationError> {
+    let set = |value: Option<Option<f64>>| value.map(|value| value.is_some());
+    if set(request.latitude) != set(request.longitude) {
+        return Err(coordinates_error());
+    }
+    Ok(())
+}
+
+/// Kilometres per degree of latitude (and of longitude at the equator)
+const KM_PER_DEGREE: f64 = 111.32;
+
+/// Parsed `near=lat,long,radius_km` filter for listing plants
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NearFilter {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub radius_km: f64,
+}
+
+/// Filters and ordering for listing plants; the default lists all of them, newest first
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PlantListFilter<'a> {
+    /// Matched against name, genus and description
+    pub search: Option<&'a str>,
+    /// `date_asc`, `date_desc` (default), `name_asc` or `name_desc`
+    pub sort: Option<&'a str>,
+    pub near: Option<&'a NearFilter>,
+    /// Normalized tags a plant must all carry
+    pub tags: &'a [String],
+    /// List archived plants instead of active ones
+    pub archived: bool,
+    /// Only plants with this status; by default every plant but dead ones
+    pub status: Option<PlantStatus>,
+}
+
+/// Coordinate ranges enclosing a `NearFilter` circle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min_latitude: f64,
+    pub max_latitude: f64,
+    pub min_longitude: f64,
+    pub max_longitude: f64,
+}
+
+impl NearFilter {
+    /// The smallest latitude/longitude box containing the circle
+    ///
+    /// Longitude isn't restricted when the box would reach a pole or cross the
+    /// antimeridian, so those searches only narrow by latitude.
+    pub fn bounding_box(&self) -> BoundingBox {
+        let latitude_delta = self.radius_km / KM_PER_DEGREE;
+        let min_latitude = (self.latitude - latitude_delta).max(-90.0);
+        let max_latitude = (self.latitude + latitude_delta).min(90.0);
+
+        let widest = min_latitude.abs().max(max_latitude.abs());
+        let (min_longitude, max_longitude) = if widest >= 90.0 {
+            (-180.0, 180.0)
+        } else {
+            let longitude_delta = self.radius_km / (KM_PER_DEGREE * widest.to_radians().cos());
+            let (min, max) = (self.longitude - longitude_delta, self.longitude + longitude_delta);
+            if min < -180.0 || max > 180.0 {
+                (-180.0, 180.0)
+            } else {
+                (min, max)
+            }
+        };
+
+        BoundingBox {
+            min_latitude,
+            max_latitude,
+            min_longitude,
+            max_longitude,
+        }
+    }
+}
+
+impl std::str::FromStr for NearFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid near filter '{}', expected lat,long,radius_km", s);
+        let parts = s
+            .split(',')
+            .map(|part| part.trim().parse::<f64>().map_err(|_| invalid()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let [latitude, longitude, radius_km] = parts[..] else {
+            return Err(invalid());
+        };
+
+        if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+            return Err("near latitude must be within ±90 and longitude within ±180".to_string());
+        }
+        if !(radius_km > 0.0 && radius_km <= 20_000.0) {
+            return Err("near radius_km must be greater than 0 and at most 20000".to_string());
+        }
+
+        Ok(Self {
+            latitude,
+            longitude,
+            radius_km,
+        })
+    }
+}
+
+/// Whole days between acquiring a plant and `today`
+pub fn plant_age_days(acquired_at: NaiveDate, today: NaiveDate) -> i64 {
+    (today - acquired_at).num_days()
+}
+
+/// The first monthly anniversary of `acquired_at` on or after `today`, with
+/// the number of months it marks
+///
+/// Plants acquired on the 29th-31st celebrate on the last day of shorter months.
+pub fn next_anniversary(acquired_at: NaiveDate, today: NaiveDate) -> Option<(NaiveDate, u32)> {
+    let months_elapsed = (today.year() - acquired_at.year()) * 12 + today.month() as i32
+        - acquired_at.month() as i32;
+    // Start a month early since the day of month may not have been reached yet
+    let mut months = u32::try_from(months_elapsed - 1).unwrap_or(0).max(1);
+    loop {
+        let date = acquired_at.checked_add_months(Months::new(months))?;
+        if date >= today {
+            return Some((date, months));
+        }
+        months += 1;
+    }
+}
+
+/// A plant's upcoming monthly or yearly acquisition anniversary
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PlantAnniversary {
+    pub plant_id: Uuid,
+    pub name: String,
+    pub acquired_at: NaiveDate,
+    pub date: NaiveDate,
+    pub days_until: i64,
+    /// Months since acquisition on `date`
+    pub months: u32,
+    /// Whether `date` is a whole number of years after acquisition
+    pub yearly: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PlantAnniversariesResponse {
+    pub anniversaries: Vec<PlantAnniversary>,
+}
+
+/// Dead plants for `GET /plants/memorial`, most recently died first
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MemorialResponse {
+    pub plants: Vec<PlantResponse>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PlantSummariesResponse {
+    pub plants: Vec<PlantSummary>,
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+    /// Relative URL of the next page, `null` on the last page
+    pub next: Option<String>,
+    /// Relative URL of the previous page, `null` on the first page
+    pub prev: Option<String>,
+}
+
+/// Result of `POST /plants/seed-examples`
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SeedExamplesResponse {
+    /// False if examples were already added to this account
+    pub seeded: bool,
+    /// The example plants created by this request
+    pub plants: Vec<PlantResponse>,
 }
 
 #[cfg(test)]
@@ -237,11 +1419,370 @@ mod tests {
     use super::*;
     use validator::Validate;
 
+    #[test]
+    fn test_care_severity_for_weekly_interval() {
+        let cases = [
+            (-5, None),
+            (-2, None),
+            (-1, Some(CareSeverity::DueSoon)),
+            (0, Some(CareSeverity::DueSoon)),
+            (1, Some(CareSeverity::Overdue)),
+            (7, Some(CareSeverity::Overdue)),
+            (8, Some(CareSeverity::Critical)),
+            (30, Some(CareSeverity::Critical)),
+        ];
+
+        for (days_overdue, expected) in cases {
+            assert_eq!(
+                care_severity(7, days_overdue, 1),
+                expected,
+                "{days_overdue} days overdue"
+            );
+        }
+    }
+
+    #[test]
+    fn test_due_soon_window_follows_reminder_lead_time() {
+        // Short lead times still flag care due today or tomorrow
+        assert_eq!(due_soon_days(0), 1);
+        assert_eq!(due_soon_days(24), 1);
+        assert_eq!(due_soon_days(25), 2);
+
+        assert_eq!(care_severity(7, -2, due_soon_days(48)), Some(CareSeverity::DueSoon));
+        assert_eq!(care_severity(7, -3, due_soon_days(48)), None);
+    }
+
+    #[test]
+    fn test_care_due_earlier_today_is_not_overdue() {
+        let at = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+        let weekly = CareSchedule {
+            interval_days: Some(7),
+            amount: None,
+            unit: None,
+            notes: None,
+        };
+        let due = Some(at("2024-03-10T08:00:00Z"));
+        let severity = |now: &str, utc_offset_minutes| {
+            due_severity(&weekly, due, at(now), CareDay { utc_offset_minutes }, 1)
+        };
+
+        // Twelve hours past the due time, but still the same day
+        assert_eq!(severity("2024-03-10T20:00:00Z", 0), Some(CareSeverity::DueSoon));
+        assert_eq!(severity("2024-03-10T23:59:59Z", 0), Some(CareSeverity::DueSoon));
+        assert_eq!(severity("2024-03-11T00:00:00Z", 0), Some(CareSeverity::Overdue));
+
+        // Five hours behind UTC it's still the 10th until 05:00 UTC on the 11th
+        assert_eq!(severity("2024-03-11T04:00:00Z", -300), Some(CareSeverity::DueSoon));
+        assert_eq!(severity("2024-03-11T05:00:00Z", -300), Some(CareSeverity::Overdue));
+
+        // Due tomorrow, even if that's less than a day away
+        assert_eq!(severity("2024-03-09T09:00:00Z", 0), Some(CareSeverity::DueSoon));
+        assert_eq!(severity("2024-03-08T09:00:00Z", 0), None);
+    }
+
+    #[test]
+    fn test_care_status_next_due_and_overdue() {
+        let at = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+        let schedule = |interval_days| CareSchedule {
+            interval_days,
+            amount: None,
+            unit: None,
+            notes: None,
+        };
+        let now = at("2024-03-10T12:00:00Z");
+        let status = |interval_days, last: Option<&str>| {
+            CareStatus::new(&schedule(interval_days), last.map(at), now, CareDay::default(), 1)
+        };
+
+        let overdue = status(Some(7), Some("2024-03-01T12:00:00Z"));
+        assert_eq!(overdue.next_due, Some(at("2024-03-08T12:00:00Z")));
+        assert!(overdue.overdue);
+        assert_eq!(overdue.severity, Some(CareSeverity::Overdue));
+
+        let due_soon = status(Some(7), Some("2024-03-04T08:00:00Z"));
+        assert_eq!(due_soon.next_due, Some(at("2024-03-11T08:00:00Z")));
+        assert!(!due_soon.overdue);
+        assert_eq!(due_soon.severity, Some(CareSeverity::DueSoon));
+
+        let never_cared_for = status(Some(7), None);
+        assert_eq!(never_cared_for.next_due, Some(now));
+        assert!(never_cared_for.overdue);
+
+        for interval_days in [None, Some(0)] {
+            let unscheduled = status(interval_days, Some("2023-01-01T00:00:00Z"));
+            assert_eq!(unscheduled.next_due, None);
+            assert!(!unscheduled.overdue);
+            assert_eq!(unscheduled.severity, None);
+        }
+    }
+
+    #[test]
+    fn test_near_filter_parse_and_bounding_box() {
+        let near: NearFilter = "55.68, 12.57, 10".parse().unwrap();
+        let bounds = near.bounding_box();
+        let latitude_span = bounds.max_latitude - bounds.min_latitude;
+        assert!((latitude_span - 2.0 * 10.0 / KM_PER_DEGREE).abs() < 1e-9);
+        // Longitude degrees are shorter away from the equator, so the box is wider
+        assert!(bounds.max_longitude - bounds.min_longitude > latitude_span);
+        assert!(bounds.min_longitude < 12.57 && bounds.max_longitude > 12.57);
+
+        let polar: NearFilter = "89.9,0,50".parse().unwrap();
+        assert_eq!(polar.bounding_box().min_longitude, -180.0);
+        assert_eq!(polar.bounding_box().max_latitude, 90.0);
+
+        assert!("55.68,12.57".parse::<NearFilter>().is_err());
+        assert!("91,0,10".parse::<NearFilter>().is_err());
+        assert!("0,0,0".parse::<NearFilter>().is_err());
+    }
+
+    #[test]
+    fn test_care_occurrences() {
+        let at = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+        let now = at("2024-06-10T12:00:00Z");
+
+        let dates: Vec<_> =
+            care_occurrences(Some(at("2024-06-08T09:00:00Z")), 7, now, CareTiming::default())
+            .take(2)
+            .collect();
+        assert_eq!(
+            dates,
+            vec![at("2024-06-15T09:00:00Z"), at("2024-06-22T09:00:00Z")]
+        );
+
+        // Missed dates are skipped, never-cared-for plants are due now
+        assert_eq!(
+            care_occurrences(Some(at("2024-05-01T09:00:00Z")), 7, now, CareTiming::default())
+                .next(),
+            Some(at("2024-06-12T09:00:00Z"))
+        );
+        assert_eq!(
+            care_occurrences(None, 3, now, CareTiming::default()).next(),
+            Some(now)
+        );
+        assert_eq!(
+            care_occurrences(None, 0, now, CareTiming::default()).next(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_care_occurrences_avoid_weekends() {
+        let at = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+        let now = at("2024-06-10T12:00:00Z");
+        let timing = CareTiming {
+            hour: Some(8),
+            avoid_weekends: true,
+        };
+
+        // Fertilizing every 14 days from Saturday 1 June lands on Saturday 15 June
+        let last_fertilized = Some(at("2024-06-01T19:30:00Z"));
+        let dates: Vec<_> = care_occurrences(last_fertilized, 14, now, timing)
+            .take(2)
+            .collect();
+        assert_eq!(
+            dates,
+            vec![at("2024-06-17T08:00:00Z"), at("2024-07-01T08:00:00Z")]
+        );
+
+        // The shift doesn't drift the schedule, and weekend days collapse into Monday
+        let daily: Vec<_> = care_occurrences(Some(at("2024-06-13T09:00:00Z")), 1, now, timing)
+            .take(3)
+            .collect();
+        assert_eq!(
+            daily,
+            vec![
+                at("2024-06-14T08:00:00Z"),
+                at("2024-06-17T08:00:00Z"),
+                at("2024-06-18T08:00:00Z"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rebalance_schedule_spreads_same_day_care() {
+        let at = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+        let now = at("2024-06-10T12:00:00Z");
+        let schedule = |interval_days| CareSchedule {
+            interval_days,
+            amount: None,
+            unit: None,
+            notes: None,
+        };
+        let plant = |id: u128, interval_days, last_watered| PlantResponse {
+            id: Uuid::from_u128(id),
+            name: format!("Plant {}", id),
+            genus: "Test Genus".to_string(),
+            description: None,
+            acquired_at: None,
+            location_name: None,
+            latitude: None,
+            longitude: None,
+            age_days: None,
+            watering_schedule: schedule(Some(interval_days)),
+            fertilizing_schedule: schedule(None),
+            last_watered,
+            last_fertilized: None,
+            reminder_lead_hours: None,
+            quantity: 1,
+            archived_at: None,
+            status: PlantStatus::Active,
+            died_at: None,
+            death_reason: None,
+            last_note_at: None,
+            last_photo_at: None,
+            last_measurement_at: None,
+            preview_id: None,
+            preview_url: None,
+            thumbnail_url: None,
+            photo_count: 0,
+            latest_photo_id: None,
+            custom_metrics: vec![],
+            care_tasks: vec![],
+            tags: vec![],
+            created_at: now,
+            updated_at: now,
+            user_id: "user".to_string(),
+        };
+
+        // Three weekly plants all due on 14 June, a daily plant that can't
+        // move, and one never watered
+        let watered = Some(at("2024-06-07T09:00:00Z"));
+        let mut plants = vec![
+            plant(1, 7, watered),
+            plant(2, 7, watered),
+            plant(3, 7, watered),
+            plant(4, 1, Some(at("2024-06-10T09:00:00Z"))),
+            plant(5, 7, None),
+        ];
+        let before = schedule_load(&plants, 7, now, CareTiming::default());
+        assert_eq!(before[4].count, 4);
+
+        let shifts = rebalance_schedule(&plants, 7, now, CareTiming::default());
+        assert_eq!(
+            shifts
+                .iter()
+                .map(|shift| (shift.plant_id.as_u128(), shift.offset_days))
+                .collect::<Vec<_>>(),
+            vec![(2, -1), (3, 1)]
+        );
+        assert_eq!(shifts[0].proposed_last_care, at("2024-06-06T09:00:00Z"));
+
+        for shift in &shifts {
+            let plant = plants
+                .iter_mut()
+                .find(|plant| plant.id == shift.plant_id)
+                .unwrap();
+            plant.last_watered = Some(shift.proposed_last_care);
+        }
+        let after = schedule_load(&plants, 7, now, CareTiming::default());
+        assert_eq!(after.iter().map(|day| day.count).max(), Some(2));
+        assert_eq!(
+            after.iter().map(|day| day.count).sum::<usize>(),
+            before.iter().map(|day| day.count).sum::<usize>()
+        );
+
+        // Nothing left to balance
+        assert!(rebalance_schedule(&plants, 7, now, CareTiming::default()).is_empty());
+    }
+
+    #[test]
+    fn test_plant_age_days() {
+        let date = |s: &str| s.parse::<NaiveDate>().unwrap();
+
+        assert_eq!(plant_age_days(date("2024-03-01"), date("2024-03-01")), 0);
+        assert_eq!(plant_age_days(date("2023-03-01"), date("2024-03-01")), 366);
+    }
+
+    #[test]
+    fn test_next_anniversary() {
+        let date = |s: &str| s.parse::<NaiveDate>().unwrap();
+
+        // Yearly anniversary later this week
+        assert_eq!(
+            next_anniversary(date("2023-06-10"), date("2024-06-07")),
+            Some((date("2024-06-10"), 12))
+        );
+        // Already passed this month, so the next one is next month
+        assert_eq!(
+            next_anniversary(date("2024-01-05"), date("2024-03-06")),
+            Some((date("2024-04-05"), 3))
+        );
+        // Acquired on the 31st: celebrated on the last day of shorter months
+        assert_eq!(
+            next_anniversary(date("2024-01-31"), date("2024-02-01")),
+            Some((date("2024-02-29"), 1))
+        );
+        // Acquired today: the first anniversary is a month out
+        assert_eq!(
+            next_anniversary(date("2024-05-15"), date("2024-05-15")),
+            Some((date("2024-06-15"), 1))
+        );
+    }
+
+    #[test]
+    fn test_care_unit_normalizes_aliases() {
+        assert_eq!("mL".parse::<CareUnit>(), Ok(CareUnit::Ml));
+        assert_eq!("milliliters".parse::<CareUnit>(), Ok(CareUnit::Ml));
+        assert_eq!("Tablespoons".parse::<CareUnit>(), Ok(CareUnit::Tbsp));
+        assert!("splashes".parse::<CareUnit>().is_err());
+
+        assert_eq!(CareUnit::normalize(Some(" mL ")), Some("ml".to_string()));
+        assert_eq!(CareUnit::normalize(Some("")), None);
+        assert_eq!(CareUnit::normalize(None), None);
+    }
+
+    #[test]
+    fn test_care_schedule_rejects_unknown_unit() {
+        let schedule = |unit: &str| CreateCareScheduleRequest {
+            interval_days: Some(7),
+            amount: Some(250.0),
+            unit: Some(unit.to_string()),
+            notes: None,
+        };
+
+        assert!(schedule("milliliters").validate().is_ok());
+        let errors = schedule("splashes").validate().unwrap_err();
+        assert!(errors.field_errors().contains_key("unit"));
+    }
+
+    #[test]
+    fn test_care_schedule_requires_amount_and_unit_together() {
+        let create = |amount: Option<f64>, unit: Option<&str>| CreateCareScheduleRequest {
+            interval_days: Some(7),
+            amount,
+            unit: unit.map(str::to_string),
+            notes: None,
+        };
+        let update = |amount: Option<f64>, unit: Option<&str>| UpdateCareScheduleRequest {
+            interval_days: Some(7),
+            amount,
+            unit: unit.map(str::to_string),
+            notes: None,
+        };
+
+        assert!(create(Some(250.0), Some("ml")).validate().is_ok());
+        assert!(create(None, None).validate().is_ok());
+        assert!(update(Some(250.0), Some("ml")).validate().is_ok());
+        assert!(update(None, None).validate().is_ok());
+
+        for (amount, unit) in [(Some(250.0), None), (Some(250.0), Some(" ")), (None, Some("ml"))] {
+            let errors = create(amount, unit).validate().unwrap_err();
+            assert_eq!(errors.field_errors()["__all__"][0].code, "amount_unit");
+            let errors = update(amount, unit).validate().unwrap_err();
+            assert_eq!(errors.field_errors()["__all__"][0].code, "amount_unit");
+        }
+    }
+
     #[test]
     fn test_create_plant_request_validation_valid() {
         let request = CreatePlantRequest {
             name: "Fiddle Leaf Fig".to_string(),
             genus: "Ficus".to_string(),
+            description: None,
+            acquired_at: None,
+            location_name: None,
+            latitude: None,
+            longitude: None,
             watering_schedule: Some(CreateCareScheduleRequest {
                 interval_days: Some(7),
                 amount: None,
@@ -254,6 +1795,8 @@ mod tests {
                 unit: None,
                 notes: None,
             }),
+            reminder_lead_hours: None,
+            quantity: None,
             custom_metrics: None,
             last_watered: None,
             last_fertilized: None,
@@ -267,6 +1810,11 @@ mod tests {
         let request = CreatePlantRequest {
             name: "".to_string(),
             genus: "Ficus".to_string(),
+            description: None,
+            acquired_at: None,
+            location_name: None,
+            latitude: None,
+            longitude: None,
             watering_schedule: Some(CreateCareScheduleRequest {
                 interval_days: Some(7),
                 amount: None,
@@ -279,6 +1827,8 @@ mod tests {
                 unit: None,
                 notes: None,
             }),
+            reminder_lead_hours: None,
+            quantity: None,
             custom_metrics: None,
             last_watered: None,
             last_fertilized: None,
@@ -296,6 +1846,11 @@ mod tests {
         let request = CreatePlantRequest {
             name: "a".repeat(101), // Exceeds max length of 100
             genus: "Ficus".to_string(),
+            description: None,
+            acquired_at: None,
+            location_name: None,
+            latitude: None,
+            longitude: None,
             watering_schedule: Some(CreateCareScheduleRequest {
                 interval_days: Some(7),
                 amount: None,
@@ -308,6 +1863,8 @@ mod tests {
                 unit: None,
                 notes: None,
             }),
+            reminder_lead_hours: None,
+            quantity: None,
             custom_metrics: None,
             last_watered: None,
             last_fertilized: None,
@@ -325,6 +1882,11 @@ mod tests {
         let request = CreatePlantRequest {
             name: "Fiddle Leaf Fig".to_string(),
             genus: "".to_string(),
+            description: None,
+            acquired_at: None,
+            location_name: None,
+            latitude: None,
+            longitude: None,
             watering_schedule: Some(CreateCareScheduleRequest {
                 interval_days: Some(7),
                 amount: None,
@@ -337,6 +1899,8 @@ mod tests {
                 unit: None,
                 notes: None,
             }),
+            reminder_lead_hours: None,
+            quantity: None,
             custom_metrics: None,
             last_watered: None,
             last_fertilized: None,
@@ -354,6 +1918,11 @@ mod tests {
         let request = CreatePlantRequest {
             name: "Fiddle Leaf Fig".to_string(),
             genus: "Ficus".to_string(),
+            description: None,
+            acquired_at: None,
+            location_name: None,
+            latitude: None,
+            longitude: None,
             watering_schedule: Some(CreateCareScheduleRequest {
                 interval_days: Some(0), // Below minimum of 1
                 amount: None,
@@ -366,6 +1935,8 @@ mod tests {
                 unit: None,
                 notes: None,
             }),
+            reminder_lead_hours: None,
+            quantity: None,
             custom_metrics: None,
             last_watered: None,
             last_fertilized: None,
@@ -384,6 +1955,11 @@ mod tests {
         let request = CreatePlantRequest {
             name: "Fiddle Leaf Fig".to_string(),
             genus: "Ficus".to_string(),
+            description: None,
+            acquired_at: None,
+            location_name: None,
+            latitude: None,
+            longitude: None,
             watering_schedule: Some(CreateCareScheduleRequest {
                 interval_days: Some(7),
                 amount: None,
@@ -396,6 +1972,8 @@ mod tests {
                 unit: None,
                 notes: None,
             }),
+            reminder_lead_hours: None,
+            quantity: None,
             custom_metrics: None,
             last_watered: None,
             last_fertilized: None,
@@ -486,6 +2064,11 @@ mod tests {
         let request = CreatePlantRequest {
             name: "Fiddle Leaf Fig".to_string(),
             genus: "Ficus".to_string(),
+            description: None,
+            acquired_at: None,
+            location_name: None,
+            latitude: None,
+            longitude: None,
             watering_schedule: Some(CreateCareScheduleRequest {
                 interval_days: Some(7),
                 amount: Some(250.0),
@@ -498,6 +2081,8 @@ mod tests {
                 unit: None,
                 notes: None,
             }),
+            reminder_lead_hours: None,
+            quantity: None,
             custom_metrics: Some(vec![custom_metric]),
             last_watered: None,
             last_fertilized: None,
@@ -533,6 +2118,12 @@ mod tests {
             id: Uuid::new_v4(),
             name: "Test Plant".to_string(),
             genus: "Test Genus".to_string(),
+            description: None,
+            acquired_at: None,
+            location_name: None,
+            latitude: None,
+            longitude: None,
+            age_days: None,
             watering_schedule: CareSchedule {
                 interval_days: Some(7),
                 amount: None,
@@ -547,9 +2138,23 @@ mod tests {
             },
             last_watered: None,
             last_fertilized: None,
+            reminder_lead_hours: None,
+            quantity: 1,
+            archived_at: None,
+            status: PlantStatus::Active,
+            died_at: None,
+            death_reason: None,
+            last_note_at: None,
+            last_photo_at: None,
+            last_measurement_at: None,
             preview_id: None,
             preview_url: None,
+            thumbnail_url: None,
+            photo_count: 0,
+            latest_photo_id: None,
             custom_metrics: vec![],
+            care_tasks: vec![],
+            tags: vec![],
             created_at: Utc::now(),
             updated_at: Utc::now(),
             user_id: Uuid::new_v4().to_string(),
@@ -560,6 +2165,8 @@ mod tests {
             total: 1,
             limit: 20,
             offset: 0,
+            next: None,
+            prev: None,
         };
 
         let json = serde_json::to_string(&response).unwrap();
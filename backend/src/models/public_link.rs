@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::models::CareSchedule;
+
+/// A plant's read-only link
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicLinkResponse {
+    pub token: String,
+    /// Where the plant can be viewed without signing in
+    pub url: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// What a read-only link shows of a plant
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicPlantResponse {
+    pub name: String,
+    pub genus: String,
+    pub watering_schedule: CareSchedule,
+    pub fertilizing_schedule: CareSchedule,
+    /// Newest first
+    pub photos: Vec<PublicPhoto>,
+    /// Shared notes only, newest first
+    pub notes: Vec<PublicNote>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicPhoto {
+    pub url: String,
+    pub caption: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicNote {
+    pub timestamp: DateTime<Utc>,
+    pub notes: String,
+}
@@ -0,0 +1,86 @@
+use chrono::{DateTime, Duration, Utc};
+
+/// Care entries of each type considered when scoring a plant
+pub const MAX_SCORED_CARE: i64 = 20;
+
+/// Days care can slip past its interval and still count as on time
+const GRACE_DAYS: i64 = 1;
+
+/// How often care kept to its schedule
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CareCompliance {
+    pub on_time: usize,
+    pub total: usize,
+}
+
+impl CareCompliance {
+    /// Combined compliance over several kinds of care
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            on_time: self.on_time + other.on_time,
+            total: self.total + other.total,
+        }
+    }
+
+    /// Percentage of care given on time, `None` without anything to judge
+    pub fn health_score(self) -> Option<u8> {
+        (self.total > 0).then(|| (self.on_time * 100 / self.total) as u8)
+    }
+}
+
+/// Judge each gap between care `times` (in any order) against `interval_days`
+///
+/// Care that is currently overdue counts as one late gap. Without an interval
+/// there's no schedule to keep, so nothing is judged.
+pub fn care_compliance(
+    interval_days: Option<i32>,
+    times: &[DateTime<Utc>],
+    now: DateTime<Utc>,
+) -> CareCompliance {
+    let Some(interval_days) = interval_days.filter(|days| *days > 0) else {
+        return CareCompliance::default();
+    };
+    let allowed = Duration::days(i64::from(interval_days) + GRACE_DAYS);
+
+    let mut times = times.to_vec();
+    times.sort();
+
+    let mut compliance = CareCompliance::default();
+    for pair in times.windows(2) {
+        compliance.total += 1;
+        if pair[1] - pair[0] <= allowed {
+            compliance.on_time += 1;
+        }
+    }
+    if times.last().is_some_and(|last| now - *last > allowed) {
+        compliance.total += 1;
+    }
+
+    compliance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn days_ago(now: DateTime<Utc>, days: &[i64]) -> Vec<DateTime<Utc>> {
+        days.iter().map(|day| now - Duration::days(*day)).collect()
+    }
+
+    #[test]
+    fn test_care_compliance_counts_late_gaps() {
+        let now = "2024-06-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        // Gaps of 7, 8 (within grace) and 12 days on a weekly schedule
+        let compliance = care_compliance(Some(7), &days_ago(now, &[30, 23, 15, 3]), now);
+        assert_eq!(compliance, CareCompliance { on_time: 2, total: 3 });
+        assert_eq!(compliance.health_score(), Some(66));
+
+        // Overdue right now counts against the plant
+        let overdue = care_compliance(Some(7), &days_ago(now, &[20, 13]), now);
+        assert_eq!(overdue, CareCompliance { on_time: 1, total: 2 });
+
+        assert_eq!(care_compliance(None, &days_ago(now, &[20, 13]), now).health_score(), None);
+        assert_eq!(care_compliance(Some(7), &[], now).health_score(), None);
+    }
+}
@@ -0,0 +1,91 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::photo::Photo;
+use super::plant::PlantResponse;
+use super::tracking_entry::TrackingEntry;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum DeletedEntityType {
+    Plant,
+    TrackingEntry,
+    Photo,
+}
+
+impl DeletedEntityType {
+    /// Value stored in the `deletions.entity_type` column
+    pub const fn as_db_str(self) -> &'static str {
+        match self {
+            Self::Plant => "plant",
+            Self::TrackingEntry => "tracking_entry",
+            Self::Photo => "photo",
+        }
+    }
+
+    pub fn from_db_str(value: &str) -> Option<Self> {
+        match value {
+            "plant" => Some(Self::Plant),
+            "tracking_entry" => Some(Self::TrackingEntry),
+            "photo" => Some(Self::Photo),
+            _ => None,
+        }
+    }
+}
+
+/// A tombstone recording that a record was deleted
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Deletion {
+    pub entity_type: DeletedEntityType,
+    pub entity_id: Uuid,
+    pub deleted_at: DateTime<Utc>,
+}
+
+/// Records created, updated or deleted since the requested cursor.
+///
+/// Deleting a plant also removes its entries and photos; only the plant's
+/// tombstone is reported in that case.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncChangesResponse {
+    pub plants: Vec<PlantResponse>,
+    pub tracking_entries: Vec<TrackingEntry>,
+    pub photos: Vec<Photo>,
+    pub deleted: Vec<Deletion>,
+    /// Pass as `since` on the next sync
+    pub server_time: DateTime<Utc>,
+    /// The cursor predates the kept deletion tombstones, so this is a full
+    /// sync instead: drop local records that aren't in it
+    pub full_resync_required: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deleted_entity_type_db_round_trip() {
+        for entity_type in [
+            DeletedEntityType::Plant,
+            DeletedEntityType::TrackingEntry,
+            DeletedEntityType::Photo,
+        ] {
+            assert_eq!(
+                DeletedEntityType::from_db_str(entity_type.as_db_str()),
+                Some(entity_type)
+            );
+        }
+        assert_eq!(DeletedEntityType::from_db_str("user"), None);
+    }
+
+    #[test]
+    fn test_deleted_entity_type_serialization() {
+        assert_eq!(
+            serde_json::to_string(&DeletedEntityType::TrackingEntry).unwrap(),
+            "\"trackingEntry\""
+        );
+    }
+}
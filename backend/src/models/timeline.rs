@@ -0,0 +1,30 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::models::photo::Photo;
+use crate::models::tracking_entry::TrackingEntry;
+
+/// One item in a plant's timeline, tagged with `type`
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum TimelineItem {
+    Entry(TrackingEntry),
+    Photo(TimelinePhoto),
+}
+
+/// A photo in the timeline, with the url it is served from
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TimelinePhoto {
+    #[serde(flatten)]
+    pub photo: Photo,
+    pub url: String,
+}
+
+/// Tracking entries and standalone photos, newest first
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TimelineResponse {
+    pub items: Vec<TimelineItem>,
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+}
@@ -1,9 +1,12 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use utoipa::ToSchema;
 use uuid::Uuid;
-use validator::Validate;
+use validator::{Validate, ValidationError};
+
+use crate::models::plant::validate_task_type;
+use crate::models::user::WeekStart;
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -16,6 +19,8 @@ pub struct TrackingEntry {
     pub notes: Option<String>,
     pub metric_id: Option<Uuid>,
     pub photo_ids: Option<serde_json::Value>, // Array of photo UUIDs
+    /// Who can see a note; always `shared` for other entry types
+    pub visibility: NoteVisibility,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -28,11 +33,67 @@ pub enum EntryType {
     CustomMetric,
     Note,
     Photo,
+    /// Care for one of the plant's care tasks, e.g. `{"care": {"taskType": "misting"}}`
+    #[serde(rename_all = "camelCase")]
+    Care { task_type: String },
 }
 
-#[derive(Debug, Deserialize, Validate, ToSchema)]
+/// Who can see a note when a plant is shared, e.g. through a public link
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NoteVisibility {
+    /// Only the plant's owner, e.g. "gift for mom"
+    OwnerOnly,
+    #[default]
+    Shared,
+}
+
+impl NoteVisibility {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            NoteVisibility::OwnerOnly => "owner_only",
+            NoteVisibility::Shared => "shared",
+        }
+    }
+}
+
+impl std::str::FromStr for NoteVisibility {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "owner_only" => Ok(NoteVisibility::OwnerOnly),
+            "shared" => Ok(NoteVisibility::Shared),
+            _ => Err(format!("Invalid note visibility: {}", s)),
+        }
+    }
+}
+
+/// Care entries must name a valid care task type
+fn validate_entry_type(entry_type: &EntryType) -> Result<(), ValidationError> {
+    match entry_type {
+        EntryType::Care { task_type } => validate_task_type(task_type),
+        _ => Ok(()),
+    }
+}
+
+/// Only notes can be made owner-only
+fn validate_note_visibility(request: &CreateTrackingEntryRequest) -> Result<(), ValidationError> {
+    match (&request.entry_type, request.visibility) {
+        (EntryType::Note, _) | (_, None | Some(NoteVisibility::Shared)) => Ok(()),
+        _ => {
+            let mut error = ValidationError::new("visibility");
+            error.message = Some("Only notes can be owner-only".into());
+            Err(error)
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_note_visibility"))]
 pub struct CreateTrackingEntryRequest {
+    #[validate(custom(function = "validate_entry_type"))]
     pub entry_type: EntryType,
     pub timestamp: DateTime<Utc>,
     pub value: Option<serde_json::Value>,
@@ -40,6 +101,8 @@ pub struct CreateTrackingEntryRequest {
     pub notes: Option<String>,
     pub metric_id: Option<Uuid>,
     pub photo_ids: Option<Vec<Uuid>>, // Array of photo UUIDs
+    /// For notes; defaults to `shared`
+    pub visibility: Option<NoteVisibility>,
 }
 
 #[derive(Debug, Deserialize, Validate, ToSchema)]
@@ -50,10 +113,151 @@ pub struct UpdateTrackingEntryRequest {
     #[validate(length(max = 1000))]
     pub notes: Option<String>,
     pub photo_ids: Option<Vec<Uuid>>, // Array of photo UUIDs
+    /// Only accepted for notes
+    pub visibility: Option<NoteVisibility>,
+}
+
+/// Most entries `POST /plants/{plant_id}/entries/bulk` accepts at once
+pub const MAX_BULK_ENTRIES: usize = 100;
+
+/// Entries to add to a plant together, e.g. a week of back-dated waterings
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateEntriesBulkRequest {
+    #[validate(custom(function = "validate_bulk_entries"))]
+    pub entries: Vec<CreateTrackingEntryRequest>,
+}
+
+/// Names the first invalid entry by its index, e.g. "Entry 2: notes: ..."
+fn validate_bulk_entries(entries: &[CreateTrackingEntryRequest]) -> Result<(), ValidationError> {
+    if entries.is_empty() || entries.len() > MAX_BULK_ENTRIES {
+        let mut error = ValidationError::new("length");
+        error.message =
+            Some(format!("Between 1 and {} entries are required", MAX_BULK_ENTRIES).into());
+        return Err(error);
+    }
+
+    for (index, entry) in entries.iter().enumerate() {
+        let Err(errors) = entry.validate() else {
+            continue;
+        };
+        let mut problems: Vec<String> = errors
+            .field_errors()
+            .into_iter()
+            .flat_map(|(field, errors)| {
+                errors.iter().map(move |error| {
+                    let problem = error
+                        .message
+                        .as_deref()
+                        .unwrap_or(error.code.as_ref())
+                        .to_string();
+                    if field == "__all__" {
+                        problem
+                    } else {
+                        format!("{}: {}", field, problem)
+                    }
+                })
+            })
+            .collect();
+        problems.sort();
+
+        let mut error = ValidationError::new("invalid_entry");
+        error.message = Some(format!("Entry {}: {}", index, problems.join("; ")).into());
+        error.add_param("index".into(), &index);
+        return Err(error);
+    }
+    Ok(())
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateEntriesBulkResponse {
+    /// In the order they were sent
+    pub entries: Vec<TrackingEntry>,
+    pub count: usize,
+}
+
+/// Which entries `DELETE /plants/{plant_id}/entries` removes. Filters combine,
+/// and at least one is required so a plant's history isn't wiped by accident.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteEntriesRequest {
+    #[validate(custom(function = "validate_entry_type"))]
+    pub entry_type: Option<EntryType>,
+    /// Only entries at or after this time
+    pub from: Option<DateTime<Utc>>,
+    /// Only entries before this time
+    pub to: Option<DateTime<Utc>>,
+    #[validate(length(min = 1, max = 1000))]
+    pub ids: Option<Vec<Uuid>>,
+}
+
+impl DeleteEntriesRequest {
+    pub fn has_filter(&self) -> bool {
+        self.entry_type.is_some() || self.from.is_some() || self.to.is_some() || self.ids.is_some()
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteEntriesResponse {
+    pub deleted: u64,
+}
+
+/// Period length for `GET /plants/{plant_id}/entries/rollup`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum RollupGranularity {
+    Day,
+    Week,
+    Month,
+}
+
+/// Number of entries of one type within a period
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryRollupBucket {
+    /// First day of the period (UTC)
+    pub period_start: NaiveDate,
+    pub entry_type: EntryType,
+    pub count: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryRollupResponse {
+    pub granularity: RollupGranularity,
+    /// The user's week start, applied when `granularity` is `week`
+    pub week_start: WeekStart,
+    pub buckets: Vec<EntryRollupBucket>,
+}
+
+/// Result of importing care history from a CSV
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportEntriesResponse {
+    /// Rows inserted, or that would be inserted when `validate_only` is set
+    pub imported: u64,
+    pub skipped: u64,
+    pub errors: Vec<ImportRowError>,
+    /// Whether this was a dry run that wrote nothing
+    pub validate_only: bool,
+}
+
+/// A CSV row that was skipped during import
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct ImportRowError {
+    /// 1-based line number in the uploaded file
+    pub line: u64,
+    pub reason: String,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
 pub struct TrackingEntriesResponse {
     pub entries: Vec<TrackingEntry>,
     pub total: i64,
+    /// Relative URL of the next page, `null` on the last page
+    pub next: Option<String>,
+    /// Relative URL of the previous page, `null` on the first page
+    pub prev: Option<String>,
 }
@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Utc, Weekday};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use utoipa::ToSchema;
@@ -53,6 +53,139 @@ impl std::str::FromStr for UserRole {
     }
 }
 
+/// First day of the week used when grouping by week
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum WeekStart {
+    Sunday,
+    #[default]
+    Monday,
+}
+
+impl WeekStart {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WeekStart::Sunday => "sunday",
+            WeekStart::Monday => "monday",
+        }
+    }
+
+    /// SQLite `weekday N` modifier for this day (0 = Sunday)
+    pub fn sqlite_weekday(self) -> u8 {
+        match self {
+            WeekStart::Sunday => 0,
+            WeekStart::Monday => 1,
+        }
+    }
+
+    /// The first day of the week containing `date`
+    pub fn start_of_week(self, date: NaiveDate) -> NaiveDate {
+        let first = match self {
+            WeekStart::Sunday => Weekday::Sun,
+            WeekStart::Monday => Weekday::Mon,
+        };
+        date - Duration::days(i64::from(date.weekday().days_since(first)))
+    }
+}
+
+impl std::str::FromStr for WeekStart {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "sunday" => Ok(WeekStart::Sunday),
+            "monday" => Ok(WeekStart::Monday),
+            _ => Err(format!("Invalid week start: {}", s)),
+        }
+    }
+}
+
+/// Per-user display and scheduling preferences
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserPreferences {
+    pub week_start: WeekStart,
+    /// Hour of day (UTC) scheduled care is placed at, `None` to keep the time of the last care
+    #[serde(default)]
+    #[validate(range(max = 23))]
+    pub care_hour: Option<u32>,
+    /// Move care that falls on a Saturday or Sunday to the following Monday
+    #[serde(default)]
+    pub avoid_weekends: bool,
+    /// The user's offset from UTC in minutes, deciding which calendar day care is due on
+    #[serde(default)]
+    #[validate(range(min = -720, max = 840))]
+    pub utc_offset_minutes: i32,
+}
+
+impl UserPreferences {
+    pub fn care_timing(&self) -> CareTiming {
+        CareTiming {
+            hour: self.care_hour,
+            avoid_weekends: self.avoid_weekends,
+        }
+    }
+
+    pub fn care_day(&self) -> CareDay {
+        CareDay {
+            utc_offset_minutes: self.utc_offset_minutes,
+        }
+    }
+}
+
+/// The user's calendar day, so care due earlier today is due today rather
+/// than overdue
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CareDay {
+    pub utc_offset_minutes: i32,
+}
+
+impl CareDay {
+    /// The user's calendar date at `instant`
+    pub fn date_of(self, instant: DateTime<Utc>) -> NaiveDate {
+        (instant + Duration::minutes(i64::from(self.utc_offset_minutes))).date_naive()
+    }
+
+    /// Whole calendar days from the day care was `due` to the day of `now`,
+    /// negative while it isn't due yet
+    pub fn days_overdue(self, due: DateTime<Utc>, now: DateTime<Utc>) -> i64 {
+        (self.date_of(now) - self.date_of(due)).num_days()
+    }
+
+    /// The instant the user's `date` begins
+    pub fn start_of(self, date: NaiveDate) -> DateTime<Utc> {
+        date.and_time(NaiveTime::MIN).and_utc()
+            - Duration::minutes(i64::from(self.utc_offset_minutes))
+    }
+}
+
+/// How scheduled care dates are adjusted for a user's reminders
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CareTiming {
+    pub hour: Option<u32>,
+    pub avoid_weekends: bool,
+}
+
+impl CareTiming {
+    /// Move `date` to the configured hour and off weekends
+    ///
+    /// Computed in UTC. The adjustment never moves a date earlier than the
+    /// start of its day, so it keeps a sequence of dates in order.
+    pub fn apply(self, date: DateTime<Utc>) -> DateTime<Utc> {
+        let date = match self.hour.and_then(|hour| date.date_naive().and_hms_opt(hour, 0, 0)) {
+            Some(at_hour) => at_hour.and_utc(),
+            None => date,
+        };
+
+        let days_to_weekday = match date.weekday() {
+            Weekday::Sat if self.avoid_weekends => 2,
+            Weekday::Sun if self.avoid_weekends => 1,
+            _ => 0,
+        };
+        date + Duration::days(days_to_weekday)
+    }
+}
+
 #[derive(Debug, FromRow)]
 pub struct UserRow {
     pub id: String,
@@ -150,6 +283,15 @@ pub struct AuthResponse {
     pub user: UserResponse,
 }
 
+/// Whether the caller's session is logged in, for keepalive pings
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionStatusResponse {
+    pub authenticated: bool,
+    /// When the session expires without further activity, `None` when not logged in
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
 impl User {
     pub fn is_admin(&self) -> bool {
         self.role == UserRole::Admin
@@ -457,4 +599,30 @@ mod tests {
         // Password hash should be included in debug but we don't want to assert on the exact value
         assert!(debug_output.contains("password_hash"));
     }
+
+    #[test]
+    fn test_start_of_week_in_user_time_zone() {
+        // Wednesday
+        let date = NaiveDate::from_ymd_opt(2024, 3, 13).unwrap();
+        assert_eq!(
+            WeekStart::Monday.start_of_week(date),
+            NaiveDate::from_ymd_opt(2024, 3, 11).unwrap()
+        );
+        assert_eq!(
+            WeekStart::Sunday.start_of_week(date),
+            NaiveDate::from_ymd_opt(2024, 3, 10).unwrap()
+        );
+        assert_eq!(
+            WeekStart::Monday.start_of_week(date - Duration::days(2)),
+            date - Duration::days(2)
+        );
+
+        let care_day = CareDay {
+            utc_offset_minutes: 120,
+        };
+        assert_eq!(
+            care_day.start_of(date).to_rfc3339(),
+            "2024-03-12T22:00:00+00:00"
+        );
+    }
 }
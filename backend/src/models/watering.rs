@@ -0,0 +1,344 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Longest stretch of precipitation history considered
+pub const MAX_LOOKBACK_DAYS: u32 = 7;
+
+/// Rain over the lookback window that's worth a day's delay
+const DELAY_MM_PER_DAY: f64 = 10.0;
+
+/// Below this much rain the window counts as dry
+const DRY_MM: f64 = 1.0;
+
+/// Waterings considered when learning the interval, most recent first
+pub const MAX_RECOMMENDATION_HISTORY: i64 = 12;
+
+/// Key identifying the watering interval recommendation, e.g. when dismissing it
+pub const WATERING_RECOMMENDATION_KEY: &str = "watering";
+
+/// Fewest gaps between waterings before an interval is suggested
+const MIN_OBSERVED_INTERVALS: usize = 3;
+
+/// Waterings closer together than this count as one, e.g. a top-up the same day
+const MIN_GAP_DAYS: f64 = 0.5;
+
+/// Relative difference from the configured interval worth suggesting a change for
+const MATERIAL_DIFFERENCE: f64 = 0.2;
+
+/// How a suggestion moves the next watering
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum WateringAdjustment {
+    Advance,
+    Delay,
+    Keep,
+}
+
+/// Weather-aware suggestion for a plant's next watering
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WateringSuggestion {
+    pub plant_id: Uuid,
+    pub adjustment: WateringAdjustment,
+    /// Days the next watering moves, negative when advanced
+    pub shift_days: i64,
+    /// Next watering according to the schedule alone
+    pub scheduled_at: DateTime<Utc>,
+    pub suggested_at: DateTime<Utc>,
+    /// Rain over the lookback window, `None` when the provider has no data
+    pub precipitation_mm: Option<f64>,
+    pub lookback_days: u32,
+    /// Weather provider the precipitation came from
+    pub provider: String,
+    pub reason: String,
+}
+
+/// Days of precipitation history to consider for a watering interval
+///
+/// Rain since about the last watering matters, up to a week.
+pub fn lookback_days(interval_days: i32) -> u32 {
+    interval_days.clamp(1, MAX_LOOKBACK_DAYS as i32) as u32
+}
+
+/// Suggest moving the next watering based on recent precipitation
+///
+/// Heavy rain delays the watering by a day per `DELAY_MM_PER_DAY` (at most one
+/// interval); a dry spell brings it forward a day unless it's already due.
+pub fn suggest_watering(
+    plant_id: Uuid,
+    interval_days: i32,
+    last_watered: Option<DateTime<Utc>>,
+    precipitation_mm: Option<f64>,
+    provider: &str,
+    now: DateTime<Utc>,
+) -> WateringSuggestion {
+    let interval = Duration::days(interval_days.into());
+    // Never watered plants are due now, as with calendar and task reminders
+    let scheduled_at = last_watered.map_or(now, |last| last + interval);
+    let lookback_days = lookback_days(interval_days);
+
+    let (adjustment, shift_days, reason) = match precipitation_mm {
+        None => (
+            WateringAdjustment::Keep,
+            0,
+            "No recent weather data, keeping the schedule".to_string(),
+        ),
+        Some(mm) if mm >= DELAY_MM_PER_DAY => {
+            let days = ((mm / DELAY_MM_PER_DAY) as i64).min(interval_days.into());
+            (
+                WateringAdjustment::Delay,
+                days,
+                format!("{mm:.1} mm of rain in the last {lookback_days} days"),
+            )
+        }
+        Some(mm) if mm < DRY_MM && scheduled_at - Duration::days(1) > now => (
+            WateringAdjustment::Advance,
+            -1,
+            format!("Only {mm:.1} mm of rain in the last {lookback_days} days"),
+        ),
+        Some(mm) if mm < DRY_MM => (
+            WateringAdjustment::Keep,
+            0,
+            format!("Only {mm:.1} mm of rain in the last {lookback_days} days, and watering is already due"),
+        ),
+        Some(mm) => (
+            WateringAdjustment::Keep,
+            0,
+            format!("{mm:.1} mm of rain in the last {lookback_days} days doesn't change the schedule"),
+        ),
+    };
+
+    WateringSuggestion {
+        plant_id,
+        adjustment,
+        shift_days,
+        scheduled_at,
+        suggested_at: scheduled_at + Duration::days(shift_days),
+        precipitation_mm,
+        lookback_days,
+        provider: provider.to_string(),
+        reason,
+    }
+}
+
+/// Suggested watering interval learned from how often the plant is actually watered
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IntervalRecommendation {
+    /// Identifies the recommendation when dismissing it
+    pub key: String,
+    /// The plant's configured interval, `None` when it has none
+    pub current_interval_days: Option<i32>,
+    /// Median days between recent waterings
+    pub observed_interval_days: f64,
+    pub suggested_interval_days: i32,
+    /// Gaps between waterings the median was taken over
+    pub intervals_observed: usize,
+    pub reason: String,
+}
+
+/// The intervals a dismissed recommendation was based on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DismissedRecommendation {
+    pub current_interval_days: Option<i32>,
+    pub suggested_interval_days: i32,
+}
+
+impl IntervalRecommendation {
+    /// Whether `dismissed` still hides this recommendation
+    ///
+    /// A dismissal stops applying once the configured interval is changed or
+    /// the history points to a different interval.
+    pub fn is_dismissed_by(&self, dismissed: &DismissedRecommendation) -> bool {
+        self.current_interval_days == dismissed.current_interval_days
+            && self.suggested_interval_days == dismissed.suggested_interval_days
+    }
+
+    pub fn dismissal(&self) -> DismissedRecommendation {
+        DismissedRecommendation {
+            current_interval_days: self.current_interval_days,
+            suggested_interval_days: self.suggested_interval_days,
+        }
+    }
+}
+
+/// Schedule changes suggested by a plant's own care history
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CareRecommendationsResponse {
+    pub plant_id: Uuid,
+    /// `None` when the history is too short, matches the schedule, or the
+    /// recommendation was dismissed
+    pub watering: Option<IntervalRecommendation>,
+}
+
+fn median(values: &mut [f64]) -> Option<f64> {
+    values.sort_by(f64::total_cmp);
+    let mid = values.len() / 2;
+    match values.len() {
+        0 => None,
+        len if len % 2 == 0 => Some((values[mid - 1] + values[mid]) / 2.0),
+        _ => Some(values[mid]),
+    }
+}
+
+/// Suggest a watering interval from when the plant was actually watered
+///
+/// Takes the median gap between `waterings` (in any order), so the odd late or
+/// early watering doesn't skew it. A change is only suggested once there are
+/// enough gaps and the rounded median differs from `interval_days` by at least
+/// a day and `MATERIAL_DIFFERENCE` of it; a plant without an interval always
+/// gets one.
+pub fn recommend_watering_interval(
+    interval_days: Option<i32>,
+    waterings: &[DateTime<Utc>],
+) -> Option<IntervalRecommendation> {
+    let mut waterings = waterings.to_vec();
+    waterings.sort();
+
+    let mut gaps: Vec<f64> = waterings
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).num_seconds() as f64 / 86_400.0)
+        .filter(|days| *days >= MIN_GAP_DAYS)
+        .collect();
+    if gaps.len() < MIN_OBSERVED_INTERVALS {
+        return None;
+    }
+
+    let observed = median(&mut gaps)?;
+    let suggested = (observed.round() as i32).max(1);
+
+    let reason = match interval_days.filter(|days| *days > 0) {
+        Some(current) => {
+            let difference = (suggested - current).abs();
+            if difference == 0 || (difference as f64) < current as f64 * MATERIAL_DIFFERENCE {
+                return None;
+            }
+            format!(
+                "Watered every {observed:.1} days on average over the last {} waterings, not every {current}",
+                gaps.len()
+            )
+        }
+        None => format!(
+            "Watered every {observed:.1} days on average over the last {} waterings",
+            gaps.len()
+        ),
+    };
+
+    Some(IntervalRecommendation {
+        key: WATERING_RECOMMENDATION_KEY.to_string(),
+        current_interval_days: interval_days,
+        observed_interval_days: (observed * 10.0).round() / 10.0,
+        suggested_interval_days: suggested,
+        intervals_observed: gaps.len(),
+        reason,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suggest(precipitation_mm: Option<f64>, days_since_watered: i64) -> WateringSuggestion {
+        let now = "2024-06-10T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        suggest_watering(
+            Uuid::nil(),
+            4,
+            Some(now - Duration::days(days_since_watered)),
+            precipitation_mm,
+            "test",
+            now,
+        )
+    }
+
+    #[test]
+    fn test_suggest_watering_adjustments() {
+        let no_data = suggest(None, 1);
+        assert_eq!(no_data.adjustment, WateringAdjustment::Keep);
+        assert_eq!(no_data.suggested_at, no_data.scheduled_at);
+        assert_eq!(no_data.lookback_days, 4);
+
+        let rainy = suggest(Some(25.0), 1);
+        assert_eq!(rainy.adjustment, WateringAdjustment::Delay);
+        assert_eq!(rainy.shift_days, 2);
+        assert_eq!(rainy.suggested_at - rainy.scheduled_at, Duration::days(2));
+
+        // Delays are capped at one interval
+        assert_eq!(suggest(Some(90.0), 1).shift_days, 4);
+
+        let dry = suggest(Some(0.2), 1);
+        assert_eq!(dry.adjustment, WateringAdjustment::Advance);
+        assert_eq!(dry.shift_days, -1);
+
+        // Nothing to advance once watering is (nearly) due
+        assert_eq!(suggest(Some(0.0), 4).adjustment, WateringAdjustment::Keep);
+
+        assert_eq!(suggest(Some(5.0), 1).adjustment, WateringAdjustment::Keep);
+    }
+
+    #[test]
+    fn test_never_watered_plant_is_due_now() {
+        let now = Utc::now();
+        let suggestion = suggest_watering(Uuid::nil(), 3, None, Some(12.0), "test", now);
+
+        assert_eq!(suggestion.scheduled_at, now);
+        assert_eq!(suggestion.suggested_at, now + Duration::days(1));
+    }
+
+    fn waterings_every(gaps: &[i64]) -> Vec<DateTime<Utc>> {
+        let start = "2024-03-01T08:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        std::iter::once(0)
+            .chain(gaps.iter().scan(0, |day, gap| {
+                *day += gap;
+                Some(*day)
+            }))
+            .map(|day| start + Duration::days(day))
+            .collect()
+    }
+
+    #[test]
+    fn test_recommends_observed_nine_day_cadence() {
+        let history = waterings_every(&[9, 10, 8, 9, 9, 11, 9]);
+
+        let recommendation = recommend_watering_interval(Some(7), &history).unwrap();
+
+        assert_eq!(recommendation.suggested_interval_days, 9);
+        assert_eq!(recommendation.observed_interval_days, 9.0);
+        assert_eq!(recommendation.current_interval_days, Some(7));
+        assert_eq!(recommendation.intervals_observed, 7);
+        assert_eq!(recommendation.key, WATERING_RECOMMENDATION_KEY);
+    }
+
+    #[test]
+    fn test_dismissal_lapses_when_intervals_change() {
+        let recommendation =
+            recommend_watering_interval(Some(7), &waterings_every(&[9, 9, 9])).unwrap();
+        let dismissed = recommendation.dismissal();
+        assert!(recommendation.is_dismissed_by(&dismissed));
+
+        let slower = recommend_watering_interval(Some(7), &waterings_every(&[9, 12, 12, 12])).unwrap();
+        assert!(!slower.is_dismissed_by(&dismissed));
+
+        let rescheduled = recommend_watering_interval(Some(5), &waterings_every(&[9, 9, 9])).unwrap();
+        assert!(!rescheduled.is_dismissed_by(&dismissed));
+    }
+
+    #[test]
+    fn test_no_recommendation_when_close_to_schedule_or_history_is_short() {
+        // Within a day, or within 20% of the interval
+        assert!(recommend_watering_interval(Some(7), &waterings_every(&[7, 8, 7, 6])).is_none());
+        assert!(recommend_watering_interval(Some(14), &waterings_every(&[16, 16, 16])).is_none());
+
+        assert!(recommend_watering_interval(Some(7), &waterings_every(&[9, 9])).is_none());
+        // Same-day top-ups don't count as gaps
+        let mut history = waterings_every(&[9, 9]);
+        history.push(history[2] + Duration::hours(2));
+        assert!(recommend_watering_interval(Some(7), &history).is_none());
+
+        // Without an interval any regular cadence is worth suggesting
+        let unscheduled = recommend_watering_interval(None, &waterings_every(&[5, 4, 5])).unwrap();
+        assert_eq!(unscheduled.suggested_interval_days, 5);
+    }
+}
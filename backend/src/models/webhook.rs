@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateWebhookRequest {
+    #[validate(url, length(max = 2048))]
+    pub url: String,
+}
+
+/// The user's webhook endpoint
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookSettingsResponse {
+    pub url: String,
+    /// Only returned when the secret was just generated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A newly generated signing secret; it is not shown again
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookSecretResponse {
+    pub secret: String,
+}
+
+/// Outcome of sending a test delivery to the webhook endpoint
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookTestResponse {
+    /// The endpoint answered with a 2xx status
+    pub success: bool,
+    /// HTTP status returned by the endpoint, if it answered at all
+    pub status: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
@@ -0,0 +1,56 @@
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+use crate::utils::hex::to_hex;
+
+/// Marks a string as a Planty API key
+const KEY_PREFIX: &str = "planty_";
+
+/// Characters of a key kept in the clear so users can tell their keys apart
+const DISPLAY_PREFIX_LEN: usize = KEY_PREFIX.len() + 8;
+
+/// Generate a new random API key
+pub fn generate_api_key() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    format!("{}{}", KEY_PREFIX, to_hex(&bytes))
+}
+
+/// The hash stored in place of the key
+///
+/// Keys are long and random, so a fast hash is enough to keep a leaked
+/// database from exposing usable keys.
+pub fn hash_api_key(key: &str) -> String {
+    to_hex(&Sha256::digest(key.as_bytes()))
+}
+
+/// The start of a key, shown when listing keys
+pub fn display_prefix(key: &str) -> String {
+    key.chars().take(DISPLAY_PREFIX_LEN).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_api_key_is_random() {
+        let key = generate_api_key();
+
+        assert!(key.starts_with(KEY_PREFIX));
+        assert_eq!(key.len(), KEY_PREFIX.len() + 64);
+        assert_ne!(key, generate_api_key());
+        assert_eq!(display_prefix(&key), key[..DISPLAY_PREFIX_LEN]);
+    }
+
+    #[test]
+    fn test_hash_api_key_is_stable_and_hides_the_key() {
+        let key = generate_api_key();
+        let hash = hash_api_key(&key);
+
+        assert_eq!(hash, hash_api_key(&key));
+        assert_eq!(hash.len(), 64);
+        assert!(!hash.contains(&key[KEY_PREFIX.len()..]));
+        assert_ne!(hash, hash_api_key(&generate_api_key()));
+    }
+}
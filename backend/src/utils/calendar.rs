@@ -1,32 +1,304 @@
+use std::ops::RangeInclusive;
+
 use chrono::{DateTime, Duration, Utc};
-use icalendar::{Calendar, Component, Event, EventLike};
+use icalendar::{Alarm, Calendar, Component, Event, EventLike};
 
-use crate::models::plant::PlantResponse;
+use crate::models::plant::{care_occurrences, CareKind, CareSchedule, CareTask, PlantResponse};
+use crate::models::tracking_entry::{EntryType, TrackingEntry};
+use crate::models::user::CareTiming;
 use crate::utils::errors::AppError;
 
-/// Generate an iCalendar feed for plant care events
+/// How far back `include_history` reaches, matching the year of upcoming events
+pub const HISTORY_DAYS: i64 = 365;
+
+/// Language used for calendar event text
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CalendarLocale {
+    #[default]
+    En,
+    Fr,
+}
+
+impl CalendarLocale {
+    /// Parse a `lang`/`locale` value such as `fr`, `fr-CA` or `fr_FR`,
+    /// falling back to English for anything unsupported
+    pub fn from_param(value: Option<&str>) -> Self {
+        let language = value
+            .and_then(|v| v.split(['-', '_']).next())
+            .map(|v| v.trim().to_ascii_lowercase());
+
+        match language.as_deref() {
+            Some("fr") => Self::Fr,
+            _ => Self::En,
+        }
+    }
+
+    fn calendar_name(self) -> &'static str {
+        match self {
+            Self::En => "Plant Care Schedule",
+            Self::Fr => "Calendrier d'entretien des plantes",
+        }
+    }
+
+    fn calendar_description(self) -> &'static str {
+        match self {
+            Self::En => "Watering and fertilizing schedule for your plants",
+            Self::Fr => "Calendrier d'arrosage et de fertilisation de vos plantes",
+        }
+    }
+
+    fn care_task_summary(self, task: &CareTask, plant_name: &str) -> String {
+        format!("🪴 {} {plant_name}", task.label())
+    }
+
+    fn summary(self, care: CareKind, plant_name: &str) -> String {
+        match (self, care) {
+            (Self::En, CareKind::Watering) => format!("💧 Water {plant_name}"),
+            (Self::En, CareKind::Fertilizing) => format!("🌱 Fertilize {plant_name}"),
+            (Self::Fr, CareKind::Watering) => format!("💧 Arroser {plant_name}"),
+            (Self::Fr, CareKind::Fertilizing) => format!("🌱 Fertiliser {plant_name}"),
+        }
+    }
+
+    fn time_to(self, care: CareKind, plant: &PlantResponse) -> String {
+        let (name, genus) = (&plant.name, &plant.genus);
+        match (self, care) {
+            (Self::En, CareKind::Watering) => format!("Time to water your {name} ({genus})."),
+            (Self::En, CareKind::Fertilizing) => {
+                format!("Time to fertilize your {name} ({genus}).")
+            }
+            (Self::Fr, CareKind::Watering) => {
+                format!("C'est le moment d'arroser votre {name} ({genus}).")
+            }
+            (Self::Fr, CareKind::Fertilizing) => {
+                format!("C'est le moment de fertiliser votre {name} ({genus}).")
+            }
+        }
+    }
+
+    fn completed(self, care: CareKind, plant: &PlantResponse) -> String {
+        let (name, genus) = (&plant.name, &plant.genus);
+        match (self, care) {
+            (Self::En, CareKind::Watering) => format!("✅ Completed: watered your {name} ({genus})."),
+            (Self::En, CareKind::Fertilizing) => {
+                format!("✅ Completed: fertilized your {name} ({genus}).")
+            }
+            (Self::Fr, CareKind::Watering) => {
+                format!("✅ Fait : vous avez arrosé votre {name} ({genus}).")
+            }
+            (Self::Fr, CareKind::Fertilizing) => {
+                format!("✅ Fait : vous avez fertilisé votre {name} ({genus}).")
+            }
+        }
+    }
+
+    fn care_task_due(self, task: &CareTask, plant: &PlantResponse) -> String {
+        let (label, name, genus) = (task.label(), &plant.name, &plant.genus);
+        match self {
+            Self::En => format!("{label} is due for your {name} ({genus})."),
+            Self::Fr => format!("{label} à faire pour votre {name} ({genus})."),
+        }
+    }
+
+    fn amount(self, amount: f64, unit: Option<&str>) -> String {
+        let unit = unit.map_or(String::new(), |unit| format!(" {}", unit));
+        match self {
+            Self::En => format!(" Amount: {}{}.", amount, unit),
+            // French uses a decimal comma and a space before the colon
+            Self::Fr => format!(" Quantité : {}{}.", amount.to_string().replace('.', ","), unit),
+        }
+    }
+
+    fn notes(self, notes: &str) -> String {
+        match self {
+            Self::En => format!(" Notes: {}", notes),
+            Self::Fr => format!(" Remarques : {}", notes),
+        }
+    }
+
+    fn every(self, care: CareKind, interval_days: i32) -> String {
+        match (self, care) {
+            (Self::En, CareKind::Watering) => format!("Water every {interval_days} days."),
+            (Self::En, CareKind::Fertilizing) => format!("Fertilize every {interval_days} days."),
+            (Self::Fr, CareKind::Watering) => format!("Arroser tous les {interval_days} jours."),
+            (Self::Fr, CareKind::Fertilizing) => {
+                format!("Fertiliser tous les {interval_days} jours.")
+            }
+        }
+    }
+
+    fn every_days(self, interval_days: i32) -> String {
+        match self {
+            Self::En => format!("Every {interval_days} days."),
+            Self::Fr => format!("Tous les {interval_days} jours."),
+        }
+    }
+
+    fn view_details(self) -> &'static str {
+        match self {
+            Self::En => "View plant details",
+            Self::Fr => "Voir la plante",
+        }
+    }
+
+    fn location(self, plant: &PlantResponse) -> String {
+        match self {
+            Self::En => format!("Plant: {} ({})", plant.name, plant.genus),
+            Self::Fr => format!("Plante : {} ({})", plant.name, plant.genus),
+        }
+    }
+
+    fn description(self, care: CareKind, plant: &PlantResponse, base_url: &str) -> String {
+        let schedule = match care {
+            CareKind::Watering => &plant.watering_schedule,
+            CareKind::Fertilizing => &plant.fertilizing_schedule,
+        };
+
+        self.event_description(
+            self.time_to(care, plant),
+            schedule,
+            self.every(care, schedule.interval_days.unwrap_or_default()),
+            plant,
+            base_url,
+        )
+    }
+
+    fn care_task_description(
+        self,
+        task: &CareTask,
+        plant: &PlantResponse,
+        base_url: &str,
+    ) -> String {
+        self.event_description(
+            self.care_task_due(task, plant),
+            &task.schedule,
+            self.every_days(task.schedule.interval_days.unwrap_or_default()),
+            plant,
+            base_url,
+        )
+    }
+
+    fn history_description(
+        self,
+        care: CareKind,
+        entry: &TrackingEntry,
+        plant: &PlantResponse,
+        base_url: &str,
+    ) -> String {
+        format!(
+            "{}{}\n\n{}: {}/plants/{}",
+            self.completed(care, plant),
+            entry
+                .notes
+                .as_ref()
+                .map_or(String::new(), |notes| format!(" {}", notes)),
+            self.view_details(),
+            base_url,
+            plant.id
+        )
+    }
+
+    /// Shared layout of every scheduled event description: what's due, how
+    /// much, how often and the schedule's notes, then a link to the plant
+    fn event_description(
+        self,
+        due: String,
+        schedule: &CareSchedule,
+        every: String,
+        plant: &PlantResponse,
+        base_url: &str,
+    ) -> String {
+        format!(
+            "{}{} {}{}\n\n{}: {}/plants/{}",
+            due,
+            schedule
+                .amount
+                .map_or(String::new(), |amt| self.amount(amt, schedule.unit.as_deref())),
+            every,
+            schedule
+                .notes
+                .as_deref()
+                .map(str::trim)
+                .filter(|notes| !notes.is_empty())
+                .map_or(String::new(), |notes| self.notes(notes)),
+            self.view_details(),
+            base_url,
+            plant.id
+        )
+    }
+}
+
+/// Reminder shown `lead_hours` before an event starts
+fn reminder(lead_hours: u32, description: &str) -> Alarm {
+    // chrono would write the trigger in seconds (-PT86400S); hours read better
+    Alarm::display(description, -Duration::hours(lead_hours.into()))
+        .add_property("TRIGGER", format!("-PT{lead_hours}H"))
+        .done()
+}
+
+/// Generate an iCalendar feed for plant care events, placed according to `timing`
+///
+/// Each event reminds `reminder_lead_hours` ahead unless its plant sets its own
+/// lead time. Watering and fertilizing entries in `history` become completed
+/// events alongside the upcoming ones.
 pub fn generate_plant_calendar(
     plants: &[PlantResponse],
+    history: &[TrackingEntry],
     _user_id: &str,
     base_url: &str,
+    locale: CalendarLocale,
+    timing: CareTiming,
+    reminder_lead_hours: u32,
 ) -> Result<String, AppError> {
     let mut calendar = Calendar::new()
-        .name("Plant Care Schedule")
-        .description("Watering and fertilizing schedule for your plants")
+        .name(locale.calendar_name())
+        .description(locale.calendar_description())
         .timezone("UTC")
         .done();
 
     let now = Utc::now();
 
     // Generate events for the next 365 days
-    let end_date = now + Duration::days(365);
+    let window = now..=now + Duration::days(365);
 
     for plant in plants {
+        let lead_hours = plant.reminder_lead_hours_or(reminder_lead_hours);
+
         // Generate watering events
-        generate_watering_events(&mut calendar, plant, now, end_date, base_url)?;
+        generate_watering_events(
+            &mut calendar,
+            plant,
+            &window,
+            base_url,
+            locale,
+            timing,
+            lead_hours,
+        )?;
 
         // Generate fertilizing events
-        generate_fertilizing_events(&mut calendar, plant, now, end_date, base_url)?;
+        generate_fertilizing_events(
+            &mut calendar,
+            plant,
+            &window,
+            base_url,
+            locale,
+            timing,
+            lead_hours,
+        )?;
+
+        // Generate events for the plant's other care tasks
+        generate_care_task_events(
+            &mut calendar,
+            plant,
+            &window,
+            base_url,
+            locale,
+            timing,
+            lead_hours,
+        );
+
+        // Generate events for care already given
+        generate_history_events(&mut calendar, plant, history, base_url, locale);
     }
 
     Ok(calendar.to_string())
@@ -36,9 +308,11 @@ pub fn generate_plant_calendar(
 fn generate_watering_events(
     calendar: &mut Calendar,
     plant: &PlantResponse,
-    start_date: DateTime<Utc>,
-    end_date: DateTime<Utc>,
+    window: &RangeInclusive<DateTime<Utc>>,
     base_url: &str,
+    locale: CalendarLocale,
+    timing: CareTiming,
+    lead_hours: u32,
 ) -> Result<(), AppError> {
     // Skip if watering is disabled
     if plant.watering_schedule.interval_days.is_none() {
@@ -54,47 +328,25 @@ fn generate_watering_events(
         return Ok(());
     }
     
-    let last_watered = plant
-        .last_watered
-        .unwrap_or_else(|| start_date - Duration::days(interval_days as i64));
-
-    let interval_duration = Duration::days(interval_days as i64);
-    let mut next_watering = last_watered + interval_duration;
-
-    // Ensure we start from a recent date (allow events that are due now or very soon)
-    // This prevents missing events due to timing precision issues
-    let start_threshold = start_date - Duration::hours(1);
-    while next_watering <= start_threshold {
-        next_watering += interval_duration;
-    }
-
-
-    let mut event_count = 0;
-    while next_watering <= end_date && event_count < 100 {
-        // Limit to prevent infinite loops
+    // Limit to 100 events per plant
+    for next_watering in care_occurrences(plant.last_watered, interval_days, *window.start(), timing)
+        .take_while(|date| window.contains(date))
+        .take(100)
+    {
+        let summary = locale.summary(CareKind::Watering, &plant.name);
         let event = Event::new()
             .uid(&format!("water-{}-{}", plant.id, next_watering.timestamp()))
-            .summary(&format!("💧 Water {}", plant.name))
-            .description(&format!(
-                "Time to water your {} ({}).{}{} Water every {} days.\n\nView plant details: {}/plants/{}",
-                plant.name,
-                plant.genus,
-                plant.watering_schedule.amount.map_or("".to_string(), |amt| format!(" Amount: {}", amt)),
-                plant.watering_schedule.unit.as_ref().map_or("".to_string(), |unit| format!(" {}", unit)),
-                interval_days,
-                base_url,
-                plant.id
-            ))
+            .summary(&summary)
+            .description(&locale.description(CareKind::Watering, plant, base_url))
             .starts(next_watering)
             .ends(next_watering + Duration::hours(1)) // 1-hour event duration
-            .location(&format!("Plant: {} ({})", plant.name, plant.genus))
+            .location(&locale.location(plant))
             .add_property("CATEGORIES", "Plant Care,Watering")
             .add_property("PRIORITY", "5") // Normal priority
+            .alarm(reminder(lead_hours, &summary))
             .done();
 
         calendar.push(event);
-        next_watering += interval_duration;
-        event_count += 1;
     }
 
     Ok(())
@@ -104,9 +356,11 @@ fn generate_watering_events(
 fn generate_fertilizing_events(
     calendar: &mut Calendar,
     plant: &PlantResponse,
-    start_date: DateTime<Utc>,
-    end_date: DateTime<Utc>,
+    window: &RangeInclusive<DateTime<Utc>>,
     base_url: &str,
+    locale: CalendarLocale,
+    timing: CareTiming,
+    lead_hours: u32,
 ) -> Result<(), AppError> {
     // Skip if fertilizing is disabled
     if plant.fertilizing_schedule.interval_days.is_none() {
@@ -122,51 +376,106 @@ fn generate_fertilizing_events(
         return Ok(());
     }
     
-    let last_fertilized = plant
-        .last_fertilized
-        .unwrap_or_else(|| start_date - Duration::days(interval_days as i64));
-
-    let interval_duration = Duration::days(interval_days as i64);
-    let mut next_fertilizing = last_fertilized + interval_duration;
-
-    // Ensure we start from a recent date (allow events that are due now or very soon)
-    // This prevents missing events due to timing precision issues
-    let start_threshold = start_date - Duration::hours(1);
-    while next_fertilizing <= start_threshold {
-        next_fertilizing += interval_duration;
-    }
-
-    let mut event_count = 0;
-    while next_fertilizing <= end_date && event_count < 100 {
-        // Limit to prevent infinite loops
+    // Limit to 100 events per plant
+    for next_fertilizing in care_occurrences(plant.last_fertilized, interval_days, *window.start(), timing)
+        .take_while(|date| window.contains(date))
+        .take(100)
+    {
+        let summary = locale.summary(CareKind::Fertilizing, &plant.name);
         let event = Event::new()
             .uid(&format!("fertilize-{}-{}", plant.id, next_fertilizing.timestamp()))
-            .summary(&format!("🌱 Fertilize {}", plant.name))
-            .description(&format!(
-                "Time to fertilize your {} ({}).{}{} Fertilize every {} days.\n\nView plant details: {}/plants/{}",
-                plant.name,
-                plant.genus,
-                plant.fertilizing_schedule.amount.map_or("".to_string(), |amt| format!(" Amount: {}", amt)),
-                plant.fertilizing_schedule.unit.as_ref().map_or("".to_string(), |unit| format!(" {}", unit)),
-                interval_days,
-                base_url,
-                plant.id
-            ))
+            .summary(&summary)
+            .description(&locale.description(CareKind::Fertilizing, plant, base_url))
             .starts(next_fertilizing)
             .ends(next_fertilizing + Duration::hours(1)) // 1-hour event duration
-            .location(&format!("Plant: {} ({})", plant.name, plant.genus))
+            .location(&locale.location(plant))
             .add_property("CATEGORIES", "Plant Care,Fertilizing")
             .add_property("PRIORITY", "4") // Slightly lower priority than watering
+            .alarm(reminder(lead_hours, &summary))
             .done();
 
         calendar.push(event);
-        next_fertilizing += interval_duration;
-        event_count += 1;
     }
 
     Ok(())
 }
 
+/// Generate events for each of a plant's care tasks that has an interval set
+fn generate_care_task_events(
+    calendar: &mut Calendar,
+    plant: &PlantResponse,
+    window: &RangeInclusive<DateTime<Utc>>,
+    base_url: &str,
+    locale: CalendarLocale,
+    timing: CareTiming,
+    lead_hours: u32,
+) {
+    for task in &plant.care_tasks {
+        let Some(interval_days) = task.schedule.interval_days.filter(|days| *days > 0) else {
+            continue;
+        };
+
+        // Limit to 100 events per task
+        for next_due in care_occurrences(task.last_done, interval_days, *window.start(), timing)
+            .take_while(|date| window.contains(date))
+            .take(100)
+        {
+            let summary = locale.care_task_summary(task, &plant.name);
+            let event = Event::new()
+                .uid(&format!(
+                    "care-{}-{}-{}",
+                    task.task_type,
+                    plant.id,
+                    next_due.timestamp()
+                ))
+                .summary(&summary)
+                .description(&locale.care_task_description(task, plant, base_url))
+                .starts(next_due)
+                .ends(next_due + Duration::hours(1)) // 1-hour event duration
+                .location(&locale.location(plant))
+                .add_property("CATEGORIES", format!("Plant Care,{}", task.label()))
+                .add_property("PRIORITY", "5")
+                .alarm(reminder(lead_hours, &summary))
+                .done();
+
+            calendar.push(event);
+        }
+    }
+}
+
+/// Generate completed events for a plant's watering and fertilizing entries
+fn generate_history_events(
+    calendar: &mut Calendar,
+    plant: &PlantResponse,
+    history: &[TrackingEntry],
+    base_url: &str,
+    locale: CalendarLocale,
+) {
+    let entries = history.iter().filter(|entry| entry.plant_id == plant.id);
+
+    // Limit to 100 events per plant; history comes newest first
+    for entry in entries.take(100) {
+        let (care, categories) = match entry.entry_type {
+            EntryType::Watering => (CareKind::Watering, "Plant Care,Watering"),
+            EntryType::Fertilizing => (CareKind::Fertilizing, "Plant Care,Fertilizing"),
+            _ => continue,
+        };
+
+        // Past care needs no reminder
+        let event = Event::new()
+            .uid(&format!("entry-{}", entry.id))
+            .summary(&locale.summary(care, &plant.name))
+            .description(&locale.history_description(care, entry, plant, base_url))
+            .starts(entry.timestamp)
+            .ends(entry.timestamp + Duration::hours(1)) // 1-hour event duration
+            .location(&locale.location(plant))
+            .add_property("CATEGORIES", categories)
+            .done();
+
+        calendar.push(event);
+    }
+}
+
 /// Generate a calendar feed URL for a user
 #[allow(dead_code)]
 pub fn generate_calendar_feed_url(base_url: &str, user_id: &str, calendar_token: &str) -> String {
@@ -194,7 +503,7 @@ pub fn generate_calendar_token(user_id: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::plant::PlantResponse;
+    use crate::models::plant::{PlantResponse, PlantStatus};
     use chrono::{Duration, Utc};
     use std::collections::hash_map::DefaultHasher;
     use std::hash::{Hash, Hasher};
@@ -205,6 +514,12 @@ mod tests {
             id: Uuid::new_v4(),
             name: "Test Plant".to_string(),
             genus: "Testicus".to_string(),
+            description: None,
+            acquired_at: None,
+            location_name: None,
+            latitude: None,
+            longitude: None,
+            age_days: None,
             watering_schedule: crate::models::plant::CareSchedule {
                 interval_days: Some(7),
                 amount: None,
@@ -219,9 +534,23 @@ mod tests {
             },
             last_watered: Some(Utc::now()),
             last_fertilized: Some(Utc::now()),
+            reminder_lead_hours: None,
+            quantity: 1,
+            archived_at: None,
+            status: PlantStatus::Active,
+            died_at: None,
+            death_reason: None,
+            last_note_at: None,
+            last_photo_at: None,
+            last_measurement_at: None,
             preview_id: None,
             preview_url: None,
+            thumbnail_url: None,
+            photo_count: 0,
+            latest_photo_id: None,
             custom_metrics: vec![],
+            care_tasks: vec![],
+            tags: vec![],
             created_at: Utc::now(),
             updated_at: Utc::now(),
             user_id: "test-user".to_string(),
@@ -238,6 +567,12 @@ mod tests {
             id: Uuid::new_v4(),
             name: name.to_string(),
             genus: genus.to_string(),
+            description: None,
+            acquired_at: None,
+            location_name: None,
+            latitude: None,
+            longitude: None,
+            age_days: None,
             watering_schedule: crate::models::plant::CareSchedule {
                 interval_days: Some(watering_days),
                 amount: None,
@@ -252,9 +587,23 @@ mod tests {
             },
             last_watered: Some(Utc::now() - Duration::days(watering_days as i64 - 1)),
             last_fertilized: Some(Utc::now() - Duration::days(fertilizing_days as i64 - 1)),
+            reminder_lead_hours: None,
+            quantity: 1,
+            archived_at: None,
+            status: PlantStatus::Active,
+            died_at: None,
+            death_reason: None,
+            last_note_at: None,
+            last_photo_at: None,
+            last_measurement_at: None,
             preview_id: None,
             preview_url: None,
+            thumbnail_url: None,
+            photo_count: 0,
+            latest_photo_id: None,
             custom_metrics: vec![],
+            care_tasks: vec![],
+            tags: vec![],
             created_at: Utc::now(),
             updated_at: Utc::now(),
             user_id: "test-user".to_string(),
@@ -264,7 +613,15 @@ mod tests {
     #[test]
     fn test_generate_plant_calendar() {
         let plants = vec![create_test_plant()];
-        let result = generate_plant_calendar(&plants, "test-user", "https://example.com");
+        let result = generate_plant_calendar(
+            &plants,
+            &[],
+            "test-user",
+            "https://example.com",
+            CalendarLocale::En,
+            CareTiming::default(),
+            1,
+        );
 
         assert!(result.is_ok());
         let calendar_str = result.unwrap();
@@ -281,6 +638,123 @@ mod tests {
         assert!(calendar_str.contains("CATEGORIES:Plant Care\\,Fertilizing"));
     }
 
+    #[test]
+    fn test_history_events_only_when_included() {
+        let plant = create_test_plant();
+        let watered_at = Utc::now() - Duration::days(3);
+        let entry = TrackingEntry {
+            id: Uuid::new_v4(),
+            plant_id: plant.id,
+            entry_type: EntryType::Watering,
+            timestamp: watered_at,
+            value: None,
+            notes: Some("Soaked".to_string()),
+            metric_id: None,
+            photo_ids: None,
+            visibility: Default::default(),
+            created_at: watered_at,
+            updated_at: watered_at,
+        };
+        let uid = format!("UID:entry-{}", entry.id);
+        let generate = |history: &[TrackingEntry]| {
+            generate_plant_calendar(
+                std::slice::from_ref(&plant),
+                history,
+                "test-user",
+                "https://example.com",
+                CalendarLocale::En,
+                CareTiming::default(),
+                1,
+            )
+            .unwrap()
+        };
+
+        let calendar_str = generate(std::slice::from_ref(&entry));
+        let event = calendar_str
+            .split("BEGIN:VEVENT")
+            .find(|event| event.contains(&uid))
+            .expect("past watering should be an event");
+        assert!(event.contains("SUMMARY:💧 Water Test Plant"));
+        assert!(event.contains("DESCRIPTION:✅ Completed: watered"));
+        assert!(event.contains(&format!("DTSTART:{}", watered_at.format("%Y%m%dT%H%M%SZ"))));
+        assert!(!event.contains("BEGIN:VALARM"));
+
+        let calendar_str = generate(&[]);
+        assert!(!calendar_str.contains(&uid));
+        assert!(!calendar_str.contains("Completed"));
+    }
+
+    #[test]
+    fn test_reminder_lead_time_per_plant() {
+        let mut fussy = create_test_plant_with_name("Calathea", "Calathea", 7, 14);
+        fussy.reminder_lead_hours = Some(24);
+        let relaxed = create_test_plant_with_name("Snake Plant", "Sansevieria", 14, 30);
+        let (fussy_id, relaxed_id) = (fussy.id, relaxed.id);
+
+        let calendar_str = generate_plant_calendar(
+            &[fussy, relaxed],
+            &[],
+            "test-user",
+            "https://example.com",
+            CalendarLocale::En,
+            CareTiming::default(),
+            2,
+        )
+        .unwrap();
+
+        let events: Vec<&str> = calendar_str.split("BEGIN:VEVENT").skip(1).collect();
+        let event_count = |plant_id: Uuid| {
+            events
+                .iter()
+                .filter(|event| event.contains(&plant_id.to_string()))
+                .count()
+        };
+        assert!(event_count(fussy_id) > 0);
+        assert!(event_count(relaxed_id) > 0);
+
+        for event in &events {
+            let expected = if event.contains(&fussy_id.to_string()) {
+                "TRIGGER:-PT24H"
+            } else {
+                "TRIGGER:-PT2H"
+            };
+            assert!(event.contains("BEGIN:VALARM"));
+            assert!(event.contains(expected), "{expected} missing from {event}");
+        }
+    }
+
+    #[test]
+    fn test_generate_care_task_events() {
+        let mut plant = create_test_plant();
+        plant.care_tasks.push(crate::models::plant::CareTask {
+            task_type: "misting".to_string(),
+            schedule: crate::models::plant::CareSchedule {
+                interval_days: Some(3),
+                amount: None,
+                unit: None,
+                notes: None,
+            },
+            last_done: Some(Utc::now() - Duration::days(1)),
+        });
+        let plant_id = plant.id;
+
+        let calendar_str = generate_plant_calendar(
+            &[plant],
+            &[],
+            "test-user",
+            "https://example.com",
+            CalendarLocale::En,
+            CareTiming::default(),
+            1,
+        )
+        .unwrap();
+
+        assert!(calendar_str.contains("SUMMARY:🪴 Misting Test Plant"));
+        assert!(calendar_str.contains(&format!("UID:care-misting-{plant_id}-")));
+        assert!(calendar_str.contains("Misting is due for your Test Plant"));
+        assert!(calendar_str.contains("CATEGORIES:Plant Care\\,Misting"));
+    }
+
     #[test]
     fn test_generate_calendar_with_multiple_plants() {
         let plants = vec![
@@ -289,7 +763,15 @@ mod tests {
             create_test_plant_with_name("Pothos", "Epipremnum", 5, 21),
         ];
 
-        let result = generate_plant_calendar(&plants, "test-user", "https://example.com");
+        let result = generate_plant_calendar(
+            &plants,
+            &[],
+            "test-user",
+            "https://example.com",
+            CalendarLocale::En,
+            CareTiming::default(),
+            1,
+        );
         assert!(result.is_ok());
 
         let calendar_str = result.unwrap();
@@ -326,7 +808,15 @@ mod tests {
     #[test]
     fn test_generate_calendar_with_empty_plants() {
         let plants = vec![];
-        let result = generate_plant_calendar(&plants, "test-user", "https://example.com");
+        let result = generate_plant_calendar(
+            &plants,
+            &[],
+            "test-user",
+            "https://example.com",
+            CalendarLocale::En,
+            CareTiming::default(),
+            1,
+        );
 
         assert!(result.is_ok());
         let calendar_str = result.unwrap();
@@ -344,7 +834,15 @@ mod tests {
     #[test]
     fn test_calendar_contains_proper_ical_format() {
         let plants = vec![create_test_plant()];
-        let result = generate_plant_calendar(&plants, "test-user", "https://example.com");
+        let result = generate_plant_calendar(
+            &plants,
+            &[],
+            "test-user",
+            "https://example.com",
+            CalendarLocale::En,
+            CareTiming::default(),
+            1,
+        );
 
         assert!(result.is_ok());
         let calendar_str = result.unwrap();
@@ -363,7 +861,15 @@ mod tests {
     #[test]
     fn test_calendar_events_have_unique_uids() {
         let plants = vec![create_test_plant()];
-        let result = generate_plant_calendar(&plants, "test-user", "https://example.com");
+        let result = generate_plant_calendar(
+            &plants,
+            &[],
+            "test-user",
+            "https://example.com",
+            CalendarLocale::En,
+            CareTiming::default(),
+            1,
+        );
 
         assert!(result.is_ok());
         let calendar_str = result.unwrap();
@@ -384,6 +890,71 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_generate_calendar_in_french() {
+        let plants = vec![create_test_plant()];
+        let calendar_str = generate_plant_calendar(
+            &plants,
+            &[],
+            "test-user",
+            "https://example.com",
+            CalendarLocale::from_param(Some("fr-FR")),
+            CareTiming::default(),
+            1,
+        )
+        .unwrap();
+        // Undo iCalendar line folding so long descriptions can be matched
+        let calendar_str = calendar_str.replace("\r\n ", "");
+
+        assert!(calendar_str.contains("SUMMARY:💧 Arroser Test Plant"));
+        assert!(calendar_str.contains("SUMMARY:🌱 Fertiliser Test Plant"));
+        assert!(calendar_str.contains("Arroser tous les 7 jours."));
+        assert!(!calendar_str.contains("Water every"));
+    }
+
+    #[test]
+    fn test_event_description_includes_amount_unit_and_notes() {
+        let mut plant = create_test_plant();
+        plant.watering_schedule.amount = Some(250.0);
+        plant.watering_schedule.unit = Some("ml".to_string());
+        plant.watering_schedule.notes = Some("Let soil dry first".to_string());
+        plant.fertilizing_schedule.amount = Some(2.5);
+        plant.fertilizing_schedule.unit = Some("ml".to_string());
+        let generate = |locale| {
+            generate_plant_calendar(
+                std::slice::from_ref(&plant),
+                &[],
+                "test-user",
+                "https://example.com",
+                locale,
+                CareTiming::default(),
+                1,
+            )
+            .unwrap()
+            .replace("\r\n ", "")
+        };
+
+        let calendar_str = generate(CalendarLocale::En);
+        assert!(calendar_str.contains(
+            "Amount: 250 ml. Water every 7 days. Notes: Let soil dry first\\n\\nView plant details"
+        ));
+        // Fertilizing has no notes
+        assert!(calendar_str.contains("Amount: 2.5 ml. Fertilize every 14 days.\\n\\n"));
+
+        let calendar_str = generate(CalendarLocale::Fr);
+        assert!(calendar_str
+            .contains("Quantité : 250 ml. Arroser tous les 7 jours. Remarques : Let soil dry first"));
+        assert!(calendar_str.contains("Quantité : 2\\,5 ml."));
+    }
+
+    #[test]
+    fn test_calendar_locale_from_param() {
+        assert_eq!(CalendarLocale::from_param(None), CalendarLocale::En);
+        assert_eq!(CalendarLocale::from_param(Some("fr")), CalendarLocale::Fr);
+        assert_eq!(CalendarLocale::from_param(Some("FR_ca")), CalendarLocale::Fr);
+        assert_eq!(CalendarLocale::from_param(Some("de")), CalendarLocale::En);
+    }
+
     #[test]
     fn test_generate_calendar_token() {
         let token1 = generate_calendar_token("user1");
@@ -443,7 +1014,15 @@ mod tests {
     fn test_calendar_events_contain_plant_links() {
         let plant = create_test_plant_with_name("My Plant", "Planticus", 7, 14);
         let plants = vec![plant];
-        let result = generate_plant_calendar(&plants, "test-user", "https://planttracker.com");
+        let result = generate_plant_calendar(
+            &plants,
+            &[],
+            "test-user",
+            "https://planttracker.com",
+            CalendarLocale::En,
+            CareTiming::default(),
+            1,
+        );
 
         assert!(result.is_ok());
         let calendar_str = result.unwrap();
@@ -456,7 +1035,15 @@ mod tests {
     #[test]
     fn test_calendar_events_within_date_range() {
         let plants = vec![create_test_plant()];
-        let result = generate_plant_calendar(&plants, "test-user", "https://example.com");
+        let result = generate_plant_calendar(
+            &plants,
+            &[],
+            "test-user",
+            "https://example.com",
+            CalendarLocale::En,
+            CareTiming::default(),
+            1,
+        );
 
         assert!(result.is_ok());
         let calendar_str = result.unwrap();
@@ -487,7 +1074,15 @@ mod tests {
         plant.last_fertilized = None;
 
         let plants = vec![plant];
-        let result = generate_plant_calendar(&plants, "test-user", "https://example.com");
+        let result = generate_plant_calendar(
+            &plants,
+            &[],
+            "test-user",
+            "https://example.com",
+            CalendarLocale::En,
+            CareTiming::default(),
+            1,
+        );
 
         assert!(result.is_ok());
         let calendar_str = result.unwrap();
@@ -505,7 +1100,15 @@ mod tests {
             3,
             7,
         )];
-        let result = generate_plant_calendar(&plants, "test-user", "https://example.com");
+        let result = generate_plant_calendar(
+            &plants,
+            &[],
+            "test-user",
+            "https://example.com",
+            CalendarLocale::En,
+            CareTiming::default(),
+            1,
+        );
 
         assert!(result.is_ok());
         let calendar_str = result.unwrap();
@@ -0,0 +1,165 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+
+use crate::models::tracking_entry::{CreateTrackingEntryRequest, EntryType, ImportRowError};
+use crate::utils::errors::AppError;
+
+/// Longest note accepted, matching `CreateTrackingEntryRequest`
+const MAX_NOTES_LENGTH: usize = 1000;
+
+/// Parse a care history CSV with `timestamp,entry_type,notes` columns
+///
+/// Rows that fail validation are reported with their line number instead of
+/// failing the whole import; only a missing or unusable header is an error.
+///
+/// # Errors
+/// * Returns `BadRequest` if the header lacks the `timestamp` or `entry_type` column
+pub fn parse_care_history(
+    csv_data: &str,
+) -> Result<(Vec<CreateTrackingEntryRequest>, Vec<ImportRowError>), AppError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(csv_data.as_bytes());
+
+    let headers = reader.headers().map_err(|e| AppError::BadRequest {
+        message: format!("Invalid CSV header: {e}"),
+    })?;
+    let column = |name: &str| headers.iter().position(|h| h.eq_ignore_ascii_case(name));
+    let (Some(timestamp_col), Some(entry_type_col)) = (column("timestamp"), column("entry_type"))
+    else {
+        return Err(AppError::BadRequest {
+            message: "CSV header must include timestamp and entry_type columns".to_string(),
+        });
+    };
+    let notes_col = column("notes");
+
+    let mut entries = Vec::new();
+    let mut errors = Vec::new();
+
+    for record in reader.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                errors.push(ImportRowError {
+                    line: e.position().map_or(0, csv::Position::line),
+                    reason: format!("Malformed row: {e}"),
+                });
+                continue;
+            }
+        };
+        let line = record.position().map_or(0, csv::Position::line);
+
+        if record.iter().all(str::is_empty) {
+            continue;
+        }
+
+        let parsed =
+            parse_timestamp(record.get(timestamp_col).unwrap_or_default()).and_then(|timestamp| {
+                let entry_type = parse_entry_type(record.get(entry_type_col).unwrap_or_default())?;
+                let notes = parse_notes(notes_col.and_then(|col| record.get(col)))?;
+                Ok(CreateTrackingEntryRequest {
+                    entry_type,
+                    timestamp,
+                    value: None,
+                    notes,
+                    metric_id: None,
+                    photo_ids: None,
+                    visibility: None,
+                })
+            });
+
+        match parsed {
+            Ok(entry) => entries.push(entry),
+            Err(reason) => errors.push(ImportRowError { line, reason }),
+        }
+    }
+
+    Ok((entries, errors))
+}
+
+/// Accepts RFC 3339, `YYYY-MM-DD HH:MM[:SS]` (UTC) or a bare `YYYY-MM-DD` date
+fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, String> {
+    if value.is_empty() {
+        return Err("Missing timestamp".to_string());
+    }
+
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Ok(timestamp.with_timezone(&Utc));
+    }
+
+    for format in [
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M",
+    ] {
+        if let Ok(timestamp) = NaiveDateTime::parse_from_str(value, format) {
+            return Ok(timestamp.and_utc());
+        }
+    }
+
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|timestamp| timestamp.and_utc())
+        .ok_or_else(|| format!("Invalid timestamp '{value}'"))
+}
+
+/// Only entry types that need no extra data can be imported
+fn parse_entry_type(value: &str) -> Result<EntryType, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "watering" => Ok(EntryType::Watering),
+        "fertilizing" => Ok(EntryType::Fertilizing),
+        "note" => Ok(EntryType::Note),
+        "" => Err("Missing entry_type".to_string()),
+        _ => Err(format!(
+            "Unsupported entry_type '{value}' (expected watering, fertilizing or note)"
+        )),
+    }
+}
+
+fn parse_notes(value: Option<&str>) -> Result<Option<String>, String> {
+    match value {
+        None | Some("") => Ok(None),
+        Some(notes) if notes.chars().count() > MAX_NOTES_LENGTH => Err(format!(
+            "Notes are longer than {MAX_NOTES_LENGTH} characters"
+        )),
+        Some(notes) => Ok(Some(notes.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_care_history_reports_bad_rows() {
+        let csv = "timestamp,entry_type,notes\n\
+                   2024-03-01T08:00:00Z,watering,\"Soaked, then drained\"\n\
+                   2024-03-02,fertilizing,\n\
+                   yesterday,watering,\n\
+                   2024-03-03 09:30,repotting,\n";
+
+        let (entries, errors) = parse_care_history(csv).unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert!(matches!(entries[0].entry_type, EntryType::Watering));
+        assert_eq!(entries[0].notes.as_deref(), Some("Soaked, then drained"));
+        assert_eq!(
+            entries[1].timestamp,
+            "2024-03-02T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].line, 4);
+        assert!(errors[0].reason.contains("Invalid timestamp"));
+        assert_eq!(errors[1].line, 5);
+        assert!(errors[1].reason.contains("Unsupported entry_type"));
+    }
+
+    #[test]
+    fn test_parse_care_history_requires_columns() {
+        let result = parse_care_history("date,kind\n2024-03-01,watering\n");
+        assert!(matches!(result, Err(AppError::BadRequest { .. })));
+    }
+}
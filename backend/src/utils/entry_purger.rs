@@ -0,0 +1,41 @@
+use chrono::Utc;
+use tokio::time::Duration;
+
+use crate::database::{tracking, DatabasePool};
+use crate::utils::job_registry::JobRegistry;
+
+/// Name the purger reports under in the job registry
+pub const JOB_NAME: &str = "entry_purger";
+
+/// How often expired soft-deleted entries are purged
+const PURGE_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Start a background task that hard-deletes tracking entries once their
+/// restore window has passed.
+pub fn start_entry_purger(pool: DatabasePool, registry: JobRegistry) {
+    tracing::info!(
+        "Starting deleted entry purger (restore window: {} days)",
+        tracking::ENTRY_RESTORE_WINDOW_DAYS
+    );
+
+    registry.spawn_periodic(JOB_NAME, PURGE_INTERVAL, move || {
+        let pool = pool.clone();
+        async move {
+            let cutoff = Utc::now() - chrono::Duration::days(tracking::ENTRY_RESTORE_WINDOW_DAYS);
+            match tracking::purge_deleted_entries_before(&pool, cutoff).await {
+                Ok(0) => {
+                    tracing::debug!("No deleted entries to purge");
+                    Ok(())
+                }
+                Ok(count) => {
+                    tracing::info!("Purged {} deleted tracking entries", count);
+                    Ok(())
+                }
+                Err(e) => {
+                    tracing::error!("Failed to purge deleted tracking entries: {}", e);
+                    Err(e.to_string())
+                }
+            }
+        }
+    });
+}
@@ -1,5 +1,5 @@
 use axum::{
-    http::StatusCode,
+    http::{header, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
@@ -17,6 +17,10 @@ pub enum AppError {
     JsonRejection(#[from] axum::extract::rejection::JsonRejection),
     #[error("Database error: {0}")]
     Database(#[from] sqlx::Error),
+    #[error("Bad request: {message}")]
+    BadRequest { message: String },
+    #[error("Unknown fields: {}", fields.join(", "))]
+    UnknownFields { fields: Vec<String> },
     #[error("Authentication error: {message}")]
     Authentication { message: String },
     #[error("Authorization error: {message}")]
@@ -27,6 +31,8 @@ pub enum AppError {
     Internal { message: String },
     #[error("External service error: {message}")]
     External { message: String },
+    #[error("Server busy: {message}")]
+    Busy { message: String },
     #[error("Configuration error: {message}")]
     Configuration { message: String },
     #[error("IO error: {0}")]
@@ -77,6 +83,15 @@ impl IntoResponse for AppError {
                     Some(serde_json::json!({ "details": rejection.to_string() })),
                 )
             }
+            Self::Database(db_error) if self.is_database_busy() => {
+                tracing::warn!("Database busy: {}", db_error);
+                (
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    "database_busy",
+                    "The database is busy, please try again shortly",
+                    None,
+                )
+            }
             Self::Database(db_error) => {
                 tracing::error!("Database error: {}", db_error);
                 (
@@ -86,6 +101,18 @@ impl IntoResponse for AppError {
                     None,
                 )
             }
+            Self::BadRequest { message } => (
+                StatusCode::BAD_REQUEST,
+                "bad_request",
+                message.as_str(),
+                None,
+            ),
+            Self::UnknownFields { fields } => (
+                StatusCode::BAD_REQUEST,
+                "unknown_fields",
+                "Request body has fields this endpoint doesn't accept",
+                Some(serde_json::json!({ "fields": fields })),
+            ),
             Self::Authentication { message } => (
                 StatusCode::UNAUTHORIZED,
                 "authentication_error",
@@ -119,6 +146,15 @@ impl IntoResponse for AppError {
                     None,
                 )
             }
+            Self::Busy { message } => {
+                tracing::warn!("Server busy: {}", message);
+                (
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    "server_busy",
+                    message.as_str(),
+                    None,
+                )
+            }
             Self::Configuration { message } => {
                 tracing::error!("Configuration error: {}", message);
                 (
@@ -164,7 +200,98 @@ impl IntoResponse for AppError {
             details,
         });
 
-        (status, body).into_response()
+        let retry_after = match &self {
+            Self::Busy { .. } => Some(SERVER_BUSY_RETRY_AFTER_SECS),
+            _ if status == StatusCode::SERVICE_UNAVAILABLE => Some(DATABASE_BUSY_RETRY_AFTER_SECS),
+            _ => None,
+        };
+        let mut response = (status, body).into_response();
+        if let Some(retry_after) = retry_after {
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from_static(retry_after));
+        }
+        response
+    }
+}
+
+/// Seconds a client should wait before retrying after a busy database
+const DATABASE_BUSY_RETRY_AFTER_SECS: &str = "1";
+
+/// Seconds a client should wait before retrying work the server had no room for
+const SERVER_BUSY_RETRY_AFTER_SECS: &str = "5";
+
+/// Primary SQLite result codes for a database held by another connection
+const SQLITE_BUSY: i32 = 5;
+const SQLITE_LOCKED: i32 = 6;
+
+impl AppError {
+    /// Whether SQLite rejected the query because another connection holds
+    /// the lock (`SQLITE_BUSY` or `SQLITE_LOCKED`, including extended codes)
+    pub fn is_database_busy(&self) -> bool {
+        let Self::Database(sqlx::Error::Database(error)) = self else {
+            return false;
+        };
+        error
+            .code()
+            .and_then(|code| code.parse::<i32>().ok())
+            .is_some_and(|code| matches!(code & 0xff, SQLITE_BUSY | SQLITE_LOCKED))
+    }
+
+    /// Missing plant. Also returned for another user's plant, so the response
+    /// is identical whether or not the id exists.
+    pub fn plant_not_found() -> Self {
+        Self::NotFound {
+            resource: "Plant".to_string(),
+        }
+    }
+
+    /// Missing tracking entry, or one on a plant the caller can't see
+    pub fn tracking_entry_not_found() -> Self {
+        Self::NotFound {
+            resource: "Tracking entry".to_string(),
+        }
+    }
+
+    /// Missing photo, or one on a plant the caller can't see
+    pub fn photo_not_found() -> Self {
+        Self::NotFound {
+            resource: "Photo".to_string(),
+        }
+    }
+
+    /// Missing user
+    pub fn user_not_found() -> Self {
+        Self::NotFound {
+            resource: "User".to_string(),
+        }
+    }
+
+    /// The user hasn't configured a webhook
+    pub fn webhook_not_found() -> Self {
+        Self::NotFound {
+            resource: "Webhook".to_string(),
+        }
+    }
+
+    /// Missing API key, or one belonging to another user
+    pub fn api_key_not_found() -> Self {
+        Self::NotFound {
+            resource: "API key".to_string(),
+        }
+    }
+
+    /// Unknown recommendation key, or nothing currently recommended for it
+    pub fn recommendation_not_found() -> Self {
+        Self::NotFound {
+            resource: "Recommendation".to_string(),
+        }
+    }
+
+    pub fn public_link_not_found() -> Self {
+        Self::NotFound {
+            resource: "Public link".to_string(),
+        }
     }
 }
 
@@ -249,6 +376,25 @@ mod tests {
         assert!(json["details"].is_null());
     }
 
+    #[tokio::test]
+    async fn test_bad_request_error_response() {
+        let error = AppError::BadRequest {
+            message: "No updates provided".to_string(),
+        };
+        let response = error.into_response();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(json["error"], "bad_request");
+        assert_eq!(json["message"], "No updates provided");
+        assert!(json["details"].is_null());
+    }
+
     #[tokio::test]
     async fn test_authentication_error_response() {
         let error = AppError::Authentication {
@@ -325,6 +471,25 @@ mod tests {
         assert!(json["details"].is_null());
     }
 
+    #[tokio::test]
+    async fn test_busy_error_response() {
+        let error = AppError::Busy {
+            message: "Too many photos are being processed".to_string(),
+        };
+        let response = error.into_response();
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.headers()[header::RETRY_AFTER], "5");
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(json["error"], "server_busy");
+        assert_eq!(json["message"], "Too many photos are being processed");
+    }
+
     #[test]
     fn test_error_display() {
         let error = AppError::Authentication {
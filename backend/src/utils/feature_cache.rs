@@ -0,0 +1,67 @@
+use std::future::Future;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use crate::models::features::FeatureFlags;
+use crate::utils::errors::Result;
+
+/// How long resolved feature flags are served before they are derived again
+const FEATURE_FLAGS_TTL: Duration = Duration::from_secs(30);
+
+/// The server's feature flags, remembered for a short while since every
+/// frontend load asks for them
+#[derive(Debug, Clone)]
+pub struct FeatureCache {
+    ttl: Duration,
+    flags: Arc<RwLock<Option<(FeatureFlags, Instant)>>>,
+}
+
+impl Default for FeatureCache {
+    fn default() -> Self {
+        Self::with_ttl(FEATURE_FLAGS_TTL)
+    }
+}
+
+impl FeatureCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_ttl(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            flags: Arc::default(),
+        }
+    }
+
+    /// The cached flags, or else the ones `resolve` derives, which are then
+    /// cached. Failed lookups aren't cached.
+    pub async fn get_or_resolve<F, Fut>(&self, resolve: F) -> Result<FeatureFlags>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<FeatureFlags>>,
+    {
+        if let Some(flags) = self.cached() {
+            return Ok(flags);
+        }
+
+        let flags = resolve().await?;
+        let mut cached = self.flags.write().unwrap_or_else(|e| e.into_inner());
+        *cached = Some((flags.clone(), Instant::now()));
+        Ok(flags)
+    }
+
+    /// Forget the cached flags, e.g. when an admin changes a setting they use
+    pub fn forget(&self) {
+        let mut cached = self.flags.write().unwrap_or_else(|e| e.into_inner());
+        *cached = None;
+    }
+
+    fn cached(&self) -> Option<FeatureFlags> {
+        let cached = self.flags.read().unwrap_or_else(|e| e.into_inner());
+        cached
+            .as_ref()
+            .filter(|(_, resolved_at)| resolved_at.elapsed() < self.ttl)
+            .map(|(flags, _)| flags.clone())
+    }
+}
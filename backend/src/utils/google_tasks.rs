@@ -4,43 +4,113 @@ use serde_json::Value;
 use crate::database::google_oauth;
 use crate::database::DatabasePool;
 use crate::models::plant::PlantResponse;
-use crate::models::google_oauth::GoogleOAuthToken;
+use crate::models::google_oauth::{GoogleOAuthToken, GoogleTaskList};
 use crate::utils::errors::{AppError, Result};
 
+/// Google's OAuth token endpoint
+pub const GOOGLE_TOKEN_URL: &str = "https://oauth2.googleapis.com/token";
+
+/// Google's OpenID Connect userinfo endpoint, used to learn the connected account
+pub const GOOGLE_USERINFO_URL: &str = "https://openidconnect.googleapis.com/v1/userinfo";
+
+/// Title of the task list plant care tasks go into unless the user picks another
+const PLANT_CARE_LIST_TITLE: &str = "Plant Care";
+
+/// Scopes requested when connecting: Tasks access plus the account's email
+const OAUTH_SCOPES: &str = "https://www.googleapis.com/auth/tasks email";
+
+/// The account lookup is optional, so don't let it hold up connecting
+const USERINFO_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Default minutes before expiry at which access tokens are refreshed
+const DEFAULT_REFRESH_MARGIN_MINUTES: i64 = 5;
+/// Google access tokens live for an hour, so a larger margin would refresh constantly
+const MAX_REFRESH_MARGIN_MINUTES: i64 = 55;
+/// Default longest the refresh scheduler sleeps between checks
+const DEFAULT_REFRESH_POLL_MINUTES: i64 = 60;
+const MAX_REFRESH_POLL_MINUTES: i64 = 24 * 60;
+
 /// Configuration for Google Tasks API
 #[derive(Debug, Clone)]
 pub struct GoogleTasksConfig {
     pub client_id: String,
     pub client_secret: String,
     pub redirect_uri: String,
+    /// Endpoint used for code exchange and token refresh
+    pub token_url: String,
+    /// Endpoint queried for the connected account's email
+    pub userinfo_url: String,
+    /// Tokens expiring within this margin are refreshed, both on use and by the scheduler
+    pub refresh_margin: Duration,
+    /// Longest the token refresh scheduler sleeps between checks
+    pub refresh_poll_interval: Duration,
 }
 
 impl GoogleTasksConfig {
-    pub fn from_env() -> Result<Self> {
-        let client_id = std::env::var("GOOGLE_CLIENT_ID")
-            .map_err(|_| AppError::Configuration {
-                message: "GOOGLE_CLIENT_ID environment variable not set".to_string(),
-            })?;
-        
-        let client_secret = std::env::var("GOOGLE_CLIENT_SECRET")
-            .map_err(|_| AppError::Configuration {
-                message: "GOOGLE_CLIENT_SECRET environment variable not set".to_string(),
-            })?;
-        
-        let redirect_uri = std::env::var("GOOGLE_REDIRECT_URI")
-            .unwrap_or_else(|_| {
-                let host_ip = std::env::var("HOST_IP").unwrap_or_else(|_| "localhost".to_string());
-                format!("http://{}:3000/api/v1/google-tasks/callback", host_ip)
-            });
-        
-        Ok(Self {
+    /// Read the Google settings through `var`, as part of loading `AppConfig`
+    ///
+    /// Returns `None` unless both `GOOGLE_CLIENT_ID` and `GOOGLE_CLIENT_SECRET`
+    /// are set; the refresh intervals are validated either way.
+    pub fn from_lookup(var: &impl Fn(&str) -> Option<String>, host_ip: &str) -> Result<Option<Self>> {
+        let refresh_margin = parse_minutes(
+            "GOOGLE_TOKEN_REFRESH_MARGIN_MINUTES",
+            var("GOOGLE_TOKEN_REFRESH_MARGIN_MINUTES").as_deref(),
+            DEFAULT_REFRESH_MARGIN_MINUTES,
+            MAX_REFRESH_MARGIN_MINUTES,
+        )?;
+
+        let refresh_poll_interval = parse_minutes(
+            "GOOGLE_TOKEN_REFRESH_POLL_MINUTES",
+            var("GOOGLE_TOKEN_REFRESH_POLL_MINUTES").as_deref(),
+            DEFAULT_REFRESH_POLL_MINUTES,
+            MAX_REFRESH_POLL_MINUTES,
+        )?;
+
+        let (Some(client_id), Some(client_secret)) =
+            (var("GOOGLE_CLIENT_ID"), var("GOOGLE_CLIENT_SECRET"))
+        else {
+            return Ok(None);
+        };
+
+        let redirect_uri = var("GOOGLE_REDIRECT_URI").unwrap_or_else(|| {
+            format!("http://{}:3000/api/v1/google-tasks/callback", host_ip)
+        });
+
+        Ok(Some(Self {
             client_id,
             client_secret,
             redirect_uri,
-        })
+            token_url: var("GOOGLE_TOKEN_URL").unwrap_or_else(|| GOOGLE_TOKEN_URL.to_string()),
+            userinfo_url: var("GOOGLE_USERINFO_URL")
+                .unwrap_or_else(|| GOOGLE_USERINFO_URL.to_string()),
+            refresh_margin,
+            refresh_poll_interval,
+        }))
+    }
+
+    /// Whether a token expiring at `expires_at` is due for a refresh
+    pub fn needs_refresh(&self, expires_at: DateTime<Utc>) -> bool {
+        expires_at < Utc::now() + self.refresh_margin
     }
 }
 
+/// Parse a whole number of minutes for `name`, which must be between 1 and `max`
+fn parse_minutes(name: &str, value: Option<&str>, default: i64, max: i64) -> Result<Duration> {
+    let minutes = match value {
+        None => default,
+        Some(value) => value
+            .trim()
+            .parse::<i64>()
+            .ok()
+            .filter(|minutes| (1..=max).contains(minutes))
+            .ok_or_else(|| AppError::Configuration {
+                message: format!("{} must be a number of minutes between 1 and {}", name, max),
+            })?,
+    };
+
+    Ok(Duration::minutes(minutes))
+}
+
 /// Create an HTTP client for Google Tasks API calls
 async fn create_http_client() -> Result<reqwest::Client> {
     let client = reqwest::Client::new();
@@ -49,7 +119,7 @@ async fn create_http_client() -> Result<reqwest::Client> {
 
 /// Generate Google OAuth authorization URL
 pub fn generate_auth_url(config: &GoogleTasksConfig, state: &str) -> String {
-    let scope = "https://www.googleapis.com/auth/tasks";
+    let scope = OAUTH_SCOPES;
     
     format!(
         "https://accounts.google.com/o/oauth2/auth?\
@@ -83,7 +153,7 @@ pub async fn exchange_code_for_tokens(
     ];
     
     let response = client
-        .post("https://oauth2.googleapis.com/token")
+        .post(&config.token_url)
         .form(&params)
         .send()
         .await
@@ -140,6 +210,34 @@ pub async fn exchange_code_for_tokens(
     Ok((access_token, refresh_token, expires_at))
 }
 
+/// Email of the Google account an access token belongs to
+///
+/// Best effort: returns `None` if the lookup fails or the token lacks the
+/// `email` scope, e.g. for connections made before it was requested.
+pub async fn fetch_account_email(config: &GoogleTasksConfig, access_token: &str) -> Option<String> {
+    let client = create_http_client().await.ok()?;
+
+    let response = client
+        .get(&config.userinfo_url)
+        .bearer_auth(access_token)
+        .timeout(USERINFO_TIMEOUT)
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status);
+    let userinfo: Value = match response {
+        Ok(response) => response.json().await.ok()?,
+        Err(e) => {
+            tracing::warn!("Failed to look up Google account email: {}", e);
+            return None;
+        }
+    };
+
+    userinfo
+        .get("email")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
+
 /// Refresh an access token using the refresh token
 pub async fn refresh_access_token(
     config: &GoogleTasksConfig,
@@ -155,7 +253,7 @@ pub async fn refresh_access_token(
     ];
     
     let response = client
-        .post("https://oauth2.googleapis.com/token")
+        .post(&config.token_url)
         .form(&params)
         .send()
         .await
@@ -176,6 +274,19 @@ pub async fn refresh_access_token(
             }
         })?;
     
+    // A revoked or expired refresh token comes back as an OAuth error
+    if let Some(error) = token_response.get("error") {
+        let error_description = token_response
+            .get("error_description")
+            .and_then(|v| v.as_str())
+            .unwrap_or("Unknown error");
+        
+        tracing::error!("OAuth refresh error: {} - {}", error, error_description);
+        return Err(AppError::Authentication {
+            message: format!("OAuth error: {}", error_description),
+        });
+    }
+    
     let access_token = token_response
         .get("access_token")
         .and_then(|v| v.as_str())
@@ -205,12 +316,10 @@ pub async fn ensure_valid_token(
             message: "No Google Tasks connection found".to_string(),
         })?;
     
-    // Check if token is expired or expires soon (within 5 minutes)
-    let needs_refresh = if let Some(expires_at) = token.expires_at {
-        expires_at < Utc::now() + Duration::minutes(5)
-    } else {
-        false
-    };
+    // Check if token is expired or expires within the configured margin
+    let needs_refresh = token
+        .expires_at
+        .is_some_and(|expires_at| config.needs_refresh(expires_at));
     
     if needs_refresh {
         if let Some(refresh_token) = &token.refresh_token {
@@ -237,15 +346,44 @@ pub async fn ensure_valid_token(
     Ok(token)
 }
 
+/// What [`create_plant_care_task`] did for one care slot
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyncedTask {
+    /// A new Google task was created with this id
+    Created(String),
+    /// A task synced earlier already covers the slot
+    Skipped(String),
+}
+
 /// Create a task for plant care using Google Tasks API
+///
+/// A plant gets at most one task per task list, care type and due date: slots
+/// synced into the list before are skipped, so syncing again only fills in
+/// missing tasks.
 pub async fn create_plant_care_task(
+    pool: &DatabasePool,
     token: &GoogleOAuthToken,
     plant: &PlantResponse,
-    task_type: &str, // "watering" or "fertilizing"
+    task_type: &str, // "watering", "fertilizing" or one of the plant's care tasks
     due_time: DateTime<Utc>,
     base_url: &str,
     task_list_id: &str,
-) -> Result<String> {
+) -> Result<SyncedTask> {
+    let plant_id = plant.id.to_string();
+    let due_date = due_time.date_naive();
+    if let Some(task_id) = google_oauth::get_synced_task_id(
+        pool,
+        &token.user_id,
+        task_list_id,
+        &plant_id,
+        task_type,
+        due_date,
+    )
+    .await?
+    {
+        return Ok(SyncedTask::Skipped(task_id));
+    }
+
     let (title, notes) = match task_type {
         "watering" => {
             let interval_days = plant.watering_schedule.interval_days.unwrap_or(0);
@@ -279,9 +417,28 @@ pub async fn create_plant_care_task(
                 ),
             )
         },
-        _ => return Err(AppError::Internal {
-            message: "Invalid task type".to_string(),
-        }),
+        _ => {
+            let Some(task) = plant.care_tasks.iter().find(|t| t.task_type == task_type) else {
+                return Err(AppError::Internal {
+                    message: "Invalid task type".to_string(),
+                });
+            };
+            let label = task.label();
+            (
+                format!("🪴 {} {}", label, plant.name),
+                format!(
+                    "{} is due for your {} ({}).{}{} Every {} days.\n\nView plant details: {}/plants/{}",
+                    label,
+                    plant.name,
+                    plant.genus,
+                    task.schedule.amount.map_or("".to_string(), |amt| format!(" Amount: {}", amt)),
+                    task.schedule.unit.as_ref().map_or("".to_string(), |unit| format!(" {}", unit)),
+                    task.schedule.interval_days.unwrap_or(0),
+                    base_url,
+                    plant.id
+                ),
+            )
+        }
     };
     
     let client = create_http_client().await?;
@@ -326,15 +483,25 @@ pub async fn create_plant_care_task(
         message: "No task ID returned from Google Tasks".to_string(),
     })?.to_string();
     
+    google_oauth::record_synced_task(
+        pool,
+        &token.user_id,
+        task_list_id,
+        &plant_id,
+        task_type,
+        due_date,
+        &task_id,
+    )
+    .await?;
+
     tracing::info!("Created {} task for plant {}: {}", task_type, plant.name, task_id);
-    Ok(task_id)
+    Ok(SyncedTask::Created(task_id))
 }
 
-/// Get or create a task list for plant care
-pub async fn get_or_create_plant_care_task_list(token: &GoogleOAuthToken) -> Result<String> {
+/// The user's Google Tasks lists
+pub async fn list_task_lists(token: &GoogleOAuthToken) -> Result<Vec<GoogleTaskList>> {
     let client = create_http_client().await?;
-    
-    // First, try to find existing "Plant Care" task list
+
     let response = client
         .get("https://tasks.googleapis.com/tasks/v1/users/@me/lists")
         .header("Authorization", format!("Bearer {}", token.access_token))
@@ -346,7 +513,7 @@ pub async fn get_or_create_plant_care_task_list(token: &GoogleOAuthToken) -> Res
                 message: "Failed to get Google Task lists".to_string(),
             }
         })?;
-    
+
     if !response.status().is_success() {
         let error_text = response.text().await.unwrap_or_default();
         tracing::error!("Google Tasks API error: {}", error_text);
@@ -354,31 +521,45 @@ pub async fn get_or_create_plant_care_task_list(token: &GoogleOAuthToken) -> Res
             message: "Google Tasks API request failed".to_string(),
         });
     }
-    
+
     let result: Value = response.json().await.map_err(|e| {
         tracing::error!("Failed to parse Google Tasks response: {}", e);
         AppError::External {
             message: "Invalid response from Google Tasks".to_string(),
         }
     })?;
-    
-    // Look for existing "Plant Care" task list
-    if let Some(items) = result["items"].as_array() {
-        for item in items {
-            if let Some(title) = item["title"].as_str() {
-                if title == "Plant Care" {
-                    if let Some(id) = item["id"].as_str() {
-                        tracing::info!("Found existing Plant Care task list: {}", id);
-                        return Ok(id.to_string());
-                    }
-                }
-            }
-        }
+
+    Ok(parse_task_lists(&result))
+}
+
+/// Task lists in a `tasklists.list` response, skipping entries without an id or title
+fn parse_task_lists(result: &Value) -> Vec<GoogleTaskList> {
+    result["items"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|item| {
+            Some(GoogleTaskList {
+                id: item["id"].as_str()?.to_string(),
+                title: item["title"].as_str()?.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Get or create a task list for plant care
+pub async fn get_or_create_plant_care_task_list(token: &GoogleOAuthToken) -> Result<String> {
+    // First, try to find existing "Plant Care" task list
+    let lists = list_task_lists(token).await?;
+    if let Some(list) = lists.iter().find(|list| list.title == PLANT_CARE_LIST_TITLE) {
+        tracing::info!("Found existing Plant Care task list: {}", list.id);
+        return Ok(list.id.clone());
     }
     
     // Create new task list if not found
+    let client = create_http_client().await?;
     let task_list_data = serde_json::json!({
-        "title": "Plant Care"
+        "title": PLANT_CARE_LIST_TITLE
     });
     
     let response = client
@@ -426,4 +607,45 @@ pub fn generate_oauth_state() -> String {
     let mut hasher = DefaultHasher::new();
     Utc::now().timestamp_nanos_opt().unwrap_or(0).hash(&mut hasher);
     format!("{:x}", hasher.finish())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_minutes_defaults_and_validates() {
+        let parse = |value| parse_minutes("TEST_MINUTES", value, 5, 55);
+
+        assert_eq!(parse(None).unwrap(), Duration::minutes(5));
+        assert_eq!(parse(Some(" 12 ")).unwrap(), Duration::minutes(12));
+        assert_eq!(parse(Some("55")).unwrap(), Duration::minutes(55));
+
+        for invalid in ["0", "-3", "56", "ten", ""] {
+            assert!(matches!(
+                parse(Some(invalid)),
+                Err(AppError::Configuration { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_parse_task_lists() {
+        let result = serde_json::json!({
+            "kind": "tasks#taskLists",
+            "items": [
+                { "kind": "tasks#taskList", "id": "list-1", "title": "My Tasks" },
+                { "kind": "tasks#taskList", "id": "list-2", "title": "Plant Care" },
+                { "kind": "tasks#taskList", "title": "No id" }
+            ]
+        });
+
+        let lists = parse_task_lists(&result);
+        assert_eq!(lists.len(), 2);
+        assert_eq!(lists[0].id, "list-1");
+        assert_eq!(lists[0].title, "My Tasks");
+        assert_eq!(lists[1].title, PLANT_CARE_LIST_TITLE);
+
+        assert!(parse_task_lists(&serde_json::json!({ "kind": "tasks#taskLists" })).is_empty());
+    }
+}
@@ -0,0 +1,33 @@
+use axum::http::{header, HeaderMap};
+
+/// Whether an `If-None-Match` header lists `etag` (or `*`)
+pub fn etag_matches(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|tag| tag.trim())
+        .any(|tag| tag == "*" || tag.strip_prefix("W/").unwrap_or(tag) == etag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn if_none_match(value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_NONE_MATCH, HeaderValue::from_static(value));
+        headers
+    }
+
+    #[test]
+    fn test_etag_matches_any_listed_tag() {
+        assert!(etag_matches(&if_none_match("\"a\", \"b\""), "\"b\""));
+        assert!(etag_matches(&if_none_match("W/\"a\""), "\"a\""));
+        assert!(etag_matches(&if_none_match("*"), "\"a\""));
+        assert!(!etag_matches(&if_none_match("\"a\""), "\"b\""));
+        assert!(!etag_matches(&HeaderMap::new(), "\"a\""));
+    }
+}
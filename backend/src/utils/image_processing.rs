@@ -1,9 +1,80 @@
 use anyhow::{Context, Result};
 use image::codecs::avif::AvifEncoder;
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::webp::WebPEncoder;
 use image::{ColorType, DynamicImage, ImageEncoder, ImageFormat};
 
+use crate::utils::image_workers::ImageWorkers;
+
 /// Maximum dimensions for image processing (4K-ish resolution)
-const MAX_DIMENSION: u32 = 3840; // 4K width/height
+pub const MAX_DIMENSION: u32 = 3840; // 4K width/height
+
+/// Width of generated thumbnails; height follows the aspect ratio
+pub const THUMBNAIL_WIDTH: u32 = 256;
+
+/// Whether this build can decode AVIF, which needs the `avif-decoder` feature
+pub const AVIF_DECODING: bool = cfg!(feature = "avif-decoder");
+
+/// How uploads exceeding the maximum dimension are handled, configured via `IMAGE_OVERSIZE`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OversizeMode {
+    /// Scale the image down to fit within the maximum dimension
+    #[default]
+    Downscale,
+    /// Reject the upload with an `ImageTooLarge` error
+    Reject,
+}
+
+/// Returned when an image exceeds the maximum dimension and `OversizeMode::Reject` is active
+#[derive(Debug, thiserror::Error)]
+#[error("Image is {width}x{height}, which exceeds the maximum dimension of {max}px")]
+pub struct ImageTooLarge {
+    pub width: u32,
+    pub height: u32,
+    pub max: u32,
+}
+
+/// How uploaded photos are processed and kept, from `AppConfig`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageSettings {
+    pub oversize_mode: OversizeMode,
+    /// Longest side a stored photo may have, at most `MAX_DIMENSION`
+    pub max_dimension: u32,
+    /// Keep the uploaded bytes so the photo can be reprocessed at new settings
+    pub keep_originals: bool,
+}
+
+impl Default for ImageSettings {
+    fn default() -> Self {
+        Self {
+            oversize_mode: OversizeMode::default(),
+            max_dimension: MAX_DIMENSION,
+            keep_originals: false,
+        }
+    }
+}
+
+/// Returned when uploaded bytes can't be used as the claimed image type
+#[derive(Debug, thiserror::Error)]
+pub enum InvalidImage {
+    #[error("Unsupported image format: {content_type}")]
+    UnsupportedFormat { content_type: String },
+    #[error("Image data could not be decoded as {content_type}: {reason}")]
+    Decode {
+        content_type: String,
+        reason: String,
+    },
+}
+
+impl InvalidImage {
+    /// Validation error code reported for the upload
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::UnsupportedFormat { .. } => "unsupported_format",
+            Self::Decode { .. } => "decode_failed",
+        }
+    }
+}
 
 /// Processed image result containing the optimized AVIF data and metadata
 #[derive(Debug)]
@@ -16,57 +87,244 @@ pub struct ProcessedImage {
     pub height: u32,
     /// Content type (always "image/avif")
     pub content_type: String,
+    /// `THUMBNAIL_WIDTH`-wide AVIF for photo grids
+    pub thumbnail: Vec<u8>,
 }
 
-/// Process an uploaded image by converting to AVIF and optionally cropping to 4K
+/// Formats a stored AVIF photo can be served in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServeFormat {
+    Avif,
+    WebP,
+    Jpeg,
+}
+
+impl ServeFormat {
+    pub const fn content_type(self) -> &'static str {
+        match self {
+            Self::Avif => "image/avif",
+            Self::WebP => "image/webp",
+            Self::Jpeg => "image/jpeg",
+        }
+    }
+
+    /// Key used for cached renditions
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Avif => "avif",
+            Self::WebP => "webp",
+            Self::Jpeg => "jpeg",
+        }
+    }
+
+    /// Pick a format from the request's `Accept` header.
+    ///
+    /// Explicitly listed AVIF or WebP wins. Clients that list specific image
+    /// types but neither of those (older browsers) get `fallback`; requests
+    /// with no header or only wildcards, like most API clients, get the stored AVIF.
+    pub fn negotiate(accept: Option<&str>, fallback: Self) -> Self {
+        let Some(accept) = accept else {
+            return Self::Avif;
+        };
+
+        let accepted: Vec<String> = accept
+            .split(',')
+            .filter_map(|range| {
+                let mut parts = range.split(';').map(str::trim);
+                let media_type = parts.next()?.to_ascii_lowercase();
+                let rejected = parts.any(|param| {
+                    param
+                        .strip_prefix("q=")
+                        .and_then(|q| q.parse::<f32>().ok())
+                        .is_some_and(|q| q <= 0.0)
+                });
+                (!rejected).then_some(media_type)
+            })
+            .collect();
+
+        if accepted.iter().any(|media_type| media_type == "image/avif") {
+            Self::Avif
+        } else if accepted.iter().any(|media_type| media_type == "image/webp") {
+            Self::WebP
+        } else if accepted
+            .iter()
+            .any(|media_type| media_type.starts_with("image/") && media_type != "image/*")
+        {
+            fallback
+        } else {
+            Self::Avif
+        }
+    }
+}
+
+/// Process an uploaded image by converting to AVIF and optionally downscaling it
 ///
-/// This function offloads CPU-intensive image processing to a blocking thread pool
-/// to avoid blocking the async runtime during heavy image operations.
+/// The CPU-intensive work runs on `workers`, which bounds how many images are
+/// processed at once and keeps it off the async runtime.
 ///
 /// # Arguments
+/// * `workers` - Pool the processing runs on
 /// * `image_data` - Raw image bytes from upload
 /// * `content_type` - Original content type for format detection
+/// * `oversize_mode` - Whether images larger than `max_dimension` are downscaled or rejected
+/// * `max_dimension` - Longest side the processed image may have
 ///
 /// # Returns
 /// * `ProcessedImage` - Optimized AVIF image with metadata
 ///
 /// # Errors
-/// * Returns error if image format is unsupported
-/// * Returns error if image processing fails
+/// * Returns `ImageWorkersBusy` if too many images are already waiting for `workers`
+/// * Returns `InvalidImage` if the format is unsupported or the data doesn't decode as it
+/// * Returns `ImageTooLarge` if the image exceeds `max_dimension` in `OversizeMode::Reject`
 /// * Returns error if AVIF encoding fails
-pub async fn process_uploaded_image(
+pub async fn process_uploaded_image_with_mode(
+    workers: &ImageWorkers,
     image_data: &[u8],
     content_type: &str,
+    oversize_mode: OversizeMode,
+    max_dimension: u32,
 ) -> Result<ProcessedImage> {
     // Clone data for move into blocking task
     let image_data = image_data.to_vec();
     let content_type = content_type.to_string();
 
-    // Offload CPU-intensive image processing to blocking thread pool
-    tokio::task::spawn_blocking(move || {
-        // Detect and load the image format
-        let format = detect_image_format(&content_type)
-            .with_context(|| format!("Unsupported image format: {}", content_type))?;
+    workers
+        .run(move || {
+            // Detect and load the image format
+            let format = detect_image_format(&content_type).map_err(|_| {
+                InvalidImage::UnsupportedFormat {
+                    content_type: content_type.clone(),
+                }
+            })?;
 
-        let image = image::load_from_memory_with_format(&image_data, format)
-            .with_context(|| "Failed to decode image")?;
+            let image = image::load_from_memory_with_format(&image_data, format).map_err(|e| {
+                InvalidImage::Decode {
+                    content_type: content_type.clone(),
+                    reason: e.to_string(),
+                }
+            })?;
 
-        // Crop to 4K if the image is larger
-        let processed_image = crop_to_max_dimension(image);
+            let (width, height) = (image.width(), image.height());
+            if oversize_mode == OversizeMode::Reject
+                && (width > max_dimension || height > max_dimension)
+            {
+                return Err(ImageTooLarge {
+                    width,
+                    height,
+                    max: max_dimension,
+                }
+                .into());
+            }
 
-        // Convert to AVIF format
-        let avif_data =
-            encode_to_avif(&processed_image).with_context(|| "Failed to encode image to AVIF")?;
+            // Scale down to the maximum dimension if the image is larger
+            let processed_image = crop_to_max_dimension(image, max_dimension);
 
-        Ok(ProcessedImage {
-            data: avif_data,
-            width: processed_image.width(),
-            height: processed_image.height(),
-            content_type: "image/avif".to_string(),
+            // Convert to AVIF format
+            let avif_data = encode_to_avif(&processed_image)
+                .with_context(|| "Failed to encode image to AVIF")?;
+            let thumbnail =
+                encode_thumbnail(&processed_image).with_context(|| "Failed to encode thumbnail")?;
+
+            Ok(ProcessedImage {
+                data: avif_data,
+                width: processed_image.width(),
+                height: processed_image.height(),
+                content_type: "image/avif".to_string(),
+                thumbnail,
+            })
         })
+        .await?
+}
+
+/// Whether image data of `content_type` can be decoded by this build
+pub fn can_decode(content_type: &str) -> bool {
+    content_type != ServeFormat::Avif.content_type() || AVIF_DECODING
+}
+
+/// Re-encode image data (in any decodable format) as `format`
+///
+/// With `bounds`, the image is first scaled down to fit within that width and
+/// height. Runs on the blocking thread pool like [`process_uploaded_image_with_mode`].
+///
+/// # Errors
+/// * Returns error if the source can't be decoded or encoding fails
+pub async fn transcode_image(
+    image_data: &[u8],
+    format: ServeFormat,
+    bounds: Option<(u32, u32)>,
+) -> Result<Vec<u8>> {
+    let image_data = image_data.to_vec();
+
+    tokio::task::spawn_blocking(move || {
+        let mut image = decode_image(&image_data)?;
+        if let Some((width, height)) = bounds {
+            if image.width() > width || image.height() > height {
+                image = image.resize(width, height, image::imageops::FilterType::Lanczos3);
+            }
+        }
+
+        match format {
+            ServeFormat::Avif => encode_to_avif(&image),
+            ServeFormat::WebP => encode_to_webp(&image),
+            ServeFormat::Jpeg => encode_to_jpeg(&image),
+        }
     })
     .await
-    .with_context(|| "Image processing task was cancelled")?
+    .with_context(|| "Image transcoding task was cancelled")?
+}
+
+/// Generate a `THUMBNAIL_WIDTH`-wide AVIF thumbnail from image data in any decodable format
+///
+/// Images already narrower than a thumbnail are re-encoded without upscaling.
+///
+/// # Errors
+/// * Returns error if the source can't be decoded or encoding fails
+pub async fn generate_thumbnail(image_data: &[u8]) -> Result<Vec<u8>> {
+    let image_data = image_data.to_vec();
+
+    tokio::task::spawn_blocking(move || {
+        let image = decode_image(&image_data)?;
+
+        encode_thumbnail(&image)
+    })
+    .await
+    .with_context(|| "Thumbnail generation task was cancelled")?
+}
+
+/// Decode image data in any supported format, including our own AVIF output
+///
+/// `image` only sniffs AVIF files whose `ftyp` box has one of two fixed sizes,
+/// which the encoder's output doesn't, so any ISO-BMFF file is tried as AVIF.
+fn decode_image(image_data: &[u8]) -> Result<DynamicImage> {
+    if image_data.get(4..8) == Some(b"ftyp".as_slice()) {
+        return decode_avif(image_data);
+    }
+    image::load_from_memory(image_data).with_context(|| "Failed to decode image")
+}
+
+#[cfg(feature = "avif-decoder")]
+fn decode_avif(image_data: &[u8]) -> Result<DynamicImage> {
+    image::load_from_memory_with_format(image_data, ImageFormat::Avif)
+        .with_context(|| "Failed to decode image")
+}
+
+#[cfg(not(feature = "avif-decoder"))]
+fn decode_avif(_image_data: &[u8]) -> Result<DynamicImage> {
+    anyhow::bail!("Decoding AVIF needs the avif-decoder feature")
+}
+
+/// Scale `image` down to `THUMBNAIL_WIDTH` wide, never up, and encode it as AVIF
+fn encode_thumbnail(image: &DynamicImage) -> Result<Vec<u8>> {
+    if image.width() <= THUMBNAIL_WIDTH {
+        return encode_to_avif(image);
+    }
+
+    let thumbnail = image.resize(
+        THUMBNAIL_WIDTH,
+        u32::MAX,
+        image::imageops::FilterType::Lanczos3,
+    );
+    encode_to_avif(&thumbnail)
 }
 
 /// Detect image format from content type
@@ -81,20 +339,20 @@ fn detect_image_format(content_type: &str) -> Result<ImageFormat> {
     }
 }
 
-/// Crop image to maximum dimension if it exceeds 4K
+/// Crop image to maximum dimension if it exceeds `max_dimension`
 ///
 /// Uses smart cropping that maintains aspect ratio and crops from center
-/// if the image is larger than MAX_DIMENSION in either dimension.
-fn crop_to_max_dimension(image: DynamicImage) -> DynamicImage {
+/// if the image is larger than `max_dimension` in either dimension.
+fn crop_to_max_dimension(image: DynamicImage, max_dimension: u32) -> DynamicImage {
     let (width, height) = (image.width(), image.height());
 
     // If image is already within limits, return as-is
-    if width <= MAX_DIMENSION && height <= MAX_DIMENSION {
+    if width <= max_dimension && height <= max_dimension {
         return image;
     }
 
-    // Calculate the scale factor to fit within MAX_DIMENSION
-    let scale_factor = (MAX_DIMENSION as f32 / width.max(height) as f32).min(1.0);
+    // Calculate the scale factor to fit within max_dimension
+    let scale_factor = (max_dimension as f32 / width.max(height) as f32).min(1.0);
     let new_width = (width as f32 * scale_factor) as u32;
     let new_height = (height as f32 * scale_factor) as u32;
 
@@ -133,6 +391,35 @@ fn encode_to_avif(image: &DynamicImage) -> Result<Vec<u8>> {
     Ok(buffer)
 }
 
+/// Encode image to JPEG for clients without AVIF support
+fn encode_to_jpeg(image: &DynamicImage) -> Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+
+    // JPEG has no alpha channel
+    let rgb_image = image.to_rgb8();
+    let (width, height) = rgb_image.dimensions();
+
+    JpegEncoder::new_with_quality(&mut buffer, 85)
+        .write_image(rgb_image.as_raw(), width, height, ColorType::Rgb8)
+        .with_context(|| "Failed to encode image as JPEG")?;
+
+    Ok(buffer)
+}
+
+/// Encode image to lossless WebP
+fn encode_to_webp(image: &DynamicImage) -> Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+
+    let rgba_image = image.to_rgba8();
+    let (width, height) = rgba_image.dimensions();
+
+    WebPEncoder::new_lossless(&mut buffer)
+        .write_image(rgba_image.as_raw(), width, height, ColorType::Rgba8)
+        .with_context(|| "Failed to encode image as WebP")?;
+
+    Ok(buffer)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,7 +434,15 @@ mod tests {
         img.write_to(&mut Cursor::new(&mut buffer), ImageOutputFormat::Jpeg(80))
             .unwrap();
 
-        let result = process_uploaded_image(&buffer, "image/jpeg").await.unwrap();
+        let result = process_uploaded_image_with_mode(
+            &ImageWorkers::default(),
+            &buffer,
+            "image/jpeg",
+            OversizeMode::Downscale,
+            MAX_DIMENSION,
+        )
+        .await
+        .unwrap();
 
         assert_eq!(result.content_type, "image/avif");
         assert_eq!(result.width, 100);
@@ -159,7 +454,7 @@ mod tests {
     async fn test_crop_large_image() {
         // Create a large test image (5000x3000)
         let large_img = DynamicImage::new_rgb8(5000, 3000);
-        let cropped = crop_to_max_dimension(large_img);
+        let cropped = crop_to_max_dimension(large_img, MAX_DIMENSION);
 
         // Should be scaled down to fit within MAX_DIMENSION
         assert!(cropped.width() <= MAX_DIMENSION);
@@ -167,6 +462,189 @@ mod tests {
         assert_eq!(cropped.width(), MAX_DIMENSION); // Wider dimension should hit the limit
     }
 
+    fn encode_png(width: u32, height: u32) -> Vec<u8> {
+        let img = DynamicImage::new_rgb8(width, height);
+        let mut buffer = Vec::new();
+        use image::ImageOutputFormat;
+        use std::io::Cursor;
+        img.write_to(&mut Cursor::new(&mut buffer), ImageOutputFormat::Png)
+            .unwrap();
+        buffer
+    }
+
+    #[tokio::test]
+    async fn test_oversized_image_downscaled() {
+        let buffer = encode_png(MAX_DIMENSION * 2, 40);
+
+        let result = process_uploaded_image_with_mode(
+            &ImageWorkers::default(),
+            &buffer,
+            "image/png",
+            OversizeMode::Downscale,
+            MAX_DIMENSION,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.width, MAX_DIMENSION);
+        assert_eq!(result.height, 20);
+    }
+
+    #[tokio::test]
+    async fn test_oversized_image_rejected() {
+        let buffer = encode_png(MAX_DIMENSION + 160, 10);
+
+        let error = process_uploaded_image_with_mode(
+            &ImageWorkers::default(),
+            &buffer,
+            "image/png",
+            OversizeMode::Reject,
+            MAX_DIMENSION,
+        )
+        .await
+        .unwrap_err();
+
+        let too_large = error.downcast_ref::<ImageTooLarge>().unwrap();
+        assert_eq!(too_large.width, MAX_DIMENSION + 160);
+        assert_eq!(too_large.max, MAX_DIMENSION);
+        assert!(error.to_string().contains("3840px"));
+    }
+
+    #[tokio::test]
+    async fn test_undecodable_image_is_invalid() {
+        // A JPEG signature followed by garbage
+        let corrupt = [0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10, 0x4A, 0x46, 0x49, 0x46];
+
+        let error = process_uploaded_image_with_mode(
+            &ImageWorkers::default(),
+            &corrupt,
+            "image/jpeg",
+            OversizeMode::Downscale,
+            MAX_DIMENSION,
+        )
+        .await
+        .unwrap_err();
+        let invalid = error.downcast_ref::<InvalidImage>().unwrap();
+        assert_eq!(invalid.code(), "decode_failed");
+        assert!(invalid
+            .to_string()
+            .contains("could not be decoded as image/jpeg"));
+
+        // Valid PNG bytes claimed to be a JPEG don't decode either
+        let error = process_uploaded_image_with_mode(
+            &ImageWorkers::default(),
+            &encode_png(4, 4),
+            "image/jpeg",
+            OversizeMode::Downscale,
+            MAX_DIMENSION,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<InvalidImage>().unwrap().code(),
+            "decode_failed"
+        );
+
+        let error = process_uploaded_image_with_mode(
+            &ImageWorkers::default(),
+            &encode_png(4, 4),
+            "image/tiff",
+            OversizeMode::Downscale,
+            MAX_DIMENSION,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<InvalidImage>().unwrap().code(),
+            "unsupported_format"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reject_mode_accepts_image_within_limits() {
+        let buffer = encode_png(100, 100);
+
+        let result = process_uploaded_image_with_mode(
+            &ImageWorkers::default(),
+            &buffer,
+            "image/png",
+            OversizeMode::Reject,
+            MAX_DIMENSION,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.width, 100);
+        assert_eq!(result.height, 100);
+    }
+
+    #[tokio::test]
+    async fn test_generate_thumbnail_is_avif() {
+        let buffer = encode_png(1024, 512);
+
+        let thumbnail = generate_thumbnail(&buffer).await.unwrap();
+
+        assert!(!thumbnail.is_empty());
+        assert_eq!(&thumbnail[4..8], b"ftyp");
+    }
+
+    #[tokio::test]
+    async fn test_processed_image_includes_thumbnail() {
+        let buffer = encode_png(1024, 512);
+
+        let result = process_uploaded_image_with_mode(
+            &ImageWorkers::default(),
+            &buffer,
+            "image/png",
+            OversizeMode::Downscale,
+            MAX_DIMENSION,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(&result.thumbnail[4..8], b"ftyp");
+        assert_eq!(avif_dimensions(&result.thumbnail), (THUMBNAIL_WIDTH, 128));
+        assert_eq!(avif_dimensions(&result.data), (1024, 512));
+    }
+
+    #[cfg(feature = "avif-decoder")]
+    #[tokio::test]
+    async fn test_generate_thumbnail_from_avif() {
+        // Stored photos only have the AVIF
+        let avif = encode_to_avif(&DynamicImage::new_rgb8(600, 300)).unwrap();
+
+        let thumbnail = generate_thumbnail(&avif).await.unwrap();
+
+        assert_eq!(avif_dimensions(&thumbnail), (THUMBNAIL_WIDTH, 128));
+        let decoded = decode_image(&thumbnail).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (THUMBNAIL_WIDTH, 128));
+    }
+
+    #[cfg(not(feature = "avif-decoder"))]
+    #[tokio::test]
+    async fn test_avif_is_not_decoded_without_the_decoder() {
+        let avif = encode_to_avif(&DynamicImage::new_rgb8(600, 300)).unwrap();
+
+        assert!(!can_decode("image/avif"));
+        assert!(can_decode("image/jpeg"));
+        let error = generate_thumbnail(&avif).await.unwrap_err();
+        assert!(error.to_string().contains("avif-decoder"));
+    }
+
+    /// Width and height from an AVIF's image spatial extents (`ispe`) property
+    fn avif_dimensions(avif: &[u8]) -> (u32, u32) {
+        let ispe = avif
+            .windows(4)
+            .position(|window| window == b"ispe")
+            .expect("AVIF has no ispe property");
+        // The box type is followed by a version/flags word, then width and height
+        let field = |offset: usize| {
+            let start = ispe + 8 + offset;
+            u32::from_be_bytes(avif[start..start + 4].try_into().unwrap())
+        };
+        (field(0), field(4))
+    }
+
     #[test]
     fn test_detect_image_format() {
         assert!(matches!(
@@ -183,4 +661,61 @@ mod tests {
         ));
         assert!(detect_image_format("image/bmp").is_err());
     }
+
+    #[test]
+    fn test_negotiate_serve_format() {
+        let fallback = ServeFormat::Jpeg;
+
+        assert_eq!(ServeFormat::negotiate(None, fallback), ServeFormat::Avif);
+        assert_eq!(
+            ServeFormat::negotiate(Some("image/avif,image/webp,*/*"), fallback),
+            ServeFormat::Avif
+        );
+        assert_eq!(
+            ServeFormat::negotiate(Some("image/webp,*/*"), fallback),
+            ServeFormat::WebP
+        );
+        assert_eq!(
+            ServeFormat::negotiate(Some("image/avif;q=0, image/webp;q=0.8"), fallback),
+            ServeFormat::WebP
+        );
+        assert_eq!(
+            ServeFormat::negotiate(Some("image/png,image/*;q=0.8,*/*;q=0.5"), fallback),
+            ServeFormat::Jpeg
+        );
+        assert_eq!(
+            ServeFormat::negotiate(Some("image/jpeg"), ServeFormat::WebP),
+            ServeFormat::WebP
+        );
+        assert_eq!(
+            ServeFormat::negotiate(Some("*/*"), fallback),
+            ServeFormat::Avif
+        );
+    }
+
+    #[tokio::test]
+    async fn test_transcode_jpeg_to_webp() {
+        let jpeg = encode_to_jpeg(&DynamicImage::new_rgb8(20, 10)).unwrap();
+
+        let webp = transcode_image(&jpeg, ServeFormat::WebP, None)
+            .await
+            .unwrap();
+
+        assert_eq!(&webp[0..4], b"RIFF");
+        assert_eq!(&webp[8..12], b"WEBP");
+        let decoded = image::load_from_memory(&webp).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (20, 10));
+
+        // Bounds scale the image down to fit, never up
+        let webp = transcode_image(&jpeg, ServeFormat::WebP, Some((10, 10)))
+            .await
+            .unwrap();
+        let decoded = image::load_from_memory(&webp).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (10, 5));
+        let webp = transcode_image(&jpeg, ServeFormat::WebP, Some((40, 40)))
+            .await
+            .unwrap();
+        let decoded = image::load_from_memory(&webp).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (20, 10));
+    }
 }
@@ -0,0 +1,200 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use tokio::sync::Semaphore;
+
+/// Default number of images processed at once (`IMAGE_PROCESSING_CONCURRENCY`)
+pub const DEFAULT_IMAGE_CONCURRENCY: usize = 2;
+
+/// Default number of images waiting for a worker (`IMAGE_PROCESSING_QUEUE`)
+pub const DEFAULT_IMAGE_QUEUE: usize = 16;
+
+/// Returned when every worker is busy and the queue is full
+#[derive(Debug, thiserror::Error)]
+#[error("Image processing is busy with {queued} images already waiting")]
+pub struct ImageWorkersBusy {
+    pub queued: usize,
+}
+
+/// Bounded pool for CPU-heavy image work
+///
+/// Work runs on the blocking thread pool so encoding never stalls the async
+/// runtime, at most `concurrency` jobs at a time. Further jobs wait their turn
+/// without holding a thread, up to `max_queued` of them; beyond that they are
+/// turned away with [`ImageWorkersBusy`]. Clones share the same workers.
+#[derive(Debug, Clone)]
+pub struct ImageWorkers {
+    permits: Arc<Semaphore>,
+    queued: Arc<AtomicUsize>,
+    concurrency: usize,
+    max_queued: usize,
+}
+
+impl Default for ImageWorkers {
+    fn default() -> Self {
+        Self::new(DEFAULT_IMAGE_CONCURRENCY, DEFAULT_IMAGE_QUEUE)
+    }
+}
+
+impl ImageWorkers {
+    /// Run up to `concurrency` jobs at once (at least one), queueing up to `max_queued`
+    pub fn new(concurrency: usize, max_queued: usize) -> Self {
+        let concurrency = concurrency.max(1);
+        Self {
+            permits: Arc::new(Semaphore::new(concurrency)),
+            queued: Arc::new(AtomicUsize::new(0)),
+            concurrency,
+            max_queued,
+        }
+    }
+
+    /// Jobs running right now
+    pub fn running(&self) -> usize {
+        self.concurrency - self.permits.available_permits()
+    }
+
+    /// Jobs waiting for a worker
+    pub fn queued(&self) -> usize {
+        self.queued.load(Ordering::SeqCst)
+    }
+
+    /// Run `work` on the blocking thread pool once a worker is free
+    ///
+    /// # Errors
+    /// * Returns `ImageWorkersBusy` if the queue is already full
+    /// * Returns error if the blocking task panics
+    pub async fn run<T, F>(&self, work: F) -> Result<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let permit = match Arc::clone(&self.permits).try_acquire_owned() {
+            Ok(permit) => permit,
+            Err(_) => {
+                let _slot = self.join_queue()?;
+                tracing::debug!(
+                    "{} of {} image workers busy, queueing ({} waiting)",
+                    self.running(),
+                    self.concurrency,
+                    self.queued()
+                );
+                Arc::clone(&self.permits)
+                    .acquire_owned()
+                    .await
+                    .context("Image workers were shut down")?
+            }
+        };
+
+        // The permit moves into the task so it's only released once the work
+        // is done, even if the caller stops waiting for it
+        tokio::task::spawn_blocking(move || {
+            let _permit = permit;
+            work()
+        })
+        .await
+        .context("Image processing task was cancelled")
+    }
+
+    /// Take a place in the queue, released when the returned slot is dropped
+    fn join_queue(&self) -> Result<QueueSlot, ImageWorkersBusy> {
+        self.queued
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |queued| {
+                (queued < self.max_queued).then_some(queued + 1)
+            })
+            .map_err(|queued| ImageWorkersBusy { queued })?;
+        Ok(QueueSlot(Arc::clone(&self.queued)))
+    }
+}
+
+/// A job's place in the queue
+struct QueueSlot(Arc<AtomicUsize>);
+
+impl Drop for QueueSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_work_runs_on_blocking_threads() {
+        let workers = ImageWorkers::new(1, 0);
+        let (done, finished) = std::sync::mpsc::channel::<()>();
+
+        // The test runtime has a single thread; work run on it would block
+        // until a signal that the runtime could never send
+        let signal = tokio::spawn(async move { done.send(()).unwrap() });
+        let received = workers
+            .run(move || finished.recv_timeout(Duration::from_secs(5)))
+            .await
+            .unwrap();
+
+        assert!(received.is_ok());
+        signal.await.unwrap();
+        assert_eq!(workers.running(), 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrency_limit_is_respected() {
+        let workers = ImageWorkers::new(2, 10);
+        let active = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+
+        let jobs: Vec<_> = (0..8)
+            .map(|_| {
+                let (workers, active, peak) = (workers.clone(), active.clone(), peak.clone());
+                tokio::spawn(async move {
+                    workers
+                        .run(move || {
+                            let now = active.fetch_add(1, Ordering::SeqCst) + 1;
+                            peak.fetch_max(now, Ordering::SeqCst);
+                            std::thread::sleep(Duration::from_millis(50));
+                            active.fetch_sub(1, Ordering::SeqCst);
+                        })
+                        .await
+                })
+            })
+            .collect();
+        for job in jobs {
+            job.await.unwrap().unwrap();
+        }
+
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+        assert_eq!(workers.running(), 0);
+        assert_eq!(workers.queued(), 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_full_queue_is_turned_away() {
+        let workers = ImageWorkers::new(1, 1);
+        let (release, released) = std::sync::mpsc::channel::<()>();
+
+        let running = tokio::spawn({
+            let workers = workers.clone();
+            async move { workers.run(move || released.recv().unwrap()).await }
+        });
+        while workers.running() == 0 {
+            tokio::task::yield_now().await;
+        }
+        let waiting = tokio::spawn({
+            let workers = workers.clone();
+            async move { workers.run(|| ()).await }
+        });
+        while workers.queued() == 0 {
+            tokio::task::yield_now().await;
+        }
+
+        let error = workers.run(|| ()).await.unwrap_err();
+        assert_eq!(error.downcast_ref::<ImageWorkersBusy>().unwrap().queued, 1);
+
+        release.send(()).unwrap();
+        running.await.unwrap().unwrap();
+        waiting.await.unwrap().unwrap();
+        assert_eq!(workers.queued(), 0);
+    }
+}
@@ -0,0 +1,168 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+/// Outcome of a background job's most recent run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum JobOutcome {
+    Success,
+    Failure,
+}
+
+/// Snapshot of a background job's schedule and last run
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JobStatus {
+    pub name: String,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub last_outcome: Option<JobOutcome>,
+    pub last_error: Option<String>,
+    pub next_run_at: Option<DateTime<Utc>>,
+}
+
+impl JobStatus {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            last_run_at: None,
+            last_outcome: None,
+            last_error: None,
+            next_run_at: None,
+        }
+    }
+}
+
+/// Shared record of background jobs, updated by the jobs themselves
+#[derive(Debug, Clone, Default)]
+pub struct JobRegistry {
+    jobs: Arc<RwLock<BTreeMap<String, JobStatus>>>,
+}
+
+impl JobRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a job so it is listed before its first run
+    pub fn register(&self, name: &str) {
+        let mut jobs = self.jobs.write().unwrap_or_else(|e| e.into_inner());
+        jobs.entry(name.to_string())
+            .or_insert_with(|| JobStatus::new(name));
+    }
+
+    /// Record a finished run, keeping the error message for failures
+    pub fn record_run(&self, name: &str, result: std::result::Result<(), String>) {
+        let mut jobs = self.jobs.write().unwrap_or_else(|e| e.into_inner());
+        let job = jobs
+            .entry(name.to_string())
+            .or_insert_with(|| JobStatus::new(name));
+
+        job.last_run_at = Some(Utc::now());
+        match result {
+            Ok(()) => {
+                job.last_outcome = Some(JobOutcome::Success);
+                job.last_error = None;
+            }
+            Err(message) => {
+                job.last_outcome = Some(JobOutcome::Failure);
+                job.last_error = Some(message);
+            }
+        }
+    }
+
+    /// Record when the job is next expected to run (`None` if it is idle)
+    pub fn set_next_run(&self, name: &str, next_run_at: Option<DateTime<Utc>>) {
+        let mut jobs = self.jobs.write().unwrap_or_else(|e| e.into_inner());
+        jobs.entry(name.to_string())
+            .or_insert_with(|| JobStatus::new(name))
+            .next_run_at = next_run_at;
+    }
+
+    /// Register `name` and run `job` in the background right away and then
+    /// every `period`, recording each run's outcome and when the next is due
+    pub fn spawn_periodic<F, Fut>(&self, name: &'static str, period: Duration, mut job: F)
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = std::result::Result<(), String>> + Send + 'static,
+    {
+        self.register(name);
+
+        let registry = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(period);
+            loop {
+                ticker.tick().await;
+
+                let result = job().await;
+                registry.record_run(name, result);
+                registry.set_next_run(
+                    name,
+                    chrono::Duration::from_std(period)
+                        .ok()
+                        .map(|interval| Utc::now() + interval),
+                );
+            }
+        });
+    }
+
+    /// All registered jobs, ordered by name
+    pub fn snapshot(&self) -> Vec<JobStatus> {
+        let jobs = self.jobs.read().unwrap_or_else(|e| e.into_inner());
+        jobs.values().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_run_tracks_latest_outcome() {
+        let registry = JobRegistry::new();
+        registry.register("example");
+
+        let job = &registry.snapshot()[0];
+        assert!(job.last_run_at.is_none());
+        assert!(job.last_outcome.is_none());
+
+        registry.record_run("example", Err("boom".to_string()));
+        let job = &registry.snapshot()[0];
+        assert_eq!(job.last_outcome, Some(JobOutcome::Failure));
+        assert_eq!(job.last_error.as_deref(), Some("boom"));
+
+        registry.record_run("example", Ok(()));
+        let job = &registry.snapshot()[0];
+        assert_eq!(job.last_outcome, Some(JobOutcome::Success));
+        assert!(job.last_error.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_spawn_periodic_runs_at_once_and_schedules_the_next_run() {
+        let registry = JobRegistry::new();
+        registry.spawn_periodic("periodic", Duration::from_secs(3600), || async {
+            Err("boom".to_string())
+        });
+
+        let job = tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                let job = registry.snapshot().remove(0);
+                if job.last_run_at.is_some() {
+                    return job;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("Job didn't run");
+
+        assert_eq!(job.name, "periodic");
+        assert_eq!(job.last_outcome, Some(JobOutcome::Failure));
+        assert_eq!(job.last_error.as_deref(), Some("boom"));
+        let next_run_at = job.next_run_at.expect("Next run not scheduled");
+        assert!(next_run_at > Utc::now() + chrono::Duration::minutes(59));
+    }
+}
@@ -0,0 +1,60 @@
+use std::sync::{Arc, Mutex};
+
+use crate::utils::errors::AppError;
+
+/// A plain-text email
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Email {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+/// Delivers outgoing email
+#[async_trait::async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, email: &Email) -> Result<(), AppError>;
+}
+
+/// Mailer used unless a transport is configured; logs each email instead of sending it
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogMailer;
+
+#[async_trait::async_trait]
+impl Mailer for LogMailer {
+    async fn send(&self, email: &Email) -> Result<(), AppError> {
+        tracing::info!(
+            "No mail transport configured, not sending \"{}\" to {}",
+            email.subject,
+            email.to
+        );
+        Ok(())
+    }
+}
+
+/// Keeps sent emails in memory, for tests
+#[allow(dead_code)]
+#[derive(Debug, Clone, Default)]
+pub struct MemoryMailer {
+    sent: Arc<Mutex<Vec<Email>>>,
+}
+
+#[allow(dead_code)]
+impl MemoryMailer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every email sent so far, oldest first
+    pub fn sent(&self) -> Vec<Email> {
+        self.sent.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl Mailer for MemoryMailer {
+    async fn send(&self, email: &Email) -> Result<(), AppError> {
+        self.sent.lock().unwrap().push(email.clone());
+        Ok(())
+    }
+}
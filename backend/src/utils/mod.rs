@@ -1,5 +1,24 @@
+pub mod api_keys;
 pub mod calendar;
+pub mod care_import;
+pub mod entry_purger;
 pub mod errors;
+pub mod feature_cache;
 pub mod google_tasks;
+pub mod hex;
+pub mod http_cache;
 pub mod image_processing;
+pub mod image_workers;
+pub mod job_registry;
+pub mod mailer;
+pub mod nullable;
+pub mod pagination;
+pub mod photo_store;
+pub mod plant_export;
+pub mod task_list_cache;
+pub mod text;
+pub mod thumbnail_backfill;
 pub mod token_refresh_scheduler;
+pub mod tombstone_pruner;
+pub mod weather;
+pub mod webhooks;
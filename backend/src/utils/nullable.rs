@@ -0,0 +1,34 @@
+use serde::{Deserialize, Deserializer};
+
+/// Deserialize a field that distinguishes "omitted" from "explicitly null"
+///
+/// Use with `#[serde(default, deserialize_with = "deserialize_nullable")]` on an
+/// `Option<Option<T>>`: a missing field stays `None`, `null` becomes `Some(None)`
+/// and a value becomes `Some(Some(value))`.
+pub fn deserialize_nullable<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct Patch {
+        #[serde(default, deserialize_with = "deserialize_nullable")]
+        value: Option<Option<i32>>,
+    }
+
+    #[test]
+    fn test_deserialize_nullable_distinguishes_null_from_missing() {
+        let parse = |json| serde_json::from_str::<Patch>(json).unwrap().value;
+
+        assert_eq!(parse("{}"), None);
+        assert_eq!(parse(r#"{"value": null}"#), Some(None));
+        assert_eq!(parse(r#"{"value": 3}"#), Some(Some(3)));
+    }
+}
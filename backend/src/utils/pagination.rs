@@ -0,0 +1,188 @@
+use axum::http::Uri;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::utils::hex::to_hex;
+
+/// Relative URLs of the neighbouring pages, `None` at either end
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PageLinks {
+    pub next: Option<String>,
+    pub prev: Option<String>,
+}
+
+/// Links for a `limit`/`offset` paginated list served at `uri`
+///
+/// The request's other query parameters are kept as they were sent.
+pub fn offset_links(uri: &Uri, total: i64, limit: i64, offset: i64) -> PageLinks {
+    let link = |offset: i64| {
+        with_params(
+            uri,
+            &[("limit", limit.to_string()), ("offset", offset.to_string())],
+        )
+    };
+
+    PageLinks {
+        next: (limit > 0 && offset + limit < total).then(|| link(offset + limit)),
+        prev: (offset > 0).then(|| link((offset - limit).max(0))),
+    }
+}
+
+/// Links for a `page`-numbered list served at `uri`, with pages counted from 1
+pub fn page_links(uri: &Uri, page: i32, total_pages: i32) -> PageLinks {
+    let link = |page: i32| with_params(uri, &[("page", page.to_string())]);
+
+    PageLinks {
+        next: (page < total_pages).then(|| link(page + 1)),
+        prev: (page > 1).then(|| link((page - 1).min(total_pages.max(1)))),
+    }
+}
+
+/// Position in a list ordered by creation time, handed to clients as an opaque token
+///
+/// Rows created at the same instant are told apart by id, so every row has its
+/// own position and rows inserted while paging don't shift later pages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageCursor {
+    /// The row's `created_at` exactly as stored
+    pub created_at: String,
+    pub id: String,
+}
+
+impl PageCursor {
+    /// The token handed to clients
+    pub fn encode(&self) -> String {
+        to_hex(format!("{}|{}", self.created_at, self.id).as_bytes())
+    }
+
+    /// Read a token made by `encode`, `None` if it isn't one
+    pub fn decode(token: &str) -> Option<Self> {
+        let digits = token
+            .chars()
+            .map(|c| c.to_digit(16))
+            .collect::<Option<Vec<u32>>>()?;
+        if digits.len() % 2 != 0 {
+            return None;
+        }
+        let bytes = digits
+            .chunks(2)
+            .map(|pair| (pair[0] * 16 + pair[1]) as u8)
+            .collect();
+
+        let text = String::from_utf8(bytes).ok()?;
+        let (created_at, id) = text.split_once('|')?;
+        created_at.parse::<DateTime<Utc>>().ok()?;
+        Uuid::parse_str(id).ok()?;
+
+        Some(Self {
+            created_at: created_at.to_string(),
+            id: id.to_string(),
+        })
+    }
+}
+
+/// Link to the page after `cursor` of a cursor paginated list served at `uri`
+pub fn cursor_link(uri: &Uri, cursor: &PageCursor) -> String {
+    with_params(uri, &[("cursor", cursor.encode())])
+}
+
+/// `uri`'s path and query with `params` replacing any existing values
+fn with_params(uri: &Uri, params: &[(&str, String)]) -> String {
+    let kept = uri
+        .query()
+        .into_iter()
+        .flat_map(|query| query.split('&'))
+        .filter(|pair| {
+            let key = pair.split('=').next().unwrap_or_default();
+            !pair.is_empty() && !params.iter().any(|(name, _)| *name == key)
+        });
+    let replaced = params.iter().map(|(name, value)| format!("{name}={value}"));
+
+    let query: Vec<String> = kept.map(str::to_string).chain(replaced).collect();
+    format!("{}?{}", uri.path(), query.join("&"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offset_links() {
+        let uri: Uri = "/api/v1/plants?search=fern&offset=20&limit=10"
+            .parse()
+            .unwrap();
+
+        let links = offset_links(&uri, 45, 10, 20);
+        assert_eq!(
+            links.next.as_deref(),
+            Some("/api/v1/plants?search=fern&limit=10&offset=30")
+        );
+        assert_eq!(
+            links.prev.as_deref(),
+            Some("/api/v1/plants?search=fern&limit=10&offset=10")
+        );
+
+        assert_eq!(offset_links(&uri, 45, 10, 40).next, None);
+        assert_eq!(offset_links(&uri, 45, 10, 0).prev, None);
+        // A partial first page goes back to the start
+        assert_eq!(
+            offset_links(&uri, 45, 10, 5).prev.as_deref(),
+            Some("/api/v1/plants?search=fern&limit=10&offset=0")
+        );
+    }
+
+    #[test]
+    fn test_page_links() {
+        let uri: Uri = "/api/v1/admin/users?role=admin".parse().unwrap();
+
+        let links = page_links(&uri, 2, 3);
+        assert_eq!(
+            links.next.as_deref(),
+            Some("/api/v1/admin/users?role=admin&page=3")
+        );
+        assert_eq!(
+            links.prev.as_deref(),
+            Some("/api/v1/admin/users?role=admin&page=1")
+        );
+        assert_eq!(page_links(&uri, 3, 3).next, None);
+        assert_eq!(page_links(&uri, 1, 0), PageLinks::default());
+    }
+
+    #[test]
+    fn test_page_cursor_round_trip() {
+        let cursor = PageCursor {
+            created_at: "2024-03-01T12:00:00.123456789+00:00".to_string(),
+            id: "0b8f3c2e-7d4a-4e1b-9c5f-2a6d8e0f1b3c".to_string(),
+        };
+
+        let token = cursor.encode();
+        assert!(token.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_eq!(PageCursor::decode(&token), Some(cursor.clone()));
+
+        let uri: Uri = "/api/v1/plants?pagination=cursor&cursor=abc&limit=5"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            cursor_link(&uri, &cursor),
+            format!("/api/v1/plants?pagination=cursor&limit=5&cursor={token}")
+        );
+    }
+
+    #[test]
+    fn test_page_cursor_rejects_tampered_tokens() {
+        let encode = |text: &str| to_hex(text.as_bytes());
+
+        assert_eq!(PageCursor::decode(""), None);
+        assert_eq!(PageCursor::decode("not-hex"), None);
+        assert_eq!(PageCursor::decode("abc"), None);
+        assert_eq!(PageCursor::decode(&encode("no separator")), None);
+        assert_eq!(
+            PageCursor::decode(&encode("yesterday|0b8f3c2e-7d4a-4e1b-9c5f-2a6d8e0f1b3c")),
+            None
+        );
+        assert_eq!(
+            PageCursor::decode(&encode("2024-03-01T12:00:00+00:00|1 OR 1=1")),
+            None
+        );
+    }
+}
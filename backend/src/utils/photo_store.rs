@@ -0,0 +1,700 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use aws_sdk_s3::config::timeout::TimeoutConfig;
+use aws_sdk_s3::config::{
+    BehaviorVersion, Region, RequestChecksumCalculation, ResponseChecksumValidation,
+};
+use aws_sdk_s3::error::{DisplayErrorContext, SdkError};
+use aws_sdk_s3::primitives::ByteStream;
+use reqwest::Url;
+use tokio::sync::OnceCell;
+
+use crate::database::DatabasePool;
+use crate::models::PhotoId;
+use crate::utils::errors::AppError;
+use crate::utils::image_processing::ServeFormat;
+
+/// Region used when `S3_REGION` is unset
+pub const DEFAULT_S3_REGION: &str = "us-east-1";
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// One of the images kept for a photo
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhotoObject {
+    /// The processed image the photo is served as
+    Image,
+    /// The uploaded bytes, kept when `KEEP_PHOTO_ORIGINALS` is on
+    Original,
+    /// A cached transcode for clients that can't display the stored image
+    Rendition(ServeFormat),
+}
+
+impl PhotoObject {
+    /// Every object a photo can have
+    pub const ALL: [Self; 4] = [
+        Self::Image,
+        Self::Original,
+        Self::Rendition(ServeFormat::WebP),
+        Self::Rendition(ServeFormat::Jpeg),
+    ];
+
+    /// The cached transcodes a photo can have
+    pub const RENDITIONS: [Self; 2] = [
+        Self::Rendition(ServeFormat::WebP),
+        Self::Rendition(ServeFormat::Jpeg),
+    ];
+
+    /// Name the object is kept under among the photo's objects
+    fn name(self) -> &'static str {
+        match self {
+            Self::Image => "image",
+            Self::Original => "original",
+            Self::Rendition(format) => format.as_str(),
+        }
+    }
+
+    /// Object key in a bucket; the image is named after the bare photo ID
+    fn key(self, photo_id: &PhotoId) -> String {
+        match self {
+            Self::Image => photo_id.to_string(),
+            _ => format!("{}.{}", photo_id, self.name()),
+        }
+    }
+}
+
+/// An object read back from a photo store
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredObject {
+    pub data: Vec<u8>,
+    pub content_type: String,
+}
+
+/// Where the image bytes of photos are kept
+///
+/// Photo rows only hold metadata and the small thumbnail shown in listings;
+/// the processed image, kept originals and cached renditions all go through
+/// the store. Objects are put before the photo's row is inserted and removed
+/// after it is deleted, so a row never points at an image that isn't there.
+#[async_trait::async_trait]
+pub trait PhotoStore: Send + Sync {
+    /// Short name reported in logs
+    fn name(&self) -> &'static str;
+
+    /// Store one of a photo's objects, replacing any previous one
+    async fn put(
+        &self,
+        photo_id: &PhotoId,
+        object: PhotoObject,
+        data: &[u8],
+        content_type: &str,
+    ) -> Result<(), AppError>;
+
+    /// One of a photo's objects, `None` if nothing is stored for it
+    async fn get(
+        &self,
+        photo_id: &PhotoId,
+        object: PhotoObject,
+    ) -> Result<Option<StoredObject>, AppError>;
+
+    /// Remove one of a photo's objects; removing one that isn't there succeeds
+    async fn delete(&self, photo_id: &PhotoId, object: PhotoObject) -> Result<(), AppError>;
+}
+
+/// Remove all images of photos whose rows are already gone, or were never inserted
+///
+/// Failures are only logged: the photos no longer exist, so at worst an
+/// image is left behind in the store.
+pub async fn delete_images(store: &dyn PhotoStore, photo_ids: &[PhotoId]) {
+    for photo_id in photo_ids {
+        for object in PhotoObject::ALL {
+            if let Err(e) = store.delete(photo_id, object).await {
+                tracing::warn!(
+                    "Failed to remove {} for photo {} from {} storage: {}",
+                    object.name(),
+                    photo_id,
+                    store.name(),
+                    e
+                );
+            }
+        }
+    }
+}
+
+/// Keeps images in the `photo_objects` table
+#[derive(Debug, Clone)]
+pub struct DatabasePhotoStore {
+    pool: DatabasePool,
+}
+
+impl DatabasePhotoStore {
+    pub fn new(pool: DatabasePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl PhotoStore for DatabasePhotoStore {
+    fn name(&self) -> &'static str {
+        "database"
+    }
+
+    async fn put(
+        &self,
+        photo_id: &PhotoId,
+        object: PhotoObject,
+        data: &[u8],
+        content_type: &str,
+    ) -> Result<(), AppError> {
+        sqlx::query(
+            "INSERT OR REPLACE INTO photo_objects (photo_id, name, content_type, data)
+             VALUES (?, ?, ?, ?)",
+        )
+        .bind(photo_id.to_string())
+        .bind(object.name())
+        .bind(content_type)
+        .bind(data)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get(
+        &self,
+        photo_id: &PhotoId,
+        object: PhotoObject,
+    ) -> Result<Option<StoredObject>, AppError> {
+        let stored = sqlx::query_as(
+            "SELECT data, content_type FROM photo_objects WHERE photo_id = ? AND name = ?",
+        )
+        .bind(photo_id.to_string())
+        .bind(object.name())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(stored.map(|(data, content_type)| StoredObject { data, content_type }))
+    }
+
+    async fn delete(&self, photo_id: &PhotoId, object: PhotoObject) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM photo_objects WHERE photo_id = ? AND name = ?")
+            .bind(photo_id.to_string())
+            .bind(object.name())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Which photo store to use (`PHOTO_STORAGE`)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum PhotoStorage {
+    #[default]
+    Database,
+    /// An S3 bucket, or any service speaking the S3 API such as MinIO
+    S3(S3Config),
+}
+
+/// Bucket for `PHOTO_STORAGE=s3`
+///
+/// Credentials aren't part of it: they come from the usual AWS sources, so
+/// access keys (with `AWS_SESSION_TOKEN` for temporary ones), `AWS_PROFILE`,
+/// web identity tokens and container or instance roles all work.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct S3Config {
+    pub bucket: String,
+    pub region: String,
+    /// Service URL for S3-compatible providers, which are addressed path-style;
+    /// AWS itself when unset
+    pub endpoint: Option<String>,
+}
+
+impl PhotoStorage {
+    /// Read the photo storage settings through `var`, as part of loading `AppConfig`
+    ///
+    /// `PHOTO_STORAGE=s3` needs `S3_BUCKET`. `S3_REGION` defaults to
+    /// `us-east-1`, and `S3_ENDPOINT` is only set for providers other than AWS.
+    pub fn from_lookup(var: &impl Fn(&str) -> Option<String>) -> Result<Self, AppError> {
+        match var("PHOTO_STORAGE").as_deref() {
+            None | Some("database") => Ok(Self::Database),
+            Some("s3") => {
+                let Some(bucket) = var("S3_BUCKET") else {
+                    return Err(AppError::Configuration {
+                        message: "PHOTO_STORAGE=s3 requires S3_BUCKET".to_string(),
+                    });
+                };
+
+                let region = var("S3_REGION").unwrap_or_else(|| DEFAULT_S3_REGION.to_string());
+                let endpoint = var("S3_ENDPOINT");
+                if let Some(endpoint) = &endpoint {
+                    let is_http = Url::parse(endpoint).is_ok_and(|url| {
+                        matches!(url.scheme(), "http" | "https") && url.has_host()
+                    });
+                    if !is_http {
+                        return Err(AppError::Configuration {
+                            message: "S3_ENDPOINT must be an http or https URL".to_string(),
+                        });
+                    }
+                }
+
+                Ok(Self::S3(S3Config {
+                    bucket,
+                    region,
+                    endpoint: endpoint.map(|endpoint| endpoint.trim_end_matches('/').to_string()),
+                }))
+            }
+            Some(_) => Err(AppError::Configuration {
+                message: "PHOTO_STORAGE must be database or s3".to_string(),
+            }),
+        }
+    }
+
+    /// Build the store this setting describes
+    pub fn store(&self, pool: DatabasePool) -> Arc<dyn PhotoStore> {
+        match self {
+            PhotoStorage::Database => Arc::new(DatabasePhotoStore::new(pool)),
+            PhotoStorage::S3(config) => Arc::new(S3PhotoStore::new(config.clone(), pool)),
+        }
+    }
+}
+
+impl S3Config {
+    /// Client settings for the bucket on top of `builder`'s region and credentials
+    fn client_config(&self, builder: aws_sdk_s3::config::Builder) -> aws_sdk_s3::Config {
+        let mut builder = builder
+            .timeout_config(
+                TimeoutConfig::builder()
+                    .operation_timeout(REQUEST_TIMEOUT)
+                    .build(),
+            )
+            // Not every S3-compatible service accepts the checksums AWS now defaults to
+            .request_checksum_calculation(RequestChecksumCalculation::WhenRequired)
+            .response_checksum_validation(ResponseChecksumValidation::WhenRequired);
+        if let Some(endpoint) = &self.endpoint {
+            builder = builder.endpoint_url(endpoint).force_path_style(true);
+        }
+        builder.build()
+    }
+}
+
+/// Keeps each image as an object named after its photo ID in an S3 bucket
+///
+/// Images stored before switching to `PHOTO_STORAGE=s3` stay in `photo_objects`:
+/// they're read from there when the bucket doesn't have them, and removed from
+/// both places with their photo.
+#[derive(Debug)]
+pub struct S3PhotoStore {
+    config: S3Config,
+    client: OnceCell<aws_sdk_s3::Client>,
+    legacy: DatabasePhotoStore,
+}
+
+impl S3PhotoStore {
+    /// A store for the bucket, looking up credentials on first use
+    pub fn new(config: S3Config, pool: DatabasePool) -> Self {
+        Self {
+            config,
+            client: OnceCell::new(),
+            legacy: DatabasePhotoStore::new(pool),
+        }
+    }
+
+    async fn client(&self) -> &aws_sdk_s3::Client {
+        self.client
+            .get_or_init(|| async {
+                let shared = aws_config::defaults(BehaviorVersion::latest())
+                    .region(Region::new(self.config.region.clone()))
+                    .load()
+                    .await;
+                aws_sdk_s3::Client::from_conf(self.config.client_config((&shared).into()))
+            })
+            .await
+    }
+}
+
+fn storage_error<E>(
+    action: &str,
+    object: PhotoObject,
+    photo_id: &PhotoId,
+    error: SdkError<E>,
+) -> AppError
+where
+    E: std::error::Error + 'static,
+{
+    tracing::error!(
+        "Failed to {} {} for photo {} in S3: {}",
+        action,
+        object.name(),
+        photo_id,
+        DisplayErrorContext(&error)
+    );
+    AppError::Internal {
+        message: "Photo storage is unavailable".to_string(),
+    }
+}
+
+#[async_trait::async_trait]
+impl PhotoStore for S3PhotoStore {
+    fn name(&self) -> &'static str {
+        "s3"
+    }
+
+    async fn put(
+        &self,
+        photo_id: &PhotoId,
+        object: PhotoObject,
+        data: &[u8],
+        content_type: &str,
+    ) -> Result<(), AppError> {
+        self.client()
+            .await
+            .put_object()
+            .bucket(&self.config.bucket)
+            .key(object.key(photo_id))
+            .content_type(content_type)
+            .body(ByteStream::from(data.to_vec()))
+            .send()
+            .await
+            .map_err(|e| storage_error("store", object, photo_id, e))?;
+
+        Ok(())
+    }
+
+    async fn get(
+        &self,
+        photo_id: &PhotoId,
+        object: PhotoObject,
+    ) -> Result<Option<StoredObject>, AppError> {
+        let result = self
+            .client()
+            .await
+            .get_object()
+            .bucket(&self.config.bucket)
+            .key(object.key(photo_id))
+            .send()
+            .await;
+
+        let output = match result {
+            Ok(output) => output,
+            Err(e) if e.as_service_error().is_some_and(|e| e.is_no_such_key()) => {
+                return self.legacy.get(photo_id, object).await;
+            }
+            Err(e) => return Err(storage_error("fetch", object, photo_id, e)),
+        };
+
+        let content_type = output
+            .content_type()
+            .unwrap_or("application/octet-stream")
+            .to_string();
+        let data = output.body.collect().await.map_err(|e| {
+            tracing::error!(
+                "Failed to read {} for photo {} from S3: {}",
+                object.name(),
+                photo_id,
+                e
+            );
+            AppError::Internal {
+                message: "Photo storage is unavailable".to_string(),
+            }
+        })?;
+
+        Ok(Some(StoredObject {
+            data: data.to_vec(),
+            content_type,
+        }))
+    }
+
+    async fn delete(&self, photo_id: &PhotoId, object: PhotoObject) -> Result<(), AppError> {
+        // S3 reports success for keys that don't exist
+        self.client()
+            .await
+            .delete_object()
+            .bucket(&self.config.bucket)
+            .key(object.key(photo_id))
+            .send()
+            .await
+            .map_err(|e| storage_error("delete", object, photo_id, e))?;
+
+        self.legacy.delete(photo_id, object).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_s3::config::Credentials;
+    use axum::{
+        body::Bytes,
+        extract::{Path, State},
+        http::{header, HeaderMap, StatusCode},
+        response::IntoResponse,
+        routing::put,
+        Router,
+    };
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    type Objects = Arc<Mutex<HashMap<String, StoredObject>>>;
+
+    fn s3_config(endpoint: String) -> S3Config {
+        S3Config {
+            bucket: "plants".to_string(),
+            region: "eu-north-1".to_string(),
+            endpoint: Some(endpoint),
+        }
+    }
+
+    async fn setup_test_db() -> DatabasePool {
+        let pool = crate::database::create_pool_with_url("sqlite::memory:")
+            .await
+            .expect("Failed to create test database");
+
+        crate::database::run_migrations(&pool)
+            .await
+            .expect("Failed to run migrations");
+
+        pool
+    }
+
+    /// A store signing with temporary credentials, as from an instance role
+    async fn s3_store(config: S3Config, access_key_id: &str) -> S3PhotoStore {
+        let builder = aws_sdk_s3::Config::builder()
+            .behavior_version(BehaviorVersion::latest())
+            .region(Region::new(config.region.clone()))
+            .credentials_provider(Credentials::new(
+                access_key_id,
+                "test-secret",
+                Some("test-session-token".to_string()),
+                None,
+                "test",
+            ));
+        let client = aws_sdk_s3::Client::from_conf(config.client_config(builder));
+
+        S3PhotoStore {
+            config,
+            client: OnceCell::new_with(Some(client)),
+            legacy: DatabasePhotoStore::new(setup_test_db().await),
+        }
+    }
+
+    /// Whether a request is signed with the test credentials and session token
+    fn is_signed(headers: &HeaderMap) -> bool {
+        let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+        header("authorization").is_some_and(|value| {
+            value.starts_with("AWS4-HMAC-SHA256 Credential=test-key/")
+                && value.contains("/eu-north-1/s3/aws4_request,")
+                && value.contains("x-amz-security-token")
+        }) && header("x-amz-security-token") == Some("test-session-token")
+    }
+
+    fn s3_error(status: StatusCode, code: &str) -> axum::response::Response {
+        let body =
+            format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?><Error><Code>{code}</Code></Error>");
+        (status, [(header::CONTENT_TYPE, "application/xml")], body).into_response()
+    }
+
+    /// An in-memory stand-in for the S3 object API
+    async fn spawn_mock_s3() -> (String, Objects) {
+        async fn put_object(
+            State(objects): State<Objects>,
+            Path((bucket, key)): Path<(String, String)>,
+            headers: HeaderMap,
+            body: Bytes,
+        ) -> axum::response::Response {
+            if !is_signed(&headers) {
+                return s3_error(StatusCode::FORBIDDEN, "AccessDenied");
+            }
+            let content_type = headers
+                .get(header::CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default()
+                .to_string();
+            let mut objects = objects.lock().unwrap();
+            objects.insert(
+                format!("{bucket}/{key}"),
+                StoredObject {
+                    data: body.to_vec(),
+                    content_type,
+                },
+            );
+            StatusCode::OK.into_response()
+        }
+
+        async fn get_object(
+            State(objects): State<Objects>,
+            Path((bucket, key)): Path<(String, String)>,
+            headers: HeaderMap,
+        ) -> axum::response::Response {
+            if !is_signed(&headers) {
+                return s3_error(StatusCode::FORBIDDEN, "AccessDenied");
+            }
+            let objects = objects.lock().unwrap();
+            match objects.get(&format!("{bucket}/{key}")) {
+                Some(object) => (
+                    [(header::CONTENT_TYPE, object.content_type.clone())],
+                    object.data.clone(),
+                )
+                    .into_response(),
+                None => s3_error(StatusCode::NOT_FOUND, "NoSuchKey"),
+            }
+        }
+
+        async fn delete_object(
+            State(objects): State<Objects>,
+            Path((bucket, key)): Path<(String, String)>,
+            headers: HeaderMap,
+        ) -> axum::response::Response {
+            if !is_signed(&headers) {
+                return s3_error(StatusCode::FORBIDDEN, "AccessDenied");
+            }
+            let mut objects = objects.lock().unwrap();
+            objects.remove(&format!("{bucket}/{key}"));
+            StatusCode::NO_CONTENT.into_response()
+        }
+
+        let objects = Objects::default();
+        let app = Router::new()
+            .route(
+                "/:bucket/:key",
+                put(put_object).get(get_object).delete(delete_object),
+            )
+            .with_state(objects.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        (format!("http://{}", address), objects)
+    }
+
+    #[tokio::test]
+    async fn test_s3_store_round_trip() {
+        let (endpoint, objects) = spawn_mock_s3().await;
+        let store = s3_store(s3_config(endpoint), "test-key").await;
+        let photo_id = PhotoId(Uuid::new_v4());
+
+        assert_eq!(
+            store.get(&photo_id, PhotoObject::Image).await.unwrap(),
+            None
+        );
+
+        store
+            .put(&photo_id, PhotoObject::Image, b"first", "image/avif")
+            .await
+            .unwrap();
+        store
+            .put(&photo_id, PhotoObject::Image, b"second", "image/avif")
+            .await
+            .unwrap();
+        store
+            .put(&photo_id, PhotoObject::Original, b"original", "image/png")
+            .await
+            .unwrap();
+        assert_eq!(
+            store.get(&photo_id, PhotoObject::Image).await.unwrap(),
+            Some(StoredObject {
+                data: b"second".to_vec(),
+                content_type: "image/avif".to_string(),
+            })
+        );
+        {
+            let objects = objects.lock().unwrap();
+            assert_eq!(
+                objects
+                    .get(&format!("plants/{photo_id}"))
+                    .map(|object| object.data.as_slice()),
+                Some(&b"second"[..])
+            );
+            assert_eq!(
+                objects
+                    .get(&format!("plants/{photo_id}.original"))
+                    .map(|object| object.content_type.as_str()),
+                Some("image/png")
+            );
+        }
+
+        delete_images(&store, &[photo_id]).await;
+        assert!(objects.lock().unwrap().is_empty());
+        assert_eq!(
+            store.get(&photo_id, PhotoObject::Image).await.unwrap(),
+            None
+        );
+        // Deleting again is fine
+        store.delete(&photo_id, PhotoObject::Image).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_s3_store_reads_images_stored_before_switching_to_s3() {
+        let (endpoint, objects) = spawn_mock_s3().await;
+        let store = s3_store(s3_config(endpoint), "test-key").await;
+        let photo_id = PhotoId(Uuid::new_v4());
+        store
+            .legacy
+            .put(
+                &photo_id,
+                PhotoObject::Image,
+                b"from the database",
+                "image/avif",
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            store.get(&photo_id, PhotoObject::Image).await.unwrap(),
+            Some(StoredObject {
+                data: b"from the database".to_vec(),
+                content_type: "image/avif".to_string(),
+            })
+        );
+        assert!(objects.lock().unwrap().is_empty());
+
+        // The bucket wins once the image is stored there
+        store
+            .put(
+                &photo_id,
+                PhotoObject::Image,
+                b"from the bucket",
+                "image/avif",
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            store
+                .get(&photo_id, PhotoObject::Image)
+                .await
+                .unwrap()
+                .map(|object| object.data),
+            Some(b"from the bucket".to_vec())
+        );
+
+        delete_images(&store, &[photo_id]).await;
+        assert_eq!(
+            store.get(&photo_id, PhotoObject::Image).await.unwrap(),
+            None
+        );
+        assert_eq!(
+            store
+                .legacy
+                .get(&photo_id, PhotoObject::Image)
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_s3_store_reports_rejected_requests() {
+        let (endpoint, _objects) = spawn_mock_s3().await;
+        let store = s3_store(s3_config(endpoint), "someone-else").await;
+        let photo_id = PhotoId(Uuid::new_v4());
+
+        assert!(store
+            .put(&photo_id, PhotoObject::Image, b"image", "image/avif")
+            .await
+            .is_err());
+        assert!(store.get(&photo_id, PhotoObject::Image).await.is_err());
+        assert!(store.delete(&photo_id, PhotoObject::Image).await.is_err());
+    }
+}
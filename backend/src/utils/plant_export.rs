@@ -0,0 +1,67 @@
+use chrono::{DateTime, Utc};
+
+use crate::models::plant::{next_due, PlantResponse};
+use crate::utils::errors::AppError;
+
+const HEADER: [&str; 9] = [
+    "id",
+    "name",
+    "genus",
+    "watering_interval_days",
+    "fertilizing_interval_days",
+    "last_watered",
+    "last_fertilized",
+    "next_watering_due",
+    "next_fertilizing_due",
+];
+
+/// Write one CSV row per plant with its care intervals, last care and next due dates
+///
+/// Dates are RFC 3339 in UTC; columns without a value are left empty.
+///
+/// # Errors
+/// * Returns `Internal` if the CSV couldn't be written
+pub fn plants_to_csv(plants: &[PlantResponse]) -> Result<String, AppError> {
+    let csv_error = |e: csv::Error| AppError::Internal {
+        message: format!("Failed to write plants CSV: {e}"),
+    };
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(HEADER).map_err(csv_error)?;
+
+    for plant in plants {
+        writer
+            .write_record([
+                plant.id.to_string(),
+                plant.name.clone(),
+                plant.genus.clone(),
+                optional(plant.watering_schedule.interval_days),
+                optional(plant.fertilizing_schedule.interval_days),
+                optional_date(plant.last_watered),
+                optional_date(plant.last_fertilized),
+                optional_date(next_due(&plant.watering_schedule, plant.last_watered)),
+                optional_date(next_due(
+                    &plant.fertilizing_schedule,
+                    plant.last_fertilized,
+                )),
+            ])
+            .map_err(csv_error)?;
+    }
+
+    let bytes = writer.into_inner().map_err(|e| AppError::Internal {
+        message: format!("Failed to write plants CSV: {e}"),
+    })?;
+    String::from_utf8(bytes).map_err(|e| AppError::Internal {
+        message: format!("Plants CSV is not valid UTF-8: {e}"),
+    })
+}
+
+fn optional(value: Option<i32>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+fn optional_date(value: Option<DateTime<Utc>>) -> String {
+    value
+        .map(|date| date.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+        .unwrap_or_default()
+}
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use crate::utils::errors::Result;
+
+/// How long a resolved task list id is reused before it is looked up again
+const TASK_LIST_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Each user's "Plant Care" Google task list id, remembered for a short while
+/// so a sync or a run of task creations looks the list up once
+#[derive(Debug, Clone)]
+pub struct TaskListCache {
+    ttl: Duration,
+    lists: Arc<RwLock<HashMap<String, (String, Instant)>>>,
+}
+
+impl Default for TaskListCache {
+    fn default() -> Self {
+        Self::with_ttl(TASK_LIST_TTL)
+    }
+}
+
+impl TaskListCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_ttl(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            lists: Arc::default(),
+        }
+    }
+
+    /// The user's cached task list id, or else the one `resolve` finds, which is
+    /// then cached. Failed lookups aren't cached.
+    pub async fn get_or_resolve<F, Fut>(&self, user_id: &str, resolve: F) -> Result<String>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<String>>,
+    {
+        if let Some(task_list_id) = self.cached(user_id) {
+            return Ok(task_list_id);
+        }
+
+        let task_list_id = resolve().await?;
+        let mut lists = self.lists.write().unwrap_or_else(|e| e.into_inner());
+        lists.insert(user_id.to_string(), (task_list_id.clone(), Instant::now()));
+        Ok(task_list_id)
+    }
+
+    /// Forget the user's task list, e.g. when they connect another account
+    pub fn forget(&self, user_id: &str) {
+        let mut lists = self.lists.write().unwrap_or_else(|e| e.into_inner());
+        lists.remove(user_id);
+    }
+
+    fn cached(&self, user_id: &str) -> Option<String> {
+        let lists = self.lists.read().unwrap_or_else(|e| e.into_inner());
+        lists
+            .get(user_id)
+            .filter(|(_, resolved_at)| resolved_at.elapsed() < self.ttl)
+            .map(|(task_list_id, _)| task_list_id.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::errors::AppError;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_task_list_resolved_once_across_sync() {
+        let cache = TaskListCache::new();
+        let lookups = AtomicUsize::new(0);
+        let resolve = || async {
+            lookups.fetch_add(1, Ordering::SeqCst);
+            Ok("plant-care-list".to_string())
+        };
+
+        // One lookup per plant, as a multi-plant sync followed by task creations would
+        for _ in 0..5 {
+            let task_list_id = cache.get_or_resolve("user-1", resolve).await.unwrap();
+            assert_eq!(task_list_id, "plant-care-list");
+        }
+        assert_eq!(lookups.load(Ordering::SeqCst), 1);
+
+        // Other users have their own lists
+        cache.get_or_resolve("user-2", resolve).await.unwrap();
+        assert_eq!(lookups.load(Ordering::SeqCst), 2);
+
+        // Reconnecting may be to another account, so the list is looked up again
+        cache.forget("user-1");
+        cache.get_or_resolve("user-1", resolve).await.unwrap();
+        assert_eq!(lookups.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_expired_and_failed_lookups_are_retried() {
+        let cache = TaskListCache::with_ttl(Duration::ZERO);
+        let lookups = AtomicUsize::new(0);
+        let resolve = || async {
+            lookups.fetch_add(1, Ordering::SeqCst);
+            Ok("plant-care-list".to_string())
+        };
+
+        cache.get_or_resolve("user-1", resolve).await.unwrap();
+        cache.get_or_resolve("user-1", resolve).await.unwrap();
+        assert_eq!(lookups.load(Ordering::SeqCst), 2);
+
+        let cache = TaskListCache::new();
+        let failed = cache
+            .get_or_resolve("user-1", || async {
+                Err(AppError::External {
+                    message: "Google Tasks API request failed".to_string(),
+                })
+            })
+            .await;
+        assert!(failed.is_err());
+        cache.get_or_resolve("user-1", resolve).await.unwrap();
+        assert_eq!(lookups.load(Ordering::SeqCst), 3);
+    }
+}
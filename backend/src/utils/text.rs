@@ -0,0 +1,55 @@
+use validator::ValidationError;
+
+/// Trims the value and collapses internal whitespace runs into a single space.
+pub fn normalize_whitespace(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Rejects display text that is blank or contains control characters.
+///
+/// Intended for use with `#[validate(custom(function = "validate_display_text"))]`;
+/// accepted values should be stored via [`normalize_whitespace`].
+pub fn validate_display_text(value: &str) -> Result<(), ValidationError> {
+    if value.chars().any(char::is_control) {
+        return Err(ValidationError::new("control_characters"));
+    }
+
+    if value.trim().is_empty() {
+        return Err(ValidationError::new("blank"));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_whitespace_trims_and_collapses() {
+        assert_eq!(normalize_whitespace("  Ficus  "), "Ficus");
+        assert_eq!(normalize_whitespace("Ficus   lyrata"), "Ficus lyrata");
+        assert_eq!(normalize_whitespace("Monstera"), "Monstera");
+    }
+
+    #[test]
+    fn test_validate_display_text_accepts_plain_text() {
+        assert!(validate_display_text("Fiddle Leaf Fig").is_ok());
+        assert!(validate_display_text("  Ficus  ").is_ok());
+    }
+
+    #[test]
+    fn test_validate_display_text_rejects_control_characters() {
+        let error = validate_display_text("Ficus\nlyrata").unwrap_err();
+        assert_eq!(error.code, "control_characters");
+
+        assert!(validate_display_text("Ficus\tlyrata").is_err());
+        assert!(validate_display_text("Ficus\u{0}").is_err());
+    }
+
+    #[test]
+    fn test_validate_display_text_rejects_blank() {
+        let error = validate_display_text("   ").unwrap_err();
+        assert_eq!(error.code, "blank");
+    }
+}
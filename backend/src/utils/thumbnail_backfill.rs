@@ -0,0 +1,190 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::sync::{Arc, RwLock};
+
+use crate::database::{photos, DatabasePool};
+use crate::models::PhotoId;
+use crate::utils::errors::AppError;
+use crate::utils::image_processing::generate_thumbnail;
+use crate::utils::job_registry::JobRegistry;
+use crate::utils::photo_store::PhotoStore;
+
+/// Name the backfill reports under in the job registry
+pub const JOB_NAME: &str = "thumbnail_backfill";
+
+/// Photos loaded per query while backfilling
+const BATCH_SIZE: i64 = 50;
+
+/// Lifecycle of the thumbnail backfill
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BackfillState {
+    #[default]
+    Idle,
+    Running,
+    Completed,
+    Failed,
+}
+
+/// Progress of the current (or most recent) thumbnail backfill
+#[derive(Debug, Clone, Default, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BackfillProgress {
+    pub state: BackfillState,
+    /// Photos without a thumbnail when the run started
+    pub total: i64,
+    pub processed: i64,
+    pub generated: i64,
+    /// Photos with nothing this build can decode (AVIF-only without `avif-decoder`)
+    pub skipped: i64,
+    pub failed: i64,
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub error: Option<String>,
+}
+
+/// Generates thumbnails for photos uploaded before thumbnails existed
+///
+/// Only photos with no thumbnail are selected, so a run that was interrupted
+/// picks up where it left off when started again.
+#[derive(Debug, Clone, Default)]
+pub struct ThumbnailBackfill {
+    progress: Arc<RwLock<BackfillProgress>>,
+}
+
+impl ThumbnailBackfill {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn progress(&self) -> BackfillProgress {
+        self.progress
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Start a backfill in the background unless one is already running
+    ///
+    /// Returns the progress right after starting (or of the running backfill).
+    pub fn start(
+        &self,
+        pool: DatabasePool,
+        store: Arc<dyn PhotoStore>,
+        registry: JobRegistry,
+    ) -> BackfillProgress {
+        {
+            let mut progress = self.progress.write().unwrap_or_else(|e| e.into_inner());
+            if progress.state == BackfillState::Running {
+                return progress.clone();
+            }
+            *progress = BackfillProgress {
+                state: BackfillState::Running,
+                started_at: Some(Utc::now()),
+                ..BackfillProgress::default()
+            };
+        }
+
+        registry.register(JOB_NAME);
+        let backfill = self.clone();
+        tokio::spawn(async move {
+            let result = backfill.run(&pool, store.as_ref()).await;
+            if let Err(e) = &result {
+                tracing::error!("Thumbnail backfill failed: {}", e);
+            }
+
+            let progress = backfill.update(|progress| {
+                progress.finished_at = Some(Utc::now());
+                match &result {
+                    Ok(()) => progress.state = BackfillState::Completed,
+                    Err(e) => {
+                        progress.state = BackfillState::Failed;
+                        progress.error = Some(e.to_string());
+                    }
+                }
+            });
+            tracing::info!(
+                "Thumbnail backfill finished: {} generated, {} skipped, {} failed",
+                progress.generated,
+                progress.skipped,
+                progress.failed
+            );
+
+            registry.record_run(JOB_NAME, result.map_err(|e| e.to_string()));
+        });
+
+        self.progress()
+    }
+
+    async fn run(&self, pool: &DatabasePool, store: &dyn PhotoStore) -> Result<(), AppError> {
+        let total = photos::count_photos_missing_thumbnail(pool).await?;
+        self.update(|progress| progress.total = total);
+        tracing::info!("Starting thumbnail backfill for {} photos", total);
+
+        // Walk by id so photos that fail aren't retried within the same run
+        let mut after_id = None;
+        loop {
+            let ids =
+                photos::list_photos_missing_thumbnail(pool, after_id.as_ref(), BATCH_SIZE).await?;
+            let Some(last_id) = ids.last().copied() else {
+                break;
+            };
+
+            for photo_id in &ids {
+                let result = self.generate_for_photo(pool, store, photo_id).await;
+                if let Err(e) = &result {
+                    tracing::warn!("Failed to generate thumbnail for photo {}: {}", photo_id, e);
+                }
+
+                self.update(|progress| {
+                    progress.processed += 1;
+                    match result {
+                        Ok(true) => progress.generated += 1,
+                        Ok(false) => progress.skipped += 1,
+                        Err(_) => progress.failed += 1,
+                    }
+                });
+            }
+
+            let progress = self.progress();
+            tracing::info!(
+                "Thumbnail backfill progress: {}/{}",
+                progress.processed,
+                progress.total
+            );
+            after_id = Some(last_id);
+        }
+
+        Ok(())
+    }
+
+    /// Generate and save a photo's thumbnail; `false` if it was skipped
+    async fn generate_for_photo(
+        &self,
+        pool: &DatabasePool,
+        store: &dyn PhotoStore,
+        photo_id: &PhotoId,
+    ) -> Result<bool, AppError> {
+        let Some(source) = photos::thumbnail_source(store, photo_id).await? else {
+            tracing::info!(
+                "Skipping thumbnail for photo {}: it has no image this build can decode \
+                 (AVIF needs the avif-decoder feature)",
+                photo_id
+            );
+            return Ok(false);
+        };
+        let thumbnail = generate_thumbnail(&source.data)
+            .await
+            .map_err(|e| AppError::Internal {
+                message: format!("{e:#}"),
+            })?;
+        photos::save_photo_thumbnail(pool, photo_id, &thumbnail).await?;
+        Ok(true)
+    }
+
+    fn update(&self, f: impl FnOnce(&mut BackfillProgress)) -> BackfillProgress {
+        let mut progress = self.progress.write().unwrap_or_else(|e| e.into_inner());
+        f(&mut progress);
+        progress.clone()
+    }
+}
@@ -6,20 +6,27 @@ use tokio::time::{sleep_until, Duration, Instant};
 use crate::database::{google_oauth, DatabasePool};
 use crate::utils::google_tasks::{refresh_access_token, GoogleTasksConfig};
 use crate::utils::errors::Result;
+use crate::utils::job_registry::JobRegistry;
+
+/// Name the scheduler reports under in the job registry
+pub const JOB_NAME: &str = "token_refresh";
 
 /// Background task scheduler for refreshing Google OAuth tokens
 pub struct TokenRefreshScheduler {
     pool: DatabasePool,
     config: GoogleTasksConfig,
     notify: Arc<Notify>,
+    registry: JobRegistry,
 }
 
 impl TokenRefreshScheduler {
-    pub fn new(pool: DatabasePool, config: GoogleTasksConfig) -> Self {
+    pub fn new(pool: DatabasePool, config: GoogleTasksConfig, registry: JobRegistry) -> Self {
+        registry.register(JOB_NAME);
         Self {
             pool,
             config,
             notify: Arc::new(Notify::new()),
+            registry,
         }
     }
 
@@ -34,26 +41,35 @@ impl TokenRefreshScheduler {
         
         loop {
             // First, refresh any tokens that need immediate refreshing
-            if let Err(e) = self.refresh_expired_tokens().await {
+            let result = self.refresh_expired_tokens().await;
+            if let Err(e) = &result {
                 tracing::error!("Failed to refresh expired tokens: {}", e);
             }
+            self.registry
+                .record_run(JOB_NAME, result.map_err(|e| e.to_string()));
 
-            // Calculate when to wake up next
+            // Calculate when to wake up next, never sleeping past the poll interval
+            let poll_deadline = Instant::now() + self.poll_interval();
             let wake_time = match self.calculate_next_wake_time().await {
-                Ok(Some(wake_time)) => wake_time,
+                Ok(Some(wake_time)) => wake_time.min(poll_deadline),
                 Ok(None) => {
-                    // No tokens to refresh, wait indefinitely for notification
-                    tracing::info!("No tokens to refresh, waiting for notification");
-                    self.notify.notified().await;
-                    continue;
+                    tracing::info!("No tokens to refresh, waiting for notification or next poll");
+                    poll_deadline
                 }
                 Err(e) => {
                     tracing::error!("Failed to calculate next wake time: {}", e);
-                    // Fallback: check again in 1 hour
-                    Instant::now() + Duration::from_secs(3600)
+                    poll_deadline
                 }
             };
 
+            let until_wake = wake_time.saturating_duration_since(Instant::now());
+            self.registry.set_next_run(
+                JOB_NAME,
+                chrono::Duration::from_std(until_wake)
+                    .ok()
+                    .map(|duration| Utc::now() + duration),
+            );
+
             // Sleep until the next wake time or until notified
             tracing::info!("Token scheduler sleeping until: {:?}", wake_time);
             
@@ -68,9 +84,19 @@ impl TokenRefreshScheduler {
         }
     }
 
+    /// Longest the scheduler sleeps before checking tokens again
+    fn poll_interval(&self) -> Duration {
+        self.config
+            .refresh_poll_interval
+            .to_std()
+            .unwrap_or(Duration::from_secs(3600))
+    }
+
     /// Refresh all tokens that are expiring soon
     async fn refresh_expired_tokens(&self) -> Result<()> {
-        let tokens = google_oauth::get_tokens_needing_refresh(&self.pool).await?;
+        let tokens =
+            google_oauth::get_tokens_needing_refresh(&self.pool, self.config.refresh_margin)
+                .await?;
         
         if tokens.is_empty() {
             tracing::debug!("No tokens need refreshing");
@@ -116,8 +142,8 @@ impl TokenRefreshScheduler {
         let next_expiration = google_oauth::get_next_token_expiration(&self.pool).await?;
         
         if let Some(expiration) = next_expiration {
-            // Wake up 10 minutes before the token expires
-            let wake_time = expiration - chrono::Duration::minutes(10);
+            // Wake up when the token enters the refresh margin
+            let wake_time = expiration - self.config.refresh_margin;
             let now = Utc::now();
             
             if wake_time <= now {
@@ -142,8 +168,9 @@ impl TokenRefreshScheduler {
 pub fn start_token_refresh_scheduler(
     pool: DatabasePool,
     config: GoogleTasksConfig,
+    registry: JobRegistry,
 ) -> Arc<Notify> {
-    let scheduler = TokenRefreshScheduler::new(pool, config);
+    let scheduler = TokenRefreshScheduler::new(pool, config, registry);
     let notifier = scheduler.get_notifier();
     
     tokio::spawn(async move {
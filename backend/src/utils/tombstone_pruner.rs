@@ -0,0 +1,47 @@
+use chrono::Utc;
+use tokio::time::Duration;
+
+use crate::database::{deletions, DatabasePool};
+use crate::utils::job_registry::JobRegistry;
+
+/// Name the pruner reports under in the job registry
+pub const JOB_NAME: &str = "tombstone_pruner";
+
+/// How often expired tombstones are pruned
+const PRUNE_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Start a background task that deletes tombstones older than `retention`.
+///
+/// Clients that have not synced within the retention window may miss
+/// deletions and should fall back to a full sync.
+pub fn start_tombstone_pruner(
+    pool: DatabasePool,
+    retention: chrono::Duration,
+    registry: JobRegistry,
+) {
+    tracing::info!(
+        "Starting tombstone pruner (retention: {} days)",
+        retention.num_days()
+    );
+
+    registry.spawn_periodic(JOB_NAME, PRUNE_INTERVAL, move || {
+        let pool = pool.clone();
+        async move {
+            let cutoff = Utc::now() - retention;
+            match deletions::prune_deletions_before(&pool, cutoff).await {
+                Ok(0) => {
+                    tracing::debug!("No expired tombstones to prune");
+                    Ok(())
+                }
+                Ok(count) => {
+                    tracing::info!("Pruned {} expired tombstones", count);
+                    Ok(())
+                }
+                Err(e) => {
+                    tracing::error!("Failed to prune tombstones: {}", e);
+                    Err(e.to_string())
+                }
+            }
+        }
+    });
+}
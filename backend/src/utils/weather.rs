@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use crate::utils::errors::AppError;
+
+/// Default Open-Meteo forecast endpoint, overridable with `WEATHER_API_URL`
+pub const OPEN_METEO_URL: &str = "https://api.open-meteo.com/v1/forecast";
+
+/// Source of recent precipitation for weather-aware watering suggestions
+#[async_trait::async_trait]
+pub trait WeatherProvider: Send + Sync {
+    /// Short name reported alongside suggestions
+    fn name(&self) -> &'static str;
+
+    /// Total precipitation in millimetres over the last `days` days
+    ///
+    /// Returns `None` when the provider has no data for the location.
+    async fn recent_precipitation_mm(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        days: u32,
+    ) -> Result<Option<f64>, AppError>;
+}
+
+/// Provider used unless an external one is configured; never has data
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StubWeatherProvider;
+
+#[async_trait::async_trait]
+impl WeatherProvider for StubWeatherProvider {
+    fn name(&self) -> &'static str {
+        "stub"
+    }
+
+    async fn recent_precipitation_mm(
+        &self,
+        _latitude: f64,
+        _longitude: f64,
+        _days: u32,
+    ) -> Result<Option<f64>, AppError> {
+        Ok(None)
+    }
+}
+
+/// Which weather provider to use (`WEATHER_PROVIDER`)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum WeatherSource {
+    #[default]
+    Stub,
+    /// Open-Meteo, only available with the `weather-api` feature
+    OpenMeteo { url: String },
+}
+
+impl WeatherSource {
+    /// Build the provider this source describes
+    pub fn provider(&self) -> Arc<dyn WeatherProvider> {
+        match self {
+            WeatherSource::Stub => Arc::new(StubWeatherProvider),
+            #[cfg(feature = "weather-api")]
+            WeatherSource::OpenMeteo { url } => Arc::new(OpenMeteoProvider::new(url.clone())),
+            #[cfg(not(feature = "weather-api"))]
+            WeatherSource::OpenMeteo { url } => {
+                // Config loading rejects this, so only a hand-built config gets here
+                tracing::warn!(
+                    "Open-Meteo ({}) needs the weather-api feature, using the stub provider",
+                    url
+                );
+                Arc::new(StubWeatherProvider)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "weather-api")]
+pub use open_meteo::OpenMeteoProvider;
+
+#[cfg(feature = "weather-api")]
+mod open_meteo {
+    use serde::Deserialize;
+    use std::time::Duration;
+
+    use super::WeatherProvider;
+    use crate::utils::errors::AppError;
+
+    const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// Daily precipitation sums from the Open-Meteo forecast API (no API key needed)
+    #[derive(Debug, Clone)]
+    pub struct OpenMeteoProvider {
+        client: reqwest::Client,
+        url: String,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct ForecastResponse {
+        daily: DailyPrecipitation,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct DailyPrecipitation {
+        precipitation_sum: Vec<Option<f64>>,
+    }
+
+    impl OpenMeteoProvider {
+        pub fn new(url: String) -> Self {
+            Self {
+                client: reqwest::Client::new(),
+                url,
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl WeatherProvider for OpenMeteoProvider {
+        fn name(&self) -> &'static str {
+            "open-meteo"
+        }
+
+        async fn recent_precipitation_mm(
+            &self,
+            latitude: f64,
+            longitude: f64,
+            days: u32,
+        ) -> Result<Option<f64>, AppError> {
+            let weather_error = |e: reqwest::Error| {
+                tracing::error!("Open-Meteo request failed: {}", e);
+                AppError::Internal {
+                    message: "Failed to fetch weather data".to_string(),
+                }
+            };
+
+            // Today's sum is included: rain this morning counts as recent
+            let forecast: ForecastResponse = self
+                .client
+                .get(&self.url)
+                .timeout(REQUEST_TIMEOUT)
+                .query(&[
+                    ("latitude", latitude.to_string()),
+                    ("longitude", longitude.to_string()),
+                    ("daily", "precipitation_sum".to_string()),
+                    ("past_days", days.to_string()),
+                    ("forecast_days", "1".to_string()),
+                    ("timezone", "UTC".to_string()),
+                ])
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status)
+                .map_err(weather_error)?
+                .json()
+                .await
+                .map_err(weather_error)?;
+
+            let sums: Vec<f64> = forecast
+                .daily
+                .precipitation_sum
+                .into_iter()
+                .flatten()
+                .collect();
+            Ok((!sums.is_empty()).then(|| sums.iter().sum()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_stub_provider_has_no_data() {
+        let provider = WeatherSource::default().provider();
+
+        assert_eq!(provider.name(), "stub");
+        assert_eq!(
+            provider
+                .recent_precipitation_mm(55.68, 12.57, 3)
+                .await
+                .unwrap(),
+            None
+        );
+    }
+}
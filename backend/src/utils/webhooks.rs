@@ -0,0 +1,288 @@
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha2::Sha256;
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+use crate::utils::hex::to_hex;
+
+/// Header carrying `sha256=<hex HMAC>` of `"{timestamp}.{body}"`
+pub const SIGNATURE_HEADER: &str = "X-Planty-Signature";
+/// Header carrying the Unix timestamp included in the signature
+pub const TIMESTAMP_HEADER: &str = "X-Planty-Timestamp";
+/// Header naming the delivered event
+pub const EVENT_HEADER: &str = "X-Planty-Event";
+
+/// How long to wait for a webhook endpoint to answer
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Why a URL can't be used as a webhook endpoint
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookTargetError {
+    #[error("Webhook URLs must use http or https")]
+    Scheme,
+    #[error("Webhook URL is missing a host")]
+    MissingHost,
+    #[error("Webhook URLs must point to a public address")]
+    PrivateAddress,
+    #[error("Webhook host could not be resolved")]
+    Unresolved,
+}
+
+impl WebhookTargetError {
+    /// Validation error code reported for the URL
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::Scheme => "scheme",
+            Self::MissingHost | Self::Unresolved => "host",
+            Self::PrivateAddress => "private_address",
+        }
+    }
+}
+
+/// Returned when a test delivery got no answer from the endpoint
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookDeliveryError {
+    #[error(transparent)]
+    Target(#[from] WebhookTargetError),
+    #[error(transparent)]
+    Request(#[from] reqwest::Error),
+}
+
+/// Check that `url` is an http(s) URL the server may deliver to
+///
+/// Unless `allow_private` is set, hosts given as an IP address (or `localhost`)
+/// must be public. Host names can point anywhere and are checked again on
+/// every delivery, once resolved.
+///
+/// # Errors
+/// * Returns `WebhookTargetError` describing why the URL can't be used
+pub fn check_webhook_url(
+    url: &str,
+    allow_private: bool,
+) -> Result<reqwest::Url, WebhookTargetError> {
+    let url = reqwest::Url::parse(url).map_err(|_| WebhookTargetError::MissingHost)?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(WebhookTargetError::Scheme);
+    }
+
+    let host = url
+        .host_str()
+        .filter(|host| !host.is_empty())
+        .ok_or(WebhookTargetError::MissingHost)?;
+    if allow_private {
+        return Ok(url);
+    }
+
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    let public = match host.parse::<IpAddr>() {
+        Ok(ip) => is_public_address(ip),
+        Err(_) => host != "localhost" && !host.ends_with(".localhost"),
+    };
+    if !public {
+        return Err(WebhookTargetError::PrivateAddress);
+    }
+
+    Ok(url)
+}
+
+/// Whether `ip` is reachable on the public internet, rather than loopback,
+/// link-local (cloud metadata services) or a private network
+fn is_public_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [first, second, ..] = ip.octets();
+            // "This network" 0.0.0.0/8 and carrier-grade NAT 100.64.0.0/10
+            let reserved = first == 0 || (first == 100 && (second & 0xc0) == 64);
+            !(reserved
+                || ip.is_private()
+                || ip.is_loopback()
+                || ip.is_link_local()
+                || ip.is_broadcast()
+                || ip.is_documentation()
+                || ip.is_multicast())
+        }
+        IpAddr::V6(ip) => {
+            if let Some(ip) = ip.to_ipv4_mapped() {
+                return is_public_address(IpAddr::V4(ip));
+            }
+            let first = ip.segments()[0];
+            // Unique local fc00::/7 and link-local fe80::/10
+            let local = (first & 0xfe00) == 0xfc00 || (first & 0xffc0) == 0xfe80;
+            !(local || ip.is_loopback() || ip.is_unspecified() || ip.is_multicast())
+        }
+    }
+}
+
+/// Resolve `host`, failing unless every address it has is public
+async fn resolve_public(host: &str, port: u16) -> Result<Vec<SocketAddr>, WebhookTargetError> {
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|_| WebhookTargetError::Unresolved)?
+        .collect();
+
+    if addrs.is_empty() {
+        return Err(WebhookTargetError::Unresolved);
+    }
+    if !addrs.iter().all(|addr| is_public_address(addr.ip())) {
+        return Err(WebhookTargetError::PrivateAddress);
+    }
+
+    Ok(addrs)
+}
+
+/// Generate a new random signing secret
+pub fn generate_secret() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    format!("whsec_{}", to_hex(&bytes))
+}
+
+/// Sign a payload as sent in `SIGNATURE_HEADER`
+///
+/// The timestamp is part of the signed message so receivers can reject
+/// replayed deliveries.
+pub fn sign_payload(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(format!("{timestamp}.{body}").as_bytes());
+    format!("sha256={}", to_hex(&mac.finalize().into_bytes()))
+}
+
+/// Send a signed `test` event, returning the endpoint's HTTP status
+///
+/// Redirects aren't followed. Unless `allow_private` is set, the endpoint's
+/// host must resolve to public addresses only, and the request goes to the
+/// addresses that were checked.
+///
+/// # Errors
+/// * Returns `Target` if the URL isn't one the server may deliver to
+/// * Returns `Request` if the endpoint couldn't be reached
+pub async fn send_test_event(
+    url: &str,
+    secret: &str,
+    allow_private: bool,
+) -> Result<u16, WebhookDeliveryError> {
+    let url = check_webhook_url(url, allow_private)?;
+
+    let mut client = reqwest::Client::builder()
+        .timeout(DELIVERY_TIMEOUT)
+        .redirect(reqwest::redirect::Policy::none());
+    if let (false, Some(host)) = (allow_private, url.domain()) {
+        let port = url.port_or_known_default().unwrap_or(80);
+        client = client.resolve_to_addrs(host, &resolve_public(host, port).await?);
+    }
+
+    let timestamp = Utc::now().timestamp();
+    let body = serde_json::json!({
+        "event": "test",
+        "sent_at": Utc::now(),
+    })
+    .to_string();
+
+    let response = client
+        .build()?
+        .post(url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header(EVENT_HEADER, "test")
+        .header(TIMESTAMP_HEADER, timestamp.to_string())
+        .header(SIGNATURE_HEADER, sign_payload(secret, timestamp, &body))
+        .body(body)
+        .send()
+        .await?;
+
+    Ok(response.status().as_u16())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_payload_matches_known_hmac() {
+        let signature = sign_payload("whsec_test", 1_700_000_000, r#"{"event":"test"}"#);
+
+        assert_eq!(
+            signature,
+            "sha256=21d2d3606ebbdbf9307ee15e83085df2b83c83dd87cc2e6d2ea6b1cb61afdc3c"
+        );
+    }
+
+    #[test]
+    fn test_sign_payload_depends_on_timestamp_and_secret() {
+        let body = r#"{"event":"test"}"#;
+        let signature = sign_payload("whsec_test", 1, body);
+
+        assert_ne!(signature, sign_payload("whsec_test", 2, body));
+        assert_ne!(signature, sign_payload("whsec_other", 1, body));
+    }
+
+    #[test]
+    fn test_check_webhook_url_rejects_private_targets() {
+        for url in [
+            "http://127.0.0.1/hook",
+            "http://localhost:8080/hook",
+            "http://api.localhost/hook",
+            "http://169.254.169.254/latest/meta-data",
+            "http://10.0.0.5/hook",
+            "http://192.168.1.1/hook",
+            "http://100.64.0.1/hook",
+            "http://0.0.0.0/hook",
+            "http://[::1]/hook",
+            "http://[fd00::1]/hook",
+            "http://[fe80::1]/hook",
+            "http://[::ffff:127.0.0.1]/hook",
+        ] {
+            assert!(
+                matches!(
+                    check_webhook_url(url, false),
+                    Err(WebhookTargetError::PrivateAddress)
+                ),
+                "{url}"
+            );
+            assert!(check_webhook_url(url, true).is_ok(), "{url}");
+        }
+    }
+
+    #[test]
+    fn test_check_webhook_url_accepts_public_http_targets() {
+        for url in [
+            "https://example.com/hook",
+            "http://93.184.216.34:8080/hook",
+            "https://[2606:2800:220:1::1]/hook",
+        ] {
+            assert!(check_webhook_url(url, false).is_ok(), "{url}");
+        }
+
+        for url in [
+            "ftp://example.com/hook",
+            "file:///etc/passwd",
+            "gopher://example.com",
+        ] {
+            assert!(
+                matches!(
+                    check_webhook_url(url, true),
+                    Err(WebhookTargetError::Scheme)
+                ),
+                "{url}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_names_resolving_to_private_addresses_are_rejected() {
+        let result = resolve_public("localhost", 80).await;
+
+        assert!(matches!(result, Err(WebhookTargetError::PrivateAddress)));
+    }
+
+    #[test]
+    fn test_generate_secret_is_random() {
+        let secret = generate_secret();
+
+        assert!(secret.starts_with("whsec_"));
+        assert_eq!(secret.len(), "whsec_".len() + 64);
+        assert_ne!(secret, generate_secret());
+    }
+}
@@ -0,0 +1,884 @@
+use serde_json::json;
+
+mod common;
+use common::TestApp;
+
+#[tokio::test]
+async fn test_update_user_with_no_updates_returns_bad_request() {
+    let app = TestApp::new().await;
+
+    let user = common::create_test_user(&app, "user@example.com", "Test User", "password123").await;
+    let user_id = user["user"]["id"].as_str().unwrap();
+
+    // Switch to the admin account created by the helper
+    common::login_user(&app, "test-admin@example.com", "admin123").await;
+
+    let response = app
+        .client
+        .put(app.url(&format!("/admin/users/{}", user_id)))
+        .json(&json!({}))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), 400);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["error"], "bad_request");
+}
+
+#[tokio::test]
+async fn test_update_user_as_non_admin_returns_forbidden() {
+    let app = TestApp::new().await;
+
+    let user = common::create_test_user(&app, "user@example.com", "Test User", "password123").await;
+    let user_id = user["user"]["id"].as_str().unwrap();
+
+    let response = app
+        .client
+        .put(app.url(&format!("/admin/users/{}", user_id)))
+        .json(&json!({ "role": "admin" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), 403);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["error"], "authorization_error");
+}
+
+#[tokio::test]
+async fn test_admin_route_requires_authentication() {
+    let app = TestApp::new().await;
+
+    let response = app
+        .client
+        .get(app.url("/admin/users"))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), 401);
+}
+
+#[tokio::test]
+async fn test_non_admin_cannot_reach_admin_handler() {
+    let app = TestApp::new().await;
+
+    let victim =
+        common::create_test_user(&app, "victim@example.com", "Victim", "password123").await;
+    let victim_id = victim["user"]["id"].as_str().unwrap().to_string();
+
+    app.client
+        .post(app.url("/auth/logout"))
+        .send()
+        .await
+        .unwrap();
+
+    common::create_test_user(&app, "user@example.com", "Test User", "password123").await;
+
+    // Non-admin is rejected before the delete handler runs
+    let response = app
+        .client
+        .delete(app.url(&format!("/admin/users/{}", victim_id)))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), 403);
+
+    // The targeted user must still exist
+    common::login_user(&app, "test-admin@example.com", "admin123").await;
+    let response = app
+        .client
+        .get(app.url("/admin/users"))
+        .send()
+        .await
+        .expect("Failed to list users");
+
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let users = body["users"].as_array().expect("users array");
+    assert!(users.iter().any(|u| u["id"] == victim_id.as_str()));
+}
+
+#[tokio::test]
+async fn test_health_reports_migrations_up_to_date() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "user@example.com", "Test User", "password123").await;
+    common::login_user(&app, "test-admin@example.com", "admin123").await;
+
+    let response = app
+        .client
+        .get(app.url("/admin/health"))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["migrations"], "up_to_date");
+}
+
+#[tokio::test]
+async fn test_health_reports_migrations_behind() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "user@example.com", "Test User", "password123").await;
+    common::login_user(&app, "test-admin@example.com", "admin123").await;
+
+    // Simulate a database that has not applied the latest migration
+    sqlx::query(
+        "DELETE FROM _sqlx_migrations WHERE version = (SELECT MAX(version) FROM _sqlx_migrations)",
+    )
+    .execute(&app.db_pool)
+    .await
+    .expect("Failed to remove migration record");
+
+    let response = app
+        .client
+        .get(app.url("/admin/health"))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["migrations"], "behind");
+}
+
+#[tokio::test]
+async fn test_demoting_one_of_two_admins_is_allowed() {
+    let app = TestApp::new().await;
+
+    let user = common::create_test_user(&app, "second@example.com", "Second", "password123").await;
+    let user_id = user["user"]["id"].as_str().unwrap();
+
+    common::login_user(&app, "test-admin@example.com", "admin123").await;
+
+    let response = app
+        .client
+        .put(app.url(&format!("/admin/users/{}", user_id)))
+        .json(&json!({ "role": "admin" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 200);
+
+    let response = app
+        .client
+        .put(app.url(&format!("/admin/users/{}", user_id)))
+        .json(&json!({ "role": "user" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["role"], "user");
+}
+
+#[tokio::test]
+async fn test_removing_last_admin_is_rejected() {
+    use planty_api::database::users as db_users;
+    use planty_api::utils::errors::AppError;
+
+    let app = TestApp::new().await;
+
+    let user = common::create_test_user(&app, "second@example.com", "Second", "password123").await;
+    let user_id = user["user"]["id"].as_str().unwrap().to_string();
+    let admin_id: String =
+        sqlx::query_scalar("SELECT id FROM users WHERE email = 'test-admin@example.com'")
+            .fetch_one(&app.db_pool)
+            .await
+            .unwrap();
+
+    // Demoting the only admin
+    let result =
+        db_users::ensure_admin_remains(&app.db_pool, std::slice::from_ref(&admin_id)).await;
+    assert!(matches!(result, Err(AppError::BadRequest { .. })));
+
+    // Non-admin targets don't affect the admin count
+    assert!(
+        db_users::ensure_admin_remains(&app.db_pool, std::slice::from_ref(&user_id))
+            .await
+            .is_ok()
+    );
+
+    // With a second admin, either one may be demoted but not both
+    sqlx::query("UPDATE users SET role = 'admin' WHERE id = ?")
+        .bind(&user_id)
+        .execute(&app.db_pool)
+        .await
+        .unwrap();
+
+    assert!(
+        db_users::ensure_admin_remains(&app.db_pool, std::slice::from_ref(&admin_id))
+            .await
+            .is_ok()
+    );
+    let result = db_users::ensure_admin_remains(&app.db_pool, &[admin_id, user_id]).await;
+    assert!(matches!(result, Err(AppError::BadRequest { .. })));
+}
+
+#[tokio::test]
+async fn test_bootstrap_admin_from_env_is_idempotent() {
+    use planty_api::admin::{ensure_bootstrap_admin, BootstrapAdmin};
+
+    let app = TestApp::new().await;
+
+    std::env::set_var("BOOTSTRAP_ADMIN_EMAIL", "bootstrap@example.com");
+    std::env::set_var("BOOTSTRAP_ADMIN_PASSWORD", "bootstrap123");
+    let bootstrap = BootstrapAdmin::from_env().expect("Bootstrap env should be set");
+    std::env::remove_var("BOOTSTRAP_ADMIN_EMAIL");
+    std::env::remove_var("BOOTSTRAP_ADMIN_PASSWORD");
+
+    let created = ensure_bootstrap_admin(&app.db_pool, &bootstrap)
+        .await
+        .expect("Failed to bootstrap admin");
+    assert_eq!(created.unwrap().email, "bootstrap@example.com");
+
+    // A second run finds the admin and does nothing
+    let created = ensure_bootstrap_admin(&app.db_pool, &bootstrap)
+        .await
+        .expect("Failed to bootstrap admin");
+    assert!(created.is_none());
+
+    let admin_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE role = 'admin'")
+        .fetch_one(&app.db_pool)
+        .await
+        .unwrap();
+    assert_eq!(admin_count, 1);
+
+    common::login_user(&app, "bootstrap@example.com", "bootstrap123").await;
+    let response = app
+        .client
+        .get(app.url("/admin/users"))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 200);
+}
+
+#[tokio::test]
+async fn test_jobs_endpoint_reflects_recorded_runs() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "user@example.com", "Test User", "password123").await;
+    common::login_user(&app, "test-admin@example.com", "admin123").await;
+
+    app.job_registry.register("nightly_cleanup");
+    app.job_registry.record_run("nightly_cleanup", Ok(()));
+    let next_run = chrono::Utc::now() + chrono::Duration::hours(24);
+    app.job_registry.set_next_run("nightly_cleanup", Some(next_run));
+    app.job_registry
+        .record_run("token_refresh", Err("refresh failed".to_string()));
+
+    let response = app
+        .client
+        .get(app.url("/admin/jobs"))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let jobs = body["jobs"].as_array().expect("jobs array");
+    assert_eq!(jobs.len(), 2);
+
+    let cleanup = &jobs[0];
+    assert_eq!(cleanup["name"], "nightly_cleanup");
+    assert_eq!(cleanup["lastOutcome"], "success");
+    assert!(cleanup["lastError"].is_null());
+    let last_run_at: chrono::DateTime<chrono::Utc> = cleanup["lastRunAt"]
+        .as_str()
+        .expect("last_run_at set")
+        .parse()
+        .expect("valid timestamp");
+    assert!(chrono::Utc::now() - last_run_at < chrono::Duration::minutes(1));
+    let reported_next: chrono::DateTime<chrono::Utc> = cleanup["nextRunAt"]
+        .as_str()
+        .expect("next_run_at set")
+        .parse()
+        .expect("valid timestamp");
+    assert_eq!(reported_next, next_run);
+
+    let refresh = &jobs[1];
+    assert_eq!(refresh["name"], "token_refresh");
+    assert_eq!(refresh["lastOutcome"], "failure");
+    assert_eq!(refresh["lastError"], "refresh failed");
+    assert!(refresh["nextRunAt"].is_null());
+}
+
+#[tokio::test]
+async fn test_thumbnail_backfill_generates_missing_thumbnails() {
+    let app = TestApp::with_config(|config| config.keep_photo_originals = true).await;
+
+    common::create_test_user(&app, "user@example.com", "Test User", "password123").await;
+    common::login_user(&app, "user@example.com", "password123").await;
+    let plant = common::create_test_plant(&app, "Fern", "Nephrolepis").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let mut photo_ids = Vec::new();
+    for _ in 0..2 {
+        let part = reqwest::multipart::Part::bytes(common::create_test_image_data(400, 300))
+            .file_name("fern.jpg")
+            .mime_str("image/jpeg")
+            .unwrap();
+        let response = app
+            .client
+            .post(app.url(&format!("/plants/{}/photos", plant_id)))
+            .multipart(reqwest::multipart::Form::new().part("file", part))
+            .send()
+            .await
+            .expect("Failed to upload photo");
+        assert_eq!(response.status(), 201);
+        let photo: serde_json::Value = response.json().await.unwrap();
+        photo_ids.push(photo["id"].as_str().unwrap().to_string());
+    }
+
+    // Photos from before thumbnails have no thumbnail, and older ones only their AVIF data
+    sqlx::query("UPDATE photos SET thumbnail_data = NULL")
+        .execute(&app.db_pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM photo_objects WHERE photo_id = ? AND name <> 'image'")
+        .bind(&photo_ids[1])
+        .execute(&app.db_pool)
+        .await
+        .unwrap();
+
+    let missing: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM photos WHERE thumbnail_data IS NULL")
+            .fetch_one(&app.db_pool)
+            .await
+            .unwrap();
+    assert_eq!(missing, 2);
+
+    common::login_user(&app, "test-admin@example.com", "admin123").await;
+
+    let response = app
+        .client
+        .post(app.url("/admin/photos/backfill-thumbnails"))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 202);
+
+    let mut progress = serde_json::Value::Null;
+    for _ in 0..300 {
+        progress = app
+            .client
+            .get(app.url("/admin/photos/backfill-thumbnails"))
+            .send()
+            .await
+            .expect("Failed to send request")
+            .json()
+            .await
+            .expect("Failed to parse response");
+        if progress["state"] != "running" {
+            break;
+        }
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    }
+
+    // Without the avif-decoder feature the AVIF-only photo is skipped
+    let avif_decoding = cfg!(feature = "avif-decoder");
+    assert_eq!(progress["state"], "completed");
+    assert_eq!(progress["total"], 2);
+    assert_eq!(progress["processed"], 2);
+    assert_eq!(progress["generated"], if avif_decoding { 2 } else { 1 });
+    assert_eq!(progress["skipped"], if avif_decoding { 0 } else { 1 });
+    assert_eq!(progress["failed"], 0);
+
+    let missing: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM photos WHERE thumbnail_data IS NULL")
+            .fetch_one(&app.db_pool)
+            .await
+            .unwrap();
+    assert_eq!(missing, if avif_decoding { 0 } else { 1 });
+
+    let thumbnail: Vec<u8> = sqlx::query_scalar("SELECT thumbnail_data FROM photos WHERE id = ?")
+        .bind(&photo_ids[0])
+        .fetch_one(&app.db_pool)
+        .await
+        .unwrap();
+    assert_eq!(&thumbnail[4..8], b"ftyp");
+}
+
+#[tokio::test]
+async fn test_admin_responses_use_camel_case() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "user@example.com", "Test User", "password123").await;
+    common::login_user(&app, "test-admin@example.com", "admin123").await;
+
+    let response = app
+        .client
+        .put(app.url("/admin/settings"))
+        .json(&json!({ "maxTotalUsers": 250, "registrationEnabled": false }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 200);
+
+    let settings: serde_json::Value = app
+        .client
+        .get(app.url("/admin/settings"))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(settings["maxTotalUsers"], 250);
+    assert_eq!(settings["registrationEnabled"], false);
+    assert!(settings["defaultUserInviteLimit"].is_number());
+    assert!(settings.get("max_total_users").is_none());
+
+    let dashboard: serde_json::Value = app
+        .client
+        .get(app.url("/admin/dashboard"))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(dashboard["systemStats"]["maxTotalUsers"], 250);
+    assert!(dashboard["systemStats"]["totalUsers"].is_number());
+    assert!(dashboard["recentUsers"].is_array());
+    assert!(dashboard["recentInvites"][0]["maxUses"].is_number());
+
+    let users: serde_json::Value = app
+        .client
+        .get(app.url("/admin/users?page=1&limit=10"))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert!(users["totalPages"].is_number());
+}
+
+#[tokio::test]
+async fn test_transfer_plants_to_another_user() {
+    let app = TestApp::new().await;
+
+    let from =
+        common::create_test_user(&app, "leaving@example.com", "Leaving", "password123").await;
+    let from_id = from["user"]["id"].as_str().unwrap().to_string();
+    let plant = common::create_test_plant(&app, "Inherited Fern", "Nephrolepis").await;
+    let plant_id = plant["id"].as_str().unwrap().to_string();
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/entries", plant_id)))
+        .json(&json!({ "entryType": "watering", "timestamp": "2024-05-01T08:00:00Z" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 201);
+
+    let to = common::create_test_user(&app, "staying@example.com", "Staying", "password123").await;
+    let to_id = to["user"]["id"].as_str().unwrap().to_string();
+
+    common::login_user(&app, "test-admin@example.com", "admin123").await;
+
+    let transfer = |from: &str, to: &str| {
+        app.client
+            .post(app.url(&format!("/admin/users/{}/transfer-plants/{}", from, to)))
+            .send()
+    };
+    assert_eq!(transfer(&from_id, &from_id).await.unwrap().status(), 400);
+    assert_eq!(
+        transfer(&from_id, "missing-user").await.unwrap().status(),
+        404
+    );
+
+    let response = transfer(&from_id, &to_id).await.unwrap();
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["transferred"], 1);
+    assert_eq!(body["toUserId"], to_id.as_str());
+
+    let (action, target_id): (String, String) =
+        sqlx::query_as("SELECT action, target_id FROM admin_audit_log")
+            .fetch_one(&app.db_pool)
+            .await
+            .unwrap();
+    assert_eq!(action, "transfer_plants");
+    assert_eq!(target_id, from_id);
+
+    // The plant and its history now belong to the target user
+    common::login_user(&app, "staying@example.com", "password123").await;
+    let plants: serde_json::Value = app
+        .client
+        .get(app.url("/plants"))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(plants["total"], 1);
+    assert_eq!(plants["plants"][0]["id"], plant_id.as_str());
+
+    let entries: serde_json::Value = app
+        .client
+        .get(app.url(&format!("/plants/{}/entries", plant_id)))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(entries["total"], 1);
+
+    common::login_user(&app, "leaving@example.com", "password123").await;
+    let plants: serde_json::Value = app
+        .client
+        .get(app.url("/plants"))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(plants["total"], 0);
+}
+
+#[tokio::test]
+async fn test_admin_lists_and_revokes_sessions() {
+    let app = TestApp::new().await;
+
+    // Registering leaves the user logged in on the app client
+    let user = common::create_test_user(&app, "session@example.com", "Session", "password123").await;
+    let user_id = user["user"]["id"].as_str().unwrap().to_string();
+
+    let admin = reqwest::Client::builder().cookie_store(true).build().unwrap();
+    let response = admin
+        .post(app.url("/auth/login"))
+        .json(&json!({ "email": "test-admin@example.com", "password": "admin123" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 200);
+
+    let response = admin
+        .get(app.url("/admin/sessions?limit=50"))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let session = body["sessions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|session| session["userId"] == user_id.as_str())
+        .expect("User's session should be listed")
+        .clone();
+    assert_eq!(session["email"], "session@example.com");
+    assert!(session["createdAt"].is_string());
+    assert!(session["expiresAt"].is_string());
+    assert_eq!(body["total"], body["sessions"].as_array().unwrap().len());
+
+    let response = admin
+        .get(app.url("/admin/sessions?limit=1"))
+        .send()
+        .await
+        .expect("Failed to send request");
+    let page: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(page["sessions"].as_array().unwrap().len(), 1);
+    assert_eq!(page["total"], body["total"]);
+
+    // Non-admins can't see the list
+    let response = app
+        .client
+        .get(app.url("/admin/sessions"))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 403);
+
+    let session_id = session["sessionId"].as_str().unwrap();
+    let response = admin
+        .delete(app.url(&format!("/admin/sessions/{}", session_id)))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 204);
+
+    let response = app
+        .client
+        .get(app.url("/auth/me"))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 401);
+
+    let response = admin
+        .delete(app.url(&format!("/admin/sessions/{}", session_id)))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 404);
+
+    let (action, target_id): (String, String) =
+        sqlx::query_as("SELECT action, target_id FROM admin_audit_log")
+            .fetch_one(&app.db_pool)
+            .await
+            .unwrap();
+    assert_eq!(action, "revoke_session");
+    assert_eq!(target_id, user_id);
+}
+
+#[tokio::test]
+async fn test_notify_waitlist_emails_each_pending_entry_once() {
+    use planty_api::database::invites as db_invites;
+
+    let app = TestApp::new().await;
+
+    for email in ["first@example.com", "second@example.com", "invited@example.com"] {
+        let response = app
+            .client
+            .post(app.url("/invites/waitlist"))
+            .json(&json!({ "email": email }))
+            .send()
+            .await
+            .expect("Failed to send request");
+        assert_eq!(response.status(), 201);
+    }
+    db_invites::update_waitlist_status(&app.db_pool, "invited@example.com", "invited", None)
+        .await
+        .unwrap();
+
+    common::create_test_user(&app, "someone@example.com", "Someone", "password123").await;
+    common::login_user(&app, "test-admin@example.com", "admin123").await;
+
+    let notify = || {
+        app.client
+            .post(app.url("/admin/waitlist/notify"))
+            .json(&json!({ "subject": "We're live", "body": "Come and join!" }))
+            .send()
+    };
+
+    let response = notify().await.unwrap();
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["notified"], 2);
+    assert_eq!(body["failed"], 0);
+
+    let mut recipients: Vec<String> = app.mailer.sent().into_iter().map(|email| email.to).collect();
+    recipients.sort();
+    assert_eq!(recipients, ["first@example.com", "second@example.com"]);
+    assert_eq!(app.mailer.sent()[0].subject, "We're live");
+
+    // Already-notified entries are skipped on a re-run
+    let body: serde_json::Value = notify().await.unwrap().json().await.unwrap();
+    assert_eq!(body["notified"], 0);
+    assert_eq!(app.mailer.sent().len(), 2);
+
+    let actions: Vec<String> =
+        sqlx::query_scalar("SELECT action FROM admin_audit_log ORDER BY id")
+            .fetch_all(&app.db_pool)
+            .await
+            .unwrap();
+    assert_eq!(actions, ["notify_waitlist", "notify_waitlist"]);
+
+    let response = app
+        .client
+        .post(app.url("/admin/waitlist/notify"))
+        .json(&json!({ "subject": "", "body": "Come and join!" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 422);
+
+    common::login_user(&app, "someone@example.com", "password123").await;
+    assert_eq!(notify().await.unwrap().status(), 403);
+}
+
+#[tokio::test]
+async fn test_dashboard_skips_rows_with_malformed_dates() {
+    let app = TestApp::new().await;
+
+    let user = common::create_test_user(&app, "broken@example.com", "Broken", "password123").await;
+    common::login_user(&app, "test-admin@example.com", "admin123").await;
+
+    sqlx::query("UPDATE users SET created_at = 'not-a-date' WHERE id = ?")
+        .bind(user["user"]["id"].as_str().unwrap())
+        .execute(&app.db_pool)
+        .await
+        .unwrap();
+    sqlx::query("UPDATE invite_codes SET expires_at = 'soon' WHERE code = (SELECT code FROM invite_codes LIMIT 1)")
+        .execute(&app.db_pool)
+        .await
+        .unwrap();
+    let invites: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM invite_codes")
+        .fetch_one(&app.db_pool)
+        .await
+        .unwrap();
+
+    let dashboard: serde_json::Value = app
+        .client
+        .get(app.url("/admin/dashboard"))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+
+    let recent_users = dashboard["recentUsers"].as_array().unwrap();
+    assert!(!recent_users.is_empty());
+    assert!(recent_users
+        .iter()
+        .all(|user| user["email"] != "broken@example.com"));
+    assert!(recent_users
+        .iter()
+        .all(|user| !user["createdAt"].as_str().unwrap().starts_with("1970")));
+    assert_eq!(
+        dashboard["recentInvites"].as_array().unwrap().len() as i64,
+        invites.min(10) - 1
+    );
+}
+
+#[tokio::test]
+async fn test_missing_admin_setting_falls_back_to_default() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "settings@example.com", "Settings", "password123").await;
+    common::login_user(&app, "test-admin@example.com", "admin123").await;
+
+    sqlx::query("DELETE FROM admin_settings WHERE key = 'max_total_users'")
+        .execute(&app.db_pool)
+        .await
+        .unwrap();
+    sqlx::query("UPDATE admin_settings SET value = '12' WHERE key = 'default_user_invite_limit'")
+        .execute(&app.db_pool)
+        .await
+        .unwrap();
+
+    let response = app
+        .client
+        .get(app.url("/admin/settings"))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 200);
+    let settings: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(settings["maxTotalUsers"], 1000);
+    assert_eq!(settings["defaultUserInviteLimit"], 12);
+
+    let effective: serde_json::Value = app
+        .client
+        .get(app.url("/admin/settings/effective"))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(
+        effective["maxTotalUsers"],
+        json!({ "value": 1000, "source": "default" })
+    );
+    assert_eq!(
+        effective["defaultUserInviteLimit"],
+        json!({ "value": 12, "source": "stored" })
+    );
+    assert_eq!(
+        effective["registrationEnabled"],
+        json!({ "value": true, "source": "stored" })
+    );
+
+    // Updating a missing setting stores it again
+    let response = app
+        .client
+        .put(app.url("/admin/settings"))
+        .json(&json!({ "maxTotalUsers": 40 }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 200);
+    let effective: serde_json::Value = app
+        .client
+        .get(app.url("/admin/settings/effective"))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(
+        effective["maxTotalUsers"],
+        json!({ "value": 40, "source": "stored" })
+    );
+}
+
+#[tokio::test]
+async fn test_bulk_role_change_is_atomic_and_counts_changed_users() {
+    let app = TestApp::new().await;
+
+    let mut user_ids = Vec::new();
+    for email in ["bulk-a@example.com", "bulk-b@example.com", "bulk-c@example.com"] {
+        let user = common::create_test_user(&app, email, "Bulk", "password123").await;
+        user_ids.push(user["user"]["id"].as_str().unwrap().to_string());
+    }
+    common::login_user(&app, "test-admin@example.com", "admin123").await;
+
+    let roles = || async {
+        let mut roles = Vec::new();
+        for id in &user_ids {
+            let role: String = sqlx::query_scalar("SELECT role FROM users WHERE id = ?")
+                .bind(id)
+                .fetch_one(&app.db_pool)
+                .await
+                .unwrap();
+            roles.push(role);
+        }
+        roles
+    };
+
+    // Fail the statement on the last user; nobody may end up changed
+    sqlx::query(
+        "CREATE TRIGGER fail_bulk_update BEFORE UPDATE ON users
+         WHEN NEW.email = 'bulk-c@example.com'
+         BEGIN SELECT RAISE(ABORT, 'bulk update failed'); END",
+    )
+    .execute(&app.db_pool)
+    .await
+    .unwrap();
+
+    let response = app
+        .client
+        .post(app.url("/admin/users/bulk"))
+        .json(&json!({ "userIds": user_ids, "action": { "set_role": "admin" } }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 500);
+    assert_eq!(roles().await, ["user", "user", "user"]);
+
+    sqlx::query("DROP TRIGGER fail_bulk_update")
+        .execute(&app.db_pool)
+        .await
+        .unwrap();
+
+    // Unknown and repeated ids aren't counted
+    let mut requested = user_ids.clone();
+    requested.push(user_ids[0].clone());
+    requested.push("no-such-user".to_string());
+    let response = app
+        .client
+        .post(app.url("/admin/users/bulk"))
+        .json(&json!({ "userIds": requested, "action": { "set_role": "admin" } }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["affectedCount"], 3);
+    assert_eq!(roles().await, ["admin", "admin", "admin"]);
+}
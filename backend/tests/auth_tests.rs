@@ -40,7 +40,7 @@ async fn test_user_registration_duplicate_email() {
         .client
         .post(app.url("/invites/create"))
         .json(&json!({
-            "max_uses": 1
+            "maxUses": 1
         }))
         .send()
         .await
@@ -169,6 +169,114 @@ async fn test_me_endpoint_unauthenticated() {
     assert_eq!(response.status(), 401); // Unauthorized
 }
 
+#[tokio::test]
+async fn test_session_status_ping() {
+    let app = TestApp::new().await;
+
+    // Without a session the ping reports it rather than failing
+    let response = app
+        .client
+        .get(app.url("/auth/session"))
+        .send()
+        .await
+        .expect("Failed to send session request");
+
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["authenticated"], false);
+    assert!(body["expiresAt"].is_null());
+
+    common::create_test_user(&app, "ping@example.com", "Ping User", "password123").await;
+
+    let response = app
+        .client
+        .get(app.url("/auth/session"))
+        .send()
+        .await
+        .expect("Failed to send session request");
+
+    assert_eq!(response.status(), 200);
+    // The cookie is reissued so the inactivity timer restarts
+    assert!(response.headers().contains_key("set-cookie"));
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["authenticated"], true);
+
+    let expires_at: chrono::DateTime<chrono::Utc> = body["expiresAt"]
+        .as_str()
+        .expect("expiresAt should be set")
+        .parse()
+        .unwrap();
+    let remaining = expires_at - chrono::Utc::now();
+    assert!(remaining > chrono::Duration::days(6));
+    assert!(remaining <= chrono::Duration::days(7));
+}
+
+#[tokio::test]
+async fn test_current_user_extractor_requires_session() {
+    let app = TestApp::new().await;
+
+    // Without a session the extractor rejects before the handler runs
+    let response = app
+        .client
+        .get(app.url("/plants"))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), 401);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["error"], "authentication_error");
+
+    // With a session the handler runs for that user
+    common::create_test_user(&app, "test@example.com", "Test User", "password123").await;
+
+    let response = app
+        .client
+        .get(app.url("/plants"))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["total"], 0);
+}
+
+#[tokio::test]
+async fn test_protected_routes_share_401_body() {
+    let app = TestApp::new().await;
+    let plant_id = uuid::Uuid::new_v4();
+
+    let requests = [
+        app.client.get(app.url("/plants")),
+        // Rejected before the body is parsed, so an invalid one doesn't matter
+        app.client
+            .post(app.url("/plants"))
+            .json(&json!({ "name": "" })),
+        app.client
+            .get(app.url(&format!("/plants/{}/entries", plant_id))),
+        app.client
+            .get(app.url(&format!("/plants/{}/photos", plant_id))),
+        app.client
+            .get(app.url(&format!("/plants/{}/care-tasks", plant_id))),
+        app.client.get(app.url("/google-tasks/status")),
+        app.client
+            .post(app.url("/google-tasks/sync-tasks"))
+            .json(&json!({})),
+    ];
+
+    let mut bodies = Vec::new();
+    for request in requests {
+        let response = request.send().await.expect("Failed to send request");
+        assert_eq!(response.status(), 401);
+        bodies.push(response.text().await.expect("Failed to read body"));
+    }
+
+    let first: serde_json::Value = serde_json::from_str(&bodies[0]).unwrap();
+    assert_eq!(first["error"], "authentication_error");
+    assert!(bodies.iter().all(|body| *body == bodies[0]), "{bodies:#?}");
+}
+
 #[tokio::test]
 async fn test_logout() {
     let app = TestApp::new().await;
@@ -246,3 +354,144 @@ async fn test_validation_errors() {
 
     assert_eq!(response.status(), 401); // Unauthorized - no invite code
 }
+
+#[tokio::test]
+async fn test_api_key_authenticates_requests_until_revoked() {
+    let app = TestApp::new().await;
+    common::create_test_user(&app, "script@example.com", "Script User", "password123").await;
+    common::create_test_plant(&app, "Monstera", "Monstera").await;
+
+    let response = app
+        .client
+        .post(app.url("/auth/api-keys"))
+        .json(&json!({ "label": "backup script", "expiresInDays": 30 }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 201);
+    let created: serde_json::Value = response.json().await.unwrap();
+    let key = created["key"].as_str().unwrap().to_string();
+    let key_id = created["apiKey"]["id"].as_str().unwrap().to_string();
+    assert!(key.starts_with(created["apiKey"]["prefix"].as_str().unwrap()));
+    assert!(created["apiKey"]["lastUsedAt"].is_null());
+    assert!(created["apiKey"]["expiresAt"].is_string());
+
+    // A client without cookies, as a script would be
+    let script = reqwest::Client::new();
+    let list_plants = |token: &str| {
+        script
+            .get(app.url("/plants"))
+            .header("Authorization", format!("Bearer {}", token))
+            .send()
+    };
+
+    let response = list_plants(&key).await.unwrap();
+    assert_eq!(response.status(), 200);
+    let plants: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(plants["total"], 1);
+
+    assert_eq!(list_plants("planty_not-a-key").await.unwrap().status(), 401);
+    assert_eq!(
+        script.get(app.url("/plants")).send().await.unwrap().status(),
+        401
+    );
+
+    let keys: serde_json::Value = app
+        .client
+        .get(app.url("/auth/api-keys"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(keys["apiKeys"].as_array().unwrap().len(), 1);
+    assert_eq!(keys["apiKeys"][0]["label"], "backup script");
+    assert!(keys["apiKeys"][0]["lastUsedAt"].is_string());
+    assert!(keys["apiKeys"][0].get("key").is_none());
+
+    let response = app
+        .client
+        .delete(app.url(&format!("/auth/api-keys/{}", key_id)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 204);
+
+    assert_eq!(list_plants(&key).await.unwrap().status(), 401);
+
+    let response = app
+        .client
+        .delete(app.url(&format!("/auth/api-keys/{}", key_id)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn test_api_key_cannot_manage_keys_or_reach_admin_routes() {
+    let app = TestApp::new().await;
+    common::create_test_user(&app, "script@example.com", "Script User", "password123").await;
+    // An admin's key must not carry the admin role either
+    common::login_user(&app, "test-admin@example.com", "admin123").await;
+
+    let response = app
+        .client
+        .post(app.url("/auth/api-keys"))
+        .json(&json!({ "label": "admin script" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 201);
+    let created: serde_json::Value = response.json().await.unwrap();
+    let key = created["key"].as_str().unwrap().to_string();
+    let key_id = created["apiKey"]["id"].as_str().unwrap().to_string();
+
+    let script = reqwest::Client::new();
+    let bearer = format!("Bearer {}", key);
+
+    let response = script
+        .get(app.url("/admin/users"))
+        .header("Authorization", &bearer)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 403);
+
+    let response = script
+        .post(app.url("/auth/api-keys"))
+        .header("Authorization", &bearer)
+        .json(&json!({ "label": "another key" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 403);
+
+    let response = script
+        .get(app.url("/auth/api-keys"))
+        .header("Authorization", &bearer)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 403);
+
+    let response = script
+        .delete(app.url(&format!("/auth/api-keys/{}", key_id)))
+        .header("Authorization", &bearer)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 403);
+
+    // The key still works for ordinary routes, and the session still reaches admin ones
+    let response = script
+        .get(app.url("/plants"))
+        .header("Authorization", &bearer)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    let response = app.client.get(app.url("/admin/users")).send().await.unwrap();
+    assert_eq!(response.status(), 200);
+}
@@ -0,0 +1,67 @@
+mod common;
+use common::TestApp;
+
+#[tokio::test]
+async fn test_calendar_feed_conditional_get() {
+    let app = TestApp::new().await;
+
+    let user =
+        common::create_test_user(&app, "calendar@example.com", "Calendar User", "password123")
+            .await;
+    let user_id = user["user"]["id"].as_str().unwrap();
+    let plant = common::create_test_plant(&app, "Calendar Plant", "Calendaria").await;
+    let plant_id = plant["id"].as_str().unwrap();
+    let feed_url = app.url(&format!("/calendar/{}.ics?token=0123456789abcdef", user_id));
+
+    let response = app.client.get(&feed_url).send().await.unwrap();
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers()["cache-control"], "private, max-age=3600");
+    let etag = response.headers()["etag"].to_str().unwrap().to_string();
+    assert!(etag.starts_with('"') && etag.ends_with('"'));
+    assert!(response.text().await.unwrap().contains("BEGIN:VCALENDAR"));
+
+    // Polling again with the ETag gets an empty 304
+    let response = app
+        .client
+        .get(&feed_url)
+        .header("If-None-Match", &etag)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 304);
+    assert_eq!(response.headers()["etag"], etag.as_str());
+    assert!(response.text().await.unwrap().is_empty());
+
+    // A different variant of the feed has its own ETag
+    let response = app
+        .client
+        .get(format!("{}&lang=fr", feed_url))
+        .header("If-None-Match", &etag)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+
+    // Caring for a plant changes the feed
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/entries", plant_id)))
+        .json(&serde_json::json!({
+            "entryType": "watering",
+            "timestamp": chrono::Utc::now().to_rfc3339(),
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 201);
+
+    let response = app
+        .client
+        .get(&feed_url)
+        .header("If-None-Match", &etag)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    assert_ne!(response.headers()["etag"], etag.as_str());
+}
@@ -1,22 +1,62 @@
-use axum::Router;
+#![allow(dead_code)]
+
+use axum::{extract::Request, Router, ServiceExt};
 use reqwest::Client;
 use sqlx::SqlitePool;
 use tempfile::TempDir;
 use tokio::net::TcpListener;
+use tower::Layer;
+use tower_http::normalize_path::NormalizePathLayer;
 
 use planty_api::app_state::AppState;
 use planty_api::auth;
-use planty_api::handlers::{auth as auth_handlers, google_tasks, plants, invites};
+use planty_api::config::AppConfig;
+use planty_api::middleware::query_count;
+use planty_api::handlers::{
+    admin, auth as auth_handlers, calendar, dashboard, features, google_tasks, integrations,
+    invites, plants, public_links, settings, sync,
+};
+use planty_api::utils::job_registry::JobRegistry;
+use planty_api::utils::mailer::MemoryMailer;
 
 pub struct TestApp {
     pub address: String,
     pub db_pool: SqlitePool,
     pub client: Client,
+    pub job_registry: JobRegistry,
+    /// Every email the app sent
+    pub mailer: MemoryMailer,
     pub _temp_dir: TempDir,
 }
 
 impl TestApp {
     pub async fn new() -> Self {
+        Self::spawn(true, false, |_| {}).await
+    }
+
+    /// Build the app as if `GOOGLE_INTEGRATION_ENABLED=false`
+    pub async fn without_google_integration() -> Self {
+        Self::spawn(false, false, |_| {}).await
+    }
+
+    /// Build the app with `configure` applied to the configuration read from the environment
+    pub async fn with_config(configure: impl FnOnce(&mut AppConfig)) -> Self {
+        Self::spawn(true, false, configure).await
+    }
+
+    /// Build the app as if `DB_QUERY_COUNT=true`
+    ///
+    /// Responses only carry a count once the test has installed
+    /// `QueryCountLayer` in the global tracing subscriber.
+    pub async fn with_query_counts() -> Self {
+        Self::spawn(true, true, |_| {}).await
+    }
+
+    async fn spawn(
+        google_integration_enabled: bool,
+        count_queries: bool,
+        configure: impl FnOnce(&mut AppConfig),
+    ) -> Self {
         let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
         // Use in-memory SQLite database for tests
         let database_url = "sqlite::memory:".to_string();
@@ -35,17 +75,46 @@ impl TestApp {
         let (session_layer, auth_layer) = auth::create_auth_layers(db_pool.clone());
 
         // Create app state
-        let app_state = AppState::new(db_pool.clone());
+        let mut config = AppConfig::from_env().expect("Invalid test configuration");
+        configure(&mut config);
+        let mailer = MemoryMailer::new();
+        let app_state = AppState::new(db_pool.clone())
+            .with_config(config)
+            .with_google_integration(google_integration_enabled)
+            .with_mailer(std::sync::Arc::new(mailer.clone()));
+        let job_registry = app_state.job_registry.clone();
+
+        let google_tasks_router = if google_integration_enabled {
+            google_tasks::routes()
+        } else {
+            google_tasks::disabled_routes()
+        };
 
         // Build app
         let app = Router::new()
             .nest("/auth", auth_handlers::routes())
+            .nest("/admin", admin::routes())
             .nest("/plants", plants::routes())
+            .nest("/public", public_links::public_routes())
+            .nest("/calendar", calendar::routes())
+            .nest("/dashboard", dashboard::routes())
             .nest("/invites", invites::routes())
-            .nest("/google-tasks", google_tasks::routes())
+            .nest("/sync", sync::routes())
+            .nest("/google-tasks", google_tasks_router)
+            .nest("/integrations", integrations::routes())
+            .nest("/features", features::routes())
+            .nest("/settings", settings::routes())
             .with_state(app_state)
             .layer(auth_layer)
             .layer(session_layer);
+        let app = if count_queries {
+            app.layer(axum::middleware::from_fn(query_count::count_queries))
+        } else {
+            app
+        };
+
+        // Trim trailing slashes before routing, as main.rs does for the API router
+        let app = NormalizePathLayer::trim_trailing_slash().layer(app);
 
         // Start server
         let listener = TcpListener::bind("127.0.0.1:0")
@@ -55,7 +124,7 @@ impl TestApp {
         let server_url = format!("http://{}", address);
 
         tokio::spawn(async move {
-            axum::serve(listener, app)
+            axum::serve(listener, ServiceExt::<Request>::into_make_service(app))
                 .await
                 .expect("Failed to start test server");
         });
@@ -73,6 +142,8 @@ impl TestApp {
             address: server_url,
             db_pool,
             client,
+            job_registry,
+            mailer,
             _temp_dir: temp_dir,
         }
     }
@@ -92,7 +163,6 @@ pub async fn create_test_user(
     // First create an admin user directly in the database to create invites
     use planty_api::database::users as db_users;
     use planty_api::models::{CreateUserRequest, UserRole};
-    use planty_api::database::invites as db_invites;
 
     // Create admin if it doesn't exist
     let admin_email = "test-admin@example.com";
@@ -140,7 +210,7 @@ pub async fn create_test_user(
         .client
         .post(app.url("/invites/create"))
         .json(&serde_json::json!({
-            "max_uses": 1
+            "maxUses": 1
         }))
         .send()
         .await
@@ -0,0 +1,88 @@
+use chrono::{Duration, Utc};
+use serde_json::json;
+
+mod common;
+use common::TestApp;
+
+async fn log_watering(app: &TestApp, plant_id: &str, timestamp: chrono::DateTime<Utc>) {
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/entries", plant_id)))
+        .json(&json!({ "entryType": "watering", "timestamp": timestamp.to_rfc3339() }))
+        .send()
+        .await
+        .expect("Failed to send entry request");
+    assert_eq!(response.status(), 201);
+}
+
+#[tokio::test]
+async fn test_dashboard_summary_requires_authentication() {
+    let app = TestApp::new().await;
+
+    let response = app
+        .client
+        .get(app.url("/dashboard/summary"))
+        .send()
+        .await
+        .expect("Failed to send summary request");
+
+    assert_eq!(response.status(), 401);
+}
+
+#[tokio::test]
+async fn test_dashboard_summary_counts() {
+    let app = TestApp::new().await;
+    common::create_test_user(&app, "dashboard@example.com", "Dashboard", "password123").await;
+
+    // Never fertilized, so fertilizing is due now
+    let fern = common::create_test_plant(&app, "Fern", "Nephrolepis").await;
+    let fern_id = fern["id"].as_str().unwrap();
+
+    // No schedules, so never due
+    let response = app
+        .client
+        .post(app.url("/plants"))
+        .json(&json!({ "name": "Cactus", "genus": "Cereus" }))
+        .send()
+        .await
+        .expect("Failed to send create plant request");
+    assert_eq!(response.status(), 201);
+    let cactus: serde_json::Value = response.json().await.unwrap();
+    let cactus_id = cactus["id"].as_str().unwrap();
+
+    log_watering(&app, fern_id, Utc::now()).await;
+    log_watering(&app, cactus_id, Utc::now() - Duration::days(30)).await;
+
+    let part = reqwest::multipart::Part::bytes(common::create_test_image_data(400, 300))
+        .file_name("cactus.jpg")
+        .mime_str("image/jpeg")
+        .unwrap();
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/photos", cactus_id)))
+        .multipart(reqwest::multipart::Form::new().part("file", part))
+        .send()
+        .await
+        .expect("Failed to upload photo");
+    assert_eq!(response.status(), 201);
+
+    // Another user's plants and entries don't count
+    common::create_test_user(&app, "other@example.com", "Other", "password123").await;
+    let other = common::create_test_plant(&app, "Other Fern", "Nephrolepis").await;
+    log_watering(&app, other["id"].as_str().unwrap(), Utc::now()).await;
+    common::login_user(&app, "dashboard@example.com", "password123").await;
+
+    let response = app
+        .client
+        .get(app.url("/dashboard/summary"))
+        .send()
+        .await
+        .expect("Failed to send summary request");
+    assert_eq!(response.status(), 200);
+
+    let summary: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(summary["totalPlants"], 2);
+    assert_eq!(summary["needsCareToday"], 1);
+    assert_eq!(summary["wateringsThisWeek"], 1);
+    assert_eq!(summary["totalPhotos"], 1);
+}
@@ -0,0 +1,127 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use axum::response::IntoResponse;
+use planty_api::database::{begin_write, run_migrations, with_transaction, DatabasePool};
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+
+/// File-backed pool (in-memory databases use table locks instead) whose
+/// connections give up on a lock after `busy_timeout`
+async fn create_file_pool(dir: &tempfile::TempDir, busy_timeout: Duration) -> DatabasePool {
+    let options = SqliteConnectOptions::from_str(&format!(
+        "sqlite://{}",
+        dir.path().join("locks.db").display()
+    ))
+    .expect("Invalid database url")
+    .create_if_missing(true)
+    .busy_timeout(busy_timeout);
+
+    let pool = SqlitePoolOptions::new()
+        .connect_with(options)
+        .await
+        .expect("Failed to create test database");
+    run_migrations(&pool)
+        .await
+        .expect("Failed to run migrations");
+    pool
+}
+
+/// Takes the write lock on a second connection and holds it for `hold`
+async fn hold_write_lock(pool: &DatabasePool, hold: Duration) -> tokio::task::JoinHandle<()> {
+    let mut tx = pool.begin().await.expect("Failed to begin transaction");
+    sqlx::query("INSERT INTO admin_settings (key, value) VALUES ('held', 'lock')")
+        .execute(&mut *tx)
+        .await
+        .expect("Failed to take write lock");
+
+    tokio::spawn(async move {
+        tokio::time::sleep(hold).await;
+        tx.commit().await.expect("Failed to release write lock");
+    })
+}
+
+async fn insert_setting(
+    pool: &DatabasePool,
+    key: &'static str,
+) -> Result<(), planty_api::utils::errors::AppError> {
+    with_transaction(pool, move |conn| {
+        Box::pin(async move {
+            sqlx::query("INSERT INTO admin_settings (key, value) VALUES (?, 'written')")
+                .bind(key)
+                .execute(&mut *conn)
+                .await?;
+            Ok(())
+        })
+    })
+    .await
+}
+
+#[tokio::test]
+async fn test_transaction_retries_while_database_locked() {
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_file_pool(&dir, Duration::from_millis(20)).await;
+
+    // Held well past the busy timeout, but released within the retries
+    let holder = hold_write_lock(&pool, Duration::from_millis(150)).await;
+    insert_setting(&pool, "retried")
+        .await
+        .expect("Write should succeed once the lock is released");
+    holder.await.unwrap();
+
+    let count: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM admin_settings WHERE key IN ('held', 'retried')")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(count, 2);
+}
+
+#[tokio::test]
+async fn test_persistently_locked_database_returns_503() {
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_file_pool(&dir, Duration::from_millis(20)).await;
+
+    let holder = hold_write_lock(&pool, Duration::from_secs(2)).await;
+    let error = insert_setting(&pool, "gave_up")
+        .await
+        .expect_err("Write should fail while the lock is held");
+    assert!(error.is_database_busy());
+
+    let response = error.into_response();
+    assert_eq!(response.status(), 503);
+    assert_eq!(response.headers()["retry-after"], "1");
+    holder.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_write_transaction_locks_up_front_and_rolls_back_when_dropped() {
+    let dir = tempfile::tempdir().unwrap();
+    let pool = create_file_pool(&dir, Duration::from_millis(20)).await;
+
+    // The lock is held before the transaction has written anything
+    let mut tx = begin_write(&pool)
+        .await
+        .expect("Failed to begin transaction");
+    let error = sqlx::query("INSERT INTO admin_settings (key, value) VALUES ('outside', 'x')")
+        .execute(&pool)
+        .await
+        .expect_err("Write should wait on the transaction's lock");
+    assert!(planty_api::utils::errors::AppError::from(error).is_database_busy());
+
+    sqlx::query("INSERT INTO admin_settings (key, value) VALUES ('dropped', 'x')")
+        .execute(&mut *tx)
+        .await
+        .unwrap();
+    drop(tx);
+
+    // Rolled back, and the lock released for the next writer
+    insert_setting(&pool, "after")
+        .await
+        .expect("Write should succeed once the transaction is dropped");
+    let keys: Vec<String> =
+        sqlx::query_scalar("SELECT key FROM admin_settings WHERE key IN ('dropped', 'after')")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+    assert_eq!(keys, vec!["after".to_string()]);
+}
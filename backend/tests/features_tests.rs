@@ -0,0 +1,46 @@
+use serde_json::json;
+
+mod common;
+use common::TestApp;
+
+async fn get_features(app: &TestApp) -> serde_json::Value {
+    let response = app
+        .client
+        .get(app.url("/features"))
+        .send()
+        .await
+        .expect("Failed to send features request");
+    assert_eq!(response.status(), 200);
+    response.json().await.expect("Failed to parse features")
+}
+
+#[tokio::test]
+async fn test_features_available_without_session() {
+    let app = TestApp::without_google_integration().await;
+
+    let features = get_features(&app).await;
+    assert_eq!(features["googleIntegration"], false);
+    assert_eq!(features["registrationEnabled"], true);
+    assert_eq!(features["waitlistEnabled"], true);
+}
+
+#[tokio::test]
+async fn test_features_reflect_registration_setting() {
+    let app = TestApp::new().await;
+    common::create_test_user(&app, "user@example.com", "Test User", "password123").await;
+    common::login_user(&app, "test-admin@example.com", "admin123").await;
+
+    // Cached after the first request
+    assert_eq!(get_features(&app).await["registrationEnabled"], true);
+
+    let response = app
+        .client
+        .put(app.url("/admin/settings"))
+        .json(&json!({ "registrationEnabled": false }))
+        .send()
+        .await
+        .expect("Failed to update settings");
+    assert_eq!(response.status(), 200);
+
+    assert_eq!(get_features(&app).await["registrationEnabled"], false);
+}
@@ -0,0 +1,92 @@
+use axum::Router;
+use std::time::Duration;
+use tokio::net::TcpListener;
+
+use planty_api::handlers::frontend;
+
+/// Serve `frontend::routes` over a built frontend in a temp directory
+async fn spawn_frontend(index_ttl: Duration) -> (String, tempfile::TempDir) {
+    let dist = tempfile::tempdir().expect("Failed to create temp directory");
+    std::fs::write(dist.path().join("index.html"), "<html>v1</html>").unwrap();
+    std::fs::write(dist.path().join("favicon.ico"), "icon").unwrap();
+    std::fs::create_dir(dist.path().join("assets")).unwrap();
+    std::fs::write(dist.path().join("assets/index-3f9a1c.js"), "console.log(1)").unwrap();
+
+    let app: Router = frontend::routes(dist.path(), index_ttl);
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind to address");
+    let address = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+
+    (address, dist)
+}
+
+fn cache_control(response: &reqwest::Response) -> Option<&str> {
+    response
+        .headers()
+        .get("cache-control")
+        .map(|value| value.to_str().unwrap())
+}
+
+#[tokio::test]
+async fn test_assets_are_immutable_and_index_is_revalidated() {
+    let (address, _dist) = spawn_frontend(Duration::from_secs(60)).await;
+    let client = reqwest::Client::new();
+
+    let asset = client
+        .get(format!("{}/assets/index-3f9a1c.js", address))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(asset.status(), 200);
+    assert_eq!(
+        cache_control(&asset),
+        Some("public, max-age=31536000, immutable")
+    );
+
+    for path in ["/", "/index.html", "/plants/123", "/favicon.ico"] {
+        let response = client.get(format!("{}{}", address, path)).send().await.unwrap();
+        assert_eq!(response.status(), 200, "{}", path);
+        assert_eq!(cache_control(&response), Some("no-cache"), "{}", path);
+    }
+
+    let page = client.get(format!("{}/plants/123", address)).send().await.unwrap();
+    assert_eq!(page.text().await.unwrap(), "<html>v1</html>");
+
+    // A missing asset must not be answered with a long-cached index.html
+    let missing = client
+        .get(format!("{}/assets/index-000000.js", address))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(missing.status(), 404);
+    assert_eq!(cache_control(&missing), None);
+}
+
+#[tokio::test]
+async fn test_index_is_reread_after_ttl() {
+    let (address, dist) = spawn_frontend(Duration::from_millis(300)).await;
+    let client = reqwest::Client::new();
+    let fetch_index = || async {
+        client
+            .get(format!("{}/calendar", address))
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap()
+    };
+
+    assert_eq!(fetch_index().await, "<html>v1</html>");
+
+    // Within the TTL the cached copy is served
+    std::fs::write(dist.path().join("index.html"), "<html>v2</html>").unwrap();
+    assert_eq!(fetch_index().await, "<html>v1</html>");
+
+    tokio::time::sleep(Duration::from_millis(400)).await;
+    assert_eq!(fetch_index().await, "<html>v2</html>");
+}
@@ -4,6 +4,7 @@ use serde_json::{json, Value};
 mod common;
 
 use common::{TestApp, create_test_user, login_user};
+use planty_api::utils::google_tasks::GoogleTasksConfig;
 
 #[tokio::test]
 async fn test_google_tasks_auth_url_requires_authentication() {
@@ -36,12 +37,55 @@ async fn test_google_tasks_status_not_connected() {
     
     let body: Value = response.json().await.expect("Failed to parse response");
     
+    assert_eq!(body["enabled"], true);
     assert_eq!(body["connected"], false);
     assert!(body["connected_at"].is_null());
     assert!(body["scopes"].is_null());
     assert!(body["expires_at"].is_null());
 }
 
+#[tokio::test]
+async fn test_google_routes_absent_when_integration_disabled() {
+    let app = TestApp::without_google_integration().await;
+    let _user = create_test_user(&app, "test@example.com", "Test User", "password123").await;
+
+    for (method, path) in [
+        ("GET", "/google-tasks/auth-url"),
+        ("GET", "/google-tasks/callback"),
+        ("POST", "/google-tasks/store-tokens"),
+        ("POST", "/google-tasks/disconnect"),
+        ("POST", "/google-tasks/refresh-token"),
+        ("GET", "/google-tasks/lists"),
+        ("POST", "/google-tasks/sync-tasks"),
+        ("POST", "/google-tasks/create-task"),
+    ] {
+        let url = format!("{}{}", app.address, path);
+        let request = match method {
+            "GET" => app.client.get(url),
+            _ => app.client.post(url).json(&json!({})),
+        };
+        let response = request.send().await.expect("Failed to execute request");
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND, "{} {}", method, path);
+    }
+
+    // The status endpoint stays available and reports the integration as disabled
+    let response = app
+        .client
+        .get(format!("{}/google-tasks/status", app.address))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = response.json().await.expect("Failed to parse response");
+
+    assert_eq!(body["enabled"], false);
+    assert_eq!(body["connected"], false);
+    assert_eq!(body["message"], "Google integration is disabled on this server");
+}
+
 #[tokio::test]
 async fn test_google_tasks_store_tokens() {
     let app = TestApp::new().await;
@@ -117,6 +161,26 @@ async fn test_google_tasks_sync_requires_connection() {
     assert!(body["error"] == "authentication_error" || body["error"] == "configuration_error");
 }
 
+#[tokio::test]
+async fn test_google_tasks_lists_requires_connection() {
+    let app = TestApp::new().await;
+    let _user = create_test_user(&app, "test@example.com", "Test User", "password123").await;
+    login_user(&app, "test@example.com", "password123").await;
+
+    let response = app
+        .client
+        .get(format!("{}/google-tasks/lists", app.address))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    // Should return either 401 (no connection) or 500 (config error)
+    assert!(response.status() == StatusCode::UNAUTHORIZED || response.status() == StatusCode::INTERNAL_SERVER_ERROR);
+
+    let body: Value = response.json().await.expect("Failed to parse response");
+    assert!(body["error"] == "authentication_error" || body["error"] == "configuration_error");
+}
+
 #[tokio::test]
 async fn test_google_tasks_create_task_requires_connection() {
     let app = TestApp::new().await;
@@ -242,4 +306,517 @@ async fn test_google_tasks_database_integration() {
     let deleted_token = google_oauth::get_oauth_token(&app.db_pool, user_id).await;
     assert!(deleted_token.is_ok());
     assert!(deleted_token.unwrap().is_none());
-}
\ No newline at end of file
+}
+/// Serve a token endpoint that issues `refreshed_access_token`, or rejects
+/// the refresh token `revoked_refresh_token` the way Google does. A sibling
+/// `/userinfo` route reports the account as `<access token>@example.com`
+async fn spawn_mock_token_endpoint() -> String {
+    use axum::response::IntoResponse;
+    use std::collections::HashMap;
+
+    let app = axum::Router::new()
+        .route(
+            "/userinfo",
+            axum::routing::get(|headers: axum::http::HeaderMap| async move {
+                let token = headers
+                    .get(axum::http::header::AUTHORIZATION)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| value.strip_prefix("Bearer "))
+                    .unwrap_or_default()
+                    .to_string();
+                axum::Json(json!({ "email": format!("{}@example.com", token) }))
+            }),
+        )
+        .route(
+            "/token",
+            axum::routing::post(
+                |axum::Form(form): axum::Form<HashMap<String, String>>| async move {
+                    if form.get("refresh_token").map(String::as_str)
+                        == Some("revoked_refresh_token")
+                    {
+                        return (
+                            axum::http::StatusCode::BAD_REQUEST,
+                            axum::Json(json!({
+                                "error": "invalid_grant",
+                                "error_description": "Token has been expired or revoked."
+                            })),
+                        )
+                            .into_response();
+                    }
+
+                    axum::Json(json!({
+                        "access_token": "refreshed_access_token",
+                        "expires_in": 3600
+                    }))
+                    .into_response()
+                },
+            ),
+        );
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind mock token endpoint");
+    let address = listener.local_addr().expect("Failed to get local address");
+    tokio::spawn(async move {
+        axum::serve(listener, app)
+            .await
+            .expect("Failed to start mock token endpoint");
+    });
+
+    format!("http://{}/token", address)
+}
+
+#[tokio::test]
+async fn test_ensure_valid_token_respects_refresh_margin() {
+    use planty_api::database::google_oauth;
+    use planty_api::utils::google_tasks::ensure_valid_token;
+
+    let app = TestApp::new().await;
+    let user = create_test_user(&app, "margin@example.com", "Margin User", "password123").await;
+    let user_id = user["user"]["id"].as_str().unwrap();
+
+    google_oauth::save_oauth_token(
+        &app.db_pool,
+        user_id,
+        "original_access_token",
+        Some("test_refresh_token"),
+        Some(chrono::Utc::now() + chrono::Duration::minutes(8)),
+        "https://www.googleapis.com/auth/tasks",
+    )
+    .await
+    .expect("Failed to save token");
+
+    let mut config = mock_google_config().await;
+
+    // Eight minutes out is outside the default margin, so the token is kept
+    let token = ensure_valid_token(&app.db_pool, user_id, &config)
+        .await
+        .expect("Failed to get token");
+    assert_eq!(token.access_token, "original_access_token");
+
+    config.refresh_margin = chrono::Duration::minutes(10);
+    let token = ensure_valid_token(&app.db_pool, user_id, &config)
+        .await
+        .expect("Failed to refresh token");
+    assert_eq!(token.access_token, "refreshed_access_token");
+    assert!(token.expires_at.unwrap() > chrono::Utc::now() + chrono::Duration::minutes(30));
+
+    let stored = google_oauth::get_oauth_token(&app.db_pool, user_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.access_token, "refreshed_access_token");
+}
+
+/// Google settings pointing at a mock token and userinfo endpoint
+async fn mock_google_config() -> GoogleTasksConfig {
+    let token_url = spawn_mock_token_endpoint().await;
+    GoogleTasksConfig {
+        client_id: "test_client_id".to_string(),
+        client_secret: "test_client_secret".to_string(),
+        redirect_uri: "http://localhost/callback".to_string(),
+        userinfo_url: token_url.replace("/token", "/userinfo"),
+        token_url,
+        refresh_margin: chrono::Duration::minutes(5),
+        refresh_poll_interval: chrono::Duration::minutes(60),
+    }
+}
+
+/// Spawn the app with its Google config pointed at the mock endpoints
+async fn spawn_app_with_mock_google() -> TestApp {
+    let google = mock_google_config().await;
+    TestApp::with_config(|config| config.google = Some(google)).await
+}
+
+#[tokio::test]
+async fn test_refresh_token_endpoint_returns_updated_expiry() {
+    use planty_api::database::google_oauth;
+
+    let app = spawn_app_with_mock_google().await;
+    let user = create_test_user(&app, "refresh@example.com", "Refresh User", "password123").await;
+    let user_id = user["user"]["id"].as_str().unwrap();
+    login_user(&app, "refresh@example.com", "password123").await;
+
+    let expired_at = chrono::Utc::now() - chrono::Duration::minutes(1);
+    google_oauth::save_oauth_token(
+        &app.db_pool,
+        user_id,
+        "expired_access_token",
+        Some("test_refresh_token"),
+        Some(expired_at),
+        "https://www.googleapis.com/auth/tasks",
+    )
+    .await
+    .expect("Failed to save token");
+
+    let response = app
+        .client
+        .post(format!("{}/google-tasks/refresh-token", app.address))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["needs_reauth"], false);
+    let expires_at: chrono::DateTime<chrono::Utc> = body["expires_at"]
+        .as_str()
+        .expect("expires_at set")
+        .parse()
+        .expect("valid timestamp");
+    assert!(expires_at > chrono::Utc::now() + chrono::Duration::minutes(30));
+
+    let stored = google_oauth::get_oauth_token(&app.db_pool, user_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.access_token, "refreshed_access_token");
+}
+
+#[tokio::test]
+async fn test_refresh_token_endpoint_flags_needs_reauth() {
+    use planty_api::database::google_oauth;
+
+    let app = spawn_app_with_mock_google().await;
+    let user = create_test_user(&app, "reauth@example.com", "Reauth User", "password123").await;
+    let user_id = user["user"]["id"].as_str().unwrap();
+    login_user(&app, "reauth@example.com", "password123").await;
+
+    // Without a connection there is nothing to refresh
+    let response = app
+        .client
+        .post(format!("{}/google-tasks/refresh-token", app.address))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let expired_at = chrono::Utc::now() - chrono::Duration::minutes(1);
+    for refresh_token in [None, Some("revoked_refresh_token")] {
+        google_oauth::save_oauth_token(
+            &app.db_pool,
+            user_id,
+            "expired_access_token",
+            refresh_token,
+            Some(expired_at),
+            "https://www.googleapis.com/auth/tasks",
+        )
+        .await
+        .expect("Failed to save token");
+
+        let response = app
+            .client
+            .post(format!("{}/google-tasks/refresh-token", app.address))
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert_eq!(response.status(), StatusCode::OK, "{:?}", refresh_token);
+        let body: Value = response.json().await.expect("Failed to parse response");
+        assert_eq!(body["needs_reauth"], true, "{:?}", refresh_token);
+    }
+}
+
+async fn count_synced_tasks(app: &TestApp, user_id: &str) -> i64 {
+    sqlx::query_scalar("SELECT COUNT(*) FROM google_synced_tasks WHERE user_id = ?")
+        .bind(user_id)
+        .fetch_one(&app.db_pool)
+        .await
+        .expect("Failed to count synced tasks")
+}
+
+async fn insert_synced_task(app: &TestApp, user_id: &str, plant_id: &str, google_task_id: &str) {
+    sqlx::query(
+        "INSERT INTO google_synced_tasks (user_id, task_list_id, plant_id, task_type, due_date, google_task_id, created_at) \
+         VALUES (?, 'plant-care', ?, 'watering', ?, ?, ?)",
+    )
+    .bind(user_id)
+    .bind(plant_id)
+    .bind(google_task_id)
+    .bind(google_task_id)
+    .bind(chrono::Utc::now().to_rfc3339())
+    .execute(&app.db_pool)
+    .await
+    .expect("Failed to insert synced task");
+}
+
+#[tokio::test]
+async fn test_reconnecting_tracks_account_and_disconnect_clears_synced_tasks() {
+    let app = spawn_app_with_mock_google().await;
+    let user = create_test_user(&app, "reconnect@example.com", "Reconnect User", "password123").await;
+    let user_id = user["user"]["id"].as_str().unwrap();
+    login_user(&app, "reconnect@example.com", "password123").await;
+    let plant = common::create_test_plant(&app, "Fern", "Nephrolepis exaltata").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let connect = |access_token: &'static str| {
+        app.client
+            .post(format!("{}/google-tasks/store-tokens", app.address))
+            .json(&json!({
+                "access_token": access_token,
+                "refresh_token": "test_refresh_token",
+                "expires_at": 1234567890
+            }))
+            .send()
+    };
+
+    let response = connect("first").await.expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    insert_synced_task(&app, user_id, plant_id, "2026-01-01").await;
+
+    let status: Value = app
+        .client
+        .get(format!("{}/google-tasks/status", app.address))
+        .send()
+        .await
+        .expect("Failed to execute request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(status["account_email"], "first@example.com");
+
+    // Reconnecting the same account keeps what was already synced
+    connect("first").await.expect("Failed to execute request");
+    assert_eq!(count_synced_tasks(&app, user_id).await, 1);
+
+    // A different account has none of those tasks, so start over
+    connect("second").await.expect("Failed to execute request");
+    assert_eq!(count_synced_tasks(&app, user_id).await, 0);
+
+    insert_synced_task(&app, user_id, plant_id, "2026-01-02").await;
+    let response = app
+        .client
+        .post(format!("{}/google-tasks/disconnect", app.address))
+        .send()
+        .await
+        .expect("Failed to execute request");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(count_synced_tasks(&app, user_id).await, 0);
+}
+
+#[tokio::test]
+async fn test_resync_skips_care_already_synced() {
+    use planty_api::database::{google_oauth, plants as db_plants};
+    use planty_api::utils::google_tasks::{create_plant_care_task, SyncedTask};
+
+    let app = TestApp::new().await;
+    let user = create_test_user(&app, "resync@example.com", "Resync User", "password123").await;
+    let user_id = user["user"]["id"].as_str().unwrap();
+    let plant = common::create_test_plant(&app, "Fern", "Nephrolepis exaltata").await;
+    let plant_id = plant["id"].as_str().unwrap();
+    let plant = db_plants::get_plant_by_id(&app.db_pool, plant_id.parse().unwrap())
+        .await
+        .expect("Failed to load plant");
+
+    let token = google_oauth::save_oauth_token(
+        &app.db_pool,
+        user_id,
+        "test_access_token",
+        Some("test_refresh_token"),
+        Some(chrono::Utc::now() + chrono::Duration::hours(1)),
+        "https://www.googleapis.com/auth/tasks",
+    )
+    .await
+    .expect("Failed to save token");
+
+    let due = (chrono::Utc::now() + chrono::Duration::days(3))
+        .date_naive()
+        .and_hms_opt(9, 0, 0)
+        .unwrap()
+        .and_utc();
+    let record = |google_task_id: &'static str| {
+        google_oauth::record_synced_task(
+            &app.db_pool,
+            user_id,
+            "list-id",
+            plant_id,
+            "watering",
+            due.date_naive(),
+            google_task_id,
+        )
+    };
+    record("first-task").await.expect("Failed to record synced task");
+
+    // Recording the same slot again keeps the task created first
+    record("second-task").await.expect("Failed to record synced task");
+    assert_eq!(count_synced_tasks(&app, user_id).await, 1);
+
+    // A synced slot is skipped without calling Google, even at another time that day
+    let synced = create_plant_care_task(
+        &app.db_pool,
+        &token,
+        &plant,
+        "watering",
+        due + chrono::Duration::hours(8),
+        "https://plants.example.com",
+        "list-id",
+    )
+    .await
+    .expect("Failed to sync task");
+    assert_eq!(synced, SyncedTask::Skipped("first-task".to_string()));
+
+    let other_slot = google_oauth::get_synced_task_id(
+        &app.db_pool,
+        user_id,
+        "list-id",
+        plant_id,
+        "fertilizing",
+        due.date_naive(),
+    )
+    .await
+    .expect("Failed to look up synced task");
+    assert_eq!(other_slot, None);
+
+    // Another task list doesn't have the task yet, so it gets its own
+    let other_list = google_oauth::get_synced_task_id(
+        &app.db_pool,
+        user_id,
+        "other-list-id",
+        plant_id,
+        "watering",
+        due.date_naive(),
+    )
+    .await
+    .expect("Failed to look up synced task");
+    assert_eq!(other_list, None);
+    google_oauth::record_synced_task(
+        &app.db_pool,
+        user_id,
+        "other-list-id",
+        plant_id,
+        "watering",
+        due.date_naive(),
+        "other-list-task",
+    )
+    .await
+    .expect("Failed to record synced task");
+    assert_eq!(count_synced_tasks(&app, user_id).await, 2);
+}
+
+#[tokio::test]
+async fn test_oauth_callback_keeps_existing_connection_unless_forced() {
+    use planty_api::database::google_oauth;
+
+    let app = spawn_app_with_mock_google().await;
+    let user = create_test_user(&app, "reconnect@example.com", "Reconnect User", "password123").await;
+    let user_id = user["user"]["id"].as_str().unwrap();
+    login_user(&app, "reconnect@example.com", "password123").await;
+
+    google_oauth::save_oauth_token(
+        &app.db_pool,
+        user_id,
+        "original_access_token",
+        Some("test_refresh_token"),
+        Some(chrono::Utc::now() + chrono::Duration::hours(1)),
+        "https://www.googleapis.com/auth/tasks",
+    )
+    .await
+    .expect("Failed to save token");
+
+    // Google sends the browser to the callback; look at the redirect instead of following it
+    let browser = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .unwrap();
+    let (app, browser) = (&app, &browser);
+    let connect = |query: &'static str| async move {
+        let body: Value = app
+            .client
+            .get(format!("{}/google-tasks/auth-url{}", app.address, query))
+            .send()
+            .await
+            .expect("Failed to execute request")
+            .json()
+            .await
+            .expect("Failed to parse response");
+        let response = browser
+            .get(format!("{}/google-tasks/callback", app.address))
+            .query(&[("code", "new_code"), ("state", body["state"].as_str().unwrap())])
+            .send()
+            .await
+            .expect("Failed to execute request");
+        assert!(response.status().is_redirection());
+        response.headers()["location"].to_str().unwrap().to_string()
+    };
+
+    let location = connect("").await;
+    assert!(location.ends_with("/calendar-settings?google=already_connected"), "{}", location);
+    let stored = google_oauth::get_oauth_token(&app.db_pool, user_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.access_token, "original_access_token");
+
+    let location = connect("?force=true").await;
+    assert!(location.ends_with("/calendar-settings"), "{}", location);
+    let stored = google_oauth::get_oauth_token(&app.db_pool, user_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.access_token, "refreshed_access_token");
+}
+
+#[tokio::test]
+async fn test_resync_claims_tasks_synced_before_lists_were_tracked() {
+    use planty_api::database::{google_oauth, plants as db_plants};
+    use planty_api::utils::google_tasks::{create_plant_care_task, SyncedTask};
+
+    let app = TestApp::new().await;
+    let user = create_test_user(&app, "legacy@example.com", "Legacy User", "password123").await;
+    let user_id = user["user"]["id"].as_str().unwrap();
+    let plant = common::create_test_plant(&app, "Fern", "Nephrolepis exaltata").await;
+    let plant_id = plant["id"].as_str().unwrap();
+    let plant = db_plants::get_plant_by_id(&app.db_pool, plant_id.parse().unwrap())
+        .await
+        .expect("Failed to load plant");
+
+    let token = google_oauth::save_oauth_token(
+        &app.db_pool,
+        user_id,
+        "test_access_token",
+        Some("test_refresh_token"),
+        Some(chrono::Utc::now() + chrono::Duration::hours(1)),
+        "https://www.googleapis.com/auth/tasks",
+    )
+    .await
+    .expect("Failed to save token");
+
+    // Rows synced before the upgrade have no list recorded
+    let due = chrono::Utc::now() + chrono::Duration::days(3);
+    sqlx::query(
+        "INSERT INTO google_synced_tasks (user_id, task_list_id, plant_id, task_type, due_date, google_task_id, created_at) \
+         VALUES (?, '', ?, 'watering', ?, 'legacy-task', ?)",
+    )
+    .bind(user_id)
+    .bind(plant_id)
+    .bind(due.date_naive().to_string())
+    .bind(chrono::Utc::now().to_rfc3339())
+    .execute(&app.db_pool)
+    .await
+    .expect("Failed to insert legacy synced task");
+
+    let claimed = google_oauth::claim_legacy_synced_tasks(&app.db_pool, user_id, "plant-care")
+        .await
+        .expect("Failed to claim synced tasks");
+    assert_eq!(claimed, 1);
+
+    // Syncing into the Plant Care list again skips the task without calling Google
+    let synced = create_plant_care_task(
+        &app.db_pool,
+        &token,
+        &plant,
+        "watering",
+        due,
+        "https://plants.example.com",
+        "plant-care",
+    )
+    .await
+    .expect("Failed to sync task");
+    assert_eq!(synced, SyncedTask::Skipped("legacy-task".to_string()));
+    assert_eq!(count_synced_tasks(&app, user_id).await, 1);
+
+    let claimed = google_oauth::claim_legacy_synced_tasks(&app.db_pool, user_id, "plant-care")
+        .await
+        .expect("Failed to claim synced tasks");
+    assert_eq!(claimed, 0);
+}
@@ -12,7 +12,7 @@ async fn test_invite_creation_requires_auth() {
         .client
         .post(app.url("/invites/create"))
         .json(&json!({
-            "max_uses": 3
+            "maxUses": 3
         }))
         .send()
         .await
@@ -66,7 +66,7 @@ async fn test_invite_validation() {
         .client
         .post(app.url("/invites/create"))
         .json(&json!({
-            "max_uses": 3
+            "maxUses": 3
         }))
         .send()
         .await
@@ -81,8 +81,8 @@ async fn test_invite_validation() {
     
     let invite_code = invite_data["code"].as_str().unwrap();
     assert!(!invite_code.is_empty());
-    assert_eq!(invite_data["max_uses"], 3);
-    assert_eq!(invite_data["current_uses"], 0);
+    assert_eq!(invite_data["maxUses"], 3);
+    assert_eq!(invite_data["currentUses"], 0);
 
     // Test invite validation
     let validate_response = app
@@ -103,7 +103,7 @@ async fn test_invite_validation() {
         .expect("Failed to parse validate response");
     
     assert_eq!(validate_data["valid"], true);
-    assert_eq!(validate_data["uses_remaining"], 3);
+    assert_eq!(validate_data["usesRemaining"], 3);
 }
 
 #[tokio::test]
@@ -148,7 +148,7 @@ async fn test_invite_list() {
         .client
         .post(app.url("/invites/create"))
         .json(&json!({
-            "max_uses": 1
+            "maxUses": 1
         }))
         .send()
         .await
@@ -172,8 +172,8 @@ async fn test_invite_list() {
     assert!(list_data["invites"].is_array());
     let invites = list_data["invites"].as_array().unwrap();
     assert_eq!(invites.len(), 1);
-    assert_eq!(invites[0]["max_uses"], 1);
-    assert_eq!(invites[0]["current_uses"], 0);
+    assert_eq!(invites[0]["maxUses"], 1);
+    assert_eq!(invites[0]["currentUses"], 0);
 }
 
 #[tokio::test]
@@ -218,7 +218,7 @@ async fn test_registration_with_invite() {
         .client
         .post(app.url("/invites/create"))
         .json(&json!({
-            "max_uses": 1
+            "maxUses": 1
         }))
         .send()
         .await
@@ -355,7 +355,7 @@ async fn test_registration_validation_errors() {
         .client
         .post(app.url("/invites/create"))
         .json(&json!({
-            "max_uses": 1
+            "maxUses": 1
         }))
         .send()
         .await
@@ -483,7 +483,7 @@ async fn test_invite_single_use_enforcement() {
         .client
         .post(app.url("/invites/create"))
         .json(&json!({
-            "max_uses": 1
+            "maxUses": 1
         }))
         .send()
         .await
@@ -553,8 +553,8 @@ async fn test_invite_single_use_enforcement() {
     
     match used_invite {
         Some(invite) => {
-            assert_eq!(invite["current_uses"], 1);
-            assert_eq!(invite["max_uses"], 1);
+            assert_eq!(invite["currentUses"], 1);
+            assert_eq!(invite["maxUses"], 1);
         }
         None => {
             // If the invite is not in the list, it means it was removed or filtered out
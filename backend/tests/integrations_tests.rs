@@ -0,0 +1,107 @@
+mod common;
+use common::TestApp;
+
+async fn list_integrations(app: &TestApp) -> Vec<serde_json::Value> {
+    let response = app
+        .client
+        .get(app.url("/integrations"))
+        .send()
+        .await
+        .expect("Failed to send integrations request");
+
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    body["integrations"].as_array().unwrap().clone()
+}
+
+fn find<'a>(integrations: &'a [serde_json::Value], name: &str) -> &'a serde_json::Value {
+    integrations
+        .iter()
+        .find(|integration| integration["name"] == name)
+        .unwrap_or_else(|| panic!("Missing integration {}", name))
+}
+
+#[tokio::test]
+async fn test_integrations_require_authentication() {
+    let app = TestApp::new().await;
+
+    let response = app
+        .client
+        .get(app.url("/integrations"))
+        .send()
+        .await
+        .expect("Failed to send integrations request");
+
+    assert_eq!(response.status(), 401);
+}
+
+#[tokio::test]
+async fn test_integrations_unconfigured_without_google_credentials() {
+    let app = TestApp::with_config(|config| config.google = None).await;
+    common::create_test_user(
+        &app,
+        "integrations@example.com",
+        "Integrations",
+        "password123",
+    )
+    .await;
+
+    let integrations = list_integrations(&app).await;
+    assert_eq!(integrations.len(), 3);
+
+    for name in ["googleTasks", "googleCalendar"] {
+        let integration = find(&integrations, name);
+        assert_eq!(integration["enabled"], true);
+        assert_eq!(integration["configured"], false);
+        assert_eq!(integration["connected"], false);
+    }
+
+    let feed = find(&integrations, "icalFeed");
+    assert_eq!(feed["enabled"], true);
+    assert_eq!(feed["configured"], true);
+}
+
+#[tokio::test]
+async fn test_integrations_report_google_disabled() {
+    let app = TestApp::without_google_integration().await;
+    common::create_test_user(&app, "disabled@example.com", "Disabled", "password123").await;
+
+    let integrations = list_integrations(&app).await;
+
+    assert_eq!(find(&integrations, "googleTasks")["enabled"], false);
+    assert_eq!(find(&integrations, "googleCalendar")["enabled"], false);
+    assert_eq!(find(&integrations, "icalFeed")["enabled"], true);
+}
+
+#[tokio::test]
+async fn test_ical_feed_connected_once_a_calendar_app_fetches_it() {
+    let app = TestApp::new().await;
+    let user = common::create_test_user(&app, "feed@example.com", "Feed User", "password123").await;
+    let user_id = user["user"]["id"].as_str().unwrap();
+
+    let integrations = list_integrations(&app).await;
+    assert_eq!(find(&integrations, "icalFeed")["connected"], false);
+
+    let response = app
+        .client
+        .get(app.url(&format!("/calendar/{}.ics?token=0123456789abcdef", user_id)))
+        .send()
+        .await
+        .expect("Failed to send calendar feed request");
+    assert_eq!(response.status(), 200);
+
+    let integrations = list_integrations(&app).await;
+    assert_eq!(find(&integrations, "icalFeed")["connected"], true);
+
+    // A feed nobody has fetched for over a week has no subscriber left
+    let last_week = chrono::Utc::now() - chrono::Duration::days(8);
+    sqlx::query("UPDATE users SET calendar_feed_fetched_at = ? WHERE id = ?")
+        .bind(last_week.to_rfc3339())
+        .bind(user_id)
+        .execute(&app.db_pool)
+        .await
+        .expect("Failed to update feed fetch time");
+
+    let integrations = list_integrations(&app).await;
+    assert_eq!(find(&integrations, "icalFeed")["connected"], false);
+}
@@ -0,0 +1,32 @@
+use planty_api::ApiDoc;
+use utoipa::OpenApi;
+
+#[test]
+fn test_public_spec_only_has_unauthenticated_endpoints() {
+    let full = ApiDoc::openapi();
+    let public = ApiDoc::public();
+
+    assert!(full.paths.paths.contains_key("/plants"));
+    assert!(!public.paths.paths.contains_key("/plants"));
+    assert!(!public.paths.paths.contains_key("/invites/create"));
+    assert!(!public.paths.paths.contains_key("/calendar/subscription"));
+
+    for path in [
+        "/auth/register",
+        "/auth/login",
+        "/invites/validate",
+        "/invites/waitlist",
+        "/calendar/{user_id}.ics",
+    ] {
+        assert!(public.paths.paths.contains_key(path), "missing {path}");
+    }
+}
+
+#[test]
+fn test_cursor_page_schema_uses_camel_case() {
+    let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let properties = &spec["components"]["schemas"]["PlantsCursorResponse"]["properties"];
+
+    assert!(properties.get("nextCursor").is_some());
+    assert!(properties.get("next_cursor").is_none());
+}
@@ -445,6 +445,42 @@ async fn test_serve_photo() {
         .expect("Failed to get photo data");
     assert!(!served_data.is_empty());
     // Don't check exact data match since it's been processed to AVIF
+
+    // Unchanged photos revalidate without a body
+    let photo_path = format!("/plants/{}/photos/{}", plant_id, photo_id);
+    let response = app.client.get(app.url(&photo_path)).send().await.unwrap();
+    assert_eq!(
+        response.headers().get("cache-control").unwrap(),
+        "private, no-cache"
+    );
+    let etag = response.headers().get("etag").unwrap().clone();
+
+    let response = app
+        .client
+        .get(app.url(&photo_path))
+        .header("If-None-Match", etag.clone())
+        .send()
+        .await
+        .expect("Failed to send serve photo request");
+    assert_eq!(response.status(), 304);
+    assert!(response.bytes().await.unwrap().is_empty());
+
+    // Reprocessing keeps the photo id, so the ETag follows the stored image
+    sqlx::query("UPDATE photo_objects SET data = ? WHERE photo_id = ? AND name = 'image'")
+        .bind(&served_data[..served_data.len() - 1])
+        .bind(photo_id)
+        .execute(&app.db_pool)
+        .await
+        .unwrap();
+    let response = app
+        .client
+        .get(app.url(&photo_path))
+        .header("If-None-Match", etag.clone())
+        .send()
+        .await
+        .expect("Failed to send serve photo request");
+    assert_eq!(response.status(), 200);
+    assert_ne!(response.headers().get("etag").unwrap(), &etag);
 }
 
 #[tokio::test]
@@ -475,3 +511,723 @@ async fn test_serve_nonexistent_photo() {
 
     assert_eq!(response.status(), 404);
 }
+
+#[tokio::test]
+async fn test_get_photo_metadata() {
+    let app = TestApp::new().await;
+
+    // Register and login user
+    common::create_test_user(&app, "metadata@example.com", "Metadata User", "password123").await;
+
+    // Create a plant
+    let plant = common::create_test_plant(&app, "Metadata Plant", "Metadaticus").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    // Upload a 16x12 photo
+    let test_image_data = common::create_test_image_data(16, 12);
+    let part = Part::bytes(test_image_data)
+        .file_name("metadata-test.jpg")
+        .mime_str("image/jpeg")
+        .expect("Failed to create part");
+
+    let form = Form::new().part("file", part);
+
+    let upload_response = app
+        .client
+        .post(app.url(&format!("/plants/{}/photos", plant_id)))
+        .multipart(form)
+        .send()
+        .await
+        .expect("Failed to send upload photo request");
+
+    assert_eq!(upload_response.status(), 201);
+
+    let upload_body: serde_json::Value = upload_response
+        .json()
+        .await
+        .expect("Failed to parse upload response");
+    let photo_id = upload_body["id"].as_str().unwrap();
+
+    // Fetch metadata only
+    let response = app
+        .client
+        .get(app.url(&format!(
+            "/plants/{}/photos/{}/metadata",
+            plant_id, photo_id
+        )))
+        .send()
+        .await
+        .expect("Failed to send photo metadata request");
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "application/json"
+    );
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["id"], photo_id);
+    assert_eq!(body["plantId"], plant_id);
+    assert_eq!(body["width"], 16);
+    assert_eq!(body["height"], 12);
+    assert_eq!(body["size"], upload_body["size"]);
+    assert_eq!(body["contentType"], "image/avif");
+    assert!(body.get("data").is_none());
+
+    // Unknown photos are not found
+    let response = app
+        .client
+        .get(app.url(&format!(
+            "/plants/{}/photos/{}/metadata",
+            plant_id,
+            uuid::Uuid::new_v4()
+        )))
+        .send()
+        .await
+        .expect("Failed to send photo metadata request");
+
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn test_upload_photo_with_caption_and_edit() {
+    let app = TestApp::new().await;
+
+    // Register and login user
+    common::create_test_user(&app, "caption@example.com", "Caption User", "password123").await;
+
+    // Create a plant
+    let plant = common::create_test_plant(&app, "Caption Plant", "Captionicus").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    // Upload photo with a caption part
+    let part = Part::bytes(common::create_test_image_data(10, 10))
+        .file_name("caption-test.jpg")
+        .mime_str("image/jpeg")
+        .expect("Failed to create part");
+
+    let form = Form::new()
+        .part("file", part)
+        .text("caption", "  First new leaf  ");
+
+    let upload_response = app
+        .client
+        .post(app.url(&format!("/plants/{}/photos", plant_id)))
+        .multipart(form)
+        .send()
+        .await
+        .expect("Failed to send upload photo request");
+
+    assert_eq!(upload_response.status(), 201);
+
+    let upload_body: serde_json::Value = upload_response
+        .json()
+        .await
+        .expect("Failed to parse upload response");
+    assert_eq!(upload_body["caption"], "First new leaf");
+    let photo_id = upload_body["id"].as_str().unwrap();
+
+    // Edit the caption
+    let response = app
+        .client
+        .put(app.url(&format!("/plants/{}/photos/{}", plant_id, photo_id)))
+        .json(&serde_json::json!({ "caption": "Repotted" }))
+        .send()
+        .await
+        .expect("Failed to send update photo request");
+
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["caption"], "Repotted");
+
+    // Listing includes the updated caption
+    let response = app
+        .client
+        .get(app.url(&format!("/plants/{}/photos", plant_id)))
+        .send()
+        .await
+        .expect("Failed to send list photos request");
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["photos"][0]["caption"], "Repotted");
+
+    // Clearing the caption
+    let response = app
+        .client
+        .put(app.url(&format!("/plants/{}/photos/{}", plant_id, photo_id)))
+        .json(&serde_json::json!({ "caption": null }))
+        .send()
+        .await
+        .expect("Failed to send update photo request");
+
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert!(body["caption"].is_null());
+
+    // Editing a photo that does not exist
+    let response = app
+        .client
+        .put(app.url(&format!(
+            "/plants/{}/photos/{}",
+            plant_id,
+            uuid::Uuid::new_v4()
+        )))
+        .json(&serde_json::json!({ "caption": "Missing" }))
+        .send()
+        .await
+        .expect("Failed to send update photo request");
+
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn test_serve_photo_negotiates_format_from_accept() {
+    // Builds without the avif-decoder feature transcode from the kept original
+    let app = TestApp::with_config(|config| config.keep_photo_originals = true).await;
+
+    common::create_test_user(&app, "accept@example.com", "Accept User", "password123").await;
+    let plant = common::create_test_plant(&app, "Accept Plant", "Acceptus").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let part = Part::bytes(common::create_test_image_data(12, 12))
+        .file_name("accept-test.jpg")
+        .mime_str("image/jpeg")
+        .expect("Failed to create part");
+    let upload_response = app
+        .client
+        .post(app.url(&format!("/plants/{}/photos", plant_id)))
+        .multipart(Form::new().part("file", part))
+        .send()
+        .await
+        .expect("Failed to send upload photo request");
+    assert_eq!(upload_response.status(), 201);
+    let upload_body: serde_json::Value = upload_response.json().await.unwrap();
+    let photo_id = upload_body["id"].as_str().unwrap();
+    let photo_url = app.url(&format!("/plants/{}/photos/{}", plant_id, photo_id));
+
+    let stored: Vec<u8> = sqlx::query_scalar(
+        "SELECT data FROM photo_objects WHERE photo_id = ? AND name = 'image'",
+    )
+    .bind(photo_id)
+    .fetch_one(&app.db_pool)
+    .await
+    .unwrap();
+
+    // AVIF-capable clients get the stored image untouched
+    let response = app
+        .client
+        .get(&photo_url)
+        .header("Accept", "image/avif")
+        .send()
+        .await
+        .expect("Failed to send serve photo request");
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get("content-type").unwrap(), "image/avif");
+    assert_eq!(response.headers().get("vary").unwrap(), "accept");
+    assert_eq!(response.bytes().await.unwrap().to_vec(), stored);
+
+    // WebP clients get a transcoded WebP
+    let response = app
+        .client
+        .get(&photo_url)
+        .header("Accept", "image/webp")
+        .send()
+        .await
+        .expect("Failed to send serve photo request");
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get("content-type").unwrap(), "image/webp");
+    let webp = response.bytes().await.unwrap();
+    assert_eq!(&webp[0..4], b"RIFF");
+    assert_eq!(&webp[8..12], b"WEBP");
+
+    // The transcode is cached per format
+    let cached: Vec<u8> = sqlx::query_scalar(
+        "SELECT data FROM photo_objects WHERE photo_id = ? AND name = 'webp'",
+    )
+    .bind(photo_id)
+    .fetch_one(&app.db_pool)
+    .await
+    .unwrap();
+    assert_eq!(cached, webp.to_vec());
+
+    // Clients accepting neither get JPEG, transcoded on first request too
+    let jpeg_count = || {
+        sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM photo_objects WHERE photo_id = ? AND name = 'jpeg'",
+        )
+        .bind(photo_id)
+        .fetch_one(&app.db_pool)
+    };
+    assert_eq!(jpeg_count().await.unwrap(), 0);
+
+    let response = app
+        .client
+        .get(&photo_url)
+        .header("Accept", "image/png,image/*;q=0.8")
+        .send()
+        .await
+        .expect("Failed to send serve photo request");
+    assert_eq!(response.headers().get("content-type").unwrap(), "image/jpeg");
+    assert_eq!(&response.bytes().await.unwrap()[0..2], &[0xFF, 0xD8]);
+    assert_eq!(jpeg_count().await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn test_list_photos_pagination_and_sort() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "paging@example.com", "Paging User", "password123").await;
+    let plant = common::create_test_plant(&app, "Paging Plant", "Paginicus").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let mut uploaded = Vec::new();
+    for i in 0..3 {
+        let part = Part::bytes(common::create_test_image_data(10, 10))
+            .file_name(format!("photo-{}.jpg", i))
+            .mime_str("image/jpeg")
+            .expect("Failed to create part");
+        let response = app
+            .client
+            .post(app.url(&format!("/plants/{}/photos", plant_id)))
+            .multipart(Form::new().part("file", part))
+            .send()
+            .await
+            .expect("Failed to upload photo");
+        assert_eq!(response.status(), 201);
+        let photo: serde_json::Value = response.json().await.expect("Failed to parse photo");
+        uploaded.push(photo["id"].as_str().unwrap().to_string());
+    }
+
+    let list = |query: &'static str| {
+        let url = app.url(&format!("/plants/{}/photos{}", plant_id, query));
+        let client = app.client.clone();
+        async move {
+            let response = client.get(url).send().await.expect("Failed to list photos");
+            assert_eq!(response.status(), 200);
+            response
+                .json::<serde_json::Value>()
+                .await
+                .expect("Failed to parse response")
+        }
+    };
+    let ids = |body: &serde_json::Value| -> Vec<String> {
+        body["photos"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| p["id"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    // Defaults are echoed when nothing is requested
+    let body = list("").await;
+    assert_eq!(body["limit"], 50);
+    assert_eq!(body["offset"], 0);
+
+    let body = list("?limit=2&offset=1&sort=date_asc").await;
+    assert_eq!(body["total"], 3);
+    assert_eq!(body["limit"], 2);
+    assert_eq!(body["offset"], 1);
+    assert_eq!(ids(&body), uploaded[1..3].to_vec());
+
+    let body = list("?limit=2&offset=1&sort=date_desc").await;
+    assert_eq!(ids(&body), vec![uploaded[1].clone(), uploaded[0].clone()]);
+}
+
+#[tokio::test]
+async fn test_plant_reports_photo_count_and_latest_photo() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "counts@example.com", "Counts User", "password123").await;
+    let plant = common::create_test_plant(&app, "Counted Plant", "Numerus").await;
+    let plant_id = plant["id"].as_str().unwrap();
+    assert_eq!(plant["photoCount"], 0);
+    assert!(plant["latestPhotoId"].is_null());
+
+    let mut uploaded = Vec::new();
+    for i in 0..2 {
+        let part = Part::bytes(common::create_test_image_data(10, 10))
+            .file_name(format!("photo-{}.jpg", i))
+            .mime_str("image/jpeg")
+            .expect("Failed to create part");
+        let response = app
+            .client
+            .post(app.url(&format!("/plants/{}/photos", plant_id)))
+            .multipart(Form::new().part("file", part))
+            .send()
+            .await
+            .expect("Failed to upload photo");
+        assert_eq!(response.status(), 201);
+        let photo: serde_json::Value = response.json().await.expect("Failed to parse photo");
+        uploaded.push(photo["id"].as_str().unwrap().to_string());
+    }
+
+    let plant: serde_json::Value = app
+        .client
+        .get(app.url(&format!("/plants/{}", plant_id)))
+        .send()
+        .await
+        .expect("Failed to get plant")
+        .json()
+        .await
+        .expect("Failed to parse plant");
+    assert_eq!(plant["photoCount"], 2);
+    assert_eq!(plant["latestPhotoId"], uploaded[1]);
+
+    let body: serde_json::Value = app
+        .client
+        .get(app.url("/plants"))
+        .send()
+        .await
+        .expect("Failed to list plants")
+        .json()
+        .await
+        .expect("Failed to parse plants");
+    let listed = &body["plants"][0];
+    assert_eq!(listed["photoCount"], 2);
+    assert_eq!(listed["latestPhotoId"], uploaded[1]);
+}
+
+#[tokio::test]
+async fn test_plant_timeline_interleaves_entries_and_photos() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "timeline@example.com", "Timeline User", "password123").await;
+    let plant = common::create_test_plant(&app, "Timeline Plant", "Chronos").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let mut entry_ids = Vec::new();
+    for timestamp in ["2024-01-01T08:00:00Z", "2099-01-01T08:00:00Z"] {
+        let response = app
+            .client
+            .post(app.url(&format!("/plants/{}/entries", plant_id)))
+            .json(&serde_json::json!({
+                "entryType": "watering",
+                "timestamp": timestamp
+            }))
+            .send()
+            .await
+            .expect("Failed to create entry");
+        assert_eq!(response.status(), 201);
+        let entry: serde_json::Value = response.json().await.expect("Failed to parse entry");
+        entry_ids.push(entry["id"].as_str().unwrap().to_string());
+    }
+
+    // Uploaded now, so it falls between the two entries
+    let part = Part::bytes(common::create_test_image_data(10, 10))
+        .file_name("timeline.jpg")
+        .mime_str("image/jpeg")
+        .expect("Failed to create part");
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/photos", plant_id)))
+        .multipart(Form::new().part("file", part))
+        .send()
+        .await
+        .expect("Failed to upload photo");
+    assert_eq!(response.status(), 201);
+    let photo: serde_json::Value = response.json().await.expect("Failed to parse photo");
+
+    let response = app
+        .client
+        .get(app.url(&format!("/plants/{}/timeline", plant_id)))
+        .send()
+        .await
+        .expect("Failed to get timeline");
+    assert_eq!(response.status(), 200);
+    let timeline: serde_json::Value = response.json().await.expect("Failed to parse timeline");
+
+    assert_eq!(timeline["total"], 3);
+    let items = timeline["items"].as_array().unwrap();
+    let order: Vec<(&str, &str)> = items
+        .iter()
+        .map(|item| (item["type"].as_str().unwrap(), item["id"].as_str().unwrap()))
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            ("entry", entry_ids[1].as_str()),
+            ("photo", photo["id"].as_str().unwrap()),
+            ("entry", entry_ids[0].as_str()),
+        ]
+    );
+    assert!(items[1]["url"].as_str().is_some());
+
+    let page: serde_json::Value = app
+        .client
+        .get(app.url(&format!("/plants/{}/timeline?limit=1&offset=1", plant_id)))
+        .send()
+        .await
+        .expect("Failed to get timeline page")
+        .json()
+        .await
+        .expect("Failed to parse timeline page");
+    assert_eq!(page["total"], 3);
+    assert_eq!(page["items"].as_array().unwrap().len(), 1);
+    assert_eq!(page["items"][0]["type"], "photo");
+}
+
+#[tokio::test]
+async fn test_upload_rejects_undecodable_image() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "corrupt@example.com", "Corrupt User", "password123").await;
+    let plant = common::create_test_plant(&app, "Corrupt Plant", "Brokenia").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    // A JPEG header followed by bytes that aren't image data
+    let mut corrupt = common::create_test_image_data(10, 10);
+    corrupt.truncate(20);
+    corrupt.extend_from_slice(b"definitely not the rest of a jpeg");
+
+    let part = Part::bytes(corrupt)
+        .file_name("corrupt.jpg")
+        .mime_str("image/jpeg")
+        .expect("Failed to create part");
+
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/photos", plant_id)))
+        .multipart(Form::new().part("file", part))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 422);
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse error");
+    let message = body["details"]["file"][0].as_str().unwrap();
+    assert!(
+        message.contains("could not be decoded as image/jpeg"),
+        "{}",
+        message
+    );
+
+    // Nothing was stored
+    let photos: serde_json::Value = app
+        .client
+        .get(app.url(&format!("/plants/{}/photos", plant_id)))
+        .send()
+        .await
+        .expect("Failed to list photos")
+        .json()
+        .await
+        .expect("Failed to parse photos");
+    assert_eq!(photos["total"], 0);
+}
+
+#[tokio::test]
+async fn test_reprocess_photo_without_original() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "reprocess@example.com", "Reprocess User", "password123").await;
+    let plant = common::create_test_plant(&app, "Reprocess Plant", "Reprocessus").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let part = Part::bytes(common::create_test_image_data(10, 10))
+        .file_name("reprocess.jpg")
+        .mime_str("image/jpeg")
+        .expect("Failed to create part");
+    let photo: serde_json::Value = app
+        .client
+        .post(app.url(&format!("/plants/{}/photos", plant_id)))
+        .multipart(Form::new().part("file", part))
+        .send()
+        .await
+        .expect("Failed to send upload photo request")
+        .json()
+        .await
+        .expect("Failed to parse upload response");
+    let photo_id = photo["id"].as_str().unwrap();
+
+    // Originals aren't kept by default
+    let response = app
+        .client
+        .post(app.url(&format!(
+            "/plants/{}/photos/{}/reprocess",
+            plant_id, photo_id
+        )))
+        .send()
+        .await
+        .expect("Failed to send reprocess request");
+    assert_eq!(response.status(), 400);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse error");
+    assert!(body["message"]
+        .as_str()
+        .unwrap()
+        .contains("KEEP_PHOTO_ORIGINALS"));
+
+    let response = app
+        .client
+        .post(app.url(&format!(
+            "/plants/{}/photos/{}/reprocess",
+            plant_id,
+            uuid::Uuid::new_v4()
+        )))
+        .send()
+        .await
+        .expect("Failed to send reprocess request");
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn test_serve_photo_thumbnail() {
+    // Builds without the avif-decoder feature thumbnail the kept original
+    let app = TestApp::with_config(|config| config.keep_photo_originals = true).await;
+
+    common::create_test_user(&app, "thumb@example.com", "Thumb User", "password123").await;
+
+    let plant = common::create_test_plant(&app, "Thumb Plant", "Thumbus").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let part = Part::bytes(common::create_test_image_data(600, 300))
+        .file_name("thumb-test.jpg")
+        .mime_str("image/jpeg")
+        .expect("Failed to create part");
+
+    let upload_response = app
+        .client
+        .post(app.url(&format!("/plants/{}/photos", plant_id)))
+        .multipart(Form::new().part("file", part))
+        .send()
+        .await
+        .expect("Failed to send upload photo request");
+    assert_eq!(upload_response.status(), 201);
+
+    let upload_body: serde_json::Value = upload_response
+        .json()
+        .await
+        .expect("Failed to parse upload response");
+    let photo_id = upload_body["id"].as_str().unwrap();
+    let thumbnail_path = format!("/plants/{}/photos/{}/thumbnail", plant_id, photo_id);
+
+    // Generated on upload
+    let stored: Option<Vec<u8>> =
+        sqlx::query_scalar("SELECT thumbnail_data FROM photos WHERE id = ?")
+            .bind(photo_id)
+            .fetch_one(&app.db_pool)
+            .await
+            .unwrap();
+    let stored = stored.expect("Thumbnail should be stored on upload");
+
+    let response = app
+        .client
+        .get(app.url(&thumbnail_path))
+        .send()
+        .await
+        .expect("Failed to send thumbnail request");
+    assert_eq!(response.status(), 200);
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "image/avif"
+    );
+    assert_eq!(
+        response.headers().get("cache-control").unwrap(),
+        "private, no-cache"
+    );
+    let etag = response.headers().get("etag").unwrap().clone();
+    assert_eq!(response.bytes().await.unwrap().as_ref(), stored.as_slice());
+
+    // Unchanged thumbnails revalidate without a body
+    let response = app
+        .client
+        .get(app.url(&thumbnail_path))
+        .header("If-None-Match", etag.clone())
+        .send()
+        .await
+        .expect("Failed to send thumbnail request");
+    assert_eq!(response.status(), 304);
+    assert!(response.bytes().await.unwrap().is_empty());
+
+    // The ETag follows the content rather than the photo id
+    sqlx::query("UPDATE photos SET thumbnail_data = ? WHERE id = ?")
+        .bind(&stored[..stored.len() - 1])
+        .bind(photo_id)
+        .execute(&app.db_pool)
+        .await
+        .unwrap();
+    let response = app
+        .client
+        .get(app.url(&thumbnail_path))
+        .header("If-None-Match", etag.clone())
+        .send()
+        .await
+        .expect("Failed to send thumbnail request");
+    assert_eq!(response.status(), 200);
+    assert_ne!(response.headers().get("etag").unwrap(), &etag);
+
+    // Photos from before thumbnails existed get one on first request
+    sqlx::query("UPDATE photos SET thumbnail_data = NULL WHERE id = ?")
+        .bind(photo_id)
+        .execute(&app.db_pool)
+        .await
+        .unwrap();
+
+    let response = app
+        .client
+        .get(app.url(&thumbnail_path))
+        .send()
+        .await
+        .expect("Failed to send thumbnail request");
+    assert_eq!(response.status(), 200);
+    assert!(!response.bytes().await.unwrap().is_empty());
+
+    let backfilled: Option<Vec<u8>> =
+        sqlx::query_scalar("SELECT thumbnail_data FROM photos WHERE id = ?")
+            .bind(photo_id)
+            .fetch_one(&app.db_pool)
+            .await
+            .unwrap();
+    assert!(backfilled.is_some());
+
+    // A photo that can't be thumbnailed is an error, not the full image
+    sqlx::query("UPDATE photos SET thumbnail_data = NULL WHERE id = ?")
+        .bind(photo_id)
+        .execute(&app.db_pool)
+        .await
+        .unwrap();
+    sqlx::query("UPDATE photo_objects SET data = ? WHERE photo_id = ?")
+        .bind(&b"not an image"[..])
+        .bind(photo_id)
+        .execute(&app.db_pool)
+        .await
+        .unwrap();
+    let response = app
+        .client
+        .get(app.url(&thumbnail_path))
+        .send()
+        .await
+        .expect("Failed to send thumbnail request");
+    assert_eq!(response.status(), 500);
+
+    // The plant links its preview's thumbnail
+    let response = app
+        .client
+        .put(app.url(&format!("/plants/{}/preview/{}", plant_id, photo_id)))
+        .send()
+        .await
+        .expect("Failed to send set preview request");
+    assert!(response.status().is_success());
+
+    let plant: serde_json::Value = app
+        .client
+        .get(app.url(&format!("/plants/{}", plant_id)))
+        .send()
+        .await
+        .expect("Failed to get plant")
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(plant["thumbnailUrl"], format!("/api/v1{}", thumbnail_path));
+
+    let response = app
+        .client
+        .get(app.url(&format!(
+            "/plants/{}/photos/{}/thumbnail",
+            plant_id,
+            uuid::Uuid::new_v4()
+        )))
+        .send()
+        .await
+        .expect("Failed to send thumbnail request");
+    assert_eq!(response.status(), 404);
+}
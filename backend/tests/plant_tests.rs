@@ -41,6 +41,70 @@ async fn test_create_plant_authenticated() {
     assert!(body["userId"].is_string());
 }
 
+#[tokio::test]
+async fn test_strict_requests_reject_unknown_fields() {
+    let app = TestApp::new().await;
+    common::create_test_user(&app, "strict@example.com", "Strict User", "password123").await;
+
+    let misspelled = json!({
+        "name": "Typo Plant",
+        "genus": "Ficus",
+        "wateringScedule": { "intervalDays": 7 }
+    });
+
+    // Ignored unless the client opts in
+    let response = app
+        .client
+        .post(app.url("/plants"))
+        .json(&misspelled)
+        .send()
+        .await
+        .expect("Failed to send create plant request");
+    assert_eq!(response.status(), 201);
+    let plant: serde_json::Value = response.json().await.unwrap();
+
+    let response = app
+        .client
+        .post(app.url("/plants"))
+        .header("Prefer", "handling=strict")
+        .json(&misspelled)
+        .send()
+        .await
+        .expect("Failed to send create plant request");
+    assert_eq!(response.status(), 400);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["error"], "unknown_fields");
+    assert_eq!(body["details"]["fields"], json!(["wateringScedule"]));
+
+    // Nested fields are reported by path
+    let response = app
+        .client
+        .put(app.url(&format!("/plants/{}", plant["id"].as_str().unwrap())))
+        .header("Prefer", "handling=strict")
+        .json(&json!({ "wateringSchedule": { "intervalDay": 5 } }))
+        .send()
+        .await
+        .expect("Failed to send update plant request");
+    assert_eq!(response.status(), 400);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["details"]["fields"], json!(["wateringSchedule.intervalDay"]));
+
+    // Well-formed strict requests go through
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/entries", plant["id"].as_str().unwrap())))
+        .header("Prefer", "handling=strict")
+        .json(&json!({
+            "entryType": "watering",
+            "timestamp": "2024-05-01T09:00:00Z",
+            "notes": "Soaked"
+        }))
+        .send()
+        .await
+        .expect("Failed to send create entry request");
+    assert_eq!(response.status(), 201);
+}
+
 #[tokio::test]
 async fn test_create_plant_unauthenticated() {
     let app = TestApp::new().await;
@@ -285,6 +349,67 @@ async fn test_plant_validation() {
     assert_eq!(response.status(), 422); // Validation error
 }
 
+#[tokio::test]
+async fn test_plant_name_and_genus_are_normalized() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "normalize@example.com", "Normalize User", "password123")
+        .await;
+
+    // Surrounding whitespace is trimmed and inner runs collapsed
+    let plant = common::create_test_plant(&app, "  Ficus  ", "Ficus   lyrata ").await;
+    assert_eq!(plant["name"], "Ficus");
+    assert_eq!(plant["genus"], "Ficus lyrata");
+
+    let plant_id = plant["id"].as_str().unwrap();
+    let response = app
+        .client
+        .put(app.url(&format!("/plants/{}", plant_id)))
+        .json(&json!({ "name": "  Fiddle   Leaf  " }))
+        .send()
+        .await
+        .expect("Failed to send update plant request");
+
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["name"], "Fiddle Leaf");
+    assert_eq!(body["genus"], "Ficus lyrata");
+}
+
+#[tokio::test]
+async fn test_plant_name_with_control_characters_rejected() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "control@example.com", "Control User", "password123").await;
+
+    let response = app
+        .client
+        .post(app.url("/plants"))
+        .json(&json!({
+            "name": "Ficus\nlyrata",
+            "genus": "Ficus",
+            "customMetrics": []
+        }))
+        .send()
+        .await
+        .expect("Failed to send create plant request");
+
+    assert_eq!(response.status(), 422); // Validation error
+
+    // Whitespace-only names are rejected as well
+    let plant = common::create_test_plant(&app, "Ficus", "Ficus").await;
+    let plant_id = plant["id"].as_str().unwrap();
+    let response = app
+        .client
+        .put(app.url(&format!("/plants/{}", plant_id)))
+        .json(&json!({ "name": "   " }))
+        .send()
+        .await
+        .expect("Failed to send update plant request");
+
+    assert_eq!(response.status(), 422); // Validation error
+}
+
 #[tokio::test]
 async fn test_plant_search() {
     let app = TestApp::new().await;
@@ -371,3 +496,2216 @@ async fn test_plant_pagination() {
     assert_eq!(body["limit"], 10);
     assert_eq!(body["offset"], 10);
 }
+
+#[tokio::test]
+async fn test_bulk_update_schedule() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "bulk@example.com", "Bulk User", "password123").await;
+
+    let mut plant_ids = Vec::new();
+    for name in ["Fern", "Pothos", "Calathea"] {
+        let plant = common::create_test_plant(&app, name, "Summerus").await;
+        plant_ids.push(plant["id"].as_str().unwrap().to_string());
+    }
+
+    let response = app
+        .client
+        .post(app.url("/plants/bulk-update-schedule"))
+        .json(&json!({
+            "plantIds": plant_ids,
+            "wateringIntervalDays": 10
+        }))
+        .send()
+        .await
+        .expect("Failed to send bulk update request");
+
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["updated"], 3);
+
+    for plant_id in &plant_ids {
+        let response = app
+            .client
+            .get(app.url(&format!("/plants/{}", plant_id)))
+            .send()
+            .await
+            .expect("Failed to send get plant request");
+        let plant: serde_json::Value = response.json().await.expect("Failed to parse response");
+
+        assert_eq!(plant["wateringSchedule"]["intervalDays"], 10);
+        // Omitted intervals are left alone
+        assert_eq!(plant["fertilizingSchedule"]["intervalDays"], 14);
+    }
+}
+
+#[tokio::test]
+async fn test_bulk_update_schedule_skips_other_users_plants() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "owner@example.com", "Owner", "password123").await;
+    let other_plant = common::create_test_plant(&app, "Not Yours", "Ownerus").await;
+    let other_plant_id = other_plant["id"].as_str().unwrap();
+
+    app.client
+        .post(app.url("/auth/logout"))
+        .send()
+        .await
+        .unwrap();
+
+    common::create_test_user(&app, "bulker@example.com", "Bulker", "password123").await;
+    let own_plant = common::create_test_plant(&app, "Mine", "Bulkus").await;
+
+    let response = app
+        .client
+        .post(app.url("/plants/bulk-update-schedule"))
+        .json(&json!({
+            "plantIds": [own_plant["id"], other_plant_id],
+            "fertilizingIntervalDays": 30
+        }))
+        .send()
+        .await
+        .expect("Failed to send bulk update request");
+
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["updated"], 1);
+
+    let interval: Option<i32> =
+        sqlx::query_scalar("SELECT fertilizing_interval_days FROM plants WHERE id = ?")
+            .bind(other_plant_id)
+            .fetch_one(&app.db_pool)
+            .await
+            .unwrap();
+    assert_eq!(interval, Some(14));
+}
+
+#[tokio::test]
+async fn test_bulk_update_schedule_validation() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "bulkval@example.com", "Bulk Val", "password123").await;
+    let plant = common::create_test_plant(&app, "Fern", "Filicus").await;
+
+    // Interval out of range
+    let response = app
+        .client
+        .post(app.url("/plants/bulk-update-schedule"))
+        .json(&json!({
+            "plantIds": [plant["id"]],
+            "wateringIntervalDays": 0
+        }))
+        .send()
+        .await
+        .expect("Failed to send bulk update request");
+    assert_eq!(response.status(), 422);
+
+    // No intervals at all
+    let response = app
+        .client
+        .post(app.url("/plants/bulk-update-schedule"))
+        .json(&json!({ "plantIds": [plant["id"]] }))
+        .send()
+        .await
+        .expect("Failed to send bulk update request");
+    assert_eq!(response.status(), 400);
+}
+
+#[tokio::test]
+async fn test_plant_last_occurrence_fields() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "occur@example.com", "Occur User", "password123").await;
+    let plant = common::create_test_plant(&app, "Tracked Plant", "Trackus").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    assert!(plant["lastNoteAt"].is_null());
+    assert!(plant["lastPhotoAt"].is_null());
+    assert!(plant["lastMeasurementAt"].is_null());
+
+    for (entry_type, timestamp) in [
+        ("note", "2024-01-01T12:00:00Z"),
+        ("note", "2024-02-01T12:00:00Z"),
+        ("photo", "2024-03-01T12:00:00Z"),
+        ("customMetric", "2024-04-01T12:00:00Z"),
+    ] {
+        let response = app
+            .client
+            .post(app.url(&format!("/plants/{}/entries", plant_id)))
+            .json(&json!({
+                "entryType": entry_type,
+                "timestamp": timestamp,
+                "value": 12.5
+            }))
+            .send()
+            .await
+            .expect("Failed to send create entry request");
+        assert_eq!(response.status(), 201);
+    }
+
+    // Later as text but earlier as an instant, so it isn't the latest note
+    sqlx::query(
+        "UPDATE tracking_entries SET timestamp = '2024-02-01T13:00:00+02:00'
+         WHERE plant_id = ? AND timestamp LIKE '2024-01-01%'",
+    )
+    .bind(plant_id)
+    .execute(&app.db_pool)
+    .await
+    .expect("Failed to update entry");
+
+    let response = app
+        .client
+        .get(app.url(&format!("/plants/{}", plant_id)))
+        .send()
+        .await
+        .expect("Failed to send get plant request");
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+
+    assert_eq!(body["lastNoteAt"], "2024-02-01T12:00:00Z");
+    assert_eq!(body["lastPhotoAt"], "2024-03-01T12:00:00Z");
+    assert_eq!(body["lastMeasurementAt"], "2024-04-01T12:00:00Z");
+
+    // The list endpoint fills them in too
+    common::create_test_plant(&app, "Untracked Plant", "Quietus").await;
+    let response = app
+        .client
+        .get(app.url("/plants"))
+        .send()
+        .await
+        .expect("Failed to send list plants request");
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+
+    for plant in body["plants"].as_array().unwrap() {
+        if plant["id"] == plant_id {
+            assert_eq!(plant["lastNoteAt"], "2024-02-01T12:00:00Z");
+            assert_eq!(plant["lastMeasurementAt"], "2024-04-01T12:00:00Z");
+        } else {
+            assert!(plant["lastNoteAt"].is_null());
+            assert!(plant["lastPhotoAt"].is_null());
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_create_plant_with_custom_metrics() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "metrics@example.com", "Metrics User", "password123").await;
+
+    let response = app
+        .client
+        .post(app.url("/plants"))
+        .json(&json!({
+            "name": "Measured Plant",
+            "genus": "Metricus",
+            "customMetrics": [
+                { "name": "Height", "unit": "cm", "dataType": "Number" },
+                { "name": "Leaf color", "unit": "", "dataType": "Text" }
+            ]
+        }))
+        .send()
+        .await
+        .expect("Failed to send create plant request");
+    assert_eq!(response.status(), 201);
+    let plant: serde_json::Value = response.json().await.expect("Failed to parse response");
+
+    let metrics: Vec<(String, String)> = sqlx::query_as(
+        "SELECT name, data_type FROM custom_metrics WHERE plant_id = ? ORDER BY name",
+    )
+    .bind(plant["id"].as_str().unwrap())
+    .fetch_all(&app.db_pool)
+    .await
+    .unwrap();
+    assert_eq!(
+        metrics,
+        vec![
+            ("Height".to_string(), "number".to_string()),
+            ("Leaf color".to_string(), "text".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_create_plant_rolls_back_when_metric_insert_fails() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "rollback@example.com", "Rollback User", "password123").await;
+
+    // Fail the metric insert that follows the plant insert
+    sqlx::query(
+        "CREATE TRIGGER fail_metric_insert BEFORE INSERT ON custom_metrics
+         BEGIN SELECT RAISE(ABORT, 'injected failure'); END",
+    )
+    .execute(&app.db_pool)
+    .await
+    .unwrap();
+
+    let response = app
+        .client
+        .post(app.url("/plants"))
+        .json(&json!({
+            "name": "Partial Plant",
+            "genus": "Partialis",
+            "customMetrics": [
+                { "name": "Height", "unit": "cm", "dataType": "Number" }
+            ]
+        }))
+        .send()
+        .await
+        .expect("Failed to send create plant request");
+    assert_eq!(response.status(), 500);
+
+    let plant_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM plants")
+        .fetch_one(&app.db_pool)
+        .await
+        .unwrap();
+    assert_eq!(plant_count, 0);
+}
+
+#[tokio::test]
+async fn test_list_plants_summary_fields() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "summary@example.com", "Summary User", "password123").await;
+    let plant = common::create_test_plant(&app, "Summary Plant", "Summaria").await;
+
+    let response = app
+        .client
+        .get(app.url("/plants?fields=summary"))
+        .send()
+        .await
+        .expect("Failed to send list plants request");
+    assert_eq!(response.status(), 200);
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["total"], 1);
+    let summary = &body["plants"][0];
+    assert_eq!(summary["id"], plant["id"]);
+    assert_eq!(summary["name"], "Summary Plant");
+    assert!(summary["nextWateringDue"].is_string());
+    assert!(summary["nextFertilizingDue"].is_string());
+    // Never cared for, so due now
+    assert_eq!(summary["wateringSeverity"], "due_soon");
+    for omitted in [
+        "customMetrics",
+        "wateringSchedule",
+        "fertilizingSchedule",
+        "userId",
+    ] {
+        assert!(summary.get(omitted).is_none(), "{} should be omitted", omitted);
+    }
+
+    // The default shape is unchanged
+    let response = app
+        .client
+        .get(app.url("/plants?fields=full"))
+        .send()
+        .await
+        .expect("Failed to send list plants request");
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert!(body["plants"][0]["customMetrics"].is_array());
+    assert!(body["plants"][0]["wateringSchedule"].is_object());
+
+    let response = app
+        .client
+        .get(app.url("/plants?fields=everything"))
+        .send()
+        .await
+        .expect("Failed to send list plants request");
+    assert_eq!(response.status(), 400);
+}
+
+#[tokio::test]
+async fn test_seed_examples_is_idempotent() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "seed@example.com", "Seed User", "password123").await;
+
+    let response = app
+        .client
+        .post(app.url("/plants/seed-examples"))
+        .send()
+        .await
+        .expect("Failed to send seed request");
+    assert_eq!(response.status(), 201);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["seeded"], true);
+    let seeded = body["plants"].as_array().unwrap();
+    assert_eq!(seeded.len(), 2);
+    assert!(seeded[0]["wateringSchedule"]["intervalDays"].is_number());
+    let metric_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM custom_metrics")
+        .fetch_one(&app.db_pool)
+        .await
+        .unwrap();
+    assert!(metric_count > 0);
+
+    let response = app
+        .client
+        .post(app.url("/plants/seed-examples"))
+        .send()
+        .await
+        .expect("Failed to send seed request");
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["seeded"], false);
+    assert!(body["plants"].as_array().unwrap().is_empty());
+
+    let body: serde_json::Value = app
+        .client
+        .get(app.url("/plants"))
+        .send()
+        .await
+        .expect("Failed to list plants")
+        .json()
+        .await
+        .expect("Failed to parse plants");
+    assert_eq!(body["total"], 2);
+}
+
+#[tokio::test]
+async fn test_care_schedule_units_are_normalized() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "units@example.com", "Units User", "password123").await;
+
+    let response = app
+        .client
+        .post(app.url("/plants"))
+        .json(&json!({
+            "name": "Unit Plant",
+            "genus": "Unitas",
+            "wateringSchedule": { "intervalDays": 7, "amount": 250.0, "unit": "mL" },
+            "fertilizingSchedule": { "intervalDays": 14, "amount": 5.0, "unit": "milliliters" },
+            "customMetrics": []
+        }))
+        .send()
+        .await
+        .expect("Failed to create plant");
+    assert_eq!(response.status(), 201);
+    let plant: serde_json::Value = response.json().await.expect("Failed to parse plant");
+    assert_eq!(plant["wateringSchedule"]["unit"], "ml");
+    assert_eq!(plant["fertilizingSchedule"]["unit"], "ml");
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let response = app
+        .client
+        .put(app.url(&format!("/plants/{}", plant_id)))
+        .json(&json!({
+            "wateringSchedule": { "intervalDays": 7, "amount": 1.0, "unit": "Cups" }
+        }))
+        .send()
+        .await
+        .expect("Failed to update plant");
+    assert_eq!(response.status(), 200);
+    let plant: serde_json::Value = response.json().await.expect("Failed to parse plant");
+    assert_eq!(plant["wateringSchedule"]["unit"], "cup");
+
+    let response = app
+        .client
+        .put(app.url(&format!("/plants/{}", plant_id)))
+        .json(&json!({
+            "wateringSchedule": { "intervalDays": 7, "amount": 3.0, "unit": "splashes" }
+        }))
+        .send()
+        .await
+        .expect("Failed to update plant");
+    assert_eq!(response.status(), 422);
+
+    let response = app
+        .client
+        .post(app.url("/plants"))
+        .json(&json!({
+            "name": "Splashy Plant",
+            "genus": "Unitas",
+            "wateringSchedule": { "intervalDays": 7, "amount": 3.0, "unit": "splashes" },
+            "customMetrics": []
+        }))
+        .send()
+        .await
+        .expect("Failed to create plant");
+    assert_eq!(response.status(), 422);
+
+    // An amount needs a unit and a unit needs an amount
+    for schedule in [
+        json!({ "intervalDays": 7, "amount": 250.0 }),
+        json!({ "intervalDays": 7, "unit": "ml" }),
+    ] {
+        let response = app
+            .client
+            .put(app.url(&format!("/plants/{}", plant_id)))
+            .json(&json!({ "fertilizingSchedule": schedule }))
+            .send()
+            .await
+            .expect("Failed to update plant");
+        assert_eq!(response.status(), 422, "{}", schedule);
+
+        let response = app
+            .client
+            .post(app.url("/plants"))
+            .json(&json!({ "name": "Half Schedule", "genus": "Unitas", "wateringSchedule": schedule }))
+            .send()
+            .await
+            .expect("Failed to create plant");
+        assert_eq!(response.status(), 422, "{}", schedule);
+    }
+}
+
+#[tokio::test]
+async fn test_plant_description_create_update_and_search() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "describe@example.com", "Describe User", "password123").await;
+    common::create_test_plant(&app, "Other Plant", "Otherus").await;
+
+    let response = app
+        .client
+        .post(app.url("/plants"))
+        .json(&json!({
+            "name": "Kitchen Fern",
+            "genus": "Nephrolepis",
+            "description": "  Gift from grandma, sits on the north windowsill  ",
+            "customMetrics": []
+        }))
+        .send()
+        .await
+        .expect("Failed to create plant");
+    assert_eq!(response.status(), 201);
+    let plant: serde_json::Value = response.json().await.expect("Failed to parse plant");
+    assert_eq!(
+        plant["description"],
+        "Gift from grandma, sits on the north windowsill"
+    );
+    let plant_id = plant["id"].as_str().unwrap();
+
+    // Updating other fields leaves the description alone
+    let plant: serde_json::Value = app
+        .client
+        .put(app.url(&format!("/plants/{}", plant_id)))
+        .json(&json!({ "name": "Fern" }))
+        .send()
+        .await
+        .expect("Failed to update plant")
+        .json()
+        .await
+        .expect("Failed to parse plant");
+    assert_eq!(
+        plant["description"],
+        "Gift from grandma, sits on the north windowsill"
+    );
+
+    let response = app
+        .client
+        .put(app.url(&format!("/plants/{}", plant_id)))
+        .json(&json!({ "description": "Moved to the bathroom for humidity" }))
+        .send()
+        .await
+        .expect("Failed to update plant");
+    assert_eq!(response.status(), 200);
+    let plant: serde_json::Value = response.json().await.expect("Failed to parse plant");
+    assert_eq!(plant["description"], "Moved to the bathroom for humidity");
+
+    let body: serde_json::Value = app
+        .client
+        .get(app.url("/plants?search=bathroom"))
+        .send()
+        .await
+        .expect("Failed to search plants")
+        .json()
+        .await
+        .expect("Failed to parse plants");
+    assert_eq!(body["total"], 1);
+    assert_eq!(body["plants"][0]["id"], plant_id);
+
+    let response = app
+        .client
+        .put(app.url(&format!("/plants/{}", plant_id)))
+        .json(&json!({ "description": "a".repeat(2001) }))
+        .send()
+        .await
+        .expect("Failed to update plant");
+    assert_eq!(response.status(), 422);
+
+    let plant: serde_json::Value = app
+        .client
+        .put(app.url(&format!("/plants/{}", plant_id)))
+        .json(&json!({ "description": "" }))
+        .send()
+        .await
+        .expect("Failed to update plant")
+        .json()
+        .await
+        .expect("Failed to parse plant");
+    assert!(plant["description"].is_null());
+}
+
+#[tokio::test]
+async fn test_plant_age_and_anniversaries() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "birthday@example.com", "Birthday User", "password123").await;
+
+    let today = chrono::Utc::now().date_naive();
+    // First birthday in three days
+    let acquired = today - chrono::Months::new(12) + chrono::Duration::days(3);
+    let response = app
+        .client
+        .post(app.url("/plants"))
+        .json(&json!({
+            "name": "Birthday Plant",
+            "genus": "Festivus",
+            "acquiredAt": acquired,
+            "customMetrics": []
+        }))
+        .send()
+        .await
+        .expect("Failed to create plant");
+    assert_eq!(response.status(), 201);
+    let plant: serde_json::Value = response.json().await.expect("Failed to parse plant");
+    assert_eq!(plant["acquiredAt"], acquired.to_string());
+    assert_eq!(plant["ageDays"], (today - acquired).num_days());
+    let plant_id = plant["id"].as_str().unwrap().to_string();
+
+    // Monthly anniversary about three weeks out, beyond the default window
+    let response = app
+        .client
+        .post(app.url("/plants"))
+        .json(&json!({
+            "name": "Later Plant",
+            "genus": "Festivus",
+            "acquiredAt": today - chrono::Months::new(2) + chrono::Duration::days(21),
+            "customMetrics": []
+        }))
+        .send()
+        .await
+        .expect("Failed to create plant");
+    assert_eq!(response.status(), 201);
+
+    let body: serde_json::Value = app
+        .client
+        .get(app.url("/plants/anniversaries?within_days=7"))
+        .send()
+        .await
+        .expect("Failed to list anniversaries")
+        .json()
+        .await
+        .expect("Failed to parse anniversaries");
+    let anniversaries = body["anniversaries"].as_array().unwrap();
+    assert_eq!(anniversaries.len(), 1);
+    assert_eq!(anniversaries[0]["plantId"], plant_id);
+    assert_eq!(anniversaries[0]["daysUntil"], 3);
+    assert_eq!(anniversaries[0]["months"], 12);
+    assert_eq!(anniversaries[0]["yearly"], true);
+
+    let response = app
+        .client
+        .get(app.url("/plants/anniversaries?within_days=1000"))
+        .send()
+        .await
+        .expect("Failed to list anniversaries");
+    assert_eq!(response.status(), 400);
+
+    // Future acquisition dates are rejected, and null clears the date
+    let response = app
+        .client
+        .put(app.url(&format!("/plants/{}", plant_id)))
+        .json(&json!({ "acquiredAt": today + chrono::Duration::days(30) }))
+        .send()
+        .await
+        .expect("Failed to update plant");
+    assert_eq!(response.status(), 422);
+
+    let plant: serde_json::Value = app
+        .client
+        .put(app.url(&format!("/plants/{}", plant_id)))
+        .json(&json!({ "acquiredAt": null }))
+        .send()
+        .await
+        .expect("Failed to update plant")
+        .json()
+        .await
+        .expect("Failed to parse plant");
+    assert!(plant["acquiredAt"].is_null());
+    assert!(plant["ageDays"].is_null());
+}
+
+#[tokio::test]
+async fn test_plant_location_validation_and_near_filter() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "locate@example.com", "Locate User", "password123").await;
+    common::create_test_plant(&app, "Unplaced Plant", "Nowhereus").await;
+
+    let create = |name: &str, location: serde_json::Value| {
+        let mut body = json!({ "name": name, "genus": "Ficus", "customMetrics": [] });
+        body.as_object_mut()
+            .unwrap()
+            .extend(location.as_object().unwrap().clone());
+        app.client.post(app.url("/plants")).json(&body).send()
+    };
+
+    // Out of range or half-specified coordinates are rejected
+    for location in [
+        json!({ "latitude": 95.0, "longitude": 12.0 }),
+        json!({ "latitude": 55.0, "longitude": -181.0 }),
+        json!({ "latitude": 55.0 }),
+    ] {
+        let response = create("Bad Location", location.clone())
+            .await
+            .expect("Failed to create plant");
+        assert_eq!(response.status(), 422, "{}", location);
+    }
+
+    // Copenhagen, Malmö (~28 km away) and Aarhus (~155 km away)
+    let response = create(
+        "Balcony Fig",
+        json!({ "locationName": "  Balcony  ", "latitude": 55.6761, "longitude": 12.5683 }),
+    )
+    .await
+    .expect("Failed to create plant");
+    assert_eq!(response.status(), 201);
+    let plant: serde_json::Value = response.json().await.expect("Failed to parse plant");
+    assert_eq!(plant["locationName"], "Balcony");
+    assert_eq!(plant["latitude"], 55.6761);
+    let plant_id = plant["id"].as_str().unwrap().to_string();
+
+    for (name, latitude, longitude) in [
+        ("Malmö Fig", 55.605, 13.0038),
+        ("Aarhus Fig", 56.1629, 10.2039),
+    ] {
+        let response = create(name, json!({ "latitude": latitude, "longitude": longitude }))
+            .await
+            .expect("Failed to create plant");
+        assert_eq!(response.status(), 201);
+    }
+
+    let names = |body: serde_json::Value| -> Vec<String> {
+        body["plants"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|plant| plant["name"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    let body: serde_json::Value = app
+        .client
+        .get(app.url("/plants?near=55.68,12.57,10&sort=name_asc"))
+        .send()
+        .await
+        .expect("Failed to list plants")
+        .json()
+        .await
+        .expect("Failed to parse plants");
+    assert_eq!(body["total"], 1);
+    assert_eq!(names(body), vec!["Balcony Fig"]);
+
+    let body: serde_json::Value = app
+        .client
+        .get(app.url("/plants?near=55.68,12.57,50&sort=name_asc"))
+        .send()
+        .await
+        .expect("Failed to list plants")
+        .json()
+        .await
+        .expect("Failed to parse plants");
+    assert_eq!(body["total"], 2);
+    assert_eq!(names(body), vec!["Balcony Fig", "Malmö Fig"]);
+
+    for near in ["55.68,12.57", "north,12.57,10", "55.68,12.57,0", "91,12.57,10"] {
+        let response = app
+            .client
+            .get(app.url(&format!("/plants?near={}", near)))
+            .send()
+            .await
+            .expect("Failed to list plants");
+        assert_eq!(response.status(), 400, "{}", near);
+    }
+
+    // Clearing the location drops the plant from near results
+    let response = app
+        .client
+        .put(app.url(&format!("/plants/{}", plant_id)))
+        .json(&json!({ "locationName": "", "latitude": null, "longitude": null }))
+        .send()
+        .await
+        .expect("Failed to update plant");
+    assert_eq!(response.status(), 200);
+    let plant: serde_json::Value = response.json().await.expect("Failed to parse plant");
+    assert!(plant["locationName"].is_null());
+    assert!(plant["latitude"].is_null());
+
+    let body: serde_json::Value = app
+        .client
+        .get(app.url("/plants?near=55.68,12.57,10"))
+        .send()
+        .await
+        .expect("Failed to list plants")
+        .json()
+        .await
+        .expect("Failed to parse plants");
+    assert_eq!(body["total"], 0);
+}
+
+#[tokio::test]
+async fn test_watering_suggestion_with_stub_weather() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "weather@example.com", "Weather User", "password123").await;
+
+    let plant: serde_json::Value = app
+        .client
+        .post(app.url("/plants"))
+        .json(&json!({
+            "name": "Patio Tomato",
+            "genus": "Solanum",
+            "latitude": 55.6761,
+            "longitude": 12.5683,
+            "wateringSchedule": { "intervalDays": 2 },
+            "customMetrics": []
+        }))
+        .send()
+        .await
+        .expect("Failed to create plant")
+        .json()
+        .await
+        .expect("Failed to parse plant");
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let response = app
+        .client
+        .get(app.url(&format!("/plants/{}/watering-suggestion", plant_id)))
+        .send()
+        .await
+        .expect("Failed to get watering suggestion");
+    assert_eq!(response.status(), 200);
+    let suggestion: serde_json::Value = response.json().await.expect("Failed to parse suggestion");
+
+    // The stub has no weather data, so the schedule is kept as is
+    assert_eq!(suggestion["plantId"], plant_id);
+    assert_eq!(suggestion["provider"], "stub");
+    assert_eq!(suggestion["adjustment"], "keep");
+    assert_eq!(suggestion["shiftDays"], 0);
+    assert_eq!(suggestion["lookbackDays"], 2);
+    assert!(suggestion["precipitationMm"].is_null());
+    assert_eq!(suggestion["suggestedAt"], suggestion["scheduledAt"]);
+    assert!(suggestion["reason"].is_string());
+
+    // Plants without coordinates can't get a suggestion
+    let indoor = common::create_test_plant(&app, "Indoor Fern", "Nephrolepis").await;
+    let response = app
+        .client
+        .get(app.url(&format!(
+            "/plants/{}/watering-suggestion",
+            indoor["id"].as_str().unwrap()
+        )))
+        .send()
+        .await
+        .expect("Failed to get watering suggestion");
+    assert_eq!(response.status(), 400);
+}
+
+#[tokio::test]
+async fn test_care_recommendations_follow_watering_history() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "cadence@example.com", "Cadence User", "password123").await;
+    let plant = common::create_test_plant(&app, "Pothos", "Epipremnum").await;
+    let plant_id = plant["id"].as_str().unwrap();
+    let recommendations = || async {
+        let response = app
+            .client
+            .get(app.url(&format!("/plants/{}/recommendations", plant_id)))
+            .send()
+            .await
+            .expect("Failed to get recommendations");
+        assert_eq!(response.status(), 200);
+        response
+            .json::<serde_json::Value>()
+            .await
+            .expect("Failed to parse recommendations")
+    };
+
+    // Not enough history yet
+    let body = recommendations().await;
+    assert_eq!(body["plantId"], plant_id);
+    assert!(body["watering"].is_null());
+
+    let start = chrono::Utc::now() - chrono::Duration::days(40);
+    for day in [0, 9, 19, 27, 36] {
+        let response = app
+            .client
+            .post(app.url(&format!("/plants/{}/entries", plant_id)))
+            .json(&json!({
+                "entryType": "watering",
+                "timestamp": start + chrono::Duration::days(day),
+            }))
+            .send()
+            .await
+            .expect("Failed to create entry");
+        assert_eq!(response.status(), 201);
+    }
+
+    let watering = recommendations().await["watering"].clone();
+    assert_eq!(watering["currentIntervalDays"], 7);
+    assert_eq!(watering["suggestedIntervalDays"], 9);
+    assert_eq!(watering["intervalsObserved"], 4);
+    assert_eq!(watering["key"], "watering");
+    assert!(watering["reason"].is_string());
+}
+
+#[tokio::test]
+async fn test_dismissed_recommendation_is_hidden_until_it_changes() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "dismiss@example.com", "Dismiss User", "password123").await;
+    let plant = common::create_test_plant(&app, "Calathea", "Goeppertia").await;
+    let plant_id = plant["id"].as_str().unwrap();
+    let watering = || async {
+        app.client
+            .get(app.url(&format!("/plants/{}/recommendations", plant_id)))
+            .send()
+            .await
+            .expect("Failed to get recommendations")
+            .json::<serde_json::Value>()
+            .await
+            .expect("Failed to parse recommendations")["watering"]
+            .clone()
+    };
+    let dismiss = |key: &'static str| {
+        app.client
+            .post(app.url(&format!(
+                "/plants/{}/recommendations/{}/dismiss",
+                plant_id, key
+            )))
+            .send()
+    };
+
+    // Nothing to dismiss yet
+    assert_eq!(dismiss("watering").await.unwrap().status(), 404);
+
+    let start = chrono::Utc::now() - chrono::Duration::days(80);
+    let water = |day: i64| {
+        app.client
+            .post(app.url(&format!("/plants/{}/entries", plant_id)))
+            .json(&json!({
+                "entryType": "watering",
+                "timestamp": start + chrono::Duration::days(day),
+            }))
+            .send()
+    };
+    for day in [0, 10, 20, 30] {
+        assert_eq!(water(day).await.unwrap().status(), 201);
+    }
+    assert_eq!(watering().await["suggestedIntervalDays"], 10);
+
+    assert_eq!(dismiss("watering").await.unwrap().status(), 204);
+    assert!(watering().await.is_null());
+    assert_eq!(dismiss("fertilizing").await.unwrap().status(), 404);
+
+    // A slower cadence is a different recommendation
+    for day in [44, 58, 72] {
+        assert_eq!(water(day).await.unwrap().status(), 201);
+    }
+    assert_eq!(watering().await["suggestedIntervalDays"], 12);
+}
+
+#[tokio::test]
+async fn test_plant_report_html() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "report@example.com", "Report User", "password123").await;
+    let plant = common::create_test_plant(&app, "Fiddle <Leaf> Fig", "Ficus").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    for (days_ago, notes) in [(10, "Soaked"), (3, "Top-up & mist")] {
+        let response = app
+            .client
+            .post(app.url(&format!("/plants/{}/entries", plant_id)))
+            .json(&json!({
+                "entryType": "watering",
+                "timestamp": chrono::Utc::now() - chrono::Duration::days(days_ago),
+                "notes": notes,
+            }))
+            .send()
+            .await
+            .expect("Failed to create entry");
+        assert_eq!(response.status(), 201);
+    }
+
+    let response = app
+        .client
+        .get(app.url(&format!("/plants/{}/report", plant_id)))
+        .send()
+        .await
+        .expect("Failed to get report");
+    assert_eq!(response.status(), 200);
+    assert!(response.headers()["content-type"]
+        .to_str()
+        .unwrap()
+        .starts_with("text/html"));
+    let html = response.text().await.unwrap();
+
+    // User-provided text is escaped
+    assert!(html.contains("<h1>Fiddle &lt;Leaf&gt; Fig</h1>"));
+    assert!(html.contains(r#"<p class="score" id="health-score">100%</p>"#));
+    assert!(html.contains(r#"<table id="care-history">"#));
+    assert_eq!(html.matches("<td>Watering</td>").count(), 2);
+    assert!(html.contains("Top-up &amp; mist"));
+    assert!(html.contains("Every 7 days, last on"));
+
+    // Other users can't see the report
+    common::create_test_user(&app, "other-report@example.com", "Other", "password123").await;
+    let response = app
+        .client
+        .get(app.url(&format!("/plants/{}/report", plant_id)))
+        .send()
+        .await
+        .expect("Failed to get report");
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn test_export_plants_csv() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "export@example.com", "Export User", "password123").await;
+
+    for body in [
+        json!({
+            "name": "Monstera, the big one",
+            "genus": "Monstera",
+            "wateringSchedule": { "intervalDays": 7 },
+            "fertilizingSchedule": { "intervalDays": 30 },
+            "lastWatered": "2024-05-01T08:00:00Z",
+            "lastFertilized": "2024-04-15T08:00:00Z",
+            "customMetrics": []
+        }),
+        json!({
+            "name": "Pothos",
+            "genus": "Epipremnum",
+            "wateringSchedule": { "intervalDays": 3 },
+            "lastWatered": "2024-05-10T18:30:00Z",
+            "customMetrics": []
+        }),
+    ] {
+        let response = app
+            .client
+            .post(app.url("/plants"))
+            .json(&body)
+            .send()
+            .await
+            .expect("Failed to create plant");
+        assert_eq!(response.status(), 201);
+    }
+
+    let response = app
+        .client
+        .get(app.url("/plants/export.csv?sort=name_asc"))
+        .send()
+        .await
+        .expect("Failed to export plants");
+    assert_eq!(response.status(), 200);
+    assert!(response.headers()["content-type"]
+        .to_str()
+        .unwrap()
+        .starts_with("text/csv"));
+    let csv = response.text().await.expect("Failed to read CSV");
+
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(
+        lines[0],
+        "id,name,genus,watering_interval_days,fertilizing_interval_days,last_watered,last_fertilized,next_watering_due,next_fertilizing_due"
+    );
+    assert_eq!(lines.len(), 3, "{}", csv);
+
+    // Names with commas are quoted; next due is last care plus the interval
+    assert!(
+        lines[1].ends_with(
+            ",\"Monstera, the big one\",Monstera,7,30,2024-05-01T08:00:00Z,2024-04-15T08:00:00Z,2024-05-08T08:00:00Z,2024-05-15T08:00:00Z"
+        ),
+        "{}",
+        lines[1]
+    );
+    assert!(
+        lines[2].ends_with(",Pothos,Epipremnum,3,,2024-05-10T18:30:00Z,,2024-05-13T18:30:00Z,"),
+        "{}",
+        lines[2]
+    );
+
+    // The listing's search filter applies
+    let csv = app
+        .client
+        .get(app.url("/plants/export.csv?search=pothos"))
+        .send()
+        .await
+        .expect("Failed to export plants")
+        .text()
+        .await
+        .expect("Failed to read CSV");
+    assert_eq!(csv.lines().count(), 2);
+}
+
+#[tokio::test]
+async fn test_upcoming_care_occurrences() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "upcoming@example.com", "Upcoming User", "password123").await;
+
+    let last_watered = chrono::Utc::now() - chrono::Duration::days(1);
+    let plant: serde_json::Value = app
+        .client
+        .post(app.url("/plants"))
+        .json(&json!({
+            "name": "Weekly Fern",
+            "genus": "Nephrolepis",
+            "wateringSchedule": { "intervalDays": 7 },
+            "fertilizingSchedule": { "intervalDays": 30 },
+            "lastWatered": last_watered,
+            "customMetrics": []
+        }))
+        .send()
+        .await
+        .expect("Failed to create plant")
+        .json()
+        .await
+        .expect("Failed to parse plant");
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let response = app
+        .client
+        .get(app.url(&format!("/plants/{}/upcoming?count=5", plant_id)))
+        .send()
+        .await
+        .expect("Failed to get upcoming care");
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse upcoming care");
+    assert_eq!(body["plantId"], plant_id);
+
+    let occurrences = body["occurrences"].as_array().unwrap();
+    assert_eq!(occurrences.len(), 5);
+    let dates: Vec<chrono::DateTime<chrono::Utc>> = occurrences
+        .iter()
+        .map(|occurrence| occurrence["date"].as_str().unwrap().parse().unwrap())
+        .collect();
+    assert!(dates.windows(2).all(|pair| pair[0] <= pair[1]));
+
+    // Never fertilized, so fertilizing is due now; watering follows a week after the last one
+    assert_eq!(occurrences[0]["careType"], "fertilizing");
+    let first_watering = occurrences
+        .iter()
+        .zip(&dates)
+        .find(|(occurrence, _)| occurrence["careType"] == "watering")
+        .map(|(_, date)| *date)
+        .unwrap();
+    let gap = first_watering - last_watered;
+    assert!((gap - chrono::Duration::days(7)).num_seconds().abs() < 2, "{}", gap);
+
+    // Without a fertilizing interval only watering is listed
+    let response = app
+        .client
+        .put(app.url(&format!("/plants/{}", plant_id)))
+        .json(&json!({ "fertilizingSchedule": { "intervalDays": null } }))
+        .send()
+        .await
+        .expect("Failed to update plant");
+    assert_eq!(response.status(), 200);
+
+    let body: serde_json::Value = app
+        .client
+        .get(app.url(&format!("/plants/{}/upcoming", plant_id)))
+        .send()
+        .await
+        .expect("Failed to get upcoming care")
+        .json()
+        .await
+        .expect("Failed to parse upcoming care");
+    let occurrences = body["occurrences"].as_array().unwrap();
+    assert_eq!(occurrences.len(), 10);
+    assert!(occurrences
+        .iter()
+        .all(|occurrence| occurrence["careType"] == "watering"));
+
+    let response = app
+        .client
+        .get(app.url(&format!("/plants/{}/upcoming?count=0", plant_id)))
+        .send()
+        .await
+        .expect("Failed to get upcoming care");
+    assert_eq!(response.status(), 400);
+}
+
+#[tokio::test]
+async fn test_upcoming_care_follows_care_timing_preferences() {
+    use chrono::{Datelike, Timelike};
+
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "weekdays@example.com", "Weekday User", "password123").await;
+    let response = app
+        .client
+        .put(app.url("/settings/preferences"))
+        .json(&json!({ "weekStart": "monday", "careHour": 8, "avoidWeekends": true }))
+        .send()
+        .await
+        .expect("Failed to update preferences");
+    assert_eq!(response.status(), 200);
+
+    let preferences: serde_json::Value = app
+        .client
+        .get(app.url("/settings/preferences"))
+        .send()
+        .await
+        .expect("Failed to get preferences")
+        .json()
+        .await
+        .expect("Failed to parse preferences");
+    assert_eq!(preferences["careHour"], 8);
+    assert_eq!(preferences["avoidWeekends"], true);
+
+    let plant = common::create_test_plant(&app, "Weekday Fern", "Nephrolepis").await;
+    let plant_id = plant["id"].as_str().unwrap();
+    let response = app
+        .client
+        .put(app.url(&format!("/plants/{}", plant_id)))
+        .json(&json!({
+            "wateringSchedule": { "intervalDays": 1 },
+            "fertilizingSchedule": { "intervalDays": 3 }
+        }))
+        .send()
+        .await
+        .expect("Failed to update plant");
+    assert_eq!(response.status(), 200);
+
+    let body: serde_json::Value = app
+        .client
+        .get(app.url(&format!("/plants/{}/upcoming?count=20", plant_id)))
+        .send()
+        .await
+        .expect("Failed to get upcoming care")
+        .json()
+        .await
+        .expect("Failed to parse upcoming care");
+    let occurrences = body["occurrences"].as_array().unwrap();
+    assert!(!occurrences.is_empty());
+    for occurrence in occurrences {
+        let date: chrono::DateTime<chrono::Utc> =
+            occurrence["date"].as_str().unwrap().parse().unwrap();
+        assert!(date.weekday().number_from_monday() <= 5, "{}", date);
+        assert_eq!((date.hour(), date.minute()), (8, 0), "{}", date);
+    }
+
+    let response = app
+        .client
+        .put(app.url("/settings/preferences"))
+        .json(&json!({ "weekStart": "monday", "careHour": 24 }))
+        .send()
+        .await
+        .expect("Failed to update preferences");
+    assert_eq!(response.status(), 422);
+}
+
+#[tokio::test]
+async fn test_plant_list_pagination_links() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "pages@example.com", "Pages User", "password123").await;
+    for i in 0..5 {
+        common::create_test_plant(&app, &format!("Plant {}", i), "Paginata").await;
+    }
+
+    let page = |query: &'static str| {
+        let app = &app;
+        async move {
+            app.client
+                .get(app.url(&format!("/plants?{}", query)))
+                .send()
+                .await
+                .expect("Failed to list plants")
+                .json::<serde_json::Value>()
+                .await
+                .expect("Failed to parse plants")
+        }
+    };
+
+    // Links keep the request path and its other params
+    let middle = page("sort=name_asc&limit=2&offset=2").await;
+    assert_eq!(middle["plants"][0]["name"], "Plant 2");
+    assert_eq!(middle["next"], "/plants?sort=name_asc&limit=2&offset=4");
+    assert_eq!(middle["prev"], "/plants?sort=name_asc&limit=2&offset=0");
+
+    let last = page("sort=name_asc&limit=2&offset=4").await;
+    assert_eq!(last["plants"].as_array().unwrap().len(), 1);
+    assert!(last["next"].is_null());
+    assert_eq!(last["prev"], "/plants?sort=name_asc&limit=2&offset=2");
+
+    // Following a link lands on the expected page
+    let followed: serde_json::Value = app
+        .client
+        .get(app.url(middle["next"].as_str().unwrap()))
+        .send()
+        .await
+        .expect("Failed to follow next link")
+        .json()
+        .await
+        .expect("Failed to parse plants");
+    assert_eq!(followed["plants"][0]["name"], "Plant 4");
+
+    let first = page("limit=10").await;
+    assert!(first["next"].is_null());
+    assert!(first["prev"].is_null());
+}
+
+#[tokio::test]
+async fn test_cursor_pagination_survives_inserts() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "cursor@example.com", "Cursor User", "password123").await;
+    let mut created = Vec::new();
+    for i in 0..5 {
+        let plant = common::create_test_plant(&app, &format!("Plant {}", i), "Paginata").await;
+        created.push(plant["id"].as_str().unwrap().to_string());
+    }
+
+    let page = |path: String| {
+        let app = &app;
+        async move {
+            let response = app.client.get(app.url(&path)).send().await.unwrap();
+            assert_eq!(response.status(), 200);
+            response.json::<serde_json::Value>().await.unwrap()
+        }
+    };
+
+    let first = page("/plants?pagination=cursor&limit=2".to_string()).await;
+    assert!(first.get("total").is_none());
+    let mut seen: Vec<String> = first["plants"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|plant| plant["id"].as_str().unwrap().to_string())
+        .collect();
+
+    // A plant added mid-pagination would shift every offset page by one
+    common::create_test_plant(&app, "Latecomer", "Paginata").await;
+
+    let mut cursor = first["nextCursor"].as_str().map(str::to_string);
+    while let Some(token) = cursor {
+        let next = page(format!("/plants?limit=2&cursor={}", token)).await;
+        seen.extend(
+            next["plants"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|plant| plant["id"].as_str().unwrap().to_string()),
+        );
+        cursor = next["nextCursor"].as_str().map(str::to_string);
+    }
+
+    // Every original plant exactly once, and not the one added behind the cursor
+    seen.sort();
+    created.sort();
+    assert_eq!(seen, created);
+
+    let response = app
+        .client
+        .get(app.url("/plants?cursor=not-a-cursor"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 400);
+
+    let response = app
+        .client
+        .get(app.url("/plants?pagination=cursor&sort=name_asc"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 400);
+}
+
+#[tokio::test]
+async fn test_schedule_load_counts_care_across_plants() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "load@example.com", "Load User", "password123").await;
+
+    // Both plants were watered four days ago on a weekly schedule, so both are due in three days
+    let last_watered = chrono::Utc::now() - chrono::Duration::days(4);
+    for name in ["Fern", "Palm"] {
+        let response = app
+            .client
+            .post(app.url("/plants"))
+            .json(&json!({
+                "name": name,
+                "genus": "Nephrolepis",
+                "wateringSchedule": { "intervalDays": 7 },
+                "lastWatered": last_watered,
+                "customMetrics": []
+            }))
+            .send()
+            .await
+            .expect("Failed to create plant");
+        assert_eq!(response.status(), 201);
+    }
+
+    let response = app
+        .client
+        .get(app.url("/plants/schedule/load?days=14"))
+        .send()
+        .await
+        .expect("Failed to get schedule load");
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse schedule load");
+
+    let days = body["days"].as_array().unwrap();
+    assert_eq!(days.len(), 14);
+    let due_date = (last_watered + chrono::Duration::days(7)).date_naive().to_string();
+    let due_day = days.iter().find(|day| day["date"] == due_date).unwrap();
+    assert_eq!(due_day["count"], 2);
+
+    let response = app
+        .client
+        .get(app.url("/plants/schedule/load?days=0"))
+        .send()
+        .await
+        .expect("Failed to get schedule load");
+    assert_eq!(response.status(), 400);
+}
+
+#[tokio::test]
+async fn test_schedule_rebalance_applies_only_without_dry_run() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "rebalance@example.com", "Rebalance User", "password123").await;
+
+    let last_watered = chrono::Utc::now() - chrono::Duration::days(4);
+    for name in ["Fern", "Palm"] {
+        let response = app
+            .client
+            .post(app.url("/plants"))
+            .json(&json!({
+                "name": name,
+                "genus": "Nephrolepis",
+                "wateringSchedule": { "intervalDays": 7 },
+                "lastWatered": last_watered,
+                "customMetrics": []
+            }))
+            .send()
+            .await
+            .expect("Failed to create plant");
+        assert_eq!(response.status(), 201);
+    }
+
+    let max_load = |body: serde_json::Value| {
+        body["days"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|day| day["count"].as_u64().unwrap())
+            .max()
+            .unwrap()
+    };
+    let get_load = || async {
+        app.client
+            .get(app.url("/plants/schedule/load?days=14"))
+            .send()
+            .await
+            .expect("Failed to get schedule load")
+            .json::<serde_json::Value>()
+            .await
+            .expect("Failed to parse schedule load")
+    };
+
+    let preview: serde_json::Value = app
+        .client
+        .post(app.url("/plants/schedule/rebalance"))
+        .send()
+        .await
+        .expect("Failed to rebalance")
+        .json()
+        .await
+        .expect("Failed to parse rebalance");
+    assert_eq!(preview["dryRun"], true);
+    assert_eq!(preview["shifts"].as_array().unwrap().len(), 1);
+    assert_eq!(max_load(get_load().await), 2);
+
+    let response = app
+        .client
+        .post(app.url("/plants/schedule/rebalance?dry_run=false"))
+        .send()
+        .await
+        .expect("Failed to rebalance");
+    assert_eq!(response.status(), 200);
+    let applied: serde_json::Value = response.json().await.expect("Failed to parse rebalance");
+    assert_eq!(applied["dryRun"], false);
+    assert_eq!(applied["shifts"], preview["shifts"]);
+    assert_eq!(max_load(get_load().await), 1);
+}
+
+#[tokio::test]
+async fn test_change_metric_data_type_with_history() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "metrictype@example.com", "Metric User", "password123").await;
+
+    let response = app
+        .client
+        .post(app.url("/plants"))
+        .json(&json!({
+            "name": "Typed Plant",
+            "genus": "Metricus",
+            "customMetrics": [
+                { "name": "Height", "unit": "cm", "dataType": "Number" },
+                { "name": "Height note", "unit": "cm", "dataType": "Text" }
+            ]
+        }))
+        .send()
+        .await
+        .expect("Failed to send create plant request");
+    assert_eq!(response.status(), 201);
+    let plant: serde_json::Value = response.json().await.unwrap();
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let metric_ids: Vec<(String, String)> =
+        sqlx::query_as("SELECT name, id FROM custom_metrics WHERE plant_id = ? ORDER BY name")
+            .bind(plant_id)
+            .fetch_all(&app.db_pool)
+            .await
+            .unwrap();
+    let (height_id, note_id) = (&metric_ids[0].1, &metric_ids[1].1);
+
+    for (metric_id, value) in [
+        (height_id, json!(12.5)),
+        (note_id, json!("14")),
+        (note_id, json!(" 15.5 ")),
+    ] {
+        let response = app
+            .client
+            .post(app.url(&format!("/plants/{}/entries", plant_id)))
+            .json(&json!({
+                "entryType": "customMetric",
+                "timestamp": "2024-04-01T12:00:00Z",
+                "metricId": metric_id,
+                "value": value
+            }))
+            .send()
+            .await
+            .expect("Failed to create entry");
+        assert_eq!(response.status(), 201);
+    }
+
+    let change_type = |metric_id: &str, name: &str, data_type: &str, query: &str| {
+        app.client
+            .put(app.url(&format!("/plants/{}{}", plant_id, query)))
+            .json(&json!({
+                "name": "Renamed Plant",
+                "customMetrics": [
+                    { "id": metric_id, "name": name, "unit": "cm", "dataType": data_type }
+                ]
+            }))
+            .send()
+    };
+    let pool = &app.db_pool;
+    let metric_type = |metric_id: &str| {
+        sqlx::query_scalar::<_, String>("SELECT data_type FROM custom_metrics WHERE id = ?")
+            .bind(metric_id.to_string())
+            .fetch_one(pool)
+    };
+
+    // Numbers recorded for Height can't become booleans, converted or not
+    for query in ["", "?convert=true"] {
+        let response = change_type(height_id, "Height", "Boolean", query)
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 400);
+    }
+    assert_eq!(metric_type(height_id).await.unwrap(), "number");
+
+    // The rest of the update is refused along with the type change
+    let name: String = sqlx::query_scalar("SELECT name FROM plants WHERE id = ?")
+        .bind(plant_id)
+        .fetch_one(&app.db_pool)
+        .await
+        .unwrap();
+    assert_eq!(name, "Typed Plant");
+
+    // Numeric text converts, but only when asked to
+    let response = change_type(note_id, "Height note", "Number", "")
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 400);
+
+    let response = change_type(note_id, "Height note", "Number", "?convert=true")
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    assert_eq!(metric_type(note_id).await.unwrap(), "number");
+
+    let mut values: Vec<String> = sqlx::query_scalar(
+        "SELECT value FROM tracking_entries WHERE metric_id = ? ORDER BY value",
+    )
+    .bind(note_id)
+    .fetch_all(&app.db_pool)
+    .await
+    .unwrap();
+    values.sort();
+    assert_eq!(values, vec!["14".to_string(), "15.5".to_string()]);
+}
+
+#[tokio::test]
+async fn test_custom_metric_limit() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "metriclimit@example.com", "Metric User", "password123").await;
+
+    let metrics = |count: usize, prefix: &str| -> Vec<serde_json::Value> {
+        (0..count)
+            .map(|i| json!({ "name": format!("{prefix} {i}"), "unit": "cm", "dataType": "Number" }))
+            .collect()
+    };
+    let create = |count: usize| {
+        app.client
+            .post(app.url("/plants"))
+            .json(&json!({
+                "name": "Measured Plant",
+                "genus": "Metricus",
+                "customMetrics": metrics(count, "Metric")
+            }))
+            .send()
+    };
+
+    // The default limit is 20
+    let response = create(21).await.unwrap();
+    assert_eq!(response.status(), 422);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(
+        body["details"]["customMetrics"][0],
+        "A plant can have at most 20 custom metrics"
+    );
+
+    let response = create(19).await.unwrap();
+    assert_eq!(response.status(), 201);
+    let plant: serde_json::Value = response.json().await.unwrap();
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let add = |count: usize| {
+        app.client
+            .put(app.url(&format!("/plants/{}", plant_id)))
+            .json(&json!({ "customMetrics": metrics(count, "Extra") }))
+            .send()
+    };
+
+    // Adding up to the limit is fine, going past it isn't
+    assert_eq!(add(1).await.unwrap().status(), 200);
+    assert_eq!(add(1).await.unwrap().status(), 422);
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM custom_metrics WHERE plant_id = ?")
+        .bind(plant_id)
+        .fetch_one(&app.db_pool)
+        .await
+        .unwrap();
+    assert_eq!(count, 20);
+}
+
+#[tokio::test]
+async fn test_reminder_lead_hours_override() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "lead@example.com", "Lead User", "password123").await;
+
+    let response = app
+        .client
+        .post(app.url("/plants"))
+        .json(&json!({ "name": "Calathea", "genus": "Calathea", "reminderLeadHours": 24 }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 201);
+    let plant: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(plant["reminderLeadHours"], 24);
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let update = |body: serde_json::Value| {
+        app.client
+            .put(app.url(&format!("/plants/{}", plant_id)))
+            .json(&body)
+            .send()
+    };
+
+    // Longer than a week is rejected
+    let response = update(json!({ "reminderLeadHours": 169 })).await.unwrap();
+    assert_eq!(response.status(), 422);
+
+    // Omitting it keeps the override, null goes back to the server default
+    let response = update(json!({ "name": "Prayer Plant" })).await.unwrap();
+    let plant: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(plant["reminderLeadHours"], 24);
+
+    let response = update(json!({ "reminderLeadHours": null })).await.unwrap();
+    let plant: serde_json::Value = response.json().await.unwrap();
+    assert!(plant["reminderLeadHours"].is_null());
+}
+
+#[tokio::test]
+async fn test_plant_quantity() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "quantity@example.com", "Quantity User", "password123").await;
+
+    let plant = common::create_test_plant(&app, "Fern", "Nephrolepis").await;
+    assert_eq!(plant["quantity"], 1);
+
+    let response = app
+        .client
+        .post(app.url("/plants"))
+        .json(&json!({ "name": "Basil", "genus": "Ocimum", "quantity": 3 }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 201);
+    let plant: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(plant["quantity"], 3);
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let update = |body: serde_json::Value| {
+        app.client
+            .put(app.url(&format!("/plants/{}", plant_id)))
+            .json(&body)
+            .send()
+    };
+
+    let response = update(json!({ "quantity": 0 })).await.unwrap();
+    assert_eq!(response.status(), 422);
+
+    // Omitting it keeps the quantity
+    let response = update(json!({ "name": "Sweet Basil" })).await.unwrap();
+    let plant: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(plant["quantity"], 3);
+
+    let response = update(json!({ "quantity": 5 })).await.unwrap();
+    let plant: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(plant["quantity"], 5);
+}
+
+#[tokio::test]
+async fn test_plant_care_status() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "status@example.com", "Status User", "password123").await;
+
+    let now = chrono::Utc::now();
+    let last_watered = now - chrono::Duration::days(10);
+    let last_fertilized = now - chrono::Duration::days(13) - chrono::Duration::hours(12);
+    let plant: serde_json::Value = app
+        .client
+        .post(app.url("/plants"))
+        .json(&json!({
+            "name": "Thirsty Fern",
+            "genus": "Nephrolepis",
+            "wateringSchedule": { "intervalDays": 7 },
+            "fertilizingSchedule": { "intervalDays": 14 },
+            "lastWatered": last_watered,
+            "lastFertilized": last_fertilized,
+            "customMetrics": []
+        }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let status = |id: String| app.client.get(app.url(&format!("/plants/{}/status", id))).send();
+
+    let response = status(plant_id.to_string()).await.unwrap();
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["plantId"], plant_id);
+
+    // Watered ten days ago on a weekly schedule
+    let due: chrono::DateTime<chrono::Utc> =
+        body["watering"]["nextDue"].as_str().unwrap().parse().unwrap();
+    assert_eq!(due, last_watered + chrono::Duration::days(7));
+    assert_eq!(body["watering"]["overdue"], true);
+    assert_eq!(body["watering"]["severity"], "overdue");
+
+    // Fertilizing comes due in half a day
+    assert_eq!(body["fertilizing"]["overdue"], false);
+    assert_eq!(body["fertilizing"]["severity"], "due_soon");
+
+    // No schedule means no due date
+    let plant: serde_json::Value = app
+        .client
+        .post(app.url("/plants"))
+        .json(&json!({
+            "name": "Cactus",
+            "genus": "Cereus",
+            "wateringSchedule": { "intervalDays": 21 }
+        }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let plant_id = plant["id"].as_str().unwrap();
+    let body: serde_json::Value = status(plant_id.to_string()).await.unwrap().json().await.unwrap();
+    assert!(body["fertilizing"]["nextDue"].is_null());
+    assert_eq!(body["fertilizing"]["overdue"], false);
+    assert!(body["fertilizing"]["severity"].is_null());
+
+    // Never watered, so watering is due right away
+    assert!(body["watering"]["nextDue"].is_string());
+    assert_eq!(body["watering"]["overdue"], true);
+
+    let response = status(Uuid::new_v4().to_string()).await.unwrap();
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn test_archive_plant() {
+    use planty_api::database::plants as db_plants;
+
+    let app = TestApp::new().await;
+
+    let user =
+        common::create_test_user(&app, "archive@example.com", "Archive User", "password123").await;
+    let user_id = user["user"]["id"].as_str().unwrap();
+    let kept = common::create_test_plant(&app, "Fern", "Nephrolepis").await;
+    let retired = common::create_test_plant(&app, "Basil", "Ocimum").await;
+    let plant_id = retired["id"].as_str().unwrap();
+    assert!(retired["archivedAt"].is_null());
+
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/entries", plant_id)))
+        .json(&json!({ "entryType": "note", "timestamp": chrono::Utc::now(), "notes": "Wilting" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 201);
+
+    let post = |path: String| app.client.post(app.url(&path)).send();
+    let list = |path: &str| app.client.get(app.url(path)).send();
+    let listed_ids = |body: serde_json::Value| -> Vec<String> {
+        body["plants"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|plant| plant["id"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    let response = post(format!("/plants/{}/archive", plant_id)).await.unwrap();
+    assert_eq!(response.status(), 200);
+    let plant: serde_json::Value = response.json().await.unwrap();
+    let archived_at = plant["archivedAt"].as_str().unwrap().to_string();
+
+    // Archiving again keeps the original time
+    let plant: serde_json::Value = post(format!("/plants/{}/archive", plant_id))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(plant["archivedAt"], archived_at.as_str());
+
+    let body: serde_json::Value = list("/plants").await.unwrap().json().await.unwrap();
+    assert_eq!(listed_ids(body), vec![kept["id"].as_str().unwrap()]);
+
+    let body: serde_json::Value = list("/plants?archived=true").await.unwrap().json().await.unwrap();
+    assert_eq!(listed_ids(body), vec![plant_id]);
+
+    // The calendar feed and Google Tasks list plants the same way
+    let (plants, total) = db_plants::list_plants_for_user(&app.db_pool, user_id, 1000, 0, None)
+        .await
+        .unwrap();
+    assert_eq!(total, 1);
+    assert!(plants.iter().all(|plant| plant.id.to_string() != plant_id));
+
+    // History is kept
+    let body: serde_json::Value = list(&format!("/plants/{}/entries", plant_id))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(body["entries"][0]["notes"], "Wilting");
+
+    let response = post(format!("/plants/{}/unarchive", plant_id)).await.unwrap();
+    assert_eq!(response.status(), 200);
+    let plant: serde_json::Value = response.json().await.unwrap();
+    assert!(plant["archivedAt"].is_null());
+
+    let body: serde_json::Value = list("/plants").await.unwrap().json().await.unwrap();
+    assert_eq!(body["total"], 2);
+
+    let response = post(format!("/plants/{}/archive", Uuid::new_v4())).await.unwrap();
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn test_dead_plant_stops_care_scheduling() {
+    use planty_api::database::plants as db_plants;
+
+    let app = TestApp::new().await;
+
+    let user =
+        common::create_test_user(&app, "dead@example.com", "Dead User", "password123").await;
+    let user_id = user["user"]["id"].as_str().unwrap();
+    let plant = common::create_test_plant(&app, "Basil", "Ocimum").await;
+    let plant_id = plant["id"].as_str().unwrap();
+    assert_eq!(plant["status"], "active");
+
+    let get = |path: String| app.client.get(app.url(&path)).send();
+    let update = |body: serde_json::Value| {
+        app.client
+            .put(app.url(&format!("/plants/{}", plant_id)))
+            .json(&body)
+            .send()
+    };
+
+    // Never watered, so care is due right away
+    let status: serde_json::Value = get(format!("/plants/{}/status", plant_id))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(status["watering"]["overdue"], true);
+
+    // Death details only go with a dead plant
+    let response = update(json!({ "deathReason": "Root rot" })).await.unwrap();
+    assert_eq!(response.status(), 422);
+
+    let response = update(json!({ "status": "dead", "deathReason": "Root rot" }))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    let dead: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(dead["status"], "dead");
+    assert_eq!(dead["deathReason"], "Root rot");
+    assert!(dead["diedAt"].is_string());
+
+    let status: serde_json::Value = get(format!("/plants/{}/status", plant_id))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(status["watering"]["overdue"], false);
+    assert!(status["watering"]["nextDue"].is_null());
+    assert!(status["fertilizing"]["severity"].is_null());
+
+    let upcoming: serde_json::Value = get(format!("/plants/{}/upcoming", plant_id))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(upcoming["occurrences"], json!([]));
+
+    // Dead plants leave the listing, and with it the calendar feed and Google Tasks
+    let body: serde_json::Value = get("/plants".to_string()).await.unwrap().json().await.unwrap();
+    assert_eq!(body["total"], 0);
+    let (plants, _) = db_plants::list_plants_for_user(&app.db_pool, user_id, 1000, 0, None)
+        .await
+        .unwrap();
+    assert!(plants.is_empty());
+
+    let body: serde_json::Value = get("/plants?status=dead".to_string())
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(body["plants"][0]["id"], plant_id);
+
+    // Reviving a plant drops its death details
+    let response = update(json!({ "status": "dormant" })).await.unwrap();
+    assert_eq!(response.status(), 200);
+    let revived: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(revived["status"], "dormant");
+    assert!(revived["diedAt"].is_null());
+    assert!(revived["deathReason"].is_null());
+
+    let body: serde_json::Value = get("/plants".to_string()).await.unwrap().json().await.unwrap();
+    assert_eq!(body["total"], 1);
+}
+
+#[tokio::test]
+async fn test_memorial_lists_dead_plants() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "memorial@example.com", "Memorial User", "password123").await;
+    common::create_test_plant(&app, "Fern", "Nephrolepis").await;
+    let basil = common::create_test_plant(&app, "Basil", "Ocimum").await;
+    let cactus = common::create_test_plant(&app, "Cactus", "Cereus").await;
+
+    let mark_dead = |plant: &serde_json::Value, died_at: &str, reason: &str| {
+        app.client
+            .put(app.url(&format!("/plants/{}", plant["id"].as_str().unwrap())))
+            .json(&json!({ "status": "dead", "diedAt": died_at, "deathReason": reason }))
+            .send()
+    };
+    let response = mark_dead(&basil, "2024-05-01T12:00:00Z", "Aphids").await.unwrap();
+    assert_eq!(response.status(), 200);
+    let response = mark_dead(&cactus, "2024-07-01T12:00:00Z", "Overwatered").await.unwrap();
+    assert_eq!(response.status(), 200);
+
+    // Archived dead plants are still remembered
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/archive", basil["id"].as_str().unwrap())))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+
+    let response = app.client.get(app.url("/plants/memorial")).send().await.unwrap();
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    let plants = body["plants"].as_array().unwrap();
+    assert_eq!(plants.len(), 2);
+    assert_eq!(plants[0]["name"], "Cactus");
+    assert_eq!(plants[0]["deathReason"], "Overwatered");
+    assert_eq!(plants[1]["name"], "Basil");
+    assert_eq!(plants[1]["diedAt"], "2024-05-01T12:00:00Z");
+
+    let response = app
+        .client
+        .put(app.url(&format!("/plants/{}", cactus["id"].as_str().unwrap())))
+        .json(&json!({ "status": "dead", "diedAt": "2999-01-01T00:00:00Z" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 422);
+}
+
+#[tokio::test]
+async fn test_trailing_slash_reaches_same_handler() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "slash@example.com", "Slash User", "password123").await;
+    common::create_test_plant(&app, "Fern", "Nephrolepis").await;
+
+    let list = |path: &str| app.client.get(app.url(path)).send();
+
+    let response = list("/plants").await.unwrap();
+    assert_eq!(response.status(), 200);
+    let without_slash: serde_json::Value = response.json().await.unwrap();
+
+    let response = list("/plants/").await.unwrap();
+    assert_eq!(response.status(), 200);
+    let with_slash: serde_json::Value = response.json().await.unwrap();
+
+    assert_eq!(with_slash, without_slash);
+    assert_eq!(with_slash["total"], 1);
+
+    // Query strings survive the trim
+    let response = list("/plants/?limit=1").await.unwrap();
+    assert_eq!(response.status(), 200);
+    let limited: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(limited["limit"], 1);
+}
+
+#[tokio::test]
+async fn test_plant_tags() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "tags@example.com", "Tag User", "password123").await;
+    let plant = common::create_test_plant(&app, "Pothos", "Epipremnum").await;
+    let plant_id = plant["id"].as_str().unwrap();
+    assert_eq!(plant["tags"], json!([]));
+
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/tags", plant_id)))
+        .json(&json!({ "tags": ["Low-Light", " toxic-to-cats ", "low-light"] }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["tags"], json!(["low-light", "toxic-to-cats"]));
+
+    let plant: serde_json::Value = app
+        .client
+        .get(app.url(&format!("/plants/{}", plant_id)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(plant["tags"], json!(["low-light", "toxic-to-cats"]));
+
+    let response = app
+        .client
+        .delete(app.url(&format!("/plants/{}/tags/TOXIC-TO-CATS", plant_id)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 204);
+
+    let response = app
+        .client
+        .delete(app.url(&format!("/plants/{}/tags/toxic-to-cats", plant_id)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 404);
+
+    for tags in [json!([]), json!(["has space"]), json!([""])] {
+        let response = app
+            .client
+            .post(app.url(&format!("/plants/{}/tags", plant_id)))
+            .json(&json!({ "tags": tags }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 422, "{}", tags);
+    }
+
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/tags", Uuid::new_v4())))
+        .json(&json!({ "tags": ["low-light"] }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn test_list_plants_filtered_by_tags() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "tagfilter@example.com", "Tag User", "password123").await;
+
+    for (name, tags) in [
+        ("Pothos", json!(["low-light", "toxic-to-cats"])),
+        ("Snake Plant", json!(["low-light"])),
+        ("Calathea", json!(["pet-safe"])),
+    ] {
+        let plant = common::create_test_plant(&app, name, "Genus").await;
+        let response = app
+            .client
+            .post(app.url(&format!("/plants/{}/tags", plant["id"].as_str().unwrap())))
+            .json(&json!({ "tags": tags }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+    }
+
+    let names = |query: &'static str| {
+        let request = app.client.get(app.url(&format!("/plants?sort=name_asc{}", query)));
+        async move {
+            let body: serde_json::Value = request.send().await.unwrap().json().await.unwrap();
+            let names: Vec<String> = body["plants"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|plant| plant["name"].as_str().unwrap().to_string())
+                .collect();
+            assert_eq!(body["total"], names.len());
+            names
+        }
+    };
+
+    assert_eq!(names("&tag=low-light").await, ["Pothos", "Snake Plant"]);
+    assert_eq!(names("&tag=Low-Light").await, ["Pothos", "Snake Plant"]);
+    assert_eq!(names("&tag=low-light&tag=toxic-to-cats").await, ["Pothos"]);
+    assert!(names("&tag=low-light&tag=pet-safe").await.is_empty());
+    assert_eq!(names("").await.len(), 3);
+}
+
+#[tokio::test]
+async fn test_public_plant_link() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "public@example.com", "Public User", "password123").await;
+    let plant = common::create_test_plant(&app, "Shared Monstera", "Monstera").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let part = reqwest::multipart::Part::bytes(common::create_test_image_data(10, 10))
+        .file_name("leaf.jpg")
+        .mime_str("image/jpeg")
+        .unwrap();
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/photos", plant_id)))
+        .multipart(reqwest::multipart::Form::new().part("file", part))
+        .send()
+        .await
+        .expect("Failed to upload photo");
+    assert_eq!(response.status(), 201);
+
+    for (notes, visibility) in [("New leaf!", "shared"), ("Gift for mom", "owner_only")] {
+        let response = app
+            .client
+            .post(app.url(&format!("/plants/{}/entries", plant_id)))
+            .json(&json!({
+                "entryType": "note",
+                "timestamp": "2024-05-01T09:00:00Z",
+                "notes": notes,
+                "visibility": visibility
+            }))
+            .send()
+            .await
+            .expect("Failed to create note");
+        assert_eq!(response.status(), 201);
+    }
+
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/public-link", plant_id)))
+        .send()
+        .await
+        .expect("Failed to create public link");
+    assert_eq!(response.status(), 201);
+    let link: serde_json::Value = response.json().await.unwrap();
+    let token = link["token"].as_str().unwrap();
+    assert_eq!(link["url"], format!("/api/v1/public/plants/{}", token));
+
+    // Viewable without an account
+    let anonymous = reqwest::Client::new();
+    let public_url = app.url(&format!("/public/plants/{}", token));
+    let response = anonymous.get(&public_url).send().await.unwrap();
+    assert_eq!(response.status(), 200);
+    let public: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(public["name"], "Shared Monstera");
+    assert_eq!(public["genus"], "Monstera");
+    assert_eq!(public["wateringSchedule"]["intervalDays"], 7);
+    assert_eq!(public["notes"], json!([{ "timestamp": "2024-05-01T09:00:00Z", "notes": "New leaf!" }]));
+    assert!(public.get("userId").is_none());
+
+    let photos = public["photos"].as_array().unwrap();
+    assert_eq!(photos.len(), 1);
+    let photo_url = photos[0]["url"].as_str().unwrap();
+    let photo_url = app.url(photo_url.trim_start_matches("/api/v1"));
+    let response = anonymous.get(&photo_url).send().await.unwrap();
+    assert_eq!(response.status(), 200);
+    assert!(response.headers()["content-type"].to_str().unwrap().starts_with("image/"));
+
+    // Revoking stops both the page and its photos
+    let response = app
+        .client
+        .delete(app.url(&format!("/plants/{}/public-link", plant_id)))
+        .send()
+        .await
+        .expect("Failed to revoke public link");
+    assert_eq!(response.status(), 204);
+
+    assert_eq!(anonymous.get(&public_url).send().await.unwrap().status(), 404);
+    assert_eq!(anonymous.get(&photo_url).send().await.unwrap().status(), 404);
+
+    let response = app
+        .client
+        .delete(app.url(&format!("/plants/{}/public-link", plant_id)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 404);
+}
@@ -0,0 +1,78 @@
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+
+use planty_api::middleware::query_count::{
+    QueryCountLayer, QUERY_COUNT_DIRECTIVES, QUERY_COUNT_HEADER,
+};
+
+mod common;
+use common::TestApp;
+
+fn install_query_counting() {
+    let mut filter = EnvFilter::new("off");
+    for directive in QUERY_COUNT_DIRECTIVES {
+        filter = filter.add_directive(directive.parse().unwrap());
+    }
+    // Only the first test in the binary gets to install it
+    let _ = tracing_subscriber::registry()
+        .with(filter)
+        .with(QueryCountLayer)
+        .try_init();
+}
+
+/// Fewest queries seen listing plants, as a request that has to open a new
+/// pool connection also counts that connection's setup
+async fn list_plants_query_count(app: &TestApp) -> usize {
+    let mut counts = Vec::new();
+    for _ in 0..2 {
+        let response = app
+            .client
+            .get(app.url("/plants"))
+            .send()
+            .await
+            .expect("Failed to list plants");
+        assert_eq!(response.status(), 200);
+        counts.push(
+            response.headers()[QUERY_COUNT_HEADER]
+                .to_str()
+                .unwrap()
+                .parse()
+                .unwrap(),
+        );
+    }
+    counts.into_iter().min().unwrap()
+}
+
+#[tokio::test]
+async fn test_plant_list_query_count_does_not_grow_with_plants() {
+    install_query_counting();
+    let app = TestApp::with_query_counts().await;
+
+    common::create_test_user(&app, "queries@example.com", "Query Counter", "password123").await;
+    for i in 0..3 {
+        common::create_test_plant(&app, &format!("Plant {}", i), "Ficus").await;
+    }
+    let few = list_plants_query_count(&app).await;
+
+    for i in 3..10 {
+        common::create_test_plant(&app, &format!("Plant {}", i), "Ficus").await;
+    }
+    let many = list_plants_query_count(&app).await;
+
+    assert!(few > 0);
+    assert_eq!(many, few, "listing plants runs a query per plant");
+    assert!(many <= 10, "listing plants ran {} queries", many);
+}
+
+#[tokio::test]
+async fn test_query_count_header_only_when_enabled() {
+    install_query_counting();
+    let app = TestApp::new().await;
+
+    let response = app
+        .client
+        .get(app.url("/features"))
+        .send()
+        .await
+        .expect("Failed to get features");
+    assert!(response.headers().get(QUERY_COUNT_HEADER).is_none());
+}
@@ -0,0 +1,392 @@
+use chrono::{Duration, Utc};
+use planty_api::database::deletions;
+use reqwest::multipart::{Form, Part};
+use serde_json::json;
+
+mod common;
+use common::TestApp;
+
+async fn get_changes(app: &TestApp, since: Option<&str>) -> serde_json::Value {
+    let mut request = app.client.get(app.url("/sync/changes"));
+    if let Some(since) = since {
+        request = request.query(&[("since", since)]);
+    }
+
+    let response = request.send().await.expect("Failed to send sync request");
+    assert_eq!(response.status(), 200);
+    response.json().await.expect("Failed to parse response")
+}
+
+async fn create_note(app: &TestApp, plant_id: &str) -> serde_json::Value {
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/entries", plant_id)))
+        .json(&json!({
+            "entryType": "note",
+            "timestamp": "2024-01-01T12:00:00Z",
+            "notes": "New leaf"
+        }))
+        .send()
+        .await
+        .expect("Failed to send create tracking entry request");
+
+    assert_eq!(response.status(), 201);
+    response.json().await.expect("Failed to parse response")
+}
+
+fn ids(items: &serde_json::Value) -> Vec<&str> {
+    items
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["id"].as_str().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_sync_changes_requires_authentication() {
+    let app = TestApp::new().await;
+
+    let response = app
+        .client
+        .get(app.url("/sync/changes"))
+        .send()
+        .await
+        .expect("Failed to send sync request");
+
+    assert_eq!(response.status(), 401);
+}
+
+#[tokio::test]
+async fn test_sync_changes_full_sync() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "sync@example.com", "Sync User", "password123").await;
+    let plant = common::create_test_plant(&app, "Sync Plant", "Syncus").await;
+    let entry = create_note(&app, plant["id"].as_str().unwrap()).await;
+
+    let body = get_changes(&app, None).await;
+
+    assert_eq!(ids(&body["plants"]), vec![plant["id"].as_str().unwrap()]);
+    assert_eq!(
+        ids(&body["trackingEntries"]),
+        vec![entry["id"].as_str().unwrap()]
+    );
+    assert_eq!(body["photos"].as_array().unwrap().len(), 0);
+    assert_eq!(body["deleted"].as_array().unwrap().len(), 0);
+    assert!(body["serverTime"].is_string());
+    assert_eq!(body["fullResyncRequired"], false);
+}
+
+#[tokio::test]
+async fn test_sync_cursor_older_than_tombstones_requires_full_resync() {
+    let app = TestApp::with_config(|config| config.tombstone_retention_days = 30).await;
+
+    common::create_test_user(&app, "sync@example.com", "Sync User", "password123").await;
+    let plant = common::create_test_plant(&app, "Sync Plant", "Syncus").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    // Within the retention window, only newer changes come back
+    let recent = (Utc::now() - Duration::days(29)).to_rfc3339();
+    let body = get_changes(&app, Some(&recent)).await;
+    assert_eq!(body["fullResyncRequired"], false);
+    assert_eq!(ids(&body["plants"]), vec![plant_id]);
+
+    let cursor = body["serverTime"].as_str().unwrap().to_string();
+    let body = get_changes(&app, Some(&cursor)).await;
+    assert_eq!(body["fullResyncRequired"], false);
+    assert!(body["plants"].as_array().unwrap().is_empty());
+
+    // Deletions from before the cutoff may already be pruned, so the client
+    // gets everything and is told to replace its local copy
+    let stale = (Utc::now() - Duration::days(31)).to_rfc3339();
+    let body = get_changes(&app, Some(&stale)).await;
+    assert_eq!(body["fullResyncRequired"], true);
+    assert_eq!(ids(&body["plants"]), vec![plant_id]);
+}
+
+#[tokio::test]
+async fn test_sync_changes_reflects_creates_and_deletes_after_cursor() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "delta@example.com", "Delta User", "password123").await;
+    let old_plant = common::create_test_plant(&app, "Old Plant", "Oldus").await;
+    let old_plant_id = old_plant["id"].as_str().unwrap();
+    let old_entry = create_note(&app, old_plant_id).await;
+    let old_entry_id = old_entry["id"].as_str().unwrap();
+
+    let cursor = get_changes(&app, None).await["serverTime"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+
+    // Nothing changed yet
+    let body = get_changes(&app, Some(&cursor)).await;
+    assert_eq!(body["plants"].as_array().unwrap().len(), 0);
+    assert_eq!(body["trackingEntries"].as_array().unwrap().len(), 0);
+    assert_eq!(body["deleted"].as_array().unwrap().len(), 0);
+
+    // Create a plant with a photo and delete the old entry
+    let new_plant = common::create_test_plant(&app, "New Plant", "Newus").await;
+    let new_plant_id = new_plant["id"].as_str().unwrap();
+
+    let part = Part::bytes(common::create_test_image_data(10, 10))
+        .file_name("sync.jpg")
+        .mime_str("image/jpeg")
+        .expect("Failed to create part");
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/photos", new_plant_id)))
+        .multipart(Form::new().part("file", part))
+        .send()
+        .await
+        .expect("Failed to send upload photo request");
+    assert_eq!(response.status(), 201);
+    let photo: serde_json::Value = response.json().await.expect("Failed to parse response");
+
+    let response = app
+        .client
+        .delete(app.url(&format!(
+            "/plants/{}/entries/{}",
+            old_plant_id, old_entry_id
+        )))
+        .send()
+        .await
+        .expect("Failed to send delete entry request");
+    assert_eq!(response.status(), 204);
+
+    let body = get_changes(&app, Some(&cursor)).await;
+
+    assert!(ids(&body["plants"]).contains(&new_plant_id));
+    assert_eq!(ids(&body["photos"]), vec![photo["id"].as_str().unwrap()]);
+    assert_eq!(body["trackingEntries"].as_array().unwrap().len(), 0);
+
+    let deleted = body["deleted"].as_array().unwrap();
+    assert_eq!(deleted.len(), 1);
+    assert_eq!(deleted[0]["entityType"], "trackingEntry");
+    assert_eq!(deleted[0]["entityId"], old_entry_id);
+
+    // Deleting a plant after a newer cursor is reported on its own
+    let cursor = body["serverTime"].as_str().unwrap().to_string();
+    tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+
+    let response = app
+        .client
+        .delete(app.url(&format!("/plants/{}", old_plant_id)))
+        .send()
+        .await
+        .expect("Failed to send delete plant request");
+    assert_eq!(response.status(), 204);
+
+    let body = get_changes(&app, Some(&cursor)).await;
+    assert_eq!(body["plants"].as_array().unwrap().len(), 0);
+
+    let deleted = body["deleted"].as_array().unwrap();
+    assert_eq!(deleted.len(), 1);
+    assert_eq!(deleted[0]["entityType"], "plant");
+    assert_eq!(deleted[0]["entityId"], old_plant_id);
+}
+
+#[tokio::test]
+async fn test_sync_changes_isolated_between_users() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "owner@example.com", "Owner", "password123").await;
+    common::create_test_plant(&app, "Owner Plant", "Ownerus").await;
+
+    app.client
+        .post(app.url("/auth/logout"))
+        .send()
+        .await
+        .unwrap();
+
+    common::create_test_user(&app, "other@example.com", "Other", "password123").await;
+
+    let body = get_changes(&app, None).await;
+    assert_eq!(body["plants"].as_array().unwrap().len(), 0);
+}
+
+async fn tombstones(app: &TestApp) -> Vec<(String, String)> {
+    sqlx::query_as("SELECT entity_type, entity_id FROM deletions ORDER BY id")
+        .fetch_all(&app.db_pool)
+        .await
+        .expect("Failed to query deletions")
+}
+
+#[tokio::test]
+async fn test_deleting_each_entity_type_records_tombstone() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "tombstone@example.com", "Tombstone", "password123").await;
+    let plant = common::create_test_plant(&app, "Doomed Plant", "Doomus").await;
+    let plant_id = plant["id"].as_str().unwrap();
+    let entry = create_note(&app, plant_id).await;
+    let entry_id = entry["id"].as_str().unwrap();
+
+    let part = Part::bytes(common::create_test_image_data(10, 10))
+        .file_name("doomed.jpg")
+        .mime_str("image/jpeg")
+        .expect("Failed to create part");
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/photos", plant_id)))
+        .multipart(Form::new().part("file", part))
+        .send()
+        .await
+        .expect("Failed to send upload photo request");
+    assert_eq!(response.status(), 201);
+    let photo: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let photo_id = photo["id"].as_str().unwrap();
+
+    for path in [
+        format!("/plants/{}/entries/{}", plant_id, entry_id),
+        format!("/plants/{}/photos/{}", plant_id, photo_id),
+        format!("/plants/{}", plant_id),
+    ] {
+        let response = app
+            .client
+            .delete(app.url(&path))
+            .send()
+            .await
+            .expect("Failed to send delete request");
+        assert_eq!(response.status(), 204);
+    }
+
+    assert_eq!(
+        tombstones(&app).await,
+        vec![
+            ("tracking_entry".to_string(), entry_id.to_string()),
+            ("photo".to_string(), photo_id.to_string()),
+            ("plant".to_string(), plant_id.to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_failed_delete_records_no_tombstone() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "nodelete@example.com", "No Delete", "password123").await;
+
+    let response = app
+        .client
+        .delete(app.url(&format!("/plants/{}", uuid::Uuid::new_v4())))
+        .send()
+        .await
+        .expect("Failed to send delete plant request");
+    assert_eq!(response.status(), 404);
+
+    assert!(tombstones(&app).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_delete_rolls_back_when_tombstone_cannot_be_recorded() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "notombstone@example.com", "No Tombstone", "password123").await;
+    let plant = common::create_test_plant(&app, "Kept Plant", "Keepus").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/entries", plant_id)))
+        .json(&json!({
+            "entryType": "note",
+            "timestamp": Utc::now().to_rfc3339(),
+            "notes": "Kept"
+        }))
+        .send()
+        .await
+        .expect("Failed to create entry");
+    assert_eq!(response.status(), 201);
+    let entry: serde_json::Value = response.json().await.unwrap();
+    let entry_id = entry["id"].as_str().unwrap();
+
+    let part = Part::bytes(common::create_test_image_data(10, 10))
+        .file_name("kept.jpg")
+        .mime_str("image/jpeg")
+        .unwrap();
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/photos", plant_id)))
+        .multipart(Form::new().part("file", part))
+        .send()
+        .await
+        .expect("Failed to upload photo");
+    assert_eq!(response.status(), 201);
+    let photo: serde_json::Value = response.json().await.unwrap();
+    let photo_id = photo["id"].as_str().unwrap();
+
+    sqlx::query(
+        "CREATE TRIGGER fail_tombstones BEFORE INSERT ON deletions
+         BEGIN SELECT RAISE(ABORT, 'tombstones unavailable'); END",
+    )
+    .execute(&app.db_pool)
+    .await
+    .unwrap();
+
+    for path in [
+        format!("/plants/{}/entries/{}", plant_id, entry_id),
+        format!("/plants/{}/photos/{}", plant_id, photo_id),
+        format!("/plants/{}", plant_id),
+    ] {
+        let response = app
+            .client
+            .delete(app.url(&path))
+            .send()
+            .await
+            .expect("Failed to send delete request");
+        assert_eq!(response.status(), 500, "{}", path);
+    }
+
+    // Nothing was deleted without its tombstone
+    let count = |query: &'static str, id: &str| {
+        let query = sqlx::query_scalar::<_, i64>(query).bind(id.to_string());
+        async { query.fetch_one(&app.db_pool).await.unwrap() }
+    };
+    let live_entries = "SELECT COUNT(*) FROM tracking_entries WHERE id = ? AND deleted_at IS NULL";
+    assert_eq!(count(live_entries, entry_id).await, 1);
+    assert_eq!(count("SELECT COUNT(*) FROM photos WHERE id = ?", photo_id).await, 1);
+    assert_eq!(count("SELECT COUNT(*) FROM plants WHERE id = ?", plant_id).await, 1);
+}
+
+#[tokio::test]
+async fn test_prune_deletions_removes_only_expired_tombstones() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "prune@example.com", "Prune", "password123").await;
+    let old_plant = common::create_test_plant(&app, "Old Plant", "Oldus").await;
+    let new_plant = common::create_test_plant(&app, "New Plant", "Newus").await;
+
+    for plant in [&old_plant, &new_plant] {
+        let response = app
+            .client
+            .delete(app.url(&format!("/plants/{}", plant["id"].as_str().unwrap())))
+            .send()
+            .await
+            .expect("Failed to send delete plant request");
+        assert_eq!(response.status(), 204);
+    }
+
+    // Age the first tombstone past the retention window
+    sqlx::query("UPDATE deletions SET deleted_at = ? WHERE entity_id = ?")
+        .bind((Utc::now() - Duration::days(120)).to_rfc3339())
+        .bind(old_plant["id"].as_str().unwrap())
+        .execute(&app.db_pool)
+        .await
+        .unwrap();
+
+    let pruned = deletions::prune_deletions_before(&app.db_pool, Utc::now() - Duration::days(90))
+        .await
+        .expect("Failed to prune deletions");
+    assert_eq!(pruned, 1);
+
+    assert_eq!(
+        tombstones(&app).await,
+        vec![(
+            "plant".to_string(),
+            new_plant["id"].as_str().unwrap().to_string()
+        )]
+    );
+}
@@ -539,3 +539,948 @@ async fn test_list_tracking_entries_with_various_types() {
     assert_eq!(timestamps[2], "2024-01-02T13:00:00Z"); // fertilizing
     assert_eq!(timestamps[3], "2024-01-01T12:00:00Z"); // watering
 }
+
+/// Create a watering entry for `plant_id` at `timestamp`, returning its id
+async fn create_watering_entry(app: &TestApp, plant_id: &str, timestamp: &str) -> String {
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/entries", plant_id)))
+        .json(&serde_json::json!({
+            "entryType": "watering",
+            "timestamp": timestamp
+        }))
+        .send()
+        .await
+        .expect("Failed to create tracking entry");
+    assert_eq!(response.status(), 201);
+
+    let entry: serde_json::Value = response.json().await.expect("Failed to parse entry");
+    entry["id"].as_str().unwrap().to_string()
+}
+
+async fn get_last_watered(app: &TestApp, plant_id: &str) -> serde_json::Value {
+    let plant: serde_json::Value = app
+        .client
+        .get(app.url(&format!("/plants/{}", plant_id)))
+        .send()
+        .await
+        .expect("Failed to get plant")
+        .json()
+        .await
+        .expect("Failed to parse plant");
+    plant["lastWatered"].clone()
+}
+
+/// Ids of a plant's entries as listed with `query`
+async fn list_entry_ids(app: &TestApp, plant_id: &str, query: &str) -> Vec<String> {
+    let body: serde_json::Value = app
+        .client
+        .get(app.url(&format!("/plants/{}/entries?{}", plant_id, query)))
+        .send()
+        .await
+        .expect("Failed to list entries")
+        .json()
+        .await
+        .expect("Failed to parse entries");
+    body["entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_entries_with_same_timestamp_have_stable_order() {
+    let app = TestApp::new().await;
+    common::create_test_user(&app, "stable-order@example.com", "Stable Order", "password123").await;
+    let plant = common::create_test_plant(&app, "Bulk Plant", "Bulkus").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let mut created = Vec::new();
+    for _ in 0..5 {
+        created.push(create_watering_entry(&app, plant_id, "2024-03-01T09:00:00Z").await);
+    }
+
+    for (sort, descending) in [("date_desc", true), ("date_asc", false)] {
+        let all = list_entry_ids(&app, plant_id, &format!("sort={sort}")).await;
+        let mut expected = created.clone();
+        expected.sort();
+        if descending {
+            expected.reverse();
+        }
+        assert_eq!(all, expected, "ties should be ordered by id ({sort})");
+
+        for _ in 0..3 {
+            assert_eq!(
+                list_entry_ids(&app, plant_id, &format!("sort={sort}")).await,
+                all
+            );
+        }
+
+        // Pages stitch back together without duplicates or gaps
+        let mut paged = Vec::new();
+        for offset in [0, 2, 4] {
+            paged.extend(
+                list_entry_ids(
+                    &app,
+                    plant_id,
+                    &format!("sort={sort}&limit=2&offset={offset}"),
+                )
+                .await,
+            );
+        }
+        assert_eq!(paged, all);
+    }
+}
+
+#[tokio::test]
+async fn test_deleted_entry_is_hidden_and_can_be_restored() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "restore@example.com", "Restore User", "password123").await;
+    let plant = common::create_test_plant(&app, "Restore Plant", "Restoricus").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    create_watering_entry(&app, plant_id, "2024-01-01T12:00:00Z").await;
+    let latest_id = create_watering_entry(&app, plant_id, "2024-01-08T12:00:00Z").await;
+    assert_eq!(get_last_watered(&app, plant_id).await, "2024-01-08T12:00:00Z");
+
+    let response = app
+        .client
+        .delete(app.url(&format!("/plants/{}/entries/{}", plant_id, latest_id)))
+        .send()
+        .await
+        .expect("Failed to delete tracking entry");
+    assert_eq!(response.status(), 204);
+
+    // The entry is gone from listings and last_watered falls back to the earlier entry
+    let body: serde_json::Value = app
+        .client
+        .get(app.url(&format!("/plants/{}/entries", plant_id)))
+        .send()
+        .await
+        .expect("Failed to list tracking entries")
+        .json()
+        .await
+        .expect("Failed to parse entries");
+    assert_eq!(body["total"], 1);
+    assert!(body["entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|e| e["id"] != latest_id.as_str()));
+    assert_eq!(get_last_watered(&app, plant_id).await, "2024-01-01T12:00:00Z");
+
+    let response = app
+        .client
+        .post(app.url(&format!(
+            "/plants/{}/entries/{}/restore",
+            plant_id, latest_id
+        )))
+        .send()
+        .await
+        .expect("Failed to restore tracking entry");
+    assert_eq!(response.status(), 200);
+    let restored: serde_json::Value = response.json().await.expect("Failed to parse entry");
+    assert_eq!(restored["id"], latest_id.as_str());
+
+    let response = app
+        .client
+        .get(app.url(&format!("/plants/{}/entries/{}", plant_id, latest_id)))
+        .send()
+        .await
+        .expect("Failed to get tracking entry");
+    assert_eq!(response.status(), 200);
+    assert_eq!(get_last_watered(&app, plant_id).await, "2024-01-08T12:00:00Z");
+
+    // Restoring an entry that isn't deleted is a 404
+    let response = app
+        .client
+        .post(app.url(&format!(
+            "/plants/{}/entries/{}/restore",
+            plant_id, latest_id
+        )))
+        .send()
+        .await
+        .expect("Failed to restore tracking entry");
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn test_restore_is_undone_when_the_tombstone_stays() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "stuck@example.com", "Stuck User", "password123").await;
+    let plant = common::create_test_plant(&app, "Stuck Plant", "Stuckus").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let entry_id = create_watering_entry(&app, plant_id, "2024-01-08T12:00:00Z").await;
+    let response = app
+        .client
+        .delete(app.url(&format!("/plants/{}/entries/{}", plant_id, entry_id)))
+        .send()
+        .await
+        .expect("Failed to delete tracking entry");
+    assert_eq!(response.status(), 204);
+    let last_watered = get_last_watered(&app, plant_id).await;
+
+    sqlx::query(
+        "CREATE TRIGGER keep_tombstones BEFORE DELETE ON deletions
+         BEGIN SELECT RAISE(ABORT, 'tombstones locked'); END",
+    )
+    .execute(&app.db_pool)
+    .await
+    .unwrap();
+
+    let restore_url = app.url(&format!("/plants/{}/entries/{}/restore", plant_id, entry_id));
+    let response = app.client.post(&restore_url).send().await.unwrap();
+    assert_eq!(response.status(), 500);
+
+    // The entry and the plant's care date stay as they were
+    let deleted_at: Option<String> =
+        sqlx::query_scalar("SELECT deleted_at FROM tracking_entries WHERE id = ?")
+            .bind(&entry_id)
+            .fetch_one(&app.db_pool)
+            .await
+            .unwrap();
+    assert!(deleted_at.is_some());
+    assert_eq!(get_last_watered(&app, plant_id).await, last_watered);
+
+    sqlx::query("DROP TRIGGER keep_tombstones")
+        .execute(&app.db_pool)
+        .await
+        .unwrap();
+    let response = app.client.post(&restore_url).send().await.unwrap();
+    assert_eq!(response.status(), 200);
+    let tombstones: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM deletions WHERE entity_id = ?")
+        .bind(&entry_id)
+        .fetch_one(&app.db_pool)
+        .await
+        .unwrap();
+    assert_eq!(tombstones, 0);
+}
+
+#[tokio::test]
+async fn test_expired_deleted_entries_are_purged() {
+    use planty_api::database::tracking::{purge_deleted_entries_before, ENTRY_RESTORE_WINDOW_DAYS};
+
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "purge@example.com", "Purge User", "password123").await;
+    let plant = common::create_test_plant(&app, "Purge Plant", "Purgicus").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let expired_id = create_watering_entry(&app, plant_id, "2024-01-01T12:00:00Z").await;
+    let recent_id = create_watering_entry(&app, plant_id, "2024-01-08T12:00:00Z").await;
+    for entry_id in [&expired_id, &recent_id] {
+        let response = app
+            .client
+            .delete(app.url(&format!("/plants/{}/entries/{}", plant_id, entry_id)))
+            .send()
+            .await
+            .expect("Failed to delete tracking entry");
+        assert_eq!(response.status(), 204);
+    }
+
+    // Age one deletion past the restore window
+    let expired_at = chrono::Utc::now() - chrono::Duration::days(ENTRY_RESTORE_WINDOW_DAYS + 1);
+    sqlx::query("UPDATE tracking_entries SET deleted_at = ? WHERE id = ?")
+        .bind(expired_at.to_rfc3339())
+        .bind(&expired_id)
+        .execute(&app.db_pool)
+        .await
+        .unwrap();
+
+    // Outside the window the entry can no longer be restored
+    let response = app
+        .client
+        .post(app.url(&format!(
+            "/plants/{}/entries/{}/restore",
+            plant_id, expired_id
+        )))
+        .send()
+        .await
+        .expect("Failed to restore tracking entry");
+    assert_eq!(response.status(), 404);
+
+    let cutoff = chrono::Utc::now() - chrono::Duration::days(ENTRY_RESTORE_WINDOW_DAYS);
+    let purged = purge_deleted_entries_before(&app.db_pool, cutoff)
+        .await
+        .expect("Failed to purge entries");
+    assert_eq!(purged, 1);
+
+    let remaining: Vec<String> = sqlx::query_scalar("SELECT id FROM tracking_entries")
+        .fetch_all(&app.db_pool)
+        .await
+        .unwrap();
+    assert_eq!(remaining, vec![recent_id]);
+}
+
+#[tokio::test]
+async fn test_import_entries_from_csv() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "import@example.com", "Import User", "password123").await;
+    let plant = common::create_test_plant(&app, "Imported Plant", "Importus").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let csv = "timestamp,entry_type,notes\n\
+               2024-03-01T08:00:00Z,watering,First drink\n\
+               2024-03-05T08:00:00Z,watering,\n\
+               not-a-date,watering,\n\
+               2024-03-10T08:00:00Z,fertilizing,\"Half strength, diluted\"\n";
+
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/entries/import.csv", plant_id)))
+        .header("Content-Type", "text/csv")
+        .body(csv)
+        .send()
+        .await
+        .expect("Failed to send import request");
+    assert_eq!(response.status(), 200);
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["imported"], 3);
+    assert_eq!(body["skipped"], 1);
+    let errors = body["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0]["line"], 4);
+    assert!(errors[0]["reason"]
+        .as_str()
+        .unwrap()
+        .contains("Invalid timestamp"));
+
+    let entries: serde_json::Value = app
+        .client
+        .get(app.url(&format!("/plants/{}/entries", plant_id)))
+        .send()
+        .await
+        .expect("Failed to list entries")
+        .json()
+        .await
+        .expect("Failed to parse entries");
+    assert_eq!(entries["total"], 3);
+
+    let plant: serde_json::Value = app
+        .client
+        .get(app.url(&format!("/plants/{}", plant_id)))
+        .send()
+        .await
+        .expect("Failed to get plant")
+        .json()
+        .await
+        .expect("Failed to parse plant");
+    assert_eq!(plant["lastWatered"], "2024-03-05T08:00:00Z");
+    assert_eq!(plant["lastFertilized"], "2024-03-10T08:00:00Z");
+}
+
+#[tokio::test]
+async fn test_create_entries_in_bulk() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "bulk-entries@example.com", "Bulk User", "password123").await;
+    let plant = common::create_test_plant(&app, "Bulk Plant", "Bulkus").await;
+    let plant_id = plant["id"].as_str().unwrap();
+    let bulk_url = app.url(&format!("/plants/{}/entries/bulk", plant_id));
+
+    let week = serde_json::json!({
+        "entries": [
+            { "entryType": "watering", "timestamp": "2024-03-03T08:00:00Z" },
+            { "entryType": "watering", "timestamp": "2024-03-07T08:00:00Z", "notes": "Latest" },
+            { "entryType": "watering", "timestamp": "2024-03-05T08:00:00Z" },
+            { "entryType": "fertilizing", "timestamp": "2024-03-04T08:00:00Z" }
+        ]
+    });
+
+    // An invalid entry is reported by index and nothing is created
+    let mut invalid = week.clone();
+    invalid["entries"][2]["notes"] = serde_json::json!("x".repeat(1001));
+    let response = app
+        .client
+        .post(&bulk_url)
+        .json(&invalid)
+        .send()
+        .await
+        .expect("Failed to send bulk request");
+    assert_eq!(response.status(), 422);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let message = body["details"]["entries"][0].as_str().unwrap();
+    assert!(message.starts_with("Entry 2: notes"), "{}", message);
+    assert!(list_entry_ids(&app, plant_id, "").await.is_empty());
+
+    // A failing insert rolls back the ones before it
+    sqlx::query(
+        "CREATE TRIGGER fail_bulk_insert BEFORE INSERT ON tracking_entries
+         WHEN NEW.entry_type = 'fertilizing'
+         BEGIN SELECT RAISE(ABORT, 'bulk insert failed'); END",
+    )
+    .execute(&app.db_pool)
+    .await
+    .unwrap();
+    let response = app
+        .client
+        .post(&bulk_url)
+        .json(&week)
+        .send()
+        .await
+        .expect("Failed to send bulk request");
+    assert_eq!(response.status(), 500);
+    assert!(list_entry_ids(&app, plant_id, "").await.is_empty());
+    assert!(get_last_watered(&app, plant_id).await.is_null());
+    sqlx::query("DROP TRIGGER fail_bulk_insert")
+        .execute(&app.db_pool)
+        .await
+        .unwrap();
+
+    let response = app
+        .client
+        .post(&bulk_url)
+        .json(&week)
+        .send()
+        .await
+        .expect("Failed to send bulk request");
+    assert_eq!(response.status(), 201);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["count"], 4);
+    let entries = body["entries"].as_array().unwrap();
+    assert_eq!(entries[1]["notes"], "Latest");
+    assert_eq!(entries[3]["entryType"], "fertilizing");
+    assert_eq!(list_entry_ids(&app, plant_id, "").await.len(), 4);
+
+    // Care dates come from the latest entry, not the last one sent
+    assert_eq!(get_last_watered(&app, plant_id).await, "2024-03-07T08:00:00Z");
+}
+
+#[tokio::test]
+async fn test_validate_only_import_writes_nothing() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "dry-run@example.com", "Dry Run User", "password123").await;
+    let plant = common::create_test_plant(&app, "Dry Run Plant", "Siccus").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let csv = "timestamp,entry_type,notes\n\
+               2024-03-01T08:00:00Z,watering,\n\
+               2024-03-05T08:00:00Z,repotting,\n\
+               2024-03-10T08:00:00Z,fertilizing,\n";
+
+    let response = app
+        .client
+        .post(app.url(&format!(
+            "/plants/{}/entries/import.csv?validate_only=true",
+            plant_id
+        )))
+        .header("Content-Type", "text/csv")
+        .body(csv)
+        .send()
+        .await
+        .expect("Failed to send import request");
+    assert_eq!(response.status(), 200);
+
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["validateOnly"], true);
+    assert_eq!(body["imported"], 2);
+    assert_eq!(body["skipped"], 1);
+    assert_eq!(body["errors"][0]["line"], 3);
+
+    let entries: serde_json::Value = app
+        .client
+        .get(app.url(&format!("/plants/{}/entries", plant_id)))
+        .send()
+        .await
+        .expect("Failed to list entries")
+        .json()
+        .await
+        .expect("Failed to parse entries");
+    assert_eq!(entries["total"], 0);
+    assert!(get_last_watered(&app, plant_id).await.is_null());
+}
+
+#[tokio::test]
+async fn test_weekly_rollup_respects_week_start() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "weeks@example.com", "Weeks User", "password123").await;
+    let plant = common::create_test_plant(&app, "Weekly Plant", "Hebdomadus").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    // Saturday, Sunday and Monday
+    for timestamp in [
+        "2024-03-09T10:00:00Z",
+        "2024-03-10T10:00:00Z",
+        "2024-03-11T10:00:00Z",
+    ] {
+        let response = app
+            .client
+            .post(app.url(&format!("/plants/{}/entries", plant_id)))
+            .json(&serde_json::json!({
+                "entryType": "watering",
+                "timestamp": timestamp
+            }))
+            .send()
+            .await
+            .expect("Failed to create entry");
+        assert_eq!(response.status(), 201);
+    }
+
+    let weekly_counts = || async {
+        let body: serde_json::Value = app
+            .client
+            .get(app.url(&format!(
+                "/plants/{}/entries/rollup?granularity=week",
+                plant_id
+            )))
+            .send()
+            .await
+            .expect("Failed to get rollup")
+            .json()
+            .await
+            .expect("Failed to parse rollup");
+        body["buckets"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|b| {
+                (
+                    b["periodStart"].as_str().unwrap().to_string(),
+                    b["count"].as_i64().unwrap(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // Monday is the default week start
+    assert_eq!(
+        weekly_counts().await,
+        vec![("2024-03-04".to_string(), 2), ("2024-03-11".to_string(), 1)]
+    );
+
+    let response = app
+        .client
+        .put(app.url("/settings/preferences"))
+        .json(&serde_json::json!({ "weekStart": "sunday" }))
+        .send()
+        .await
+        .expect("Failed to update preferences");
+    assert_eq!(response.status(), 200);
+
+    assert_eq!(
+        weekly_counts().await,
+        vec![("2024-03-03".to_string(), 1), ("2024-03-10".to_string(), 2)]
+    );
+
+    let response = app
+        .client
+        .get(app.url(&format!(
+            "/plants/{}/entries/rollup?granularity=fortnight",
+            plant_id
+        )))
+        .send()
+        .await
+        .expect("Failed to get rollup");
+    assert_eq!(response.status(), 400);
+}
+
+#[tokio::test]
+async fn test_custom_care_task_can_be_logged() {
+    let app = TestApp::new().await;
+    common::create_test_user(&app, "misting@example.com", "Misting User", "password123").await;
+    let plant = common::create_test_plant(&app, "Misted Fern", "Nephrolepis").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/care-tasks", plant_id)))
+        .json(&serde_json::json!({
+            "taskType": "misting",
+            "schedule": { "intervalDays": 3 }
+        }))
+        .send()
+        .await
+        .expect("Failed to create care task");
+    assert_eq!(response.status(), 201);
+    let task: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(task["taskType"], "misting");
+    assert!(task["lastDone"].is_null());
+
+    // Each task type can only be added once, and the built-ins can't be added at all
+    for (task_type, status) in [("misting", 400), ("watering", 422), ("Leaf Shine", 422)] {
+        let response = app
+            .client
+            .post(app.url(&format!("/plants/{}/care-tasks", plant_id)))
+            .json(&serde_json::json!({
+                "taskType": task_type,
+                "schedule": { "intervalDays": 3 }
+            }))
+            .send()
+            .await
+            .expect("Failed to create care task");
+        assert_eq!(response.status(), status, "task type {task_type}");
+    }
+
+    // Nothing has been done yet, so watering, fertilizing and misting are all due today
+    let load: serde_json::Value = app
+        .client
+        .get(app.url("/plants/schedule/load?days=1"))
+        .send()
+        .await
+        .expect("Failed to get schedule load")
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(load["days"][0]["count"], 3);
+
+    let timestamp = chrono::Utc::now() - chrono::Duration::hours(1);
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/entries", plant_id)))
+        .json(&serde_json::json!({
+            "entryType": { "care": { "taskType": "misting" } },
+            "timestamp": timestamp.to_rfc3339()
+        }))
+        .send()
+        .await
+        .expect("Failed to create entry");
+    assert_eq!(response.status(), 201);
+    let entry: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(entry["entryType"]["care"]["taskType"], "misting");
+    let entry_id = entry["id"].as_str().unwrap();
+
+    let plant: serde_json::Value = app
+        .client
+        .get(app.url(&format!("/plants/{}", plant_id)))
+        .send()
+        .await
+        .expect("Failed to get plant")
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(plant["careTasks"][0]["taskType"], "misting");
+    let last_done: chrono::DateTime<chrono::Utc> = plant["careTasks"][0]["lastDone"]
+        .as_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert_eq!(last_done.timestamp(), timestamp.timestamp());
+
+    let summaries: serde_json::Value = app
+        .client
+        .get(app.url("/plants?fields=summary"))
+        .send()
+        .await
+        .expect("Failed to list plants")
+        .json()
+        .await
+        .unwrap();
+    let summary_task = &summaries["plants"][0]["careTasks"][0];
+    assert_eq!(summary_task["taskType"], "misting");
+    assert!(summary_task["nextDue"].is_string());
+
+    let entries: serde_json::Value = app
+        .client
+        .get(app.url(&format!("/plants/{}/entries", plant_id)))
+        .send()
+        .await
+        .expect("Failed to list entries")
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(
+        entries["entries"][0]["entryType"]["care"]["taskType"],
+        "misting"
+    );
+
+    // Deleting the entry rolls last_done back
+    let response = app
+        .client
+        .delete(app.url(&format!("/plants/{}/entries/{}", plant_id, entry_id)))
+        .send()
+        .await
+        .expect("Failed to delete entry");
+    assert_eq!(response.status(), 204);
+
+    let task: serde_json::Value = app
+        .client
+        .get(app.url(&format!("/plants/{}/care-tasks/misting", plant_id)))
+        .send()
+        .await
+        .expect("Failed to get care task")
+        .json()
+        .await
+        .unwrap();
+    assert!(task["lastDone"].is_null());
+
+    // Care entries need a valid task type
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/entries", plant_id)))
+        .json(&serde_json::json!({
+            "entryType": { "care": { "taskType": "watering" } },
+            "timestamp": timestamp.to_rfc3339()
+        }))
+        .send()
+        .await
+        .expect("Failed to create entry");
+    assert_eq!(response.status(), 422);
+
+    let response = app
+        .client
+        .delete(app.url(&format!("/plants/{}/care-tasks/misting", plant_id)))
+        .send()
+        .await
+        .expect("Failed to delete care task");
+    assert_eq!(response.status(), 204);
+
+    let response = app
+        .client
+        .get(app.url(&format!("/plants/{}/care-tasks/misting", plant_id)))
+        .send()
+        .await
+        .expect("Failed to get care task");
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn test_bulk_delete_entries_by_filter() {
+    let app = TestApp::new().await;
+    common::create_test_user(&app, "bulkdelete@example.com", "Bulk User", "password123").await;
+    let plant = common::create_test_plant(&app, "Bulk Plant", "Purgea").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    for (entry_type, timestamp) in [
+        ("watering", "2024-03-01T10:00:00Z"),
+        ("watering", "2024-03-05T10:00:00Z"),
+        ("watering", "2024-03-09T10:00:00Z"),
+        ("fertilizing", "2024-03-02T10:00:00Z"),
+        ("note", "2024-03-08T10:00:00Z"),
+    ] {
+        let response = app
+            .client
+            .post(app.url(&format!("/plants/{}/entries", plant_id)))
+            .json(&serde_json::json!({
+                "entryType": entry_type,
+                "timestamp": timestamp
+            }))
+            .send()
+            .await
+            .expect("Failed to create entry");
+        assert_eq!(response.status(), 201);
+    }
+
+    let delete = |body: serde_json::Value| {
+        app.client
+            .delete(app.url(&format!("/plants/{}/entries", plant_id)))
+            .json(&body)
+            .send()
+    };
+    // At least one filter is required
+    let response = delete(serde_json::json!({})).await.unwrap();
+    assert_eq!(response.status(), 400);
+
+    // Watering on or after March 4th, leaving the first watering
+    let response = delete(serde_json::json!({
+        "entryType": "watering",
+        "from": "2024-03-04T00:00:00Z"
+    }))
+    .await
+    .unwrap();
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["deleted"], 2);
+    assert_eq!(
+        get_last_watered(&app, plant_id).await,
+        "2024-03-01T10:00:00Z"
+    );
+
+    // Everything before March 3rd, whatever its type
+    let response = delete(serde_json::json!({ "to": "2024-03-03T00:00:00Z" }))
+        .await
+        .unwrap();
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["deleted"], 2);
+    assert!(get_last_watered(&app, plant_id).await.is_null());
+
+    let entries: serde_json::Value = app
+        .client
+        .get(app.url(&format!("/plants/{}/entries", plant_id)))
+        .send()
+        .await
+        .expect("Failed to list entries")
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(entries["total"], 1);
+    assert_eq!(entries["entries"][0]["entryType"], "note");
+
+    // Another user's plant looks missing
+    common::create_test_user(&app, "bulkother@example.com", "Other User", "password123").await;
+    let response = delete(serde_json::json!({ "entryType": "note" }))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn test_note_visibility_defaults_to_shared() {
+    let app = TestApp::new().await;
+    common::create_test_user(&app, "private@example.com", "Private", "password123").await;
+    let plant = common::create_test_plant(&app, "Gift Fern", "Nephrolepis").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let create = |body: serde_json::Value| {
+        app.client
+            .post(app.url(&format!("/plants/{}/entries", plant_id)))
+            .json(&body)
+            .send()
+    };
+
+    let shared = create(serde_json::json!({
+        "entryType": "note",
+        "timestamp": "2024-01-01T12:00:00Z",
+        "notes": "New leaf"
+    }))
+    .await
+    .unwrap();
+    assert_eq!(shared.status(), 201);
+    let shared: serde_json::Value = shared.json().await.unwrap();
+    assert_eq!(shared["visibility"], "shared");
+
+    let private = create(serde_json::json!({
+        "entryType": "note",
+        "timestamp": "2024-01-02T12:00:00Z",
+        "notes": "Gift for mom",
+        "visibility": "owner_only"
+    }))
+    .await
+    .unwrap();
+    assert_eq!(private.status(), 201);
+    let private: serde_json::Value = private.json().await.unwrap();
+    assert_eq!(private["visibility"], "owner_only");
+
+    // Only notes can be hidden
+    let watering = create(serde_json::json!({
+        "entryType": "watering",
+        "timestamp": "2024-01-03T12:00:00Z",
+        "visibility": "owner_only"
+    }))
+    .await
+    .unwrap();
+    assert_eq!(watering.status(), 422);
+
+    // The owner sees both notes
+    let response = app
+        .client
+        .get(app.url(&format!("/plants/{}/entries?entry_type=note", plant_id)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    let visibilities: Vec<&str> = body["entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["visibility"].as_str().unwrap())
+        .collect();
+    assert_eq!(visibilities.len(), 2);
+    assert!(visibilities.contains(&"shared"));
+    assert!(visibilities.contains(&"owner_only"));
+
+    // A note can be shared again later
+    let private_id = private["id"].as_str().unwrap();
+    let response = app
+        .client
+        .put(app.url(&format!("/plants/{}/entries/{}", plant_id, private_id)))
+        .json(&serde_json::json!({ "visibility": "shared" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    let updated: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(updated["visibility"], "shared");
+}
+
+#[tokio::test]
+async fn test_owner_only_notes_hidden_from_public_link_viewers() {
+    let app = TestApp::new().await;
+    common::create_test_user(&app, "sharer@example.com", "Sharer", "password123").await;
+    let plant = common::create_test_plant(&app, "Shared Fern", "Nephrolepis").await;
+    let plant_id = plant["id"].as_str().unwrap();
+
+    let mut note_ids = Vec::new();
+    for (timestamp, notes, visibility) in [
+        ("2024-01-01T12:00:00Z", "New leaf", "shared"),
+        ("2024-01-02T12:00:00Z", "Gift for mom", "owner_only"),
+    ] {
+        let response = app
+            .client
+            .post(app.url(&format!("/plants/{}/entries", plant_id)))
+            .json(&serde_json::json!({
+                "entryType": "note",
+                "timestamp": timestamp,
+                "notes": notes,
+                "visibility": visibility
+            }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 201);
+        let note: serde_json::Value = response.json().await.unwrap();
+        note_ids.push(note["id"].as_str().unwrap().to_string());
+    }
+
+    let response = app
+        .client
+        .post(app.url(&format!("/plants/{}/public-link", plant_id)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 201);
+    let link: serde_json::Value = response.json().await.unwrap();
+    let public_url = app.url(&format!("/public/plants/{}", link["token"].as_str().unwrap()));
+
+    let viewer = reqwest::Client::new();
+    let shared_notes = || async {
+        let response = viewer.get(&public_url).send().await.unwrap();
+        assert_eq!(response.status(), 200);
+        let body: serde_json::Value = response.json().await.unwrap();
+        body["notes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|note| note["notes"].as_str().unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+    let owner_notes = || async {
+        let response = app
+            .client
+            .get(app.url(&format!("/plants/{}/entries?entry_type=note", plant_id)))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let body: serde_json::Value = response.json().await.unwrap();
+        body["total"].as_i64().unwrap()
+    };
+
+    // The viewer sees only the shared note, the owner sees both
+    assert_eq!(shared_notes().await, vec!["New leaf"]);
+    assert_eq!(owner_notes().await, 2);
+
+    // Swapping visibilities swaps what the viewer sees
+    for (note_id, visibility) in [(&note_ids[0], "owner_only"), (&note_ids[1], "shared")] {
+        let response = app
+            .client
+            .put(app.url(&format!("/plants/{}/entries/{}", plant_id, note_id)))
+            .json(&serde_json::json!({ "visibility": visibility }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+    }
+
+    assert_eq!(shared_notes().await, vec!["Gift for mom"]);
+    assert_eq!(owner_notes().await, 2);
+}
@@ -397,3 +397,36 @@ async fn test_session_isolation_after_logout() {
         .expect("Failed to create plant after logout");
     assert_eq!(response.status(), 401); // Unauthorized
 }
+
+#[tokio::test]
+async fn test_foreign_and_missing_plants_return_identical_not_found() {
+    let app = TestApp::new().await;
+
+    common::create_test_user(&app, "owner@example.com", "Owner", "password123").await;
+    let plant = common::create_test_plant(&app, "Owned Plant", "Ownicus").await;
+    let foreign_id = plant["id"].as_str().unwrap().to_string();
+
+    app.client
+        .post(app.url("/auth/logout"))
+        .send()
+        .await
+        .unwrap();
+    common::create_test_user(&app, "prober@example.com", "Prober", "password123").await;
+
+    let missing_id = uuid::Uuid::new_v4().to_string();
+    for suffix in ["", "/entries", "/photos"] {
+        let mut bodies = Vec::new();
+        for plant_id in [&foreign_id, &missing_id] {
+            let response = app
+                .client
+                .get(app.url(&format!("/plants/{}{}", plant_id, suffix)))
+                .send()
+                .await
+                .expect("Failed to send request");
+            assert_eq!(response.status(), 404, "/plants/{{id}}{}", suffix);
+            bodies.push(response.bytes().await.expect("Failed to read body"));
+        }
+
+        assert_eq!(bodies[0], bodies[1], "/plants/{{id}}{}", suffix);
+    }
+}
@@ -0,0 +1,248 @@
+use serde_json::json;
+
+mod common;
+use common::TestApp;
+
+use planty_api::utils::webhooks::{sign_payload, SIGNATURE_HEADER, TIMESTAMP_HEADER};
+
+/// Serve a webhook receiver that answers 204 only when the delivery is
+/// signed with `secret`, and 401 otherwise
+async fn spawn_webhook_receiver(secret: String) -> String {
+    use axum::http::{HeaderMap, StatusCode};
+
+    let app = axum::Router::new().route(
+        "/hook",
+        axum::routing::post(move |headers: HeaderMap, body: String| async move {
+            let header = |name: &str| {
+                headers
+                    .get(name)
+                    .and_then(|value| value.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string()
+            };
+            let timestamp: i64 = header(TIMESTAMP_HEADER).parse().unwrap_or_default();
+
+            if header(SIGNATURE_HEADER) == sign_payload(&secret, timestamp, &body) {
+                StatusCode::NO_CONTENT
+            } else {
+                StatusCode::UNAUTHORIZED
+            }
+        }),
+    );
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind webhook receiver");
+    let address = listener.local_addr().expect("Failed to get local address");
+    tokio::spawn(async move {
+        axum::serve(listener, app)
+            .await
+            .expect("Failed to start webhook receiver");
+    });
+
+    format!("http://{}/hook", address)
+}
+
+/// Test receivers listen on loopback, which webhooks may only target when allowed
+async fn local_webhook_app() -> TestApp {
+    TestApp::with_config(|config| config.webhook_allow_private_targets = true).await
+}
+
+async fn configure_webhook(app: &TestApp, url: &str) -> serde_json::Value {
+    let response = app
+        .client
+        .put(app.url("/settings/webhook"))
+        .json(&json!({ "url": url }))
+        .send()
+        .await
+        .expect("Failed to send webhook update");
+    assert_eq!(response.status(), 200);
+    response.json().await.expect("Failed to parse response")
+}
+
+#[tokio::test]
+async fn test_webhook_test_delivery_is_signed() {
+    let app = local_webhook_app().await;
+    common::create_test_user(&app, "hook@example.com", "Hook User", "password123").await;
+
+    // Placeholder URL just to obtain the secret the receiver will verify against
+    let settings = configure_webhook(&app, "http://127.0.0.1:9/hook").await;
+    let secret = settings["secret"].as_str().expect("secret on first setup");
+
+    let url = spawn_webhook_receiver(secret.to_string()).await;
+    let settings = configure_webhook(&app, &url).await;
+    assert!(settings.get("secret").is_none(), "secret is only shown once");
+
+    let response = app
+        .client
+        .post(app.url("/settings/webhook/test"))
+        .send()
+        .await
+        .expect("Failed to send webhook test");
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["success"], true);
+    assert_eq!(body["status"], 204);
+}
+
+#[tokio::test]
+async fn test_webhook_test_reports_unreachable_endpoint() {
+    let app = local_webhook_app().await;
+    common::create_test_user(&app, "down@example.com", "Down User", "password123").await;
+    configure_webhook(&app, "http://127.0.0.1:9/hook").await;
+
+    let response = app
+        .client
+        .post(app.url("/settings/webhook/test"))
+        .send()
+        .await
+        .expect("Failed to send webhook test");
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["success"], false);
+    assert!(body["status"].is_null());
+    assert_eq!(body["error"], "Could not reach webhook endpoint");
+}
+
+#[tokio::test]
+async fn test_rotate_webhook_secret() {
+    let app = TestApp::new().await;
+    let user = common::create_test_user(&app, "rotate@example.com", "Rotate User", "password123")
+        .await;
+    let user_id = user["user"]["id"].as_str().unwrap();
+
+    // Nothing to rotate before a webhook is configured
+    let response = app
+        .client
+        .post(app.url("/settings/webhook/rotate-secret"))
+        .send()
+        .await
+        .expect("Failed to send rotate request");
+    assert_eq!(response.status(), 404);
+
+    let settings = configure_webhook(&app, "https://example.com/hook").await;
+    let original = settings["secret"].as_str().unwrap().to_string();
+
+    let response = app
+        .client
+        .post(app.url("/settings/webhook/rotate-secret"))
+        .send()
+        .await
+        .expect("Failed to send rotate request");
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    let rotated = body["secret"].as_str().unwrap();
+    assert_ne!(rotated, original);
+
+    let stored: String = sqlx::query_scalar("SELECT secret FROM user_webhooks WHERE user_id = ?")
+        .bind(user_id)
+        .fetch_one(&app.db_pool)
+        .await
+        .unwrap();
+    assert_eq!(stored, rotated);
+}
+
+#[tokio::test]
+async fn test_webhook_url_must_be_public_http() {
+    let app = TestApp::new().await;
+    common::create_test_user(&app, "ssrf@example.com", "Ssrf User", "password123").await;
+
+    for url in [
+        "http://127.0.0.1:9/hook",
+        "http://localhost/hook",
+        "http://169.254.169.254/latest/meta-data",
+        "http://10.0.0.1/hook",
+        "http://[::1]/hook",
+        "ftp://example.com/hook",
+    ] {
+        let response = app
+            .client
+            .put(app.url("/settings/webhook"))
+            .json(&json!({ "url": url }))
+            .send()
+            .await
+            .expect("Failed to send webhook update");
+        assert_eq!(response.status(), 422, "{url}");
+    }
+
+    let response = app
+        .client
+        .get(app.url("/settings/webhook"))
+        .send()
+        .await
+        .expect("Failed to get webhook");
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn test_webhook_test_skips_private_targets_saved_earlier() {
+    let app = TestApp::new().await;
+    let user =
+        common::create_test_user(&app, "early@example.com", "Early User", "password123").await;
+    let user_id = user["user"]["id"].as_str().unwrap();
+
+    // A URL saved before targets were checked still isn't delivered to
+    configure_webhook(&app, "https://example.com/hook").await;
+    let url = spawn_webhook_receiver("whsec_unused".to_string()).await;
+    sqlx::query("UPDATE user_webhooks SET url = ? WHERE user_id = ?")
+        .bind(&url)
+        .bind(user_id)
+        .execute(&app.db_pool)
+        .await
+        .unwrap();
+
+    let response = app
+        .client
+        .post(app.url("/settings/webhook/test"))
+        .send()
+        .await
+        .expect("Failed to send webhook test");
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["success"], false);
+    assert!(body["status"].is_null());
+    assert_eq!(body["error"], "Could not reach webhook endpoint");
+}
+
+#[tokio::test]
+async fn test_webhook_test_does_not_follow_redirects() {
+    use axum::http::{header, StatusCode};
+
+    let app = local_webhook_app().await;
+    common::create_test_user(&app, "redirect@example.com", "Redirect User", "password123").await;
+
+    let hits = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let target_hits = hits.clone();
+    let receiver = axum::Router::new()
+        .route(
+            "/hook",
+            axum::routing::post(|| async {
+                (
+                    StatusCode::TEMPORARY_REDIRECT,
+                    [(header::LOCATION, "/internal")],
+                )
+            }),
+        )
+        .route(
+            "/internal",
+            axum::routing::post(move || async move {
+                target_hits.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                StatusCode::NO_CONTENT
+            }),
+        );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, receiver).await.unwrap() });
+
+    configure_webhook(&app, &format!("http://{}/hook", address)).await;
+    let response = app
+        .client
+        .post(app.url("/settings/webhook/test"))
+        .send()
+        .await
+        .expect("Failed to send webhook test");
+    let body: serde_json::Value = response.json().await.expect("Failed to parse response");
+    assert_eq!(body["success"], false);
+    assert_eq!(body["status"], 307);
+    assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 0);
+}
@@ -104,8 +104,8 @@ class ApiClient {
     return response;
   }
 
-  async validateInvite(request: ValidateInviteRequest): Promise<{ valid: boolean; uses_remaining: number }> {
-    return this.request<{ valid: boolean; uses_remaining: number }>('/invites/validate', {
+  async validateInvite(request: ValidateInviteRequest): Promise<{ valid: boolean; usesRemaining: number }> {
+    return this.request<{ valid: boolean; usesRemaining: number }>('/invites/validate', {
       method: 'POST', 
       body: JSON.stringify(request),
     });
@@ -115,6 +115,10 @@ class ApiClient {
     return this.request<User>('/auth/me');
   }
 
+  async pingSession(): Promise<{ authenticated: boolean; expiresAt: string | null }> {
+    return this.request<{ authenticated: boolean; expiresAt: string | null }>('/auth/session');
+  }
+
   async logout(): Promise<void> {
     await this.request('/auth/logout', {
       method: 'POST',
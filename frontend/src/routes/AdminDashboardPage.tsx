@@ -3,12 +3,12 @@ import { A } from '@solidjs/router';
 import { LoadingSpinner } from '@/components/ui/LoadingSpinner';
 
 interface SystemStats {
-  total_users: number;
-  max_total_users: number;
-  total_invites: number;
-  active_invites: number;
-  used_invites: number;
-  admin_count: number;
+  totalUsers: number;
+  maxTotalUsers: number;
+  totalInvites: number;
+  activeInvites: number;
+  usedInvites: number;
+  adminCount: number;
 }
 
 interface RecentUser {
@@ -16,23 +16,23 @@ interface RecentUser {
   email: string;
   name: string;
   role: string;
-  created_at: string;
+  createdAt: string;
 }
 
 interface RecentInvite {
   id: string;
   code: string;
-  created_by_name?: string;
-  max_uses: number;
-  current_uses: number;
-  is_active: boolean;
-  created_at: string;
+  createdByName?: string;
+  maxUses: number;
+  currentUses: number;
+  isActive: boolean;
+  createdAt: string;
 }
 
 interface AdminDashboardData {
-  system_stats: SystemStats;
-  recent_users: RecentUser[];
-  recent_invites: RecentInvite[];
+  systemStats: SystemStats;
+  recentUsers: RecentUser[];
+  recentInvites: RecentInvite[];
 }
 
 export const AdminDashboardPage: Component = () => {
@@ -119,7 +119,7 @@ export const AdminDashboardPage: Component = () => {
                         <dl>
                           <dt class="text-sm font-medium text-gray-500 truncate">Total Users</dt>
                           <dd class="text-lg font-medium text-gray-900">
-                            {data()!.system_stats.total_users} / {data()!.system_stats.max_total_users}
+                            {data()!.systemStats.totalUsers} / {data()!.systemStats.maxTotalUsers}
                           </dd>
                         </dl>
                       </div>
@@ -139,7 +139,7 @@ export const AdminDashboardPage: Component = () => {
                         <dl>
                           <dt class="text-sm font-medium text-gray-500 truncate">Active Invites</dt>
                           <dd class="text-lg font-medium text-gray-900">
-                            {data()!.system_stats.active_invites} / {data()!.system_stats.total_invites}
+                            {data()!.systemStats.activeInvites} / {data()!.systemStats.totalInvites}
                           </dd>
                         </dl>
                       </div>
@@ -158,7 +158,7 @@ export const AdminDashboardPage: Component = () => {
                       <div class="ml-5 w-0 flex-1">
                         <dl>
                           <dt class="text-sm font-medium text-gray-500 truncate">Admins</dt>
-                          <dd class="text-lg font-medium text-gray-900">{data()!.system_stats.admin_count}</dd>
+                          <dd class="text-lg font-medium text-gray-900">{data()!.systemStats.adminCount}</dd>
                         </dl>
                       </div>
                     </div>
@@ -221,7 +221,7 @@ export const AdminDashboardPage: Component = () => {
               <div class="px-4 py-5 sm:p-6">
                 <h2 class="text-lg font-medium text-gray-900 mb-4">Recent Users</h2>
                 <div class="space-y-3">
-                  {data()!.recent_users.map((user) => (
+                  {data()!.recentUsers.map((user) => (
                     <div class="flex items-center justify-between">
                       <div>
                         <p class="text-sm font-medium text-gray-900">{user.name}</p>
@@ -235,7 +235,7 @@ export const AdminDashboardPage: Component = () => {
                         }`}>
                           {user.role}
                         </span>
-                        <p class="text-xs text-gray-500 mt-1">{formatDate(user.created_at)}</p>
+                        <p class="text-xs text-gray-500 mt-1">{formatDate(user.createdAt)}</p>
                       </div>
                     </div>
                   ))}
@@ -248,19 +248,19 @@ export const AdminDashboardPage: Component = () => {
               <div class="px-4 py-5 sm:p-6">
                 <h2 class="text-lg font-medium text-gray-900 mb-4">Recent Invites</h2>
                 <div class="space-y-3">
-                  {data()!.recent_invites.map((invite) => (
+                  {data()!.recentInvites.map((invite) => (
                     <div class="flex items-center justify-between">
                       <div>
                         <p class="text-sm font-medium text-gray-900 font-mono">{invite.code}</p>
-                        <p class="text-sm text-gray-500">by {invite.created_by_name || 'System'}</p>
+                        <p class="text-sm text-gray-500">by {invite.createdByName || 'System'}</p>
                       </div>
                       <div class="text-right">
                         <span class={`inline-flex items-center px-2.5 py-0.5 rounded-full text-xs font-medium ${
-                          invite.is_active ? 'bg-green-100 text-green-800' : 'bg-gray-100 text-gray-800'
+                          invite.isActive ? 'bg-green-100 text-green-800' : 'bg-gray-100 text-gray-800'
                         }`}>
-                          {invite.current_uses}/{invite.max_uses} used
+                          {invite.currentUses}/{invite.maxUses} used
                         </span>
-                        <p class="text-xs text-gray-500 mt-1">{formatDate(invite.created_at)}</p>
+                        <p class="text-xs text-gray-500 mt-1">{formatDate(invite.createdAt)}</p>
                       </div>
                     </div>
                   ))}
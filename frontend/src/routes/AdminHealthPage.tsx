@@ -6,13 +6,13 @@ interface SystemHealth {
   timestamp: string;
   database: {
     status: string;
-    size_bytes: number;
-    page_count: number;
-    page_size: number;
+    sizeBytes: number;
+    pageCount: number;
+    pageSize: number;
   };
-  activity_24h: {
-    new_users: number;
-    new_invites: number;
+  activity24h: {
+    newUsers: number;
+    newInvites: number;
   };
   uptime: {
     note: string;
@@ -198,21 +198,21 @@ export const AdminHealthPage: Component = () => {
                 
                 <div class="text-center p-4 bg-gray-50 rounded-lg">
                   <div class="text-2xl font-bold text-blue-600">
-                    {formatBytes(health()?.database.size_bytes || 0)}
+                    {formatBytes(health()?.database.sizeBytes || 0)}
                   </div>
                   <div class="text-sm text-gray-600">Database Size</div>
                 </div>
                 
                 <div class="text-center p-4 bg-gray-50 rounded-lg">
                   <div class="text-2xl font-bold text-purple-600">
-                    {health()?.database.page_count?.toLocaleString() || 0}
+                    {health()?.database.pageCount?.toLocaleString() || 0}
                   </div>
                   <div class="text-sm text-gray-600">Pages</div>
                 </div>
                 
                 <div class="text-center p-4 bg-gray-50 rounded-lg">
                   <div class="text-2xl font-bold text-indigo-600">
-                    {formatBytes(health()?.database.page_size || 0)}
+                    {formatBytes(health()?.database.pageSize || 0)}
                   </div>
                   <div class="text-sm text-gray-600">Page Size</div>
                 </div>
@@ -228,7 +228,7 @@ export const AdminHealthPage: Component = () => {
               <div class="grid grid-cols-1 md:grid-cols-2 gap-6">
                 <div class="text-center p-4 bg-green-50 rounded-lg">
                   <div class="text-3xl font-bold text-green-600">
-                    {health()?.activity_24h.new_users || 0}
+                    {health()?.activity24h.newUsers || 0}
                   </div>
                   <div class="text-sm text-green-800">New Users</div>
                   <div class="text-xs text-gray-500 mt-1">Last 24 hours</div>
@@ -236,7 +236,7 @@ export const AdminHealthPage: Component = () => {
                 
                 <div class="text-center p-4 bg-blue-50 rounded-lg">
                   <div class="text-3xl font-bold text-blue-600">
-                    {health()?.activity_24h.new_invites || 0}
+                    {health()?.activity24h.newInvites || 0}
                   </div>
                   <div class="text-sm text-blue-800">New Invites</div>
                   <div class="text-xs text-gray-500 mt-1">Last 24 hours</div>
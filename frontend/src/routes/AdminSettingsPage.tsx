@@ -2,9 +2,9 @@ import { Component, createSignal, onMount, Show } from 'solid-js';
 import { A } from '@solidjs/router';
 
 interface AdminSettings {
-  max_total_users: number;
-  default_user_invite_limit: number;
-  registration_enabled: boolean;
+  maxTotalUsers: number;
+  defaultUserInviteLimit: number;
+  registrationEnabled: boolean;
 }
 
 export const AdminSettingsPage: Component = () => {
@@ -36,9 +36,9 @@ export const AdminSettingsPage: Component = () => {
       setSettings(data);
       
       // Update form state
-      setMaxUsers(data.max_total_users);
-      setDefaultInviteLimit(data.default_user_invite_limit);
-      setRegistrationEnabled(data.registration_enabled);
+      setMaxUsers(data.maxTotalUsers);
+      setDefaultInviteLimit(data.defaultUserInviteLimit);
+      setRegistrationEnabled(data.registrationEnabled);
     } catch (err) {
       console.error('Error loading settings:', err);
       setError(err instanceof Error ? err.message : 'Failed to load settings');
@@ -62,9 +62,9 @@ export const AdminSettingsPage: Component = () => {
         },
         credentials: 'include',
         body: JSON.stringify({
-          max_total_users: maxUsers(),
-          default_user_invite_limit: defaultInviteLimit(),
-          registration_enabled: registrationEnabled(),
+          maxTotalUsers: maxUsers(),
+          defaultUserInviteLimit: defaultInviteLimit(),
+          registrationEnabled: registrationEnabled(),
         }),
       });
       
@@ -237,18 +237,18 @@ export const AdminSettingsPage: Component = () => {
               <Show when={settings()}>
                 <div class="grid grid-cols-1 md:grid-cols-3 gap-6">
                   <div class="bg-blue-50 p-4 rounded-lg">
-                    <div class="text-2xl font-bold text-blue-600">{settings()?.max_total_users}</div>
+                    <div class="text-2xl font-bold text-blue-600">{settings()?.maxTotalUsers}</div>
                     <div class="text-sm text-blue-800">Max Users Allowed</div>
                   </div>
                   
                   <div class="bg-green-50 p-4 rounded-lg">
-                    <div class="text-2xl font-bold text-green-600">{settings()?.default_user_invite_limit}</div>
+                    <div class="text-2xl font-bold text-green-600">{settings()?.defaultUserInviteLimit}</div>
                     <div class="text-sm text-green-800">Default Invite Limit</div>
                   </div>
                   
                   <div class="bg-purple-50 p-4 rounded-lg">
                     <div class="text-2xl font-bold text-purple-600">
-                      {settings()?.registration_enabled ? 'Enabled' : 'Disabled'}
+                      {settings()?.registrationEnabled ? 'Enabled' : 'Disabled'}
                     </div>
                     <div class="text-sm text-purple-800">Registration Status</div>
                   </div>
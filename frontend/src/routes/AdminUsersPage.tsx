@@ -6,12 +6,12 @@ interface User {
   email: string;
   name: string;
   role: string;
-  can_create_invites: boolean;
-  max_invites: number | null;
-  invites_created: number;
-  invites_remaining: number | null;
-  created_at: string;
-  updated_at: string;
+  canCreateInvites: boolean;
+  maxInvites: number | null;
+  invitesCreated: number;
+  invitesRemaining: number | null;
+  createdAt: string;
+  updatedAt: string;
 }
 
 interface UserListResponse {
@@ -19,7 +19,7 @@ interface UserListResponse {
   total: number;
   page: number;
   limit: number;
-  total_pages: number;
+  totalPages: number;
 }
 
 export const AdminUsersPage: Component = () => {
@@ -164,7 +164,7 @@ export const AdminUsersPage: Component = () => {
                 Users ({data()?.total || 0})
               </h2>
               <div class="text-sm text-gray-500">
-                Page {data()?.page || 1} of {data()?.total_pages || 1}
+                Page {data()?.page || 1} of {data()?.totalPages || 1}
               </div>
             </div>
 
@@ -206,14 +206,14 @@ export const AdminUsersPage: Component = () => {
                         </td>
                         <td class="px-6 py-4 whitespace-nowrap text-sm text-gray-900">
                           <Show
-                            when={user.can_create_invites}
+                            when={user.canCreateInvites}
                             fallback={<span class="text-gray-400">None</span>}
                           >
                             <div>
                               <div>Can create invites</div>
                               <div class="text-xs text-gray-500">
-                                {user.invites_remaining !== null 
-                                  ? `${user.invites_remaining} remaining` 
+                                {user.invitesRemaining !== null 
+                                  ? `${user.invitesRemaining} remaining` 
                                   : 'Unlimited'
                                 }
                               </div>
@@ -221,7 +221,7 @@ export const AdminUsersPage: Component = () => {
                           </Show>
                         </td>
                         <td class="px-6 py-4 whitespace-nowrap text-sm text-gray-500">
-                          {formatDate(user.created_at)}
+                          {formatDate(user.createdAt)}
                         </td>
                         <td class="px-6 py-4 whitespace-nowrap text-right text-sm font-medium">
                           <button
@@ -242,7 +242,7 @@ export const AdminUsersPage: Component = () => {
             </div>
 
             {/* Pagination */}
-            <Show when={(data()?.total_pages || 0) > 1}>
+            <Show when={(data()?.totalPages || 0) > 1}>
               <div class="mt-6 flex items-center justify-between">
                 <button
                   onClick={() => loadUsers(Math.max(1, currentPage() - 1), roleFilter())}
@@ -252,11 +252,11 @@ export const AdminUsersPage: Component = () => {
                   Previous
                 </button>
                 <span class="text-sm text-gray-700">
-                  Page {currentPage()} of {data()?.total_pages || 1}
+                  Page {currentPage()} of {data()?.totalPages || 1}
                 </span>
                 <button
-                  onClick={() => loadUsers(Math.min(data()?.total_pages || 1, currentPage() + 1), roleFilter())}
-                  disabled={currentPage() >= (data()?.total_pages || 1)}
+                  onClick={() => loadUsers(Math.min(data()?.totalPages || 1, currentPage() + 1), roleFilter())}
+                  disabled={currentPage() >= (data()?.totalPages || 1)}
                   class="relative inline-flex items-center px-4 py-2 border border-gray-300 text-sm font-medium rounded-md text-gray-700 bg-white hover:bg-gray-50 disabled:opacity-50 disabled:cursor-not-allowed"
                 >
                   Next
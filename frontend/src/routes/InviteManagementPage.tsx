@@ -8,11 +8,11 @@ import { apiClient } from '@/api/client';
 interface InviteCode {
   id: string;
   code: string;
-  max_uses: number;
-  current_uses: number;
-  is_active: boolean;
-  expires_at?: string;
-  created_at: string;
+  maxUses: number;
+  currentUses: number;
+  isActive: boolean;
+  expiresAt?: string;
+  createdAt: string;
 }
 
 export const InviteManagementPage: Component = () => {
@@ -55,8 +55,8 @@ export const InviteManagementPage: Component = () => {
       const newInvite = await apiClient.request<InviteCode>('/invites/create', {
         method: 'POST',
         body: JSON.stringify({
-          max_uses: maxUses(),
-          expires_at: expiresAt,
+          maxUses: maxUses(),
+          expiresAt: expiresAt,
         }),
       });
 
@@ -190,17 +190,17 @@ export const InviteManagementPage: Component = () => {
                           {invite.code}
                         </code>
                         <span class={`px-2 py-1 rounded-full text-xs font-medium ${
-                          invite.is_active ? 'bg-green-100 text-green-800' : 'bg-red-100 text-red-800'
+                          invite.isActive ? 'bg-green-100 text-green-800' : 'bg-red-100 text-red-800'
                         }`}>
-                          {invite.is_active ? 'Active' : 'Inactive'}
+                          {invite.isActive ? 'Active' : 'Inactive'}
                         </span>
                       </div>
                       
                       <div class="mt-2 text-sm text-gray-600 space-x-4">
-                        <span>Uses: {invite.current_uses} / {invite.max_uses}</span>
-                        <span>Created: {new Date(invite.created_at).toLocaleDateString()}</span>
-                        {invite.expires_at && (
-                          <span>Expires: {new Date(invite.expires_at).toLocaleDateString()}</span>
+                        <span>Uses: {invite.currentUses} / {invite.maxUses}</span>
+                        <span>Created: {new Date(invite.createdAt).toLocaleDateString()}</span>
+                        {invite.expiresAt && (
+                          <span>Expires: {new Date(invite.expiresAt).toLocaleDateString()}</span>
                         )}
                       </div>
                     </div>